@@ -13,7 +13,8 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
 
 use evaluations::{
     ClientInferenceExecutor, EvaluationCoreArgs, EvaluationFunctionConfig,
-    EvaluationFunctionConfigTable, EvaluationVariant, run_evaluation_core_streaming,
+    EvaluationFunctionConfigTable, EvaluationRetryPolicy, EvaluationVariant,
+    run_evaluation_core_streaming,
 };
 use futures::StreamExt;
 use pyo3::{
@@ -557,6 +558,8 @@ impl BaseTensorZeroGateway {
             extra_body,
             extra_headers,
             internal_dynamic_variant_config,
+            // Not yet exposed to Python callers; see `ClientInferenceParams::timeout_ms`.
+            timeout_ms: None,
             otlp_traces_extra_headers: otlp_traces_extra_headers.unwrap_or_default(),
             otlp_traces_extra_attributes: otlp_traces_extra_attributes.unwrap_or_default(),
             otlp_traces_extra_resources: otlp_traces_extra_resources.unwrap_or_default(),
@@ -1506,6 +1509,7 @@ impl TensorZeroGateway {
         let core_args = EvaluationCoreArgs {
             inference_executor,
             clickhouse_client: app_state.clickhouse_connection_info.clone(),
+            postgres_connection_info: app_state.postgres_connection_info.clone(),
             evaluation_config,
             function_configs,
             evaluation_name,
@@ -1516,6 +1520,7 @@ impl TensorZeroGateway {
             concurrency,
             inference_cache: inference_cache_enum,
             tags: HashMap::new(), // No external tags for Python client evaluations
+            retry_policy: EvaluationRetryPolicy::default(),
         };
 
         let result = tokio_block_on_without_gil(
@@ -2772,6 +2777,7 @@ impl AsyncTensorZeroGateway {
             let core_args = EvaluationCoreArgs {
                 inference_executor,
                 clickhouse_client: app_state.clickhouse_connection_info.clone(),
+                postgres_connection_info: app_state.postgres_connection_info.clone(),
                 evaluation_config,
                 function_configs,
                 evaluation_name,
@@ -2782,6 +2788,7 @@ impl AsyncTensorZeroGateway {
                 concurrency,
                 inference_cache: inference_cache_enum,
                 tags: HashMap::new(), // No external tags for Python client evaluations
+                retry_policy: EvaluationRetryPolicy::default(),
             };
 
             let result =
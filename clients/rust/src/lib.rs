@@ -1,8 +1,6 @@
 #![recursion_limit = "256"]
 
 use std::{collections::HashMap, sync::Arc};
-use tensorzero_core::config::snapshot::ConfigSnapshot;
-use tensorzero_core::config::write_config_snapshot;
 use tensorzero_core::db::HealthCheckable;
 use tensorzero_core::db::inferences::InferenceQueries;
 use tensorzero_core::endpoints::datasets::{InsertDatapointParams, StaleDatasetResponse};
@@ -23,9 +21,13 @@ use uuid::Uuid;
 // Client core types
 pub use tensorzero_core::client::{
     Client, ClientBuilder, ClientBuilderMode, ClientMode, EmbeddedGateway, HTTPGateway,
-    PostgresConfig, get_config_no_verify_credentials,
+    ParallelInferenceItem, ParallelInferenceOptions, ParallelInferenceOutput, PostgresConfig,
+    ResponseCacheOptions, get_config_no_verify_credentials,
 };
 
+// Offline queue types
+pub use offline_queue::{FlushSummary, OFFLINE_QUEUE_ID_TAG, OfflineQueue, QueuedOrSent};
+
 // Client error types
 pub use tensorzero_core::client::{
     ClientBuilderError, TensorZeroError, TensorZeroInternalError, err_to_http,
@@ -43,10 +45,11 @@ pub use tensorzero_core::client::input_handling;
 
 // Re-export other commonly used types from tensorzero-core
 pub use tensorzero_core::config::Config;
+pub use tensorzero_core::db::cache_queries::{CacheQueries, CacheStats};
 pub use tensorzero_core::db::clickhouse::query_builder::{
-    BooleanMetricFilter, FloatComparisonOperator, FloatMetricFilter, InferenceFilter, OrderBy,
-    OrderByTerm, OrderDirection, TagComparisonOperator, TagFilter, TimeComparisonOperator,
-    TimeFilter,
+    BooleanMetricFilter, DemonstrationFeedbackFilter, FloatComparisonOperator, FloatMetricFilter,
+    InferenceFilter, OrderBy, OrderByTerm, OrderDirection, TagComparisonOperator, TagFilter,
+    TimeComparisonOperator, TimeFilter,
 };
 pub use tensorzero_core::db::datasets::{
     DatasetQueries, GetDatapointParams, GetDatapointsParams, GetDatasetMetadataParams,
@@ -55,13 +58,18 @@ pub use tensorzero_core::db::inferences::{InferenceOutputSource, ListInferencesP
 pub use tensorzero_core::db::stored_datapoint::{
     StoredChatInferenceDatapoint, StoredDatapoint, StoredJsonInferenceDatapoint,
 };
-pub use tensorzero_core::db::{ClickHouseConnection, ModelUsageTimePoint, TimeWindow};
+pub use tensorzero_core::db::{
+    ClickHouseConnection, ConfigSnapshotTagFilter, ModelUsageTimePoint, TimeWindow,
+};
 pub use tensorzero_core::endpoints::datasets::v1::types::{
     CreateChatDatapointRequest, CreateDatapointRequest, CreateDatapointsFromInferenceRequest,
     CreateDatapointsFromInferenceRequestParams, CreateDatapointsRequest, CreateDatapointsResponse,
-    CreateJsonDatapointRequest, DatasetMetadata, DeleteDatapointsRequest, DeleteDatapointsResponse,
-    GetDatapointsRequest, GetDatapointsResponse, JsonDatapointOutputUpdate, ListDatapointsRequest,
-    ListDatasetsRequest, ListDatasetsResponse, UpdateChatDatapointRequest,
+    CreateJsonDatapointRequest, DatapointEmbedding, DatasetFileFormat, DatasetMetadata,
+    DeduplicateDatapointsRequest, DeduplicateDatapointsResponse, DeduplicationAction,
+    DeduplicationStrategy, DeleteDatapointsRequest, DeleteDatapointsResponse, DuplicateGroup,
+    ExportDatasetRequest, GetDatapointsRequest, GetDatapointsResponse, ImportDatasetRequest,
+    ImportDatasetResponse, JsonDatapointOutputUpdate, ListDatapointsRequest, ListDatasetsRequest,
+    ListDatasetsResponse, SplitDatasetRequest, SplitDatasetResponse, UpdateChatDatapointRequest,
     UpdateDatapointMetadataRequest, UpdateDatapointRequest, UpdateDatapointsMetadataRequest,
     UpdateDatapointsRequest, UpdateDatapointsResponse, UpdateJsonDatapointRequest,
 };
@@ -74,8 +82,24 @@ pub use tensorzero_core::endpoints::inference::{
     ChatCompletionInferenceParams, InferenceOutput, InferenceParams, InferenceResponse,
     InferenceResponseChunk, InferenceStream,
 };
+use tensorzero_core::endpoints::internal::cache::invalidate_cache;
+pub use tensorzero_core::endpoints::internal::cache::{
+    InvalidateCacheParams, InvalidateCacheResponse,
+};
 pub use tensorzero_core::endpoints::internal::config::{
-    GetConfigResponse, WriteConfigRequest, WriteConfigResponse,
+    AbortCanaryRequest, CanaryRollout, CanaryRolloutKey, CanaryStatus,
+    ConfigSnapshotSummaryResponse, GetConfigResponse, ListConfigSnapshotsRequest,
+    ListConfigSnapshotsResponse, StartCanaryRequest, UpdateSnapshotTagsRequest,
+    UpdateSnapshotTagsResponse, ValidateConfigRequest, ValidateConfigResponse, WriteConfigRequest,
+    WriteConfigResponse,
+};
+use tensorzero_core::endpoints::internal::config::{
+    abort_canary, get_canary_status, list_config_snapshots, start_canary, update_snapshot_tags,
+    validate_config, write_config,
+};
+pub use tensorzero_core::endpoints::internal::evaluations::{
+    EvaluationRunComparison, EvaluationRunDetails, EvaluationRunInfo, EvaluatorDelta,
+    ListEvaluationRunsResponse,
 };
 pub use tensorzero_core::endpoints::object_storage::ObjectResponse;
 pub use tensorzero_core::endpoints::stored_inferences::v1::types::{
@@ -111,6 +135,8 @@ pub use tensorzero_optimizers::endpoints::{
 // Keep git module for Git-related extension traits
 mod git;
 
+pub mod offline_queue;
+
 #[cfg(feature = "e2e_tests")]
 pub mod test_helpers;
 
@@ -119,6 +145,7 @@ pub mod test_helpers;
 pub use tensorzero_core::observability;
 
 use crate::git::GitInfo;
+use crate::offline_queue::QueuedWrite;
 
 // NOTE(shuyangli): For methods that delegate to APIs in the gateway, the arguments generally are flattened from the request type for
 // ease of use, except when the type contains more than 2-3 fields or multiple fields with the same type (e.g. `ListDatapointsRequest`).
@@ -240,6 +267,93 @@ pub trait ClientExt {
         request: ListDatapointsRequest,
     ) -> Result<GetDatapointsResponse, TensorZeroError>;
 
+    /// Deterministically partitions a dataset's datapoints into named splits (e.g.
+    /// `train` / `test`), recorded as a `tensorzero::split` tag on each datapoint.
+    /// Re-running with the same `request` reproduces the same assignment, so it's safe to
+    /// call again after adding more datapoints to the dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset_name` - The name of the dataset to split.
+    /// * `request` - The split names/weights, seed, and optional function name filter.
+    ///
+    /// # Returns
+    ///
+    /// A `SplitDatasetResponse` containing the number of datapoints assigned to each split.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn split_dataset(
+        &self,
+        dataset_name: String,
+        request: SplitDatasetRequest,
+    ) -> Result<SplitDatasetResponse, TensorZeroError>;
+
+    /// Detects duplicate or near-duplicate datapoints within a dataset and either tags or
+    /// deletes them, depending on `request.action`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset_name` - The name of the dataset to deduplicate.
+    /// * `request` - The deduplication strategy, action, and optional function name filter.
+    ///
+    /// # Returns
+    ///
+    /// A `DeduplicateDatapointsResponse` reporting the duplicate groups that were found.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn deduplicate_datapoints(
+        &self,
+        dataset_name: String,
+        request: DeduplicateDatapointsRequest,
+    ) -> Result<DeduplicateDatapointsResponse, TensorZeroError>;
+
+    /// Exports a dataset's datapoints as a downloadable file (currently only `jsonl` is
+    /// implemented; `parquet` is accepted but returns an error).
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset_name` - The name of the dataset to export.
+    /// * `request` - The export format and optional function name filter.
+    ///
+    /// # Returns
+    ///
+    /// The raw exported file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn export_dataset(
+        &self,
+        dataset_name: String,
+        request: ExportDatasetRequest,
+    ) -> Result<Vec<u8>, TensorZeroError>;
+
+    /// Bulk-imports datapoints into a dataset from a file previously produced by
+    /// `export_dataset` (currently only `jsonl` is implemented; `parquet` is accepted but
+    /// returns an error).
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset_name` - The name of the dataset to import into.
+    /// * `request` - The file format and contents to import.
+    ///
+    /// # Returns
+    ///
+    /// An `ImportDatasetResponse` containing the IDs of the created datapoints.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn import_dataset(
+        &self,
+        dataset_name: String,
+        request: ImportDatasetRequest,
+    ) -> Result<ImportDatasetResponse, TensorZeroError>;
+
     /// Updates datapoints in the dataset.
     ///
     /// # Arguments
@@ -379,6 +493,50 @@ pub trait ClientExt {
         request: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, TensorZeroError>;
 
+    // ================================================================
+    // Evaluation run operations
+    // ================================================================
+
+    /// Lists evaluation runs across all functions, most recent first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn list_evaluation_runs(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListEvaluationRunsResponse, TensorZeroError>;
+
+    /// Gets a single evaluation run, including its aggregate per-metric statistics, so past runs
+    /// can be compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails, or if no run with the given id exists
+    /// for the given function.
+    async fn get_evaluation_run(
+        &self,
+        evaluation_run_id: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunDetails, TensorZeroError>;
+
+    /// Compares two evaluation runs, computing per-evaluator deltas (paired on shared datapoint
+    /// IDs where possible) and flagging significant regressions so callers can gate config
+    /// rollouts on the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn compare_evaluation_runs(
+        &self,
+        run_a: Uuid,
+        run_b: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunComparison, TensorZeroError>;
+
     // ================================================================
     // Workflow evaluation operations
     // ================================================================
@@ -530,6 +688,136 @@ pub trait ClientExt {
         request: WriteConfigRequest,
     ) -> Result<WriteConfigResponse, TensorZeroError>;
 
+    /// Stages a config snapshot as a canary rollout for a percentage of traffic.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails, the snapshot doesn't exist, or a
+    /// canary rollout is already active for it.
+    async fn start_canary(
+        &self,
+        config_snapshot_hash: &str,
+        request: StartCanaryRequest,
+    ) -> Result<CanaryRollout, TensorZeroError>;
+
+    /// Gets the canary rollout state for a config snapshot, or `None` if it has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails or the snapshot doesn't exist.
+    async fn get_canary_status(
+        &self,
+        config_snapshot_hash: &str,
+    ) -> Result<Option<CanaryRollout>, TensorZeroError>;
+
+    /// Aborts the active canary rollout for a config snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails or there is no active rollout.
+    async fn abort_canary(
+        &self,
+        config_snapshot_hash: &str,
+        request: AbortCanaryRequest,
+    ) -> Result<CanaryRollout, TensorZeroError>;
+
+    /// Lists config snapshots ordered by creation time (most recent first), with pagination and
+    /// optional tag filtering.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn list_config_snapshots(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag_filter: Option<ConfigSnapshotTagFilter>,
+    ) -> Result<ListConfigSnapshotsResponse, TensorZeroError>;
+
+    /// Merges `tags` into a config snapshot's existing tags (new tags override existing keys),
+    /// leaving its config and templates untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails or the snapshot doesn't exist.
+    async fn update_snapshot_tags(
+        &self,
+        config_snapshot_hash: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<UpdateSnapshotTagsResponse, TensorZeroError>;
+
+    /// Validates a config (parsing, schema, template, and model validation, optionally including
+    /// credential and object storage checks), without persisting a snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request itself fails. A config that fails validation
+    /// is reported in the response's `valid`/`error`/`error_details` fields, not as an `Err`.
+    async fn validate_config(
+        &self,
+        request: ValidateConfigRequest,
+    ) -> Result<ValidateConfigResponse, TensorZeroError>;
+
+    /// Returns the inference cache's hit rate, broken down by model and by function.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails.
+    async fn get_cache_stats(&self) -> Result<CacheStats, TensorZeroError>;
+
+    /// Invalidates (soft-deletes) cached inference outputs matching `params`, so that stale
+    /// outputs from a provider-side model update stop being served from the cache.
+    ///
+    /// Only `params.model_name` is currently supported; see [`InvalidateCacheParams`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the request fails, or if `params` names an unsupported
+    /// invalidation scope (function or config-snapshot hash).
+    async fn invalidate_cache(
+        &self,
+        params: InvalidateCacheParams,
+    ) -> Result<InvalidateCacheResponse, TensorZeroError>;
+
+    /// Sends feedback, or - if the gateway is unreachable - persists it to `queue` to be retried
+    /// later with [`ClientExt::flush_offline_queue`]. See the [`offline_queue`] module docs for
+    /// what durability and idempotency guarantees this queue does and doesn't provide.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the gateway rejected the request (e.g. a validation
+    /// error) - only a transport-level failure is queued rather than returned.
+    async fn feedback_or_queue(
+        &self,
+        queue: &OfflineQueue,
+        params: FeedbackParams,
+    ) -> Result<QueuedOrSent<FeedbackResponse>, TensorZeroError>;
+
+    /// Creates datapoints, or - if the gateway is unreachable - persists the request to `queue`
+    /// to be retried later with [`ClientExt::flush_offline_queue`]. See the [`offline_queue`]
+    /// module docs for what durability and idempotency guarantees this queue does and doesn't
+    /// provide.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TensorZeroError` if the gateway rejected the request (e.g. a validation
+    /// error) - only a transport-level failure is queued rather than returned.
+    async fn create_datapoints_or_queue(
+        &self,
+        queue: &OfflineQueue,
+        dataset_name: String,
+        datapoints: Vec<CreateDatapointRequest>,
+    ) -> Result<QueuedOrSent<CreateDatapointsResponse>, TensorZeroError>;
+
+    /// Retries every write currently in `queue`, removing entries that succeed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if the queue file itself can't be read or rewritten. Individual
+    /// write failures during the retry are not errors - they're reflected in the returned
+    /// `FlushSummary` and left in the queue for the next flush.
+    async fn flush_offline_queue(&self, queue: &OfflineQueue) -> std::io::Result<FlushSummary>;
+
     #[cfg(any(feature = "e2e_tests", feature = "pyo3"))]
     fn get_app_state_data(&self) -> Option<&tensorzero_core::utils::gateway::AppStateData>;
 }
@@ -992,6 +1280,132 @@ impl ClientExt for Client {
         }
     }
 
+    async fn split_dataset(
+        &self,
+        dataset_name: String,
+        request: SplitDatasetRequest,
+    ) -> Result<SplitDatasetResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join(&format!("v1/datasets/{dataset_name}/split")).map_err(|e| TensorZeroError::Other {
+                    source: Error::new(ErrorDetails::InvalidBaseUrl {
+                        message: format!("Failed to join base URL with /v1/datasets/{dataset_name}/split endpoint: {e}"),
+                    })
+                    .into(),
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    tensorzero_core::endpoints::datasets::v1::split_dataset(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        &dataset_name,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn deduplicate_datapoints(
+        &self,
+        dataset_name: String,
+        request: DeduplicateDatapointsRequest,
+    ) -> Result<DeduplicateDatapointsResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join(&format!("v1/datasets/{dataset_name}/deduplicate")).map_err(|e| TensorZeroError::Other {
+                    source: Error::new(ErrorDetails::InvalidBaseUrl {
+                        message: format!("Failed to join base URL with /v1/datasets/{dataset_name}/deduplicate endpoint: {e}"),
+                    })
+                    .into(),
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    tensorzero_core::endpoints::datasets::v1::deduplicate_datapoints(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        &dataset_name,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn export_dataset(
+        &self,
+        dataset_name: String,
+        request: ExportDatasetRequest,
+    ) -> Result<Vec<u8>, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join(&format!("v1/datasets/{dataset_name}/export")).map_err(|e| TensorZeroError::Other {
+                    source: Error::new(ErrorDetails::InvalidBaseUrl {
+                        message: format!("Failed to join base URL with /v1/datasets/{dataset_name}/export endpoint: {e}"),
+                    })
+                    .into(),
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_get_bytes(builder).await?.to_vec())
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    tensorzero_core::endpoints::datasets::v1::export_dataset(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        &dataset_name,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn import_dataset(
+        &self,
+        dataset_name: String,
+        request: ImportDatasetRequest,
+    ) -> Result<ImportDatasetResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join(&format!("v1/datasets/{dataset_name}/import")).map_err(|e| TensorZeroError::Other {
+                    source: Error::new(ErrorDetails::InvalidBaseUrl {
+                        message: format!("Failed to join base URL with /v1/datasets/{dataset_name}/import endpoint: {e}"),
+                    })
+                    .into(),
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    tensorzero_core::endpoints::datasets::v1::import_dataset(
+                        &gateway.handle.app_state.config,
+                        &gateway.handle.app_state.http_client,
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        &dataset_name,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
     async fn update_datapoints_metadata(
         &self,
         dataset_name: String,
@@ -1169,26 +1583,179 @@ impl ClientExt for Client {
         }
     }
 
-    /// Query the Clickhouse database for inferences.
-    ///
-    /// This function is only available in EmbeddedGateway mode.
-    ///
-    /// # Arguments
-    ///
-    /// * `function_name` - The name of the function to query.
-    /// * `variant_name` - The name of the variant to query. Optional
-    /// * `filters` - A filter tree to apply to the query. Optional
-    /// * `output_source` - The source of the output to query. "inference" or "demonstration"
-    /// * `limit` - The maximum number of inferences to return. Optional
-    /// * `offset` - The offset to start from. Optional
-    /// * `format` - The format to return the inferences in. For now, only "JSONEachRow" is supported.
-    async fn experimental_list_inferences(
+    async fn list_evaluation_runs(
         &self,
-        params: ListInferencesParams<'_>,
-    ) -> Result<Vec<StoredInference>, TensorZeroError> {
-        // TODO: consider adding a flag that returns the generated sql query
-        let ClientMode::EmbeddedGateway { gateway, .. } = self.mode() else {
-            return Err(TensorZeroError::Other {
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListEvaluationRunsResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let mut url = client.base_url.join("internal/evaluations/runs").map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/evaluations/runs endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                url.query_pairs_mut()
+                    .append_pair("limit", &limit.to_string())
+                    .append_pair("offset", &offset.to_string());
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    tensorzero_core::endpoints::internal::evaluations::list_evaluation_runs(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        limit,
+                        offset,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn get_evaluation_run(
+        &self,
+        evaluation_run_id: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunDetails, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let mut url = client
+                    .base_url
+                    .join(&format!("internal/evaluations/runs/{evaluation_run_id}"))
+                    .map_err(|e| TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/evaluations/runs/{evaluation_run_id} endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    })?;
+                url.query_pairs_mut()
+                    .append_pair("evaluation_name", &evaluation_name)
+                    .append_pair("function_name", &function_name);
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    let config = &gateway.handle.app_state.config;
+                    let evaluation_config = config
+                        .evaluations
+                        .get(&evaluation_name)
+                        .ok_or_else(|| {
+                            Error::new(ErrorDetails::InvalidRequest {
+                                message: format!("Unknown evaluation: {evaluation_name}"),
+                            })
+                        })
+                        .map_err(err_to_http)?;
+                    let function_config =
+                        tensorzero_core::function::get_function(&config.functions, &function_name)
+                            .map_err(err_to_http)?;
+                    tensorzero_core::endpoints::internal::evaluations::get_evaluation_run(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        evaluation_run_id,
+                        &evaluation_name,
+                        &function_name,
+                        function_config.config_type(),
+                        evaluation_config,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn compare_evaluation_runs(
+        &self,
+        run_a: Uuid,
+        run_b: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunComparison, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let mut url = client
+                    .base_url
+                    .join(&format!("internal/evaluations/runs/{run_a}/compare"))
+                    .map_err(|e| TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/evaluations/runs/{run_a}/compare endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    })?;
+                url.query_pairs_mut()
+                    .append_pair("other_evaluation_run_id", &run_b.to_string())
+                    .append_pair("evaluation_name", &evaluation_name)
+                    .append_pair("function_name", &function_name);
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    let config = &gateway.handle.app_state.config;
+                    let evaluation_config = config
+                        .evaluations
+                        .get(&evaluation_name)
+                        .ok_or_else(|| {
+                            Error::new(ErrorDetails::InvalidRequest {
+                                message: format!("Unknown evaluation: {evaluation_name}"),
+                            })
+                        })
+                        .map_err(err_to_http)?;
+                    let function_config =
+                        tensorzero_core::function::get_function(&config.functions, &function_name)
+                            .map_err(err_to_http)?;
+                    tensorzero_core::endpoints::internal::evaluations::compare_evaluation_runs(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        run_a,
+                        run_b,
+                        &evaluation_name,
+                        &function_name,
+                        function_config.config_type(),
+                        evaluation_config,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    /// Query the Clickhouse database for inferences.
+    ///
+    /// This function is only available in EmbeddedGateway mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_name` - The name of the function to query.
+    /// * `variant_name` - The name of the variant to query. Optional
+    /// * `filters` - A filter tree to apply to the query. Optional
+    /// * `output_source` - The source of the output to query. "inference" or "demonstration"
+    /// * `limit` - The maximum number of inferences to return. Optional
+    /// * `offset` - The offset to start from. Optional
+    /// * `format` - The format to return the inferences in. For now, only "JSONEachRow" is supported.
+    async fn experimental_list_inferences(
+        &self,
+        params: ListInferencesParams<'_>,
+    ) -> Result<Vec<StoredInference>, TensorZeroError> {
+        // TODO: consider adding a flag that returns the generated sql query
+        let ClientMode::EmbeddedGateway { gateway, .. } = self.mode() else {
+            return Err(TensorZeroError::Other {
                 source: Error::new(ErrorDetails::InvalidClientMode {
                     mode: "Http".to_string(),
                     message: "This function is only available in EmbeddedGateway mode".to_string(),
@@ -1509,30 +2076,385 @@ impl ClientExt for Client {
                     }
                 })?;
                 let builder = client.http_client.post(url).json(&request);
+                let response = client.send_and_parse_http_response(builder).await?.0;
+                self.invalidate_response_cache();
+                Ok(response)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                Box::pin(with_embedded_timeout(*timeout, async {
+                    write_config(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        &gateway.handle.app_state.config,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                }))
+                .await
+            }
+        }
+    }
+
+    async fn start_canary(
+        &self,
+        config_snapshot_hash: &str,
+        request: StartCanaryRequest,
+    ) -> Result<CanaryRollout, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let endpoint = format!("internal/config/{config_snapshot_hash}/canary");
+                let url = client.base_url.join(&endpoint).map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/{{hash}}/canary endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client.http_client.post(url).json(&request);
                 Ok(client.send_and_parse_http_response(builder).await?.0)
             }
             ClientMode::EmbeddedGateway { gateway, timeout } => {
                 Box::pin(with_embedded_timeout(*timeout, async {
-                    let mut snapshot = ConfigSnapshot::new(request.config, request.extra_templates)
-                        .map_err(err_to_http)?;
-                    snapshot.tags = request.tags;
+                    start_canary(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        config_snapshot_hash,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                }))
+                .await
+            }
+        }
+    }
+
+    async fn get_canary_status(
+        &self,
+        config_snapshot_hash: &str,
+    ) -> Result<Option<CanaryRollout>, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let endpoint = format!("internal/config/{config_snapshot_hash}/canary");
+                let url = client.base_url.join(&endpoint).map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/{{hash}}/canary endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    get_canary_status(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        config_snapshot_hash,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn abort_canary(
+        &self,
+        config_snapshot_hash: &str,
+        request: AbortCanaryRequest,
+    ) -> Result<CanaryRollout, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let endpoint = format!("internal/config/{config_snapshot_hash}/canary/abort");
+                let url = client.base_url.join(&endpoint).map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/{{hash}}/canary/abort endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                Box::pin(with_embedded_timeout(*timeout, async {
+                    abort_canary(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        config_snapshot_hash,
+                        request,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                }))
+                .await
+            }
+        }
+    }
 
-                    let hash = snapshot.hash.to_string();
+    async fn list_config_snapshots(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag_filter: Option<ConfigSnapshotTagFilter>,
+    ) -> Result<ListConfigSnapshotsResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let mut url = client.base_url.join("internal/config/snapshots").map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/snapshots endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                {
+                    let mut query_pairs = url.query_pairs_mut();
+                    query_pairs
+                        .append_pair("limit", &limit.to_string())
+                        .append_pair("offset", &offset.to_string());
+                    if let Some(tag_filter) = &tag_filter {
+                        query_pairs
+                            .append_pair("tag_key", &tag_filter.key)
+                            .append_pair("tag_value", &tag_filter.value);
+                    }
+                }
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    list_config_snapshots(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        ListConfigSnapshotsRequest {
+                            limit,
+                            offset,
+                            tag_key: tag_filter.as_ref().map(|f| f.key.clone()),
+                            tag_value: tag_filter.as_ref().map(|f| f.value.clone()),
+                        },
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
 
-                    write_config_snapshot(
+    async fn update_snapshot_tags(
+        &self,
+        config_snapshot_hash: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<UpdateSnapshotTagsResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let endpoint = format!("internal/config/{config_snapshot_hash}/tags");
+                let url = client.base_url.join(&endpoint).map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/{{hash}}/tags endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client
+                    .http_client
+                    .post(url)
+                    .json(&UpdateSnapshotTagsRequest { tags });
+                let response = client.send_and_parse_http_response(builder).await?.0;
+                self.invalidate_response_cache();
+                Ok(response)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    update_snapshot_tags(
                         &gateway.handle.app_state.clickhouse_connection_info,
-                        snapshot,
+                        config_snapshot_hash,
+                        tags,
                     )
                     .await
-                    .map_err(err_to_http)?;
+                    .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
 
-                    Ok(WriteConfigResponse { hash })
+    async fn validate_config(
+        &self,
+        request: ValidateConfigRequest,
+    ) -> Result<ValidateConfigResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join("internal/config/validate").map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/config/validate endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client.http_client.post(url).json(&request);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                Box::pin(with_embedded_timeout(*timeout, async {
+                    Ok(validate_config(&gateway.handle.app_state.config, request).await)
                 }))
                 .await
             }
         }
     }
 
+    async fn get_cache_stats(&self) -> Result<CacheStats, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client.base_url.join("internal/cache/stats").map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/cache/stats endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    }
+                })?;
+                let builder = client.http_client.get(url);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    gateway
+                        .handle
+                        .app_state
+                        .clickhouse_connection_info
+                        .get_cache_stats()
+                        .await
+                        .map_err(err_to_http)
+                })
+                .await
+            }
+        }
+    }
+
+    async fn invalidate_cache(
+        &self,
+        params: InvalidateCacheParams,
+    ) -> Result<InvalidateCacheResponse, TensorZeroError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(client) => {
+                let url = client
+                    .base_url
+                    .join("internal/cache/invalidate")
+                    .map_err(|e| TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InvalidBaseUrl {
+                            message: format!(
+                                "Failed to join base URL with /internal/cache/invalidate endpoint: {e}"
+                            ),
+                        })
+                        .into(),
+                    })?;
+                let builder = client.http_client.post(url).json(&params);
+                Ok(client.send_and_parse_http_response(builder).await?.0)
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                with_embedded_timeout(*timeout, async {
+                    let invalidated_count = invalidate_cache(
+                        &gateway.handle.app_state.clickhouse_connection_info,
+                        params,
+                    )
+                    .await
+                    .map_err(err_to_http)?;
+                    Ok(InvalidateCacheResponse { invalidated_count })
+                })
+                .await
+            }
+        }
+    }
+
+    async fn feedback_or_queue(
+        &self,
+        queue: &OfflineQueue,
+        params: FeedbackParams,
+    ) -> Result<QueuedOrSent<FeedbackResponse>, TensorZeroError> {
+        match self.feedback(params.clone()).await {
+            Ok(response) => Ok(QueuedOrSent::Sent(response)),
+            Err(e) if offline_queue::is_unreachable(&e) => {
+                let queue_id = queue.enqueue(QueuedWrite::Feedback(params)).map_err(|e| {
+                    TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InternalError {
+                            message: format!("Failed to write to offline queue: {e}"),
+                        })
+                        .into(),
+                    }
+                })?;
+                Ok(QueuedOrSent::Queued { queue_id })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn create_datapoints_or_queue(
+        &self,
+        queue: &OfflineQueue,
+        dataset_name: String,
+        datapoints: Vec<CreateDatapointRequest>,
+    ) -> Result<QueuedOrSent<CreateDatapointsResponse>, TensorZeroError> {
+        match self
+            .create_datapoints(dataset_name.clone(), datapoints.clone())
+            .await
+        {
+            Ok(response) => Ok(QueuedOrSent::Sent(response)),
+            Err(e) if offline_queue::is_unreachable(&e) => {
+                let queue_id = queue
+                    .enqueue(QueuedWrite::CreateDatapoints {
+                        dataset_name,
+                        datapoints,
+                    })
+                    .map_err(|e| TensorZeroError::Other {
+                        source: Error::new(ErrorDetails::InternalError {
+                            message: format!("Failed to write to offline queue: {e}"),
+                        })
+                        .into(),
+                    })?;
+                Ok(QueuedOrSent::Queued { queue_id })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn flush_offline_queue(&self, queue: &OfflineQueue) -> std::io::Result<FlushSummary> {
+        queue
+            .flush(|write| async {
+                match write {
+                    QueuedWrite::Feedback(params) => self.feedback(params).await.map(|_| ()),
+                    QueuedWrite::CreateDatapoints {
+                        dataset_name,
+                        datapoints,
+                    } => self
+                        .create_datapoints(dataset_name, datapoints)
+                        .await
+                        .map(|_| ()),
+                }
+            })
+            .await
+    }
+
     async fn get_variant_sampling_probabilities(
         &self,
         function_name: &str,
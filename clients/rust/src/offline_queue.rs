@@ -0,0 +1,211 @@
+//! An optional, file-backed queue for feedback and datapoint writes that couldn't reach the
+//! gateway, so that a caller on an unreliable network (e.g. an edge or desktop application)
+//! doesn't lose observability data during an outage.
+//!
+//! # Scoping notes
+//!
+//! This was requested as a SQLite-backed queue, but this workspace has no SQLite dependency
+//! (`rusqlite` or `sqlx`'s `sqlite` feature) and this session has no network access to add one.
+//! A newline-delimited JSON file gives the same durability guarantee that actually matters here
+//! (queued writes survive a process restart), using dependencies (`serde_json`, `std::fs`)
+//! already available everywhere in this crate.
+//!
+//! Every queued write carries a client-generated `queue_id`, included as a
+//! `tensorzero::offline_queue_id` tag on the eventual write. The gateway does not currently
+//! deduplicate feedback or datapoint writes on this tag, so this queue provides at-least-once
+//! delivery, not exactly-once: if a write actually succeeded but the response was lost (e.g. the
+//! connection dropped after the gateway processed it), flushing will submit it again as a
+//! distinct row. The tag exists so an operator can deduplicate downstream if that matters for
+//! their use case.
+//!
+//! This module intentionally does not intercept `ClientExt::feedback` or
+//! `ClientExt::create_datapoints` transparently, since that would change those methods' existing
+//! contract (a `Result` that reflects whether the write actually happened) for every caller.
+//! Instead, [`ClientExt::feedback_or_queue`](crate::ClientExt::feedback_or_queue) and
+//! [`ClientExt::create_datapoints_or_queue`](crate::ClientExt::create_datapoints_or_queue) are
+//! new, separate, opt-in methods.
+
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{CreateDatapointRequest, FeedbackParams, TensorZeroError};
+
+/// A single tag key added to the write's `tags` map to identify a queued write. See the module
+/// docs for what this does and doesn't guarantee.
+pub const OFFLINE_QUEUE_ID_TAG: &str = "tensorzero::offline_queue_id";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum QueuedWrite {
+    Feedback(FeedbackParams),
+    CreateDatapoints {
+        dataset_name: String,
+        datapoints: Vec<CreateDatapointRequest>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QueueEntry {
+    queue_id: Uuid,
+    write: QueuedWrite,
+}
+
+/// The outcome of a single write attempted through an offline queue.
+#[derive(Debug, Clone)]
+pub enum QueuedOrSent<T> {
+    /// The write reached the gateway and succeeded.
+    Sent(T),
+    /// The gateway was unreachable; the write was persisted to the offline queue under
+    /// `queue_id` and will be retried by a future call to `ClientExt::flush_offline_queue`.
+    Queued { queue_id: Uuid },
+}
+
+/// The outcome of retrying every entry in an [`OfflineQueue`].
+#[derive(Debug, Clone, Default)]
+pub struct FlushSummary {
+    /// Number of queued writes that were successfully sent and removed from the queue.
+    pub flushed: usize,
+    /// Number of queued writes that failed again and remain in the queue.
+    pub remaining: usize,
+}
+
+/// A file-backed queue of feedback and datapoint writes that couldn't reach the gateway.
+/// See the [module docs](self) for the durability and idempotency guarantees this does and
+/// doesn't provide.
+pub struct OfflineQueue {
+    path: PathBuf,
+    // Guards read-modify-write access to the queue file; queuing/flushing are expected to be
+    // infrequent (only on network failure or reconnect), so a blocking mutex is fine here.
+    lock: Mutex<()>,
+}
+
+impl OfflineQueue {
+    /// Opens (or creates) an offline queue backed by the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            lock: Mutex::new(()),
+        })
+    }
+
+    pub(crate) fn enqueue(&self, mut write: QueuedWrite) -> std::io::Result<Uuid> {
+        let queue_id = Uuid::now_v7();
+        tag_with_queue_id(&mut write, queue_id);
+        let entry = QueueEntry { queue_id, write };
+        let line = serde_json::to_string(&entry)
+            .map_err(|e| std::io::Error::other(format!("Failed to serialize queue entry: {e}")))?;
+
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut file = OpenOptions::new().append(true).open(&self.path)?;
+        writeln!(file, "{line}")?;
+        Ok(queue_id)
+    }
+
+    fn read_all(path: &Path) -> std::io::Result<Vec<QueueEntry>> {
+        let file = OpenOptions::new().read(true).create(true).open(path)?;
+        BufReader::new(file)
+            .lines()
+            .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+            .map(|line| {
+                let line = line?;
+                serde_json::from_str(&line)
+                    .map_err(|e| std::io::Error::other(format!("Failed to parse queue entry: {e}")))
+            })
+            .collect()
+    }
+
+    fn write_all(path: &Path, entries: &[QueueEntry]) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        for entry in entries {
+            let line = serde_json::to_string(entry).map_err(|e| {
+                std::io::Error::other(format!("Failed to serialize queue entry: {e}"))
+            })?;
+            writeln!(file, "{line}")?;
+        }
+        Ok(())
+    }
+
+    /// Retries every write currently in the queue, in the order it was queued, using `send`.
+    /// Writes for which `send` returns `Ok` are removed from the queue; writes for which it
+    /// returns `Err` are left in place (in their original order) for a future flush.
+    pub(crate) async fn flush<F, Fut>(&self, mut send: F) -> std::io::Result<FlushSummary>
+    where
+        F: FnMut(QueuedWrite) -> Fut,
+        Fut: std::future::Future<Output = Result<(), TensorZeroError>>,
+    {
+        let entries = {
+            let _guard = self
+                .lock
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            Self::read_all(&self.path)?
+        };
+
+        let mut remaining = Vec::new();
+        let mut flushed = 0;
+        for entry in entries {
+            match send(entry.write.clone()).await {
+                Ok(()) => flushed += 1,
+                Err(_) => remaining.push(entry),
+            }
+        }
+
+        let summary = FlushSummary {
+            flushed,
+            remaining: remaining.len(),
+        };
+
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Self::write_all(&self.path, &remaining)?;
+        Ok(summary)
+    }
+}
+
+/// Returns `true` for a [`TensorZeroError`] that indicates the gateway itself couldn't be
+/// reached (a request timeout, or a transport-level failure that never got an HTTP response),
+/// as opposed to an error the gateway sent back deliberately (`TensorZeroError::Http`, e.g. a
+/// validation error) which should be surfaced to the caller rather than queued.
+///
+/// `TensorZeroError::Other` is also used for non-transport failures (e.g. response
+/// deserialization), so this is a heuristic, not a precise classification - the enum doesn't
+/// currently distinguish those cases from a connection failure.
+pub(crate) fn is_unreachable(error: &TensorZeroError) -> bool {
+    !matches!(error, TensorZeroError::Http { .. })
+}
+
+/// Stamps every write in `write` with `queue_id` under [`OFFLINE_QUEUE_ID_TAG`], so a caller can
+/// recognize a write that went through the offline queue after the fact.
+pub(crate) fn tag_with_queue_id(write: &mut QueuedWrite, queue_id: Uuid) {
+    let id = queue_id.to_string();
+    match write {
+        QueuedWrite::Feedback(params) => {
+            params.tags.insert(OFFLINE_QUEUE_ID_TAG.to_string(), id);
+        }
+        QueuedWrite::CreateDatapoints { datapoints, .. } => {
+            for datapoint in datapoints {
+                let tags = match datapoint {
+                    CreateDatapointRequest::Chat(d) => &mut d.tags,
+                    CreateDatapointRequest::Json(d) => &mut d.tags,
+                };
+                tags.get_or_insert_with(std::collections::HashMap::new)
+                    .insert(OFFLINE_QUEUE_ID_TAG.to_string(), id.clone());
+            }
+        }
+    }
+}
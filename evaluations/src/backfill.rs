@@ -0,0 +1,247 @@
+//! Backfill evaluator runs over already-stored inference outputs.
+//!
+//! Unlike [`crate::run_evaluation_core_streaming`] (which issues a *new* inference for each
+//! datapoint), a backfill re-scores inferences TensorZero already has on record: it looks them
+//! up with an [`InferenceFilter`], wraps each one in a synthetic [`Datapoint`] so evaluators see
+//! the same shape of data they'd see from a dataset, and evaluates it in place. This lets a
+//! newly-added evaluator be applied retroactively to production history without re-running any
+//! inference (and without paying for it again).
+//!
+//! A backfilled datapoint has no curated reference output, so evaluators that require one (e.g.
+//! `exact_match`) report `Ok(None)` for it, exactly as they would for a dataset datapoint with no
+//! `output` set. LLM judges, which score the generated output directly, are unaffected.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use futures::stream::{FuturesUnordered, StreamExt};
+use tensorzero_core::cache::CacheEnabledMode;
+use tensorzero_core::client::InferenceResponse;
+use tensorzero_core::client::input_handling::resolved_input_to_client_input;
+use tensorzero_core::config::Config;
+use tensorzero_core::db::clickhouse::query_builder::InferenceFilter;
+use tensorzero_core::db::inferences::{
+    InferenceOutputSource, InferenceQueries, ListInferencesParams,
+};
+use tensorzero_core::endpoints::datasets::{
+    ChatInferenceDatapoint, Datapoint, JsonInferenceDatapoint,
+};
+use tensorzero_core::endpoints::inference::{ChatInferenceResponse, JsonInferenceResponse};
+use tensorzero_core::evaluations::EvaluationConfig;
+use tensorzero_core::inference::types::usage::Usage;
+use tensorzero_core::stored_inference::StoredInferenceDatabase;
+use tokio::sync::Semaphore;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+use crate::Clients;
+use crate::evaluators::{EvaluateInferenceParams, EvaluationResult, evaluate_inference};
+use crate::stopping::CancellationTokens;
+use crate::types::ExecutorStorageResolver;
+
+/// Selects which already-stored inferences a backfill should re-evaluate.
+///
+/// `function_name` must match the function the evaluation's evaluators are configured for.
+/// `inference_ids` and `filters` are optional narrowing criteria on top of it; when both are
+/// omitted, every stored inference for the function (up to `limit`) is backfilled.
+pub struct BackfillFilter<'a> {
+    pub function_name: String,
+    pub variant_name: Option<String>,
+    pub inference_ids: Option<Vec<Uuid>>,
+    pub filters: Option<&'a InferenceFilter>,
+    pub limit: u32,
+}
+
+pub struct BackfillEvaluationParams<'a> {
+    pub clients: Arc<Clients>,
+    pub config: Arc<Config>,
+    pub evaluation_config: Arc<EvaluationConfig>,
+    pub evaluation_name: Arc<String>,
+    pub evaluation_run_id: Uuid,
+    pub filter: BackfillFilter<'a>,
+    pub concurrency: usize,
+    pub external_tags: Arc<HashMap<String, String>>,
+}
+
+/// Result of backfilling a single historical inference.
+pub struct BackfillItemResult {
+    pub inference_id: Uuid,
+    pub evaluation_result: EvaluationResult,
+}
+
+/// Runs the evaluation's evaluators over already-stored inference outputs matching
+/// `params.filter`, writing scores as feedback exactly as a live evaluation run would. No new
+/// inference is made; the historical output is scored as-is.
+#[instrument(skip_all, fields(evaluation_name = %params.evaluation_name, function_name = %params.filter.function_name))]
+pub async fn backfill_evaluation(
+    params: BackfillEvaluationParams<'_>,
+) -> Result<Vec<BackfillItemResult>> {
+    let EvaluationConfig::Inference(inference_evaluation_config) = &*params.evaluation_config;
+    if inference_evaluation_config.function_name != params.filter.function_name {
+        return Err(anyhow!(
+            "Evaluation `{}` is configured for function `{}`, but the backfill filter targets function `{}`",
+            params.evaluation_name,
+            inference_evaluation_config.function_name,
+            params.filter.function_name,
+        ));
+    }
+
+    let list_params = ListInferencesParams {
+        function_name: Some(&params.filter.function_name),
+        ids: params.filter.inference_ids.as_deref(),
+        variant_name: params.filter.variant_name.as_deref(),
+        episode_id: None,
+        filters: params.filter.filters,
+        output_source: InferenceOutputSource::Inference,
+        limit: params.filter.limit,
+        offset: 0,
+        pagination: None,
+        order_by: None,
+        search_query_experimental: None,
+    };
+    let stored_inferences = params
+        .clients
+        .clickhouse_client
+        .list_inferences(&params.config, &list_params)
+        .await?;
+
+    info!(
+        count = stored_inferences.len(),
+        "Backfilling evaluator run over historical inferences"
+    );
+
+    let resolver = ExecutorStorageResolver(params.clients.inference_executor.clone());
+    let semaphore = Arc::new(Semaphore::new(params.concurrency));
+    let no_cancellation = CancellationTokens::default();
+
+    let mut futures = FuturesUnordered::new();
+    for stored in stored_inferences {
+        let semaphore = semaphore.clone();
+        let resolver = &resolver;
+        let clients = params.clients.clone();
+        let evaluation_config = params.evaluation_config.clone();
+        let evaluation_name = params.evaluation_name.clone();
+        let evaluation_run_id = params.evaluation_run_id;
+        let external_tags = params.external_tags.clone();
+        let no_cancellation = &no_cancellation;
+        futures.push(async move {
+            let _permit = semaphore.acquire().await?;
+            let inference_id = stored.id();
+            let (datapoint, inference_response) =
+                build_synthetic_datapoint(stored, resolver).await?;
+            let input = Arc::new(datapoint.input().clone());
+            let evaluation_result = evaluate_inference(
+                EvaluateInferenceParams {
+                    inference_response: Arc::new(inference_response),
+                    input,
+                    datapoint: Arc::new(datapoint),
+                    evaluation_config,
+                    evaluation_name,
+                    clients,
+                    evaluation_run_id,
+                    // The historical inference is being re-scored, not re-run, so there is
+                    // nothing for the inference cache to serve here; only an LLM judge (which
+                    // does make a new inference) would consult it.
+                    inference_cache: CacheEnabledMode::On,
+                    external_tags,
+                    send_feedback: true,
+                },
+                no_cancellation,
+            )
+            .await
+            .map_err(|e| anyhow!("Error evaluating backfilled inference {inference_id}: {e}"))?;
+            Ok::<_, anyhow::Error>(BackfillItemResult {
+                inference_id,
+                evaluation_result,
+            })
+        });
+    }
+
+    let mut results = Vec::with_capacity(futures.len());
+    while let Some(result) = futures.next().await {
+        match result {
+            Ok(item) => results.push(item),
+            Err(e) => warn!("Backfill task failed: {e}"),
+        }
+    }
+    Ok(results)
+}
+
+/// Builds the synthetic [`Datapoint`] and [`InferenceResponse`] evaluators need out of a
+/// [`StoredInferenceDatabase`] row. The datapoint has no reference `output`: it exists only to
+/// carry the (resolved) input and identifying metadata into the evaluator, not as a curated
+/// dataset entry.
+async fn build_synthetic_datapoint(
+    stored: StoredInferenceDatabase,
+    resolver: &ExecutorStorageResolver,
+) -> Result<(Datapoint, InferenceResponse)> {
+    Ok(match stored {
+        StoredInferenceDatabase::Chat(chat) => {
+            let resolved_input = chat.input.reresolve(resolver).await?;
+            let input = resolved_input_to_client_input(resolved_input)?;
+            let datapoint = Datapoint::Chat(ChatInferenceDatapoint {
+                dataset_name: "tensorzero::backfill".to_string(),
+                function_name: chat.function_name,
+                id: Uuid::now_v7(),
+                episode_id: Some(chat.episode_id),
+                input,
+                output: None,
+                tool_params: chat.tool_params.into(),
+                tags: Some(chat.tags),
+                auxiliary: String::new(),
+                is_deleted: false,
+                is_custom: false,
+                source_inference_id: Some(chat.inference_id),
+                staled_at: None,
+                updated_at: String::new(),
+                name: None,
+            });
+            let inference_response = InferenceResponse::Chat(ChatInferenceResponse {
+                inference_id: chat.inference_id,
+                episode_id: chat.episode_id,
+                variant_name: chat.variant_name,
+                content: chat.output,
+                usage: Usage::zero(),
+                raw_usage: None,
+                original_response: None,
+                raw_response: None,
+                finish_reason: None,
+            });
+            (datapoint, inference_response)
+        }
+        StoredInferenceDatabase::Json(json) => {
+            let resolved_input = json.input.reresolve(resolver).await?;
+            let input = resolved_input_to_client_input(resolved_input)?;
+            let datapoint = Datapoint::Json(JsonInferenceDatapoint {
+                dataset_name: "tensorzero::backfill".to_string(),
+                function_name: json.function_name,
+                id: Uuid::now_v7(),
+                episode_id: Some(json.episode_id),
+                input,
+                output: None,
+                output_schema: json.output_schema,
+                tags: Some(json.tags),
+                auxiliary: String::new(),
+                is_deleted: false,
+                is_custom: false,
+                source_inference_id: Some(json.inference_id),
+                staled_at: None,
+                updated_at: String::new(),
+                name: None,
+            });
+            let inference_response = InferenceResponse::Json(JsonInferenceResponse {
+                inference_id: json.inference_id,
+                episode_id: json.episode_id,
+                variant_name: json.variant_name,
+                output: json.output,
+                usage: Usage::zero(),
+                raw_usage: None,
+                original_response: None,
+                raw_response: None,
+                finish_reason: None,
+            });
+            (datapoint, inference_response)
+        }
+    })
+}
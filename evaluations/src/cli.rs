@@ -72,6 +72,11 @@ pub struct Args {
     /// halves of the CI in the case of asymmetric CIs) <= precision_target.
     #[arg(long = "adaptive-stopping-precision", value_parser = parse_precision_target, value_delimiter = ',', num_args = 0..)]
     pub precision_targets: Vec<(String, f32)>,
+
+    /// Maximum number of retries for an inference call that fails with a transient error
+    /// (a request timeout or a provider 5xx). `0` (the default) disables retries.
+    #[arg(long, default_value = "0")]
+    pub max_inference_retries: usize,
 }
 
 /// Parse a single precision target in format "evaluator_name=precision_target"
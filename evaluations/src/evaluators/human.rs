@@ -0,0 +1,114 @@
+//! The `human` evaluator kind. Instead of calling a model, it enqueues a
+//! pending review task in the review queue (see `db::review_queue`) for a
+//! reviewer to label through the review queue's gateway endpoints, then waits
+//! for that label to show up in the `StaticEvaluationHumanFeedback` cache -
+//! the same cache `llm_judge` checks to short-circuit on a human override
+//! (see `evaluators::llm_judge::run_llm_judge_evaluator`).
+//!
+//! There is no durable task queue backing evaluations (see
+//! `durable-tools::evaluation_jobs`), so "suspend until a human label arrives
+//! or a timeout elapses" is implemented here as a plain bounded poll loop
+//! rather than durable suspend/resume: a worker restart while polling loses
+//! the wait (the caller has to re-run the evaluator), though the queued
+//! review task itself survives, since it lives in Postgres rather than
+//! in-process state.
+//!
+//! For a submitted label to be picked up here, the reviewer's submission
+//! (through `submit_review_label_handler`) must include the
+//! `tensorzero::datapoint_id`, `tensorzero::evaluator_inference_id`, and
+//! `tensorzero::human_feedback` tags - the same tag contract the generic
+//! `/feedback` endpoint already uses to populate `StaticEvaluationHumanFeedback`
+//! (see `endpoints::feedback::human_feedback`).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::Value;
+use tensorzero_core::client::InferenceResponse;
+use tensorzero_core::db::evaluation_queries::EvaluationQueries;
+use tensorzero_core::db::review_queue::ReviewQueueQueries;
+use tensorzero_core::endpoints::datasets::Datapoint;
+use tensorzero_core::evaluations::{HumanEvaluatorConfig, get_evaluator_metric_name};
+use tracing::{debug, info};
+
+use crate::Clients;
+
+/// How often to re-check for a submitted human label while waiting.
+const HUMAN_LABEL_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub struct HumanEvaluationResult {
+    pub value: Value,
+}
+
+impl HumanEvaluationResult {
+    pub fn tags(&self) -> HashMap<String, String> {
+        HashMap::from([(
+            "tensorzero::derived_from_human_feedback".to_string(),
+            "true".to_string(),
+        )])
+    }
+}
+
+pub struct RunHumanEvaluatorParams<'a> {
+    pub inference_response: &'a InferenceResponse,
+    pub datapoint: &'a Datapoint,
+    pub clients: &'a Clients,
+    pub human_config: &'a HumanEvaluatorConfig,
+    pub evaluation_name: &'a str,
+    pub evaluator_name: &'a str,
+}
+
+pub async fn run_human_evaluator(
+    params: RunHumanEvaluatorParams<'_>,
+) -> Result<Option<HumanEvaluationResult>> {
+    let RunHumanEvaluatorParams {
+        inference_response,
+        datapoint,
+        clients,
+        human_config,
+        evaluation_name,
+        evaluator_name,
+    } = params;
+    let metric_name = get_evaluator_metric_name(evaluation_name, evaluator_name);
+    let serialized_output = inference_response.get_serialized_output()?;
+
+    debug!("Checking for existing human feedback");
+    if let Some(existing) = clients
+        .clickhouse_client
+        .get_inference_evaluation_human_feedback(&metric_name, &datapoint.id(), &serialized_output)
+        .await?
+    {
+        info!("Found existing human feedback, skipping the review queue");
+        return Ok(Some(HumanEvaluationResult {
+            value: existing.value,
+        }));
+    }
+
+    debug!("No existing human feedback found, enqueuing a review task");
+    clients
+        .postgres_connection_info
+        .create_review_tasks(&[inference_response.inference_id()], &metric_name)
+        .await?;
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(human_config.timeout_s);
+    while tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(HUMAN_LABEL_POLL_INTERVAL).await;
+        if let Some(label) = clients
+            .clickhouse_client
+            .get_inference_evaluation_human_feedback(
+                &metric_name,
+                &datapoint.id(),
+                &serialized_output,
+            )
+            .await?
+        {
+            info!("Human label arrived while polling");
+            return Ok(Some(HumanEvaluationResult { value: label.value }));
+        }
+    }
+
+    info!("Timed out waiting for a human label");
+    Ok(None)
+}
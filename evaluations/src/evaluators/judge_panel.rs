@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use tensorzero_core::cache::CacheEnabledMode;
+use tensorzero_core::client::{InferenceResponse, Input};
+use tensorzero_core::endpoints::datasets::Datapoint;
+use tensorzero_core::evaluations::{JudgePanelAggregation, JudgePanelConfig, LLMJudgeOutputType};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use crate::Clients;
+use crate::evaluators::llm_judge::{
+    LLMJudgeEvaluationResult, RunLLMJudgeEvaluatorParams, run_llm_judge_evaluator,
+};
+use futures::stream::{FuturesUnordered, TryStreamExt};
+
+/// The result of running every judge in a [`JudgePanelConfig`] and aggregating
+/// their scores. Per-judge results are retained so callers can inspect the
+/// individual scores behind the aggregate; only the aggregate `value` is sent
+/// as feedback by the standard evaluation flow.
+#[derive(Debug)]
+pub struct JudgePanelEvaluationResult {
+    pub evaluator_inference_id: Uuid,
+    pub value: Value,
+    pub disagreement: f64,
+    pub judges: HashMap<String, LLMJudgeEvaluationResult>,
+}
+
+impl JudgePanelEvaluationResult {
+    pub fn tags(&self) -> HashMap<String, String> {
+        let mut tags = HashMap::from([(
+            "tensorzero::judge_panel_disagreement".to_string(),
+            self.disagreement.to_string(),
+        )]);
+        if self.judges.values().all(|judge| judge.human_feedback) {
+            tags.insert(
+                "tensorzero::derived_from_human_feedback".to_string(),
+                "true".to_string(),
+            );
+        }
+        tags
+    }
+}
+
+pub struct RunJudgePanelEvaluatorParams<'a> {
+    pub inference_response: &'a InferenceResponse,
+    pub datapoint: &'a Datapoint,
+    pub clients: &'a Clients,
+    pub judge_panel_config: &'a JudgePanelConfig,
+    pub evaluation_name: &'a str,
+    pub evaluator_name: &'a str,
+    pub evaluation_run_id: Uuid,
+    pub input: &'a Input,
+    pub inference_cache: CacheEnabledMode,
+    pub external_tags: &'a HashMap<String, String>,
+}
+
+/// Runs every judge in the panel concurrently and aggregates their scores.
+///
+/// Each judge is run as a standalone LLM judge evaluator, namespaced as
+/// `{evaluator_name}::{judge_name}` (matching the function/metric naming
+/// convention used when the panel is loaded). If any judge is unable to
+/// produce a score (e.g. a reference output is required but missing), the
+/// whole panel returns `None`, since a partial panel cannot be aggregated.
+#[instrument(skip_all, fields(datapoint_id = %params.datapoint.id(), evaluator_name = %params.evaluator_name))]
+pub async fn run_judge_panel_evaluator(
+    params: RunJudgePanelEvaluatorParams<'_>,
+) -> Result<Option<JudgePanelEvaluationResult>> {
+    let RunJudgePanelEvaluatorParams {
+        inference_response,
+        datapoint,
+        clients,
+        judge_panel_config,
+        evaluation_name,
+        evaluator_name,
+        evaluation_run_id,
+        input,
+        inference_cache,
+        external_tags,
+    } = params;
+
+    let judge_results: Vec<(String, Option<LLMJudgeEvaluationResult>)> =
+        FuturesUnordered::from_iter(judge_panel_config.judges.iter().map(
+            |(judge_name, llm_judge_config)| async move {
+                let panel_judge_name = format!("{evaluator_name}::{judge_name}");
+                let result = run_llm_judge_evaluator(RunLLMJudgeEvaluatorParams {
+                    inference_response,
+                    datapoint,
+                    clients,
+                    llm_judge_config,
+                    evaluation_name,
+                    evaluator_name: &panel_judge_name,
+                    evaluation_run_id,
+                    input,
+                    inference_cache,
+                    external_tags,
+                })
+                .await?;
+                Ok::<_, anyhow::Error>((judge_name.clone(), result))
+            },
+        ))
+        .try_collect()
+        .await?;
+
+    let mut judges = HashMap::with_capacity(judge_results.len());
+    for (judge_name, result) in judge_results {
+        match result {
+            Some(result) => {
+                judges.insert(judge_name, result);
+            }
+            None => {
+                debug!(
+                    judge_name = %judge_name,
+                    "Judge produced no value, skipping the whole panel"
+                );
+                return Ok(None);
+            }
+        }
+    }
+
+    let scores = judges
+        .values()
+        .map(|judge| value_to_score(&judge.value))
+        .collect::<Result<Vec<f64>>>()?;
+
+    let (aggregate_score, disagreement) = aggregate_scores(judge_panel_config.aggregation, &scores);
+    let value = score_to_value(judge_panel_config.output_type, aggregate_score);
+
+    // We don't have a single inference to point to for the panel as a whole, so
+    // we arbitrarily surface the first judge's inference id; the full set of
+    // per-judge inference ids is available via `judges`.
+    let evaluator_inference_id = judges
+        .values()
+        .next()
+        .map(|judge| judge.evaluator_inference_id)
+        .ok_or_else(|| anyhow!("Judge panel has no judges. This should never happen."))?;
+
+    Ok(Some(JudgePanelEvaluationResult {
+        evaluator_inference_id,
+        value,
+        disagreement,
+        judges,
+    }))
+}
+
+fn value_to_score(value: &Value) -> Result<f64> {
+    match value {
+        Value::Number(number) => number
+            .as_f64()
+            .ok_or_else(|| anyhow!("Judge score is not a valid number: {number}")),
+        Value::Bool(value) => Ok(if *value { 1.0 } else { 0.0 }),
+        other => Err(anyhow!("Judge score is not a number or boolean: {other}")),
+    }
+}
+
+fn score_to_value(output_type: LLMJudgeOutputType, score: f64) -> Value {
+    match output_type {
+        LLMJudgeOutputType::Float => Value::from(score),
+        LLMJudgeOutputType::Boolean => Value::from(score >= 0.5),
+    }
+}
+
+/// Aggregates a panel's judge scores according to the configured strategy,
+/// returning `(aggregate_score, disagreement)`.
+///
+/// Disagreement is the population standard deviation of the scores for `Mean`
+/// and `Median`, and the fraction of judges that disagreed with the winning
+/// value for `Majority`.
+fn aggregate_scores(aggregation: JudgePanelAggregation, scores: &[f64]) -> (f64, f64) {
+    #[expect(clippy::cast_precision_loss)]
+    let n = scores.len() as f64;
+    match aggregation {
+        JudgePanelAggregation::Mean => {
+            let mean = scores.iter().sum::<f64>() / n;
+            (mean, standard_deviation(scores, mean))
+        }
+        JudgePanelAggregation::Median => {
+            let mut sorted = scores.to_vec();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            let mid = sorted.len() / 2;
+            let median = if sorted.len() % 2 == 0 {
+                (sorted[mid - 1] + sorted[mid]) / 2.0
+            } else {
+                sorted[mid]
+            };
+            (
+                median,
+                standard_deviation(scores, scores.iter().sum::<f64>() / n),
+            )
+        }
+        JudgePanelAggregation::Majority => {
+            let votes_for_true = scores.iter().filter(|&&score| score >= 0.5).count();
+            let majority_value = if votes_for_true * 2 > scores.len() {
+                1.0
+            } else {
+                0.0
+            };
+            let dissenting = scores
+                .iter()
+                .filter(|&&score| {
+                    let voted_true = if score >= 0.5 { 1.0 } else { 0.0 };
+                    voted_true != majority_value
+                })
+                .count();
+            #[expect(clippy::cast_precision_loss)]
+            let disagreement = dissenting as f64 / n;
+            (majority_value, disagreement)
+        }
+    }
+}
+
+fn standard_deviation(scores: &[f64], mean: f64) -> f64 {
+    #[expect(clippy::cast_precision_loss)]
+    let n = scores.len() as f64;
+    let variance = scores
+        .iter()
+        .map(|score| (score - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    variance.sqrt()
+}
@@ -0,0 +1,88 @@
+use anyhow::{Result, bail};
+use serde_json::Value;
+use tensorzero_core::client::InferenceResponse;
+use tensorzero_core::evaluations::LexicalDiversityConfig;
+use tensorzero_core::inference::types::ContentBlockChatOutput;
+use tracing::{debug, instrument, warn};
+
+/// Computes a judge-free "distinct-n" lexical diversity score for the response text: the
+/// fraction of `n`-grams (with `n = config.ngram_size`) that are unique. A response that
+/// degenerates into repeated phrases or loops scores close to `0.0`; a lexically varied
+/// response scores close to `1.0`. Unlike `LLMJudge`/`JudgePanel`, this requires no reference
+/// output and no additional model call, so it runs cheaply against every response.
+#[instrument(skip_all)]
+pub(super) fn run_lexical_diversity_evaluator(
+    inference_response: &InferenceResponse,
+    config: &LexicalDiversityConfig,
+) -> Result<Option<Value>> {
+    let text = match inference_response {
+        InferenceResponse::Chat(response) => extract_chat_text(&response.content),
+        InferenceResponse::Json(response) => response.output.raw.clone().unwrap_or_default(),
+    };
+    if config.ngram_size == 0 {
+        warn!("Lexical diversity evaluator configured with ngram_size = 0");
+        bail!("`ngram_size` must be at least 1");
+    }
+    let score = distinct_n(&text, config.ngram_size);
+    debug!(score = ?score, "Lexical diversity evaluator completed");
+    Ok(score.map(Value::from))
+}
+
+fn extract_chat_text(blocks: &[ContentBlockChatOutput]) -> String {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlockChatOutput::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns `unique n-grams / total n-grams` over whitespace-tokenized `text`, or `None` if
+/// `text` is too short to contain a single `n`-gram (e.g. an empty or tool-call-only response).
+fn distinct_n(text: &str, n: usize) -> Option<f64> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    if tokens.len() < n {
+        return None;
+    }
+    let ngrams: Vec<&[&str]> = tokens.windows(n).collect();
+    let total = ngrams.len();
+    let unique = ngrams
+        .iter()
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    #[expect(clippy::cast_precision_loss)]
+    Some(unique as f64 / total as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_n_fully_diverse() {
+        let score = distinct_n("the quick brown fox jumps over the lazy dog", 2)
+            .expect("text is long enough to have bigrams");
+        assert_eq!(score, 1.0, "no bigram repeats, so distinct-2 should be 1.0");
+    }
+
+    #[test]
+    fn test_distinct_n_degenerate_repetition() {
+        let score =
+            distinct_n("the the the the the the", 1).expect("text is long enough to have unigrams");
+        assert!(
+            score < 0.5,
+            "a response that repeats a single token should score low on lexical diversity, got {score}"
+        );
+    }
+
+    #[test]
+    fn test_distinct_n_too_short() {
+        assert_eq!(
+            distinct_n("hi", 3),
+            None,
+            "text shorter than the n-gram size has no n-grams to score"
+        );
+    }
+}
@@ -28,6 +28,9 @@ pub struct LLMJudgeEvaluationResult {
     pub evaluator_inference_id: Uuid,
     pub value: Value,
     pub human_feedback: bool,
+    /// Per-criterion scores for a rubric-based judge (see `LLMJudgeConfig::criteria`).
+    /// `None` for a plain, single-`score` judge or when the result came from human feedback.
+    pub criteria: Option<HashMap<String, f64>>,
 }
 
 impl LLMJudgeEvaluationResult {
@@ -41,6 +44,21 @@ impl LLMJudgeEvaluationResult {
             HashMap::new()
         }
     }
+
+    /// The value we surface to the evaluations crate's own stats aggregation (jsonl output,
+    /// `compute_stats()`). For a rubric judge this embeds the per-criterion breakdown alongside
+    /// the overall score; for a plain judge it's just the scalar `value`. This is deliberately
+    /// separate from `value`, which is the scalar sent to the feedback/metric system and must
+    /// stay a plain number or boolean.
+    pub fn stats_value(&self) -> Value {
+        match &self.criteria {
+            Some(criteria) => json!({
+                "score": self.value,
+                "criteria": criteria,
+            }),
+            None => self.value.clone(),
+        }
+    }
 }
 
 pub struct RunLLMJudgeEvaluatorParams<'a> {
@@ -88,6 +106,7 @@ pub async fn run_llm_judge_evaluator(
             evaluator_inference_id: human_feedback.evaluator_inference_id,
             value: human_feedback.value,
             human_feedback: true,
+            criteria: None,
         }));
     }
     debug!("Preparing LLM judge input");
@@ -137,6 +156,7 @@ pub async fn run_llm_judge_evaluator(
         extra_body: Default::default(),
         extra_headers: Default::default(),
         internal_dynamic_variant_config: None,
+        timeout_ms: None,
         otlp_traces_extra_headers: HashMap::new(),
         otlp_traces_extra_attributes: HashMap::new(),
         otlp_traces_extra_resources: HashMap::new(),
@@ -163,22 +183,35 @@ pub async fn run_llm_judge_evaluator(
             .parsed
             .ok_or_else(|| anyhow::anyhow!("JSON output does not contain a `parsed` field"))?,
     };
-    let value = match llm_judge_config.output_type {
-        LLMJudgeOutputType::Float | LLMJudgeOutputType::Boolean => Some(
-            output
-                .get("score")
-                .ok_or_else(|| anyhow::anyhow!("JSON output does not contain a `score` field"))?
-                .clone(),
-        ),
+    let (value, criteria) = match &llm_judge_config.criteria {
+        Some(criteria_config) => {
+            let mut criteria = HashMap::with_capacity(criteria_config.len());
+            for name in criteria_config.keys() {
+                let score = output.get(name).and_then(Value::as_f64).ok_or_else(|| {
+                    anyhow::anyhow!("JSON output does not contain a numeric `{name}` field")
+                })?;
+                criteria.insert(name.clone(), score);
+            }
+            #[expect(clippy::cast_precision_loss)]
+            let mean = criteria.values().sum::<f64>() / criteria.len() as f64;
+            (json!(mean), Some(criteria))
+        }
+        None => match llm_judge_config.output_type {
+            LLMJudgeOutputType::Float | LLMJudgeOutputType::Boolean => (
+                output
+                    .get("score")
+                    .ok_or_else(|| anyhow::anyhow!("JSON output does not contain a `score` field"))?
+                    .clone(),
+                None,
+            ),
+        },
     };
-    match value {
-        Some(value) => Ok(Some(LLMJudgeEvaluationResult {
-            evaluator_inference_id,
-            value,
-            human_feedback: false,
-        })),
-        None => Ok(None),
-    }
+    Ok(Some(LLMJudgeEvaluationResult {
+        evaluator_inference_id,
+        value,
+        human_feedback: false,
+        criteria,
+    }))
 }
 
 /// We prepare the input for the LLM judge evaluator.
@@ -568,6 +601,7 @@ mod tests {
             optimize: LLMJudgeOptimize::Max,
             include: LLMJudgeIncludeConfig::default(),
             description: None,
+            criteria: None,
         };
         let input = Input {
             system: Some(System::Text("You are a helpful assistant".to_string())),
@@ -649,6 +683,7 @@ mod tests {
                 reference_output: true,
             },
             description: None,
+            criteria: None,
         };
         let input = prepare_llm_judge_input(
             &llm_judge_config,
@@ -863,6 +898,7 @@ mod tests {
                 reference_output: false,
             },
             description: None,
+            criteria: None,
         };
         let datapoint = Datapoint::Chat(ChatInferenceDatapoint {
             dataset_name: "dataset".to_string(),
@@ -897,6 +933,7 @@ mod tests {
                 reference_output: true,
             },
             description: None,
+            criteria: None,
         };
         let datapoint = Datapoint::Chat(ChatInferenceDatapoint {
             dataset_name: "dataset".to_string(),
@@ -994,6 +1031,7 @@ mod tests {
                 reference_output: true,
             },
             description: None,
+            criteria: None,
         };
         let result = prepare_final_message_messages_input(&config, "Generated", None);
         assert_eq!(result, None);
@@ -1017,6 +1055,7 @@ mod tests {
                 reference_output: false,
             },
             description: None,
+            criteria: None,
         };
         let message = prepare_final_message_messages_input(&config, "Generated", None).unwrap();
         let expected = format!(
@@ -1038,6 +1077,7 @@ mod tests {
                 reference_output: false,
             },
             description: None,
+            criteria: None,
         };
         let input = Input {
             system: Some(System::Text("System instruction".to_string())),
@@ -1150,6 +1190,7 @@ mod tests {
             optimize: LLMJudgeOptimize::Max,
             include: LLMJudgeIncludeConfig::default(),
             description: None,
+            criteria: None,
         };
         let input = Input {
             system: None,
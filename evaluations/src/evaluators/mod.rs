@@ -11,9 +11,19 @@ use tensorzero_core::evaluations::{EvaluationConfig, EvaluatorConfig, get_evalua
 
 mod exact_match;
 use exact_match::run_exact_match_evaluator;
+mod human;
+pub mod judge_panel;
+mod lexical_diversity;
 pub mod llm_judge;
+mod tool_call_correctness;
 use futures::stream::{FuturesUnordered, StreamExt};
+use human::{HumanEvaluationResult, RunHumanEvaluatorParams, run_human_evaluator};
+use judge_panel::{
+    JudgePanelEvaluationResult, RunJudgePanelEvaluatorParams, run_judge_panel_evaluator,
+};
+use lexical_diversity::run_lexical_diversity_evaluator;
 use llm_judge::{LLMJudgeEvaluationResult, RunLLMJudgeEvaluatorParams, run_llm_judge_evaluator};
+use tool_call_correctness::run_tool_call_correctness_evaluator;
 use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
@@ -277,6 +287,55 @@ async fn run_evaluator(params: RunEvaluatorParams<'_>) -> Result<EvaluatorResult
             debug!(result = ?result, "LLM judge evaluator completed");
             EvaluatorResult::LLMJudge(result)
         }
+        EvaluatorConfig::JudgePanel(judge_panel_config) => {
+            debug!("Running judge panel evaluator");
+            let result = run_judge_panel_evaluator(RunJudgePanelEvaluatorParams {
+                inference_response,
+                datapoint,
+                clients,
+                judge_panel_config,
+                evaluation_name,
+                evaluator_name: &evaluator_name,
+                evaluation_run_id,
+                input,
+                inference_cache,
+                external_tags: external_tags.as_ref(),
+            })
+            .await?;
+            debug!(result = ?result, "Judge panel evaluator completed");
+            EvaluatorResult::JudgePanel(result)
+        }
+        EvaluatorConfig::Human(human_config) => {
+            debug!("Running human evaluator");
+            let result = run_human_evaluator(RunHumanEvaluatorParams {
+                inference_response,
+                datapoint,
+                clients,
+                human_config,
+                evaluation_name,
+                evaluator_name: &evaluator_name,
+            })
+            .await?;
+            debug!(result = ?result, "Human evaluator completed");
+            EvaluatorResult::Human(result)
+        }
+        EvaluatorConfig::ToolCallCorrectness(tool_call_correctness_config) => {
+            debug!("Running tool call correctness evaluator");
+            let result = run_tool_call_correctness_evaluator(
+                inference_response,
+                datapoint,
+                tool_call_correctness_config,
+            )?;
+            debug!(result = ?result, "Tool call correctness evaluator completed");
+            EvaluatorResult::ToolCallCorrectness(result)
+        }
+        EvaluatorConfig::LexicalDiversity(lexical_diversity_config) => {
+            debug!("Running lexical diversity evaluator");
+            let result =
+                run_lexical_diversity_evaluator(inference_response, lexical_diversity_config)?;
+            debug!(result = ?result, "Lexical diversity evaluator completed");
+            EvaluatorResult::LexicalDiversity(result)
+        }
     })
 }
 
@@ -284,6 +343,10 @@ async fn run_evaluator(params: RunEvaluatorParams<'_>) -> Result<EvaluatorResult
 pub enum EvaluatorResult {
     ExactMatch(Option<Value>),
     LLMJudge(Option<LLMJudgeEvaluationResult>),
+    JudgePanel(Option<JudgePanelEvaluationResult>),
+    Human(Option<HumanEvaluationResult>),
+    ToolCallCorrectness(Option<Value>),
+    LexicalDiversity(Option<Value>),
 }
 
 impl<'a> EvaluatorResult {
@@ -291,6 +354,10 @@ impl<'a> EvaluatorResult {
         match self {
             EvaluatorResult::ExactMatch(value) => value.as_ref(),
             EvaluatorResult::LLMJudge(value) => value.as_ref().map(|v| &v.value),
+            EvaluatorResult::JudgePanel(value) => value.as_ref().map(|v| &v.value),
+            EvaluatorResult::Human(value) => value.as_ref().map(|v| &v.value),
+            EvaluatorResult::ToolCallCorrectness(value) => value.as_ref(),
+            EvaluatorResult::LexicalDiversity(value) => value.as_ref(),
         }
     }
 
@@ -298,12 +365,22 @@ impl<'a> EvaluatorResult {
         match self {
             EvaluatorResult::ExactMatch(_) => None,
             EvaluatorResult::LLMJudge(value) => value.as_ref().map(|v| &v.evaluator_inference_id),
+            EvaluatorResult::JudgePanel(value) => value.as_ref().map(|v| &v.evaluator_inference_id),
+            // There's no separate "evaluator inference" for a human label - the thing
+            // being reviewed is the inference under evaluation itself.
+            EvaluatorResult::Human(_) => None,
+            EvaluatorResult::ToolCallCorrectness(_) => None,
+            EvaluatorResult::LexicalDiversity(_) => None,
         }
     }
     pub fn value_owned(self) -> Option<Value> {
         match self {
             EvaluatorResult::ExactMatch(value) => value,
-            EvaluatorResult::LLMJudge(value) => value.map(|v| v.value),
+            EvaluatorResult::LLMJudge(value) => value.map(|v| v.stats_value()),
+            EvaluatorResult::JudgePanel(value) => value.map(|v| v.value),
+            EvaluatorResult::Human(value) => value.map(|v| v.value),
+            EvaluatorResult::ToolCallCorrectness(value) => value,
+            EvaluatorResult::LexicalDiversity(value) => value,
         }
     }
     pub fn tags(&'a self) -> HashMap<String, String> {
@@ -313,6 +390,16 @@ impl<'a> EvaluatorResult {
                 .as_ref()
                 .map(LLMJudgeEvaluationResult::tags)
                 .unwrap_or_default(),
+            EvaluatorResult::JudgePanel(value) => value
+                .as_ref()
+                .map(JudgePanelEvaluationResult::tags)
+                .unwrap_or_default(),
+            EvaluatorResult::Human(value) => value
+                .as_ref()
+                .map(HumanEvaluationResult::tags)
+                .unwrap_or_default(),
+            EvaluatorResult::ToolCallCorrectness(_) => HashMap::new(),
+            EvaluatorResult::LexicalDiversity(_) => HashMap::new(),
         }
     }
 }
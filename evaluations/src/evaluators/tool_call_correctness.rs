@@ -0,0 +1,364 @@
+use anyhow::{Result, bail};
+use serde_json::Value;
+use tensorzero_core::client::InferenceResponse;
+use tensorzero_core::endpoints::datasets::Datapoint;
+use tensorzero_core::evaluations::ToolCallCorrectnessConfig;
+use tensorzero_core::inference::types::{ContentBlockChatOutput, InferenceResponseToolCall};
+use tracing::{debug, instrument, warn};
+
+/// Compares the tool calls in a chat response against the tool calls declared as the reference
+/// output on the datapoint, matching on tool name and an argument subset (with tolerances), and
+/// returns an F1 score combining precision and recall over the matched calls.
+#[instrument(skip_all, fields(datapoint_id = %datapoint.id()))]
+pub(super) fn run_tool_call_correctness_evaluator(
+    inference_response: &InferenceResponse,
+    datapoint: &Datapoint,
+    config: &ToolCallCorrectnessConfig,
+) -> Result<Option<Value>> {
+    match (inference_response, datapoint) {
+        (InferenceResponse::Chat(response), Datapoint::Chat(datapoint)) => {
+            let Some(output) = &datapoint.output else {
+                debug!("No reference output available for tool call comparison");
+                return Ok(None);
+            };
+            let expected_calls = extract_tool_calls(output);
+            if expected_calls.is_empty() {
+                debug!("Reference output declares no expected tool calls");
+                return Ok(None);
+            }
+            let actual_calls = extract_tool_calls(&response.content);
+            let score = score_tool_calls(&expected_calls, &actual_calls, config);
+            debug!(score = %score, "Tool call correctness comparison completed");
+            Ok(Some(Value::from(score)))
+        }
+        (InferenceResponse::Json(_), Datapoint::Json(_)) => {
+            warn!("Tool call correctness evaluator does not apply to JSON functions");
+            bail!("Tool call correctness evaluator does not apply to JSON functions")
+        }
+        _ => {
+            let datapoint_type = match datapoint {
+                Datapoint::Chat(_) => "Chat",
+                Datapoint::Json(_) => "Json",
+            };
+            let response_type = match inference_response {
+                InferenceResponse::Chat(_) => "Chat",
+                InferenceResponse::Json(_) => "Json",
+            };
+            warn!(
+                datapoint_type = %datapoint_type,
+                response_type = %response_type,
+                "Datapoint and inference response types do not match"
+            );
+            bail!("Datapoint and inference response types do not match")
+        }
+    }
+}
+
+fn extract_tool_calls(blocks: &[ContentBlockChatOutput]) -> Vec<&InferenceResponseToolCall> {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlockChatOutput::ToolCall(tool_call) => Some(tool_call),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Computes the F1 score for `actual` against `expected`: each expected call is greedily matched
+/// against the first unmatched, name-and-argument-matching actual call. When
+/// `config.require_order` is set, matches must additionally occur in the same relative order.
+fn score_tool_calls(
+    expected: &[&InferenceResponseToolCall],
+    actual: &[&InferenceResponseToolCall],
+    config: &ToolCallCorrectnessConfig,
+) -> f64 {
+    let mut matched_actual = vec![false; actual.len()];
+    let mut matched_count = 0usize;
+    let mut next_actual_index = 0usize;
+    for expected_call in expected {
+        let search_start = if config.require_order {
+            next_actual_index
+        } else {
+            0
+        };
+        let found = actual
+            .iter()
+            .enumerate()
+            .skip(search_start)
+            .find(|(i, actual_call)| {
+                !matched_actual[*i]
+                    && tool_calls_match(expected_call, actual_call, config.argument_tolerance)
+            })
+            .map(|(i, _)| i);
+        if let Some(i) = found {
+            matched_actual[i] = true;
+            matched_count += 1;
+            if config.require_order {
+                next_actual_index = i + 1;
+            }
+        }
+    }
+    let precision = if actual.is_empty() {
+        0.0
+    } else {
+        matched_count as f64 / actual.len() as f64
+    };
+    // `expected` is guaranteed non-empty by the caller.
+    let recall = matched_count as f64 / expected.len() as f64;
+    if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * precision * recall / (precision + recall)
+    }
+}
+
+fn tool_calls_match(
+    expected: &InferenceResponseToolCall,
+    actual: &InferenceResponseToolCall,
+    argument_tolerance: f32,
+) -> bool {
+    if resolve_name(expected) != resolve_name(actual) {
+        return false;
+    }
+    match (resolve_arguments(expected), resolve_arguments(actual)) {
+        (Some(expected_args), Some(actual_args)) => {
+            arguments_subset_match(&expected_args, &actual_args, argument_tolerance)
+        }
+        // The reference call declares no parseable arguments, so only the name is checked.
+        (None, _) => true,
+        (Some(_), None) => false,
+    }
+}
+
+fn resolve_name(tool_call: &InferenceResponseToolCall) -> &str {
+    tool_call.name.as_deref().unwrap_or(&tool_call.raw_name)
+}
+
+fn resolve_arguments(tool_call: &InferenceResponseToolCall) -> Option<Value> {
+    tool_call
+        .arguments
+        .clone()
+        .or_else(|| serde_json::from_str(&tool_call.raw_arguments).ok())
+}
+
+/// Returns true if every key in `expected` is present in `actual` with a matching value.
+/// Extra keys in `actual` are ignored, since the request calls for a subset match.
+fn arguments_subset_match(expected: &Value, actual: &Value, tolerance: f32) -> bool {
+    let (Value::Object(expected_map), Value::Object(actual_map)) = (expected, actual) else {
+        return expected == actual;
+    };
+    expected_map.iter().all(|(key, expected_value)| {
+        actual_map
+            .get(key)
+            .is_some_and(|actual_value| values_match(expected_value, actual_value, tolerance))
+    })
+}
+
+fn values_match(expected: &Value, actual: &Value, tolerance: f32) -> bool {
+    match (expected.as_f64(), actual.as_f64()) {
+        (Some(expected_number), Some(actual_number)) if tolerance > 0.0 => {
+            let allowed = expected_number.abs() * f64::from(tolerance);
+            (expected_number - actual_number).abs() <= allowed
+        }
+        _ => expected == actual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tensorzero_core::client::Role;
+    use tensorzero_core::endpoints::{
+        datasets::ChatInferenceDatapoint, inference::ChatInferenceResponse,
+    };
+    use tensorzero_core::inference::types::{
+        Input, InputMessage, InputMessageContent, Text, Usage,
+    };
+    use uuid::Uuid;
+
+    fn tool_call(id: &str, name: &str, arguments: Value) -> InferenceResponseToolCall {
+        InferenceResponseToolCall {
+            id: id.to_string(),
+            raw_name: name.to_string(),
+            raw_arguments: arguments.to_string(),
+            name: Some(name.to_string()),
+            arguments: Some(arguments),
+        }
+    }
+
+    fn datapoint_with_output(output: Option<Vec<ContentBlockChatOutput>>) -> Datapoint {
+        Datapoint::Chat(ChatInferenceDatapoint {
+            id: Uuid::now_v7(),
+            input: Input {
+                system: None,
+                messages: vec![InputMessage {
+                    role: Role::User,
+                    content: vec![InputMessageContent::Text(Text {
+                        text: "book me a flight".to_string(),
+                    })],
+                }],
+            },
+            dataset_name: "test".to_string(),
+            function_name: "test".to_string(),
+            name: None,
+            episode_id: Some(Uuid::now_v7()),
+            output,
+            tool_params: Default::default(),
+            tags: None,
+            auxiliary: String::new(),
+            is_deleted: false,
+            source_inference_id: None,
+            staled_at: None,
+            updated_at: "2025-10-13T20:17:36Z".to_string(),
+            is_custom: false,
+        })
+    }
+
+    fn response_with_content(content: Vec<ContentBlockChatOutput>) -> InferenceResponse {
+        InferenceResponse::Chat(ChatInferenceResponse {
+            inference_id: Uuid::now_v7(),
+            episode_id: Uuid::now_v7(),
+            variant_name: "test".to_string(),
+            content,
+            usage: Usage {
+                input_tokens: Some(10),
+                output_tokens: Some(10),
+            },
+            raw_usage: None,
+            original_response: None,
+            raw_response: None,
+            finish_reason: None,
+        })
+    }
+
+    #[test]
+    fn test_tool_call_correctness_exact_match() {
+        let datapoint = datapoint_with_output(Some(vec![ContentBlockChatOutput::ToolCall(
+            tool_call("1", "book_flight", json!({"destination": "SFO"})),
+        )]));
+        let response = response_with_content(vec![ContentBlockChatOutput::ToolCall(tool_call(
+            "2",
+            "book_flight",
+            json!({"destination": "SFO"}),
+        ))]);
+        let config = ToolCallCorrectnessConfig::default();
+        let result = run_tool_call_correctness_evaluator(&response, &datapoint, &config).unwrap();
+        assert_eq!(
+            result,
+            Some(Value::from(1.0)),
+            "an exact name/argument match should score a perfect F1 of 1.0"
+        );
+    }
+
+    #[test]
+    fn test_tool_call_correctness_argument_subset_and_tolerance() {
+        let datapoint =
+            datapoint_with_output(Some(vec![ContentBlockChatOutput::ToolCall(tool_call(
+                "1",
+                "book_flight",
+                json!({"destination": "SFO", "price": 100.0}),
+            ))]));
+        // The model's call includes an extra field and a slightly different price.
+        let response = response_with_content(vec![ContentBlockChatOutput::ToolCall(tool_call(
+            "2",
+            "book_flight",
+            json!({"destination": "SFO", "price": 102.0, "seat": "aisle"}),
+        ))]);
+        let config = ToolCallCorrectnessConfig {
+            argument_tolerance: 0.05,
+            ..Default::default()
+        };
+        let result = run_tool_call_correctness_evaluator(&response, &datapoint, &config).unwrap();
+        assert_eq!(
+            result,
+            Some(Value::from(1.0)),
+            "an argument within tolerance and extra actual fields should still match, since this is a subset comparison"
+        );
+
+        let config_no_tolerance = ToolCallCorrectnessConfig::default();
+        let result =
+            run_tool_call_correctness_evaluator(&response, &datapoint, &config_no_tolerance)
+                .unwrap();
+        assert_eq!(
+            result,
+            Some(Value::from(0.0)),
+            "with no tolerance configured, a differing price should not match, yielding a score of 0"
+        );
+    }
+
+    #[test]
+    fn test_tool_call_correctness_partial_precision_recall() {
+        let datapoint = datapoint_with_output(Some(vec![
+            ContentBlockChatOutput::ToolCall(tool_call("1", "search_flights", json!({}))),
+            ContentBlockChatOutput::ToolCall(tool_call(
+                "2",
+                "book_flight",
+                json!({"destination": "SFO"}),
+            )),
+        ]));
+        // Only one of the two expected calls is made, plus an unexpected extra call.
+        let response = response_with_content(vec![
+            ContentBlockChatOutput::ToolCall(tool_call("3", "search_flights", json!({}))),
+            ContentBlockChatOutput::ToolCall(tool_call("4", "cancel_flight", json!({}))),
+        ]);
+        let config = ToolCallCorrectnessConfig::default();
+        let result = run_tool_call_correctness_evaluator(&response, &datapoint, &config).unwrap();
+        // precision = 1/2, recall = 1/2, F1 = 0.5
+        assert_eq!(
+            result,
+            Some(Value::from(0.5)),
+            "one matched call out of two expected and two actual should give precision=recall=0.5, F1=0.5"
+        );
+    }
+
+    #[test]
+    fn test_tool_call_correctness_no_reference_output() {
+        let datapoint = datapoint_with_output(None);
+        let response = response_with_content(vec![ContentBlockChatOutput::ToolCall(tool_call(
+            "1",
+            "book_flight",
+            json!({"destination": "SFO"}),
+        ))]);
+        let config = ToolCallCorrectnessConfig::default();
+        let result = run_tool_call_correctness_evaluator(&response, &datapoint, &config).unwrap();
+        assert_eq!(
+            result, None,
+            "an evaluator run against a datapoint with no reference output should produce no value"
+        );
+    }
+
+    #[test]
+    fn test_tool_call_correctness_require_order() {
+        let datapoint = datapoint_with_output(Some(vec![
+            ContentBlockChatOutput::ToolCall(tool_call("1", "search_flights", json!({}))),
+            ContentBlockChatOutput::ToolCall(tool_call("2", "book_flight", json!({}))),
+        ]));
+        // The model called the tools in the opposite order.
+        let response = response_with_content(vec![
+            ContentBlockChatOutput::ToolCall(tool_call("3", "book_flight", json!({}))),
+            ContentBlockChatOutput::ToolCall(tool_call("4", "search_flights", json!({}))),
+        ]);
+        let unordered_config = ToolCallCorrectnessConfig::default();
+        let result =
+            run_tool_call_correctness_evaluator(&response, &datapoint, &unordered_config).unwrap();
+        assert_eq!(
+            result,
+            Some(Value::from(1.0)),
+            "without require_order, matches ignore call sequence"
+        );
+
+        let ordered_config = ToolCallCorrectnessConfig {
+            require_order: true,
+            ..Default::default()
+        };
+        let result =
+            run_tool_call_correctness_evaluator(&response, &datapoint, &ordered_config).unwrap();
+        assert_eq!(
+            result,
+            Some(Value::from(0.5)),
+            "with require_order, matching only proceeds forward through the actual calls, so the \
+             out-of-sequence book_flight call is left unmatched and the score drops to 0.5"
+        );
+    }
+}
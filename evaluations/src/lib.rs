@@ -8,6 +8,9 @@ use evaluators::{EvaluateInferenceParams, evaluate_inference};
 use helpers::get_cache_options;
 
 // Public re-exports for external consumers
+pub use backfill::{
+    BackfillEvaluationParams, BackfillFilter, BackfillItemResult, backfill_evaluation,
+};
 pub use cli::{Args, OutputFormat};
 pub use stats::{
     EvaluationError, EvaluationInfo, EvaluationStats, EvaluationUpdate, EvaluatorStats,
@@ -22,16 +25,18 @@ use tensorzero_core::cache::CacheEnabledMode;
 use tensorzero_core::client::Input;
 use tensorzero_core::client::{
     ClientBuilder, ClientBuilderMode, ClientInferenceParams, DynamicToolParams, InferenceOutput,
-    InferenceParams, InferenceResponse, PostgresConfig,
+    InferenceParams, InferenceResponse, PostgresConfig, TensorZeroError,
     input_handling::resolved_input_to_client_input,
 };
 use tensorzero_core::config::{ConfigFileGlob, MetricConfigOptimize};
+use tensorzero_core::db::postgres::PostgresConnectionInfo;
 use tensorzero_core::endpoints::datasets::v1::{
     get_datapoints, list_datapoints,
     types::{GetDatapointsRequest, ListDatapointsRequest},
 };
 use tensorzero_core::evaluations::{EvaluationConfig, EvaluatorConfig};
 use tensorzero_core::inference::types::InputExt;
+use tensorzero_core::utils::gateway::setup_postgres;
 use tensorzero_core::utils::spawn_ignoring_shutdown;
 use tensorzero_core::{
     config::Config, db::clickhouse::ClickHouseConnectionInfo, endpoints::datasets::Datapoint,
@@ -43,11 +48,14 @@ use tokio::{
 use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
+pub mod backfill;
 pub mod cli;
 pub mod evaluators;
 pub mod helpers;
+pub mod simulation;
 pub mod stats;
 pub mod stopping;
+pub mod trajectory;
 pub mod types;
 
 /// Buffer size for the mpsc channel used to stream evaluation updates.
@@ -77,6 +85,7 @@ pub(crate) fn merge_tags(
 pub struct Clients {
     pub inference_executor: Arc<dyn EvaluationsInferenceExecutor>,
     pub clickhouse_client: ClickHouseConnectionInfo,
+    pub postgres_connection_info: PostgresConnectionInfo,
 }
 
 /// High-level wrapper function for running evaluations called from the CLI.
@@ -178,6 +187,12 @@ pub async fn run_evaluation(
     let config = Arc::new(config);
     debug!("Configuration loaded successfully");
 
+    // Only used by the `human` evaluator to enqueue review tasks; other evaluators
+    // never touch Postgres, so we don't fail evaluation setup if it's unconfigured.
+    let postgres_connection_info = setup_postgres(&config, postgres_url.clone())
+        .await
+        .map_err(|e| anyhow!("Failed to set up Postgres client: {e}"))?;
+
     // Look up evaluation config from the loaded config
     let evaluation_config = config
         .evaluations
@@ -217,6 +232,7 @@ pub async fn run_evaluation(
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: clickhouse_client.clone(),
+        postgres_connection_info,
         evaluation_config,
         function_configs,
         dataset_name: args.dataset_name,
@@ -227,6 +243,10 @@ pub async fn run_evaluation(
         inference_cache: args.inference_cache,
         concurrency: args.concurrency,
         tags: HashMap::new(), // CLI doesn't have autopilot context
+        retry_policy: EvaluationRetryPolicy {
+            max_attempts: args.max_inference_retries + 1,
+            ..Default::default()
+        },
     };
 
     // Convert Vec<(String, f32)> to HashMap<String, f32> for precision_targets
@@ -338,6 +358,8 @@ pub async fn run_evaluation_with_app_state(
         .await
         .map_err(|e| anyhow!("Failed to create ClickHouse client for evaluation: {e}"))?;
 
+    let postgres_connection_info = app_state.postgres_connection_info.clone();
+
     // Create AppStateInferenceExecutor to call handlers directly without HTTP overhead
     let inference_executor = Arc::new(AppStateInferenceExecutor::new(app_state));
 
@@ -357,6 +379,7 @@ pub async fn run_evaluation_with_app_state(
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client,
+        postgres_connection_info,
         evaluation_config: Arc::new(params.evaluation_config),
         function_configs,
         dataset_name: params.dataset_name,
@@ -367,6 +390,7 @@ pub async fn run_evaluation_with_app_state(
         inference_cache: params.cache_mode,
         concurrency: params.concurrency,
         tags: params.tags,
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     // Run the evaluation
@@ -452,6 +476,7 @@ pub async fn run_evaluation_core_streaming(
     let clients = Arc::new(Clients {
         inference_executor: args.inference_executor,
         clickhouse_client: args.clickhouse_client,
+        postgres_connection_info: args.postgres_connection_info,
     });
 
     // Use the pre-resolved evaluation configuration
@@ -559,6 +584,7 @@ pub async fn run_evaluation_core_streaming(
         semaphore,
         cancellation_tokens: cancellation_tokens_arc,
         external_tags: Arc::new(args.tags),
+        retry_policy: args.retry_policy,
     };
 
     // Process all datapoints across all variants
@@ -677,6 +703,7 @@ struct InferDatapointParams<'a> {
     function_config: &'a EvaluationFunctionConfig,
     inference_cache: CacheEnabledMode,
     external_tags: &'a HashMap<String, String>,
+    retry_policy: EvaluationRetryPolicy,
 }
 
 #[instrument(skip_all, fields(datapoint_id = %params.datapoint.id(), function_name = %params.function_name))]
@@ -693,6 +720,7 @@ async fn infer_datapoint(params: InferDatapointParams<'_>) -> Result<InferenceRe
         input,
         inference_cache,
         external_tags,
+        retry_policy,
     } = params;
 
     // Extract variant_name, internal_dynamic_variant_config, and dryrun from the variant enum
@@ -786,13 +814,14 @@ async fn infer_datapoint(params: InferDatapointParams<'_>) -> Result<InferenceRe
         extra_body: Default::default(),
         extra_headers: Default::default(),
         internal_dynamic_variant_config: internal_dynamic_variant_config.clone(),
+        timeout_ms: None,
         otlp_traces_extra_headers: HashMap::new(),
         otlp_traces_extra_attributes: HashMap::new(),
         otlp_traces_extra_resources: HashMap::new(),
         api_key: None,
     };
     debug!("Making inference request");
-    let inference_result = clients.inference_executor.inference(params).await?;
+    let inference_result = infer_with_retries(clients, params, retry_policy).await?;
     match inference_result {
         InferenceOutput::NonStreaming(inference_response) => {
             debug!(inference_id = %inference_response.inference_id(), "Inference completed successfully");
@@ -805,6 +834,41 @@ async fn infer_datapoint(params: InferDatapointParams<'_>) -> Result<InferenceRe
     }
 }
 
+/// Runs an inference request, retrying transient failures according to `retry_policy`.
+///
+/// We can't use the `backon`-based retry helper in `tensorzero_core::utils::retries` here:
+/// that helper is scoped to `tensorzero_core::error::Error`, while inference made through
+/// `EvaluationsInferenceExecutor` fails with `TensorZeroError`. Backoff is applied manually
+/// instead, doubling from `initial_backoff` up to `max_backoff` between attempts.
+async fn infer_with_retries(
+    clients: &Clients,
+    params: ClientInferenceParams,
+    retry_policy: EvaluationRetryPolicy,
+) -> Result<InferenceOutput, TensorZeroError> {
+    let mut backoff = retry_policy.initial_backoff;
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match clients.inference_executor.inference(params.clone()).await {
+            Ok(output) => return Ok(output),
+            Err(error) => {
+                if attempt >= retry_policy.max_attempts
+                    || !EvaluationRetryPolicy::is_retryable(&error)
+                {
+                    return Err(error);
+                }
+                tracing::warn!(
+                    attempt,
+                    max_attempts = retry_policy.max_attempts,
+                    "Retryable inference error, retrying after {backoff:?}: {error}"
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = std::cmp::min(backoff * 2, retry_policy.max_backoff);
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Shared Batch Processing Infrastructure
 // ============================================================================
@@ -831,6 +895,8 @@ pub struct ProcessBatchParams {
     pub cancellation_tokens: Arc<stopping::CancellationTokens>,
     /// External tags to apply to all inferences
     pub external_tags: Arc<HashMap<String, String>>,
+    /// Retry policy applied to each inference call
+    pub retry_policy: EvaluationRetryPolicy,
 }
 
 /// Result of processing a single (datapoint, variant) pair.
@@ -935,6 +1001,7 @@ pub async fn process_batch(
             let semaphore = params.semaphore.clone();
             let cancellation_tokens = params.cancellation_tokens.clone();
             let external_tags = params.external_tags.clone();
+            let retry_policy = params.retry_policy;
             let variant = variant.clone();
             let variant_for_map = variant.clone(); // Clone before moving into async block
             let datapoint = datapoint.clone();
@@ -967,6 +1034,7 @@ pub async fn process_batch(
                         input: &input,
                         inference_cache,
                         external_tags: &external_tags,
+                        retry_policy,
                     })
                     .await
                     .map_err(|e| {
@@ -1106,6 +1174,7 @@ mod tests {
                     mean: 0.4,
                     stderr: 0.1,
                     count: 10,
+                    criteria: None,
                 },
             );
             stats.insert(
@@ -1114,6 +1183,7 @@ mod tests {
                     mean: 0.3,
                     stderr: 0.1,
                     count: 10,
+                    criteria: None,
                 },
             );
             stats.insert(
@@ -1122,6 +1192,7 @@ mod tests {
                     mean: 0.1,
                     stderr: 0.05,
                     count: 10,
+                    criteria: None,
                 },
             );
             stats
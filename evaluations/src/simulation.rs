@@ -0,0 +1,254 @@
+//! Multi-turn simulation harness.
+//!
+//! Runs a scripted "user simulator" function against a target function for up to
+//! `max_turns` turns per scenario datapoint, scoring the target's response on each
+//! turn with the configured evaluators. This complements the single-turn evaluation
+//! flow in [`crate::run_evaluation`] with end-to-end, multi-turn agent evaluation.
+//!
+//! Scope: this only supports chat functions on both sides of the conversation (the
+//! target's `Text` content blocks are fed back to the simulator as `User` messages,
+//! and vice versa); tool calls, thoughts, and JSON functions are not carried across
+//! turns. Simulation runs are not persisted to ClickHouse and are not wired into the
+//! `evaluations` CLI - see [`SimulationConfig`] for why this doesn't reuse the
+//! `EvaluationConfig` config-loading path.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use tensorzero_core::cache::CacheEnabledMode;
+use tensorzero_core::client::{
+    ClientInferenceParams, InferenceOutput, InferenceParams, InferenceResponse, Input,
+};
+use tensorzero_core::endpoints::datasets::Datapoint;
+use tensorzero_core::evaluations::{EvaluationConfig, InferenceEvaluationConfig, SimulationConfig};
+use tensorzero_core::inference::types::{
+    ContentBlockChatOutput, InputMessage, InputMessageContent, Role,
+};
+use tensorzero_core::tool::DynamicToolParams;
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use crate::Clients;
+use crate::evaluators::{EvaluateInferenceParams, EvaluationResult, evaluate_inference};
+use crate::helpers::get_cache_options;
+use crate::merge_tags;
+use crate::stopping::CancellationTokens;
+
+/// The result of a single turn of a simulation: the target function's response and
+/// the evaluator scores computed against it.
+pub struct SimulationTurnResult {
+    pub turn: u32,
+    pub target_response: Arc<InferenceResponse>,
+    pub evaluations: EvaluationResult,
+}
+
+pub struct RunSimulationParams {
+    pub datapoint: Arc<Datapoint>,
+    pub simulation_config: Arc<SimulationConfig>,
+    pub simulation_name: Arc<String>,
+    pub clients: Arc<Clients>,
+    pub simulation_run_id: Uuid,
+    pub inference_cache: CacheEnabledMode,
+    pub external_tags: Arc<HashMap<String, String>>,
+}
+
+/// Runs a scenario datapoint through up to `simulation_config.max_turns` turns of
+/// conversation between the target function and the user simulator function,
+/// evaluating the target's response on every turn.
+///
+/// Both functions are called with their config-default variant (dynamic variant
+/// overrides aren't supported here, unlike `run_evaluation`'s `EvaluationVariant`).
+#[instrument(skip_all, fields(datapoint_id = %params.datapoint.id(), simulation_name = %params.simulation_name))]
+pub async fn run_simulation(params: RunSimulationParams) -> Result<Vec<SimulationTurnResult>> {
+    let RunSimulationParams {
+        datapoint,
+        simulation_config,
+        simulation_name,
+        clients,
+        simulation_run_id,
+        inference_cache,
+        external_tags,
+    } = params;
+
+    let mut conversation = datapoint.input().clone();
+    let mut turns = Vec::with_capacity(simulation_config.max_turns as usize);
+
+    // Evaluators are scored per-turn by reusing the existing single-turn
+    // `evaluate_inference` machinery, wrapped in an `EvaluationConfig::Inference` for
+    // the target function - this avoids adding a new `EvaluationConfig` variant that
+    // every existing `EvaluationConfig::Inference(...)` call site across the
+    // workspace would need to be updated to handle.
+    let per_turn_evaluation_config =
+        Arc::new(EvaluationConfig::Inference(InferenceEvaluationConfig {
+            evaluators: simulation_config.evaluators.clone(),
+            function_name: simulation_config.target_function_name.clone(),
+            description: simulation_config.description.clone(),
+        }));
+
+    for turn in 0..simulation_config.max_turns {
+        let target_response = Arc::new(
+            run_turn_inference(
+                &clients,
+                &simulation_config.target_function_name,
+                &conversation,
+                simulation_run_id,
+                &simulation_name,
+                datapoint.id(),
+                turn,
+                "target",
+                inference_cache,
+                &external_tags,
+            )
+            .await?,
+        );
+
+        let evaluations = evaluate_inference(
+            EvaluateInferenceParams {
+                inference_response: target_response.clone(),
+                datapoint: datapoint.clone(),
+                input: Arc::new(conversation.clone()),
+                evaluation_config: per_turn_evaluation_config.clone(),
+                evaluation_name: simulation_name.clone(),
+                clients: clients.clone(),
+                evaluation_run_id: simulation_run_id,
+                inference_cache,
+                external_tags: external_tags.clone(),
+                send_feedback: false,
+            },
+            &CancellationTokens::default(),
+        )
+        .await?;
+
+        conversation.messages.push(chat_output_to_input_message(
+            Role::Assistant,
+            &target_response,
+        )?);
+
+        turns.push(SimulationTurnResult {
+            turn,
+            target_response,
+            evaluations,
+        });
+
+        // The scenario ends after the target's final turn - no need to simulate one
+        // more user reply that nothing will ever respond to.
+        if turn + 1 == simulation_config.max_turns {
+            break;
+        }
+
+        let user_simulator_response = run_turn_inference(
+            &clients,
+            &simulation_config.user_simulator_function_name,
+            &conversation,
+            simulation_run_id,
+            &simulation_name,
+            datapoint.id(),
+            turn,
+            "user_simulator",
+            inference_cache,
+            &external_tags,
+        )
+        .await?;
+
+        conversation.messages.push(chat_output_to_input_message(
+            Role::User,
+            &user_simulator_response,
+        )?);
+    }
+
+    Ok(turns)
+}
+
+#[expect(clippy::too_many_arguments)]
+async fn run_turn_inference(
+    clients: &Clients,
+    function_name: &str,
+    conversation: &Input,
+    simulation_run_id: Uuid,
+    simulation_name: &str,
+    datapoint_id: Uuid,
+    turn: u32,
+    role: &str,
+    inference_cache: CacheEnabledMode,
+    external_tags: &HashMap<String, String>,
+) -> Result<InferenceResponse> {
+    let internal_tags = HashMap::from([
+        (
+            "tensorzero::simulation_run_id".to_string(),
+            simulation_run_id.to_string(),
+        ),
+        (
+            "tensorzero::simulation_name".to_string(),
+            simulation_name.to_string(),
+        ),
+        (
+            "tensorzero::datapoint_id".to_string(),
+            datapoint_id.to_string(),
+        ),
+        ("tensorzero::simulation_turn".to_string(), turn.to_string()),
+        ("tensorzero::simulation_role".to_string(), role.to_string()),
+    ]);
+    let tags = merge_tags(external_tags, internal_tags)?;
+
+    debug!(
+        function_name,
+        role, turn, "Running simulation turn inference"
+    );
+
+    let params = ClientInferenceParams {
+        function_name: Some(function_name.to_string()),
+        variant_name: None,
+        input: conversation.clone(),
+        tags,
+        dynamic_tool_params: DynamicToolParams::default(),
+        output_schema: None,
+        credentials: HashMap::new(),
+        cache_options: get_cache_options(inference_cache),
+        dryrun: Some(false),
+        episode_id: None,
+        model_name: None,
+        stream: Some(false),
+        params: InferenceParams::default(),
+        include_original_response: false,
+        include_raw_response: false,
+        include_raw_usage: false,
+        internal: true,
+        extra_body: Default::default(),
+        extra_headers: Default::default(),
+        internal_dynamic_variant_config: None,
+        timeout_ms: None,
+        otlp_traces_extra_headers: HashMap::new(),
+        otlp_traces_extra_attributes: HashMap::new(),
+        otlp_traces_extra_resources: HashMap::new(),
+        api_key: None,
+    };
+
+    match clients.inference_executor.inference(params).await? {
+        InferenceOutput::NonStreaming(response) => Ok(response),
+        InferenceOutput::Streaming(_) => {
+            bail!("Streaming inference should never happen in simulations")
+        }
+    }
+}
+
+/// Converts a chat response's `Text` content blocks into a single input message,
+/// dropping any tool calls, thoughts, or unknown blocks (see module-level scope note).
+fn chat_output_to_input_message(role: Role, response: &InferenceResponse) -> Result<InputMessage> {
+    let InferenceResponse::Chat(chat_response) = response else {
+        bail!(
+            "Simulations only support chat functions, but received a JSON function response \
+             for inference {}",
+            response.inference_id()
+        );
+    };
+    let content = chat_response
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlockChatOutput::Text(text) => Some(InputMessageContent::Text(text.clone())),
+            _ => None,
+        })
+        .collect();
+    Ok(InputMessage { role, content })
+}
@@ -89,6 +89,10 @@ impl EvaluationStats {
             .iter()
             .map(|(key, config)| (key.clone(), PerEvaluatorStats::new(config.is_bernoulli())))
             .collect();
+        // Per-criterion breakdown for rubric-based LLM judges, keyed by evaluator name and
+        // then by criterion name. Populated lazily since most evaluators aren't rubric-based.
+        let mut per_criterion_stats: HashMap<String, HashMap<String, PerEvaluatorStats>> =
+            HashMap::new();
         debug!(evaluators = ?evaluators.keys().collect::<Vec<_>>(), "Initialized data collectors for evaluators");
 
         // Collect evaluation inference data using PerEvaluatorStats
@@ -118,6 +122,33 @@ impl EvaluationStats {
                             );
                         }
                     }
+                    // A rubric-based LLM judge's stats value is `{"score": <mean>, "criteria": {...}}`
+                    // (see `LLMJudgeEvaluationResult::stats_value`).
+                    Some(Value::Object(obj)) => {
+                        if let Some(stats) = per_evaluator_stats.get_mut(evaluation_name) {
+                            if let Some(score) = obj.get("score").and_then(Value::as_f64) {
+                                stats.push(score as f32);
+                            }
+                        } else {
+                            tracing::error!(
+                                evaluator_name = %evaluation_name,
+                                "Received evaluation result for unknown evaluator"
+                            );
+                        }
+                        if let Some(Value::Object(criteria)) = obj.get("criteria") {
+                            let criterion_stats = per_criterion_stats
+                                .entry(evaluation_name.clone())
+                                .or_default();
+                            for (criterion_name, criterion_value) in criteria {
+                                if let Some(score) = criterion_value.as_f64() {
+                                    criterion_stats
+                                        .entry(criterion_name.clone())
+                                        .or_insert_with(|| PerEvaluatorStats::new(false))
+                                        .push(score as f32);
+                                }
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -128,7 +159,15 @@ impl EvaluationStats {
         let stats: HashMap<String, EvaluatorStats> = per_evaluator_stats
             .into_iter()
             .map(|(evaluator_name, per_eval_stats)| {
-                let eval_stats = per_eval_stats.to_evaluator_stats();
+                let mut eval_stats = per_eval_stats.to_evaluator_stats();
+                if let Some(criterion_stats) = per_criterion_stats.remove(&evaluator_name) {
+                    eval_stats.criteria = Some(
+                        criterion_stats
+                            .into_iter()
+                            .map(|(name, stats)| (name, stats.to_evaluator_stats()))
+                            .collect(),
+                    );
+                }
                 debug!(
                     evaluator_name = %evaluator_name,
                     count = eval_stats.count,
@@ -212,6 +251,10 @@ pub struct EvaluatorStats {
     pub mean: f32,
     pub stderr: f32,
     pub count: usize,
+    /// Per-criterion breakdown for a rubric-based LLM judge (see `LLMJudgeConfig::criteria`).
+    /// `None` for evaluators that produce a single scalar score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<HashMap<String, EvaluatorStats>>,
 }
 
 impl std::fmt::Display for EvaluatorStats {
@@ -280,6 +323,7 @@ impl PerEvaluatorStats {
             mean: self.mean().unwrap_or(0.0),
             stderr: self.stderr().unwrap_or(0.0),
             count: self.count(),
+            criteria: None,
         }
     }
 }
@@ -0,0 +1,164 @@
+//! Trajectory-level (episode-scope) evaluation.
+//!
+//! Scores an entire episode - its full ordered list of inferences - with a single
+//! evaluator result, for evaluating multi-turn/agentic behavior end-to-end instead of one
+//! inference at a time. Complements [`crate::simulation`] (which generates a multi-turn
+//! trajectory) and the single-inference evaluation flow in [`crate::run_evaluation`].
+//!
+//! Scope: there is no "timeline API" in this codebase exposing an episode's inferences and
+//! tool events as a separate stream - tool calls and results are already inline in each
+//! chat inference's content blocks, so the ordered list of inferences returned by
+//! `list_inferences` (filtered by `episode_id`, ordered by timestamp) already carries
+//! everything a "timeline" would. This reuses the existing `evaluate_inference` evaluator
+//! dispatch (`ExactMatch`/`LLMJudge`/`JudgePanel`/`Human`/`ToolCallCorrectness`/
+//! `LexicalDiversity`) unmodified, by packaging the
+//! trajectory as a synthetic single-turn datapoint/response pair whose "output" is the
+//! full serialized ordered inference list - mirroring how [`crate::simulation`] reuses
+//! `EvaluationConfig::Inference` per-turn instead of adding new evaluator-dispatch code.
+//! Evaluators that need to read the whole trajectory (typically an `LLMJudge` with
+//! `input_format = "serialized"`) see it in that serialized output.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use tensorzero_core::cache::CacheEnabledMode;
+use tensorzero_core::config::Config;
+use tensorzero_core::db::inferences::{
+    InferenceOutputSource, InferenceQueries, ListInferencesParams,
+};
+use tensorzero_core::endpoints::datasets::{ChatInferenceDatapoint, Datapoint};
+use tensorzero_core::endpoints::inference::{ChatInferenceResponse, InferenceResponse};
+use tensorzero_core::endpoints::shared_types::OrderDirection;
+use tensorzero_core::endpoints::stored_inferences::v1::types::{OrderBy, OrderByTerm};
+use tensorzero_core::evaluations::{
+    EvaluationConfig, InferenceEvaluationConfig, TrajectoryEvaluationConfig,
+};
+use tensorzero_core::inference::types::usage::Usage;
+use tensorzero_core::inference::types::{ContentBlockChatOutput, Input, Text};
+use tensorzero_core::stored_inference::StoredInferenceDatabase;
+use tensorzero_core::tool::DynamicToolParams;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::Clients;
+use crate::evaluators::{EvaluateInferenceParams, EvaluationResult, evaluate_inference};
+use crate::stopping::CancellationTokens;
+
+/// The synthetic function name recorded on the datapoint/inference built to represent a
+/// trajectory - there's no real function backing a whole episode, so this exists only to
+/// give evaluators (and any logs/errors they produce) a stable, recognizable name.
+const TRAJECTORY_FUNCTION_NAME: &str = "tensorzero::trajectory_evaluation";
+
+pub struct RunTrajectoryEvaluationParams {
+    pub config: Arc<Config>,
+    pub episode_id: Uuid,
+    pub trajectory_evaluation_config: Arc<TrajectoryEvaluationConfig>,
+    pub evaluation_name: Arc<String>,
+    pub clients: Arc<Clients>,
+    pub evaluation_run_id: Uuid,
+    pub inference_cache: CacheEnabledMode,
+    pub external_tags: Arc<HashMap<String, String>>,
+}
+
+/// Runs every evaluator in `trajectory_evaluation_config` once against the full ordered
+/// trajectory of inferences in `episode_id`.
+#[instrument(skip_all, fields(episode_id = %params.episode_id, evaluation_name = %params.evaluation_name))]
+pub async fn run_trajectory_evaluation(
+    params: RunTrajectoryEvaluationParams,
+) -> Result<EvaluationResult> {
+    let RunTrajectoryEvaluationParams {
+        config,
+        episode_id,
+        trajectory_evaluation_config,
+        evaluation_name,
+        clients,
+        evaluation_run_id,
+        inference_cache,
+        external_tags,
+    } = params;
+
+    let trajectory_storage = clients
+        .clickhouse_client
+        .list_inferences(
+            &config,
+            &ListInferencesParams {
+                episode_id: Some(&episode_id),
+                output_source: InferenceOutputSource::Inference,
+                order_by: Some(&[OrderBy {
+                    term: OrderByTerm::Timestamp,
+                    direction: OrderDirection::Asc,
+                }]),
+                limit: u32::MAX,
+                ..Default::default()
+            },
+        )
+        .await?;
+    if trajectory_storage.is_empty() {
+        bail!("Episode {episode_id} has no inferences to run a trajectory evaluation against");
+    }
+    let trajectory = trajectory_storage
+        .into_iter()
+        .map(StoredInferenceDatabase::into_stored_inference)
+        .collect::<Result<Vec<_>, _>>()?;
+    let trajectory_json = serde_json::to_string_pretty(&trajectory)?;
+
+    // Evaluators are run by reusing the existing single-turn `evaluate_inference`
+    // machinery, wrapped in an `EvaluationConfig::Inference` - see the module doc comment
+    // for why this avoids adding a new `EvaluationConfig` variant or evaluator-dispatch
+    // path. The datapoint/response pair below is synthetic: there's no real function or
+    // prior inference call backing "the whole episode", only sentinel values needed to
+    // satisfy the shape `evaluate_inference` expects.
+    let datapoint = Arc::new(Datapoint::Chat(ChatInferenceDatapoint {
+        dataset_name: String::new(),
+        function_name: TRAJECTORY_FUNCTION_NAME.to_string(),
+        id: episode_id,
+        episode_id: Some(episode_id),
+        input: Input::default(),
+        output: None,
+        tool_params: DynamicToolParams::default(),
+        tags: None,
+        auxiliary: String::new(),
+        is_deleted: false,
+        is_custom: true,
+        source_inference_id: None,
+        staled_at: None,
+        updated_at: String::new(),
+        name: None,
+    }));
+    let response = Arc::new(InferenceResponse::Chat(ChatInferenceResponse {
+        inference_id: Uuid::now_v7(),
+        episode_id,
+        variant_name: TRAJECTORY_FUNCTION_NAME.to_string(),
+        content: vec![ContentBlockChatOutput::Text(Text {
+            text: trajectory_json,
+        })],
+        usage: Usage::zero(),
+        raw_usage: None,
+        original_response: None,
+        raw_response: None,
+        finish_reason: None,
+    }));
+    let evaluation_config = Arc::new(EvaluationConfig::Inference(InferenceEvaluationConfig {
+        evaluators: trajectory_evaluation_config.evaluators.clone(),
+        function_name: TRAJECTORY_FUNCTION_NAME.to_string(),
+        description: trajectory_evaluation_config.description.clone(),
+    }));
+
+    evaluate_inference(
+        EvaluateInferenceParams {
+            inference_response: response,
+            datapoint,
+            input: Arc::new(Input::default()),
+            evaluation_config,
+            evaluation_name,
+            clients,
+            evaluation_run_id,
+            inference_cache,
+            external_tags,
+            send_feedback: false,
+        },
+        &CancellationTokens::default(),
+    )
+    .await
+}
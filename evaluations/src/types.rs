@@ -3,6 +3,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -14,6 +15,7 @@ use tensorzero_core::{
     config::UninitializedVariantInfo,
     db::clickhouse::BatchWriterHandle,
     db::clickhouse::ClickHouseConnectionInfo,
+    db::postgres::PostgresConnectionInfo,
     error::Error,
     evaluations::{EvaluationConfig, EvaluationFunctionConfigTable},
     inference::types::storage::StoragePath,
@@ -108,6 +110,7 @@ impl EvaluationsInferenceExecutor for AppStateInferenceExecutor {
             self.app_state.postgres_connection_info.clone(),
             self.app_state.deferred_tasks.clone(),
             self.app_state.rate_limiting_manager.clone(),
+            self.app_state.hot_cache.clone(),
             endpoint_params,
             None, // No API key for internal calls
         ))
@@ -147,6 +150,51 @@ impl tensorzero_core::inference::types::stored_input::StoragePathResolver
     }
 }
 
+/// Retry policy for evaluation inference calls.
+///
+/// A single flaky inference (a transient timeout or a provider's 5xx) currently counts as a
+/// hard error for the datapoint, which inflates `num_errors` and can poison adaptive stopping's
+/// per-variant failure confidence sequence. This lets callers configure automatic retries with
+/// exponential backoff before giving up and reporting an error.
+#[derive(Debug, Clone, Copy)]
+pub struct EvaluationRetryPolicy {
+    /// Maximum number of attempts per inference call, including the first. `1` (the default)
+    /// disables retries.
+    pub max_attempts: usize,
+    /// Delay before the first retry. Each subsequent retry doubles the previous delay, capped
+    /// at `max_backoff`.
+    pub initial_backoff: Duration,
+    /// Upper bound on the backoff delay between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for EvaluationRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl EvaluationRetryPolicy {
+    /// Whether `error` belongs to a retryable class. Request timeouts and 5xx responses from
+    /// the model provider or gateway are treated as transient; everything else (validation
+    /// errors, 4xx client errors, rate limiting) is not retried, since a retry would just fail
+    /// the same way.
+    pub fn is_retryable(error: &TensorZeroError) -> bool {
+        matches!(
+            error,
+            TensorZeroError::RequestTimeout
+                | TensorZeroError::Http {
+                    status_code: 500..=599,
+                    ..
+                }
+        )
+    }
+}
+
 /// Specifies which variant to use for evaluation.
 /// Either a variant name from the config, or a dynamic variant configuration.
 #[derive(Clone, Debug)]
@@ -168,6 +216,11 @@ pub struct EvaluationCoreArgs {
     /// ClickHouse client for database operations
     pub clickhouse_client: ClickHouseConnectionInfo,
 
+    /// Postgres client, used by the `human` evaluator to enqueue review tasks.
+    /// May be `PostgresConnectionInfo::Disabled` if Postgres isn't configured,
+    /// in which case a `human` evaluator will fail when it tries to enqueue.
+    pub postgres_connection_info: PostgresConnectionInfo,
+
     /// The evaluation configuration (pre-resolved by caller)
     pub evaluation_config: Arc<EvaluationConfig>,
 
@@ -203,6 +256,10 @@ pub struct EvaluationCoreArgs {
     /// These tags will be added to each inference, with internal evaluation tags
     /// taking precedence in case of conflicts.
     pub tags: HashMap<String, String>,
+
+    /// Retry policy applied to each inference call made during the evaluation.
+    /// Defaults to no retries.
+    pub retry_policy: EvaluationRetryPolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
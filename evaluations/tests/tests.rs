@@ -14,6 +14,7 @@ use tensorzero_core::db::clickhouse::TableName;
 use tensorzero_core::db::clickhouse::test_helpers::{
     select_inference_evaluation_human_feedback_clickhouse, select_model_inferences_clickhouse,
 };
+use tensorzero_core::db::postgres::PostgresConnectionInfo;
 use tensorzero_core::db::stored_datapoint::{
     StoredChatInferenceDatapoint, StoredJsonInferenceDatapoint,
 };
@@ -29,7 +30,8 @@ use url::Url;
 use common::{get_config, get_tensorzero_client, init_tracing_for_tests};
 use evaluations::{
     Args, EvaluationCoreArgs, EvaluationFunctionConfig, EvaluationFunctionConfigTable,
-    EvaluationVariant, OutputFormat, run_evaluation, run_evaluation_core_streaming,
+    EvaluationRetryPolicy, EvaluationVariant, OutputFormat, run_evaluation,
+    run_evaluation_core_streaming,
     stats::{EvaluationUpdate, PerEvaluatorStats},
 };
 use std::collections::HashMap;
@@ -528,6 +530,7 @@ async fn test_datapoint_ids_and_max_datapoints_mutually_exclusive_core_streaming
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: clickhouse,
+        postgres_connection_info: PostgresConnectionInfo::Disabled,
         evaluation_config,
         function_configs,
         evaluation_name,
@@ -538,6 +541,7 @@ async fn test_datapoint_ids_and_max_datapoints_mutually_exclusive_core_streaming
         concurrency: 10,
         inference_cache: CacheEnabledMode::On,
         tags: HashMap::new(),
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     let result = run_evaluation_core_streaming(core_args, Some(10), HashMap::new()).await;
@@ -1757,6 +1761,7 @@ async fn test_run_llm_judge_evaluator_chat() {
         output_type: LLMJudgeOutputType::Boolean,
         cutoff: None,
         description: None,
+        criteria: None,
     };
     // Construct the equivalent Input for the datapoint
     let input = Input {
@@ -1944,6 +1949,7 @@ async fn test_run_llm_judge_evaluator_json() {
         output_type: LLMJudgeOutputType::Boolean,
         cutoff: None,
         description: None,
+        criteria: None,
     };
     // Construct the equivalent Input for the datapoint
     let input = Input {
@@ -2772,6 +2778,7 @@ async fn test_evaluation_with_dynamic_variant() {
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: clickhouse,
+        postgres_connection_info: PostgresConnectionInfo::Disabled,
         evaluation_config,
         function_configs,
         dataset_name: Some(dataset_name),
@@ -2782,6 +2789,7 @@ async fn test_evaluation_with_dynamic_variant() {
         inference_cache: CacheEnabledMode::Off,
         concurrency: 2,
         tags: HashMap::new(),
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     let result = run_evaluation_core_streaming(core_args, None, HashMap::new()).await;
@@ -2838,6 +2846,7 @@ async fn test_max_datapoints_parameter() {
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: clickhouse.clone(),
+        postgres_connection_info: PostgresConnectionInfo::Disabled,
         evaluation_config,
         function_configs,
         dataset_name: Some(dataset_name.clone()),
@@ -2848,6 +2857,7 @@ async fn test_max_datapoints_parameter() {
         inference_cache: CacheEnabledMode::Off,
         concurrency: 2,
         tags: HashMap::new(),
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     let max_datapoints = Some(3);
@@ -2938,6 +2948,7 @@ async fn test_precision_targets_parameter() {
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: clickhouse.clone(),
+        postgres_connection_info: PostgresConnectionInfo::Disabled,
         evaluation_config,
         function_configs,
         dataset_name: Some(dataset_name.clone()),
@@ -2948,6 +2959,7 @@ async fn test_precision_targets_parameter() {
         inference_cache: CacheEnabledMode::Off,
         concurrency: 5,
         tags: external_tags.clone(),
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     // Run with precision targets
@@ -19,6 +19,12 @@ pub struct GatewayArgs {
     #[arg(long)]
     pub default_config: bool,
 
+    /// Optionally, merge in an environment-specific overlay: all config files matching this
+    /// glob pattern (e.g. `tensorzero.prod.toml`) are merged on top of `--config-file`, with
+    /// the overlay's values winning on conflicts. Requires `--config-file`.
+    #[arg(long)]
+    pub config_overlay: Option<PathBuf>,
+
     /// Sets the log format used for all gateway logs.
     #[arg(long)]
     #[arg(value_enum)]
@@ -1,6 +1,8 @@
 #![recursion_limit = "256"]
 
+use autopilot_worker::{AutopilotWorkerConfig, AutopilotWorkerHandle, spawn_autopilot_worker};
 use clap::Parser;
+use durable_tools::EmbeddedClient;
 use futures::{FutureExt, StreamExt};
 use mimalloc::MiMalloc;
 use secrecy::ExposeSecret;
@@ -10,11 +12,6 @@ use std::io::ErrorKind;
 use std::net::SocketAddr;
 use std::process::ExitCode;
 use std::time::Duration;
-use tensorzero_core::observability::request_logging::InFlightRequestsData;
-use tensorzero_signals::shutdown_signal;
-use tokio_stream::wrappers::IntervalStream;
-use autopilot_worker::{AutopilotWorkerConfig, AutopilotWorkerHandle, spawn_autopilot_worker};
-use durable_tools::EmbeddedClient;
 use tensorzero_auth::constants::{DEFAULT_ORGANIZATION, DEFAULT_WORKSPACE};
 use tensorzero_core::config::{Config, ConfigFileGlob};
 use tensorzero_core::db::clickhouse::migration_manager::manual_run_clickhouse_migrations;
@@ -24,11 +21,15 @@ use tensorzero_core::endpoints::status::TENSORZERO_VERSION;
 use tensorzero_core::error;
 use tensorzero_core::feature_flags;
 use tensorzero_core::observability;
+use tensorzero_core::observability::request_logging::InFlightRequestsData;
 use tensorzero_core::utils::gateway;
+use tensorzero_signals::shutdown_signal;
+use tokio_stream::wrappers::IntervalStream;
 
 mod cli;
 mod router;
 mod routes;
+mod tls;
 
 use cli::GatewayArgs;
 
@@ -129,6 +130,11 @@ async fn run() -> Result<(), ExitCode> {
 
     tracing::info!("Starting TensorZero Gateway {TENSORZERO_VERSION} (commit: {git_sha})");
 
+    if args.config_overlay.is_some() && args.config_file.is_none() {
+        tracing::error!("`--config-overlay` requires `--config-file` to also be specified.");
+        return Err(ExitCode::FAILURE);
+    }
+
     // Handle `--config-file` or `--default-config`
     let (unwritten_config, glob) = match (args.default_config, args.config_file) {
         (true, Some(_)) => {
@@ -153,6 +159,12 @@ async fn run() -> Result<(), ExitCode> {
         (false, Some(path)) => {
             let glob = ConfigFileGlob::new_from_path(&path)
                 .log_err_pretty("Failed to process config file glob")?;
+            let glob = if let Some(overlay_path) = args.config_overlay {
+                glob.with_overlay(&overlay_path.display().to_string())
+                    .log_err_pretty("Failed to process config overlay glob")?
+            } else {
+                glob
+            };
             (
 
                     Config::load_and_verify_from_path(&glob)
@@ -387,13 +399,32 @@ async fn run() -> Result<(), ExitCode> {
         shutdown_token_clone.cancel();
     });
 
-    let server_fut = axum::serve(listener, router)
-        .with_graceful_shutdown(shutdown_token.clone().cancelled_owned())
-        .into_future()
-        .map(|r| {
-            let _ = r.log_err_pretty("Failed to start server");
-        })
-        .shared();
+    let server_fut: futures::future::Shared<futures::future::BoxFuture<'static, ()>> =
+        if let Some(tls_config) = config.gateway.tls.clone() {
+            tracing::info!("TLS termination is enabled for the gateway listener");
+            let shutdown_token_for_tls = shutdown_token.clone();
+            async move {
+                if let Err(exit_code) =
+                    tls::serve_tls(listener, &tls_config, router, shutdown_token_for_tls).await
+                {
+                    tracing::error!("TLS server exited with {exit_code:?}");
+                }
+            }
+            .boxed()
+            .shared()
+        } else {
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(shutdown_token.clone().cancelled_owned())
+            .into_future()
+            .map(|r| {
+                let _ = r.log_err_pretty("Failed to start server");
+            })
+            .boxed()
+            .shared()
+        };
 
     // This is a purely informational logging task, so we don't need to wait for it to finish.
     #[expect(clippy::disallowed_methods)]
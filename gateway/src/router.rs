@@ -6,14 +6,16 @@
 use crate::routes::build_api_routes;
 use axum::{
     Router,
-    extract::{DefaultBodyLimit, Request},
+    body::{Body, to_bytes},
+    extract::{DefaultBodyLimit, MatchedPath, Request},
     middleware::{self, Next},
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use metrics_exporter_prometheus::PrometheusHandle;
 use std::sync::Arc;
 use tensorzero_auth::middleware::TensorzeroAuthMiddlewareStateInner;
 use tensorzero_core::endpoints::TensorzeroAuthMiddlewareState;
+use tensorzero_core::config::gateway::AccessPolicyConfig;
 use tensorzero_core::observability::TracerWrapper;
 use tensorzero_core::observability::request_logging::InFlightRequestsData;
 use tensorzero_core::{endpoints, utils::gateway::AppStateData};
@@ -51,6 +53,30 @@ pub fn build_axum_router(
         ));
     }
 
+    if app_state.config.gateway.access_policy.enabled {
+        // Applied after (so it wraps outside) authentication, so disallowed
+        // clients are rejected before we do any auth lookup work.
+        router = router.layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            ip_allowlist_middleware,
+        ));
+    }
+
+    if app_state
+        .config
+        .gateway
+        .mirroring
+        .as_ref()
+        .is_some_and(|mirroring| mirroring.enabled)
+    {
+        // Applied after (so it wraps outside) authentication and the IP allowlist,
+        // so we only mirror requests that were actually let through.
+        router = router.layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            mirroring_middleware,
+        ));
+    }
+
     let in_flight_requests_data =
         tensorzero_core::observability::request_logging::InFlightRequestsData::new();
     // Everything added from this point onwards does *NOT* have authentication applied - that is,
@@ -75,7 +101,129 @@ pub fn build_axum_router(
 /// We apply authentication to all routes *except* these ones, to make it difficult
 /// to accidentally skip running authentication on a route, especially if we later refactor
 /// how we build up our router.
-const UNAUTHENTICATED_ROUTES: &[&str] = &["/status", "/health", "/internal/autopilot/status"];
+///
+/// `/webhooks/{webhook_name}` is included here because it has its own authentication
+/// mechanism - an HMAC-SHA256 signature of the request body (see
+/// [`tensorzero_core::endpoints::webhooks`]) - since the caller is a third-party system
+/// that can't be issued a TensorZero API key.
+const UNAUTHENTICATED_ROUTES: &[&str] = &[
+    "/status",
+    "/health",
+    "/internal/autopilot/status",
+    "/webhooks/{webhook_name}",
+];
+
+/// Extracts the client IP for `gateway.access_policy` purposes.
+///
+/// By default, this is the TCP peer address of the connection (via
+/// `ConnectInfo`, wired up by [`crate::serve`]) - a value the client cannot
+/// spoof. If `gateway.access_policy.trust_x_forwarded_for` is set, the first
+/// entry of the `X-Forwarded-For` header is used instead, on the assumption
+/// that the immediate peer is a trusted reverse proxy that sets (and
+/// strips any client-supplied value of) that header.
+fn client_ip(policy: &AccessPolicyConfig, request: &Request) -> Option<std::net::IpAddr> {
+    if policy.trust_x_forwarded_for {
+        request
+            .headers()
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .and_then(|ip| ip.trim().parse().ok())
+    } else {
+        request
+            .extensions()
+            .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+            .map(|connect_info| connect_info.0.ip())
+    }
+}
+
+async fn ip_allowlist_middleware(
+    axum::extract::State(app_state): axum::extract::State<AppStateData>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // `route_overrides` is keyed by the route's Axum path pattern (e.g. `/internal/config`),
+    // not the concrete request path, so that a single override also covers parameterized
+    // routes like `/webhooks/{webhook_name}`. `MatchedPath` is only present once the router
+    // has matched a route, so fall back to the concrete path for anything that 404s.
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let policy = &app_state.config.gateway.access_policy;
+    match client_ip(policy, &request) {
+        Some(ip) if policy.is_allowed(&route, ip) => next.run(request).await,
+        Some(ip) => {
+            tracing::warn!("Rejected request from disallowed IP {ip} to {route}");
+            axum::http::StatusCode::FORBIDDEN.into_response()
+        }
+        None => {
+            tracing::warn!(
+                "Rejected request to {route}: no known client IP to check against `gateway.access_policy`"
+            );
+            axum::http::StatusCode::FORBIDDEN.into_response()
+        }
+    }
+}
+
+/// Maximum request body size we'll buffer in order to mirror it. Larger bodies
+/// are forwarded to the primary handler as normal, but are not mirrored.
+const MAX_MIRRORED_BODY_SIZE: usize = 10 * 1024 * 1024;
+
+/// Mirrors a sample of requests to `gateway.mirroring.staging_url`, per
+/// `MirroringConfig`. Mirroring is fire-and-forget: the mirrored request is
+/// sent from a background task, and its response (or any error) is discarded.
+/// This never delays or affects the response returned to the original caller.
+async fn mirroring_middleware(
+    axum::extract::State(app_state): axum::extract::State<AppStateData>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(mirroring) = app_state.config.gateway.mirroring.clone() else {
+        return next.run(request).await;
+    };
+    if !mirroring.should_mirror() {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let Ok(body_bytes) = to_bytes(body, MAX_MIRRORED_BODY_SIZE).await else {
+        tracing::warn!("Failed to buffer request body for mirroring, skipping mirror");
+        let request = Request::from_parts(parts, Body::empty());
+        return next.run(request).await;
+    };
+
+    let method = parts.method.clone();
+    let path_and_query = parts
+        .uri
+        .path_and_query()
+        .map(|pq| pq.as_str().to_string())
+        .unwrap_or_else(|| parts.uri.path().to_string());
+    let headers = parts.headers.clone();
+    let http_client = app_state.http_client.clone();
+    let staging_url = mirroring.staging_url.clone();
+    let mirrored_body = body_bytes.clone();
+
+    tokio::spawn(async move {
+        let Ok(url) = staging_url.join(&path_and_query) else {
+            tracing::warn!("Failed to construct mirror URL for path {path_and_query}");
+            return;
+        };
+        let result = http_client
+            .request(method, url)
+            .headers(headers)
+            .body(mirrored_body)
+            .send()
+            .await;
+        if let Err(err) = result {
+            tracing::warn!("Failed to mirror request to staging gateway: {err}");
+        }
+    });
+
+    let request = Request::from_parts(parts, Body::from(body_bytes));
+    next.run(request).await
+}
 
 async fn add_version_header(request: Request, next: Next) -> Response {
     #[cfg_attr(not(feature = "e2e_tests"), expect(unused_mut))]
@@ -32,6 +32,18 @@ pub fn build_otel_enabled_routes() -> (OtelEnabledRoutes, Router<AppStateData>)
             get(endpoints::batch_inference::poll_batch_inference_handler),
         ),
         ("/feedback", post(endpoints::feedback::feedback_handler)),
+        (
+            "/feedback/by_correlation_id",
+            post(endpoints::feedback::by_correlation_id::feedback_by_correlation_id_handler),
+        ),
+        (
+            "/episodes/{episode_id}/fork",
+            post(endpoints::episodes::fork::fork_episode_handler),
+        ),
+        (
+            "/webhooks/{webhook_name}",
+            post(endpoints::webhooks::webhook_handler),
+        ),
     ];
     routes.extend(build_openai_compatible_routes().routes);
     let mut router = Router::new();
@@ -71,6 +83,10 @@ fn build_observability_routes() -> Router<AppStateData> {
             "/v1/inferences/get_inferences",
             post(endpoints::stored_inferences::v1::get_inferences_handler),
         )
+        .route(
+            "/v1/inferences/search_inferences",
+            post(endpoints::stored_inferences::v1::search_inferences_handler),
+        )
 }
 
 /// This function builds the public routes for datasets.
@@ -123,6 +139,26 @@ fn build_datasets_routes() -> Router<AppStateData> {
             "/v1/datasets/{dataset_name}/list_datapoints",
             post(endpoints::datasets::v1::list_datapoints_handler),
         )
+        .route(
+            "/v1/datasets/{dataset_name}/split",
+            post(endpoints::datasets::v1::split_dataset_handler),
+        )
+        .route(
+            "/v1/datasets/{dataset_name}/deduplicate",
+            post(endpoints::datasets::v1::deduplicate_datapoints_handler),
+        )
+        .route(
+            "/v1/datasets/{dataset_name}/export",
+            post(endpoints::datasets::v1::export_dataset_handler),
+        )
+        .route(
+            "/v1/datasets/{dataset_name}/import",
+            post(endpoints::datasets::v1::import_dataset_handler),
+        )
+        .route(
+            "/v1/datasets/{dataset_name}/sync",
+            post(endpoints::datasets::v1::sync_dataset_handler),
+        )
         .route(
             "/v1/datasets/{dataset_name}",
             delete(endpoints::datasets::v1::delete_dataset_handler),
@@ -176,6 +212,11 @@ fn build_evaluations_routes() -> Router<AppStateData> {
             "/dynamic_evaluation_run/{run_id}/episode",
             post(endpoints::workflow_evaluation_run::dynamic_evaluation_run_episode_handler),
         )
+        // Non-streaming parity with the durable-tools `run_evaluation` action/SDK method.
+        .route(
+            "/evaluations/run",
+            post(super::run_evaluation::run_evaluation_handler),
+        )
 }
 
 /// This function builds the public routes for meta-observability (e.g. gateway health).
@@ -21,6 +21,10 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/functions/{function_name}/variant_sampling_probabilities",
             get(endpoints::variant_probabilities::get_variant_sampling_probabilities_by_function_handler),
         )
+        .route(
+            "/internal/functions/{function_name}/prompt_token_overhead",
+            get(endpoints::variant_prompt_overhead::get_variant_prompt_token_overhead_by_function_handler),
+        )
         .route(
             "/internal/functions/{function_name}/metrics",
             get(endpoints::functions::internal::get_function_metrics_handler),
@@ -29,6 +33,10 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/functions/{function_name}/variant_performances",
             get(endpoints::functions::internal::get_variant_performances_handler),
         )
+        .route(
+            "/internal/functions/{function_name}/segment_analysis",
+            get(endpoints::functions::internal::get_segment_analysis_handler),
+        )
         .route(
             "/internal/functions/inference_counts",
             get(endpoints::internal::inference_count::list_functions_with_inference_count_handler),
@@ -61,6 +69,10 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/feedback/timeseries",
             get(endpoints::feedback::internal::get_cumulative_feedback_timeseries_handler),
         )
+        .route(
+            "/internal/feedback/timeseries/bucketed",
+            get(endpoints::feedback::internal::get_feedback_timeseries_handler),
+        )
         .route(
             "/internal/feedback/{inference_id}/demonstrations",
             get(endpoints::feedback::internal::get_demonstration_feedback_handler),
@@ -93,6 +105,10 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/episodes/{episode_id}/inference_count",
             get(endpoints::episodes::internal::get_episode_inference_count_handler),
         )
+        .route(
+            "/internal/episodes/{episode_id}/cost",
+            get(endpoints::episodes::internal::get_episode_cost_handler),
+        )
         .route(
             "/internal/datasets/{dataset_name}/datapoints",
             #[expect(deprecated)]
@@ -136,6 +152,14 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/evaluations/runs",
             get(endpoints::internal::evaluations::list_evaluation_runs_handler),
         )
+        .route(
+            "/internal/evaluations/runs/{evaluation_run_id}",
+            get(endpoints::internal::evaluations::get_evaluation_run_handler),
+        )
+        .route(
+            "/internal/evaluations/runs/{evaluation_run_id}/compare",
+            get(endpoints::internal::evaluations::compare_evaluation_runs_handler),
+        )
         .route(
             "/internal/evaluations/runs/search",
             get(endpoints::internal::evaluations::search_evaluation_runs_handler),
@@ -229,6 +253,36 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/config/{hash}",
             get(endpoints::internal::config::get_config_by_hash_handler),
         )
+        .route(
+            "/internal/config/snapshots",
+            get(endpoints::internal::config::list_config_snapshots_handler),
+        )
+        .route(
+            "/internal/config/validate",
+            post(endpoints::internal::config::validate_config_handler),
+        )
+        .route(
+            "/internal/config/{hash}/tags",
+            post(endpoints::internal::config::update_snapshot_tags_handler),
+        )
+        .route(
+            "/internal/config/{hash}/diff",
+            get(endpoints::internal::config::diff_config_handler),
+        )
+        // Canary rollout endpoints
+        .route(
+            "/internal/config/{hash}/canary",
+            get(endpoints::internal::config::get_canary_status_handler)
+                .post(endpoints::internal::config::start_canary_handler),
+        )
+        .route(
+            "/internal/config/{hash}/canary/abort",
+            post(endpoints::internal::config::abort_canary_handler),
+        )
+        .route(
+            "/internal/config/{hash}/functions/{function_name}/variants/{variant_name}/retire",
+            post(endpoints::internal::config::retire_variant_handler),
+        )
         // Inference count endpoint
         .route(
             "/internal/inferences/count",
@@ -262,4 +316,61 @@ pub fn build_internal_non_otel_enabled_routes() -> Router<AppStateData> {
             "/internal/autopilot/status",
             get(endpoints::internal::autopilot::autopilot_status_handler),
         )
+        // Review queue / labeling workflow endpoints
+        .route(
+            "/internal/review_queue/tasks",
+            post(endpoints::internal::review_queue::create_review_tasks_handler),
+        )
+        .route(
+            "/internal/review_queue/tasks/{task_id}/assign",
+            post(endpoints::internal::review_queue::assign_review_task_handler),
+        )
+        .route(
+            "/internal/review_queue/tasks/{task_id}/label",
+            post(endpoints::internal::review_queue::submit_review_label_handler),
+        )
+        .route(
+            "/internal/review_queue/{metric_name}/summary",
+            get(endpoints::internal::review_queue::get_review_queue_summary_handler),
+        )
+        .route(
+            "/internal/review_queue/{metric_name}/tasks",
+            get(endpoints::internal::review_queue::list_review_tasks_handler),
+        )
+        // Unified job tracking endpoints (optimizations, evaluations, bulk inference, backfills)
+        .route(
+            "/internal/jobs",
+            get(endpoints::internal::jobs::list_jobs_handler),
+        )
+        .route(
+            "/internal/jobs/{job_id}",
+            get(endpoints::internal::jobs::get_job_handler),
+        )
+        .route(
+            "/internal/jobs/{job_id}/cancel",
+            post(endpoints::internal::jobs::cancel_job_handler),
+        )
+        // Cache inspection and invalidation endpoints
+        .route(
+            "/internal/cache/stats",
+            get(endpoints::internal::cache::get_cache_stats_handler),
+        )
+        .route(
+            "/internal/cache/invalidate",
+            post(endpoints::internal::cache::invalidate_cache_handler),
+        )
+        // Ollama local model management endpoints
+        .route(
+            "/internal/ollama/models",
+            get(endpoints::internal::ollama::list_local_models_handler),
+        )
+        .route(
+            "/internal/ollama/models/pull",
+            post(endpoints::internal::ollama::pull_model_handler),
+        )
+        // Gateway activity event stream (for dashboards)
+        .route(
+            "/internal/events/stream",
+            get(endpoints::internal::events::stream_events_handler),
+        )
 }
@@ -4,6 +4,7 @@ mod action;
 pub mod evaluations;
 mod external;
 mod internal;
+mod run_evaluation;
 
 use axum::Router;
 use metrics_exporter_prometheus::PrometheusHandle;
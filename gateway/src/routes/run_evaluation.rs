@@ -0,0 +1,39 @@
+//! Non-streaming evaluation endpoint for the TensorZero Gateway.
+//!
+//! This mirrors the `run_evaluation` action exposed by the durable-tools worker (see
+//! `durable_tools::action`) and by the embedded SDK client, so that HTTP gateway callers
+//! can get the same aggregated `RunEvaluationResponse` without going through a config
+//! snapshot or a durable-tools job. It is distinct from the SSE endpoint in
+//! `gateway/src/routes/evaluations.rs`, which streams per-datapoint events and takes an
+//! inline evaluation/function config rather than a config-defined evaluation name.
+
+use axum::extract::State;
+use axum::{Json, debug_handler};
+use durable_tools::run_evaluation::{
+    RunEvaluationError, RunEvaluationParams, RunEvaluationResponse, run_evaluation,
+};
+use tensorzero_core::error::{Error, ErrorDetails};
+use tensorzero_core::utils::gateway::{AppState, AppStateData, StructuredJson};
+use tracing::instrument;
+
+/// Handler for `POST /evaluations/run`
+///
+/// Runs an evaluation defined in the current config and returns aggregated results.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "run_evaluation", skip_all, fields(evaluation_name = %params.evaluation_name))]
+pub async fn run_evaluation_handler(
+    State(app_state): AppState,
+    StructuredJson(params): StructuredJson<RunEvaluationParams>,
+) -> Result<Json<RunEvaluationResponse>, Error> {
+    let response = run_evaluation(app_state, &params)
+        .await
+        .map_err(|e| match e {
+            RunEvaluationError::Validation(message) => {
+                Error::new(ErrorDetails::InvalidRequest { message })
+            }
+            RunEvaluationError::Runtime(message) => {
+                Error::new(ErrorDetails::EvaluationRun { message })
+            }
+        })?;
+    Ok(Json(response))
+}
@@ -0,0 +1,188 @@
+//! Native TLS termination for the gateway's own listener (see
+//! `gateway.tls` in `tensorzero.toml`), for deployments that don't sit
+//! behind a TLS-terminating proxy. Supports optional mutual TLS via a
+//! client CA bundle.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use axum::Router;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder as HyperBuilder;
+use hyper_util::service::TowerToHyperService;
+use rustls::crypto::ring::sign::any_supported_type;
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use rustls::{RootCertStore, ServerConfig as RustlsServerConfig};
+use tensorzero_core::config::gateway::TlsConfig;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::sync::CancellationToken;
+
+fn load_certs(path: &str) -> Result<Vec<rustls::pki_types::CertificateDer<'static>>, ExitCode> {
+    let file = fs::File::open(path).map_err(|e| {
+        tracing::error!("Failed to open TLS certificate file `{path}`: {e}");
+        ExitCode::FAILURE
+    })?;
+    rustls_pemfile::certs(&mut std::io::BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            tracing::error!("Failed to parse TLS certificate file `{path}`: {e}");
+            ExitCode::FAILURE
+        })
+}
+
+fn load_private_key(path: &str) -> Result<rustls::pki_types::PrivateKeyDer<'static>, ExitCode> {
+    let file = fs::File::open(path).map_err(|e| {
+        tracing::error!("Failed to open TLS private key file `{path}`: {e}");
+        ExitCode::FAILURE
+    })?;
+    rustls_pemfile::private_key(&mut std::io::BufReader::new(file))
+        .map_err(|e| {
+            tracing::error!("Failed to parse TLS private key file `{path}`: {e}");
+            ExitCode::FAILURE
+        })?
+        .ok_or_else(|| {
+            tracing::error!("No private key found in `{path}`");
+            ExitCode::FAILURE
+        })
+}
+
+fn load_certified_key(cert_path: &str, key_path: &str) -> Result<CertifiedKey, ExitCode> {
+    let certs = load_certs(cert_path)?;
+    let key = load_private_key(key_path)?;
+    let signing_key = any_supported_type(&key).map_err(|e| {
+        tracing::error!("Unsupported private key type in `{key_path}`: {e}");
+        ExitCode::FAILURE
+    })?;
+    Ok(CertifiedKey::new(certs, signing_key))
+}
+
+/// Selects which certificate to present based on the SNI hostname the
+/// client sends in its `ClientHello`, per `TlsConfig::sni_certs`. Falls back
+/// to `default` when the client doesn't send SNI, or sends a hostname not
+/// listed in `sni_certs`.
+struct SniCertResolver {
+    default: Arc<CertifiedKey>,
+    by_hostname: HashMap<String, Arc<CertifiedKey>>,
+}
+
+impl std::fmt::Debug for SniCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SniCertResolver")
+            .field("hostnames", &self.by_hostname.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ResolvesServerCert for SniCertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let resolved = client_hello
+            .server_name()
+            .and_then(|hostname| self.by_hostname.get(hostname));
+        Some(resolved.cloned().unwrap_or_else(|| self.default.clone()))
+    }
+}
+
+/// Builds the `rustls::ServerConfig` used to terminate TLS for the
+/// gateway's listener, including client-certificate verification when
+/// `tls.client_ca_path` is set and SNI-based certificate selection when
+/// `tls.sni_certs` is set.
+fn build_server_config(tls: &TlsConfig) -> Result<RustlsServerConfig, ExitCode> {
+    let default_key = load_certified_key(&tls.cert_path, &tls.key_path)?;
+    let mut by_hostname = HashMap::with_capacity(tls.sni_certs.len());
+    for (hostname, sni_cert) in &tls.sni_certs {
+        let certified_key = load_certified_key(&sni_cert.cert_path, &sni_cert.key_path)?;
+        by_hostname.insert(hostname.clone(), Arc::new(certified_key));
+    }
+    let cert_resolver = Arc::new(SniCertResolver {
+        default: Arc::new(default_key),
+        by_hostname,
+    });
+
+    let client_verifier = if let Some(client_ca_path) = &tls.client_ca_path {
+        let ca_certs = load_certs(client_ca_path)?;
+        let mut roots = RootCertStore::empty();
+        for cert in ca_certs {
+            roots.add(cert).map_err(|e| {
+                tracing::error!("Failed to load client CA certificate: {e}");
+                ExitCode::FAILURE
+            })?;
+        }
+        let mut builder = WebPkiClientVerifier::builder(Arc::new(roots));
+        if !tls.require_client_cert {
+            builder = builder.allow_unauthenticated();
+        }
+        builder.build().map_err(|e| {
+            tracing::error!("Failed to build client certificate verifier: {e}");
+            ExitCode::FAILURE
+        })?
+    } else {
+        rustls::server::WebPkiClientVerifier::no_client_auth()
+    };
+
+    Ok(RustlsServerConfig::builder()
+        .with_client_cert_verifier(client_verifier)
+        .with_cert_resolver(cert_resolver))
+}
+
+/// Accepts TCP connections on `listener`, terminates TLS according to
+/// `tls`, and serves `router` over each resulting connection. Mirrors the
+/// shape of `axum::serve(...).with_graceful_shutdown(...)`, but axum
+/// doesn't provide a built-in TLS listener, so we drive the accept loop
+/// ourselves.
+pub async fn serve_tls(
+    listener: TcpListener,
+    tls: &TlsConfig,
+    router: Router,
+    shutdown_token: CancellationToken,
+) -> Result<(), ExitCode> {
+    let server_config = build_server_config(tls)?;
+    let acceptor = TlsAcceptor::from(Arc::new(server_config));
+
+    loop {
+        let (tcp_stream, peer_addr) = tokio::select! {
+            () = shutdown_token.cancelled() => return Ok(()),
+            accept_result = listener.accept() => match accept_result {
+                Ok(pair) => pair,
+                Err(e) if e.kind() == ErrorKind::ConnectionAborted => continue,
+                Err(e) => {
+                    tracing::warn!("Failed to accept TCP connection: {e}");
+                    continue;
+                }
+            },
+        };
+
+        let acceptor = acceptor.clone();
+        let router = router.clone();
+        let shutdown_token = shutdown_token.clone();
+        #[expect(clippy::disallowed_methods)]
+        tokio::spawn(async move {
+            let tls_stream = match acceptor.accept(tcp_stream).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    tracing::warn!("TLS handshake with {peer_addr} failed: {e}");
+                    return;
+                }
+            };
+            // Mirrors what `into_make_service_with_connect_info` does for the non-TLS
+            // listener in `main.rs`, so `ConnectInfo<SocketAddr>` is available to
+            // extractors (e.g. the IP allowlist middleware) on this path too.
+            let router = router.layer(axum::Extension(axum::extract::ConnectInfo(peer_addr)));
+            let service = TowerToHyperService::new(router);
+            let conn = HyperBuilder::new(TokioExecutor::new())
+                .serve_connection_with_upgrades(TokioIo::new(tls_stream), service);
+            tokio::select! {
+                () = shutdown_token.cancelled() => {}
+                result = conn => {
+                    if let Err(e) = result {
+                        tracing::warn!("Error serving TLS connection from {peer_addr}: {e}");
+                    }
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,53 @@
+#![expect(clippy::unwrap_used)]
+use crate::common::start_gateway_on_random_port;
+
+mod common;
+
+/// `route_overrides` is keyed by the route's Axum path pattern (e.g.
+/// `/webhooks/{webhook_name}`), not the concrete request path. This test sets
+/// a global allowlist that would permit the test's own loopback address, but
+/// overrides the parameterized webhook route with a CIDR that excludes it -
+/// so a request to a concrete webhook path only gets rejected if the override
+/// is actually being looked up by the matched route pattern rather than the
+/// literal path.
+#[tokio::test]
+async fn test_access_policy_route_override_matches_parameterized_route() {
+    let child_data = start_gateway_on_random_port(
+        r#"
+        [gateway.access_policy]
+        enabled = true
+        allowed_cidrs = ["127.0.0.1/32", "::1/128"]
+
+        [gateway.access_policy.route_overrides]
+        "/webhooks/{webhook_name}" = ["10.0.0.0/8"]
+    "#,
+        None,
+    )
+    .await;
+
+    // Not overridden: falls back to `allowed_cidrs`, which permits loopback.
+    let health_response = reqwest::Client::new()
+        .get(format!("http://{}/health", child_data.addr))
+        .send()
+        .await
+        .unwrap();
+    assert!(
+        health_response.status().is_success(),
+        "requests to routes without an override should use `allowed_cidrs`"
+    );
+
+    // Overridden with a CIDR that excludes loopback: must be rejected even
+    // though `allowed_cidrs` alone would have permitted this request.
+    let webhook_response = reqwest::Client::new()
+        .post(format!("http://{}/webhooks/my_webhook", child_data.addr))
+        .body("{}")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        webhook_response.status(),
+        reqwest::StatusCode::FORBIDDEN,
+        "the route_overrides entry for `/webhooks/{{webhook_name}}` should apply to a concrete \
+         webhook path, not just the literal pattern string"
+    );
+}
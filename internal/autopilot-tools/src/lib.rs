@@ -12,6 +12,8 @@
 //!
 //! - `InferenceTool` - Calls TensorZero inference endpoint, optionally with a historical config snapshot
 //! - `FeedbackTool` - Submits feedback for inferences or episodes (comments, demonstrations, metrics)
+//! - `SubmitDemonstrationsTool` - Submits a batch of demonstrations, reporting each outcome independently
+//! - `ListDemonstrationsTool` - Lists demonstrations (inferences with a corrected output) with filtering and pagination
 //! - `CreateDatapointsTool` - Creates datapoints in a dataset
 //! - `CreateDatapointsFromInferencesTool` - Creates datapoints from existing inferences
 //! - `ListDatasetsTool` - Lists available datasets with metadata
@@ -19,11 +21,19 @@
 //! - `GetDatapointsTool` - Gets specific datapoints by ID
 //! - `UpdateDatapointsTool` - Updates existing datapoints
 //! - `DeleteDatapointsTool` - Deletes datapoints by ID
+//! - `CurateDatasetFromFailuresTool` - Curates a dataset from production inferences that look like failures
 //! - `LaunchOptimizationWorkflowTool` - Launches an optimization workflow (e.g., fine-tuning)
 //! - `GetLatestFeedbackByMetricTool` - Gets the latest feedback ID for each metric for a target
 //! - `GetFeedbackByVariantTool` - Gets feedback statistics (mean, variance, count) by variant for a function and metric
 //! - `RunEvaluationTool` - Runs an evaluation on a dataset and returns statistics
+//! - `CompareEvaluationRunsTool` - Compares two evaluation runs and flags significant regressions
 //! - `ListInferencesTool` - Lists inferences with filtering and pagination
+//! - `PromoteVariantTool` - Promotes a winning variant by writing a new config snapshot with an updated weight
+//! - `FreezeAsTestTool` - Freezes production inferences into a named golden regression suite
+//! - `RunGoldenSuiteTool` - Replays a golden regression suite live and reports per-datapoint diffs
+//! - `RunOptimizationPipelineTool` - Chains launch -> poll -> register -> evaluate for an optimization job
+//! - `RunHyperparameterSweepTool` - Fans out a grid/random hyperparameter search across optimization pipeline jobs and picks the best run
+//! - `RunReplayBackfillTool` - Replays historical inferences against a variant at a throttled, checkpointed rate for migration validation
 //!
 //! # Test Tools (e2e_tests feature)
 //!
@@ -138,6 +148,9 @@ pub async fn for_each_tool<V: ToolVisitor>(visitor: &V) -> Result<(), V::Error>
 
     // Feedback tool
     visitor.visit_simple_tool::<tools::FeedbackTool>().await?;
+    visitor
+        .visit_simple_tool::<tools::SubmitDemonstrationsTool>()
+        .await?;
 
     // Datapoint CRUD tools
     visitor
@@ -161,9 +174,21 @@ pub async fn for_each_tool<V: ToolVisitor>(visitor: &V) -> Result<(), V::Error>
     visitor
         .visit_simple_tool::<tools::DeleteDatapointsTool>()
         .await?;
+    visitor
+        .visit_simple_tool::<tools::CurateDatasetFromFailuresTool>()
+        .await?;
     visitor
         .visit_task_tool(tools::LaunchOptimizationWorkflowTool)
         .await?;
+    visitor
+        .visit_task_tool(tools::RunOptimizationPipelineTool)
+        .await?;
+    visitor
+        .visit_task_tool(tools::RunHyperparameterSweepTool)
+        .await?;
+    visitor
+        .visit_task_tool(tools::RunReplayBackfillTool)
+        .await?;
     visitor
         .visit_simple_tool::<tools::GetLatestFeedbackByMetricTool>()
         .await?;
@@ -175,12 +200,26 @@ pub async fn for_each_tool<V: ToolVisitor>(visitor: &V) -> Result<(), V::Error>
     visitor
         .visit_simple_tool::<tools::RunEvaluationTool>()
         .await?;
+    visitor
+        .visit_simple_tool::<tools::CompareEvaluationRunsTool>()
+        .await?;
+
+    // Golden-trace regression tools
+    visitor
+        .visit_simple_tool::<tools::FreezeAsTestTool>()
+        .await?;
+    visitor
+        .visit_simple_tool::<tools::RunGoldenSuiteTool>()
+        .await?;
 
     // Config snapshot tools
     visitor.visit_simple_tool::<tools::GetConfigTool>().await?;
     visitor
         .visit_simple_tool::<tools::WriteConfigTool>()
         .await?;
+    visitor
+        .visit_simple_tool::<tools::PromoteVariantTool>()
+        .await?;
 
     // Inference query tools
     visitor
@@ -189,6 +228,9 @@ pub async fn for_each_tool<V: ToolVisitor>(visitor: &V) -> Result<(), V::Error>
     visitor
         .visit_simple_tool::<tools::GetInferencesTool>()
         .await?;
+    visitor
+        .visit_simple_tool::<tools::ListDemonstrationsTool>()
+        .await?;
 
     // Test tools (e2e_tests feature)
     // ------------------------------
@@ -0,0 +1,110 @@
+//! Tool for comparing two evaluation runs and flagging significant regressions.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata, ToolResult};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::EvaluationRunComparison;
+use uuid::Uuid;
+
+use autopilot_client::AutopilotSideInfo;
+
+/// Parameters for the compare_evaluation_runs tool (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CompareEvaluationRunsToolParams {
+    /// The evaluation run to treat as the baseline.
+    pub run_a: Uuid,
+    /// The evaluation run to compare against the baseline.
+    pub run_b: Uuid,
+    /// The name of the evaluation both runs belong to.
+    pub evaluation_name: String,
+    /// The name of the function both runs evaluated.
+    pub function_name: String,
+}
+
+/// Tool for comparing two evaluation runs.
+///
+/// Computes per-evaluator deltas between `run_b` and `run_a`, paired on shared datapoint IDs
+/// where possible, and flags whether each delta is a significant regression given the metric's
+/// configured optimization direction. Useful for gating a config rollout on whether a new
+/// variant regresses any evaluator relative to the current one.
+#[derive(Default)]
+pub struct CompareEvaluationRunsTool;
+
+impl ToolMetadata for CompareEvaluationRunsTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = EvaluationRunComparison;
+    type LlmParams = CompareEvaluationRunsToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("compare_evaluation_runs")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Compare two evaluation runs of the same evaluation and function. Returns, per \
+             evaluator, the mean difference between run_b and run_a (paired on shared datapoint \
+             IDs where possible) and whether that difference is a significant regression given \
+             the metric's optimization direction.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Compare two evaluation runs and flag significant regressions.",
+            "properties": {
+                "run_a": {
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "The evaluation run to treat as the baseline."
+                },
+                "run_b": {
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "The evaluation run to compare against the baseline."
+                },
+                "evaluation_name": {
+                    "type": "string",
+                    "description": "The name of the evaluation both runs belong to."
+                },
+                "function_name": {
+                    "type": "string",
+                    "description": "The name of the function both runs evaluated."
+                }
+            },
+            "required": ["run_a", "run_b", "evaluation_name", "function_name"]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for CompareEvaluationRunsTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        _side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        ctx.client()
+            .compare_evaluation_runs(
+                llm_params.run_a,
+                llm_params.run_b,
+                llm_params.evaluation_name,
+                llm_params.function_name,
+            )
+            .await
+            .map_err(|e| AutopilotToolError::client_error("compare_evaluation_runs", e).into())
+    }
+}
@@ -0,0 +1,219 @@
+//! Tool for curating a dataset from production inferences that look like failures.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata, ToolResult};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{
+    CreateDatapointsFromInferenceRequestParams, DeduplicateDatapointsRequest,
+    DeduplicateDatapointsResponse, DeduplicationAction, DeduplicationStrategy,
+    ListInferencesRequest,
+};
+use uuid::Uuid;
+
+use autopilot_client::AutopilotSideInfo;
+
+/// Parameters for the curate_dataset_from_failures tool (visible to LLM).
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CurateDatasetFromFailuresToolParams {
+    /// The name of the dataset to add curated datapoints to.
+    pub dataset_name: String,
+    /// Query selecting the "failure" inferences to curate - e.g. a `float_metric`/`boolean_metric`
+    /// filter for a low feedback score, or a `tag` filter for a specific error tag. Combine
+    /// filters with `and`/`or` to narrow the query further.
+    #[serde(flatten)]
+    pub query: ListInferencesRequest,
+}
+
+/// Output of the curate_dataset_from_failures tool.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CurateDatasetFromFailuresToolOutput {
+    /// The IDs of the newly-created datapoints, before deduplication.
+    pub created_datapoint_ids: Vec<Uuid>,
+    /// The result of deduplicating the dataset after the new datapoints were added.
+    pub deduplication: DeduplicateDatapointsResponse,
+}
+
+/// Tool for sampling "hard" examples from production and curating them into a dataset.
+///
+/// Queries stored inferences matching `query` (typically a metric or tag filter identifying
+/// failures - e.g. a low feedback score or a specific error tag), creates datapoints from every
+/// match via `create_datapoints_from_inferences`, then deduplicates the dataset by exact input
+/// hash so repeatedly running this tool doesn't pile up redundant copies of the same failure.
+#[derive(Default)]
+pub struct CurateDatasetFromFailuresTool;
+
+impl ToolMetadata for CurateDatasetFromFailuresTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = CurateDatasetFromFailuresToolOutput;
+    type LlmParams = CurateDatasetFromFailuresToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("curate_dataset_from_failures")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Sample production inferences that look like failures (by low feedback score or a \
+             specific tag) into a dataset, deduplicating against what's already there. Useful \
+             for building a regression/eval dataset out of real failures.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Curate a dataset from production inferences that look like failures.",
+            "properties": {
+                "dataset_name": {
+                    "type": "string",
+                    "description": "The name of the dataset to add curated datapoints to."
+                },
+                "function_name": {
+                    "type": "string",
+                    "description": "Filter by function name (optional)."
+                },
+                "output_source": {
+                    "type": "string",
+                    "enum": ["none", "inference", "demonstration"],
+                    "description": "Source for the created datapoints' output: 'none' (input-only), 'inference' (original output, default), or 'demonstration' (use demonstration feedback)."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of failure inferences to curate (default: all matches)."
+                },
+                "filters": {
+                    "description": "Filter identifying the failures to curate. Use a 'float_metric'/'boolean_metric' filter for a low feedback score, or a 'tag' filter for a specific error tag. Combine with 'and'/'or'/'not' as needed.",
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "description": "Filter by float metric value (e.g. a feedback score below a threshold).",
+                            "properties": {
+                                "type": { "const": "float_metric" },
+                                "metric_name": { "type": "string", "description": "Name of the metric to filter by." },
+                                "value": { "type": "number", "description": "Value to compare against." },
+                                "comparison_operator": {
+                                    "type": "string",
+                                    "enum": ["<", "<=", "=", ">", ">=", "!="],
+                                    "description": "Comparison operator."
+                                }
+                            },
+                            "required": ["type", "metric_name", "value", "comparison_operator"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Filter by boolean metric value (e.g. a failure flag).",
+                            "properties": {
+                                "type": { "const": "boolean_metric" },
+                                "metric_name": { "type": "string", "description": "Name of the metric to filter by." },
+                                "value": { "type": "boolean", "description": "Value to compare against." }
+                            },
+                            "required": ["type", "metric_name", "value"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Filter by tag key-value pair (e.g. a specific error tag).",
+                            "properties": {
+                                "type": { "const": "tag" },
+                                "key": { "type": "string", "description": "Tag key." },
+                                "value": { "type": "string", "description": "Tag value." },
+                                "comparison_operator": {
+                                    "type": "string",
+                                    "enum": ["=", "!="],
+                                    "description": "Comparison operator."
+                                }
+                            },
+                            "required": ["type", "key", "value", "comparison_operator"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical AND of multiple filters.",
+                            "properties": {
+                                "type": { "const": "and" },
+                                "children": { "type": "array", "description": "Array of filters to AND together.", "items": { "type": "object" } }
+                            },
+                            "required": ["type", "children"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical OR of multiple filters.",
+                            "properties": {
+                                "type": { "const": "or" },
+                                "children": { "type": "array", "description": "Array of filters to OR together.", "items": { "type": "object" } }
+                            },
+                            "required": ["type", "children"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical NOT of a filter.",
+                            "properties": {
+                                "type": { "const": "not" },
+                                "child": { "type": "object", "description": "Filter to negate." }
+                            },
+                            "required": ["type", "child"]
+                        }
+                    ]
+                }
+            },
+            "required": ["dataset_name", "filters"],
+            "additionalProperties": false
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for CurateDatasetFromFailuresTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        _side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let function_name = llm_params.query.function_name.clone();
+
+        let created = ctx
+            .client()
+            .create_datapoints_from_inferences(
+                llm_params.dataset_name.clone(),
+                CreateDatapointsFromInferenceRequestParams::InferenceQuery {
+                    query: Box::new(llm_params.query),
+                },
+            )
+            .await
+            .map_err(|e| {
+                AutopilotToolError::client_error("create_datapoints_from_inferences", e)
+            })?;
+
+        // Exact-hash deduplication (rather than embedding similarity) since this tool has no
+        // embeddings on hand, and re-running it over time on an overlapping query is the main
+        // case we want cheap protection against.
+        let deduplication = ctx
+            .client()
+            .deduplicate_datapoints(
+                llm_params.dataset_name,
+                DeduplicateDatapointsRequest {
+                    strategy: DeduplicationStrategy::ExactHash,
+                    action: DeduplicationAction::Tag,
+                    function_name,
+                },
+            )
+            .await
+            .map_err(|e| AutopilotToolError::client_error("deduplicate_datapoints", e))?;
+
+        Ok(CurateDatasetFromFailuresToolOutput {
+            created_datapoint_ids: created.ids,
+            deduplication,
+        })
+    }
+}
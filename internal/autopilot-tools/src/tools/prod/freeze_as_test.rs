@@ -0,0 +1,117 @@
+//! Tool for freezing production inferences into a golden regression suite.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata, ToolResult};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{
+    CreateDatapointsFromInferenceRequestParams, CreateDatapointsResponse, InferenceOutputSource,
+};
+use uuid::Uuid;
+
+use autopilot_client::AutopilotSideInfo;
+
+/// Parameters for the freeze_as_test tool (visible to LLM).
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FreezeAsTestToolParams {
+    /// Name of the golden suite to freeze these inferences into. Doubles as the dataset name -
+    /// `run_golden_suite` replays whatever dataset carries this name.
+    pub name: String,
+    /// The inference IDs to snapshot as golden traces.
+    pub inference_ids: Vec<Uuid>,
+    /// Source for each datapoint's reference output: `none` (input-only), `inference` (the
+    /// inference's own output, default - the recorded "golden" trace), or `demonstration` (use
+    /// demonstration feedback instead).
+    #[serde(default)]
+    pub output_source: Option<InferenceOutputSource>,
+}
+
+/// Tool for freezing production inferences into a golden regression suite.
+///
+/// Snapshots each inference's input and output into a datapoint via
+/// `create_datapoints_from_inferences`, using `name` as both the golden suite's identity and its
+/// backing dataset name. The frozen output becomes the reference trace that `run_golden_suite`
+/// later diffs live replays against.
+///
+/// This does not separately capture the inference's config hash or feedback scores as datapoint
+/// fields - datapoints have no such fields in this codebase. The frozen output itself is the
+/// snapshot: it already reflects whatever config produced it at freeze time.
+#[derive(Default)]
+pub struct FreezeAsTestTool;
+
+impl ToolMetadata for FreezeAsTestTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = CreateDatapointsResponse;
+    type LlmParams = FreezeAsTestToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("freeze_as_test")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Freeze a set of production inferences into a named golden regression suite, \
+             snapshotting each one's input and output as a datapoint. Replay the suite later \
+             with run_golden_suite.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Freeze inferences into a golden regression suite.",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Name of the golden suite (and its backing dataset)."
+                },
+                "inference_ids": {
+                    "type": "array",
+                    "items": { "type": "string", "format": "uuid" },
+                    "description": "The inference IDs to snapshot as golden traces."
+                },
+                "output_source": {
+                    "type": "string",
+                    "enum": ["none", "inference", "demonstration"],
+                    "description": "Source for each datapoint's reference output: 'none' (input-only), 'inference' (original output, default), or 'demonstration' (use demonstration feedback)."
+                }
+            },
+            "required": ["name", "inference_ids"],
+            "additionalProperties": false
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for FreezeAsTestTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        _side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        ctx.client()
+            .create_datapoints_from_inferences(
+                llm_params.name,
+                CreateDatapointsFromInferenceRequestParams::InferenceIds {
+                    inference_ids: llm_params.inference_ids,
+                    output_source: llm_params.output_source,
+                },
+            )
+            .await
+            .map_err(|e| {
+                AutopilotToolError::client_error("create_datapoints_from_inferences", e).into()
+            })
+    }
+}
@@ -427,6 +427,7 @@ impl TaskTool for LaunchOptimizationWorkflowTool {
                     offset: params.offset,
                     val_fraction: params.val_fraction,
                     optimizer_config: params.optimizer_config,
+                    contamination_check: None,
                 };
 
                 state
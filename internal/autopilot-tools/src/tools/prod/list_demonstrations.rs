@@ -0,0 +1,199 @@
+//! Tool for listing demonstrations (corrected outputs) with filtering and pagination.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata, ToolResult};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{GetInferencesResponse, ListInferencesRequest};
+
+use autopilot_client::AutopilotSideInfo;
+
+/// Parameters for the list_demonstrations tool (visible to LLM).
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ListDemonstrationsToolParams {
+    /// Request parameters for listing demonstrations (filtering, pagination, ordering).
+    /// `output_source` is always forced to `demonstration` and inferences without a
+    /// demonstration are always excluded, regardless of what's set here.
+    #[serde(flatten)]
+    pub request: ListInferencesRequest,
+}
+
+/// Tool for listing demonstrations (inferences with a corrected/human-provided output).
+///
+/// A thin wrapper over `list_inferences` that only returns inferences with demonstration
+/// feedback, with the demonstration as the returned output - useful for surveying available
+/// corrections before assembling them into a fine-tuning dataset with
+/// `create_datapoints_from_inferences` (using `output_source: demonstration`) or submitting
+/// new ones with `submit_demonstrations`.
+#[derive(Default)]
+pub struct ListDemonstrationsTool;
+
+impl ToolMetadata for ListDemonstrationsTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = GetInferencesResponse;
+    type LlmParams = ListDemonstrationsToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("list_demonstrations")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "List demonstrations (inferences that have a corrected/human-provided output). \
+             Can filter by function name, variant name, episode ID, tags, metrics, and time \
+             ranges, and order results. Returns the demonstration value as each result's output.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "List demonstrations with filtering and pagination.",
+            "properties": {
+                "function_name": {
+                    "type": "string",
+                    "description": "Filter by function name (optional)."
+                },
+                "variant_name": {
+                    "type": "string",
+                    "description": "Filter by variant name (optional)."
+                },
+                "episode_id": {
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "Filter by episode ID (optional)."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of demonstrations to return (default: 20)."
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Number of demonstrations to skip (for pagination). Cannot be used with 'before' or 'after'."
+                },
+                "before": {
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "Inference ID to paginate before (exclusive). Returns demonstrations earlier in time. Cannot be used with 'after' or 'offset'."
+                },
+                "after": {
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "Inference ID to paginate after (exclusive). Returns demonstrations later in time. Cannot be used with 'before' or 'offset'."
+                },
+                "filters": {
+                    "description": "Optional additional filter to narrow down which demonstrations are returned. Supports filtering by metrics, tags, time, and logical combinations (AND/OR/NOT).",
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "description": "Filter by float metric value.",
+                            "properties": {
+                                "type": { "const": "float_metric" },
+                                "metric_name": { "type": "string", "description": "Name of the metric to filter by." },
+                                "value": { "type": "number", "description": "Value to compare against." },
+                                "comparison_operator": {
+                                    "type": "string",
+                                    "enum": ["<", "<=", "=", ">", ">=", "!="],
+                                    "description": "Comparison operator."
+                                }
+                            },
+                            "required": ["type", "metric_name", "value", "comparison_operator"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Filter by boolean metric value.",
+                            "properties": {
+                                "type": { "const": "boolean_metric" },
+                                "metric_name": { "type": "string", "description": "Name of the metric to filter by." },
+                                "value": { "type": "boolean", "description": "Value to compare against." }
+                            },
+                            "required": ["type", "metric_name", "value"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Filter by tag key-value pair.",
+                            "properties": {
+                                "type": { "const": "tag" },
+                                "key": { "type": "string", "description": "Tag key." },
+                                "value": { "type": "string", "description": "Tag value." },
+                                "comparison_operator": {
+                                    "type": "string",
+                                    "enum": ["=", "!="],
+                                    "description": "Comparison operator."
+                                }
+                            },
+                            "required": ["type", "key", "value", "comparison_operator"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Filter by timestamp.",
+                            "properties": {
+                                "type": { "const": "time" },
+                                "time": { "type": "string", "format": "date-time", "description": "Timestamp to compare against (ISO 8601 format)." },
+                                "comparison_operator": {
+                                    "type": "string",
+                                    "enum": ["<", "<=", "=", ">", ">=", "!="],
+                                    "description": "Comparison operator."
+                                }
+                            },
+                            "required": ["type", "time", "comparison_operator"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical AND of multiple filters.",
+                            "properties": {
+                                "type": { "const": "and" },
+                                "children": { "type": "array", "description": "Array of filters to AND together.", "items": { "type": "object" } }
+                            },
+                            "required": ["type", "children"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical OR of multiple filters.",
+                            "properties": {
+                                "type": { "const": "or" },
+                                "children": { "type": "array", "description": "Array of filters to OR together.", "items": { "type": "object" } }
+                            },
+                            "required": ["type", "children"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Logical NOT of a filter.",
+                            "properties": {
+                                "type": { "const": "not" },
+                                "child": { "type": "object", "description": "Filter to negate." }
+                            },
+                            "required": ["type", "child"]
+                        }
+                    ]
+                }
+            }
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for ListDemonstrationsTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        _side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        ctx.client()
+            .list_demonstrations(llm_params.request)
+            .await
+            .map_err(|e| AutopilotToolError::client_error("list_demonstrations", e).into())
+    }
+}
@@ -4,10 +4,13 @@
 //! to perform actions like inference, feedback, and other operations.
 
 mod auto_reject_tool_call;
+mod compare_evaluation_runs;
 mod create_datapoints;
 mod create_datapoints_from_inferences;
+mod curate_dataset_from_failures;
 mod delete_datapoints;
 mod feedback;
+mod freeze_as_test;
 mod get_config;
 mod get_datapoints;
 mod get_feedback_by_variant;
@@ -17,18 +20,31 @@ mod inference;
 mod launch_optimization_workflow;
 mod list_datapoints;
 mod list_datasets;
+mod list_demonstrations;
 mod list_inferences;
+mod promote_variant;
 mod run_evaluation;
+mod run_golden_suite;
+mod run_hyperparameter_sweep;
+mod run_optimization_pipeline;
+mod run_replay_backfill;
+mod submit_demonstrations;
 mod update_datapoints;
 mod write_config;
 
 pub use auto_reject_tool_call::AutoRejectToolCallTool;
+pub use compare_evaluation_runs::{CompareEvaluationRunsTool, CompareEvaluationRunsToolParams};
 pub use create_datapoints::{CreateDatapointsTool, CreateDatapointsToolParams};
 pub use create_datapoints_from_inferences::{
     CreateDatapointsFromInferencesTool, CreateDatapointsFromInferencesToolParams,
 };
+pub use curate_dataset_from_failures::{
+    CurateDatasetFromFailuresTool, CurateDatasetFromFailuresToolOutput,
+    CurateDatasetFromFailuresToolParams,
+};
 pub use delete_datapoints::{DeleteDatapointsTool, DeleteDatapointsToolParams};
 pub use feedback::{FeedbackTool, FeedbackToolParams};
+pub use freeze_as_test::{FreezeAsTestTool, FreezeAsTestToolParams};
 pub use get_config::{GetConfigTool, GetConfigToolParams};
 pub use get_datapoints::{GetDatapointsTool, GetDatapointsToolParams};
 pub use get_feedback_by_variant::{GetFeedbackByVariantTool, GetFeedbackByVariantToolParams};
@@ -43,7 +59,25 @@ pub use launch_optimization_workflow::{
 };
 pub use list_datapoints::{ListDatapointsTool, ListDatapointsToolParams};
 pub use list_datasets::{ListDatasetsTool, ListDatasetsToolParams};
+pub use list_demonstrations::{ListDemonstrationsTool, ListDemonstrationsToolParams};
 pub use list_inferences::{ListInferencesTool, ListInferencesToolParams};
+pub use promote_variant::{PromoteVariantTool, PromoteVariantToolOutput, PromoteVariantToolParams};
 pub use run_evaluation::{RunEvaluationTool, RunEvaluationToolParams};
+pub use run_golden_suite::{RunGoldenSuiteTool, RunGoldenSuiteToolParams};
+pub use run_hyperparameter_sweep::{
+    HyperparameterSweepJobResult, RunHyperparameterSweepTool, RunHyperparameterSweepToolOutput,
+    RunHyperparameterSweepToolParams,
+};
+pub use run_optimization_pipeline::{
+    RunOptimizationPipelineTool, RunOptimizationPipelineToolOutput,
+    RunOptimizationPipelineToolParams,
+};
+pub use run_replay_backfill::{
+    BackfillReplayFailure, OffPeakWindow, RunReplayBackfillTool, RunReplayBackfillToolOutput,
+    RunReplayBackfillToolParams,
+};
+pub use submit_demonstrations::{
+    SubmitDemonstrationsTool, SubmitDemonstrationsToolItem, SubmitDemonstrationsToolParams,
+};
 pub use update_datapoints::{UpdateDatapointsTool, UpdateDatapointsToolParams};
 pub use write_config::{WriteConfigTool, WriteConfigToolParams};
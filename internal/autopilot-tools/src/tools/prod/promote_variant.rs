@@ -0,0 +1,209 @@
+//! Tool for promoting a winning variant by writing a new config snapshot with an updated weight.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata, ToolResult};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{GetConfigResponse, WriteConfigRequest, WriteConfigResponse};
+use tensorzero_core::config::{UninitializedFunctionConfig, UninitializedVariantConfig};
+
+use autopilot_client::AutopilotSideInfo;
+
+/// Parameters for the promote_variant tool (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PromoteVariantToolParams {
+    /// The name of the function whose variant weights should be updated.
+    pub function_name: String,
+    /// The name of the winning variant to promote.
+    pub winning_variant: String,
+    /// The weight to assign to the winning variant (e.g. `0.2` to ramp it up to 20% of traffic).
+    pub new_weight: f64,
+    /// The config snapshot hash to base the promotion on. Defaults to the current live config.
+    #[serde(default)]
+    pub base_config_snapshot_hash: Option<String>,
+}
+
+/// Output of the promote_variant tool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PromoteVariantToolOutput {
+    /// The hash of the newly written config snapshot.
+    pub hash: String,
+    /// The weight the winning variant had before promotion (if it was set).
+    pub previous_weight: Option<f64>,
+    /// The weight the winning variant now has.
+    pub new_weight: f64,
+}
+
+/// Tool for promoting a variant that won a top-k evaluation comparison.
+///
+/// Reads a config snapshot (the current live one, or a specific one by hash), sets the winning
+/// variant's weight to `new_weight`, and writes the result as a new config snapshot via
+/// `write_config`. The promotion (function, variant, previous and new weight, and the config it
+/// was based on) is recorded as tags on the written snapshot, alongside the usual autopilot tags,
+/// so it can be audited later.
+#[derive(Default)]
+pub struct PromoteVariantTool;
+
+impl ToolMetadata for PromoteVariantTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = PromoteVariantToolOutput;
+    type LlmParams = PromoteVariantToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("promote_variant")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Promote a winning variant by writing a new config snapshot that sets its weight, \
+             e.g. to shift traffic to it after it wins a top-k evaluation comparison. Records \
+             the promotion as tags on the written snapshot for auditability.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Promote a winning variant by updating its weight in a new config snapshot.",
+            "properties": {
+                "function_name": {
+                    "type": "string",
+                    "description": "The name of the function whose variant weights should be updated."
+                },
+                "winning_variant": {
+                    "type": "string",
+                    "description": "The name of the winning variant to promote."
+                },
+                "new_weight": {
+                    "type": "number",
+                    "description": "The weight to assign to the winning variant (e.g. 0.2 to ramp it up to 20% of traffic)."
+                },
+                "base_config_snapshot_hash": {
+                    "type": "string",
+                    "description": "The config snapshot hash to base the promotion on. Defaults to the current live config."
+                }
+            },
+            "required": ["function_name", "winning_variant", "new_weight"]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+/// Returns a mutable reference to the `weight` field of a variant config, regardless of variant
+/// type. `ChainOfThought` flattens a `ChatCompletion` config, so it delegates to that.
+fn variant_weight_mut(variant: &mut UninitializedVariantConfig) -> &mut Option<f64> {
+    match variant {
+        UninitializedVariantConfig::ChatCompletion(config) => &mut config.weight,
+        UninitializedVariantConfig::BestOfNSampling(config) => &mut config.weight,
+        UninitializedVariantConfig::Dicl(config) => &mut config.weight,
+        UninitializedVariantConfig::MixtureOfN(config) => &mut config.weight,
+        UninitializedVariantConfig::ChainOfThought(config) => &mut config.inner.weight,
+        UninitializedVariantConfig::FallbackChain(config) => &mut config.weight,
+    }
+}
+
+#[async_trait]
+impl SimpleTool for PromoteVariantTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let base_hash = llm_params
+            .base_config_snapshot_hash
+            .clone()
+            .unwrap_or_else(|| side_info.config_snapshot_hash.clone());
+
+        let GetConfigResponse {
+            mut config,
+            extra_templates,
+            ..
+        } = ctx
+            .client()
+            .get_config_snapshot(Some(base_hash.clone()))
+            .await
+            .map_err(|e| AutopilotToolError::client_error("get_config_snapshot", e))?;
+
+        let function = config
+            .functions
+            .get_mut(&llm_params.function_name)
+            .ok_or_else(|| {
+                AutopilotToolError::validation(format!(
+                    "Unknown function: {}",
+                    llm_params.function_name
+                ))
+            })?;
+        let variants = match function {
+            UninitializedFunctionConfig::Chat(chat_config) => &mut chat_config.variants,
+            UninitializedFunctionConfig::Json(json_config) => &mut json_config.variants,
+        };
+        let variant = variants
+            .get_mut(&llm_params.winning_variant)
+            .ok_or_else(|| {
+                AutopilotToolError::validation(format!(
+                    "Unknown variant `{}` for function `{}`",
+                    llm_params.winning_variant, llm_params.function_name
+                ))
+            })?;
+
+        let weight = variant_weight_mut(&mut variant.inner);
+        let previous_weight = *weight;
+        *weight = Some(llm_params.new_weight);
+
+        let mut tags = side_info.to_tags();
+        tags.insert(
+            "tensorzero::autopilot::promote_variant::function_name".to_string(),
+            llm_params.function_name,
+        );
+        tags.insert(
+            "tensorzero::autopilot::promote_variant::winning_variant".to_string(),
+            llm_params.winning_variant,
+        );
+        tags.insert(
+            "tensorzero::autopilot::promote_variant::base_config_snapshot_hash".to_string(),
+            base_hash,
+        );
+        tags.insert(
+            "tensorzero::autopilot::promote_variant::new_weight".to_string(),
+            llm_params.new_weight.to_string(),
+        );
+        if let Some(previous_weight) = previous_weight {
+            tags.insert(
+                "tensorzero::autopilot::promote_variant::previous_weight".to_string(),
+                previous_weight.to_string(),
+            );
+        }
+
+        let request = WriteConfigRequest {
+            config,
+            extra_templates,
+            tags,
+        };
+
+        let WriteConfigResponse {
+            hash,
+            policy_violations: _,
+        } = ctx
+            .client()
+            .write_config(request)
+            .await
+            .map_err(|e| AutopilotToolError::client_error("write_config", e))?;
+
+        Ok(PromoteVariantToolOutput {
+            hash,
+            previous_weight,
+            new_weight: llm_params.new_weight,
+        })
+    }
+}
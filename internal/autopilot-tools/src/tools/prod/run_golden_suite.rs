@@ -0,0 +1,125 @@
+//! Tool for replaying a golden regression suite and reporting per-datapoint diffs.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use durable_tools::{
+    CacheEnabledMode, NonControlToolError, RunEvaluationParams, RunEvaluationResponse, SimpleTool,
+    SimpleToolContext, ToolMetadata, ToolResult,
+};
+
+use crate::error::AutopilotToolError;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+
+use autopilot_client::AutopilotSideInfo;
+
+fn default_concurrency() -> usize {
+    10
+}
+
+/// Parameters for the run_golden_suite tool (visible to LLM).
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RunGoldenSuiteToolParams {
+    /// Name of the golden suite to replay - must match the dataset name a prior `freeze_as_test`
+    /// call used, and must have a matching `[evaluations.<name>]` entry in config comparing live
+    /// output against the frozen reference (e.g. via `exact_match` or `tool_call_correctness`).
+    pub name: String,
+    /// Name of the variant to replay the suite against.
+    pub variant_name: String,
+    /// Number of concurrent inference requests (default: 10).
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+}
+
+/// Tool for replaying a golden regression suite and reporting diffs against the frozen traces.
+///
+/// Runs the suite's dataset (named `name`, as produced by `freeze_as_test`) through the
+/// evaluation config of the same name, live against `variant_name`. Each datapoint's frozen
+/// output serves as the reference, so this is a live replay rather than a separate
+/// record/replay mode - the "recording" already happened at freeze time.
+///
+/// Evaluations in this codebase are config-defined only; there is no runtime API to create
+/// one. `run_golden_suite` therefore requires a `[evaluations.<name>]` entry to already exist -
+/// it does not create it. Gating a rollout on the diff (the "regression gate" from the request)
+/// is done by feeding this run's ID and a prior baseline run's ID into `compare_evaluation_runs`,
+/// rather than by a bespoke gate mechanism here.
+#[derive(Default)]
+pub struct RunGoldenSuiteTool;
+
+impl ToolMetadata for RunGoldenSuiteTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = RunEvaluationResponse;
+    type LlmParams = RunGoldenSuiteToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("run_golden_suite")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Replay a golden regression suite (created with freeze_as_test) live against a \
+             variant and report per-datapoint diffs against the frozen reference output. \
+             Requires a matching [evaluations.<name>] config entry.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Replay a golden regression suite and report diffs.",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Name of the golden suite to replay (its dataset and evaluation config share this name)."
+                },
+                "variant_name": {
+                    "type": "string",
+                    "description": "Name of the variant to replay the suite against."
+                },
+                "concurrency": {
+                    "type": "integer",
+                    "description": "Number of concurrent inference requests (default: 10)."
+                }
+            },
+            "required": ["name", "variant_name"],
+            "additionalProperties": false
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for RunGoldenSuiteTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let params = RunEvaluationParams {
+            evaluation_name: llm_params.name.clone(),
+            dataset_name: Some(llm_params.name),
+            datapoint_ids: None,
+            variant_name: llm_params.variant_name,
+            concurrency: llm_params.concurrency,
+            inference_cache: CacheEnabledMode::On,
+            max_datapoints: None,
+            precision_targets: HashMap::new(),
+            include_datapoint_results: true,
+            tags: side_info.to_tags(),
+        };
+
+        ctx.client()
+            .run_evaluation(params)
+            .await
+            .map_err(|e| AutopilotToolError::client_error("run_evaluation", e).into())
+    }
+}
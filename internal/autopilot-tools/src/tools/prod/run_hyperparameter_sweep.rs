@@ -0,0 +1,385 @@
+//! Tool for running a hyperparameter sweep over an optimization job.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use durable_tools::{
+    NonControlToolError, RunOptimizationPipelineResult, SpawnOptions, TaskTool, ToolContext,
+    ToolMetadata, ToolResult,
+};
+
+use crate::error::AutopilotToolError;
+
+use autopilot_client::AutopilotSideInfo;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero_core::db::inferences::InferenceOutputSource;
+use tensorzero_core::endpoints::stored_inferences::v1::types::{InferenceFilter, OrderBy};
+use tensorzero_core::optimization::UninitializedOptimizerInfo;
+use tensorzero_optimizers::hyperparameter_sweep::{
+    HyperparameterSweepSpec, expand_hyperparameter_sweep,
+};
+
+use super::run_optimization_pipeline::{
+    RunOptimizationPipelineToolOutput, RunOptimizationPipelineToolParams,
+};
+
+/// Parameters for the run_hyperparameter_sweep tool (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RunHyperparameterSweepToolParams {
+    /// The function name to optimize.
+    pub function_name: String,
+    /// The variant name to use as a template for rendering inferences, and (for optimizers that
+    /// produce a bare fine-tuned model rather than a variant) as the template whose prompts each
+    /// registered variant will reuse.
+    pub template_variant_name: String,
+    /// Optional variant name to filter inferences by (defaults to all variants).
+    #[serde(default)]
+    pub query_variant_name: Option<String>,
+    /// Optional filters to apply when querying inferences.
+    #[serde(default)]
+    pub filters: Option<InferenceFilter>,
+    /// Source of the output data (inference output, demonstration, etc.).
+    pub output_source: InferenceOutputSource,
+    /// Optional ordering for the inferences.
+    #[serde(default)]
+    pub order_by: Option<Vec<OrderBy>>,
+    /// Maximum number of inferences to use.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Offset for pagination.
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Fraction of data to use for validation (0.0 to 1.0, exclusive).
+    #[serde(default)]
+    pub val_fraction: Option<f64>,
+    /// The base optimizer configuration that `hyperparameter_sweep` overrides per job.
+    pub optimizer_config: UninitializedOptimizerInfo,
+    /// The grid or random search to run over `optimizer_config`'s hyperparameters.
+    pub hyperparameter_sweep: HyperparameterSweepSpec,
+    /// Maximum number of sweep jobs to run concurrently through the durable queue.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+    /// The config snapshot to register each job's output into. Defaults to the current live
+    /// config.
+    #[serde(default)]
+    pub base_config_snapshot_hash: Option<String>,
+    /// Prefix used to name each job's registered variant: job `i` is named `{prefix}_{i}`.
+    pub variant_name_prefix: String,
+    /// Name of the evaluation to run against the holdout dataset (must be defined in config).
+    pub evaluation_name: String,
+    /// Name of the holdout dataset to evaluate each job's registered variant(s) on.
+    pub holdout_dataset_name: String,
+    /// Name of the evaluator (from `evaluation_name`) whose mean score picks the best run.
+    pub evaluator_name: String,
+}
+
+fn default_max_concurrency() -> usize {
+    4
+}
+
+/// The outcome of a single sweep job.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HyperparameterSweepJobResult {
+    /// The variant name this job registered its output under.
+    pub variant_name: String,
+    /// The concrete optimizer config this job ran with, after applying its sweep combination.
+    pub optimizer_config: UninitializedOptimizerInfo,
+    /// The underlying optimization pipeline's result.
+    pub result: RunOptimizationPipelineResult,
+    /// The mean score of `evaluator_name` on the holdout dataset, if the job completed and that
+    /// evaluator reported a score for its registered variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+}
+
+/// Response from the run_hyperparameter_sweep tool.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunHyperparameterSweepToolOutput {
+    pub jobs: Vec<HyperparameterSweepJobResult>,
+    /// Index into `jobs` of the best-scoring completed run, if any job completed and reported a
+    /// score for `evaluator_name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_job_index: Option<usize>,
+}
+
+/// Tool for sweeping an optimizer's hyperparameters: expands a grid or random search spec into
+/// one `optimizer_config` per combination, fans the resulting jobs out through the durable queue
+/// (each running the full launch -> poll -> register -> evaluate pipeline), and aggregates their
+/// statuses and eval scores to identify the best run.
+///
+/// Jobs are spawned in batches of `max_concurrency` via `run_optimization_pipeline`, joining each
+/// batch before spawning the next, since `ToolContext` has no built-in concurrency limiter.
+#[derive(Default)]
+pub struct RunHyperparameterSweepTool;
+
+impl ToolMetadata for RunHyperparameterSweepTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = RunHyperparameterSweepToolOutput;
+    type LlmParams = RunHyperparameterSweepToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("run_hyperparameter_sweep")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Sweep an optimizer's hyperparameters with a grid or random search, running each \
+             combination as a full optimization pipeline job (launch, poll, register, evaluate) \
+             with a concurrency cap, and report the best-scoring run.",
+        )
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(default_max_wait_secs())
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Run a hyperparameter sweep over an optimizer configuration.",
+            "properties": {
+                "function_name": {
+                    "type": "string",
+                    "description": "The function name to optimize."
+                },
+                "template_variant_name": {
+                    "type": "string",
+                    "description": "The variant name to use as a template for rendering inferences."
+                },
+                "query_variant_name": {
+                    "type": "string",
+                    "description": "Optional variant name to filter inferences by (defaults to all variants)."
+                },
+                "output_source": {
+                    "type": "string",
+                    "enum": ["none", "inference", "demonstration"],
+                    "description": "Source of the inference output. 'inference' returns the original output, 'demonstration' returns manually-curated output if available, 'none' returns no output."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of inferences to use."
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Offset for pagination."
+                },
+                "val_fraction": {
+                    "type": "number",
+                    "description": "Fraction of data for validation (0.0 to 1.0, exclusive)."
+                },
+                "optimizer_config": {
+                    "type": "object",
+                    "description": "The base optimizer configuration. Use 'type' to select the optimizer (e.g. 'openai_sft', 'fireworks_sft', 'gcp_vertex_gemini_sft', 'together_sft'). Hyperparameter fields set here are overridden per job by hyperparameter_sweep.",
+                    "properties": {
+                        "type": {
+                            "type": "string",
+                            "enum": ["openai_sft", "fireworks_sft", "gcp_vertex_gemini_sft", "together_sft"],
+                            "description": "Optimizer type identifier."
+                        },
+                        "model": {
+                            "type": "string",
+                            "description": "The model to fine-tune."
+                        }
+                    },
+                    "required": ["type", "model"]
+                },
+                "hyperparameter_sweep": {
+                    "type": "object",
+                    "description": "The grid or random search to run over optimizer_config's hyperparameters.",
+                    "properties": {
+                        "search": {
+                            "description": "The search strategy.",
+                            "anyOf": [
+                                {
+                                    "type": "object",
+                                    "description": "Every combination of the given axis values.",
+                                    "properties": {
+                                        "type": { "type": "string", "enum": ["grid"] }
+                                    },
+                                    "required": ["type"]
+                                },
+                                {
+                                    "type": "object",
+                                    "description": "num_samples combinations, each drawing one value per axis at random.",
+                                    "properties": {
+                                        "type": { "type": "string", "enum": ["random"] },
+                                        "num_samples": { "type": "integer", "description": "Number of combinations to sample." }
+                                    },
+                                    "required": ["type", "num_samples"]
+                                }
+                            ]
+                        },
+                        "axes": {
+                            "type": "object",
+                            "description": "Named hyperparameter axes to sweep, e.g. {\"n_epochs\": [1, 2, 3]}. Axis names must match a field on optimizer_config.",
+                            "additionalProperties": {
+                                "type": "array",
+                                "items": {}
+                            }
+                        }
+                    },
+                    "required": ["search", "axes"]
+                },
+                "max_concurrency": {
+                    "type": "integer",
+                    "description": "Maximum number of sweep jobs to run concurrently through the durable queue. Default: 4."
+                },
+                "base_config_snapshot_hash": {
+                    "type": "string",
+                    "description": "The config snapshot hash to register each job's output into. Defaults to the current live config."
+                },
+                "variant_name_prefix": {
+                    "type": "string",
+                    "description": "Prefix used to name each job's registered variant: job i is named '{prefix}_{i}'."
+                },
+                "evaluation_name": {
+                    "type": "string",
+                    "description": "Name of the evaluation to run against the holdout dataset (must be defined in config)."
+                },
+                "holdout_dataset_name": {
+                    "type": "string",
+                    "description": "Name of the holdout dataset to evaluate each job's registered variant(s) on."
+                },
+                "evaluator_name": {
+                    "type": "string",
+                    "description": "Name of the evaluator (from evaluation_name) whose mean score picks the best run."
+                }
+            },
+            "required": [
+                "function_name", "template_variant_name", "output_source", "optimizer_config",
+                "hyperparameter_sweep", "variant_name_prefix", "evaluation_name",
+                "holdout_dataset_name", "evaluator_name"
+            ]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl TaskTool for RunHyperparameterSweepTool {
+    async fn execute(
+        &self,
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: &mut ToolContext<'_>,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let optimizer_configs = expand_hyperparameter_sweep(
+            &llm_params.optimizer_config,
+            &llm_params.hyperparameter_sweep,
+        )
+        .map_err(|e| AutopilotToolError::validation(e.to_string()))?;
+        if optimizer_configs.is_empty() {
+            return Err(AutopilotToolError::validation(
+                "Hyperparameter sweep produced no jobs to run",
+            )
+            .into());
+        }
+
+        let side_info_json = serde_json::to_value(&side_info).map_err(|e| {
+            AutopilotToolError::validation(format!("Failed to serialize side info: {e}"))
+        })?;
+
+        let mut jobs: Vec<HyperparameterSweepJobResult> =
+            Vec::with_capacity(optimizer_configs.len());
+        for (batch_index, batch) in optimizer_configs
+            .chunks(llm_params.max_concurrency.max(1))
+            .enumerate()
+        {
+            let mut handles = Vec::with_capacity(batch.len());
+            for (offset, optimizer_config) in batch.iter().enumerate() {
+                let job_index = batch_index * llm_params.max_concurrency.max(1) + offset;
+                let variant_name = format!("{}_{job_index}", llm_params.variant_name_prefix);
+
+                let pipeline_params = RunOptimizationPipelineToolParams {
+                    function_name: llm_params.function_name.clone(),
+                    template_variant_name: llm_params.template_variant_name.clone(),
+                    query_variant_name: llm_params.query_variant_name.clone(),
+                    filters: llm_params.filters.clone(),
+                    output_source: llm_params.output_source,
+                    order_by: llm_params.order_by.clone(),
+                    limit: llm_params.limit,
+                    offset: llm_params.offset,
+                    val_fraction: llm_params.val_fraction,
+                    optimizer_config: optimizer_config.clone(),
+                    base_config_snapshot_hash: llm_params.base_config_snapshot_hash.clone(),
+                    variant_name: variant_name.clone(),
+                    evaluation_name: llm_params.evaluation_name.clone(),
+                    holdout_dataset_name: llm_params.holdout_dataset_name.clone(),
+                };
+                let pipeline_params_json = serde_json::to_value(&pipeline_params).map_err(|e| {
+                    AutopilotToolError::validation(format!(
+                        "Failed to serialize sweep job {job_index} params: {e}"
+                    ))
+                })?;
+
+                let handle = ctx
+                    .spawn_tool(
+                        "run_optimization_pipeline",
+                        pipeline_params_json,
+                        side_info_json.clone(),
+                        SpawnOptions::default(),
+                    )
+                    .await?;
+                handles.push((variant_name, optimizer_config.clone(), handle));
+            }
+
+            for (variant_name, optimizer_config, handle) in handles {
+                let output_json = ctx.join_tool(handle).await?;
+                let output: RunOptimizationPipelineToolOutput = serde_json::from_value(output_json)
+                    .map_err(|e| {
+                        AutopilotToolError::validation(format!(
+                            "Failed to deserialize sweep job `{variant_name}` result: {e}"
+                        ))
+                    })?;
+                let score =
+                    score_for_variant(&output.result, &variant_name, &llm_params.evaluator_name);
+                jobs.push(HyperparameterSweepJobResult {
+                    variant_name,
+                    optimizer_config,
+                    result: output.result,
+                    score,
+                });
+            }
+        }
+
+        let best_job_index = jobs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, job)| job.score.map(|score| (index, score)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index);
+
+        Ok(RunHyperparameterSweepToolOutput {
+            jobs,
+            best_job_index,
+        })
+    }
+}
+
+/// Extracts the mean score of `evaluator_name` for `variant_name` from a completed pipeline
+/// result, or `None` if the job didn't complete or that evaluator has no stats for this variant.
+fn score_for_variant(
+    result: &RunOptimizationPipelineResult,
+    variant_name: &str,
+    evaluator_name: &str,
+) -> Option<f32> {
+    let RunOptimizationPipelineResult::Completed { evaluations, .. } = result else {
+        return None;
+    };
+    evaluations
+        .get(variant_name)
+        .and_then(|response| response.stats.get(evaluator_name))
+        .map(|stats| stats.mean)
+}
+
+fn default_max_wait_secs() -> u64 {
+    86400
+}
@@ -0,0 +1,551 @@
+//! Tool for running the full launch -> poll -> register -> evaluate optimization pipeline.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use durable_tools::{
+    ActionInput, ActionResponse, CacheEnabledMode, NonControlToolError, RunEvaluationParams,
+    RunOptimizationPipelineResult, TaskTool, ToolContext, ToolMetadata, ToolResult,
+    register_optimizer_output,
+};
+
+use crate::error::AutopilotToolError;
+
+use autopilot_client::{
+    AutopilotSideInfo, CreateEventGatewayRequest, EventPayload, EventPayloadStatusUpdate,
+    StatusUpdate,
+};
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{GetConfigResponse, WriteConfigRequest, WriteConfigResponse};
+use tensorzero_core::db::inferences::InferenceOutputSource;
+use tensorzero_core::endpoints::stored_inferences::v1::types::{InferenceFilter, OrderBy};
+use tensorzero_core::optimization::{
+    OptimizationJobHandle, OptimizationJobInfo, UninitializedOptimizerInfo,
+};
+use tensorzero_optimizers::endpoints::LaunchOptimizationWorkflowParams;
+use uuid::Uuid;
+
+/// Parameters for the run_optimization_pipeline tool (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RunOptimizationPipelineToolParams {
+    /// The function name to optimize.
+    pub function_name: String,
+    /// The variant name to use as a template for rendering inferences, and (for optimizers that
+    /// produce a bare fine-tuned model rather than a variant) as the template whose prompts the
+    /// newly registered variant will reuse.
+    pub template_variant_name: String,
+    /// Optional variant name to filter inferences by (defaults to all variants).
+    #[serde(default)]
+    pub query_variant_name: Option<String>,
+    /// Optional filters to apply when querying inferences.
+    #[serde(default)]
+    pub filters: Option<InferenceFilter>,
+    /// Source of the output data (inference output, demonstration, etc.).
+    pub output_source: InferenceOutputSource,
+    /// Optional ordering for the inferences.
+    #[serde(default)]
+    pub order_by: Option<Vec<OrderBy>>,
+    /// Maximum number of inferences to use.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Offset for pagination.
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Fraction of data to use for validation (0.0 to 1.0, exclusive).
+    #[serde(default)]
+    pub val_fraction: Option<f64>,
+    /// The optimizer configuration (e.g., SFT, DPO, MIPROv2).
+    pub optimizer_config: UninitializedOptimizerInfo,
+    /// The config snapshot to register the optimizer's output into. Defaults to the current live
+    /// config.
+    #[serde(default)]
+    pub base_config_snapshot_hash: Option<String>,
+    /// Name to give the newly registered variant, for optimizer outputs that don't already come
+    /// with one (a bare fine-tuned model). Ignored for optimizers that produce already-named
+    /// variants (e.g. gepa).
+    pub variant_name: String,
+    /// Name of the evaluation to run against the holdout dataset (must be defined in config).
+    pub evaluation_name: String,
+    /// Name of the holdout dataset to evaluate the newly registered variant(s) on.
+    pub holdout_dataset_name: String,
+}
+
+/// Response from the run_optimization_pipeline tool.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunOptimizationPipelineToolOutput {
+    pub result: RunOptimizationPipelineResult,
+}
+
+/// Tool for running the full optimization pipeline: launch an optimization workflow, poll it to
+/// completion, register its output as a new variant (or variants) in a config snapshot, and
+/// evaluate each registered variant against a holdout dataset.
+///
+/// This is the durable, checkpointed counterpart to
+/// `TensorZeroClient::run_optimization_pipeline`: each side-effecting step (launch, poll,
+/// register + write, evaluate, report) is wrapped in `ToolContext::step` so a worker restart
+/// resumes from the last completed step instead of re-launching the optimization job or
+/// re-registering a variant. It also reports the outcome into the autopilot session as a
+/// `StatusUpdate` event, since results here aren't otherwise visible to the session.
+#[derive(Default)]
+pub struct RunOptimizationPipelineTool;
+
+impl ToolMetadata for RunOptimizationPipelineTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = RunOptimizationPipelineToolOutput;
+    type LlmParams = RunOptimizationPipelineToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("run_optimization_pipeline")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Run the full optimization pipeline: launch an optimization workflow, poll until \
+             completion, register the resulting fine-tuned model or variant(s) in a new config \
+             snapshot, and evaluate against a holdout dataset. Reports the outcome into this \
+             autopilot session.",
+        )
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(default_max_wait_secs())
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Run the launch -> poll -> register -> evaluate optimization pipeline.",
+            "properties": {
+                "function_name": {
+                    "type": "string",
+                    "description": "The function name to optimize."
+                },
+                "template_variant_name": {
+                    "type": "string",
+                    "description": "The variant name to use as a template for rendering inferences, and to reuse the prompt from when registering a bare fine-tuned model."
+                },
+                "query_variant_name": {
+                    "type": "string",
+                    "description": "Optional variant name to filter inferences by (defaults to all variants)."
+                },
+                "output_source": {
+                    "type": "string",
+                    "enum": ["none", "inference", "demonstration"],
+                    "description": "Source of the inference output. 'inference' returns the original output, 'demonstration' returns manually-curated output if available, 'none' returns no output."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of inferences to use."
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Offset for pagination."
+                },
+                "val_fraction": {
+                    "type": "number",
+                    "description": "Fraction of data for validation (0.0 to 1.0, exclusive)."
+                },
+                "optimizer_config": {
+                    "description": "The optimizer configuration. Use 'type' to select the optimizer.",
+                    "anyOf": [
+                        {
+                            "type": "object",
+                            "description": "OpenAI supervised fine-tuning configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["openai_sft"], "description": "Optimizer type identifier." },
+                                "model": { "type": "string", "description": "The model to fine-tune (e.g., 'gpt-4.1-2025-04-14')." },
+                                "batch_size": { "type": "integer", "description": "Batch size for training." },
+                                "learning_rate_multiplier": { "type": "number", "description": "Learning rate multiplier." },
+                                "n_epochs": { "type": "integer", "description": "Number of training epochs." },
+                                "seed": { "type": "integer", "description": "Random seed for reproducibility." },
+                                "suffix": { "type": "string", "description": "Suffix for the fine-tuned model name in OpenAI." }
+                            },
+                            "required": ["type", "model"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Fireworks supervised fine-tuning configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["fireworks_sft"], "description": "Optimizer type identifier." },
+                                "model": { "type": "string", "description": "The model to fine-tune." },
+                                "epochs": { "type": "integer", "description": "Number of training epochs." },
+                                "learning_rate": { "type": "number", "description": "Learning rate for training." },
+                                "batch_size": { "type": "integer", "description": "Batch size (in tokens) for training." },
+                                "max_context_length": { "type": "integer", "description": "Maximum context length." },
+                                "lora_rank": { "type": "integer", "description": "Rank of the LoRA matrix." },
+                                "early_stop": { "type": "boolean", "description": "Whether to enable early stopping." },
+                                "display_name": { "type": "string", "description": "Display name for the fine-tuning job." },
+                                "output_model": { "type": "string", "description": "Model ID for the resulting fine-tuned model." },
+                                "deploy_after_training": { "type": "boolean", "description": "Whether to deploy the model after training." }
+                            },
+                            "required": ["type", "model"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "GCP Vertex Gemini supervised fine-tuning configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["gcp_vertex_gemini_sft"], "description": "Optimizer type identifier." },
+                                "model": { "type": "string", "description": "The model to fine-tune (e.g., 'gemini-2.5-flash')." },
+                                "learning_rate_multiplier": { "type": "number", "description": "Learning rate multiplier." },
+                                "adapter_size": { "type": "integer", "description": "Adapter size for fine-tuning." },
+                                "n_epochs": { "type": "integer", "description": "Number of training epochs." },
+                                "seed": { "type": "integer", "description": "Random seed for reproducibility." },
+                                "tuned_model_display_name": { "type": "string", "description": "Display name for the tuned model." }
+                            },
+                            "required": ["type", "model"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Together AI supervised fine-tuning configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["together_sft"], "description": "Optimizer type identifier." },
+                                "model": { "type": "string", "description": "The base model to fine-tune." },
+                                "n_epochs": { "type": "integer", "description": "Number of training epochs. Default: 1." },
+                                "n_checkpoints": { "type": "integer", "description": "Number of checkpoints to save. Default: 1." },
+                                "learning_rate": { "type": "number", "description": "Learning rate. Default: 0.00001." },
+                                "warmup_ratio": { "type": "number", "description": "Warmup ratio. Default: 0.0." },
+                                "suffix": { "type": "string", "description": "Suffix for the fine-tuned model name." }
+                            },
+                            "required": ["type", "model"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "Dynamic In-Context Learning (DICL) optimization configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["dicl"], "description": "Optimizer type identifier." },
+                                "embedding_model": { "type": "string", "description": "The embedding model to use (e.g., 'openai::text-embedding-3-small')." },
+                                "variant_name": { "type": "string", "description": "Name for the DICL variant to create." },
+                                "function_name": { "type": "string", "description": "Name of the function to optimize." },
+                                "dimensions": { "type": "integer", "description": "Dimensions of the embeddings. Uses model default if not specified." },
+                                "batch_size": { "type": "integer", "description": "Batch size for getting embeddings. Default: 128." },
+                                "max_concurrency": { "type": "integer", "description": "Maximum concurrency for embeddings. Default: 10." },
+                                "k": { "type": "integer", "description": "Number of nearest neighbors for DICL. Default: 10." },
+                                "model": { "type": "string", "description": "Model for the DICL variant. Default: 'openai::gpt-5-mini-2025-08-07'." },
+                                "append_to_existing_variants": { "type": "boolean", "description": "Whether to append to existing variants. Default: false." }
+                            },
+                            "required": ["type", "embedding_model", "variant_name", "function_name"]
+                        },
+                        {
+                            "type": "object",
+                            "description": "GEPA (Genetic Evolution with Pareto Analysis) prompt optimization configuration.",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["gepa"], "description": "Optimizer type identifier." },
+                                "function_name": { "type": "string", "description": "Name of the function to optimize." },
+                                "evaluation_name": { "type": "string", "description": "Name of the evaluation used to score candidate variants." },
+                                "analysis_model": { "type": "string", "description": "Model for analysis (e.g., 'anthropic::claude-sonnet-4-5')." },
+                                "mutation_model": { "type": "string", "description": "Model for mutation (e.g., 'anthropic::claude-sonnet-4-5')." },
+                                "initial_variants": { "type": "array", "items": { "type": "string" }, "description": "Optional list of variant names to initialize GEPA with." },
+                                "variant_prefix": { "type": "string", "description": "Prefix for newly created optimized variants." },
+                                "batch_size": { "type": "integer", "description": "Number of samples to analyze per iteration. Default: 5." },
+                                "max_iterations": { "type": "integer", "description": "Maximum training iterations. Default: 1." },
+                                "max_concurrency": { "type": "integer", "description": "Maximum concurrent inference calls. Default: 10." },
+                                "seed": { "type": "integer", "description": "Random seed for reproducibility." },
+                                "timeout": { "type": "integer", "description": "Client timeout in seconds. Default: 300." },
+                                "max_tokens": { "type": "integer", "description": "Max tokens for analysis/mutation model calls." }
+                            },
+                            "required": ["type", "function_name", "evaluation_name", "analysis_model", "mutation_model"]
+                        }
+                    ]
+                },
+                "base_config_snapshot_hash": {
+                    "type": "string",
+                    "description": "The config snapshot hash to register the optimizer's output into. Defaults to the current live config."
+                },
+                "variant_name": {
+                    "type": "string",
+                    "description": "Name to give the newly registered variant, for optimizer outputs that don't already come with one (a bare fine-tuned model)."
+                },
+                "evaluation_name": {
+                    "type": "string",
+                    "description": "Name of the evaluation to run against the holdout dataset (must be defined in config)."
+                },
+                "holdout_dataset_name": {
+                    "type": "string",
+                    "description": "Name of the holdout dataset to evaluate the newly registered variant(s) on."
+                }
+            },
+            "required": [
+                "function_name", "template_variant_name", "output_source", "optimizer_config",
+                "variant_name", "evaluation_name", "holdout_dataset_name"
+            ]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl TaskTool for RunOptimizationPipelineTool {
+    async fn execute(
+        &self,
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: &mut ToolContext<'_>,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        // Step 1: Launch the optimization workflow
+        let job_handle: OptimizationJobHandle = ctx
+            .step("launch", llm_params.clone(), |params, state| async move {
+                let launch_params = LaunchOptimizationWorkflowParams {
+                    function_name: params.function_name,
+                    template_variant_name: params.template_variant_name,
+                    query_variant_name: params.query_variant_name,
+                    filters: params.filters,
+                    output_source: params.output_source,
+                    order_by: params.order_by,
+                    limit: params.limit,
+                    offset: params.offset,
+                    val_fraction: params.val_fraction,
+                    optimizer_config: params.optimizer_config,
+                    contamination_check: None,
+                };
+
+                state
+                    .t0_client()
+                    .launch_optimization_workflow(launch_params)
+                    .await
+                    .map_err(|e| anyhow::Error::msg(e.to_string()))
+            })
+            .await?;
+
+        // Step 2: Poll until completion
+        let poll_interval = Duration::from_secs(side_info.optimization.poll_interval_secs);
+        let max_wait_secs = side_info.optimization.max_wait_secs as i64;
+        let start = ctx.now().await?;
+        let mut iteration = 0u32;
+
+        let job_info = loop {
+            let status: OptimizationJobInfo = ctx
+                .step(
+                    &format!("poll_{iteration}"),
+                    job_handle.clone(),
+                    |handle, state| async move {
+                        state
+                            .t0_client()
+                            .poll_optimization(&handle)
+                            .await
+                            .map_err(|e| anyhow::Error::msg(e.to_string()))
+                    },
+                )
+                .await?;
+
+            match &status {
+                OptimizationJobInfo::Completed { .. } | OptimizationJobInfo::Failed { .. } => {
+                    break status;
+                }
+                OptimizationJobInfo::Pending { .. } => {
+                    let elapsed = ctx.now().await? - start;
+                    if elapsed.num_seconds() > max_wait_secs {
+                        return Err(AutopilotToolError::validation(format!(
+                            "Optimization timed out after {max_wait_secs} seconds"
+                        ))
+                        .into());
+                    }
+
+                    ctx.sleep_for(&format!("wait_{iteration}"), poll_interval)
+                        .await?;
+                    iteration += 1;
+                }
+            }
+        };
+
+        let output = match job_info {
+            OptimizationJobInfo::Completed { output } => output,
+            job_info => {
+                let message = format!(
+                    "Optimization pipeline for `{}` did not complete: {job_info:?}",
+                    llm_params.function_name
+                );
+                report_status(ctx, side_info.session_id, message).await?;
+                return Ok(RunOptimizationPipelineToolOutput {
+                    result: RunOptimizationPipelineResult::OptimizationFailed { job_info },
+                });
+            }
+        };
+
+        // Step 3: Register the optimizer's output in a new config snapshot
+        let base_hash = llm_params
+            .base_config_snapshot_hash
+            .clone()
+            .unwrap_or_else(|| side_info.config_snapshot_hash.clone());
+        let register_params = (
+            base_hash,
+            llm_params.function_name.clone(),
+            llm_params.template_variant_name.clone(),
+            llm_params.variant_name.clone(),
+            output,
+        );
+        let (config_snapshot_hash, registered_variants): (String, Vec<String>) = ctx
+            .step(
+                "register",
+                register_params,
+                |(base_hash, function_name, template_variant_name, variant_name, output),
+                 state| async move {
+                    let GetConfigResponse {
+                        mut config,
+                        extra_templates,
+                        ..
+                    } = state
+                        .t0_client()
+                        .get_config_snapshot(Some(base_hash))
+                        .await
+                        .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+
+                    let registered_variants = register_optimizer_output(
+                        &mut config,
+                        &function_name,
+                        &template_variant_name,
+                        &variant_name,
+                        output,
+                    )
+                    .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+
+                    let mut tags = HashMap::new();
+                    tags.insert(
+                        "tensorzero::autopilot::run_optimization_pipeline::function_name"
+                            .to_string(),
+                        function_name,
+                    );
+                    tags.insert(
+                        "tensorzero::autopilot::run_optimization_pipeline::registered_variants"
+                            .to_string(),
+                        registered_variants.join(","),
+                    );
+
+                    let WriteConfigResponse {
+                        hash,
+                        policy_violations: _,
+                    } = state
+                        .t0_client()
+                        .write_config(WriteConfigRequest {
+                            config,
+                            extra_templates,
+                            tags,
+                        })
+                        .await
+                        .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+
+                    Ok((hash, registered_variants))
+                },
+            )
+            .await?;
+
+        // Step 4: Evaluate each registered variant against the holdout dataset
+        let mut evaluations = HashMap::with_capacity(registered_variants.len());
+        for variant_name in &registered_variants {
+            let eval_params = (
+                config_snapshot_hash.clone(),
+                llm_params.evaluation_name.clone(),
+                llm_params.holdout_dataset_name.clone(),
+                variant_name.clone(),
+            );
+            let response = ctx
+                .step(
+                    &format!("evaluate_{variant_name}"),
+                    eval_params,
+                    |(snapshot_hash, evaluation_name, holdout_dataset_name, variant_name),
+                     state| async move {
+                        let snapshot_hash =
+                            snapshot_hash
+                                .parse()
+                                .map_err(|_: std::convert::Infallible| {
+                                    anyhow::Error::msg("Failed to parse config snapshot hash")
+                                })?;
+                        let response = state
+                            .t0_client()
+                            .action(
+                                snapshot_hash,
+                                ActionInput::RunEvaluation(Box::new(RunEvaluationParams {
+                                    evaluation_name,
+                                    dataset_name: Some(holdout_dataset_name),
+                                    datapoint_ids: None,
+                                    variant_name,
+                                    concurrency: 10,
+                                    inference_cache: CacheEnabledMode::On,
+                                    max_datapoints: None,
+                                    precision_targets: HashMap::new(),
+                                    include_datapoint_results: false,
+                                    tags: HashMap::new(),
+                                })),
+                            )
+                            .await
+                            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+
+                        match response {
+                            ActionResponse::RunEvaluation(eval_response) => Ok(eval_response),
+                            _ => Err(anyhow::Error::msg(
+                                "Unexpected response type from action endpoint",
+                            )),
+                        }
+                    },
+                )
+                .await?;
+            evaluations.insert(variant_name.clone(), response);
+        }
+
+        // Step 5: Report the outcome into the autopilot session
+        report_status(
+            ctx,
+            side_info.session_id,
+            format!(
+                "Optimization pipeline for `{}` completed: registered variant(s) {} in config \
+                 snapshot `{config_snapshot_hash}` and evaluated against `{}` (evaluation \
+                 `{}`).",
+                llm_params.function_name,
+                registered_variants.join(", "),
+                llm_params.holdout_dataset_name,
+                llm_params.evaluation_name,
+            ),
+        )
+        .await?;
+
+        Ok(RunOptimizationPipelineToolOutput {
+            result: RunOptimizationPipelineResult::Completed {
+                config_snapshot_hash,
+                registered_variants,
+                evaluations,
+            },
+        })
+    }
+}
+
+/// Reports a `StatusUpdate` event into the autopilot session so the pipeline's outcome is
+/// visible even though nothing else in the session calls this tool's output directly.
+async fn report_status(
+    ctx: &mut ToolContext<'_>,
+    session_id: Uuid,
+    text: String,
+) -> ToolResult<()> {
+    ctx.step(
+        "report",
+        (session_id, text),
+        |(session_id, text), state| async move {
+            state
+                .t0_client()
+                .create_autopilot_event(
+                    session_id,
+                    CreateEventGatewayRequest {
+                        payload: EventPayload::StatusUpdate(EventPayloadStatusUpdate {
+                            status_update: StatusUpdate::Text { text },
+                        }),
+                        previous_user_message_event_id: None,
+                    },
+                )
+                .await
+                .map_err(|e| anyhow::Error::msg(e.to_string()))
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+fn default_max_wait_secs() -> u64 {
+    86400
+}
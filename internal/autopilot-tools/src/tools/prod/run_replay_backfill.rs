@@ -0,0 +1,296 @@
+//! Tool for replaying a large batch of historical inferences against a variant at a throttled
+//! rate, for large-scale migration validation.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Timelike;
+use durable_tools::{NonControlToolError, TaskTool, ToolContext, ToolMetadata, ToolResult};
+
+use autopilot_client::AutopilotSideInfo;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use tensorzero::{
+    ClientInferenceParams, GetInferencesResponse, InferenceResponse, ListInferencesRequest,
+    StoredInference,
+};
+use tensorzero_core::db::inferences::InferenceOutputSource;
+use uuid::Uuid;
+
+fn default_requests_per_minute() -> u32 {
+    60
+}
+
+fn default_max_wait_secs() -> u64 {
+    // A backfill over a large historical set, throttled to an off-peak window, can legitimately
+    // run for a very long time - default to a generous ceiling rather than the shorter timeouts
+    // used by tools that finish in minutes.
+    60 * 60 * 24 * 7
+}
+
+/// A UTC hour-of-day window during which the backfill is allowed to send requests.
+///
+/// `start_hour_utc` may be greater than `end_hour_utc` to express a window that wraps past
+/// midnight (e.g. `22` to `6` for "run overnight only"). Both bounds are inclusive.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+pub struct OffPeakWindow {
+    pub start_hour_utc: u8,
+    pub end_hour_utc: u8,
+}
+
+impl OffPeakWindow {
+    fn contains(&self, hour: u8) -> bool {
+        if self.start_hour_utc <= self.end_hour_utc {
+            (self.start_hour_utc..=self.end_hour_utc).contains(&hour)
+        } else {
+            hour >= self.start_hour_utc || hour <= self.end_hour_utc
+        }
+    }
+
+    /// Seconds until the window next contains `hour` (0 if it already does), given the current
+    /// minute/second within `hour`.
+    fn seconds_until_open(&self, hour: u32, minute: u32, second: u32) -> i64 {
+        if self.contains(hour as u8) {
+            return 0;
+        }
+        let elapsed_this_hour = i64::from(minute * 60 + second);
+        for offset in 1..24 {
+            let candidate_hour = ((hour + offset) % 24) as u8;
+            if self.contains(candidate_hour) {
+                return i64::from(offset) * 3600 - elapsed_this_hour;
+            }
+        }
+        0
+    }
+}
+
+/// Parameters for the run_replay_backfill tool (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RunReplayBackfillToolParams {
+    /// The function whose historical inferences should be replayed.
+    pub function_name: String,
+    /// The variant to replay each historical input against.
+    pub target_variant_name: String,
+    /// Optional variant name to restrict the historical inferences to (defaults to all variants).
+    #[serde(default)]
+    pub query_variant_name: Option<String>,
+    /// Maximum number of historical inferences to replay.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Offset into the historical inferences to start from.
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Client-side cap on replay requests per minute, on top of whatever the gateway's own
+    /// rate-limiting config (`[[rate_limiting.rules]]`) enforces for `target_variant_name`'s
+    /// providers. Default: 60.
+    #[serde(default = "default_requests_per_minute")]
+    pub requests_per_minute: u32,
+    /// If set, replay only sends requests during this UTC hour-of-day window; outside it, the
+    /// tool durably sleeps until the window opens instead of polling.
+    #[serde(default)]
+    pub off_peak_window: Option<OffPeakWindow>,
+}
+
+/// A historical inference that failed to replay.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackfillReplayFailure {
+    pub inference_id: Uuid,
+    pub error: String,
+}
+
+/// Response from the run_replay_backfill tool.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunReplayBackfillToolOutput {
+    /// Total number of historical inferences selected for replay.
+    pub total: usize,
+    /// Number that replayed successfully.
+    pub succeeded: usize,
+    /// Inferences that failed to replay, in replay order.
+    pub failed: Vec<BackfillReplayFailure>,
+}
+
+/// Tool for replaying a large set of historical inferences against a new variant at a controlled
+/// rate, for validating a migration before shifting production traffic.
+///
+/// Each replay is its own `ToolContext::step`, so a worker restart resumes from the next
+/// unreplayed inference instead of starting over or double-sending requests already recorded as
+/// completed. One inference failing to replay doesn't stop the rest: failures are collected and
+/// reported in the output, similar to `SubmitDemonstrationsTool`.
+///
+/// Historical inputs are replayed via `StoredInput::into_input`, which does not re-fetch file
+/// data from object storage (files are passed through as pointers) - this matches how the input
+/// was originally stored and avoids re-downloading potentially large resources for every
+/// replayed inference.
+#[derive(Default)]
+pub struct RunReplayBackfillTool;
+
+impl ToolMetadata for RunReplayBackfillTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = RunReplayBackfillToolOutput;
+    type LlmParams = RunReplayBackfillToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("run_replay_backfill")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Replay a large set of historical inferences for a function against a target \
+             variant at a controlled rate, optionally restricted to an off-peak UTC hour \
+             window, for large-scale migration validation.",
+        )
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(default_max_wait_secs())
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Replay historical inferences against a variant at a controlled rate.",
+            "properties": {
+                "function_name": {
+                    "type": "string",
+                    "description": "The function whose historical inferences should be replayed."
+                },
+                "target_variant_name": {
+                    "type": "string",
+                    "description": "The variant to replay each historical input against."
+                },
+                "query_variant_name": {
+                    "type": "string",
+                    "description": "Optional variant name to restrict the historical inferences to (defaults to all variants)."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of historical inferences to replay."
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Offset into the historical inferences to start from."
+                },
+                "requests_per_minute": {
+                    "type": "integer",
+                    "description": "Client-side cap on replay requests per minute. Default: 60."
+                },
+                "off_peak_window": {
+                    "type": "object",
+                    "description": "If set, only replay during this UTC hour-of-day window (inclusive bounds; end_hour_utc < start_hour_utc wraps past midnight).",
+                    "properties": {
+                        "start_hour_utc": { "type": "integer", "minimum": 0, "maximum": 23 },
+                        "end_hour_utc": { "type": "integer", "minimum": 0, "maximum": 23 }
+                    },
+                    "required": ["start_hour_utc", "end_hour_utc"]
+                }
+            },
+            "required": ["function_name", "target_variant_name"]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl TaskTool for RunReplayBackfillTool {
+    async fn execute(
+        &self,
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        _side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: &mut ToolContext<'_>,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let list_request = ListInferencesRequest {
+            function_name: Some(llm_params.function_name.clone()),
+            variant_name: llm_params.query_variant_name.clone(),
+            output_source: InferenceOutputSource::None,
+            limit: llm_params.limit,
+            offset: llm_params.offset,
+            ..Default::default()
+        };
+        let response: GetInferencesResponse = ctx
+            .step("list", list_request, |request, state| async move {
+                state
+                    .t0_client()
+                    .list_inferences(request)
+                    .await
+                    .map_err(|e| anyhow::Error::msg(e.to_string()))
+            })
+            .await?;
+
+        let total = response.inferences.len();
+        let interval =
+            Duration::from_secs_f64(60.0 / f64::from(llm_params.requests_per_minute.max(1)));
+
+        let mut succeeded = 0usize;
+        let mut failed = Vec::new();
+
+        for (index, stored) in response.inferences.into_iter().enumerate() {
+            let inference_id = stored.id();
+            let input = match stored {
+                StoredInference::Chat(inner) => inner.input,
+                StoredInference::Json(inner) => inner.input,
+            }
+            .into_input();
+
+            if let Some(window) = llm_params.off_peak_window {
+                let now = ctx.now().await?;
+                let wait_secs = window.seconds_until_open(now.hour(), now.minute(), now.second());
+                if wait_secs > 0 {
+                    ctx.sleep_for(
+                        &format!("wait_offpeak_{index}"),
+                        Duration::from_secs(wait_secs as u64),
+                    )
+                    .await?;
+                }
+            }
+
+            if index > 0 {
+                ctx.sleep_for(&format!("throttle_{index}"), interval)
+                    .await?;
+            }
+
+            let params = ClientInferenceParams {
+                function_name: Some(llm_params.function_name.clone()),
+                variant_name: Some(llm_params.target_variant_name.clone()),
+                input,
+                internal: true,
+                dryrun: Some(false),
+                ..Default::default()
+            };
+
+            let result: ToolResult<InferenceResponse> = ctx
+                .step(
+                    &format!("replay_{index}"),
+                    params,
+                    |params, state| async move {
+                        state
+                            .t0_client()
+                            .inference(params)
+                            .await
+                            .map_err(|e| anyhow::Error::msg(e.to_string()))
+                    },
+                )
+                .await;
+
+            match result {
+                Ok(_) => succeeded += 1,
+                Err(e) => failed.push(BackfillReplayFailure {
+                    inference_id,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(RunReplayBackfillToolOutput {
+            total,
+            succeeded,
+            failed,
+        })
+    }
+}
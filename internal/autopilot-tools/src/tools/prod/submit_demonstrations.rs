@@ -0,0 +1,132 @@
+//! Tool for submitting a batch of demonstrations (corrected outputs).
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use durable_tools::{
+    DemonstrationSubmission, NonControlToolError, SimpleTool, SimpleToolContext, ToolMetadata,
+    ToolResult,
+};
+
+use crate::error::AutopilotToolError;
+use durable_tools::SubmitDemonstrationsResponse;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use autopilot_client::AutopilotSideInfo;
+
+/// A single demonstration to submit (visible to LLM).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SubmitDemonstrationsToolItem {
+    /// The episode ID this demonstration corrects. Exactly one of episode_id/inference_id must be set.
+    #[serde(default)]
+    pub episode_id: Option<Uuid>,
+    /// The inference ID this demonstration corrects. Exactly one of episode_id/inference_id must be set.
+    #[serde(default)]
+    pub inference_id: Option<Uuid>,
+    /// The corrected output: a string, or an array of content blocks for tool calls.
+    pub value: Value,
+}
+
+/// Parameters for the submit_demonstrations tool (visible to LLM).
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SubmitDemonstrationsToolParams {
+    /// The demonstrations to submit.
+    pub demonstrations: Vec<SubmitDemonstrationsToolItem>,
+}
+
+/// Tool for submitting a batch of demonstrations in one call.
+///
+/// Composed from repeated calls to `feedback` (there's no batch feedback endpoint): one
+/// demonstration failing doesn't stop the rest, and the response reports each outcome in request
+/// order so a caller assembling a fine-tuning dataset from a large batch of corrections can
+/// retry just the failures.
+#[derive(Default)]
+pub struct SubmitDemonstrationsTool;
+
+impl ToolMetadata for SubmitDemonstrationsTool {
+    type SideInfo = AutopilotSideInfo;
+    type Output = SubmitDemonstrationsResponse;
+    type LlmParams = SubmitDemonstrationsToolParams;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("submit_demonstrations")
+    }
+
+    fn description(&self) -> Cow<'static, str> {
+        Cow::Borrowed(
+            "Submit a batch of demonstrations (corrected outputs) for inferences or episodes. \
+             One demonstration failing doesn't stop the rest; the response reports each \
+             outcome in the same order the demonstrations were given.",
+        )
+    }
+
+    fn parameters_schema(&self) -> ToolResult<Schema> {
+        let schema = serde_json::json!({
+            "type": "object",
+            "description": "Submit a batch of demonstrations.",
+            "properties": {
+                "demonstrations": {
+                    "type": "array",
+                    "description": "The demonstrations to submit.",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "episode_id": {
+                                "type": "string",
+                                "format": "uuid",
+                                "description": "The episode ID to provide a demonstration for. Use either episode_id or inference_id."
+                            },
+                            "inference_id": {
+                                "type": "string",
+                                "format": "uuid",
+                                "description": "The inference ID to provide a demonstration for. Use either episode_id or inference_id."
+                            },
+                            "value": {
+                                "description": "The corrected output: a string, or an array of content blocks for tool calls."
+                            }
+                        },
+                        "required": ["value"]
+                    }
+                }
+            },
+            "required": ["demonstrations"]
+        });
+
+        serde_json::from_value(schema).map_err(|e| {
+            NonControlToolError::SchemaGeneration {
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleTool for SubmitDemonstrationsTool {
+    async fn execute(
+        llm_params: <Self as ToolMetadata>::LlmParams,
+        side_info: <Self as ToolMetadata>::SideInfo,
+        ctx: SimpleToolContext<'_>,
+        _idempotency_key: &str,
+    ) -> ToolResult<<Self as ToolMetadata>::Output> {
+        let tags = side_info.to_tags();
+        let demonstrations = llm_params
+            .demonstrations
+            .into_iter()
+            .map(|item| DemonstrationSubmission {
+                episode_id: item.episode_id,
+                inference_id: item.inference_id,
+                value: item.value,
+                tags: tags.clone(),
+            })
+            .collect();
+
+        ctx.client()
+            .submit_demonstrations(demonstrations)
+            .await
+            .map_err(|e| AutopilotToolError::client_error("submit_demonstrations", e).into())
+    }
+}
@@ -108,6 +108,7 @@ async fn test_write_config_tool_sets_autopilot_tags(pool: PgPool) {
         .return_once(|_| {
             Ok(WriteConfigResponse {
                 hash: "written_hash".to_string(),
+                policy_violations: vec![],
             })
         });
 
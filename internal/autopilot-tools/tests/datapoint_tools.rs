@@ -11,22 +11,23 @@ use durable_tools::{ErasedSimpleTool, SimpleToolContext, TensorZeroClientError};
 use sqlx::PgPool;
 use tensorzero::{
     CreateChatDatapointRequest, CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams,
-    ListDatapointsRequest, ListDatasetsRequest, UpdateChatDatapointRequest, UpdateDatapointRequest,
+    DeduplicationStrategy, ListDatapointsRequest, ListDatasetsRequest, ListInferencesRequest,
+    UpdateChatDatapointRequest, UpdateDatapointRequest,
 };
 use uuid::Uuid;
 
 use autopilot_tools::tools::{
     CreateDatapointsFromInferencesTool, CreateDatapointsFromInferencesToolParams,
-    CreateDatapointsTool, CreateDatapointsToolParams, DeleteDatapointsTool,
-    DeleteDatapointsToolParams, GetDatapointsTool, GetDatapointsToolParams, ListDatapointsTool,
-    ListDatapointsToolParams, ListDatasetsTool, ListDatasetsToolParams, UpdateDatapointsTool,
-    UpdateDatapointsToolParams,
+    CreateDatapointsTool, CreateDatapointsToolParams, CurateDatasetFromFailuresTool,
+    CurateDatasetFromFailuresToolParams, DeleteDatapointsTool, DeleteDatapointsToolParams,
+    GetDatapointsTool, GetDatapointsToolParams, ListDatapointsTool, ListDatapointsToolParams,
+    ListDatasetsTool, ListDatasetsToolParams, UpdateDatapointsTool, UpdateDatapointsToolParams,
 };
 use common::{
     MockTensorZeroClient, create_mock_chat_datapoint, create_mock_create_datapoints_response,
-    create_mock_dataset_metadata, create_mock_delete_datapoints_response,
-    create_mock_get_datapoints_response, create_mock_list_datasets_response,
-    create_mock_update_datapoints_response, create_test_input,
+    create_mock_dataset_metadata, create_mock_deduplicate_datapoints_response,
+    create_mock_delete_datapoints_response, create_mock_get_datapoints_response,
+    create_mock_list_datasets_response, create_mock_update_datapoints_response, create_test_input,
 };
 
 // ===== CreateDatapointsTool Tests =====
@@ -774,6 +775,97 @@ async fn test_create_datapoints_from_inferences_tool_error(pool: PgPool) {
     assert!(result.is_err(), "Should return error when client fails");
 }
 
+// ===== CurateDatasetFromFailuresTool Tests =====
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_curate_dataset_from_failures_tool_basic(pool: PgPool) {
+    let datapoint_id = Uuid::now_v7();
+    let mock_create_response = create_mock_create_datapoints_response(vec![datapoint_id]);
+    let mock_dedup_response = create_mock_deduplicate_datapoints_response();
+
+    let llm_params = CurateDatasetFromFailuresToolParams {
+        dataset_name: "test_dataset".to_string(),
+        query: ListInferencesRequest {
+            function_name: Some("test_function".to_string()),
+            ..Default::default()
+        },
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client
+        .expect_create_datapoints_from_inferences()
+        .withf(|dataset_name, _params| dataset_name == "test_dataset")
+        .return_once(move |_, _| Ok(mock_create_response));
+    mock_client
+        .expect_deduplicate_datapoints()
+        .withf(|dataset_name, request| {
+            dataset_name == "test_dataset"
+                && matches!(request.strategy, DeduplicationStrategy::ExactHash)
+        })
+        .return_once(move |_, _| Ok(mock_dedup_response));
+
+    let tool = CurateDatasetFromFailuresTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await
+        .expect("CurateDatasetFromFailuresTool execution should succeed");
+
+    assert!(result.is_object(), "Result should be a JSON object");
+}
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_curate_dataset_from_failures_tool_error(pool: PgPool) {
+    let llm_params = CurateDatasetFromFailuresToolParams {
+        dataset_name: "test_dataset".to_string(),
+        query: ListInferencesRequest::default(),
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client
+        .expect_create_datapoints_from_inferences()
+        .returning(|_, _| Err(TensorZeroClientError::AutopilotUnavailable));
+
+    let tool = CurateDatasetFromFailuresTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Should return error when create_datapoints_from_inferences fails"
+    );
+}
+
 // ===== ListDatasetsTool Tests =====
 
 #[sqlx::test(migrator = "MIGRATOR")]
@@ -0,0 +1,221 @@
+//! Integration tests for the golden-trace regression tools (FreezeAsTestTool, RunGoldenSuiteTool).
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use autopilot_client::{AutopilotSideInfo, OptimizationWorkflowSideInfo};
+use durable::MIGRATOR;
+use durable_tools::{ErasedSimpleTool, RunEvaluationResponse, SimpleToolContext};
+use sqlx::PgPool;
+use tensorzero::{CreateDatapointsFromInferenceRequestParams, InferenceOutputSource};
+use uuid::Uuid;
+
+use autopilot_tools::tools::{
+    FreezeAsTestTool, FreezeAsTestToolParams, RunGoldenSuiteTool, RunGoldenSuiteToolParams,
+};
+use common::{MockTensorZeroClient, create_mock_create_datapoints_response};
+
+// ===== FreezeAsTestTool Tests =====
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_freeze_as_test_tool_basic(pool: PgPool) {
+    let datapoint_id = Uuid::now_v7();
+    let inference_id = Uuid::now_v7();
+    let mock_response = create_mock_create_datapoints_response(vec![datapoint_id]);
+
+    let llm_params = FreezeAsTestToolParams {
+        name: "golden_checkout".to_string(),
+        inference_ids: vec![inference_id],
+        output_source: Some(InferenceOutputSource::Inference),
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client
+        .expect_create_datapoints_from_inferences()
+        .withf(move |dataset_name, params| {
+            dataset_name == "golden_checkout"
+                && matches!(
+                    params,
+                    CreateDatapointsFromInferenceRequestParams::InferenceIds {
+                        inference_ids,
+                        output_source: Some(InferenceOutputSource::Inference),
+                    } if *inference_ids == vec![inference_id]
+                )
+        })
+        .return_once(move |_, _| Ok(mock_response));
+
+    let tool = FreezeAsTestTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await
+        .expect("FreezeAsTestTool execution should succeed");
+
+    assert!(result.is_object(), "Result should be a JSON object");
+}
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_freeze_as_test_tool_error(pool: PgPool) {
+    let llm_params = FreezeAsTestToolParams {
+        name: "golden_checkout".to_string(),
+        inference_ids: vec![Uuid::now_v7()],
+        output_source: None,
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client
+        .expect_create_datapoints_from_inferences()
+        .returning(|_, _| Err(durable_tools::TensorZeroClientError::AutopilotUnavailable));
+
+    let tool = FreezeAsTestTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Should return error when create_datapoints_from_inferences fails"
+    );
+}
+
+// ===== RunGoldenSuiteTool Tests =====
+
+fn create_mock_run_evaluation_response() -> RunEvaluationResponse {
+    RunEvaluationResponse {
+        evaluation_run_id: Uuid::now_v7(),
+        num_datapoints: 3,
+        num_successes: 3,
+        num_errors: 0,
+        stats: HashMap::new(),
+        datapoint_results: None,
+    }
+}
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_run_golden_suite_tool_basic(pool: PgPool) {
+    let mock_response = create_mock_run_evaluation_response();
+    let expected_response = mock_response.clone();
+
+    let llm_params = RunGoldenSuiteToolParams {
+        name: "golden_checkout".to_string(),
+        variant_name: "production".to_string(),
+        concurrency: 5,
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client
+        .expect_run_evaluation()
+        .withf(|params| {
+            params.evaluation_name == "golden_checkout"
+                && params.dataset_name == Some("golden_checkout".to_string())
+                && params.datapoint_ids.is_none()
+                && params.variant_name == "production"
+                && params.concurrency == 5
+                && params.include_datapoint_results
+        })
+        .return_once(move |_| Ok(mock_response));
+
+    let tool = RunGoldenSuiteTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await
+        .expect("RunGoldenSuiteTool execution should succeed");
+
+    let response: RunEvaluationResponse =
+        serde_json::from_value(result).expect("Failed to deserialize response");
+    assert_eq!(
+        response.evaluation_run_id, expected_response.evaluation_run_id,
+        "evaluation_run_id should match the mocked response"
+    );
+    assert_eq!(
+        response.num_datapoints, expected_response.num_datapoints,
+        "num_datapoints should match the mocked response"
+    );
+}
+
+#[sqlx::test(migrator = "MIGRATOR")]
+async fn test_run_golden_suite_tool_error(pool: PgPool) {
+    let llm_params = RunGoldenSuiteToolParams {
+        name: "nonexistent_suite".to_string(),
+        variant_name: "production".to_string(),
+        concurrency: 10,
+    };
+
+    let side_info = AutopilotSideInfo {
+        tool_call_event_id: Uuid::now_v7(),
+        session_id: Uuid::now_v7(),
+        config_snapshot_hash: "test_hash".to_string(),
+        optimization: OptimizationWorkflowSideInfo::default(),
+    };
+
+    let mut mock_client = MockTensorZeroClient::new();
+    mock_client.expect_run_evaluation().returning(|_| {
+        Err(durable_tools::TensorZeroClientError::Evaluation(
+            "Evaluation 'nonexistent_suite' not found in config".to_string(),
+        ))
+    });
+
+    let tool = RunGoldenSuiteTool;
+    let t0_client: Arc<dyn durable_tools::TensorZeroClient> = Arc::new(mock_client);
+    let ctx = SimpleToolContext::new(&pool, &t0_client);
+
+    let result = tool
+        .execute_erased(
+            serde_json::to_value(&llm_params).expect("Failed to serialize llm_params"),
+            serde_json::to_value(&side_info).expect("Failed to serialize side_info"),
+            ctx,
+            "test-idempotency-key",
+        )
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Should return error when the golden suite's evaluation config is missing"
+    );
+}
@@ -26,6 +26,7 @@ fn create_mock_run_evaluation_response() -> RunEvaluationResponse {
             mean: 0.85,
             stderr: 0.02,
             count: 100,
+            criteria: None,
         },
     );
     RunEvaluationResponse {
@@ -444,6 +445,7 @@ async fn test_run_evaluation_tool_with_datapoint_results(pool: PgPool) {
             mean: 0.85,
             stderr: 0.05,
             count: 2,
+            criteria: None,
         },
     );
     stats.insert(
@@ -452,6 +454,7 @@ async fn test_run_evaluation_tool_with_datapoint_results(pool: PgPool) {
             mean: 0.85,
             stderr: 0.0,
             count: 1,
+            criteria: None,
         },
     );
 
@@ -394,154 +394,12 @@ pub enum ToolFailure {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use durable_tools::{ActionInput, ActionResponse, CreateEventResponse, TensorZeroClientError};
-    use mockall::mock;
+    use durable_tools::{CreateEventResponse, MockTensorZeroClient, TensorZeroClientError};
     use schemars::JsonSchema;
-    use tensorzero::{
-        ClientInferenceParams, CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams,
-        CreateDatapointsResponse, DeleteDatapointsResponse, FeedbackParams, FeedbackResponse,
-        GetConfigResponse, GetDatapointsResponse, GetInferencesRequest, GetInferencesResponse,
-        InferenceResponse, ListDatapointsRequest, ListDatasetsRequest, ListDatasetsResponse,
-        ListInferencesRequest, UpdateDatapointRequest, UpdateDatapointsResponse,
-        WriteConfigRequest, WriteConfigResponse,
-    };
-    use tensorzero_core::config::snapshot::SnapshotHash;
-    use tensorzero_core::db::feedback::FeedbackByVariant;
-    use tensorzero_core::endpoints::feedback::internal::LatestFeedbackIdByMetricResponse;
-    use tensorzero_core::optimization::{OptimizationJobHandle, OptimizationJobInfo};
-    use tensorzero_optimizers::endpoints::LaunchOptimizationWorkflowParams;
-
-    // Mock TensorZeroClient using mockall::mock! macro
-    // (same pattern as autopilot-tools/tests/common/mod.rs)
-    mock! {
-        pub TensorZeroClient {}
-
-        #[async_trait]
-        impl TensorZeroClient for TensorZeroClient {
-            async fn inference(
-                &self,
-                params: ClientInferenceParams,
-            ) -> Result<InferenceResponse, TensorZeroClientError>;
-
-            async fn feedback(
-                &self,
-                params: FeedbackParams,
-            ) -> Result<FeedbackResponse, TensorZeroClientError>;
-
-            async fn create_autopilot_event(
-                &self,
-                session_id: Uuid,
-                request: CreateEventGatewayRequest,
-            ) -> Result<CreateEventResponse, TensorZeroClientError>;
-
-            async fn list_autopilot_events(
-                &self,
-                session_id: Uuid,
-                params: durable_tools::ListEventsParams,
-            ) -> Result<durable_tools::GatewayListEventsResponse, TensorZeroClientError>;
-
-            async fn list_autopilot_sessions(
-                &self,
-                params: durable_tools::ListSessionsParams,
-            ) -> Result<durable_tools::ListSessionsResponse, TensorZeroClientError>;
-
-            async fn action(
-                &self,
-                snapshot_hash: SnapshotHash,
-                params: ActionInput,
-            ) -> Result<ActionResponse, TensorZeroClientError>;
-
-            async fn get_config_snapshot(
-                &self,
-                hash: Option<String>,
-            ) -> Result<GetConfigResponse, TensorZeroClientError>;
-
-            async fn write_config(
-                &self,
-                request: WriteConfigRequest,
-            ) -> Result<WriteConfigResponse, TensorZeroClientError>;
-
-            async fn create_datapoints(
-                &self,
-                dataset_name: String,
-                datapoints: Vec<CreateDatapointRequest>,
-            ) -> Result<CreateDatapointsResponse, TensorZeroClientError>;
-
-            async fn create_datapoints_from_inferences(
-                &self,
-                dataset_name: String,
-                params: CreateDatapointsFromInferenceRequestParams,
-            ) -> Result<CreateDatapointsResponse, TensorZeroClientError>;
-
-            async fn list_datasets(
-                &self,
-                request: ListDatasetsRequest,
-            ) -> Result<ListDatasetsResponse, TensorZeroClientError>;
-
-            async fn list_datapoints(
-                &self,
-                dataset_name: String,
-                request: ListDatapointsRequest,
-            ) -> Result<GetDatapointsResponse, TensorZeroClientError>;
-
-            async fn get_datapoints(
-                &self,
-                dataset_name: Option<String>,
-                ids: Vec<Uuid>,
-            ) -> Result<GetDatapointsResponse, TensorZeroClientError>;
-
-            async fn update_datapoints(
-                &self,
-                dataset_name: String,
-                datapoints: Vec<UpdateDatapointRequest>,
-            ) -> Result<UpdateDatapointsResponse, TensorZeroClientError>;
-
-            async fn delete_datapoints(
-                &self,
-                dataset_name: String,
-                ids: Vec<Uuid>,
-            ) -> Result<DeleteDatapointsResponse, TensorZeroClientError>;
-
-            /// List inferences with filtering and pagination.
-            async fn list_inferences(
-                &self,
-                request: ListInferencesRequest,
-            ) -> Result<GetInferencesResponse, TensorZeroClientError>;
-
-            /// Get specific inferences by their IDs.
-            async fn get_inferences(
-                &self,
-                request: GetInferencesRequest,
-            ) -> Result<GetInferencesResponse, TensorZeroClientError>;
-
-            async fn launch_optimization_workflow(
-                &self,
-                params: LaunchOptimizationWorkflowParams,
-            ) -> Result<OptimizationJobHandle, TensorZeroClientError>;
-
-            async fn poll_optimization(
-                &self,
-                job_handle: &OptimizationJobHandle,
-            ) -> Result<OptimizationJobInfo, TensorZeroClientError>;
-
-            async fn get_latest_feedback_id_by_metric(
-                &self,
-                target_id: Uuid,
-            ) -> Result<LatestFeedbackIdByMetricResponse, TensorZeroClientError>;
-
-            async fn get_feedback_by_variant(
-                &self,
-                metric_name: String,
-                function_name: String,
-                variant_names: Option<Vec<String>>,
-            ) -> Result<Vec<FeedbackByVariant>, TensorZeroClientError>;
-
-            async fn run_evaluation(
-                &self,
-                params: durable_tools::RunEvaluationParams,
-            ) -> Result<durable_tools::RunEvaluationResponse, TensorZeroClientError>;
-        }
-    }
+
+    // `MockTensorZeroClient` used to be hand-rolled here via `mockall::mock!` (same pattern as
+    // autopilot-tools/tests/common/mod.rs). It's now shared from `durable-tools`' `testing`
+    // module, behind its `test-support` feature.
 
     // ===== Test TaskTool for wrapper testing =====
 
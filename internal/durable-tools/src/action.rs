@@ -130,6 +130,7 @@ pub async fn action(
                 app_state.postgres_connection_info.clone(),
                 app_state.deferred_tasks.clone(),
                 app_state.rate_limiting_manager.clone(),
+                app_state.hot_cache.clone(),
                 (*inference_params).try_into()?,
                 None, // No API key for internal endpoint
             ))
@@ -0,0 +1,97 @@
+//! In-process job tracking for asynchronous (fire-and-poll) evaluation runs.
+//!
+//! `run_evaluation` blocks until the entire evaluation completes, which can
+//! exceed tool call timeouts for large datasets. [`EvaluationJobRegistry`]
+//! lets a caller start an evaluation in the background and poll its status
+//! by handle instead.
+//!
+//! Jobs are tracked in memory only: there is no durable task queue backing
+//! evaluations in this codebase (unlike optimization jobs, which poll an
+//! external provider and so need no local state at all). A worker restart
+//! loses all in-flight job state; callers that need resumability across
+//! restarts should persist `RunEvaluationParams` themselves and re-`start`
+//! after a crash.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use tensorzero_core::utils::gateway::AppStateData;
+
+use crate::run_evaluation::{RunEvaluationParams, RunEvaluationResponse, run_evaluation};
+
+/// Handle to a background evaluation job, returned by `start_evaluation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationJobHandle {
+    pub job_id: Uuid,
+}
+
+/// Current status of a background evaluation job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status")]
+#[serde(rename_all = "snake_case")]
+pub enum EvaluationJobStatus {
+    /// The job is still running.
+    Running,
+    /// The job finished successfully.
+    Completed { response: RunEvaluationResponse },
+    /// The job failed.
+    Failed { error: String },
+}
+
+/// Tracks in-flight and completed evaluation jobs, keyed by job ID.
+///
+/// Cloning an `EvaluationJobRegistry` is cheap: it shares the same
+/// underlying job map (via `Arc`), matching the pattern used for other
+/// shared gateway state.
+#[derive(Clone, Default)]
+pub struct EvaluationJobRegistry {
+    jobs: Arc<Mutex<HashMap<Uuid, EvaluationJobStatus>>>,
+}
+
+impl EvaluationJobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns `params` as a background evaluation job and returns a handle
+    /// immediately, without waiting for the job to complete.
+    pub fn start(
+        &self,
+        app_state: AppStateData,
+        params: RunEvaluationParams,
+    ) -> EvaluationJobHandle {
+        let job_id = Uuid::now_v7();
+        self.jobs
+            .lock()
+            .expect("evaluation job registry lock poisoned")
+            .insert(job_id, EvaluationJobStatus::Running);
+
+        let jobs = self.jobs.clone();
+        tokio::spawn(async move {
+            let status = match run_evaluation(app_state, &params).await {
+                Ok(response) => EvaluationJobStatus::Completed { response },
+                Err(e) => EvaluationJobStatus::Failed {
+                    error: e.to_string(),
+                },
+            };
+            jobs.lock()
+                .expect("evaluation job registry lock poisoned")
+                .insert(job_id, status);
+        });
+
+        EvaluationJobHandle { job_id }
+    }
+
+    /// Returns the current status of a job, or `None` if `job_id` is unknown
+    /// (never started, or started by a process that has since restarted).
+    pub fn poll(&self, job_handle: &EvaluationJobHandle) -> Option<EvaluationJobStatus> {
+        self.jobs
+            .lock()
+            .expect("evaluation job registry lock poisoned")
+            .get(&job_handle.job_id)
+            .cloned()
+    }
+}
@@ -169,12 +169,15 @@
 pub mod action;
 mod context;
 mod error;
+pub mod evaluation_jobs;
 mod executor;
 mod registry;
 pub mod run_evaluation;
 mod simple_tool;
 mod task_tool;
 pub mod tensorzero_client;
+#[cfg(any(test, feature = "test-support"))]
+pub mod testing;
 mod tool_metadata;
 
 #[cfg(test)]
@@ -222,6 +225,11 @@ pub use tensorzero_client::{
     UpdateDatapointsResponse,
 };
 
+// Re-export demonstration feedback types
+pub use tensorzero_client::{
+    DemonstrationSubmission, DemonstrationSubmissionOutcome, SubmitDemonstrationsResponse,
+};
+
 // Re-export inference query filter and ordering types
 pub use tensorzero::{
     BooleanMetricFilter, FloatComparisonOperator, FloatMetricFilter, InferenceFilter,
@@ -232,10 +240,21 @@ pub use tensorzero::{
 // Re-export config snapshot types for historical inference
 pub use tensorzero_client::SnapshotHash;
 
+// Re-export config snapshot listing/tagging types
+pub use tensorzero_client::{
+    ConfigSnapshotTagFilter, ListConfigSnapshotsResponse, UpdateSnapshotTagsResponse,
+};
+
 // Re-export action and evaluation types
 pub use tensorzero_client::{
     ActionInput, ActionInputInfo, ActionResponse, CacheEnabledMode, DatapointResult,
-    EvaluatorStats, RunEvaluationParams, RunEvaluationResponse,
+    EvaluationJobHandle, EvaluationJobStatus, EvaluatorStats, RunEvaluationParams,
+    RunEvaluationResponse,
+};
+
+// Re-export optimization pipeline types
+pub use tensorzero_client::{
+    RunOptimizationPipelineParams, RunOptimizationPipelineResult, register_optimizer_output,
 };
 
 // Re-export TensorZero inference types for convenience
@@ -7,16 +7,23 @@
 use async_trait::async_trait;
 use autopilot_client::AutopilotError;
 use autopilot_client::GatewayListEventsResponse;
+use std::collections::HashMap;
+
 use tensorzero::{
-    Client, ClientExt, ClientInferenceParams, ClientMode, CreateDatapointRequest,
-    CreateDatapointsFromInferenceRequestParams, CreateDatapointsResponse, DeleteDatapointsResponse,
-    FeedbackParams, FeedbackResponse, GetConfigResponse, GetDatapointsResponse,
-    GetInferencesRequest, GetInferencesResponse, InferenceOutput, InferenceResponse,
-    ListDatapointsRequest, ListDatasetsRequest, ListDatasetsResponse, ListInferencesRequest,
-    TensorZeroError, UpdateDatapointRequest, UpdateDatapointsResponse, WriteConfigRequest,
-    WriteConfigResponse,
+    CacheStats, Client, ClientEmbeddingParams, ClientExt, ClientInferenceParams, ClientMode,
+    ConfigSnapshotTagFilter, CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams,
+    CreateDatapointsResponse, DeduplicateDatapointsRequest, DeduplicateDatapointsResponse,
+    DeleteDatapointsResponse, EmbeddingResponse, EvaluationRunComparison, FeedbackParams,
+    FeedbackResponse, GetConfigResponse, GetDatapointsResponse, GetInferencesRequest,
+    GetInferencesResponse, InferenceOutput, InferenceResponse, InvalidateCacheParams,
+    InvalidateCacheResponse, ListConfigSnapshotsResponse, ListDatapointsRequest,
+    ListDatasetsRequest, ListDatasetsResponse, ListInferencesRequest, TensorZeroError,
+    UpdateDatapointRequest, UpdateDatapointsResponse, UpdateSnapshotTagsResponse,
+    ValidateConfigRequest, ValidateConfigResponse, WriteConfigRequest, WriteConfigResponse,
 };
 use tensorzero_core::config::snapshot::SnapshotHash;
+use tensorzero_core::db::TimeWindow;
+use tensorzero_core::db::feedback::BucketedFeedbackTimeSeriesPoint;
 use tensorzero_core::db::feedback::FeedbackByVariant;
 use tensorzero_core::db::feedback::FeedbackQueries;
 use tensorzero_core::endpoints::feedback::internal::{
@@ -32,9 +39,9 @@ use uuid::Uuid;
 use crate::action::{ActionInput, ActionInputInfo, ActionResponse};
 
 use super::{
-    CreateEventGatewayRequest, CreateEventResponse, ListEventsParams, ListSessionsParams,
-    ListSessionsResponse, RunEvaluationParams, RunEvaluationResponse, TensorZeroClient,
-    TensorZeroClientError,
+    CreateEventGatewayRequest, CreateEventResponse, EvaluationJobHandle, EvaluationJobStatus,
+    ListEventsParams, ListSessionsParams, ListSessionsResponse, RunEvaluationParams,
+    RunEvaluationResponse, TensorZeroClient, TensorZeroClientError,
 };
 
 /// Implementation of `TensorZeroClient` for the TensorZero SDK `Client`.
@@ -347,6 +354,51 @@ impl TensorZeroClient for Client {
             .map_err(TensorZeroClientError::TensorZero)
     }
 
+    async fn list_config_snapshots(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag_filter: Option<ConfigSnapshotTagFilter>,
+    ) -> Result<ListConfigSnapshotsResponse, TensorZeroClientError> {
+        ClientExt::list_config_snapshots(self, limit, offset, tag_filter)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
+    async fn update_snapshot_tags(
+        &self,
+        config_snapshot_hash: String,
+        tags: HashMap<String, String>,
+    ) -> Result<UpdateSnapshotTagsResponse, TensorZeroClientError> {
+        ClientExt::update_snapshot_tags(self, &config_snapshot_hash, tags)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
+    async fn validate_config(
+        &self,
+        request: ValidateConfigRequest,
+    ) -> Result<ValidateConfigResponse, TensorZeroClientError> {
+        ClientExt::validate_config(self, request)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
+    async fn get_cache_stats(&self) -> Result<CacheStats, TensorZeroClientError> {
+        ClientExt::get_cache_stats(self)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
+    async fn invalidate_cache(
+        &self,
+        params: InvalidateCacheParams,
+    ) -> Result<InvalidateCacheResponse, TensorZeroClientError> {
+        ClientExt::invalidate_cache(self, params)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
     // ========== Datapoint CRUD Operations ==========
 
     async fn create_datapoints(
@@ -388,6 +440,18 @@ impl TensorZeroClient for Client {
             .map_err(TensorZeroClientError::TensorZero)
     }
 
+    async fn compare_evaluation_runs(
+        &self,
+        run_a: Uuid,
+        run_b: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunComparison, TensorZeroClientError> {
+        ClientExt::compare_evaluation_runs(self, run_a, run_b, evaluation_name, function_name)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
     async fn get_datapoints(
         &self,
         dataset_name: Option<String>,
@@ -418,6 +482,16 @@ impl TensorZeroClient for Client {
             .map_err(TensorZeroClientError::TensorZero)
     }
 
+    async fn deduplicate_datapoints(
+        &self,
+        dataset_name: String,
+        request: DeduplicateDatapointsRequest,
+    ) -> Result<DeduplicateDatapointsResponse, TensorZeroClientError> {
+        ClientExt::deduplicate_datapoints(self, dataset_name, request)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
     // ========== Inference Query Operations ==========
 
     async fn list_inferences(
@@ -443,6 +517,17 @@ impl TensorZeroClient for Client {
         .map_err(TensorZeroClientError::TensorZero)
     }
 
+    // ========== Embedding Operations ==========
+
+    async fn embed(
+        &self,
+        params: ClientEmbeddingParams,
+    ) -> Result<EmbeddingResponse, TensorZeroClientError> {
+        Client::embed(self, params)
+            .await
+            .map_err(TensorZeroClientError::TensorZero)
+    }
+
     // ========== Optimization Operations ==========
 
     async fn launch_optimization_workflow(
@@ -592,14 +677,74 @@ impl TensorZeroClient for Client {
         }
     }
 
+    async fn get_feedback_timeseries(
+        &self,
+        function_name: String,
+        metric_name: String,
+        variant_names: Option<Vec<String>>,
+        time_window: TimeWindow,
+        max_periods: u32,
+    ) -> Result<Vec<BucketedFeedbackTimeSeriesPoint>, TensorZeroClientError> {
+        match self.mode() {
+            ClientMode::HTTPGateway(_) => Err(TensorZeroClientError::NotSupported(
+                "get_feedback_timeseries is only available in embedded mode".to_string(),
+            )),
+            ClientMode::EmbeddedGateway {
+                gateway,
+                timeout: _,
+            } => gateway
+                .handle
+                .app_state
+                .clickhouse_connection_info
+                .get_feedback_timeseries(
+                    function_name,
+                    metric_name,
+                    variant_names,
+                    time_window,
+                    max_periods,
+                )
+                .await
+                .map_err(|e| {
+                    TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
+                }),
+        }
+    }
+
     async fn run_evaluation(
         &self,
         params: RunEvaluationParams,
     ) -> Result<RunEvaluationResponse, TensorZeroClientError> {
         match self.mode() {
-            ClientMode::HTTPGateway(_) => Err(TensorZeroClientError::NotSupported(
-                "run_evaluation is only supported in embedded gateway mode".to_string(),
-            )),
+            ClientMode::HTTPGateway(http) => {
+                let url = http
+                    .base_url
+                    .join("evaluations/run")
+                    .map_err(|e: url::ParseError| {
+                        TensorZeroClientError::Autopilot(AutopilotError::InvalidUrl(e))
+                    })?;
+
+                let response = http
+                    .http_client
+                    .post(url)
+                    .json(&params)
+                    .send()
+                    .await
+                    .map_err(|e| TensorZeroClientError::Autopilot(AutopilotError::Request(e)))?;
+
+                if !response.status().is_success() {
+                    let status = response.status().as_u16();
+                    let text = response.text().await.unwrap_or_default();
+                    return Err(TensorZeroClientError::Autopilot(AutopilotError::Http {
+                        status_code: status,
+                        message: text,
+                    }));
+                }
+
+                response
+                    .json()
+                    .await
+                    .map_err(|e| TensorZeroClientError::Autopilot(AutopilotError::Request(e)))
+            }
             ClientMode::EmbeddedGateway {
                 gateway,
                 timeout: _,
@@ -608,4 +753,29 @@ impl TensorZeroClient for Client {
                 .map_err(|e| TensorZeroClientError::Evaluation(e.to_string())),
         }
     }
+
+    // `start_evaluation`/`poll_evaluation` need somewhere to keep job state
+    // between the two calls. `EmbeddedClient` (used by durable-tools workers)
+    // owns a long-lived `EvaluationJobRegistry` for this, but a `Client` may
+    // be constructed fresh per call site, so it has nowhere durable-within-a-
+    // process to stash job state. Route both operations through
+    // `EmbeddedClient` instead of the SDK `Client`.
+    async fn start_evaluation(
+        &self,
+        _params: RunEvaluationParams,
+    ) -> Result<EvaluationJobHandle, TensorZeroClientError> {
+        Err(TensorZeroClientError::NotSupported(
+            "start_evaluation requires the EmbeddedClient used by durable-tools workers"
+                .to_string(),
+        ))
+    }
+
+    async fn poll_evaluation(
+        &self,
+        _job_handle: &EvaluationJobHandle,
+    ) -> Result<EvaluationJobStatus, TensorZeroClientError> {
+        Err(TensorZeroClientError::NotSupported(
+            "poll_evaluation requires the EmbeddedClient used by durable-tools workers".to_string(),
+        ))
+    }
 }
@@ -5,37 +5,52 @@
 
 use async_trait::async_trait;
 use autopilot_client::GatewayListEventsResponse;
+use std::collections::HashMap;
+
 use tensorzero::{
-    ClientInferenceParams, CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams,
-    CreateDatapointsResponse, DeleteDatapointsResponse, FeedbackParams, FeedbackResponse,
+    CacheStats, ClientEmbeddingParams, ClientInferenceParams, ConfigSnapshotTagFilter,
+    CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams, CreateDatapointsResponse,
+    DeduplicateDatapointsRequest, DeduplicateDatapointsResponse, DeleteDatapointsResponse,
+    EmbeddingResponse, EvaluationRunComparison, FeedbackParams, FeedbackResponse,
     GetConfigResponse, GetDatapointsResponse, GetInferencesRequest, GetInferencesResponse,
-    InferenceOutput, InferenceResponse, ListDatapointsRequest, ListDatasetsRequest,
-    ListDatasetsResponse, ListInferencesRequest, TensorZeroError, UpdateDatapointRequest,
-    UpdateDatapointsResponse, WriteConfigRequest, WriteConfigResponse,
+    InferenceOutput, InferenceResponse, InvalidateCacheParams, InvalidateCacheResponse,
+    ListConfigSnapshotsResponse, ListDatapointsRequest, ListDatasetsRequest, ListDatasetsResponse,
+    ListInferencesRequest, TensorZeroError, UpdateDatapointRequest, UpdateDatapointsResponse,
+    UpdateSnapshotTagsResponse, ValidateConfigRequest, ValidateConfigResponse, WriteConfigRequest,
+    WriteConfigResponse,
 };
-use tensorzero_core::config::snapshot::{ConfigSnapshot, SnapshotHash};
-use tensorzero_core::config::write_config_snapshot;
+use tensorzero_core::config::snapshot::SnapshotHash;
 use tensorzero_core::db::ConfigQueries;
+use tensorzero_core::db::TimeWindow;
+use tensorzero_core::db::cache_queries::CacheQueries;
+use tensorzero_core::db::feedback::BucketedFeedbackTimeSeriesPoint;
 use tensorzero_core::db::feedback::FeedbackByVariant;
 use tensorzero_core::db::feedback::FeedbackQueries;
 use tensorzero_core::endpoints::datasets::v1::types::{
     CreateDatapointsFromInferenceRequest, CreateDatapointsRequest, DeleteDatapointsRequest,
     GetDatapointsRequest, UpdateDatapointsRequest,
 };
+use tensorzero_core::endpoints::embeddings::embeddings;
 use tensorzero_core::endpoints::feedback::feedback;
 use tensorzero_core::endpoints::feedback::internal::LatestFeedbackIdByMetricResponse;
 use tensorzero_core::endpoints::inference::inference;
 use tensorzero_core::endpoints::internal::autopilot::{create_event, list_events, list_sessions};
+use tensorzero_core::endpoints::internal::cache::invalidate_cache;
+use tensorzero_core::endpoints::internal::config::{
+    ListConfigSnapshotsRequest, list_config_snapshots, update_snapshot_tags, validate_config,
+    write_config,
+};
 use tensorzero_core::error::{Error, ErrorDetails};
 use tensorzero_core::utils::gateway::AppStateData;
 use uuid::Uuid;
 
 use crate::action::{ActionInput, ActionInputInfo, ActionResponse};
+use crate::evaluation_jobs::EvaluationJobRegistry;
 
 use super::{
-    CreateEventGatewayRequest, CreateEventResponse, ListEventsParams, ListSessionsParams,
-    ListSessionsResponse, RunEvaluationParams, RunEvaluationResponse, TensorZeroClient,
-    TensorZeroClientError,
+    CreateEventGatewayRequest, CreateEventResponse, EvaluationJobHandle, EvaluationJobStatus,
+    ListEventsParams, ListSessionsParams, ListSessionsResponse, RunEvaluationParams,
+    RunEvaluationResponse, TensorZeroClient, TensorZeroClientError,
 };
 
 /// TensorZero client that uses an existing gateway's state directly.
@@ -44,12 +59,16 @@ use super::{
 /// call inference and autopilot endpoints without HTTP overhead.
 pub struct EmbeddedClient {
     app_state: AppStateData,
+    evaluation_jobs: EvaluationJobRegistry,
 }
 
 impl EmbeddedClient {
     /// Create a new embedded client from gateway state.
     pub fn new(app_state: AppStateData) -> Self {
-        Self { app_state }
+        Self {
+            app_state,
+            evaluation_jobs: EvaluationJobRegistry::new(),
+        }
     }
 }
 
@@ -72,6 +91,7 @@ impl TensorZeroClient for EmbeddedClient {
             self.app_state.postgres_connection_info.clone(),
             self.app_state.deferred_tasks.clone(),
             self.app_state.rate_limiting_manager.clone(),
+            self.app_state.hot_cache.clone(),
             internal_params,
             None, // No API key in embedded mode
         ))
@@ -224,21 +244,76 @@ impl TensorZeroClient for EmbeddedClient {
         &self,
         request: WriteConfigRequest,
     ) -> Result<WriteConfigResponse, TensorZeroClientError> {
-        let mut snapshot =
-            ConfigSnapshot::new(request.config, request.extra_templates).map_err(|e| {
-                TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
-            })?;
-        snapshot.tags = request.tags;
+        write_config(
+            &self.app_state.clickhouse_connection_info,
+            &self.app_state.config,
+            request,
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
+
+    async fn list_config_snapshots(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag_filter: Option<ConfigSnapshotTagFilter>,
+    ) -> Result<ListConfigSnapshotsResponse, TensorZeroClientError> {
+        list_config_snapshots(
+            &self.app_state.clickhouse_connection_info,
+            ListConfigSnapshotsRequest {
+                limit,
+                offset,
+                tag_key: tag_filter.as_ref().map(|f| f.key.clone()),
+                tag_value: tag_filter.as_ref().map(|f| f.value.clone()),
+            },
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
+
+    async fn update_snapshot_tags(
+        &self,
+        config_snapshot_hash: String,
+        tags: HashMap<String, String>,
+    ) -> Result<UpdateSnapshotTagsResponse, TensorZeroClientError> {
+        update_snapshot_tags(
+            &self.app_state.clickhouse_connection_info,
+            &config_snapshot_hash,
+            tags,
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
 
-        let hash = snapshot.hash.to_string();
+    async fn validate_config(
+        &self,
+        request: ValidateConfigRequest,
+    ) -> Result<ValidateConfigResponse, TensorZeroClientError> {
+        Ok(validate_config(&self.app_state.config, request).await)
+    }
 
-        write_config_snapshot(&self.app_state.clickhouse_connection_info, snapshot)
+    async fn get_cache_stats(&self) -> Result<CacheStats, TensorZeroClientError> {
+        self.app_state
+            .clickhouse_connection_info
+            .get_cache_stats()
             .await
             .map_err(|e| {
                 TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
-            })?;
+            })
+    }
 
-        Ok(WriteConfigResponse { hash })
+    async fn invalidate_cache(
+        &self,
+        params: InvalidateCacheParams,
+    ) -> Result<InvalidateCacheResponse, TensorZeroClientError> {
+        let invalidated_count =
+            invalidate_cache(&self.app_state.clickhouse_connection_info, params)
+                .await
+                .map_err(|e| {
+                    TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
+                })?;
+        Ok(InvalidateCacheResponse { invalidated_count })
     }
 
     // ========== Datapoint CRUD Operations ==========
@@ -290,6 +365,39 @@ impl TensorZeroClient for EmbeddedClient {
         .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
     }
 
+    async fn compare_evaluation_runs(
+        &self,
+        run_a: Uuid,
+        run_b: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunComparison, TensorZeroClientError> {
+        let config = &self.app_state.config;
+        let evaluation_config = config.evaluations.get(&evaluation_name).ok_or_else(|| {
+            TensorZeroClientError::TensorZero(TensorZeroError::Other {
+                source: Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Unknown evaluation: {evaluation_name}"),
+                })
+                .into(),
+            })
+        })?;
+        let function_config =
+            tensorzero_core::function::get_function(&config.functions, &function_name).map_err(
+                |e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }),
+            )?;
+        tensorzero_core::endpoints::internal::evaluations::compare_evaluation_runs(
+            &self.app_state.clickhouse_connection_info,
+            run_a,
+            run_b,
+            &evaluation_name,
+            &function_name,
+            function_config.config_type(),
+            evaluation_config,
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
+
     async fn list_datapoints(
         &self,
         dataset_name: String,
@@ -352,6 +460,20 @@ impl TensorZeroClient for EmbeddedClient {
         .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
     }
 
+    async fn deduplicate_datapoints(
+        &self,
+        dataset_name: String,
+        request: DeduplicateDatapointsRequest,
+    ) -> Result<DeduplicateDatapointsResponse, TensorZeroClientError> {
+        tensorzero_core::endpoints::datasets::v1::deduplicate_datapoints(
+            &self.app_state.clickhouse_connection_info,
+            &dataset_name,
+            request,
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
+
     // ========== Inference Query Operations ==========
 
     async fn list_inferences(
@@ -380,6 +502,32 @@ impl TensorZeroClient for EmbeddedClient {
         .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
     }
 
+    // ========== Embedding Operations ==========
+
+    async fn embed(
+        &self,
+        params: ClientEmbeddingParams,
+    ) -> Result<EmbeddingResponse, TensorZeroClientError> {
+        let internal_params = params
+            .try_into()
+            .map_err(|e: tensorzero_core::error::Error| {
+                TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
+            })?;
+
+        embeddings(
+            self.app_state.config.clone(),
+            &self.app_state.http_client,
+            self.app_state.clickhouse_connection_info.clone(),
+            self.app_state.postgres_connection_info.clone(),
+            self.app_state.deferred_tasks.clone(),
+            self.app_state.rate_limiting_manager.clone(),
+            internal_params,
+            None, // No API key in embedded mode
+        )
+        .await
+        .map_err(|e| TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() }))
+    }
+
     // ========== Optimization Operations ==========
 
     async fn launch_optimization_workflow(
@@ -437,6 +585,29 @@ impl TensorZeroClient for EmbeddedClient {
             })
     }
 
+    async fn get_feedback_timeseries(
+        &self,
+        function_name: String,
+        metric_name: String,
+        variant_names: Option<Vec<String>>,
+        time_window: TimeWindow,
+        max_periods: u32,
+    ) -> Result<Vec<BucketedFeedbackTimeSeriesPoint>, TensorZeroClientError> {
+        self.app_state
+            .clickhouse_connection_info
+            .get_feedback_timeseries(
+                function_name,
+                metric_name,
+                variant_names,
+                time_window,
+                max_periods,
+            )
+            .await
+            .map_err(|e| {
+                TensorZeroClientError::TensorZero(TensorZeroError::Other { source: e.into() })
+            })
+    }
+
     async fn run_evaluation(
         &self,
         params: RunEvaluationParams,
@@ -445,4 +616,23 @@ impl TensorZeroClient for EmbeddedClient {
             .await
             .map_err(|e| TensorZeroClientError::Evaluation(e.to_string()))
     }
+
+    async fn start_evaluation(
+        &self,
+        params: RunEvaluationParams,
+    ) -> Result<EvaluationJobHandle, TensorZeroClientError> {
+        Ok(self.evaluation_jobs.start(self.app_state.clone(), params))
+    }
+
+    async fn poll_evaluation(
+        &self,
+        job_handle: &EvaluationJobHandle,
+    ) -> Result<EvaluationJobStatus, TensorZeroClientError> {
+        self.evaluation_jobs.poll(job_handle).ok_or_else(|| {
+            TensorZeroClientError::NotSupported(format!(
+                "No evaluation job found for job_id `{}` (it may predate a worker restart)",
+                job_handle.job_id
+            ))
+        })
+    }
 }
@@ -6,23 +6,33 @@
 
 mod client_ext;
 mod embedded;
+mod optimization_pipeline;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 pub use tensorzero::{
-    Client, ClientBuilder, ClientBuilderError, ClientBuilderMode, ClientInferenceParams,
-    CreateDatapointRequest, CreateDatapointsFromInferenceRequestParams, CreateDatapointsResponse,
-    DeleteDatapointsResponse, FeedbackParams, FeedbackResponse, GetConfigResponse,
-    GetDatapointsResponse, InferenceResponse, ListDatapointsRequest, ListDatasetsRequest,
-    ListDatasetsResponse, PostgresConfig, TensorZeroError, UpdateDatapointRequest,
-    UpdateDatapointsResponse, WriteConfigRequest, WriteConfigResponse,
+    CacheStats, Client, ClientBuilder, ClientBuilderError, ClientBuilderMode,
+    ClientEmbeddingParams, ClientInferenceParams, ConfigSnapshotTagFilter, CreateDatapointRequest,
+    CreateDatapointsFromInferenceRequestParams, CreateDatapointsResponse,
+    DeduplicateDatapointsRequest, DeduplicateDatapointsResponse, DeleteDatapointsResponse,
+    EmbeddingResponse, EvaluationRunComparison, FeedbackParams, FeedbackResponse,
+    GetConfigResponse, GetDatapointsResponse, InferenceResponse, InvalidateCacheParams,
+    InvalidateCacheResponse, ListConfigSnapshotsResponse, ListDatapointsRequest,
+    ListDatasetsRequest, ListDatasetsResponse, PostgresConfig, TensorZeroError,
+    UpdateDatapointRequest, UpdateDatapointsResponse, UpdateSnapshotTagsResponse,
+    ValidateConfigRequest, ValidateConfigResponse, WriteConfigRequest, WriteConfigResponse,
+};
+use tensorzero::{
+    GetInferencesRequest, GetInferencesResponse, InferenceOutputSource, ListInferencesRequest,
 };
-use tensorzero::{GetInferencesRequest, GetInferencesResponse, ListInferencesRequest};
 pub use tensorzero_core::cache::CacheEnabledMode;
 pub use tensorzero_core::config::snapshot::SnapshotHash;
-use tensorzero_core::db::feedback::FeedbackByVariant;
+use tensorzero_core::db::TimeWindow;
+use tensorzero_core::db::feedback::{BucketedFeedbackTimeSeriesPoint, FeedbackByVariant};
 use tensorzero_core::endpoints::feedback::internal::LatestFeedbackIdByMetricResponse;
 pub use tensorzero_core::optimization::OptimizationJobHandle;
 pub use tensorzero_core::optimization::OptimizationJobInfo;
@@ -33,6 +43,11 @@ use uuid::Uuid;
 // Re-export client implementations
 pub use embedded::EmbeddedClient;
 
+// Re-export optimization pipeline types from the optimization_pipeline submodule
+pub use optimization_pipeline::{
+    RunOptimizationPipelineParams, RunOptimizationPipelineResult, register_optimizer_output,
+};
+
 // Re-export autopilot types for use by tools
 pub use autopilot_client::{
     CreateEventResponse, EventPayload, EventPayloadToolResult, GatewayListEventsResponse,
@@ -48,6 +63,9 @@ pub use crate::run_evaluation::{
     DatapointResult, EvaluatorStats, RunEvaluationParams, RunEvaluationResponse,
 };
 
+// Re-export async evaluation job types from crate::evaluation_jobs
+pub use crate::evaluation_jobs::{EvaluationJobHandle, EvaluationJobStatus};
+
 #[cfg(any(test, feature = "test-support"))]
 use mockall::automock;
 
@@ -77,6 +95,41 @@ pub enum TensorZeroClientError {
     /// Evaluation error.
     #[error("Evaluation error: {0}")]
     Evaluation(String),
+
+    /// Error from `run_optimization_pipeline` (timeout, missing function/variant, or an
+    /// unexpected response from a step in the pipeline).
+    #[error("Optimization pipeline error: {0}")]
+    Pipeline(String),
+}
+
+/// A single demonstration to submit via `TensorZeroClient::submit_demonstrations`: the corrected
+/// output for an inference or episode, in the same value shape `FeedbackParams` expects for
+/// `metric_name: "demonstration"` (a string or an array of content blocks).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemonstrationSubmission {
+    /// The episode ID this demonstration corrects. Exactly one of `episode_id`/`inference_id` must be set.
+    pub episode_id: Option<Uuid>,
+    /// The inference ID this demonstration corrects. Exactly one of `episode_id`/`inference_id` must be set.
+    pub inference_id: Option<Uuid>,
+    /// The corrected output: a string, or an array of content blocks for tool calls.
+    pub value: serde_json::Value,
+    /// Tags to attach to the resulting feedback.
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+/// The outcome of submitting a single demonstration via `submit_demonstrations`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum DemonstrationSubmissionOutcome {
+    Submitted { feedback_id: Uuid },
+    Failed { error: String },
+}
+
+/// Response from `submit_demonstrations`: one outcome per input demonstration, in request order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitDemonstrationsResponse {
+    pub results: Vec<DemonstrationSubmissionOutcome>,
 }
 
 /// Trait for TensorZero client operations, enabling mocking in tests via mockall.
@@ -107,6 +160,43 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         params: FeedbackParams,
     ) -> Result<FeedbackResponse, TensorZeroClientError>;
 
+    /// Submit multiple demonstrations (corrected outputs) in one call.
+    ///
+    /// There's no batch feedback endpoint, so this is composed from repeated calls to
+    /// `feedback`, each converted into a `FeedbackParams` with `metric_name: "demonstration"`.
+    /// Submissions are independent: one failing doesn't stop the rest, and the response reports
+    /// each outcome in request order, so a caller assembling a fine-tuning dataset from a large
+    /// batch of corrections can retry just the failures instead of the whole batch.
+    async fn submit_demonstrations(
+        &self,
+        demonstrations: Vec<DemonstrationSubmission>,
+    ) -> Result<SubmitDemonstrationsResponse, TensorZeroClientError> {
+        let mut results = Vec::with_capacity(demonstrations.len());
+        for demonstration in demonstrations {
+            let outcome = match self
+                .feedback(FeedbackParams {
+                    episode_id: demonstration.episode_id,
+                    inference_id: demonstration.inference_id,
+                    metric_name: "demonstration".to_string(),
+                    value: demonstration.value,
+                    internal: true,
+                    tags: demonstration.tags,
+                    dryrun: None,
+                })
+                .await
+            {
+                Ok(response) => DemonstrationSubmissionOutcome::Submitted {
+                    feedback_id: response.feedback_id,
+                },
+                Err(e) => DemonstrationSubmissionOutcome::Failed {
+                    error: e.to_string(),
+                },
+            };
+            results.push(outcome);
+        }
+        Ok(SubmitDemonstrationsResponse { results })
+    }
+
     /// Get the latest feedback ID for each metric for a target.
     async fn get_latest_feedback_id_by_metric(
         &self,
@@ -126,6 +216,20 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         variant_names: Option<Vec<String>>,
     ) -> Result<Vec<FeedbackByVariant>, TensorZeroClientError>;
 
+    /// Get a bucketed (non-cumulative) time series of feedback statistics for a function and
+    /// metric, one point per variant per time bucket. Useful for spotting drift over time,
+    /// unlike the lifetime aggregates from `get_feedback_by_variant`.
+    ///
+    /// Note: This method only works in embedded mode (no HTTP endpoint available).
+    async fn get_feedback_timeseries(
+        &self,
+        function_name: String,
+        metric_name: String,
+        variant_names: Option<Vec<String>>,
+        time_window: TimeWindow,
+        max_periods: u32,
+    ) -> Result<Vec<BucketedFeedbackTimeSeriesPoint>, TensorZeroClientError>;
+
     /// Create an event in an autopilot session.
     ///
     /// Use `Uuid::nil()` as `session_id` to create a new session.
@@ -178,6 +282,40 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         request: WriteConfigRequest,
     ) -> Result<WriteConfigResponse, TensorZeroClientError>;
 
+    /// Lists config snapshots ordered by creation time (most recent first), with pagination and
+    /// optional tag filtering.
+    async fn list_config_snapshots(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag_filter: Option<ConfigSnapshotTagFilter>,
+    ) -> Result<ListConfigSnapshotsResponse, TensorZeroClientError>;
+
+    /// Merges `tags` into a config snapshot's existing tags (new tags override existing keys),
+    /// leaving its config and templates untouched.
+    async fn update_snapshot_tags(
+        &self,
+        config_snapshot_hash: String,
+        tags: HashMap<String, String>,
+    ) -> Result<UpdateSnapshotTagsResponse, TensorZeroClientError>;
+
+    /// Validates a config (parsing, schema, template, and model validation, optionally including
+    /// credential and object storage checks), without persisting a snapshot.
+    async fn validate_config(
+        &self,
+        request: ValidateConfigRequest,
+    ) -> Result<ValidateConfigResponse, TensorZeroClientError>;
+
+    /// Get the inference cache's hit rate, broken down by model and by function.
+    async fn get_cache_stats(&self) -> Result<CacheStats, TensorZeroClientError>;
+
+    /// Invalidate cached inference outputs for a model. Only model-scoped invalidation is
+    /// currently supported; see [`InvalidateCacheParams`].
+    async fn invalidate_cache(
+        &self,
+        params: InvalidateCacheParams,
+    ) -> Result<InvalidateCacheResponse, TensorZeroClientError>;
+
     // ========== Datapoint CRUD Operations ==========
 
     /// Create datapoints in a dataset.
@@ -200,6 +338,20 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         request: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, TensorZeroClientError>;
 
+    /// Compare two evaluation runs of the same evaluation and function.
+    ///
+    /// Computes per-evaluator deltas between `run_b` and `run_a`, paired on shared datapoint IDs
+    /// where possible, and flags whether each delta is a significant regression given the
+    /// metric's configured optimization direction. Useful for gating a config rollout on whether
+    /// a new variant regresses any evaluator relative to the current one.
+    async fn compare_evaluation_runs(
+        &self,
+        run_a: Uuid,
+        run_b: Uuid,
+        evaluation_name: String,
+        function_name: String,
+    ) -> Result<EvaluationRunComparison, TensorZeroClientError>;
+
     /// List datapoints in a dataset with filtering and pagination.
     async fn list_datapoints(
         &self,
@@ -228,6 +380,13 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         ids: Vec<Uuid>,
     ) -> Result<DeleteDatapointsResponse, TensorZeroClientError>;
 
+    /// Detect and collapse duplicate or near-duplicate datapoints within a dataset.
+    async fn deduplicate_datapoints(
+        &self,
+        dataset_name: String,
+        request: DeduplicateDatapointsRequest,
+    ) -> Result<DeduplicateDatapointsResponse, TensorZeroClientError>;
+
     // ========== Inference Query Operations ==========
 
     /// List inferences with filtering and pagination.
@@ -242,6 +401,35 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         request: GetInferencesRequest,
     ) -> Result<GetInferencesResponse, TensorZeroClientError>;
 
+    /// List inferences that have demonstration feedback (a corrected/human-provided output),
+    /// with the same filtering, pagination, and ordering as `list_inferences`.
+    ///
+    /// Convenience wrapper over `list_inferences` that forces `output_source` to
+    /// `Demonstration`: the underlying query joins against the demonstration feedback table to
+    /// produce the output, which already restricts results to inferences that have one, so
+    /// callers filter for "the corrections I want" (by function, tag, metric, time range, ...)
+    /// via `request.filters` as usual, without needing an explicit "has a demonstration" filter.
+    async fn list_demonstrations(
+        &self,
+        mut request: ListInferencesRequest,
+    ) -> Result<GetInferencesResponse, TensorZeroClientError> {
+        request.output_source = InferenceOutputSource::Demonstration;
+        self.list_inferences(request).await
+    }
+
+    // ========== Embedding Operations ==========
+
+    /// Embed a single input or a batch of inputs.
+    ///
+    /// Batch embedding is expressed via `ClientEmbeddingParams::input`
+    /// (`EmbeddingInput::Batch`/`EmbeddingInput::BatchTokens`), not a separate
+    /// method, mirroring how the underlying `Client::embed` call is batch-capable.
+    /// Useful for building semantic search or dedup features over datapoints.
+    async fn embed(
+        &self,
+        params: ClientEmbeddingParams,
+    ) -> Result<EmbeddingResponse, TensorZeroClientError>;
+
     // ========== Optimization Operations ==========
 
     /// Launch an optimization workflow.
@@ -260,6 +448,28 @@ pub trait TensorZeroClient: Send + Sync + 'static {
         job_handle: &OptimizationJobHandle,
     ) -> Result<OptimizationJobInfo, TensorZeroClientError>;
 
+    /// Launches an optimization workflow, polls it to completion, registers its output as a new
+    /// variant (or variants) in a config snapshot, and evaluates each registered variant against
+    /// a holdout dataset.
+    ///
+    /// This is a default-bodied composite built entirely from the smaller operations above
+    /// (`launch_optimization_workflow`, `poll_optimization`, `get_config_snapshot`,
+    /// `write_config`, `action`), so it doesn't need a client-specific implementation. Polling
+    /// uses `tokio::time::sleep`, which is fine for a direct/embedded/SDK caller but isn't
+    /// crash-safe; the `RunOptimizationPipelineTool` autopilot task performs the same sequence
+    /// durably (checkpointed via `ToolContext::step`/`sleep_for`) for use inside a long-running
+    /// autopilot session.
+    ///
+    /// If the optimization job itself fails, this returns
+    /// `RunOptimizationPipelineResult::OptimizationFailed` without registering or evaluating
+    /// anything.
+    async fn run_optimization_pipeline(
+        &self,
+        params: RunOptimizationPipelineParams,
+    ) -> Result<RunOptimizationPipelineResult, TensorZeroClientError> {
+        optimization_pipeline::run_optimization_pipeline(self, params).await
+    }
+
     // ========== Evaluation Operations ==========
 
     /// Run an evaluation on a dataset or set of datapoints.
@@ -269,12 +479,81 @@ pub trait TensorZeroClient: Send + Sync + 'static {
     ///
     /// Returns summary statistics for each evaluator.
     ///
-    /// Note: This operation is only supported in embedded gateway mode.
-    /// HTTP gateway mode will return a `NotSupported` error.
+    /// In HTTP gateway mode, this calls `POST /evaluations/run`
+    /// (see `gateway::routes::run_evaluation`), which blocks until the
+    /// evaluation finishes. Unlike `start_evaluation`/`poll_evaluation`
+    /// below, there is no HTTP job-handle variant of this call: doing so
+    /// would require the gateway process itself to hold shared job-registry
+    /// state (like `EvaluationJobRegistry`) across requests, which
+    /// `AppStateData` does not currently have room for. Long-running
+    /// evaluations over HTTP should use a sufficiently long client timeout
+    /// until that lands.
     async fn run_evaluation(
         &self,
         params: RunEvaluationParams,
     ) -> Result<RunEvaluationResponse, TensorZeroClientError>;
+
+    /// Starts an evaluation in the background and returns a job handle
+    /// immediately, instead of blocking until it completes.
+    ///
+    /// Poll the returned handle with `poll_evaluation` to check progress and
+    /// retrieve the final result.
+    ///
+    /// Note: jobs are tracked in-process only (see [`EvaluationJobRegistry`]
+    /// (crate::evaluation_jobs::EvaluationJobRegistry)) — there is no durable
+    /// task queue backing evaluations in this codebase, so a worker restart
+    /// loses in-flight job state. This operation is only supported in
+    /// embedded gateway mode; HTTP gateway mode will return a `NotSupported`
+    /// error.
+    async fn start_evaluation(
+        &self,
+        params: RunEvaluationParams,
+    ) -> Result<EvaluationJobHandle, TensorZeroClientError>;
+
+    /// Polls a previously started evaluation job for its current status.
+    ///
+    /// Returns `NotSupported` if `job_handle` refers to a job this process
+    /// has no record of (e.g. it was started before a worker restart).
+    async fn poll_evaluation(
+        &self,
+        job_handle: &EvaluationJobHandle,
+    ) -> Result<EvaluationJobStatus, TensorZeroClientError>;
+
+    // NOTE: There is no `run_topk_evaluation` operation, durable task, or
+    // `StartTopKEvaluationResponse` type anywhere in this codebase to add
+    // cancellation to — `run_evaluation` above is the only evaluation entry
+    // point, and it runs synchronously to completion rather than being
+    // spawned as a durable, cancellable task. Adding `cancel_topk_evaluation`
+    // would require first building the top-k evaluation feature itself,
+    // which is out of scope for a cancellation change. Leaving this as a
+    // pointer for whoever picks up top-k evaluations: cancellation should be
+    // threaded through as a durable task cancellation from the start, rather
+    // than bolted on afterward.
+
+    // NOTE: There is likewise no `poll_topk_task` function or 500ms polling
+    // loop anywhere in this codebase — the only `format!`-built query against
+    // `durable.t_{queue_name}` is `check_tool_rejection_exists` in
+    // `autopilot-client/src/reject_missing_tool.rs`, which is a one-shot
+    // duplicate-check, not a wait-for-completion poll loop. Once top-k
+    // evaluations exist as a durable task, prefer LISTEN/NOTIFY (or a
+    // completion hook on `SpawnClient`, if one is added) over a fixed-interval
+    // poll from the start; a bound `$1` parameter can carry values but not a
+    // table name, so a per-queue-table design like `durable.t_{queue_name}`
+    // will keep needing `format!`/`AssertSqlSafe` regardless — a single
+    // shared table with a `queue_name` column would let all such queries be
+    // fully parameterized.
+
+    // NOTE: For the same reason, there is no `RunTopKEvaluationResponse` or
+    // per-batch progress channel to stream here. The closest existing
+    // precedent for streaming evaluation progress is the SSE endpoint in
+    // `gateway/src/routes/evaluations.rs` (`run_evaluation_handler`), which
+    // sends per-datapoint `EvaluationUpdate` events as a function runs against
+    // a single dataset. Once top-k evaluations exist, extending that same SSE
+    // mechanism with per-batch/per-variant events (and, if durable-tools
+    // workers need it too, publishing those events onto the autopilot session
+    // the way `reject_missing_tool` publishes authorization events) is a more
+    // natural fit than adding a second, evaluation-specific streaming
+    // mechanism from scratch.
 }
 
 /// Create a TensorZero client from an existing TensorZero `Client`.
@@ -0,0 +1,305 @@
+//! `run_optimization_pipeline`: chains an optimization job through to an evaluated variant.
+//!
+//! This composes several already-existing `TensorZeroClient` building blocks
+//! (`launch_optimization_workflow`, `poll_optimization`, `get_config_snapshot`, `write_config`,
+//! `action`) into one call, so a caller doesn't have to hand-roll the launch/poll/register/
+//! evaluate sequence themselves. It deliberately does not introduce any new RPCs.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tensorzero_core::config::{
+    UninitializedConfig, UninitializedFunctionConfig, UninitializedVariantConfig,
+    UninitializedVariantInfo,
+};
+use tensorzero_core::optimization::{OptimizationJobInfo, OptimizerOutput};
+use tensorzero_core::variant::chat_completion::UninitializedChatCompletionConfig;
+use tensorzero_optimizers::endpoints::LaunchOptimizationWorkflowParams;
+
+use super::{
+    ActionInput, ActionResponse, CacheEnabledMode, GetConfigResponse, TensorZeroClient,
+    TensorZeroClientError, WriteConfigRequest,
+};
+use crate::run_evaluation::{RunEvaluationParams, RunEvaluationResponse};
+
+fn default_poll_interval_secs() -> u64 {
+    60
+}
+
+fn default_max_wait_secs() -> u64 {
+    86400
+}
+
+/// Parameters for `TensorZeroClient::run_optimization_pipeline`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunOptimizationPipelineParams {
+    /// The optimization job to launch.
+    pub launch: LaunchOptimizationWorkflowParams,
+    /// The config snapshot to register the optimizer's output into. Defaults to the live config.
+    #[serde(default)]
+    pub base_config_snapshot_hash: Option<String>,
+    /// Name to give the newly registered variant, for optimizer outputs that don't already come
+    /// with one (a bare fine-tuned `Model`). Ignored for optimizers that produce already-named
+    /// variants (e.g. `gepa`).
+    pub variant_name: String,
+    /// Name of the evaluation to run against the holdout dataset (must be defined in config).
+    pub evaluation_name: String,
+    /// Name of the holdout dataset to evaluate the newly registered variant(s) on.
+    pub holdout_dataset_name: String,
+    /// Polling interval in seconds while waiting for the optimization job (default: 60).
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Maximum time to wait for the optimization job in seconds (default: 86400 = 24 hours).
+    #[serde(default = "default_max_wait_secs")]
+    pub max_wait_secs: u64,
+}
+
+/// Outcome of `TensorZeroClient::run_optimization_pipeline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RunOptimizationPipelineResult {
+    /// The optimization job completed, its output was registered as one or more variants in a
+    /// new config snapshot, and each registered variant was evaluated against the holdout
+    /// dataset.
+    Completed {
+        config_snapshot_hash: String,
+        registered_variants: Vec<String>,
+        evaluations: HashMap<String, RunEvaluationResponse>,
+    },
+    /// The optimization job itself failed, so no variant was registered or evaluated.
+    OptimizationFailed { job_info: OptimizationJobInfo },
+}
+
+/// Converts an `OptimizerOutput` into one or more entries in `config`'s `functions` (and, for a
+/// bare fine-tuned `Model`, `models`) map, returning the name(s) of the variant(s) it registered.
+///
+/// - `OptimizerOutput::Variant` and `OptimizerOutput::Model` produce a single unnamed variant,
+///   registered as `variant_name`.
+/// - `OptimizerOutput::Model` additionally needs a model entry, since a bare model isn't directly
+///   usable by a function: it's registered under a synthesized model name and wrapped in a new
+///   `chat_completion` variant that inherits its templates from `template_variant_name`, so the
+///   fine-tuned model gets called with the same prompt it was trained on.
+/// - `OptimizerOutput::Variants` (currently only produced by `gepa`) already comes with names
+///   chosen by the optimizer, so all of them are registered as-is and `variant_name` is unused.
+pub fn register_optimizer_output(
+    config: &mut UninitializedConfig,
+    function_name: &str,
+    template_variant_name: &str,
+    variant_name: &str,
+    output: OptimizerOutput,
+) -> Result<Vec<String>, TensorZeroClientError> {
+    let function = config.functions.get_mut(function_name).ok_or_else(|| {
+        TensorZeroClientError::Pipeline(format!(
+            "Function `{function_name}` not found in the config snapshot"
+        ))
+    })?;
+    let variants = match function {
+        UninitializedFunctionConfig::Chat(chat_config) => &mut chat_config.variants,
+        UninitializedFunctionConfig::Json(json_config) => &mut json_config.variants,
+    };
+
+    match output {
+        OptimizerOutput::Variant(variant) => {
+            variants.insert(
+                variant_name.to_string(),
+                UninitializedVariantInfo {
+                    inner: *variant,
+                    timeouts: None,
+                },
+            );
+            Ok(vec![variant_name.to_string()])
+        }
+        OptimizerOutput::Variants(named_variants) => {
+            let mut registered = Vec::with_capacity(named_variants.len());
+            for (name, variant) in named_variants {
+                variants.insert(
+                    name.clone(),
+                    UninitializedVariantInfo {
+                        inner: *variant,
+                        timeouts: None,
+                    },
+                );
+                registered.push(name);
+            }
+            Ok(registered)
+        }
+        OptimizerOutput::Model(model_config) => {
+            let template = variants.get(template_variant_name).ok_or_else(|| {
+                TensorZeroClientError::Pipeline(format!(
+                    "Template variant `{template_variant_name}` not found on function \
+                     `{function_name}`"
+                ))
+            })?;
+            let (system_template, user_template, assistant_template, templates) =
+                match &template.inner {
+                    UninitializedVariantConfig::ChatCompletion(chat_config) => (
+                        chat_config.system_template.clone(),
+                        chat_config.user_template.clone(),
+                        chat_config.assistant_template.clone(),
+                        chat_config.templates.clone(),
+                    ),
+                    _ => (None, None, None, Default::default()),
+                };
+
+            let model_name = format!("tensorzero::optimization_pipeline::{variant_name}");
+            config
+                .models
+                .insert(Arc::from(model_name.as_str()), model_config);
+
+            variants.insert(
+                variant_name.to_string(),
+                UninitializedVariantInfo {
+                    inner: UninitializedVariantConfig::ChatCompletion(
+                        UninitializedChatCompletionConfig {
+                            weight: None,
+                            model: Arc::from(model_name.as_str()),
+                            system_template,
+                            user_template,
+                            assistant_template,
+                            input_wrappers: None,
+                            templates,
+                            temperature: None,
+                            top_p: None,
+                            max_tokens: None,
+                            presence_penalty: None,
+                            frequency_penalty: None,
+                            seed: None,
+                            stop_sequences: None,
+                            reasoning_effort: None,
+                            service_tier: None,
+                            thinking_budget_tokens: None,
+                            verbosity: None,
+                            json_mode: None,
+                            retries: Default::default(),
+                            extra_body: None,
+                            extra_headers: None,
+                        },
+                    ),
+                    timeouts: None,
+                },
+            );
+            Ok(vec![variant_name.to_string()])
+        }
+    }
+}
+
+/// Default implementation of `TensorZeroClient::run_optimization_pipeline`, shared by every
+/// client so the launch/poll/register/evaluate sequence only has to be written once.
+pub async fn run_optimization_pipeline(
+    client: &(dyn TensorZeroClient + '_),
+    params: RunOptimizationPipelineParams,
+) -> Result<RunOptimizationPipelineResult, TensorZeroClientError> {
+    let function_name = params.launch.function_name.clone();
+    let template_variant_name = params.launch.template_variant_name.clone();
+
+    let job_handle = client.launch_optimization_workflow(params.launch).await?;
+
+    let poll_interval = std::time::Duration::from_secs(params.poll_interval_secs);
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(params.max_wait_secs);
+
+    let job_info = loop {
+        let status = client.poll_optimization(&job_handle).await?;
+        match status {
+            OptimizationJobInfo::Pending { .. } => {
+                if std::time::Instant::now() >= deadline {
+                    return Err(TensorZeroClientError::Pipeline(format!(
+                        "Optimization timed out after {} seconds",
+                        params.max_wait_secs
+                    )));
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+            other => break other,
+        }
+    };
+
+    let output = match job_info {
+        OptimizationJobInfo::Completed { output } => output,
+        job_info => {
+            return Ok(RunOptimizationPipelineResult::OptimizationFailed { job_info });
+        }
+    };
+
+    let GetConfigResponse {
+        mut config,
+        extra_templates,
+        ..
+    } = client
+        .get_config_snapshot(params.base_config_snapshot_hash.clone())
+        .await?;
+
+    let registered_variants = register_optimizer_output(
+        &mut config,
+        &function_name,
+        &template_variant_name,
+        &params.variant_name,
+        output,
+    )?;
+
+    let mut tags = HashMap::new();
+    tags.insert(
+        "tensorzero::optimization_pipeline::function_name".to_string(),
+        function_name,
+    );
+    tags.insert(
+        "tensorzero::optimization_pipeline::registered_variants".to_string(),
+        registered_variants.join(","),
+    );
+
+    let write_response = client
+        .write_config(WriteConfigRequest {
+            config,
+            extra_templates,
+            tags,
+        })
+        .await?;
+
+    let snapshot_hash: super::SnapshotHash =
+        write_response
+            .hash
+            .parse()
+            .map_err(|_: std::convert::Infallible| {
+                TensorZeroClientError::Pipeline(
+                    "Failed to parse newly written config snapshot hash".to_string(),
+                )
+            })?;
+
+    let mut evaluations = HashMap::with_capacity(registered_variants.len());
+    for variant_name in &registered_variants {
+        let eval_params = RunEvaluationParams {
+            evaluation_name: params.evaluation_name.clone(),
+            dataset_name: Some(params.holdout_dataset_name.clone()),
+            datapoint_ids: None,
+            variant_name: variant_name.clone(),
+            concurrency: 10,
+            inference_cache: CacheEnabledMode::On,
+            max_datapoints: None,
+            precision_targets: HashMap::new(),
+            include_datapoint_results: false,
+            tags: HashMap::new(),
+        };
+        let response = client
+            .action(
+                snapshot_hash.clone(),
+                ActionInput::RunEvaluation(Box::new(eval_params)),
+            )
+            .await?;
+        match response {
+            ActionResponse::RunEvaluation(eval_response) => {
+                evaluations.insert(variant_name.clone(), eval_response);
+            }
+            _ => {
+                return Err(TensorZeroClientError::Pipeline(
+                    "Unexpected response type from action endpoint".to_string(),
+                ));
+            }
+        }
+    }
+
+    Ok(RunOptimizationPipelineResult::Completed {
+        config_snapshot_hash: write_response.hash,
+        registered_variants,
+        evaluations,
+    })
+}
@@ -0,0 +1,168 @@
+//! Shared test fixtures for downstream tool crates.
+//!
+//! [`crate::MockTensorZeroClient`] (behind the `test-support` feature) already mocks
+//! [`crate::TensorZeroClient`] via `mockall::automock`. This module adds the fixture generators
+//! and a "sensible defaults" builder that consumers otherwise end up hand-rolling per-crate - see
+//! `autopilot-tools`' test helpers before this module existed for what that duplication looked
+//! like.
+//!
+//! Everything here is gated the same way as [`crate::MockTensorZeroClient`]: available under
+//! `#[cfg(test)]` within this crate, and to other crates via the `test-support` feature.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use tensorzero_core::endpoints::datasets::{ChatInferenceDatapoint, Datapoint};
+use tensorzero_core::endpoints::feedback::FeedbackResponse;
+use tensorzero_core::endpoints::inference::{ChatInferenceResponse, InferenceResponse};
+use tensorzero_core::inference::types::{
+    ContentBlockChatOutput, Input, InputMessage, InputMessageContent, Role, StoredInput,
+    StoredInputMessage, StoredInputMessageContent, Text, Usage,
+};
+use tensorzero_core::stored_inference::{StoredChatInference, StoredInference};
+use tensorzero_core::tool::DynamicToolParams;
+
+use crate::MockTensorZeroClient;
+
+/// Builds a [`MockTensorZeroClient`] with sensible defaults already wired up, so tests only need
+/// to override the expectations they actually care about.
+///
+/// By default, `inference()` returns [`mock_chat_response`] with the text `"mock response"` and `feedback()`
+/// returns a fresh [`FeedbackResponse`]. Every other method is left unmocked, so calling one that
+/// hasn't been overridden panics per `mockall`'s usual "no expectations set" behavior.
+pub struct MockTensorZeroClientBuilder {
+    client: MockTensorZeroClient,
+}
+
+impl MockTensorZeroClientBuilder {
+    pub fn new() -> Self {
+        let mut client = MockTensorZeroClient::new();
+        client
+            .expect_inference()
+            .returning(|_| Ok(mock_chat_response("mock response")));
+        client
+            .expect_feedback()
+            .returning(|_| Ok(mock_feedback_response(Uuid::now_v7())));
+        Self { client }
+    }
+
+    /// Overrides the default `inference()` expectation to always return `response`.
+    pub fn with_inference_response(mut self, response: InferenceResponse) -> Self {
+        self.client.checkpoint();
+        self.client
+            .expect_inference()
+            .returning(move |_| Ok(response.clone()));
+        // `checkpoint()` clears every expectation, so restore the default `feedback()` one.
+        self.client
+            .expect_feedback()
+            .returning(|_| Ok(mock_feedback_response(Uuid::now_v7())));
+        self
+    }
+
+    pub fn build(self) -> MockTensorZeroClient {
+        self.client
+    }
+}
+
+impl Default for MockTensorZeroClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a mock feedback response with the given ID.
+pub fn mock_feedback_response(feedback_id: Uuid) -> FeedbackResponse {
+    FeedbackResponse { feedback_id }
+}
+
+/// Creates a mock chat inference response with the given text content.
+pub fn mock_chat_response(text: &str) -> InferenceResponse {
+    InferenceResponse::Chat(ChatInferenceResponse {
+        inference_id: Uuid::now_v7(),
+        episode_id: Uuid::now_v7(),
+        variant_name: "test_variant".to_string(),
+        content: vec![ContentBlockChatOutput::Text(Text {
+            text: text.to_string(),
+        })],
+        usage: Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+        },
+        raw_usage: None,
+        original_response: None,
+        raw_response: None,
+        finish_reason: None,
+    })
+}
+
+/// Creates a simple single-message `Input` for use in tests.
+pub fn mock_input(text: &str) -> Input {
+    Input {
+        system: None,
+        messages: vec![InputMessage {
+            role: Role::User,
+            content: vec![InputMessageContent::Text(Text {
+                text: text.to_string(),
+            })],
+        }],
+    }
+}
+
+/// Creates a mock chat datapoint for testing.
+pub fn mock_chat_datapoint(id: Uuid, dataset_name: &str, function_name: &str) -> Datapoint {
+    Datapoint::Chat(ChatInferenceDatapoint {
+        dataset_name: dataset_name.to_string(),
+        function_name: function_name.to_string(),
+        id,
+        episode_id: Some(Uuid::now_v7()),
+        input: mock_input("test input"),
+        output: Some(vec![ContentBlockChatOutput::Text(Text {
+            text: "test output".to_string(),
+        })]),
+        tool_params: DynamicToolParams::default(),
+        tags: Some(HashMap::new()),
+        auxiliary: String::new(),
+        is_deleted: false,
+        is_custom: false,
+        source_inference_id: None,
+        staled_at: None,
+        updated_at: "2024-01-01T00:00:00Z".to_string(),
+        name: None,
+    })
+}
+
+/// Creates a mock stored chat inference for testing.
+pub fn mock_stored_chat_inference(
+    inference_id: Uuid,
+    function_name: &str,
+    variant_name: &str,
+) -> StoredInference {
+    StoredInference::Chat(StoredChatInference {
+        function_name: function_name.to_string(),
+        variant_name: variant_name.to_string(),
+        input: StoredInput {
+            system: None,
+            messages: vec![StoredInputMessage {
+                role: Role::User,
+                content: vec![StoredInputMessageContent::Text(Text {
+                    text: "test input".to_string(),
+                })],
+            }],
+        },
+        output: vec![ContentBlockChatOutput::Text(Text {
+            text: "test output".to_string(),
+        })],
+        dispreferred_outputs: vec![],
+        timestamp: Utc::now(),
+        episode_id: Uuid::now_v7(),
+        inference_id,
+        tool_params: DynamicToolParams::default(),
+        tags: HashMap::new(),
+        extra_body: Default::default(),
+        inference_params: Default::default(),
+        processing_time_ms: Some(100),
+        ttft_ms: Some(50),
+    })
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+
+use crate::key::TensorZeroAuthError;
+
+/// The kinds of access that can be restricted on a dataset. See
+/// [`check_dataset_permission`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatasetPermission {
+    /// Read datapoints (e.g. list/get datapoints, use as evaluation input).
+    Read,
+    /// Write datapoints (create, update, delete, clone into the dataset).
+    Write,
+    /// Use the dataset as input to an evaluation run.
+    Evaluate,
+}
+
+/// A per-dataset access policy, scoped to an (organization, workspace) pair.
+///
+/// A dataset with no policy row is unrestricted; see [`check_dataset_permission`].
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(sqlx::FromRow, Debug, PartialEq, Eq, Clone, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct DatasetPermissionPolicy {
+    pub organization: String,
+    pub workspace: String,
+    pub dataset_name: String,
+    pub can_read: bool,
+    pub can_write: bool,
+    pub can_evaluate: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Creates or replaces the access policy for a dataset within an
+/// (organization, workspace) pair.
+pub async fn set_dataset_permission_policy(
+    organization: &str,
+    workspace: &str,
+    dataset_name: &str,
+    can_read: bool,
+    can_write: bool,
+    can_evaluate: bool,
+    pool: &PgPool,
+) -> Result<DatasetPermissionPolicy, TensorZeroAuthError> {
+    let policy = sqlx::query_as!(
+        DatasetPermissionPolicy,
+        "INSERT INTO tensorzero_auth_dataset_permission
+            (organization, workspace, dataset_name, can_read, can_write, can_evaluate)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (organization, workspace, dataset_name)
+         DO UPDATE SET can_read = $4, can_write = $5, can_evaluate = $6, updated_at = NOW()
+         RETURNING organization, workspace, dataset_name, can_read, can_write, can_evaluate, created_at, updated_at",
+        organization,
+        workspace,
+        dataset_name,
+        can_read,
+        can_write,
+        can_evaluate,
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(policy)
+}
+
+/// Looks up the access policy for a dataset, if one has been set.
+pub async fn get_dataset_permission_policy(
+    organization: &str,
+    workspace: &str,
+    dataset_name: &str,
+    pool: &PgPool,
+) -> Result<Option<DatasetPermissionPolicy>, TensorZeroAuthError> {
+    let policy = sqlx::query_as!(
+        DatasetPermissionPolicy,
+        "SELECT organization, workspace, dataset_name, can_read, can_write, can_evaluate, created_at, updated_at
+         FROM tensorzero_auth_dataset_permission
+         WHERE organization = $1 AND workspace = $2 AND dataset_name = $3",
+        organization,
+        workspace,
+        dataset_name,
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(policy)
+}
+
+/// Checks whether `organization`/`workspace` may perform `permission` on
+/// `dataset_name`.
+///
+/// A dataset with no policy row is unrestricted (returns `true`), so that
+/// datasets created before this feature existed keep working unchanged.
+/// Callers that need to enforce this (e.g. dataset and evaluation route
+/// handlers) are responsible for calling this before performing the
+/// corresponding operation; this function only answers the question, it
+/// does not intercept requests itself.
+pub async fn check_dataset_permission(
+    organization: &str,
+    workspace: &str,
+    dataset_name: &str,
+    permission: DatasetPermission,
+    pool: &PgPool,
+) -> Result<bool, TensorZeroAuthError> {
+    let Some(policy) =
+        get_dataset_permission_policy(organization, workspace, dataset_name, pool).await?
+    else {
+        return Ok(true);
+    };
+    Ok(match permission {
+        DatasetPermission::Read => policy.can_read,
+        DatasetPermission::Write => policy.can_write,
+        DatasetPermission::Evaluate => policy.can_evaluate,
+    })
+}
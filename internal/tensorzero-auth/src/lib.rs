@@ -1,4 +1,5 @@
 pub mod constants;
+pub mod dataset_permissions;
 pub mod key;
 pub mod middleware;
 pub mod postgres;
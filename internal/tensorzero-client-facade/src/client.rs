@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use durable_tools::TensorZeroClient;
+
+use crate::types::{
+    FacadeError, FacadeFeedbackRequest, FacadeFeedbackResponse, FacadeInferenceRequest,
+    FacadeInferenceResponse,
+};
+
+/// A stable facade over `Arc<dyn TensorZeroClient>`. See the crate-level docs for what's covered.
+pub struct StableTensorZeroClient {
+    inner: Arc<dyn TensorZeroClient>,
+}
+
+impl StableTensorZeroClient {
+    pub fn new(inner: Arc<dyn TensorZeroClient>) -> Self {
+        Self { inner }
+    }
+
+    /// Runs text-only, non-streaming chat inference.
+    pub async fn infer(
+        &self,
+        request: FacadeInferenceRequest,
+    ) -> Result<FacadeInferenceResponse, FacadeError> {
+        let response = self
+            .inner
+            .inference(request.into())
+            .await
+            .map_err(|e| FacadeError::Client(e.to_string()))?;
+        response.try_into()
+    }
+
+    /// Submits feedback for an inference or episode.
+    pub async fn send_feedback(
+        &self,
+        request: FacadeFeedbackRequest,
+    ) -> Result<FacadeFeedbackResponse, FacadeError> {
+        let response = self
+            .inner
+            .feedback(request.into())
+            .await
+            .map_err(|e| FacadeError::Client(e.to_string()))?;
+        Ok(response.into())
+    }
+}
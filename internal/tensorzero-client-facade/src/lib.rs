@@ -0,0 +1,27 @@
+//! A stable, narrow facade over [`durable_tools::TensorZeroClient`] for external integrators.
+//!
+//! `durable_tools::TensorZeroClient` re-exports request/response types directly from
+//! `tensorzero-core`, `tensorzero-optimizers`, and `evaluations`, so a tool built against it
+//! breaks whenever those internal crates change a field or module path. This crate defines its
+//! own wire-stable types for a small slice of that surface (text-only chat inference and
+//! feedback submission) and converts to/from the internal types at the boundary, so downstream
+//! crates can depend on `tensorzero-client-facade` instead of pinning internal module paths.
+//!
+//! Only [`StableTensorZeroClient::infer`] and [`StableTensorZeroClient::send_feedback`] are
+//! covered so far. `durable_tools::TensorZeroClient` has ~30 methods spanning datapoints,
+//! evaluations, optimization, and autopilot events, each with its own internal request/response
+//! types; stabilizing all of them in one pass isn't attempted here. `infer` is further scoped
+//! down to text-only input and text-only chat output (no JSON functions, tool calls, files, or
+//! streaming) since that's the part of `ClientInferenceParams`/`InferenceResponse` with no
+//! dependency on `DynamicToolParams` or multi-modal content types. Extending coverage to more
+//! operations or richer content should follow the same pattern: add a `Facade*` type in
+//! [`types`] with `From`/`TryFrom` conversions, then a method on [`StableTensorZeroClient`].
+
+pub mod client;
+pub mod types;
+
+pub use client::StableTensorZeroClient;
+pub use types::{
+    FacadeError, FacadeFeedbackRequest, FacadeFeedbackResponse, FacadeInferenceRequest,
+    FacadeInferenceResponse, FacadeInput, FacadeMessage, FacadeRole, FacadeUsage,
+};
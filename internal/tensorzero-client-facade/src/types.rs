@@ -0,0 +1,205 @@
+//! Wire-stable request/response types for [`crate::StableTensorZeroClient`].
+//!
+//! These types intentionally don't reuse anything from `tensorzero-core`: they're converted
+//! to/from the internal types in this module, so a caller only ever sees the shapes defined
+//! here.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use tensorzero_core::client::{ClientInferenceParams, FeedbackParams, FeedbackResponse};
+use tensorzero_core::endpoints::inference::InferenceResponse;
+use tensorzero_core::inference::types::{
+    ContentBlockChatOutput, Input, InputMessage, InputMessageContent, Role, System, Text,
+};
+
+/// Error converting between facade types and internal TensorZero types.
+#[derive(Debug, thiserror::Error)]
+pub enum FacadeError {
+    /// The request or response used a shape this facade doesn't cover yet (see the module docs
+    /// in `lib.rs` for what's currently supported).
+    #[error("unsupported by tensorzero-client-facade: {0}")]
+    Unsupported(String),
+
+    /// The underlying `durable_tools::TensorZeroClient` call failed.
+    #[error("client error: {0}")]
+    Client(String),
+}
+
+/// A chat message role, mirroring `tensorzero_core::inference::types::Role`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacadeRole {
+    User,
+    Assistant,
+}
+
+impl From<FacadeRole> for Role {
+    fn from(role: FacadeRole) -> Self {
+        match role {
+            FacadeRole::User => Role::User,
+            FacadeRole::Assistant => Role::Assistant,
+        }
+    }
+}
+
+/// A single text-only turn in a [`FacadeInput`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FacadeMessage {
+    pub role: FacadeRole,
+    pub text: String,
+}
+
+/// Text-only input for [`FacadeInferenceRequest`].
+///
+/// Covers the common case of a plain conversation. Templates, tool calls, tool results, and
+/// files aren't representable here; build `ClientInferenceParams` directly if you need them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FacadeInput {
+    pub system: Option<String>,
+    pub messages: Vec<FacadeMessage>,
+}
+
+impl From<FacadeInput> for Input {
+    fn from(input: FacadeInput) -> Self {
+        Input {
+            system: input.system.map(System::Text),
+            messages: input
+                .messages
+                .into_iter()
+                .map(|message| InputMessage {
+                    role: message.role.into(),
+                    content: vec![InputMessageContent::Text(Text { text: message.text })],
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Request for [`crate::StableTensorZeroClient::infer`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FacadeInferenceRequest {
+    /// Exactly one of `function_name`/`model_name` must be set, same as `ClientInferenceParams`.
+    pub function_name: Option<String>,
+    pub model_name: Option<String>,
+    pub episode_id: Option<Uuid>,
+    pub input: FacadeInput,
+    pub variant_name: Option<String>,
+    pub dryrun: Option<bool>,
+    pub tags: HashMap<String, String>,
+}
+
+impl From<FacadeInferenceRequest> for ClientInferenceParams {
+    fn from(request: FacadeInferenceRequest) -> Self {
+        ClientInferenceParams {
+            function_name: request.function_name,
+            model_name: request.model_name,
+            episode_id: request.episode_id,
+            input: request.input.into(),
+            variant_name: request.variant_name,
+            dryrun: request.dryrun,
+            tags: request.tags,
+            ..Default::default()
+        }
+    }
+}
+
+/// Token usage for a single inference, mirroring `tensorzero_core::inference::types::Usage`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FacadeUsage {
+    pub input_tokens: Option<u32>,
+    pub output_tokens: Option<u32>,
+}
+
+/// Response from [`crate::StableTensorZeroClient::infer`].
+///
+/// `content` is the concatenation of the response's text blocks; tool calls, thoughts, and
+/// unknown content blocks are dropped rather than surfaced, since this facade doesn't yet have
+/// stable types for them.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FacadeInferenceResponse {
+    pub inference_id: Uuid,
+    pub episode_id: Uuid,
+    pub variant_name: String,
+    pub content: String,
+    pub usage: FacadeUsage,
+}
+
+impl TryFrom<InferenceResponse> for FacadeInferenceResponse {
+    type Error = FacadeError;
+
+    fn try_from(response: InferenceResponse) -> Result<Self, Self::Error> {
+        let chat = match response {
+            InferenceResponse::Chat(chat) => chat,
+            InferenceResponse::Json(_) => {
+                return Err(FacadeError::Unsupported(
+                    "JSON function responses aren't covered by tensorzero-client-facade yet"
+                        .to_string(),
+                ));
+            }
+        };
+
+        let content = chat
+            .content
+            .into_iter()
+            .filter_map(|block| match block {
+                ContentBlockChatOutput::Text(text) => Some(text.text),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("");
+
+        Ok(FacadeInferenceResponse {
+            inference_id: chat.inference_id,
+            episode_id: chat.episode_id,
+            variant_name: chat.variant_name,
+            content,
+            usage: FacadeUsage {
+                input_tokens: chat.usage.input_tokens,
+                output_tokens: chat.usage.output_tokens,
+            },
+        })
+    }
+}
+
+/// Request for [`crate::StableTensorZeroClient::send_feedback`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FacadeFeedbackRequest {
+    /// Exactly one of `episode_id`/`inference_id` must be set, same as `FeedbackParams`.
+    pub episode_id: Option<Uuid>,
+    pub inference_id: Option<Uuid>,
+    pub metric_name: String,
+    pub value: serde_json::Value,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+impl From<FacadeFeedbackRequest> for FeedbackParams {
+    fn from(request: FacadeFeedbackRequest) -> Self {
+        FeedbackParams {
+            episode_id: request.episode_id,
+            inference_id: request.inference_id,
+            metric_name: request.metric_name,
+            value: request.value,
+            internal: false,
+            tags: request.tags,
+            dryrun: None,
+        }
+    }
+}
+
+/// Response from [`crate::StableTensorZeroClient::send_feedback`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FacadeFeedbackResponse {
+    pub feedback_id: Uuid,
+}
+
+impl From<FeedbackResponse> for FacadeFeedbackResponse {
+    fn from(response: FeedbackResponse) -> Self {
+        FacadeFeedbackResponse {
+            feedback_id: response.feedback_id,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Generates strongly-typed Rust input/output structs for each function in a TensorZero config
+//! file, so application code gets compile-time-checked structs instead of raw `serde_json::Value`
+//! payloads.
+//!
+//! This is intentionally scoped to `object`-shaped JSON schemas with primitive (or array of
+//! primitive) properties: anything more complex - `$ref`, `oneOf`/`anyOf`/`allOf`, nested objects
+//! - falls back to a `serde_json::Value` field, with a comment in the generated file explaining
+//! why, rather than a best-effort guess that would need per-schema adjustment. It generates the
+//! input/output structs themselves; it does not generate client methods, since those are a much
+//! thinner, less error-prone wrapper that application code can write directly against `Client`.
+
+mod schema_codegen;
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use tensorzero_core::config::{Config, ConfigFileGlob};
+use tensorzero_core::function::FunctionConfig;
+
+use schema_codegen::{generate_struct, struct_name};
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Generates typed Rust structs from a TensorZero config's function schemas"
+)]
+struct Args {
+    /// Path to the TensorZero config file (or a glob of config files).
+    #[arg(long)]
+    config_file: PathBuf,
+
+    /// Path to write the generated Rust source to.
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    let args = Args::parse();
+
+    let glob = ConfigFileGlob::new_from_path(&args.config_file)?;
+    let config = Config::load_from_path_optional_verify_credentials(&glob, false)
+        .await?
+        .dangerous_into_config_without_writing();
+
+    let mut generated = String::new();
+    generated.push_str("// @generated by tensorzero-codegen. Do not edit by hand.\n\n");
+
+    let mut function_names: Vec<&String> = config.functions.keys().collect();
+    function_names.sort();
+
+    for function_name in function_names {
+        let function = &config.functions[function_name];
+        generated.push_str(&format!("// Function `{function_name}`\n"));
+
+        for (suffix, schema) in [
+            ("SystemInput", function.system_schema()),
+            ("UserInput", function.user_schema()),
+            ("AssistantInput", function.assistant_schema()),
+        ] {
+            let Some(schema) = schema else { continue };
+            let name = struct_name(function_name, suffix);
+            match generate_struct(&name, &schema.value) {
+                Some(generated_struct) => generated.push_str(&generated_struct.source),
+                None => generated.push_str(&format!(
+                    "// `{name}` schema isn't a plain object schema; use `serde_json::Value` at call sites.\n"
+                )),
+            }
+        }
+
+        if let FunctionConfig::Json(params) = function.as_ref() {
+            let name = struct_name(function_name, "Output");
+            match generate_struct(&name, &params.output_schema.value) {
+                Some(generated_struct) => generated.push_str(&generated_struct.source),
+                None => generated.push_str(&format!(
+                    "// `{name}` schema isn't a plain object schema; use `serde_json::Value` at call sites.\n"
+                )),
+            }
+        }
+
+        generated.push('\n');
+    }
+
+    tokio::fs::write(&args.out, generated).await?;
+    tracing::info!(out = %args.out.display(), "Wrote generated function bindings");
+
+    Ok(())
+}
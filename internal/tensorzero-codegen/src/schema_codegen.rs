@@ -0,0 +1,104 @@
+//! Best-effort JSON-schema-to-Rust-struct conversion.
+//!
+//! Only `object` schemas with primitive (or array-of-primitive) typed properties are turned
+//! into named struct fields. Anything more complex - `$ref`, `oneOf`/`anyOf`/`allOf`, nested
+//! objects, untyped schemas - is left to the caller to represent as `serde_json::Value`, rather
+//! than guessing at a Rust type that would silently be wrong for some inputs.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+pub struct GeneratedStruct {
+    pub source: String,
+}
+
+/// Converts a function name and schema role (e.g. `"SystemInput"`) into a `PascalCase` struct name.
+pub fn struct_name(function_name: &str, suffix: &str) -> String {
+    let mut name = String::new();
+    for part in function_name.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.extend(chars);
+        }
+    }
+    name.push_str(suffix);
+    name
+}
+
+/// Converts a JSON schema property name into a valid Rust field identifier, returning whether
+/// it had to be changed (in which case the caller should emit `#[serde(rename = "...")]`).
+fn sanitize_field_name(name: &str) -> (String, bool) {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.chars().next().is_none_or(|c| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    let renamed = sanitized != name;
+    (sanitized, renamed)
+}
+
+fn rust_type_for_schema(schema: &Value) -> String {
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => "String".to_string(),
+        Some("integer") => "i64".to_string(),
+        Some("number") => "f64".to_string(),
+        Some("boolean") => "bool".to_string(),
+        Some("array") => {
+            let item_ty = schema
+                .get("items")
+                .map(rust_type_for_schema)
+                .unwrap_or_else(|| "serde_json::Value".to_string());
+            format!("Vec<{item_ty}>")
+        }
+        // Nested objects, `$ref`, `oneOf`/`anyOf`/`allOf`, and untyped schemas aren't modeled
+        // as their own structs - fall back to a raw JSON value rather than guessing.
+        _ => "serde_json::Value".to_string(),
+    }
+}
+
+/// Generates a struct for an object-typed JSON schema. Returns `None` if the top-level schema
+/// isn't an `object` schema with `properties` (e.g. it's `{}`, a `oneOf`, or a non-object type) -
+/// callers should fall back to `serde_json::Value` for the whole schema in that case.
+pub fn generate_struct(name: &str, schema: &Value) -> Option<GeneratedStruct> {
+    if schema.get("type").and_then(Value::as_str) != Some("object") {
+        return None;
+    }
+    let properties = schema.get("properties")?.as_object()?;
+    let required: HashSet<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    let mut fields = String::new();
+    for (prop_name, prop_schema) in properties {
+        let (field_name, renamed) = sanitize_field_name(prop_name);
+        let is_required = required.contains(prop_name.as_str());
+        let mut ty = rust_type_for_schema(prop_schema);
+        if !is_required {
+            ty = format!("Option<{ty}>");
+            fields.push_str("    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n");
+        }
+        if renamed {
+            fields.push_str(&format!("    #[serde(rename = \"{prop_name}\")]\n"));
+        }
+        fields.push_str(&format!("    pub {field_name}: {ty},\n"));
+    }
+
+    let source = format!(
+        "#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]\npub struct {name} {{\n{fields}}}\n"
+    );
+    Some(GeneratedStruct { source })
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use crate::config::OtlpConfig;
 use crate::db::clickhouse::{ClickHouseConnectionInfo, TableName};
+use crate::db::valkey::ValkeyConnectionInfo;
 use crate::embeddings::{Embedding, EmbeddingModelResponse, EmbeddingRequest};
 use crate::error::{Error, ErrorDetails, warn_discarded_cache_write};
 use crate::inference::types::{
@@ -14,8 +15,10 @@ use crate::model::StreamResponse;
 use crate::serde_util::{deserialize_json_string, serialize_json_string};
 use crate::tool::{InferenceResponseToolCall, InferenceResponseToolCallExt, ToolCallConfig};
 use crate::utils::spawn_ignoring_shutdown;
+use async_trait::async_trait;
 use blake3::Hash;
 use clap::ValueEnum;
+use redis::AsyncCommands;
 use serde::de::{DeserializeOwned, IgnoredAny};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -88,6 +91,94 @@ pub struct CacheOptions {
     pub enabled: CacheEnabledMode,
 }
 
+/// Default time-to-live for hot-tier cache entries, in seconds. Independent of a caller's
+/// `max_age_s` (which bounds how far back a ClickHouse lookup is allowed to read) - this
+/// bounds how long an entry is allowed to live in the hot tier before it's evicted and the
+/// next lookup falls back to ClickHouse.
+pub const DEFAULT_HOT_CACHE_TTL_S: u64 = 300;
+
+/// Values larger than this are written to ClickHouse only, skipping the hot tier. Protects
+/// the hot-tier backend (e.g. Valkey) from having to hold arbitrarily large cached responses,
+/// such as long streaming completions, in memory.
+pub const DEFAULT_HOT_CACHE_MAX_VALUE_BYTES: usize = 1_000_000;
+
+/// A pluggable hot-tier cache that sits in front of the ClickHouse-backed cache below
+/// (`cache_lookup` / `start_cache_write`). Implementations are expected to be eventually
+/// consistent with ClickHouse: a miss (or an error, which we treat as a miss) always falls
+/// through to the ClickHouse lookup, so a `CacheBackend` is free to evict entries, drop
+/// writes, or come up empty without affecting correctness - it only affects latency.
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Fetch the raw bytes previously written for `key`, if present and not yet expired.
+    async fn get(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, Error>;
+
+    /// Store `value` under `key`, subject to the backend's own TTL and size limits.
+    async fn set(&self, key: &CacheKey, value: Vec<u8>) -> Result<(), Error>;
+}
+
+/// Valkey (Redis-compatible)-backed `CacheBackend`. A `GET`/`SET EX` round-trip to Valkey is
+/// on the order of the network RTT rather than a ClickHouse query, which is the win for
+/// workloads that repeatedly re-run the exact same request, e.g. evaluation re-runs with
+/// `CacheEnabledMode::On`.
+///
+/// Wraps a `ValkeyConnectionInfo` rather than requiring one - when it's `Disabled` (no
+/// `TENSORZERO_VALKEY_URL` configured), `get`/`set` are no-ops and every lookup falls through
+/// to ClickHouse, so callers don't need to special-case "no hot tier configured".
+pub struct ValkeyCacheBackend {
+    connection_info: ValkeyConnectionInfo,
+    ttl_s: u64,
+    max_value_bytes: usize,
+}
+
+impl ValkeyCacheBackend {
+    pub fn new(connection_info: ValkeyConnectionInfo, ttl_s: u64, max_value_bytes: usize) -> Self {
+        Self {
+            connection_info,
+            ttl_s,
+            max_value_bytes,
+        }
+    }
+
+    fn redis_key(key: &CacheKey) -> String {
+        format!("tensorzero_cache:{}", key.get_long_key())
+    }
+}
+
+#[async_trait]
+impl CacheBackend for ValkeyCacheBackend {
+    async fn get(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, Error> {
+        let Some(connection) = self.connection_info.get_connection() else {
+            return Ok(None);
+        };
+        let mut connection = connection.clone();
+        let value: Option<Vec<u8>> = connection.get(Self::redis_key(key)).await.map_err(|e| {
+            Error::new(ErrorDetails::ValkeyQuery {
+                message: format!("Failed to read from hot cache: {e}"),
+            })
+        })?;
+        Ok(value)
+    }
+
+    async fn set(&self, key: &CacheKey, value: Vec<u8>) -> Result<(), Error> {
+        let Some(connection) = self.connection_info.get_connection() else {
+            return Ok(());
+        };
+        if value.len() > self.max_value_bytes {
+            return Ok(());
+        }
+        let mut connection = connection.clone();
+        let _: () = connection
+            .set_ex(Self::redis_key(key), value, self.ttl_s)
+            .await
+            .map_err(|e| {
+                Error::new(ErrorDetails::ValkeyQuery {
+                    message: format!("Failed to write to hot cache: {e}"),
+                })
+            })?;
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct BaseModelProviderRequest<'request, T> {
     pub request: &'request T,
@@ -320,6 +411,8 @@ pub struct StreamingCacheData {
 fn spawn_maybe_cache_write<T: Serialize + CacheOutput + Send + Sync + 'static>(
     row: FullCacheRow<T>,
     clickhouse_client: ClickHouseConnectionInfo,
+    hot_cache: Arc<dyn CacheBackend>,
+    cache_key: CacheKey,
     cache_validation_info: CacheValidationInfo,
 ) {
     spawn_ignoring_shutdown(async move {
@@ -329,6 +422,14 @@ fn spawn_maybe_cache_write<T: Serialize + CacheOutput + Send + Sync + 'static>(
             .should_write_to_cache(cache_validation_info)
             .await
         {
+            match serde_json::to_vec(&row.data) {
+                Ok(bytes) => {
+                    if let Err(e) = hot_cache.set(&cache_key, bytes).await {
+                        tracing::warn!("Failed to write to hot cache: {e}");
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to serialize cache entry for hot cache: {e}"),
+            }
             if let Err(e) = clickhouse_client
                 .write_batched(&[row], TableName::ModelInferenceCache)
                 .await
@@ -358,6 +459,7 @@ pub struct CacheValidationInfo {
 // This doesn't block
 pub fn start_cache_write<T: Serialize + CacheOutput + Send + Sync + 'static>(
     clickhouse_client: &ClickHouseConnectionInfo,
+    hot_cache: Arc<dyn CacheBackend>,
     cache_key: CacheKey,
     cache_data: CacheData<T>,
     cache_validation_info: CacheValidationInfo,
@@ -372,6 +474,8 @@ pub fn start_cache_write<T: Serialize + CacheOutput + Send + Sync + 'static>(
             data: cache_data,
         },
         clickhouse_client,
+        hot_cache,
+        cache_key,
         cache_validation_info,
     );
     Ok(())
@@ -390,6 +494,7 @@ pub struct CachedProviderInferenceResponseChunk {
 // This starts a trailing write to the cache (without blocking the http response)
 pub fn start_cache_write_streaming(
     clickhouse_client: &ClickHouseConnectionInfo,
+    hot_cache: Arc<dyn CacheBackend>,
     cache_key: CacheKey,
     chunks: Vec<ProviderInferenceResponseChunk>,
     raw_request: &str,
@@ -433,6 +538,8 @@ pub fn start_cache_write_streaming(
             },
         },
         clickhouse_client,
+        hot_cache,
+        cache_key,
         CacheValidationInfo { tool_config },
     );
     Ok(())
@@ -440,11 +547,13 @@ pub fn start_cache_write_streaming(
 
 pub async fn embedding_cache_lookup(
     clickhouse_connection_info: &ClickHouseConnectionInfo,
+    hot_cache: &dyn CacheBackend,
     request: &EmbeddingModelProviderRequest<'_>,
     max_age_s: Option<u32>,
 ) -> Result<Option<EmbeddingModelResponse>, Error> {
     let result = cache_lookup_inner::<EmbeddingCacheData>(
         clickhouse_connection_info,
+        hot_cache,
         request.get_cache_key()?,
         max_age_s,
     )
@@ -454,11 +563,13 @@ pub async fn embedding_cache_lookup(
 
 pub async fn cache_lookup(
     clickhouse_connection_info: &ClickHouseConnectionInfo,
+    hot_cache: &dyn CacheBackend,
     request: ModelProviderRequest<'_>,
     max_age_s: Option<u32>,
 ) -> Result<Option<ModelInferenceResponse>, Error> {
     let result = cache_lookup_inner::<NonStreamingCacheData>(
         clickhouse_connection_info,
+        hot_cache,
         request.get_cache_key()?,
         max_age_s,
     )
@@ -470,11 +581,13 @@ pub async fn cache_lookup(
 
 pub async fn cache_lookup_streaming(
     clickhouse_connection_info: &ClickHouseConnectionInfo,
+    hot_cache: &dyn CacheBackend,
     request: ModelProviderRequest<'_>,
     max_age_s: Option<u32>,
 ) -> Result<Option<StreamResponse>, Error> {
     let result = cache_lookup_inner(
         clickhouse_connection_info,
+        hot_cache,
         request.get_cache_key()?,
         max_age_s,
     )
@@ -488,11 +601,24 @@ pub async fn cache_lookup_streaming(
     }))
 }
 
-pub async fn cache_lookup_inner<T: CacheOutput + DeserializeOwned>(
+pub async fn cache_lookup_inner<T: CacheOutput + Serialize + DeserializeOwned>(
     clickhouse_connection_info: &ClickHouseConnectionInfo,
+    hot_cache: &dyn CacheBackend,
     cache_key: CacheKey,
     max_age_s: Option<u32>,
 ) -> Result<Option<CacheData<T>>, Error> {
+    // Check the hot tier first. A hot-tier lookup failure (e.g. Valkey unreachable) is treated
+    // the same as a miss - we always have ClickHouse to fall back to, so we don't want a hot
+    // tier outage to take the cache itself down.
+    match hot_cache.get(&cache_key).await {
+        Ok(Some(bytes)) => match serde_json::from_slice::<CacheData<T>>(&bytes) {
+            Ok(data) => return Ok(Some(data)),
+            Err(e) => tracing::warn!("Failed to deserialize hot cache entry: {e}"),
+        },
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Hot cache lookup failed, falling back to ClickHouse: {e}"),
+    }
+
     // NOTE: the short cache key is just so the ClickHouse index can be as efficient as possible
     // but we always check against the long cache key before returning a result
     let short_cache_key = cache_key.get_short_key()?.to_string();
@@ -552,6 +678,15 @@ pub async fn cache_lookup_inner<T: CacheOutput + DeserializeOwned>(
             message: format!("Failed to deserialize output: {e}"),
         })
     })?;
+    // Best-effort backfill of the hot tier so the next lookup for this key is a hot hit.
+    match serde_json::to_vec(&result) {
+        Ok(bytes) => {
+            if let Err(e) = hot_cache.set(&cache_key, bytes).await {
+                tracing::warn!("Failed to backfill hot cache: {e}");
+            }
+        }
+        Err(e) => tracing::warn!("Failed to serialize cache entry for hot cache backfill: {e}"),
+    }
     Ok(Some(result))
 }
 
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::CacheParamsOptions,
+    client::client_inference_params::ClientSecretString,
+    embeddings::{EmbeddingEncodingFormat, EmbeddingInput},
+    endpoints::embeddings::EmbeddingsParams,
+    endpoints::openai_compatible::types::embeddings::{
+        OpenAICompatibleEmbeddingParams, TENSORZERO_EMBEDDING_MODEL_NAME_PREFIX,
+    },
+    error::Error,
+};
+
+// This mirrors `ClientInferenceParams`'s relationship to `Params`: a copy-paste of
+// `EmbeddingsParams` with just the `credentials` field adjusted to allow serialization.
+/// The expected payload is a JSON object with the following fields:
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct ClientEmbeddingParams {
+    /// The input to embed. A batch of inputs can be embedded in a single request by
+    /// providing `EmbeddingInput::Batch`/`EmbeddingInput::BatchTokens`.
+    pub input: EmbeddingInput,
+    pub model_name: String,
+    pub dimensions: Option<u32>,
+    #[serde(default)]
+    pub encoding_format: EmbeddingEncodingFormat,
+    // if true, the embedding will not be stored
+    pub dryrun: Option<bool>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(type = "Map<string, string>"))]
+    pub credentials: HashMap<String, ClientSecretString>,
+    #[serde(default)]
+    pub cache_options: CacheParamsOptions,
+    #[serde(default)]
+    pub include_raw_response: bool,
+
+    /// Tensorzero API key to set in the `Authorization` header when making the HTTP request to
+    /// the TensorZero Gateway. This field is not serialized into the request body.
+    #[serde(skip)]
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(skip))]
+    pub api_key: Option<SecretString>,
+}
+
+impl TryFrom<ClientEmbeddingParams> for EmbeddingsParams {
+    type Error = Error;
+    fn try_from(this: ClientEmbeddingParams) -> Result<Self, Error> {
+        Ok(EmbeddingsParams {
+            input: this.input,
+            model_name: this.model_name,
+            dimensions: this.dimensions,
+            encoding_format: this.encoding_format,
+            dryrun: this.dryrun,
+            // TODO - can we avoid reconstructing the hashmap here?
+            credentials: this
+                .credentials
+                .into_iter()
+                .map(|(k, v)| (k, v.0))
+                .collect(),
+            cache_options: this.cache_options,
+            include_raw_response: this.include_raw_response,
+        })
+    }
+}
+
+impl From<ClientEmbeddingParams> for OpenAICompatibleEmbeddingParams {
+    fn from(this: ClientEmbeddingParams) -> Self {
+        OpenAICompatibleEmbeddingParams {
+            input: this.input,
+            model: format!(
+                "{TENSORZERO_EMBEDDING_MODEL_NAME_PREFIX}{}",
+                this.model_name
+            ),
+            dimensions: this.dimensions,
+            encoding_format: this.encoding_format,
+            tensorzero_credentials: this
+                .credentials
+                .into_iter()
+                .map(|(k, v)| (k, v.0))
+                .collect(),
+            tensorzero_dryrun: this.dryrun,
+            tensorzero_cache_options: Some(this.cache_options),
+            tensorzero_include_raw_response: this.include_raw_response,
+        }
+    }
+}
+
+// This asserts that the fields in `ClientEmbeddingParams` match the fields in `EmbeddingsParams`,
+// by explicitly naming all of the fields in both structs.
+// This will stop compiling if the fields don't match.
+#[expect(unused)]
+fn assert_params_match(client_params: ClientEmbeddingParams) {
+    let ClientEmbeddingParams {
+        input,
+        model_name,
+        dimensions,
+        encoding_format,
+        dryrun,
+        credentials,
+        cache_options,
+        include_raw_response,
+        api_key: _,
+    } = client_params;
+    let _ = EmbeddingsParams {
+        input,
+        model_name,
+        dimensions,
+        encoding_format,
+        dryrun,
+        credentials: credentials.into_iter().map(|(k, v)| (k, v.0)).collect(),
+        cache_options,
+        include_raw_response,
+    };
+}
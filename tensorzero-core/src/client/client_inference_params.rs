@@ -78,6 +78,10 @@ pub struct ClientInferenceParams {
     /// If `true`, include `raw_usage` in the response's `usage` field, containing the raw usage data from each model inference.
     #[serde(default)]
     pub include_raw_usage: bool,
+    /// If `true`, add a `snapshot_hash` field to the response, identifying the exact config
+    /// snapshot that produced it.
+    #[serde(default)]
+    pub include_snapshot_hash: bool,
     // NOTE: Currently, ts_rs does not handle #[serde(transparent)] correctly,
     // so we disable the type generation for the extra_body and extra_headers fields.
     // I tried doing a direct #[ts(type = "InferenceExtraBody[]")] and
@@ -93,6 +97,12 @@ pub struct ClientInferenceParams {
     #[cfg_attr(feature = "ts-bindings", ts(skip))]
     pub extra_headers: UnfilteredInferenceExtraHeaders,
     pub internal_dynamic_variant_config: Option<UninitializedVariantInfo>,
+    /// An overall deadline (in milliseconds) for the inference request, covering variant
+    /// selection, sampling retries, and each variant's own model/provider fallback chain.
+    /// If exceeded, the request fails with a `InferenceTimeout` error instead of continuing
+    /// to retry other variants. This is independent of (and applied in addition to) any
+    /// `timeouts` configured on the function's variants, models, or model providers.
+    pub timeout_ms: Option<u64>,
 
     /// OTLP trace headers to attach to the HTTP request to the TensorZero Gateway.
     /// These headers will be prefixed with `tensorzero-otlp-traces-extra-header-` and
@@ -146,9 +156,11 @@ impl TryFrom<ClientInferenceParams> for Params {
             include_original_response: this.include_original_response,
             include_raw_response: this.include_raw_response,
             include_raw_usage: this.include_raw_usage,
+            include_snapshot_hash: this.include_snapshot_hash,
             extra_body: this.extra_body,
             extra_headers: this.extra_headers,
             internal_dynamic_variant_config: this.internal_dynamic_variant_config,
+            timeout_ms: this.timeout_ms,
         })
     }
 }
@@ -176,9 +188,11 @@ fn assert_params_match(client_params: ClientInferenceParams) {
         include_original_response,
         include_raw_response,
         include_raw_usage,
+        include_snapshot_hash,
         extra_body,
         extra_headers,
         internal_dynamic_variant_config,
+        timeout_ms,
         otlp_traces_extra_headers: _,
         otlp_traces_extra_attributes: _,
         otlp_traces_extra_resources: _,
@@ -202,9 +216,11 @@ fn assert_params_match(client_params: ClientInferenceParams) {
         include_original_response,
         include_raw_response,
         include_raw_usage,
+        include_snapshot_hash,
         extra_body,
         extra_headers,
         internal_dynamic_variant_config,
+        timeout_ms,
     };
 }
 
@@ -11,6 +11,7 @@ use crate::feature_flags;
 use crate::http::TensorzeroResponseWrapper;
 use crate::http::{DEFAULT_HTTP_CLIENT_TIMEOUT, TensorzeroHttpClient, TensorzeroRequestBuilder};
 use crate::inference::types::stored_input::StoragePathResolver;
+use crate::inference::types::usage::Usage;
 use crate::observability::{
     TENSORZERO_OTLP_ATTRIBUTE_PREFIX, TENSORZERO_OTLP_HEADERS_PREFIX,
     TENSORZERO_OTLP_RESOURCE_PREFIX,
@@ -24,18 +25,23 @@ use crate::{
     error::{Error, ErrorDetails},
     utils::gateway::{GatewayHandle, setup_clickhouse, setup_postgres, setup_valkey},
 };
+use futures::stream::FuturesUnordered;
 use reqwest::header::HeaderMap;
 use reqwest_eventsource::Event;
 use secrecy::{ExposeSecret, SecretString};
 use std::fmt::Debug;
+use tokio::sync::Semaphore;
 use tokio::time::error::Elapsed;
 use tokio_stream::StreamExt;
 use url::Url;
 
+pub use client_embedding_params::ClientEmbeddingParams;
 pub use client_inference_params::{ClientInferenceParams, ClientSecretString};
 pub use input_handling::resolved_input_to_client_input;
 
 pub use crate::cache::CacheParamsOptions;
+pub use crate::embeddings::{EmbeddingEncodingFormat, EmbeddingInput};
+pub use crate::endpoints::embeddings::EmbeddingResponse;
 pub use crate::endpoints::feedback::FeedbackResponse;
 pub use crate::endpoints::feedback::Params as FeedbackParams;
 pub use crate::endpoints::inference::{
@@ -49,8 +55,12 @@ pub use crate::inference::types::{
 };
 pub use crate::tool::{DynamicToolParams, Tool};
 
+pub mod client_embedding_params;
 pub mod client_inference_params;
 pub mod input_handling;
+mod response_cache;
+
+pub use response_cache::ResponseCacheOptions;
 
 pub enum ClientMode {
     HTTPGateway(HTTPGateway),
@@ -80,12 +90,68 @@ pub struct HttpResponse<T> {
     pub raw_response: Option<String>,
 }
 
+/// Options controlling [`Client::parallel_inference`]'s bounded-concurrency fan-out.
+#[derive(Debug, Clone)]
+pub struct ParallelInferenceOptions {
+    /// The maximum number of inference requests in flight at once.
+    pub concurrency: usize,
+    /// If set, an individual inference is reported as `TensorZeroError::RequestTimeout`
+    /// if it hasn't completed within this duration. Streaming inferences are timed out
+    /// based on when the initial response begins, not the full duration of the stream.
+    pub per_item_timeout: Option<Duration>,
+}
+
+impl Default for ParallelInferenceOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 10,
+            per_item_timeout: None,
+        }
+    }
+}
+
+/// The outcome of a single request within a [`Client::parallel_inference`] call.
+#[derive(Debug)]
+pub struct ParallelInferenceItem {
+    /// The position of the originating request in the `requests` vector passed to
+    /// `parallel_inference`. Items are not returned in this order, since faster requests
+    /// can complete before slower ones that were submitted earlier.
+    pub index: usize,
+    pub result: Result<InferenceOutput, TensorZeroError>,
+}
+
+/// The result of a [`Client::parallel_inference`] call.
+#[derive(Debug)]
+pub struct ParallelInferenceOutput {
+    pub items: Vec<ParallelInferenceItem>,
+    /// The sum of `Usage` across every successful, non-streaming item in `items`.
+    /// Streaming items are excluded, since their usage isn't known until the caller
+    /// fully consumes the stream.
+    pub usage: Usage,
+}
+
+fn sum_usage(a: Usage, b: Usage) -> Usage {
+    Usage {
+        input_tokens: match (a.input_tokens, b.input_tokens) {
+            (Some(a), Some(b)) => Some(a + b),
+            (Some(tokens), None) | (None, Some(tokens)) => Some(tokens),
+            (None, None) => None,
+        },
+        output_tokens: match (a.output_tokens, b.output_tokens) {
+            (Some(a), Some(b)) => Some(a + b),
+            (Some(tokens), None) | (None, Some(tokens)) => Some(tokens),
+            (None, None) => None,
+        },
+    }
+}
+
 pub struct HTTPGateway {
     pub base_url: Url,
     pub http_client: TensorzeroHttpClient,
     headers: HeaderMap,
     timeout: Option<Duration>,
     verbose_errors: bool,
+    response_cache: Option<response_cache::ResponseCache>,
 }
 
 impl HTTPGateway {
@@ -202,6 +268,26 @@ impl HTTPGateway {
         Ok((response, raw_response))
     }
 
+    pub async fn send_and_get_bytes(
+        &self,
+        builder: TensorzeroRequestBuilder<'_>,
+    ) -> Result<bytes::Bytes, TensorZeroError> {
+        let builder = self.customize_builder(builder);
+        let resp = self.check_http_response(builder.send().await).await?;
+        resp.bytes().await.map_err(|e| TensorZeroError::Other {
+            source: Error::new(ErrorDetails::Serialization {
+                message: format!(
+                    "Error reading response body: {}",
+                    DisplayOrDebug {
+                        val: e,
+                        debug: self.verbose_errors,
+                    }
+                ),
+            })
+            .into(),
+        })
+    }
+
     async fn send_http_stream_inference(
         &self,
         builder: TensorzeroRequestBuilder<'_>,
@@ -318,6 +404,7 @@ pub struct ClientBuilder {
     api_key: Option<SecretString>,
     timeout: Option<Duration>,
     drop_wrapper: Option<DropWrapper>,
+    response_cache_options: Option<ResponseCacheOptions>,
 }
 
 /// An error type representing an error from within the TensorZero gateway
@@ -480,6 +567,7 @@ impl ClientBuilder {
             api_key: None,
             timeout: None,
             drop_wrapper: None,
+            response_cache_options: None,
         }
     }
 
@@ -529,6 +617,20 @@ impl ClientBuilder {
         self
     }
 
+    /// Enables an in-process cache of non-streaming inference responses, keyed by function,
+    /// variant, a hash of the rest of the request, and the config snapshot hash last set via
+    /// `Client::set_response_cache_snapshot_hash` (if any). This is only used in `HTTPGateway`
+    /// mode, and only helps callers (e.g. tools) that repeat the same idempotent inference
+    /// request - a cache hit skips the gateway entirely, so it also skips whatever the gateway
+    /// would otherwise have done for that request (e.g. writing a new inference row). Off by
+    /// default.
+    ///
+    /// See the `response_cache` module for how the cache is invalidated on config changes.
+    pub fn with_response_cache(mut self, options: ResponseCacheOptions) -> Self {
+        self.response_cache_options = Some(options);
+        self
+    }
+
     /// Constructs a `Client`, returning an error if the configuration is invalid.
     pub async fn build(self) -> Result<Client, ClientBuilderError> {
         // Initialize feature flags (for embedded clients).
@@ -909,6 +1011,9 @@ impl ClientBuilder {
                 headers,
                 timeout: self.timeout,
                 verbose_errors: self.verbose_errors,
+                response_cache: self
+                    .response_cache_options
+                    .map(response_cache::ResponseCache::new),
             })),
             verbose_errors: self.verbose_errors,
         })
@@ -942,6 +1047,27 @@ impl Client {
         &self.mode
     }
 
+    /// Sets the config snapshot hash used as part of the response cache key (see
+    /// `ClientBuilder::with_response_cache`). Pass `None` to clear it. No-op if the response
+    /// cache isn't enabled or this client isn't in `HTTPGateway` mode.
+    pub fn set_response_cache_snapshot_hash(&self, snapshot_hash: Option<String>) {
+        if let ClientMode::HTTPGateway(client) = &*self.mode {
+            if let Some(cache) = &client.response_cache {
+                cache.set_snapshot_hash(snapshot_hash);
+            }
+        }
+    }
+
+    /// Drops all entries from the response cache (see `ClientBuilder::with_response_cache`).
+    /// No-op if the response cache isn't enabled or this client isn't in `HTTPGateway` mode.
+    pub fn invalidate_response_cache(&self) {
+        if let ClientMode::HTTPGateway(client) = &*self.mode {
+            if let Some(cache) = &client.response_cache {
+                cache.invalidate_all();
+            }
+        }
+    }
+
     /// Assigns feedback for a TensorZero inference.
     /// See https://www.tensorzero.com/docs/gateway/api-reference#post-feedback
     pub async fn feedback(
@@ -981,6 +1107,47 @@ impl Client {
         }
     }
 
+    /// Embeds a single input or a batch of inputs.
+    /// See https://www.tensorzero.com/docs/gateway/api-reference#post-openaiv1embeddings
+    ///
+    /// In HTTP gateway mode, this is served by the OpenAI-compatible `/openai/v1/embeddings`
+    /// endpoint (there is no native `/embeddings` route), converting to and from TensorZero's
+    /// native request/response types. In embedded gateway mode, the core embeddings logic is
+    /// called directly.
+    pub async fn embed(
+        &self,
+        params: ClientEmbeddingParams,
+    ) -> Result<EmbeddingResponse, TensorZeroError> {
+        match &*self.mode {
+            ClientMode::HTTPGateway(_) => {
+                let api_key = params.api_key.clone();
+                let openai_params: OpenAICompatibleEmbeddingParams = params.into();
+                let response = self.http_embeddings(openai_params, api_key).await?;
+                Ok(response.response.into())
+            }
+            ClientMode::EmbeddedGateway { gateway, timeout } => {
+                Ok(with_embedded_timeout(*timeout, async {
+                    crate::endpoints::embeddings::embeddings(
+                        gateway.handle.app_state.config.clone(),
+                        &gateway.handle.app_state.http_client,
+                        gateway.handle.app_state.clickhouse_connection_info.clone(),
+                        gateway.handle.app_state.postgres_connection_info.clone(),
+                        gateway.handle.app_state.deferred_tasks.clone(),
+                        gateway.handle.app_state.rate_limiting_manager.clone(),
+                        gateway.handle.app_state.hot_cache.clone(),
+                        params.try_into().map_err(err_to_http)?,
+                        // We currently ban auth-enabled configs in embedded gateway mode,
+                        // so we don't have an API key here
+                        None,
+                    )
+                    .await
+                    .map_err(err_to_http)
+                })
+                .await?)
+            }
+        }
+    }
+
     pub async fn http_embeddings(
         &self,
         params: OpenAICompatibleEmbeddingParams,
@@ -1136,7 +1303,19 @@ impl Client {
         params: ClientInferenceParams,
     ) -> Result<InferenceOutput, TensorZeroError> {
         match &*self.mode {
-            ClientMode::HTTPGateway(_) => Ok(self.http_inference(params).await?.response),
+            ClientMode::HTTPGateway(client) => {
+                let Some(cache) = &client.response_cache else {
+                    return Ok(self.http_inference(params).await?.response);
+                };
+                if let Some(cached) = cache.get(&params) {
+                    return Ok(InferenceOutput::NonStreaming(cached));
+                }
+                let response = self.http_inference(params.clone()).await?.response;
+                if let InferenceOutput::NonStreaming(inference_response) = &response {
+                    cache.insert(&params, inference_response.clone());
+                }
+                Ok(response)
+            }
             ClientMode::EmbeddedGateway { gateway, timeout } => {
                 Ok(with_embedded_timeout(*timeout, async {
                     let res = Box::pin(crate::endpoints::inference::inference(
@@ -1146,6 +1325,7 @@ impl Client {
                         gateway.handle.app_state.postgres_connection_info.clone(),
                         gateway.handle.app_state.deferred_tasks.clone(),
                         gateway.handle.app_state.rate_limiting_manager.clone(),
+                        gateway.handle.app_state.hot_cache.clone(),
                         params.try_into().map_err(err_to_http)?,
                         // We currently ban auth-enabled configs in embedded gateway mode,
                         // so we don't have an API key here
@@ -1168,6 +1348,55 @@ impl Client {
         }
     }
 
+    /// Runs many inferences concurrently, so that callers outside the `evaluations` crate
+    /// don't need to hand-roll a `Semaphore` + `FuturesUnordered` fan-out to avoid overwhelming
+    /// the gateway (or a rate-limited model provider) with unbounded parallelism.
+    ///
+    /// Requests are dispatched with at most `options.concurrency` in flight at a time. Each
+    /// request's outcome (including errors) is reported individually in the returned
+    /// `ParallelInferenceOutput::items`; a failure in one request does not cancel the others.
+    /// Items complete in whatever order finishes first, not the order of `requests` — use
+    /// `ParallelInferenceItem::index` to match a result back to its input.
+    pub async fn parallel_inference(
+        &self,
+        requests: Vec<ClientInferenceParams>,
+        options: ParallelInferenceOptions,
+    ) -> ParallelInferenceOutput {
+        let semaphore = Arc::new(Semaphore::new(options.concurrency.max(1)));
+        let per_item_timeout = options.per_item_timeout;
+        let mut pending = FuturesUnordered::new();
+        for (index, params) in requests.into_iter().enumerate() {
+            let semaphore = Arc::clone(&semaphore);
+            pending.push(async move {
+                // The semaphore is only ever closed by dropping it, so this can't fail.
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("parallel_inference semaphore should not be closed");
+                let result = match per_item_timeout {
+                    Some(timeout) => {
+                        match tokio::time::timeout(timeout, self.inference(params)).await {
+                            Ok(result) => result,
+                            Err(_) => Err(TensorZeroError::RequestTimeout),
+                        }
+                    }
+                    None => self.inference(params).await,
+                };
+                ParallelInferenceItem { index, result }
+            });
+        }
+
+        let mut items = Vec::with_capacity(pending.len());
+        let mut usage = Usage::zero();
+        while let Some(item) = futures::StreamExt::next(&mut pending).await {
+            if let Ok(InferenceOutput::NonStreaming(response)) = &item.result {
+                usage = sum_usage(usage, response.usage());
+            }
+            items.push(item);
+        }
+        ParallelInferenceOutput { items, usage }
+    }
+
     pub async fn get_object(
         &self,
         storage_path: StoragePath,
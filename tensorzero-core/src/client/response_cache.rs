@@ -0,0 +1,98 @@
+//! An optional in-process cache of non-streaming inference responses for `Client` in
+//! `HTTPGateway` mode. This is purely a client-side latency optimization for callers (e.g.
+//! tools) that repeat the same idempotent inference request: a cache hit skips the HTTP
+//! round trip to the gateway entirely, so it also skips whatever the gateway would have done
+//! for that request (e.g. writing a new inference row to ClickHouse). It is opt-in and off by
+//! default for this reason.
+//!
+//! There is currently no server-pushed config-change notification stream in this codebase, so
+//! cache invalidation on a config change is driven by whatever config-mutating operations
+//! already exist on `Client`: `write_config` and `update_snapshot_tags` both call
+//! `Client::invalidate_response_cache` after a successful request. Callers that learn about a
+//! config change some other way (e.g. a canary rollout completing) can call
+//! `Client::invalidate_response_cache` or `Client::set_response_cache_snapshot_hash` directly.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arc_swap::ArcSwapOption;
+use moka::sync::Cache;
+
+use crate::client::client_inference_params::ClientInferenceParams;
+use crate::endpoints::inference::InferenceResponse;
+
+/// Options for the optional in-process response cache. See `ClientBuilder::with_response_cache`.
+#[derive(Debug, Clone)]
+pub struct ResponseCacheOptions {
+    pub max_capacity: u64,
+    pub time_to_live: Duration,
+}
+
+impl Default for ResponseCacheOptions {
+    fn default() -> Self {
+        Self {
+            max_capacity: 1000,
+            time_to_live: Duration::from_secs(300),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ResponseCacheKey {
+    function_name: String,
+    variant_name: Option<String>,
+    input_hash: [u8; 32],
+    snapshot_hash: Option<Arc<String>>,
+}
+
+pub(super) struct ResponseCache {
+    cache: Cache<ResponseCacheKey, InferenceResponse>,
+    snapshot_hash: ArcSwapOption<String>,
+}
+
+impl ResponseCache {
+    pub(super) fn new(options: ResponseCacheOptions) -> Self {
+        Self {
+            cache: Cache::builder()
+                .max_capacity(options.max_capacity)
+                .time_to_live(options.time_to_live)
+                .build(),
+            snapshot_hash: ArcSwapOption::empty(),
+        }
+    }
+
+    pub(super) fn set_snapshot_hash(&self, snapshot_hash: Option<String>) {
+        self.snapshot_hash.store(snapshot_hash.map(Arc::new));
+    }
+
+    pub(super) fn invalidate_all(&self) {
+        self.cache.invalidate_all();
+    }
+
+    /// The full serialized request (minus the fields `ClientInferenceParams` already skips,
+    /// like `api_key`) is used as the "input hash" component of the key, rather than just the
+    /// `input` field, so that requests which differ in some other way (e.g. `params` overrides
+    /// or `tags`) are never treated as equivalent.
+    fn key_for(&self, params: &ClientInferenceParams) -> Option<ResponseCacheKey> {
+        if params.stream.unwrap_or(false) {
+            return None;
+        }
+        let serialized = serde_json::to_vec(params).ok()?;
+        Some(ResponseCacheKey {
+            function_name: params.function_name.clone().unwrap_or_default(),
+            variant_name: params.variant_name.clone(),
+            input_hash: *blake3::hash(&serialized).as_bytes(),
+            snapshot_hash: self.snapshot_hash.load_full(),
+        })
+    }
+
+    pub(super) fn get(&self, params: &ClientInferenceParams) -> Option<InferenceResponse> {
+        self.cache.get(&self.key_for(params)?)
+    }
+
+    pub(super) fn insert(&self, params: &ClientInferenceParams, response: InferenceResponse) {
+        if let Some(key) = self.key_for(params) {
+            self.cache.insert(key, response);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+/// A per-function data-residency constraint: inference for this function must only be routed to
+/// model providers whose `region` (see [`crate::model::ModelProvider::region`]) is one of
+/// `allowed_regions`.
+///
+/// Providers with no `region` configured are treated as non-compliant with any policy, since we
+/// have no basis to say where they serve from - operators who want to opt a function into this
+/// check must also tag every provider it can route to with a `region`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct DataResidencyPolicy {
+    /// Regions a compliant provider's `region` may match, e.g. `["eu-west-1", "eu-central-1"]`.
+    pub allowed_regions: Vec<String>,
+}
+
+impl DataResidencyPolicy {
+    /// Whether a provider serving from `provider_region` satisfies this policy.
+    pub fn allows(&self, provider_region: Option<&str>) -> bool {
+        match provider_region {
+            Some(region) => self.allowed_regions.iter().any(|allowed| allowed == region),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_matching_region() {
+        let policy = DataResidencyPolicy {
+            allowed_regions: vec!["eu-west-1".to_string()],
+        };
+        assert!(
+            policy.allows(Some("eu-west-1")),
+            "A provider whose region is in allowed_regions should be allowed"
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_matching_region() {
+        let policy = DataResidencyPolicy {
+            allowed_regions: vec!["eu-west-1".to_string()],
+        };
+        assert!(
+            !policy.allows(Some("us-east-1")),
+            "A provider whose region is not in allowed_regions should be rejected"
+        );
+    }
+
+    #[test]
+    fn test_rejects_missing_region() {
+        let policy = DataResidencyPolicy {
+            allowed_regions: vec!["eu-west-1".to_string()],
+        };
+        assert!(
+            !policy.allows(None),
+            "A provider with no configured region should be rejected, since we can't verify where it serves from"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::Duration;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +17,295 @@ use crate::{
 };
 
 use super::ObjectStoreInfo;
+use super::policy::ConfigPolicyConfig;
+
+/// Native TLS termination for the gateway's own listener, for deployments
+/// that don't sit behind a TLS-terminating proxy (e.g. a load balancer or
+/// sidecar). Certificate/key material is read from the filesystem paths
+/// given here rather than embedded in the config file.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct TlsConfig {
+    /// Path to a PEM-encoded certificate chain for the gateway's listener.
+    /// Used when the client doesn't send SNI, or sends a hostname not listed
+    /// in `sni_certs`.
+    pub cert_path: String,
+    /// Path to the PEM-encoded private key matching `cert_path`.
+    pub key_path: String,
+    /// If set, enables mutual TLS: client connections must present a
+    /// certificate signed by a CA in this PEM-encoded bundle.
+    #[serde(default)]
+    pub client_ca_path: Option<String>,
+    /// When mTLS is enabled (`client_ca_path` is set), whether to reject
+    /// connections that don't present a client certificate at all.
+    /// Defaults to `true` - if you want to accept both mTLS and plain TLS
+    /// clients, terminate TLS in front of the gateway instead.
+    #[serde(default = "default_require_client_cert")]
+    pub require_client_cert: bool,
+    /// Additional certificate/key pairs selected by SNI hostname, for
+    /// terminating TLS for multiple hostnames on the same listener (e.g. a
+    /// gateway shared across several customer-facing domains). Keyed by the
+    /// hostname the client presents in its `ClientHello`; `cert_path`/`key_path`
+    /// above remain the default for hostnames not listed here.
+    #[serde(default)]
+    pub sni_certs: HashMap<String, SniCertConfig>,
+}
+
+/// A certificate/key pair served for one SNI hostname. See `TlsConfig::sni_certs`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct SniCertConfig {
+    /// Path to a PEM-encoded certificate chain for this hostname.
+    pub cert_path: String,
+    /// Path to the PEM-encoded private key matching `cert_path`.
+    pub key_path: String,
+}
+
+fn default_require_client_cert() -> bool {
+    true
+}
+
+impl TlsConfig {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.cert_path.trim().is_empty() {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message: "`gateway.tls.cert_path` must not be empty".to_string(),
+            }));
+        }
+        if self.key_path.trim().is_empty() {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message: "`gateway.tls.key_path` must not be empty".to_string(),
+            }));
+        }
+        if !self.require_client_cert && self.client_ca_path.is_none() {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message:
+                    "`gateway.tls.require_client_cert` can only be set to `false` when `gateway.tls.client_ca_path` is also set"
+                        .to_string(),
+            }));
+        }
+        for (hostname, sni_cert) in &self.sni_certs {
+            if hostname.trim().is_empty() {
+                return Err(Error::new(crate::error::ErrorDetails::Config {
+                    message: "`gateway.tls.sni_certs` keys must not be empty".to_string(),
+                }));
+            }
+            if sni_cert.cert_path.trim().is_empty() || sni_cert.key_path.trim().is_empty() {
+                return Err(Error::new(crate::error::ErrorDetails::Config {
+                    message: format!(
+                        "`gateway.tls.sni_certs.{hostname}.cert_path`/`key_path` must not be empty"
+                    ),
+                }));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An IP allowlist applied to gateway requests, optionally scoped to
+/// specific routes. The client IP used to check `allowed_cidrs` is, by
+/// default, the TCP peer address of the connection - the address we
+/// actually accepted the socket from, which a client cannot spoof.
+///
+/// If the gateway sits behind a reverse proxy (load balancer, CDN) that
+/// terminates the client connection itself, the TCP peer address seen by
+/// the gateway is the proxy, not the original client. Set
+/// `trust_x_forwarded_for: true` to instead take the client IP from the
+/// first entry of the `X-Forwarded-For` header - only do this if that
+/// proxy is trusted to set (and strip any client-supplied value of)
+/// `X-Forwarded-For`, since otherwise a caller can put any IP it wants in
+/// that header and bypass the allowlist entirely.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct AccessPolicyConfig {
+    /// Trust the `X-Forwarded-For` header (set by a trusted reverse proxy) instead of the
+    /// TCP peer address when determining the client IP. See the struct-level doc for the
+    /// security implications of enabling this.
+    #[serde(default)]
+    pub trust_x_forwarded_for: bool,
+    #[serde(default)]
+    pub enabled: bool,
+    /// CIDR ranges (e.g. `10.0.0.0/8`, `203.0.113.5/32`) allowed to reach
+    /// any route not covered by `route_overrides`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(type = "string[]"))]
+    pub allowed_cidrs: Vec<ipnet::IpNet>,
+    /// Per-route CIDR allowlists, keyed by the route's Axum path pattern
+    /// (e.g. `/internal/config`). A route listed here uses only its own
+    /// allowlist, not `allowed_cidrs`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(type = "Record<string, string[]>"))]
+    pub route_overrides: std::collections::HashMap<String, Vec<ipnet::IpNet>>,
+}
+
+impl AccessPolicyConfig {
+    /// Returns whether `ip` is allowed to access `route`, given this policy.
+    pub fn is_allowed(&self, route: &str, ip: std::net::IpAddr) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        let cidrs = self
+            .route_overrides
+            .get(route)
+            .unwrap_or(&self.allowed_cidrs);
+        cidrs.iter().any(|cidr| cidr.contains(&ip))
+    }
+}
+
+/// Mirrors a sample of production inference requests to a staging gateway,
+/// so a new config can be soak-tested against real traffic shapes before
+/// being promoted to production. Mirrored requests are fire-and-forget:
+/// their responses (and any errors) are discarded, and mirroring never
+/// delays or affects the response returned to the original caller.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct MirroringConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Base URL of the staging gateway that mirrored requests are forwarded to.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "string"))]
+    pub staging_url: url::Url,
+    /// Fraction of requests to mirror, from `0.0` (none) to `1.0` (all).
+    #[serde(default = "default_mirroring_sample_rate")]
+    pub sample_rate: f64,
+}
+
+fn default_mirroring_sample_rate() -> f64 {
+    1.0
+}
+
+impl MirroringConfig {
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(0.0..=1.0).contains(&self.sample_rate) {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message: format!(
+                    "`gateway.mirroring.sample_rate` must be between 0.0 and 1.0, got {}",
+                    self.sample_rate
+                ),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Returns whether a request should be mirrored, given this config.
+    pub fn should_mirror(&self) -> bool {
+        self.enabled && rand::random::<f64>() < self.sample_rate
+    }
+}
+
+/// Default, global limits on how much a single episode (a sequence of
+/// inferences sharing an `episode_id`, e.g. one agent run) may consume,
+/// protecting against runaway agent loops. Requires Postgres to be enabled -
+/// if Postgres is disabled, budgets are not enforced.
+///
+/// `None` in any of the limit fields means that dimension is unbounded.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct EpisodeBudgetConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum total tokens (prompt + completion, across all inferences in the episode).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u64>,
+    /// Maximum total cost, in USD, across all inferences in the episode.
+    ///
+    /// Cost is only tracked for inferences made through a model provider with `pricing`
+    /// configured (see [`crate::model::ModelPricing`]); inferences through providers with no
+    /// `pricing` set don't contribute to the running total. If every provider an episode uses
+    /// is unpriced, this limit is never reached and is effectively unenforced - set `pricing`
+    /// on those providers, or rely on `max_tokens`/`max_inference_count` instead.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
+    /// Maximum number of inferences in the episode.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inference_count: Option<u32>,
+}
+
+impl EpisodeBudgetConfig {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.enabled
+            && self.max_tokens.is_none()
+            && self.max_cost_usd.is_none()
+            && self.max_inference_count.is_none()
+        {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message: "`gateway.episode_budgets` is enabled but none of `max_tokens`, `max_cost_usd`, or `max_inference_count` are set".to_string(),
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// An inbound webhook that translates feedback events from an external system (a support
+/// tool, a CRM) into TensorZero feedback. Registered under `gateway.webhooks.<name>` and
+/// exposed at `POST /webhooks/<name>`.
+///
+/// Requests must carry an HMAC-SHA256 signature of the raw request body, hex-encoded, in
+/// the `X-TensorZero-Webhook-Signature` header, computed with the secret at
+/// `secret_location`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct WebhookConfig {
+    /// Where to find the shared secret used to verify the request signature.
+    pub secret_location: CredentialLocation,
+    /// Maps fields of the external JSON payload onto `FeedbackParams`.
+    pub field_mapping: WebhookFieldMapping,
+}
+
+impl WebhookConfig {
+    pub fn validate(&self, name: &str) -> Result<(), Error> {
+        self.field_mapping.validate(name)
+    }
+}
+
+/// Maps fields of an external webhook payload onto `FeedbackParams`. Each `*_pointer`
+/// field is a JSON Pointer (RFC 6901) into the webhook payload, e.g. `/data/ticket/id`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct WebhookFieldMapping {
+    /// Pointer to the inference id. Mutually exclusive with `episode_id_pointer`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inference_id_pointer: Option<String>,
+    /// Pointer to the episode id. Mutually exclusive with `inference_id_pointer`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode_id_pointer: Option<String>,
+    /// Pointer to the feedback value.
+    pub value_pointer: String,
+    /// The TensorZero metric name (or `comment`/`demonstration`) to attach the feedback to.
+    pub metric_name: String,
+}
+
+impl WebhookFieldMapping {
+    pub fn validate(&self, name: &str) -> Result<(), Error> {
+        if self.inference_id_pointer.is_some() == self.episode_id_pointer.is_some() {
+            return Err(Error::new(crate::error::ErrorDetails::Config {
+                message: format!(
+                    "webhook `{name}` must set exactly one of `field_mapping.inference_id_pointer` or `field_mapping.episode_id_pointer`"
+                ),
+            }));
+        }
+        Ok(())
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -172,11 +463,39 @@ pub struct UninitializedGatewayConfig {
     pub relay: Option<UninitializedRelayConfig>,
     #[serde(default)]
     pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    #[serde(default)]
+    pub access_policy: AccessPolicyConfig,
+    #[serde(default)]
+    pub mirroring: Option<MirroringConfig>,
+    #[serde(default)]
+    pub episode_budgets: Option<EpisodeBudgetConfig>,
+    #[serde(default)]
+    pub webhooks: HashMap<String, WebhookConfig>,
+    /// Organization guardrails evaluated against every config snapshot written via
+    /// `write_config`. Lives on the live gateway config (not the snapshot itself), the same
+    /// way `access_policy` does, so it applies uniformly regardless of which snapshot is being
+    /// written.
+    #[serde(default)]
+    pub policy: ConfigPolicyConfig,
 }
 
 impl UninitializedGatewayConfig {
     pub fn load(self, object_store_info: Option<&ObjectStoreInfo>) -> Result<GatewayConfig, Error> {
         self.metrics.validate()?;
+        if let Some(tls) = &self.tls {
+            tls.validate()?;
+        }
+        if let Some(mirroring) = &self.mirroring {
+            mirroring.validate()?;
+        }
+        if let Some(episode_budgets) = &self.episode_budgets {
+            episode_budgets.validate()?;
+        }
+        for (name, webhook) in &self.webhooks {
+            webhook.validate(name)?;
+        }
         let fetch_and_encode_input_files_before_inference = if let Some(value) =
             self.fetch_and_encode_input_files_before_inference
         {
@@ -230,6 +549,12 @@ impl UninitializedGatewayConfig {
                 .unwrap_or(DEFAULT_HTTP_CLIENT_TIMEOUT),
             relay,
             metrics: self.metrics,
+            tls: self.tls,
+            access_policy: self.access_policy,
+            mirroring: self.mirroring,
+            episode_budgets: self.episode_budgets,
+            webhooks: self.webhooks,
+            policy: self.policy,
         })
     }
 }
@@ -255,6 +580,17 @@ pub struct GatewayConfig {
     #[serde(skip)]
     pub relay: Option<TensorzeroRelay>,
     pub metrics: MetricsConfig,
+    pub tls: Option<TlsConfig>,
+    #[serde(default)]
+    pub access_policy: AccessPolicyConfig,
+    #[serde(default)]
+    pub mirroring: Option<MirroringConfig>,
+    #[serde(default)]
+    pub episode_budgets: Option<EpisodeBudgetConfig>,
+    #[serde(default)]
+    pub webhooks: HashMap<String, WebhookConfig>,
+    #[serde(default)]
+    pub policy: ConfigPolicyConfig,
 }
 
 impl Default for GatewayConfig {
@@ -274,6 +610,12 @@ impl Default for GatewayConfig {
             global_outbound_http_timeout: DEFAULT_HTTP_CLIENT_TIMEOUT,
             relay: Default::default(),
             metrics: Default::default(),
+            tls: Default::default(),
+            access_policy: Default::default(),
+            mirroring: Default::default(),
+            episode_budgets: Default::default(),
+            webhooks: Default::default(),
+            policy: Default::default(),
         }
     }
 }
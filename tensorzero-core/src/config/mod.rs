@@ -33,6 +33,7 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 use unwritten::UnwrittenConfig;
 use url::Url;
 
+use crate::config::data_residency::DataResidencyPolicy;
 use crate::config::gateway::{GatewayConfig, UninitializedGatewayConfig};
 use crate::config::path::{ResolvedTomlPathData, ResolvedTomlPathDirectory};
 use crate::config::snapshot::ConfigSnapshot;
@@ -59,15 +60,20 @@ use crate::variant::best_of_n_sampling::UninitializedBestOfNSamplingConfig;
 use crate::variant::chain_of_thought::UninitializedChainOfThoughtConfig;
 use crate::variant::chat_completion::UninitializedChatCompletionConfig;
 use crate::variant::dicl::UninitializedDiclConfig;
+use crate::variant::fallback_chain::UninitializedFallbackChainConfig;
 use crate::variant::mixture_of_n::UninitializedMixtureOfNConfig;
 use crate::variant::{Variant, VariantConfig, VariantInfo};
 use std::error::Error as StdError;
 
 pub mod built_in;
+pub mod data_residency;
 pub mod gateway;
 pub mod path;
+pub mod policy;
 pub mod provider_types;
 pub mod rate_limiting;
+pub mod secret_manager;
+pub mod secrets_scan;
 pub mod snapshot;
 mod span_map;
 pub mod stored;
@@ -109,8 +115,10 @@ pub struct Config {
     pub models: Arc<ModelTable>, // model name => model config
     pub embedding_models: Arc<EmbeddingModelTable>, // embedding model name => embedding model config
     pub functions: HashMap<String, Arc<FunctionConfig>>, // function name => function config
+    pub function_aliases: HashMap<String, FunctionAliasConfig>, // alias name => alias config
     pub metrics: HashMap<String, MetricConfig>,     // metric name => metric config
-    pub tools: HashMap<String, Arc<StaticToolConfig>>, // tool name => tool config
+    pub composite_objectives: HashMap<String, CompositeObjectiveConfig>, // objective name => composite objective config
+    pub tools: HashMap<String, Arc<StaticToolConfig>>,                   // tool name => tool config
     pub evaluations: HashMap<String, Arc<EvaluationConfig>>, // evaluation name => evaluation config
     pub templates: Arc<TemplateConfig<'static>>,
     pub object_store_info: Option<ObjectStoreInfo>,
@@ -187,6 +195,21 @@ impl TimeoutsConfig {
     }
 }
 
+/// Configures hedged (racing) requests for a model, to reduce tail latency.
+///
+/// If the primary provider (the first entry in `routing`) hasn't produced a response within
+/// `delay_ms`, a second request is fired at the next provider in `routing`, and whichever
+/// responds first is used; the other request is cancelled on a best-effort basis.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct HedgeConfig {
+    /// How long to wait for the primary provider before also firing the request at the
+    /// secondary provider.
+    pub delay_ms: u64,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct TemplateFilesystemAccess {
@@ -492,11 +515,274 @@ pub struct MetricConfig {
     pub r#type: MetricConfigType,
     pub optimize: MetricConfigOptimize,
     pub level: MetricConfigLevel,
+    /// The preferred way to aggregate this metric's feedback values across a group of
+    /// inferences or episodes (e.g. when computing variant performance). Defaults to `mean`.
+    #[serde(default)]
+    pub aggregation: MetricConfigAggregation,
+    /// Valid value bounds for this metric, enforced when feedback is written. Only meaningful
+    /// for `Float` metrics; `Boolean` metrics with bounds configured fail validation.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<MetricConfigBounds>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
+impl MetricConfig {
+    /// Validates that this metric's own configuration is internally consistent (e.g. bounds
+    /// are ordered and only present on metric types that support them). Called once per
+    /// metric during `Config::validate`.
+    pub fn validate(&self, metric_name: &str) -> Result<(), Error> {
+        let Some(bounds) = &self.bounds else {
+            return Ok(());
+        };
+        if self.r#type != MetricConfigType::Float {
+            return Err(ErrorDetails::Config {
+                message: format!(
+                    "Metric `{metric_name}` has `bounds` configured, but bounds are only supported for `float` metrics"
+                ),
+            }
+            .into());
+        }
+        if let (Some(min), Some(max)) = (bounds.min, bounds.max)
+            && min > max
+        {
+            return Err(ErrorDetails::Config {
+                message: format!(
+                    "Metric `{metric_name}` has `bounds.min` ({min}) greater than `bounds.max` ({max})"
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Valid value bounds for a `Float` metric, enforced when feedback is written for that
+/// metric. Either bound may be omitted to leave that side unconstrained.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct MetricConfigBounds {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+/// The preferred way to aggregate a metric's feedback values across a group of inferences or
+/// episodes (e.g. when computing variant performance for evaluations or bandit exploration).
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum MetricConfigAggregation {
+    /// Arithmetic mean. The default, and the only sensible aggregation for `Boolean`
+    /// metrics (where it's equivalent to a success rate).
+    #[default]
+    Mean,
+    /// Sum of values. Useful for `Float` metrics that represent counts or totals (e.g. cost,
+    /// tokens) rather than per-inference scores.
+    Sum,
+    /// Fraction of feedback values that are truthy/non-zero. Equivalent to `mean` for
+    /// `Boolean` metrics, but distinguishes intent for `Float` metrics thresholded at zero.
+    Rate,
+}
+
+impl MetricConfigAggregation {
+    /// Returns the ClickHouse expression that aggregates `column` according to this
+    /// aggregation preference.
+    pub fn to_clickhouse_agg_expr(&self, column: &str) -> String {
+        match self {
+            MetricConfigAggregation::Mean => format!("avg({column})"),
+            MetricConfigAggregation::Sum => format!("sum({column})"),
+            MetricConfigAggregation::Rate => format!("(countIf({column} != 0) / count())"),
+        }
+    }
+}
+
+/// A named, config-defined combination of metrics used to rank or score variants for a single
+/// purpose (e.g. bandit exploration, top-k selection, regression gates) without re-encoding the
+/// weighting logic in each consumer. Consumers look this up by name in
+/// `Config::composite_objectives` and call [`CompositeObjectiveConfig::score`] with the relevant
+/// metrics' aggregated values.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct CompositeObjectiveConfig {
+    /// The metrics that make up this objective, keyed by metric name, each contributing a
+    /// weighted, optionally normalized term to the combined score.
+    pub metrics: HashMap<String, CompositeObjectiveMetricConfig>,
+}
+
+impl CompositeObjectiveConfig {
+    /// Validates that this objective is internally consistent and only references metrics that
+    /// exist. Called once per composite objective during `Config::validate`.
+    pub fn validate(
+        &self,
+        objective_name: &str,
+        metrics: &HashMap<String, MetricConfig>,
+    ) -> Result<(), Error> {
+        if self.metrics.is_empty() {
+            return Err(ErrorDetails::Config {
+                message: format!(
+                    "Composite objective `{objective_name}` must reference at least one metric"
+                ),
+            }
+            .into());
+        }
+        for (metric_name, term) in &self.metrics {
+            let metric = metrics.get(metric_name).ok_or_else(|| {
+                Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Composite objective `{objective_name}` references unknown metric `{metric_name}`"
+                    ),
+                })
+            })?;
+            if term.weight == 0.0 || !term.weight.is_finite() {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Composite objective `{objective_name}` has a non-finite or zero weight for metric `{metric_name}`"
+                    ),
+                }
+                .into());
+            }
+            if term.normalization == CompositeObjectiveNormalization::MinMax {
+                let has_full_bounds = metric
+                    .bounds
+                    .as_ref()
+                    .is_some_and(|bounds| bounds.min.is_some() && bounds.max.is_some());
+                if !has_full_bounds {
+                    return Err(ErrorDetails::Config {
+                        message: format!(
+                            "Composite objective `{objective_name}` uses `min_max` normalization for metric `{metric_name}`, which requires both `bounds.min` and `bounds.max` to be configured on that metric"
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Combines already-aggregated per-metric values (e.g. from
+    /// [`MetricConfigAggregation::to_clickhouse_agg_expr`]) into a single scalar score, applying
+    /// each metric's configured normalization, sign (via `optimize`), and weight. Higher is
+    /// always better in the returned score, regardless of each underlying metric's own
+    /// `optimize` direction.
+    ///
+    /// Returns an error if `metric_values` is missing a value for a metric this objective
+    /// references, or if a `min_max`-normalized metric has no `bounds` configured (should have
+    /// already been rejected by `validate`).
+    pub fn score(
+        &self,
+        metric_values: &HashMap<String, f64>,
+        metrics: &HashMap<String, MetricConfig>,
+    ) -> Result<f64, Error> {
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+        for (metric_name, term) in &self.metrics {
+            let metric = metrics.get(metric_name).ok_or_else(|| {
+                Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Composite objective references unknown metric `{metric_name}`"
+                    ),
+                })
+            })?;
+            let value = *metric_values.get(metric_name).ok_or_else(|| {
+                Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Missing value for metric `{metric_name}` while scoring a composite objective"
+                    ),
+                })
+            })?;
+            let normalized = match term.normalization {
+                CompositeObjectiveNormalization::None => value,
+                CompositeObjectiveNormalization::MinMax => {
+                    let bounds = metric.bounds.as_ref().ok_or_else(|| {
+                        Error::new(ErrorDetails::Config {
+                            message: format!(
+                                "Metric `{metric_name}` has no `bounds` configured for `min_max` normalization"
+                            ),
+                        })
+                    })?;
+                    match (bounds.min, bounds.max) {
+                        (Some(min), Some(max)) if max > min => {
+                            ((value - min) / (max - min)).clamp(0.0, 1.0)
+                        }
+                        _ => value,
+                    }
+                }
+            };
+            let signed = match metric.optimize {
+                MetricConfigOptimize::Max => normalized,
+                MetricConfigOptimize::Min => -normalized,
+            };
+            weighted_sum += signed * term.weight;
+            weight_total += term.weight.abs();
+        }
+        if weight_total == 0.0 {
+            return Err(ErrorDetails::Config {
+                message: "Composite objective has zero total weight".to_string(),
+            }
+            .into());
+        }
+        Ok(weighted_sum / weight_total)
+    }
+}
+
+/// A single metric's contribution to a `CompositeObjectiveConfig`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct CompositeObjectiveMetricConfig {
+    /// This metric's relative contribution to the combined score. Weights don't need to sum to
+    /// 1; they're normalized against the total weight when the score is computed.
+    pub weight: f64,
+    /// How to normalize this metric's raw value before applying its weight and sign.
+    #[serde(default)]
+    pub normalization: CompositeObjectiveNormalization,
+}
+
+/// How to normalize a metric's raw value before it's combined into a composite objective.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum CompositeObjectiveNormalization {
+    /// Use the metric's raw value unchanged.
+    #[default]
+    None,
+    /// Rescale the metric's configured `bounds` (`min`..=`max`) to `[0, 1]` before applying its
+    /// weight. Requires the metric to have both `bounds.min` and `bounds.max` configured.
+    MinMax,
+}
+
+/// Transparently routes calls to a deprecated function name to its
+/// replacement, so that a function can be renamed without breaking existing
+/// callers. Once `sunset_date` has passed, calls to the alias are rejected
+/// instead of routed, so the alias can eventually be removed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct FunctionAliasConfig {
+    /// The name of the function that calls to this alias should be routed to.
+    pub target: String,
+    /// After this date, calls to the alias fail instead of being routed.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
@@ -615,6 +901,12 @@ impl MetricConfigLevel {
 pub struct ConfigFileGlob {
     pub glob: String,
     pub paths: Vec<PathBuf>,
+    /// Paths matched by an optional overlay glob (e.g. `tensorzero.prod.toml`), set via
+    /// `with_overlay`. The overlay is merged on top of `paths` with "last value wins"
+    /// semantics for scalars, unlike the base files, which error on conflicting values -
+    /// this is what lets an overlay override a handful of values (model endpoints,
+    /// credentials, weights) without copy-pasting the rest of the config per environment.
+    pub overlay_paths: Vec<PathBuf>,
     _private: (),
 }
 
@@ -628,10 +920,20 @@ impl ConfigFileGlob {
         Self {
             glob: String::new(),
             paths: vec![],
+            overlay_paths: vec![],
             _private: (),
         }
     }
 
+    /// Adds an environment overlay glob (e.g. `tensorzero.prod.toml`) on top of this base
+    /// config. The overlay is resolved the same way as the base glob, but merged with
+    /// "last value wins" semantics instead of erroring on conflicts.
+    pub fn with_overlay(mut self, overlay_glob: &str) -> Result<Self, Error> {
+        let overlay = Self::new(overlay_glob.to_string())?;
+        self.overlay_paths = overlay.paths;
+        Ok(self)
+    }
+
     pub fn new(glob: String) -> Result<Self, Error> {
         // Build a matcher from the glob pattern
         let matcher = globset::Glob::new(&glob)
@@ -664,6 +966,7 @@ impl ConfigFileGlob {
         Ok(Self {
             glob,
             paths: glob_paths,
+            overlay_paths: vec![],
             _private: (),
         })
     }
@@ -771,6 +1074,12 @@ impl RuntimeOverlay {
             global_outbound_http_timeout,
             relay,
             metrics,
+            tls,
+            access_policy,
+            mirroring,
+            episode_budgets,
+            webhooks,
+            policy,
         } = &config.gateway;
 
         Self {
@@ -794,6 +1103,12 @@ impl RuntimeOverlay {
                 ),
                 relay: relay.as_ref().map(|relay| relay.original_config.clone()),
                 metrics: metrics.clone(),
+                tls: tls.clone(),
+                access_policy: access_policy.clone(),
+                mirroring: mirroring.clone(),
+                episode_budgets: episode_budgets.clone(),
+                webhooks: webhooks.clone(),
+                policy: policy.clone(),
             },
             postgres: config.postgres.clone(),
             rate_limiting: UninitializedRateLimitingConfig::from(&config.rate_limiting),
@@ -811,6 +1126,7 @@ struct ProcessedConfigInput {
     models: HashMap<Arc<str>, UninitializedModelConfig>,
     embedding_models: HashMap<Arc<str>, UninitializedEmbeddingModelConfig>,
     metrics: HashMap<String, MetricConfig>,
+    composite_objectives: HashMap<String, CompositeObjectiveConfig>,
     evaluations: HashMap<String, UninitializedEvaluationConfig>,
     provider_types: ProviderTypesConfig,
     optimizers: HashMap<String, UninitializedOptimizerInfo>,
@@ -820,6 +1136,7 @@ struct ProcessedConfigInput {
     snapshot: ConfigSnapshot,
     /// All functions (user-defined + built-in), loaded and ready to use
     functions: HashMap<String, Arc<FunctionConfig>>,
+    function_aliases: HashMap<String, FunctionAliasConfig>,
     gateway_config: GatewayConfig,
     object_store_info: Option<ObjectStoreInfo>,
 }
@@ -865,7 +1182,9 @@ async fn process_config_input(
                 models,
                 embedding_models,
                 functions,
+                function_aliases,
                 metrics,
+                composite_objectives,
                 tools,
                 evaluations,
                 provider_types,
@@ -909,6 +1228,7 @@ async fn process_config_input(
                 models,
                 embedding_models,
                 metrics,
+                composite_objectives,
                 evaluations,
                 provider_types,
                 optimizers,
@@ -916,6 +1236,7 @@ async fn process_config_input(
                 rate_limiting,
                 snapshot,
                 functions: all_functions,
+                function_aliases,
                 gateway_config,
                 object_store_info,
             })
@@ -943,7 +1264,9 @@ async fn process_config_input(
                 models,
                 embedding_models,
                 functions,
+                function_aliases,
                 metrics,
+                composite_objectives,
                 tools,
                 evaluations,
                 provider_types,
@@ -961,7 +1284,9 @@ async fn process_config_input(
                 models: models.clone(),
                 embedding_models: embedding_models.clone(),
                 functions: functions.clone(),
+                function_aliases: function_aliases.clone(),
                 metrics: metrics.clone(),
+                composite_objectives: composite_objectives.clone(),
                 tools: tools.clone(),
                 evaluations: evaluations.clone(),
                 provider_types: provider_types.clone(),
@@ -996,6 +1321,7 @@ async fn process_config_input(
                 models,
                 embedding_models,
                 metrics,
+                composite_objectives,
                 evaluations,
                 provider_types,
                 optimizers,
@@ -1004,6 +1330,7 @@ async fn process_config_input(
                 // unused
                 snapshot,
                 functions: all_functions,
+                function_aliases,
                 gateway_config,
                 object_store_info: overlay_object_store_info,
             })
@@ -1178,6 +1505,7 @@ impl Config {
             models,
             embedding_models,
             metrics,
+            composite_objectives,
             evaluations: uninitialized_evaluations,
             provider_types,
             optimizers: uninitialized_optimizers,
@@ -1185,6 +1513,7 @@ impl Config {
             rate_limiting,
             snapshot,
             functions,
+            function_aliases,
             gateway_config,
             object_store_info,
         } = process_config_input(input, &mut templates).await?;
@@ -1255,7 +1584,9 @@ impl Config {
             models: Arc::new(models),
             embedding_models: Arc::new(embedding_models),
             functions,
+            function_aliases,
             metrics,
+            composite_objectives,
             tools,
             evaluations: HashMap::new(),
             templates: Arc::new(templates),
@@ -1379,8 +1710,30 @@ impl Config {
                 .await?;
         }
 
+        // Validate each function alias: it must not collide with a real function name,
+        // and must point at a function that actually exists.
+        for (alias_name, alias) in &self.function_aliases {
+            if self.functions.contains_key(alias_name) {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Function alias `{alias_name}` has the same name as an existing function"
+                    ),
+                }
+                .into());
+            }
+            if !self.functions.contains_key(&alias.target) {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Function alias `{alias_name}` targets unknown function `{}`",
+                        alias.target
+                    ),
+                }
+                .into());
+            }
+        }
+
         // Ensure that no metrics are named "comment" or "demonstration"
-        for metric_name in self.metrics.keys() {
+        for (metric_name, metric) in &self.metrics {
             if metric_name == "comment" || metric_name == "demonstration" {
                 return Err(ErrorDetails::Config {
                     message: format!("Metric name '{metric_name}' is reserved and cannot be used"),
@@ -1393,6 +1746,12 @@ impl Config {
                 }
                 .into());
             }
+            metric.validate(metric_name)?;
+        }
+
+        // Validate each composite objective, ensuring it only references metrics that exist
+        for (objective_name, objective) in &self.composite_objectives {
+            objective.validate(objective_name, &self.metrics)?;
         }
 
         // Validate each model
@@ -1434,9 +1793,63 @@ impl Config {
         &'a self,
         function_name: &str,
     ) -> Result<Cow<'a, Arc<FunctionConfig>>, Error> {
+        if let Some(alias) = self.function_aliases.get(function_name) {
+            if let Some(sunset_date) = alias.sunset_date
+                && chrono::Utc::now() >= sunset_date
+            {
+                return Err(ErrorDetails::FunctionAliasSunset {
+                    alias: function_name.to_string(),
+                    target: alias.target.clone(),
+                    sunset_date,
+                }
+                .into());
+            }
+            tracing::warn!(
+                "Function `{function_name}` is a deprecated alias for `{}`. Please update callers to use the new name.",
+                alias.target
+            );
+            metrics::counter!(
+                "tensorzero_function_alias_deprecated_total",
+                "alias" => function_name.to_string(),
+                "target" => alias.target.clone()
+            )
+            .increment(1);
+            return get_function(&self.functions, &alias.target);
+        }
         get_function(&self.functions, function_name)
     }
 
+    /// Get all functions whose name falls under a hierarchical namespace pattern.
+    ///
+    /// Function names may use `/` as a namespace separator (e.g. `billing/classify_ticket`),
+    /// purely by convention - the config loader has no special handling for the character.
+    /// This lookup lets callers operate on a whole namespace at once instead of listing
+    /// functions one at a time. `pattern` is interpreted as:
+    /// - `"billing/*"`: every function whose name starts with `billing/`
+    /// - `"billing/classify_ticket"`: exactly that function, if it exists
+    ///
+    /// Note: this only supports read-side selection (e.g. "which functions are in this
+    /// namespace?" for listing or filtering). Applying a config change (like a parameter
+    /// preset) across a namespace isn't supported here, since config in this codebase is
+    /// immutable per snapshot - `write_config` replaces the whole snapshot rather than
+    /// patching individual functions, so a namespace-wide write would need a config-diffing
+    /// mechanism that doesn't exist yet.
+    pub fn get_functions_in_namespace(&self, pattern: &str) -> Vec<(&str, &Arc<FunctionConfig>)> {
+        if let Some(namespace_prefix) = pattern.strip_suffix("/*") {
+            let prefix = format!("{namespace_prefix}/");
+            self.functions
+                .iter()
+                .filter(|(name, _)| name.starts_with(&prefix))
+                .map(|(name, function)| (name.as_str(), function))
+                .collect()
+        } else {
+            self.functions
+                .get_key_value(pattern)
+                .map(|(name, function)| vec![(name.as_str(), function)])
+                .unwrap_or_default()
+        }
+    }
+
     /// Get a metric by name, producing an error if it's not found
     pub fn get_metric_or_err<'a>(&'a self, metric_name: &str) -> Result<&'a MetricConfig, Error> {
         self.metrics.get(metric_name).ok_or_else(|| {
@@ -1695,8 +2108,12 @@ pub struct UninitializedConfig {
     #[serde(default)]
     pub functions: HashMap<String, UninitializedFunctionConfig>, // function name => function config
     #[serde(default)]
+    pub function_aliases: HashMap<String, FunctionAliasConfig>, // alias name => alias config
+    #[serde(default)]
     pub metrics: HashMap<String, MetricConfig>, // metric name => metric config
     #[serde(default)]
+    pub composite_objectives: HashMap<String, CompositeObjectiveConfig>, // objective name => composite objective config
+    #[serde(default)]
     pub tools: HashMap<String, UninitializedToolConfig>, // tool name => tool config
     #[serde(default)]
     pub evaluations: HashMap<String, UninitializedEvaluationConfig>, // evaluation name => evaluation
@@ -1732,6 +2149,106 @@ impl UninitializedConfig {
         let table = SpanMap::from_glob(glob, allow_empty_glob)?;
         Ok(UninitializedGlobbedConfig { table })
     }
+
+    /// Detects providers across different `[models.*]` entries that appear to point at the same
+    /// underlying model. Large configs tend to accumulate this kind of drift over time (e.g. a
+    /// model re-added under a new name instead of being reused), which silently splits
+    /// observability and rate limiting across the duplicate names. This is analysis, not a hard
+    /// validation error - a duplicate isn't necessarily a mistake, so it's surfaced through
+    /// `validate_config` rather than failing config load.
+    ///
+    /// Two providers are considered the same underlying model if they share a provider type
+    /// (e.g. `anthropic`) and an identifying field (`model_name`, `model_id`, `deployment_id`,
+    /// `endpoint_name`, or `endpoint_id`, whichever the provider type has). Within a group,
+    /// `conflicting` is set if the providers aren't configured identically - most commonly
+    /// because they point at different credentials.
+    pub fn find_duplicate_models(&self) -> Vec<DuplicateModelFinding> {
+        let mut groups: HashMap<(String, String), Vec<(String, serde_json::Value)>> =
+            HashMap::new();
+
+        for (model_name, model) in &self.models {
+            for (provider_name, provider) in &model.providers {
+                let Ok(provider_json) = serde_json::to_value(&provider.config) else {
+                    continue;
+                };
+                let (Some(provider_type), Some(model_identifier)) = (
+                    provider_json
+                        .get("type")
+                        .and_then(serde_json::Value::as_str)
+                        .map(str::to_string),
+                    provider_model_identifier(&provider_json),
+                ) else {
+                    continue;
+                };
+                groups
+                    .entry((provider_type, model_identifier))
+                    .or_default()
+                    .push((
+                        format!("models.{model_name}.providers.{provider_name}"),
+                        provider_json,
+                    ));
+            }
+        }
+
+        let mut findings: Vec<DuplicateModelFinding> = groups
+            .into_iter()
+            .filter(|(_, entries)| entries.len() > 1)
+            .map(|((provider_type, model_identifier), mut entries)| {
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                let conflicting = entries.windows(2).any(|pair| pair[0].1 != pair[1].1);
+                DuplicateModelFinding {
+                    provider_type,
+                    model_identifier,
+                    locations: entries.into_iter().map(|(location, _)| location).collect(),
+                    conflicting,
+                }
+            })
+            .collect();
+
+        findings.sort_by(|a, b| {
+            (&a.provider_type, &a.model_identifier).cmp(&(&b.provider_type, &b.model_identifier))
+        });
+        findings
+    }
+}
+
+/// Extracts the field that identifies which underlying model a provider config points at, so
+/// [`UninitializedConfig::find_duplicate_models`] can group providers by it. Different provider
+/// types name this field differently, so we try each of the field names used across
+/// `UninitializedProviderConfig`'s variants and take the first that's present.
+fn provider_model_identifier(provider_json: &serde_json::Value) -> Option<String> {
+    const IDENTIFIER_KEYS: &[&str] = &[
+        "model_name",
+        "model_id",
+        "deployment_id",
+        "endpoint_name",
+        "endpoint_id",
+    ];
+    let object = provider_json.as_object()?;
+    IDENTIFIER_KEYS.iter().find_map(|key| {
+        object
+            .get(*key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    })
+}
+
+/// A group of providers, across possibly-different `[models.*]` entries, that appear to point at
+/// the same underlying model. See [`UninitializedConfig::find_duplicate_models`].
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct DuplicateModelFinding {
+    /// The provider type shared by every entry in `locations` (e.g. `anthropic`).
+    pub provider_type: String,
+    /// The identifying field value shared by every entry in `locations` (e.g. the `model_name`).
+    pub model_identifier: String,
+    /// The dotted config path to each provider entry that shares this identity, e.g.
+    /// `models.my-model.providers.my-provider`.
+    pub locations: Vec<String>,
+    /// True if the providers at `locations` aren't all configured identically - most commonly
+    /// because they specify different credentials for what looks like the same model.
+    pub conflicting: bool,
 }
 
 /// Deserialize a TOML table into `UninitializedConfig`
@@ -1793,6 +2310,8 @@ pub struct UninitializedFunctionConfigChat {
     #[serde(default)]
     pub description: Option<String>,
     pub experimentation: Option<UninitializedExperimentationConfig>,
+    #[serde(default)]
+    pub data_residency: Option<DataResidencyPolicy>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1808,6 +2327,8 @@ pub struct UninitializedFunctionConfigJson {
     #[serde(default)]
     pub description: Option<String>,
     pub experimentation: Option<UninitializedExperimentationConfig>,
+    #[serde(default)]
+    pub data_residency: Option<DataResidencyPolicy>,
 }
 
 /// Holds all of the schemas used by a chat completion function.
@@ -2042,6 +2563,7 @@ impl UninitializedFunctionConfig {
                     description: params.description,
                     all_explicit_templates_names: all_template_names,
                     experimentation,
+                    data_residency: params.data_residency,
                 }))
             }
             UninitializedFunctionConfig::Json(mut params) => {
@@ -2135,6 +2657,7 @@ impl UninitializedFunctionConfig {
                     description: params.description,
                     all_explicit_template_names: all_template_names,
                     experimentation,
+                    data_residency: params.data_residency,
                 }))
             }
         }
@@ -2172,6 +2695,21 @@ pub enum UninitializedVariantConfig {
     /// DEPRECATED (#5298 / 2026.2+): Use `chat_completion` with reasoning instead.
     #[serde(rename = "experimental_chain_of_thought")]
     ChainOfThought(UninitializedChainOfThoughtConfig),
+    #[serde(rename = "experimental_fallback_chain")]
+    FallbackChain(UninitializedFallbackChainConfig),
+}
+
+impl UninitializedVariantConfig {
+    pub fn weight(&self) -> Option<f64> {
+        match self {
+            UninitializedVariantConfig::ChatCompletion(params) => params.weight,
+            UninitializedVariantConfig::BestOfNSampling(params) => params.weight,
+            UninitializedVariantConfig::Dicl(params) => params.weight,
+            UninitializedVariantConfig::MixtureOfN(params) => params.weight,
+            UninitializedVariantConfig::ChainOfThought(params) => params.inner.weight,
+            UninitializedVariantConfig::FallbackChain(params) => params.weight,
+        }
+    }
 }
 
 /// Holds extra information used for enriching error messages
@@ -2213,6 +2751,9 @@ impl UninitializedVariantInfo {
                 );
                 VariantConfig::ChainOfThought(params.load(schemas, error_context)?)
             }
+            UninitializedVariantConfig::FallbackChain(params) => {
+                VariantConfig::FallbackChain(params.load(schemas, error_context)?)
+            }
         };
         Ok(VariantInfo {
             inner,
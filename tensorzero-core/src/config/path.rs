@@ -604,6 +604,33 @@ pub(super) fn merge_tomls<'a>(
     Ok(())
 }
 
+/// Merges an environment overlay `Table` on top of a base `Table`, with the overlay winning
+/// on any conflict. Unlike `merge_tomls` (used to combine independent glob files, where a
+/// conflicting scalar almost certainly indicates an accidental duplicate), this is used to
+/// combine a base config with a deliberate environment-specific overlay
+/// (e.g. `tensorzero.prod.toml`), where overriding a handful of values (model endpoints,
+/// credentials, weights) is the whole point.
+///
+/// Tables are merged recursively so an overlay can override a single nested key without
+/// having to repeat its surrounding table. Any other value type (including a table on one
+/// side and a scalar on the other) is resolved by taking the overlay's value outright.
+pub(super) fn merge_toml_overlay(mut base: toml::Table, overlay: toml::Table) -> toml::Table {
+    for (key, overlay_value) in overlay {
+        match (base.remove(&key), overlay_value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                base.insert(
+                    key,
+                    toml::Value::Table(merge_toml_overlay(base_table, overlay_table)),
+                );
+            }
+            (_, overlay_value) => {
+                base.insert(key, overlay_value);
+            }
+        }
+    }
+    base
+}
+
 /// Converts a `toml::DeValue` to a `toml::Value`.
 /// This just removes all of the `Spanned` wrappers, and leaves the value otherwise unchanged.
 pub(super) fn de_value_to_value(value: DeValue<'_>) -> Result<toml::Value, Error> {
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use super::Config;
+use crate::variant::VariantConfig;
+
+/// Organization guardrails evaluated against every config snapshot passed to `write_config`.
+///
+/// This is deliberately a small set of declarative rules rather than a general expression
+/// language - the rules below cover the concrete guardrails organizations actually ask for
+/// (a temperature ceiling, an approved-model allowlist, a variant weight cap), and a new rule
+/// is a new field here rather than a new expression to parse. If a future guardrail doesn't fit
+/// this shape, it's a sign this struct needs another field, not that it needs a general-purpose
+/// evaluator.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ConfigPolicyConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Whether a violation rejects the `write_config` call (`enforce`, the default) or is
+    /// merely reported back to the caller while the snapshot is still persisted (`warn`).
+    #[serde(default)]
+    pub mode: PolicyMode,
+    /// Maximum `temperature` allowed on any variant that sets one.
+    pub max_temperature: Option<f32>,
+    /// If set, every variant's `model` must be one of these model names. Only applies to
+    /// variant types that talk to a single model directly (`chat_completion`, `dicl`);
+    /// `best_of_n_sampling` and `mixture_of_n` compose other variants rather than naming a
+    /// model themselves, so they aren't checked against this rule.
+    pub approved_models: Option<Vec<String>>,
+    /// Maximum weight (0.0-1.0) any variant may have.
+    pub max_variant_weight: Option<f64>,
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum PolicyMode {
+    #[default]
+    Enforce,
+    Warn,
+}
+
+impl ConfigPolicyConfig {
+    /// Evaluates this policy against `config`, returning a human-readable description of each
+    /// violation found. An empty vector means the config satisfies every enabled rule.
+    pub fn evaluate(&self, config: &Config) -> Vec<String> {
+        if !self.enabled {
+            return vec![];
+        }
+
+        let mut violations = Vec::new();
+        for (function_name, function_config) in &config.functions {
+            for (variant_name, variant_info) in function_config.variants() {
+                self.check_variant(
+                    function_name,
+                    variant_name,
+                    &variant_info.inner,
+                    &mut violations,
+                );
+            }
+        }
+        violations
+    }
+
+    fn check_variant(
+        &self,
+        function_name: &str,
+        variant_name: &str,
+        variant: &VariantConfig,
+        violations: &mut Vec<String>,
+    ) {
+        if let Some(max_temperature) = self.max_temperature
+            && let Some(temperature) = variant_temperature(variant)
+            && temperature > max_temperature
+        {
+            violations.push(format!(
+                "`{function_name}.{variant_name}` has temperature {temperature}, which exceeds the organization maximum of {max_temperature}"
+            ));
+        }
+
+        if let Some(approved_models) = &self.approved_models
+            && let Some(model) = variant_model(variant)
+            && !approved_models.iter().any(|approved| approved == model)
+        {
+            violations.push(format!(
+                "`{function_name}.{variant_name}` uses model `{model}`, which is not in the organization's approved model list"
+            ));
+        }
+
+        if let Some(max_variant_weight) = self.max_variant_weight
+            && let Some(weight) = variant.weight()
+            && weight > max_variant_weight
+        {
+            violations.push(format!(
+                "`{function_name}.{variant_name}` has weight {weight}, which exceeds the organization maximum of {max_variant_weight}"
+            ));
+        }
+    }
+}
+
+fn variant_temperature(variant: &VariantConfig) -> Option<f32> {
+    match variant {
+        VariantConfig::ChatCompletion(params) => params.temperature(),
+        VariantConfig::ChainOfThought(params) => params.inner.temperature(),
+        VariantConfig::Dicl(params) => params.temperature(),
+        VariantConfig::BestOfNSampling(_)
+        | VariantConfig::MixtureOfN(_)
+        | VariantConfig::FallbackChain(_) => None,
+    }
+}
+
+fn variant_model(variant: &VariantConfig) -> Option<&str> {
+    match variant {
+        VariantConfig::ChatCompletion(params) => Some(params.model().as_ref()),
+        VariantConfig::ChainOfThought(params) => Some(params.inner.model().as_ref()),
+        VariantConfig::Dicl(params) => Some(params.model().as_ref()),
+        VariantConfig::BestOfNSampling(_)
+        | VariantConfig::MixtureOfN(_)
+        | VariantConfig::FallbackChain(_) => None,
+    }
+}
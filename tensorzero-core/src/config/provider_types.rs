@@ -27,8 +27,12 @@ pub struct ProviderTypesConfig {
     #[serde(default)]
     pub mistral: MistralProviderTypeConfig,
     #[serde(default)]
+    pub ollama: OllamaProviderTypeConfig,
+    #[serde(default)]
     pub openai: OpenAIProviderTypeConfig,
     #[serde(default)]
+    pub openai_compatible: OpenAICompatibleProviderTypeConfig,
+    #[serde(default)]
     pub openrouter: OpenRouterProviderTypeConfig,
     #[serde(default)]
     pub sglang: SGLangProviderTypeConfig,
@@ -352,6 +356,29 @@ impl Default for OpenAIDefaults {
     }
 }
 
+// OpenAI-compatible
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OpenAICompatibleProviderTypeConfig {
+    #[serde(default)]
+    pub defaults: OpenAICompatibleDefaults,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OpenAICompatibleDefaults {
+    pub api_key_location: CredentialLocationWithFallback,
+}
+
+impl Default for OpenAICompatibleDefaults {
+    fn default() -> Self {
+        Self {
+            api_key_location: CredentialLocationWithFallback::Single(CredentialLocation::Env(
+                "OPENAI_COMPATIBLE_API_KEY".to_string(),
+            )),
+        }
+    }
+}
+
 // Openrouter
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -460,6 +487,29 @@ impl Default for TogetherDefaults {
     }
 }
 
+// Ollama
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OllamaProviderTypeConfig {
+    #[serde(default)]
+    pub defaults: OllamaDefaults,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OllamaDefaults {
+    pub api_key_location: CredentialLocationWithFallback,
+}
+
+impl Default for OllamaDefaults {
+    fn default() -> Self {
+        Self {
+            // Ollama servers are typically unauthenticated when run locally, so unlike most
+            // other providers we default to no credential rather than an env var name.
+            api_key_location: CredentialLocationWithFallback::Single(CredentialLocation::None),
+        }
+    }
+}
+
 // vLLM
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
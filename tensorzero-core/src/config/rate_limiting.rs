@@ -3,9 +3,10 @@ use std::sync::Arc;
 use tensorzero_auth::key::PUBLIC_ID_LENGTH;
 
 use crate::rate_limiting::{
-    ApiKeyPublicIdConfigScope, ApiKeyPublicIdValueScope, RateLimit, RateLimitInterval,
-    RateLimitResource, RateLimitingConfigPriority, RateLimitingConfigRule, RateLimitingConfigScope,
-    RateLimitingConfigScopes, TagRateLimitingConfigScope, TagValueScope,
+    ApiKeyPublicIdConfigScope, ApiKeyPublicIdValueScope, ModelNameConfigScope, ModelNameValueScope,
+    RateLimit, RateLimitInterval, RateLimitResource, RateLimitingConfigPriority,
+    RateLimitingConfigRule, RateLimitingConfigScope, RateLimitingConfigScopes,
+    TagRateLimitingConfigScope, TagValueScope,
 };
 
 /*
@@ -185,6 +186,24 @@ impl<'de> Deserialize<'de> for TagValueScope {
     }
 }
 
+impl<'de> Deserialize<'de> for ModelNameValueScope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s == "tensorzero::each" {
+            Ok(ModelNameValueScope::Each)
+        } else if s.starts_with("tensorzero::") {
+            Err(serde::de::Error::custom(
+                r#"Model names in rate limiting scopes besides tensorzero::each may not start with "tensorzero::"."#,
+            ))
+        } else {
+            Ok(ModelNameValueScope::Concrete(s))
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for ApiKeyPublicIdValueScope {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -230,8 +249,13 @@ impl<'de> Deserialize<'de> for RateLimitingConfigScope {
                     .map(RateLimitingConfigScope::ApiKeyPublicId)
                     .map_err(serde::de::Error::custom);
             }
+            if table.contains_key("model_name") {
+                return ModelNameConfigScope::deserialize(value)
+                    .map(RateLimitingConfigScope::ModelName)
+                    .map_err(serde::de::Error::custom);
+            }
             // As we add other variants, we will add impls here
-            // if table.contains_key("model_name") { ... }
+            // if table.contains_key("function_name") { ... }
         }
 
         // If no variant matches, return a clear error
@@ -0,0 +1,130 @@
+//! Scans a config snapshot for embedded credentials before it's persisted.
+//!
+//! Config snapshots are immutable and kept forever, so a secret pasted into a template or a
+//! free-text field (a system prompt, a tag, a description) can't later be redacted the way an
+//! environment variable can be rotated. `CredentialLocation` already keeps real credentials out
+//! of the config proper (providers only ever reference an env var, a file path, or an SDK
+//! default - never a literal key), so this only needs to catch accidental leaks: someone
+//! pasting a live key into a template body or another string field instead of wiring it up
+//! through `CredentialLocation`.
+//!
+//! This is a fixed list of well-known credential formats, not a general-purpose secret
+//! detector - it trades recall for a low false-positive rate, since a false positive here
+//! blocks a legitimate `write_config` call.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::UninitializedConfig;
+
+struct SecretPattern {
+    name: &'static str,
+    regex: Regex,
+}
+
+static SECRET_PATTERNS: LazyLock<Vec<SecretPattern>> = LazyLock::new(|| {
+    vec![
+        SecretPattern {
+            name: "OpenAI API key",
+            regex: Regex::new(r"\bsk-[A-Za-z0-9_-]{20,}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "Anthropic API key",
+            regex: Regex::new(r"\bsk-ant-[A-Za-z0-9_-]{20,}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "AWS access key ID",
+            regex: Regex::new(r"\b(AKIA|ASIA)[A-Z0-9]{16}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "GitHub token",
+            regex: Regex::new(r"\bgh[pousr]_[A-Za-z0-9]{36,}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "Slack token",
+            regex: Regex::new(r"\bxox[baprs]-[A-Za-z0-9-]{10,}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "Google API key",
+            regex: Regex::new(r"\bAIza[A-Za-z0-9_-]{35}\b").unwrap(),
+        },
+        SecretPattern {
+            name: "PEM private key",
+            regex: Regex::new(r"-----BEGIN [A-Z ]*PRIVATE KEY-----").unwrap(),
+        },
+    ]
+});
+
+/// One embedded credential found while scanning a config snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedSecret {
+    /// Where the secret was found (e.g. `extra_templates["system.jinja"]` or a JSON pointer
+    /// into the config).
+    pub location: String,
+    /// The kind of credential the pattern matched (e.g. `"OpenAI API key"`).
+    pub pattern_name: &'static str,
+}
+
+impl std::fmt::Display for DetectedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} found in {}", self.pattern_name, self.location)
+    }
+}
+
+/// Scans `config` and `extra_templates` for embedded credentials, returning one `DetectedSecret`
+/// per match. An empty vector means nothing matched any known credential pattern.
+///
+/// The config is scanned by serializing it to JSON and walking every string value, rather than
+/// checking specific fields, since a secret can end up in any free-text field (a system prompt,
+/// a tag value, a description) - not just the ones we'd think to check.
+pub fn scan_for_secrets(
+    config: &UninitializedConfig,
+    extra_templates: &HashMap<String, String>,
+) -> Vec<DetectedSecret> {
+    let mut found = Vec::new();
+
+    for (template_path, contents) in extra_templates {
+        scan_str(
+            contents,
+            &format!("extra_templates[\"{template_path}\"]"),
+            &mut found,
+        );
+    }
+
+    if let Ok(value) = serde_json::to_value(config) {
+        scan_value(&value, "config", &mut found);
+    }
+
+    found
+}
+
+fn scan_value(value: &Value, path: &str, found: &mut Vec<DetectedSecret>) {
+    match value {
+        Value::String(s) => scan_str(s, path, found),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                scan_value(item, &format!("{path}[{i}]"), found);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                scan_value(item, &format!("{path}.{key}"), found);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+fn scan_str(s: &str, location: &str, found: &mut Vec<DetectedSecret>) {
+    for pattern in SECRET_PATTERNS.iter() {
+        if pattern.regex.is_match(s) {
+            found.push(DetectedSecret {
+                location: location.to_string(),
+                pattern_name: pattern.name,
+            });
+        }
+    }
+}
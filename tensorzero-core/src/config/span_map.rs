@@ -4,7 +4,7 @@ use toml::Table;
 use toml::de::DeTable;
 
 use crate::config::ConfigFileGlob;
-use crate::config::path::{merge_tomls, resolve_toml_relative_paths};
+use crate::config::path::{merge_toml_overlay, merge_tomls, resolve_toml_relative_paths};
 use crate::error::{Error, ErrorDetails};
 
 /// Holds range information for a merged config file.
@@ -60,7 +60,25 @@ impl SpanMap {
     /// All of the `ResolvedTomlPath` entries in the resulting `Table` have been remapped to
     /// take their source toml file into account.
     /// As a result, consumers of the returned `Table` don't need to care about globbing.
+    ///
+    /// If `glob` has an overlay set (via `ConfigFileGlob::with_overlay`), the overlay files
+    /// are merged the same way among themselves, then merged on top of the base table with
+    /// "last value wins" semantics for scalars, unlike the base files (which error on
+    /// conflicting values). This is what lets an environment overlay override a handful of
+    /// values without duplicating the rest of the config.
     pub fn from_glob(glob: &ConfigFileGlob, allow_empty: bool) -> Result<Table, Error> {
+        let base_table = Self::merge_paths(&glob.glob, &glob.paths, allow_empty)?;
+        if glob.overlay_paths.is_empty() {
+            return Ok(base_table);
+        }
+        let overlay_table = Self::merge_paths(&glob.glob, &glob.overlay_paths, false)?;
+        Ok(merge_toml_overlay(base_table, overlay_table))
+    }
+
+    /// Loads and merges the given config file paths (either the base glob's paths, or an
+    /// overlay glob's paths) into a single `Table`, erroring on conflicting scalar values
+    /// between the given paths.
+    fn merge_paths(glob_str: &str, paths: &[PathBuf], allow_empty: bool) -> Result<Table, Error> {
         let mut found_file = false;
         let mut range_to_file = Vec::new();
         let mut previous_range_end: usize = 0;
@@ -68,7 +86,7 @@ impl SpanMap {
         // contents to have the same lifetime. This increases peak memory usage during config loading,
         // but all of these temporary allocations get freed before we construct our final loaded `Config`
         let mut padded_strs = Vec::new();
-        for file in &glob.paths {
+        for file in paths {
             found_file = true;
             let base_path = file
                 .parent()
@@ -111,7 +129,7 @@ impl SpanMap {
         }
         if !found_file && !allow_empty {
             return Err(ErrorDetails::Glob {
-                glob: glob.glob.to_string(),
+                glob: glob_str.to_string(),
                 message: "No config files matched glob".to_string(),
             }
             .into());
@@ -302,4 +320,52 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_from_glob_with_overlay() {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut base_file = NamedTempFile::new().unwrap();
+        write!(
+            base_file,
+            r#"
+            [models.my_model]
+            routing = ["openai"]
+
+            [models.my_model.providers.openai]
+            type = "openai"
+            model_name = "gpt-4"
+
+            [gateway]
+            debug = false
+            "#
+        )
+        .unwrap();
+
+        let mut overlay_file = NamedTempFile::new().unwrap();
+        write!(
+            overlay_file,
+            r#"
+            [gateway]
+            debug = true
+            "#
+        )
+        .unwrap();
+
+        let glob = ConfigFileGlob::new_from_path(base_file.path())
+            .unwrap()
+            .with_overlay(&overlay_file.path().display().to_string())
+            .unwrap();
+
+        let table = SpanMap::from_glob(&glob, false).unwrap();
+
+        // The overlay should override `gateway.debug`...
+        assert_eq!(table["gateway"]["debug"].as_bool(), Some(true));
+        // ...while leaving values it doesn't mention untouched.
+        assert_eq!(
+            table["models"]["my_model"]["providers"]["openai"]["model_name"].as_str(),
+            Some("gpt-4")
+        );
+    }
 }
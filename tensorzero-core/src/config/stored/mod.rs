@@ -15,8 +15,8 @@ use std::sync::Arc;
 use crate::config::gateway::UninitializedGatewayConfig;
 use crate::config::provider_types::ProviderTypesConfig;
 use crate::config::{
-    MetricConfig, PostgresConfig, TimeoutsConfig, UninitializedConfig, UninitializedFunctionConfig,
-    UninitializedToolConfig,
+    CompositeObjectiveConfig, FunctionAliasConfig, MetricConfig, PostgresConfig, TimeoutsConfig,
+    UninitializedConfig, UninitializedFunctionConfig, UninitializedToolConfig,
 };
 use crate::embeddings::{UninitializedEmbeddingModelConfig, UninitializedEmbeddingProviderConfig};
 use crate::evaluations::UninitializedEvaluationConfig;
@@ -163,8 +163,12 @@ pub struct StoredConfig {
     #[serde(default)]
     pub functions: HashMap<String, UninitializedFunctionConfig>,
     #[serde(default)]
+    pub function_aliases: HashMap<String, FunctionAliasConfig>,
+    #[serde(default)]
     pub metrics: HashMap<String, MetricConfig>,
     #[serde(default)]
+    pub composite_objectives: HashMap<String, CompositeObjectiveConfig>,
+    #[serde(default)]
     pub tools: HashMap<String, UninitializedToolConfig>,
     #[serde(default)]
     pub evaluations: HashMap<String, UninitializedEvaluationConfig>,
@@ -188,7 +192,9 @@ impl From<UninitializedConfig> for StoredConfig {
             object_storage,
             models,
             functions,
+            function_aliases,
             metrics,
+            composite_objectives,
             tools,
             evaluations,
             provider_types,
@@ -205,7 +211,9 @@ impl From<UninitializedConfig> for StoredConfig {
             object_storage,
             models,
             functions,
+            function_aliases,
             metrics,
+            composite_objectives,
             tools,
             evaluations,
             provider_types,
@@ -229,7 +237,9 @@ impl From<StoredConfig> for UninitializedConfig {
             object_storage,
             models,
             functions,
+            function_aliases,
             metrics,
+            composite_objectives,
             tools,
             evaluations,
             provider_types,
@@ -244,7 +254,9 @@ impl From<StoredConfig> for UninitializedConfig {
             object_storage,
             models,
             functions,
+            function_aliases,
             metrics,
+            composite_objectives,
             tools,
             evaluations,
             provider_types,
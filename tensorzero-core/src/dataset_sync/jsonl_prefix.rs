@@ -0,0 +1,116 @@
+//! A [`DatasetSyncSource`] that reads datapoints out of `.jsonl` files stored under a prefix in
+//! an object store.
+
+use futures::TryStreamExt;
+use object_store::path::Path;
+
+use crate::config::ObjectStoreInfo;
+use crate::endpoints::datasets::Datapoint;
+use crate::error::{Error, ErrorDetails};
+
+use super::{DatasetSyncSource, SyncRecord};
+
+/// Reads datapoints from every `.jsonl` object under `prefix` in an object store, one datapoint
+/// per line.
+///
+/// A record's `external_id` is its object storage location plus line number (e.g.
+/// `data/2024-01.jsonl#L3`), so appending lines to an existing file, or adding new files under
+/// the prefix, is picked up as new records without disturbing the `external_id`s of lines
+/// already synced.
+pub struct JsonlPrefixSource {
+    object_store: ObjectStoreInfo,
+    prefix: Path,
+    source_name: String,
+}
+
+impl JsonlPrefixSource {
+    pub fn new(
+        object_store: ObjectStoreInfo,
+        prefix: &str,
+        source_name: String,
+    ) -> Result<Self, Error> {
+        let prefix = Path::parse(prefix).map_err(|e| {
+            Error::new(ErrorDetails::Config {
+                message: format!("Invalid object store prefix '{prefix}': {e}"),
+            })
+        })?;
+        Ok(Self {
+            object_store,
+            prefix,
+            source_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetSyncSource for JsonlPrefixSource {
+    fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    async fn fetch_records(&self) -> Result<Vec<SyncRecord>, Error> {
+        let Some(store) = self.object_store.object_store.as_ref() else {
+            return Err(Error::new(ErrorDetails::Config {
+                message: "JsonlPrefixSource requires an object store to be configured".to_string(),
+            }));
+        };
+
+        let locations: Vec<Path> = store
+            .list(Some(&self.prefix))
+            .map_ok(|meta| meta.location)
+            .try_collect()
+            .await
+            .map_err(|e| {
+                Error::new(ErrorDetails::InternalError {
+                    message: format!(
+                        "Failed to list objects under prefix '{}': {e:?}",
+                        self.prefix
+                    ),
+                })
+            })?;
+
+        let mut records = Vec::new();
+        for location in locations {
+            if location.extension() != Some("jsonl") {
+                continue;
+            }
+
+            let result = store.get(&location).await.map_err(|e| {
+                Error::new(ErrorDetails::InternalError {
+                    message: format!("Failed to read object '{location}': {e:?}"),
+                })
+            })?;
+            let bytes = result.bytes().await.map_err(|e| {
+                Error::new(ErrorDetails::InternalError {
+                    message: format!("Failed to read object contents of '{location}': {e:?}"),
+                })
+            })?;
+            let text = String::from_utf8(bytes.to_vec()).map_err(|e| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Object '{location}' is not valid UTF-8: {e}"),
+                })
+            })?;
+
+            for (line_number, line) in text.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let datapoint: Datapoint = serde_json::from_str(line).map_err(|e| {
+                    Error::new(ErrorDetails::InvalidRequest {
+                        message: format!(
+                            "Failed to parse datapoint at '{location}' line {}: {e}",
+                            line_number + 1
+                        ),
+                    })
+                })?;
+                records.push(SyncRecord {
+                    external_id: format!("{location}#L{}", line_number + 1),
+                    datapoint,
+                });
+            }
+        }
+
+        Ok(records)
+    }
+}
@@ -0,0 +1,215 @@
+//! Connectors for incrementally syncing datapoints from external sources into a
+//! TensorZero dataset.
+//!
+//! A sync run fetches every record currently available from a [`DatasetSyncSource`], hashes
+//! each one, and compares that hash against the [`DatapointProvenance::ExternalSync`] tag
+//! recorded on the datapoint the previous sync created for the same `external_id`. Records
+//! whose hash hasn't changed are skipped, so repeated runs against an unchanged source insert
+//! nothing - a sync can safely be triggered on a schedule by an external scheduler (e.g. a
+//! Kubernetes `CronJob` or Airflow) hitting [`crate::endpoints::datasets::v1::sync_dataset`]
+//! without piling up duplicate datapoints. This crate has no internal periodic-job scheduler
+//! to hook a connector into, so periodicity is left to the caller rather than invented here.
+//!
+//! # Supported sources
+//!
+//! - [`jsonl_prefix::JsonlPrefixSource`]: reads every `.jsonl` object under a prefix in an
+//!   S3-compatible or filesystem object store (via [`crate::config::ObjectStoreInfo`], the
+//!   same abstraction already used for resolved-input file storage), treating each line as
+//!   one datapoint.
+//!
+//! Google Sheets and arbitrary customer SQL sources are not implemented here: this crate has
+//! no Google Sheets API client and no generic SQL driver dependency, and either is a larger
+//! undertaking (new external dependency, new credential type, its own error handling) than
+//! fits alongside this connector abstraction. [`DatasetSyncSource`] is the extension point a
+//! future connector would implement.
+
+pub mod jsonl_prefix;
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::db::datasets::DatasetQueries;
+use crate::db::stored_datapoint::StoredDatapoint;
+use crate::endpoints::datasets::v1::types::{
+    CreateChatDatapointRequest, CreateJsonDatapointRequest, JsonDatapointOutputUpdate,
+};
+use crate::endpoints::datasets::{Datapoint, DatapointProvenance, validate_dataset_name};
+use crate::error::{Error, ErrorDetails};
+use crate::http::TensorzeroHttpClient;
+use crate::inference::types::FetchContext;
+
+/// One record fetched from an external source, ready to be diffed against previously-synced
+/// content and (if new or changed) inserted as a datapoint.
+#[derive(Debug, Clone)]
+pub struct SyncRecord {
+    /// A stable identifier for this record within its source (e.g. an object storage key).
+    /// Used to detect whether a later sync is updating an existing record or adding a new one.
+    pub external_id: String,
+    /// The datapoint to insert, parsed from the source.
+    pub datapoint: Datapoint,
+}
+
+impl SyncRecord {
+    /// Content hash used for change detection, computed over the record's serialized
+    /// datapoint contents (not its `external_id`), so a sync is a no-op unless the datapoint's
+    /// actual contents would change.
+    fn content_hash(&self) -> Result<String, Error> {
+        let bytes = serde_json::to_vec(&self.datapoint).map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!("Failed to serialize sync record for hashing: {e}"),
+            })
+        })?;
+        Ok(blake3::hash(&bytes).to_hex().to_string())
+    }
+}
+
+/// A pluggable source of records for [`sync_dataset`] to pull from. Implement this to add a
+/// new external connector.
+#[async_trait]
+pub trait DatasetSyncSource: Send + Sync {
+    /// A short, stable name identifying this source, recorded in the
+    /// [`DatapointProvenance::ExternalSync`] tag on every datapoint it produces.
+    fn source_name(&self) -> &str;
+
+    /// Fetches every record currently available from the source. Sources are expected to be
+    /// small enough to enumerate in full on each sync run; `sync_dataset`'s change detection
+    /// then decides what actually needs inserting.
+    async fn fetch_records(&self) -> Result<Vec<SyncRecord>, Error>;
+}
+
+/// Summary of what a sync run did.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct DatasetSyncReport {
+    /// Records seen for the first time and inserted as new datapoints.
+    pub inserted: u32,
+    /// Records seen before whose content has changed since, and so were re-inserted.
+    pub updated: u32,
+    /// Records seen before whose content is unchanged, and so were skipped.
+    pub unchanged: u32,
+}
+
+/// Syncs `source`'s records into `dataset_name`, skipping any record whose content hash
+/// matches `previously_synced_hashes[external_id]`.
+///
+/// `previously_synced_hashes` is supplied by the caller (see
+/// [`crate::endpoints::datasets::v1::sync_dataset`]) rather than looked up here, since
+/// building it requires querying existing datapoints in the dataset and parsing their
+/// provenance tags - a concern that belongs with the caller's ClickHouse access, not this
+/// pure sync/diff logic.
+pub async fn sync_dataset(
+    config: &Config,
+    http_client: &TensorzeroHttpClient,
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    source: &dyn DatasetSyncSource,
+    previously_synced_hashes: &HashMap<String, String>,
+) -> Result<DatasetSyncReport, Error> {
+    validate_dataset_name(dataset_name)?;
+
+    let records = source.fetch_records().await?;
+    let fetch_context = FetchContext {
+        client: http_client,
+        object_store_info: &config.object_store_info,
+    };
+
+    let mut report = DatasetSyncReport::default();
+    let mut to_insert = Vec::with_capacity(records.len());
+    for record in records {
+        let content_hash = record.content_hash()?;
+        match previously_synced_hashes.get(&record.external_id) {
+            Some(existing_hash) if *existing_hash == content_hash => {
+                report.unchanged += 1;
+                continue;
+            }
+            Some(_) => report.updated += 1,
+            None => report.inserted += 1,
+        }
+
+        let provenance = DatapointProvenance::ExternalSync {
+            source: source.source_name().to_string(),
+            external_id: record.external_id.clone(),
+            content_hash,
+        };
+        let stored = insert_synced_datapoint(
+            config,
+            &fetch_context,
+            dataset_name,
+            record.datapoint,
+            provenance,
+        )
+        .await?;
+        to_insert.push(stored);
+    }
+
+    if !to_insert.is_empty() {
+        clickhouse.insert_datapoints(&to_insert).await?;
+    }
+
+    Ok(report)
+}
+
+async fn insert_synced_datapoint(
+    config: &Config,
+    fetch_context: &FetchContext<'_>,
+    dataset_name: &str,
+    datapoint: Datapoint,
+    provenance: DatapointProvenance,
+) -> Result<StoredDatapoint, Error> {
+    let (provenance_key, provenance_value) = provenance.to_tag().map_err(|e| {
+        Error::new(ErrorDetails::Serialization {
+            message: format!("Failed to serialize datapoint provenance: {e}"),
+        })
+    })?;
+
+    match datapoint {
+        Datapoint::Chat(chat) => {
+            let mut tags = chat.tags.unwrap_or_default();
+            tags.insert(provenance_key, provenance_value);
+            let insert = CreateChatDatapointRequest {
+                function_name: chat.function_name,
+                episode_id: chat.episode_id,
+                input: chat.input,
+                output: chat.output,
+                dynamic_tool_params: chat.tool_params,
+                tags: Some(tags),
+                name: chat.name,
+            }
+            .into_database_insert(config, fetch_context, dataset_name)
+            .await
+            .map_err(|e| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Failed to sync chat datapoint: {e}"),
+                })
+            })?;
+            Ok(StoredDatapoint::Chat(insert))
+        }
+        Datapoint::Json(json) => {
+            let mut tags = json.tags.unwrap_or_default();
+            tags.insert(provenance_key, provenance_value);
+            let insert = CreateJsonDatapointRequest {
+                function_name: json.function_name,
+                episode_id: json.episode_id,
+                input: json.input,
+                output: json
+                    .output
+                    .map(|output| JsonDatapointOutputUpdate { raw: output.raw }),
+                output_schema: Some(json.output_schema),
+                tags: Some(tags),
+                name: json.name,
+            }
+            .into_database_insert(config, fetch_context, dataset_name)
+            .await
+            .map_err(|e| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Failed to sync json datapoint: {e}"),
+                })
+            })?;
+            Ok(StoredDatapoint::Json(insert))
+        }
+    }
+}
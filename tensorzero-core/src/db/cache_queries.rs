@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// Cache hit-rate breakdown for a single model, computed from `ModelInference.cached`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ModelCacheHitRate {
+    pub model_name: String,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_rate: f64,
+}
+
+/// Cache hit-rate breakdown for a single function, computed by joining `ModelInference` against
+/// `InferenceById`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct FunctionCacheHitRate {
+    pub function_name: String,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_rate: f64,
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct CacheStats {
+    pub by_model: Vec<ModelCacheHitRate>,
+    pub by_function: Vec<FunctionCacheHitRate>,
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait CacheQueries {
+    /// Returns the cache hit rate broken down by model and by function, computed from
+    /// `ModelInference.cached` (and, for the per-function breakdown, joined against
+    /// `InferenceById`).
+    async fn get_cache_stats(&self) -> Result<CacheStats, Error>;
+
+    /// Soft-deletes every non-deleted `ModelInferenceCache` row for the given model, so that
+    /// future lookups for that model miss the ClickHouse-backed cache. This does not evict
+    /// matching entries from the hot cache tier (see [`crate::cache::CacheBackend`]) - those
+    /// still expire on their own TTL, so a purge is not immediate for requests served from there.
+    ///
+    /// Returns the number of rows invalidated.
+    async fn invalidate_cache_by_model(&self, model_name: &str) -> Result<u64, Error>;
+}
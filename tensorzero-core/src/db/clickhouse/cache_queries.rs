@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::db::cache_queries::{CacheQueries, CacheStats, FunctionCacheHitRate, ModelCacheHitRate};
+use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::error::{Error, ErrorDetails};
+
+use super::select_queries::parse_json_rows;
+
+#[async_trait]
+impl CacheQueries for ClickHouseConnectionInfo {
+    async fn get_cache_stats(&self) -> Result<CacheStats, Error> {
+        let by_model_query = r"
+            SELECT
+                model_name,
+                toUInt64(countIf(cached)) as hits,
+                toUInt64(countIf(NOT cached)) as misses
+            FROM ModelInference
+            GROUP BY model_name
+            ORDER BY model_name
+            FORMAT JSONEachRow
+        "
+        .to_string();
+
+        let by_function_query = r"
+            SELECT
+                ib.function_name as function_name,
+                toUInt64(countIf(mi.cached)) as hits,
+                toUInt64(countIf(NOT mi.cached)) as misses
+            FROM ModelInference mi
+            JOIN InferenceById ib ON toUInt128(mi.inference_id) = ib.id_uint
+            GROUP BY ib.function_name
+            ORDER BY ib.function_name
+            FORMAT JSONEachRow
+        "
+        .to_string();
+
+        let (by_model_response, by_function_response) = tokio::try_join!(
+            self.run_query_synchronous_no_params(by_model_query),
+            self.run_query_synchronous_no_params(by_function_query),
+        )?;
+
+        #[derive(serde::Deserialize)]
+        struct RawHitRate<K> {
+            #[serde(flatten)]
+            key: K,
+            hits: u64,
+            misses: u64,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ModelKey {
+            model_name: String,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct FunctionKey {
+            function_name: String,
+        }
+
+        let by_model = parse_json_rows::<RawHitRate<ModelKey>>(&by_model_response.response)?
+            .into_iter()
+            .map(|row| ModelCacheHitRate {
+                model_name: row.key.model_name,
+                hits: row.hits,
+                misses: row.misses,
+                hit_rate: hit_rate(row.hits, row.misses),
+            })
+            .collect();
+
+        let by_function =
+            parse_json_rows::<RawHitRate<FunctionKey>>(&by_function_response.response)?
+                .into_iter()
+                .map(|row| FunctionCacheHitRate {
+                    function_name: row.key.function_name,
+                    hits: row.hits,
+                    misses: row.misses,
+                    hit_rate: hit_rate(row.hits, row.misses),
+                })
+                .collect();
+
+        Ok(CacheStats {
+            by_model,
+            by_function,
+        })
+    }
+
+    async fn invalidate_cache_by_model(&self, model_name: &str) -> Result<u64, Error> {
+        if model_name.is_empty() {
+            return Err(Error::new(ErrorDetails::InvalidRequest {
+                message: "model_name must not be empty".to_string(),
+            }));
+        }
+
+        // `ModelInferenceCache` is a `ReplacingMergeTree(timestamp, is_deleted)` table, so we
+        // soft-delete by reinserting every non-deleted row for this model with `is_deleted = true`
+        // and a newer `timestamp`, following the same fetch-then-reinsert pattern used for
+        // datapoint soft deletes (see `db::clickhouse::dataset_queries`).
+        let query = r"
+            INSERT INTO ModelInferenceCache
+                (short_cache_key, long_cache_key, timestamp, output, raw_request, raw_response, is_deleted, model_name)
+            SELECT
+                short_cache_key,
+                long_cache_key,
+                now() as timestamp,
+                output,
+                raw_request,
+                raw_response,
+                true as is_deleted,
+                model_name
+            FROM ModelInferenceCache FINAL
+            WHERE model_name = {model_name:String} AND is_deleted = false
+        "
+        .to_string();
+
+        let mut params = HashMap::new();
+        params.insert("model_name", model_name);
+        let result = self.run_query_synchronous(query, &params).await?;
+        Ok(result.metadata.written_rows)
+    }
+}
+
+fn hit_rate(hits: u64, misses: u64) -> f64 {
+    let total = hits + misses;
+    if total == 0 {
+        0.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
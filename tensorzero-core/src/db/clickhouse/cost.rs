@@ -0,0 +1,233 @@
+//! ClickHouse queries for inference cost.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::ClickHouseConnectionInfo;
+use crate::db::cost::{CostQueries, DailyModelCost};
+use crate::error::{Error, ErrorDetails};
+
+#[async_trait]
+impl CostQueries for ClickHouseConnectionInfo {
+    async fn get_daily_cost_by_model(
+        &self,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<DailyModelCost>, Error> {
+        let query_params: HashMap<&str, &str> =
+            HashMap::from([("start_date", start_date), ("end_date", end_date)]);
+
+        let query = "SELECT
+                 toString(toDate(timestamp)) AS date,
+                 model_name,
+                 sum(cost_usd) AS cost_usd
+             FROM ModelInference
+             WHERE toDate(timestamp) >= {start_date:Date} AND toDate(timestamp) <= {end_date:Date}
+             GROUP BY date, model_name
+             ORDER BY date, model_name
+             FORMAT JSONEachRow"
+            .to_string();
+
+        let response = self.run_query_synchronous(query, &query_params).await?;
+        parse_daily_model_costs(&response.response)
+    }
+
+    async fn get_episode_cost_usd(&self, episode_id: Uuid) -> Result<f64, Error> {
+        let mut query_params_owned = HashMap::new();
+        query_params_owned.insert("episode_id".to_string(), episode_id.to_string());
+
+        let query = "SELECT sum(mi.cost_usd) AS cost_usd
+             FROM InferenceByEpisodeId FINAL AS ie
+             INNER JOIN ModelInference AS mi ON mi.inference_id = uint_to_uuid(ie.id_uint)
+             WHERE ie.episode_id_uint = toUInt128(toUUID({episode_id:String}))
+             FORMAT JSONEachRow"
+            .to_string();
+
+        let query_params: HashMap<&str, &str> = query_params_owned
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let response = self.run_query_synchronous(query, &query_params).await?;
+        parse_cost_usd(&response.response)
+    }
+}
+
+fn parse_daily_model_costs(response: &str) -> Result<Vec<DailyModelCost>, Error> {
+    #[derive(Deserialize)]
+    struct DailyModelCostRow {
+        date: String,
+        model_name: String,
+        cost_usd: Option<f64>,
+    }
+
+    response
+        .trim()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let row: DailyModelCostRow = serde_json::from_str(line).map_err(|e| {
+                Error::new(ErrorDetails::ClickHouseDeserialization {
+                    message: format!("Failed to deserialize daily model cost: {e}"),
+                })
+            })?;
+            Ok(DailyModelCost {
+                date: row.date,
+                model_name: row.model_name,
+                cost_usd: row.cost_usd.unwrap_or(0.0),
+            })
+        })
+        .collect()
+}
+
+fn parse_cost_usd(response: &str) -> Result<f64, Error> {
+    #[derive(Deserialize)]
+    struct CostResult {
+        cost_usd: Option<f64>,
+    }
+
+    let line = response.trim().lines().next().ok_or_else(|| {
+        Error::new(ErrorDetails::ClickHouseDeserialization {
+            message: "No cost result returned from database".to_string(),
+        })
+    })?;
+
+    let result: CostResult = serde_json::from_str(line).map_err(|e| {
+        Error::new(ErrorDetails::ClickHouseDeserialization {
+            message: format!("Failed to deserialize cost: {e}"),
+        })
+    })?;
+
+    Ok(result.cost_usd.unwrap_or(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::db::clickhouse::{
+        ClickHouseResponse, ClickHouseResponseMetadata, clickhouse_client::MockClickHouseClient,
+        query_builder::test_util::assert_query_contains,
+    };
+
+    #[tokio::test]
+    async fn test_get_daily_cost_by_model_query() {
+        let mut mock_clickhouse_client = MockClickHouseClient::new();
+        mock_clickhouse_client
+            .expect_run_query_synchronous()
+            .withf(|query, parameters| {
+                assert_query_contains(
+                    query,
+                    "GROUP BY date, model_name
+             ORDER BY date, model_name",
+                );
+                assert_eq!(parameters.get("start_date"), Some(&"2026-08-01"));
+                assert_eq!(parameters.get("end_date"), Some(&"2026-08-07"));
+                true
+            })
+            .returning(|_, _| {
+                Ok(ClickHouseResponse {
+                    response: concat!(
+                        "{\"date\":\"2026-08-01\",\"model_name\":\"openai::gpt-4o\",\"cost_usd\":1.5}\n",
+                        "{\"date\":\"2026-08-02\",\"model_name\":\"openai::gpt-4o\",\"cost_usd\":2.25}\n",
+                    )
+                    .to_string(),
+                    metadata: ClickHouseResponseMetadata {
+                        read_rows: 2,
+                        written_rows: 0,
+                    },
+                })
+            });
+
+        let conn = ClickHouseConnectionInfo::new_mock(Arc::new(mock_clickhouse_client));
+        let costs = conn
+            .get_daily_cost_by_model("2026-08-01", "2026-08-07")
+            .await
+            .unwrap();
+        assert_eq!(
+            costs,
+            vec![
+                DailyModelCost {
+                    date: "2026-08-01".to_string(),
+                    model_name: "openai::gpt-4o".to_string(),
+                    cost_usd: 1.5,
+                },
+                DailyModelCost {
+                    date: "2026-08-02".to_string(),
+                    model_name: "openai::gpt-4o".to_string(),
+                    cost_usd: 2.25,
+                },
+            ],
+            "Should return one row per (date, model_name) bucket, in the order returned by ClickHouse"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_episode_cost_usd_query() {
+        let episode_id = Uuid::now_v7();
+
+        let mut mock_clickhouse_client = MockClickHouseClient::new();
+        mock_clickhouse_client
+            .expect_run_query_synchronous()
+            .withf(move |query, parameters| {
+                assert_query_contains(
+                    query,
+                    "SELECT sum(mi.cost_usd) AS cost_usd
+                     FROM InferenceByEpisodeId FINAL AS ie
+                     INNER JOIN ModelInference AS mi ON mi.inference_id = uint_to_uuid(ie.id_uint)
+                     WHERE ie.episode_id_uint = toUInt128(toUUID({episode_id:String}))
+                     FORMAT JSONEachRow",
+                );
+                assert_eq!(
+                    parameters.get("episode_id"),
+                    Some(&episode_id.to_string().as_str())
+                );
+                true
+            })
+            .returning(|_, _| {
+                Ok(ClickHouseResponse {
+                    response: r#"{"cost_usd":0.0042}"#.to_string(),
+                    metadata: ClickHouseResponseMetadata {
+                        read_rows: 1,
+                        written_rows: 0,
+                    },
+                })
+            });
+
+        let conn = ClickHouseConnectionInfo::new_mock(Arc::new(mock_clickhouse_client));
+        let cost_usd = conn.get_episode_cost_usd(episode_id).await.unwrap();
+        assert_eq!(
+            cost_usd, 0.0042,
+            "Should return the summed cost from ClickHouse"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_episode_cost_usd_no_rows() {
+        let episode_id = Uuid::now_v7();
+
+        let mut mock_clickhouse_client = MockClickHouseClient::new();
+        mock_clickhouse_client
+            .expect_run_query_synchronous()
+            .returning(|_, _| {
+                Ok(ClickHouseResponse {
+                    response: r#"{"cost_usd":null}"#.to_string(),
+                    metadata: ClickHouseResponseMetadata {
+                        read_rows: 1,
+                        written_rows: 0,
+                    },
+                })
+            });
+
+        let conn = ClickHouseConnectionInfo::new_mock(Arc::new(mock_clickhouse_client));
+        let cost_usd = conn.get_episode_cost_usd(episode_id).await.unwrap();
+        assert_eq!(
+            cost_usd, 0.0,
+            "An episode with no priced model inferences should have zero cost"
+        );
+    }
+}
@@ -13,13 +13,13 @@ use crate::endpoints::datasets::v1::types::{DatapointOrderBy, DatapointOrderByTe
 use crate::endpoints::shared_types::OrderDirection;
 // TODO: move things somewhere sensible
 use crate::db::datasets::{
-    DatasetMetadata, DatasetQueries, GetDatapointParams, GetDatapointsParams,
+    DatapointLineage, DatasetMetadata, DatasetQueries, GetDatapointParams, GetDatapointsParams,
     GetDatasetMetadataParams,
 };
 use crate::db::stored_datapoint::{
     StoredChatInferenceDatapoint, StoredDatapoint, StoredJsonInferenceDatapoint,
 };
-use crate::endpoints::datasets::validate_dataset_name;
+use crate::endpoints::datasets::{DatapointProvenance, validate_dataset_name};
 use crate::error::{Error, ErrorDetails};
 
 #[async_trait]
@@ -635,6 +635,56 @@ impl DatasetQueries for ClickHouseConnectionInfo {
 
         Ok(results)
     }
+
+    async fn get_datapoint_lineage(
+        &self,
+        dataset_name: &str,
+        datapoint_id: Uuid,
+    ) -> Result<DatapointLineage, Error> {
+        let datapoint = self
+            .get_datapoint(&GetDatapointParams {
+                dataset_name: dataset_name.to_string(),
+                datapoint_id,
+                allow_stale: Some(true),
+            })
+            .await?;
+        let provenance = datapoint.provenance();
+
+        let sibling_datapoint_ids = match &provenance {
+            DatapointProvenance::Inference { inference_id } => {
+                let query = r"
+                    SELECT id FROM (
+                        SELECT id FROM ChatInferenceDatapoint FINAL
+                        WHERE source_inference_id = {inference_id: UUID} AND staled_at IS NULL
+                        UNION ALL
+                        SELECT id FROM JsonInferenceDatapoint FINAL
+                        WHERE source_inference_id = {inference_id: UUID} AND staled_at IS NULL
+                    )
+                ";
+                let inference_id_str = inference_id.to_string();
+                let query_params = HashMap::from([("inference_id", inference_id_str.as_str())]);
+                let response = self
+                    .run_query_synchronous(query.to_string(), &query_params)
+                    .await?;
+                response
+                    .response
+                    .lines()
+                    .filter_map(|line| Uuid::parse_str(line.trim()).ok())
+                    .filter(|id| *id != datapoint_id)
+                    .collect()
+            }
+            DatapointProvenance::Manual
+            | DatapointProvenance::Synthetic { .. }
+            | DatapointProvenance::Import { .. }
+            | DatapointProvenance::ExternalSync { .. } => vec![],
+        };
+
+        Ok(DatapointLineage {
+            datapoint_id,
+            provenance,
+            sibling_datapoint_ids,
+        })
+    }
 }
 
 /// Converts a vec of OrderBy terms to the correct ClickHouse ORDER BY clauses.
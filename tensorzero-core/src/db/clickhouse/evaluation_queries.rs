@@ -8,6 +8,8 @@ use serde::Deserialize;
 use super::ClickHouseConnectionInfo;
 use super::escape_string_for_clickhouse_literal;
 use super::select_queries::{parse_count, parse_json_rows};
+use crate::db::evaluation_queries::EvaluationComparisonRow;
+use crate::db::evaluation_queries::EvaluationJudgeSnapshotRow;
 use crate::db::evaluation_queries::EvaluationQueries;
 use crate::db::evaluation_queries::EvaluationResultRow;
 use crate::db::evaluation_queries::EvaluationRunInfoByIdRow;
@@ -63,6 +65,39 @@ impl RawEvaluationStatisticsRow {
     }
 }
 
+/// Raw paired-comparison row from ClickHouse before CI computation.
+#[derive(Debug, Deserialize)]
+struct RawEvaluationComparisonRow {
+    metric_name: String,
+    paired_datapoint_count: u32,
+    mean_diff: f64,
+    stdev_diff: Option<f64>,
+}
+
+impl RawEvaluationComparisonRow {
+    /// Converts the raw row to the final `EvaluationComparisonRow` by computing a Wald confidence
+    /// interval for the mean difference in Rust. Wald (rather than Wilson) is used even for
+    /// boolean metrics, since a paired difference of two Bernoulli values is a value in
+    /// `{-1, 0, 1}`, not itself a Bernoulli variable.
+    fn into_evaluation_comparison_row(self) -> EvaluationComparisonRow {
+        let (ci_lower, ci_upper) = if let Some(stdev_diff) = self.stdev_diff {
+            wald_confint(self.mean_diff, stdev_diff, self.paired_datapoint_count)
+                .map(|(l, u)| (Some(l), Some(u)))
+                .unwrap_or((None, None))
+        } else {
+            (None, None)
+        };
+
+        EvaluationComparisonRow {
+            metric_name: self.metric_name,
+            paired_datapoint_count: self.paired_datapoint_count,
+            mean_diff: self.mean_diff,
+            ci_lower,
+            ci_upper,
+        }
+    }
+}
+
 // Private helper for constructing the subquery for datapoint IDs
 fn get_evaluation_result_datapoint_id_subquery(
     function_name: &str,
@@ -455,6 +490,137 @@ impl EvaluationQueries for ClickHouseConnectionInfo {
             .collect())
     }
 
+    async fn get_evaluation_run_comparison(
+        &self,
+        function_name: &str,
+        metric_names: &[String],
+        run_a: uuid::Uuid,
+        run_b: uuid::Uuid,
+    ) -> Result<Vec<EvaluationComparisonRow>, Error> {
+        let metric_names_str: Vec<String> = metric_names.iter().map(|s| format!("'{s}'")).collect();
+        let metric_names_joined = format!("[{}]", metric_names_str.join(","));
+
+        // Pair up feedback for the same datapoint across the two runs, then aggregate the
+        // per-datapoint differences (run_b minus run_a) in Rust into a Wald confidence interval,
+        // the same way `get_evaluation_statistics` computes CIs for a single run.
+        let sql_query = r"
+            WITH
+            run_a_inferences AS (
+                SELECT DISTINCT inference_id
+                FROM TagInference WHERE key = 'tensorzero::evaluation_run_id'
+                AND function_name = {function_name:String}
+                AND value = {run_a:String}
+            ),
+            run_b_inferences AS (
+                SELECT DISTINCT inference_id
+                FROM TagInference WHERE key = 'tensorzero::evaluation_run_id'
+                AND function_name = {function_name:String}
+                AND value = {run_b:String}
+            ),
+            run_a_datapoints AS (
+                SELECT inference_id, value AS datapoint_id
+                FROM TagInference
+                WHERE key = 'tensorzero::datapoint_id'
+                AND function_name = {function_name:String}
+                AND inference_id IN (SELECT inference_id FROM run_a_inferences)
+            ),
+            run_b_datapoints AS (
+                SELECT inference_id, value AS datapoint_id
+                FROM TagInference
+                WHERE key = 'tensorzero::datapoint_id'
+                AND function_name = {function_name:String}
+                AND inference_id IN (SELECT inference_id FROM run_b_inferences)
+            ),
+            paired_inferences AS (
+                SELECT
+                    a.datapoint_id AS datapoint_id,
+                    a.inference_id AS inference_id_a,
+                    b.inference_id AS inference_id_b
+                FROM run_a_datapoints AS a
+                INNER JOIN run_b_datapoints AS b ON a.datapoint_id = b.datapoint_id
+            ),
+            float_feedback_a AS (
+                SELECT metric_name, argMax(value, timestamp) AS value, target_id
+                FROM FloatMetricFeedback
+                WHERE metric_name IN ({metric_names:Array(String)})
+                AND target_id IN (SELECT inference_id_a FROM paired_inferences)
+                GROUP BY target_id, metric_name
+            ),
+            float_feedback_b AS (
+                SELECT metric_name, argMax(value, timestamp) AS value, target_id
+                FROM FloatMetricFeedback
+                WHERE metric_name IN ({metric_names:Array(String)})
+                AND target_id IN (SELECT inference_id_b FROM paired_inferences)
+                GROUP BY target_id, metric_name
+            ),
+            boolean_feedback_a AS (
+                SELECT metric_name, argMax(value, timestamp) AS value, target_id
+                FROM BooleanMetricFeedback
+                WHERE metric_name IN ({metric_names:Array(String)})
+                AND target_id IN (SELECT inference_id_a FROM paired_inferences)
+                GROUP BY target_id, metric_name
+            ),
+            boolean_feedback_b AS (
+                SELECT metric_name, argMax(value, timestamp) AS value, target_id
+                FROM BooleanMetricFeedback
+                WHERE metric_name IN ({metric_names:Array(String)})
+                AND target_id IN (SELECT inference_id_b FROM paired_inferences)
+                GROUP BY target_id, metric_name
+            ),
+            float_diffs AS (
+                SELECT
+                    fa.metric_name AS metric_name,
+                    toFloat64(fb.value) - toFloat64(fa.value) AS diff
+                FROM paired_inferences p
+                INNER JOIN float_feedback_a fa ON fa.target_id = p.inference_id_a
+                INNER JOIN float_feedback_b fb
+                    ON fb.target_id = p.inference_id_b AND fb.metric_name = fa.metric_name
+            ),
+            boolean_diffs AS (
+                SELECT
+                    ba.metric_name AS metric_name,
+                    toFloat64(bb.value) - toFloat64(ba.value) AS diff
+                FROM paired_inferences p
+                INNER JOIN boolean_feedback_a ba ON ba.target_id = p.inference_id_a
+                INNER JOIN boolean_feedback_b bb
+                    ON bb.target_id = p.inference_id_b AND bb.metric_name = ba.metric_name
+            ),
+            all_diffs AS (
+                SELECT * FROM float_diffs
+                UNION ALL
+                SELECT * FROM boolean_diffs
+            )
+            SELECT
+                metric_name,
+                toUInt32(count()) AS paired_datapoint_count,
+                avg(diff) AS mean_diff,
+                stddevSamp(diff) AS stdev_diff
+            FROM all_diffs
+            GROUP BY metric_name
+            ORDER BY metric_name ASC
+            FORMAT JSONEachRow
+            "
+        .to_string();
+
+        let function_name_str = function_name.to_string();
+        let run_a_str = run_a.to_string();
+        let run_b_str = run_b.to_string();
+        let mut params: HashMap<&str, &str> = HashMap::new();
+        params.insert("function_name", function_name_str.as_str());
+        params.insert("metric_names", metric_names_joined.as_str());
+        params.insert("run_a", run_a_str.as_str());
+        params.insert("run_b", run_b_str.as_str());
+
+        let response = self.run_query_synchronous(sql_query, &params).await?;
+        let raw_rows: Vec<RawEvaluationComparisonRow> =
+            parse_json_rows(response.response.as_str())?;
+
+        Ok(raw_rows
+            .into_iter()
+            .map(RawEvaluationComparisonRow::into_evaluation_comparison_row)
+            .collect())
+    }
+
     async fn get_evaluation_results(
         &self,
         function_name: &str,
@@ -522,6 +688,14 @@ impl EvaluationQueries for ClickHouseConnectionInfo {
                 WHERE metric_name IN ({{metric_names:Array(String)}})
                 AND target_id IN (SELECT inference_id FROM all_inference_ids)
                 GROUP BY target_id, metric_name
+            ),
+            judge_inference AS (
+                SELECT id, toString(snapshot_hash) as snapshot_hash
+                FROM JsonInference
+                WHERE id IN (
+                    SELECT toUUIDOrNull(evaluator_inference_id) FROM filtered_feedback
+                    WHERE length(evaluator_inference_id) > 0
+                )
             )
             SELECT
                 filtered_dp.input as input,
@@ -532,6 +706,7 @@ impl EvaluationQueries for ClickHouseConnectionInfo {
                 toUUID(filtered_inference.tags['tensorzero::evaluation_run_id']) as evaluation_run_id,
                 filtered_inference.tags['tensorzero::dataset_name'] as dataset_name,
                 if(length(filtered_feedback.evaluator_inference_id) > 0, filtered_feedback.evaluator_inference_id, null) as evaluator_inference_id,
+                judge_inference.snapshot_hash as evaluator_snapshot_hash,
                 filtered_inference.id as inference_id,
                 filtered_inference.episode_id as episode_id,
                 filtered_feedback.metric_name as metric_name,
@@ -545,6 +720,8 @@ impl EvaluationQueries for ClickHouseConnectionInfo {
                 ON toUUIDOrNull(filtered_inference.tags['tensorzero::datapoint_id']) = filtered_dp.id
             LEFT JOIN filtered_feedback
                 ON filtered_feedback.target_id = filtered_inference.id
+            LEFT JOIN judge_inference
+                ON judge_inference.id = toUUIDOrNull(filtered_feedback.evaluator_inference_id)
             ORDER BY toUInt128(datapoint_id) DESC, metric_name DESC
             FORMAT JSONEachRow
             "
@@ -569,6 +746,79 @@ impl EvaluationQueries for ClickHouseConnectionInfo {
             .collect()
     }
 
+    async fn get_evaluation_judge_snapshot_hashes(
+        &self,
+        function_name: &str,
+        function_type: FunctionConfigType,
+        evaluation_run_ids: &[uuid::Uuid],
+        metric_names: &[String],
+    ) -> Result<Vec<EvaluationJudgeSnapshotRow>, Error> {
+        let inference_table_name = function_type.table_name();
+        let (datapoint_id_subquery, params_owned) = get_evaluation_result_datapoint_id_subquery(
+            function_name,
+            evaluation_run_ids,
+            None, // datapoint_id filter
+            /* limit= */ None,
+            /* offset= */ None,
+        );
+
+        let metric_names_str: Vec<String> = metric_names.iter().map(|s| format!("'{s}'")).collect();
+        let metric_names_joined = format!("[{}]", metric_names_str.join(","));
+
+        let sql_query = format!(
+            r"WITH {datapoint_id_subquery},
+            filtered_inference AS (
+                SELECT
+                    id,
+                    tags['tensorzero::evaluation_run_id'] AS evaluation_run_id
+                FROM {inference_table_name}
+                WHERE id IN (SELECT inference_id FROM all_inference_ids)
+                AND function_name = {{function_name:String}}
+            ),
+            filtered_feedback AS (
+                SELECT metric_name,
+                       argMax(tags['tensorzero::evaluator_inference_id'], timestamp) as evaluator_inference_id,
+                       target_id
+                FROM BooleanMetricFeedback
+                WHERE metric_name IN ({{metric_names:Array(String)}})
+                AND target_id IN (SELECT inference_id FROM all_inference_ids)
+                GROUP BY target_id, metric_name
+                UNION ALL
+                SELECT metric_name,
+                       argMax(tags['tensorzero::evaluator_inference_id'], timestamp) as evaluator_inference_id,
+                       target_id
+                FROM FloatMetricFeedback
+                WHERE metric_name IN ({{metric_names:Array(String)}})
+                AND target_id IN (SELECT inference_id FROM all_inference_ids)
+                GROUP BY target_id, metric_name
+            )
+            SELECT
+                filtered_inference.evaluation_run_id as evaluation_run_id,
+                filtered_feedback.metric_name as metric_name,
+                groupUniqArray(toString(judge_inference.snapshot_hash)) as snapshot_hashes
+            FROM filtered_inference
+            INNER JOIN filtered_feedback
+                ON filtered_feedback.target_id = filtered_inference.id
+            INNER JOIN JsonInference AS judge_inference
+                ON judge_inference.id = toUUIDOrNull(filtered_feedback.evaluator_inference_id)
+            WHERE length(filtered_feedback.evaluator_inference_id) > 0
+            GROUP BY filtered_inference.evaluation_run_id, filtered_feedback.metric_name
+            FORMAT JSONEachRow
+            "
+        );
+
+        let function_name_str = function_name.to_string();
+        let mut params: HashMap<&str, &str> = params_owned
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        params.insert("function_name", function_name_str.as_str());
+        params.insert("metric_names", metric_names_joined.as_str());
+
+        let response = self.run_query_synchronous(sql_query, &params).await?;
+        parse_json_rows(response.response.as_str())
+    }
+
     async fn get_inference_evaluation_human_feedback(
         &self,
         metric_name: &str,
@@ -9,10 +9,11 @@ use crate::{
     db::{
         FeedbackQueries, TableBounds, TimeWindow,
         feedback::{
-            BooleanMetricFeedbackInsert, BooleanMetricFeedbackRow, CommentFeedbackInsert,
-            CommentFeedbackRow, CumulativeFeedbackTimeSeriesPoint, DemonstrationFeedbackInsert,
-            DemonstrationFeedbackRow, FeedbackBounds, FeedbackBoundsByType, FeedbackByVariant,
-            FeedbackRow, FloatMetricFeedbackInsert, FloatMetricFeedbackRow,
+            BooleanMetricFeedbackInsert, BooleanMetricFeedbackRow, BucketedFeedbackTimeSeriesPoint,
+            CommentFeedbackInsert, CommentFeedbackRow, CumulativeFeedbackTimeSeriesPoint,
+            DemonstrationFeedbackInsert, DemonstrationFeedbackRow, FeedbackBounds,
+            FeedbackBoundsByType, FeedbackByVariant, FeedbackByVariantAndTag, FeedbackRow,
+            FloatMetricFeedbackInsert, FloatMetricFeedbackRow, GetFeedbackByTagParams,
             GetVariantPerformanceParams, LatestFeedbackRow, MetricType, MetricWithFeedback,
             StaticEvaluationHumanFeedbackInsert, VariantPerformanceRow,
         },
@@ -496,6 +497,14 @@ fn build_variant_performance_query(
 
     let inference_table = params.inference_table_name();
     let metric_table = params.metric_table_name();
+    let value_per_episode_agg = params
+        .metric_config
+        .aggregation
+        .to_clickhouse_agg_expr("value_per_episode");
+    let f_value_agg = params
+        .metric_config
+        .aggregation
+        .to_clickhouse_agg_expr("f.value");
 
     // Build variant filter if specified
     let variant_filter = match params.variant_name {
@@ -535,9 +544,14 @@ fn build_variant_performance_query(
                 '1970-01-01T00:00:00.000Z' AS period_start,
                 variant_name,
                 toUInt32(count()) AS count,
-                avg(value_per_episode) AS avg_metric,
+                {value_per_episode_agg} AS avg_metric,
                 stddevSamp(value_per_episode) AS stdev,
-                1.96 * (stddevSamp(value_per_episode) / sqrt(count())) AS ci_error
+                1.96 * (stddevSamp(value_per_episode) / sqrt(count())) AS ci_error,
+                quantile(0.5)(value_per_episode) AS median,
+                quantile(0.05)(value_per_episode) AS p5,
+                quantile(0.95)(value_per_episode) AS p95,
+                min(value_per_episode) AS min,
+                max(value_per_episode) AS max
             FROM sub
             GROUP BY
                 variant_name
@@ -579,9 +593,14 @@ fn build_variant_performance_query(
                     formatDateTime(period_start, '%Y-%m-%dT%H:%i:%S.000Z') AS period_start,
                     variant_name,
                     toUInt32(count()) AS count,
-                    avg(value_per_episode) AS avg_metric,
+                    {value_per_episode_agg} AS avg_metric,
                     stddevSamp(value_per_episode) AS stdev,
-                    1.96 * (stddevSamp(value_per_episode) / sqrt(count())) AS ci_error
+                    1.96 * (stddevSamp(value_per_episode) / sqrt(count())) AS ci_error,
+                    quantile(0.5)(value_per_episode) AS median,
+                    quantile(0.05)(value_per_episode) AS p5,
+                    quantile(0.95)(value_per_episode) AS p95,
+                    min(value_per_episode) AS min,
+                    max(value_per_episode) AS max
                 FROM sub
                 GROUP BY
                     period_start,
@@ -600,9 +619,14 @@ fn build_variant_performance_query(
                 '1970-01-01T00:00:00.000Z' AS period_start,
                 i.variant_name AS variant_name,
                 toUInt32(count()) AS count,
-                avg(f.value) AS avg_metric,
+                {f_value_agg} AS avg_metric,
                 stddevSamp(f.value) AS stdev,
-                1.96 * (stddevSamp(f.value) / sqrt(count())) AS ci_error
+                1.96 * (stddevSamp(f.value) / sqrt(count())) AS ci_error,
+                quantile(0.5)(f.value) AS median,
+                quantile(0.05)(f.value) AS p5,
+                quantile(0.95)(f.value) AS p95,
+                min(f.value) AS min,
+                max(f.value) AS max
             FROM {inference_table} i
             JOIN (
                 SELECT
@@ -631,9 +655,14 @@ fn build_variant_performance_query(
                     formatDateTime(dateTrunc({{time_window_unit:String}}, i.timestamp), '%Y-%m-%dT%H:%i:%S.000Z') AS period_start,
                     i.variant_name AS variant_name,
                     toUInt32(count()) AS count,
-                    avg(f.value) AS avg_metric,
+                    {f_value_agg} AS avg_metric,
                     stddevSamp(f.value) AS stdev,
-                    1.96 * (stddevSamp(f.value) / sqrt(count())) AS ci_error
+                    1.96 * (stddevSamp(f.value) / sqrt(count())) AS ci_error,
+                    quantile(0.5)(f.value) AS median,
+                    quantile(0.05)(f.value) AS p5,
+                    quantile(0.95)(f.value) AS p95,
+                    min(f.value) AS min,
+                    max(f.value) AS max
                 FROM {inference_table} i
                 JOIN (
                     SELECT
@@ -659,6 +688,115 @@ fn build_variant_performance_query(
     (query, query_params)
 }
 
+fn build_feedback_by_tag_query(
+    params: &GetFeedbackByTagParams<'_>,
+) -> (String, HashMap<String, String>) {
+    let mut query_params = HashMap::new();
+    query_params.insert(
+        "function_name".to_string(),
+        params.function_name.to_string(),
+    );
+    query_params.insert("metric_name".to_string(), params.metric_name.to_string());
+    query_params.insert("tag_key".to_string(), params.tag_key.to_string());
+
+    let inference_table = params.inference_table_name();
+    let metric_table = params.metric_table_name();
+    let value_per_episode_agg = params
+        .metric_config
+        .aggregation
+        .to_clickhouse_agg_expr("value_per_episode");
+    let f_value_agg = params
+        .metric_config
+        .aggregation
+        .to_clickhouse_agg_expr("f.value");
+
+    let variant_filter = match params.variant_name {
+        Some(variant_name) => {
+            query_params.insert("variant_name".to_string(), variant_name.to_string());
+            " AND i.variant_name = {variant_name:String}"
+        }
+        None => "",
+    };
+
+    let query = match params.metric_level() {
+        // Episode-level metric, cumulative only - segment analysis is not broken down over time
+        MetricConfigLevel::Episode => format!(
+            r"
+            WITH sub AS (
+                SELECT
+                    i.variant_name AS variant_name,
+                    i.tags[{{tag_key:String}}] AS tag_value,
+                    i.episode_id AS episode_id,
+                    any(f.value) AS value_per_episode
+                FROM {inference_table} i
+                JOIN (
+                    SELECT
+                        target_id,
+                        value,
+                        ROW_NUMBER() OVER (PARTITION BY target_id ORDER BY timestamp DESC) as rn
+                    FROM {metric_table}
+                    WHERE metric_name = {{metric_name:String}}
+                ) f ON i.episode_id = f.target_id AND f.rn = 1
+                WHERE
+                    i.function_name = {{function_name:String}}
+                    AND i.tags[{{tag_key:String}}] != ''
+                    {variant_filter}
+                GROUP BY
+                    variant_name,
+                    tag_value,
+                    episode_id
+            )
+            SELECT
+                variant_name,
+                tag_value,
+                toUInt32(count()) AS count,
+                {value_per_episode_agg} AS avg_metric,
+                stddevSamp(value_per_episode) AS stdev
+            FROM sub
+            GROUP BY
+                variant_name,
+                tag_value
+            ORDER BY
+                variant_name ASC,
+                tag_value ASC
+            FORMAT JSONEachRow"
+        ),
+
+        // Inference-level metric, cumulative only
+        MetricConfigLevel::Inference => format!(
+            r"
+            SELECT
+                i.variant_name AS variant_name,
+                i.tags[{{tag_key:String}}] AS tag_value,
+                toUInt32(count()) AS count,
+                {f_value_agg} AS avg_metric,
+                stddevSamp(f.value) AS stdev
+            FROM {inference_table} i
+            JOIN (
+                SELECT
+                    target_id,
+                    value,
+                    ROW_NUMBER() OVER (PARTITION BY target_id ORDER BY timestamp DESC) as rn
+                FROM {metric_table}
+                WHERE metric_name = {{metric_name:String}}
+            ) f ON i.id = f.target_id AND f.rn = 1
+            WHERE
+                i.function_name = {{function_name:String}}
+                AND i.tags[{{tag_key:String}}] != ''
+                {variant_filter}
+            GROUP BY
+                variant_name,
+                tag_value
+            ORDER BY
+                variant_name ASC,
+                tag_value ASC
+            FORMAT JSONEachRow"
+        ),
+    };
+
+    (query, query_params)
+}
+
 // Implementation of FeedbackQueries trait
 #[async_trait]
 impl FeedbackQueries for ClickHouseConnectionInfo {
@@ -722,6 +860,112 @@ impl FeedbackQueries for ClickHouseConnectionInfo {
             })
     }
 
+    async fn get_feedback_timeseries(
+        &self,
+        function_name: String,
+        metric_name: String,
+        variant_names: Option<Vec<String>>,
+        time_window: TimeWindow,
+        max_periods: u32,
+    ) -> Result<Vec<BucketedFeedbackTimeSeriesPoint>, Error> {
+        // Convert TimeWindow to ClickHouse INTERVAL syntax and interval functions
+        let (interval_str, interval_function) = match time_window {
+            TimeWindow::Minute => ("INTERVAL 1 MINUTE", "toIntervalMinute"),
+            TimeWindow::Hour => ("INTERVAL 1 HOUR", "toIntervalHour"),
+            TimeWindow::Day => ("INTERVAL 1 DAY", "toIntervalDay"),
+            TimeWindow::Week => ("INTERVAL 1 WEEK", "toIntervalWeek"),
+            TimeWindow::Month => ("INTERVAL 1 MONTH", "toIntervalMonth"),
+            TimeWindow::Cumulative => {
+                return Err(Error::new(ErrorDetails::InvalidRequest {
+                    message: "Cumulative time window is not supported for feedback timeseries"
+                        .to_string(),
+                }));
+            }
+        };
+
+        // If variants are passed, build variant filter.
+        // If None we don't filter at all;
+        // If empty, we'll return an empty vector for consistency
+        let variant_filter = match variant_names {
+            None => String::new(),
+            Some(names) if names.is_empty() => {
+                return Ok(vec![]);
+            }
+            Some(names) => {
+                let escaped_names: Vec<String> = names
+                    .iter()
+                    .map(|name| format!("'{}'", escape_string_for_clickhouse_literal(name)))
+                    .collect();
+                format!(" AND variant_name IN ({})", escaped_names.join(", "))
+            }
+        };
+
+        // Unlike get_cumulative_feedback_timeseries, each bucket's mean/variance/count is
+        // computed only from feedback received during that bucket - no running merge across
+        // buckets - so a single bad bucket shows up as a spike instead of being smoothed away.
+        let query = format!(
+            r"
+            WITH
+                AggregatedFilteredFeedbackByVariantStatistics AS (
+                    SELECT
+                        toStartOfInterval(minute, {interval_str}) + {interval_str} AS period_end,
+                        variant_name,
+                        avgMerge(feedback_mean) AS mean,
+                        varSampStableMerge(feedback_variance) AS variance,
+                        sum(count) AS count
+                    FROM FeedbackByVariantStatistics
+                    WHERE
+                        function_name = {{function_name:String}}
+                        AND metric_name = {{metric_name:String}}
+                        {variant_filter}
+                    GROUP BY
+                        period_end,
+                        variant_name
+                ),
+
+                WindowStart AS (
+                    SELECT
+                        (SELECT max(period_end) FROM AggregatedFilteredFeedbackByVariantStatistics) - {interval_function}({{max_periods:UInt32}}) AS window_start_time
+                )
+
+            SELECT
+                formatDateTime(period_end, '%Y-%m-%dT%H:%i:%SZ') AS period_end,
+                variant_name,
+                mean,
+                variance,
+                count
+            FROM AggregatedFilteredFeedbackByVariantStatistics
+            WHERE period_end >= (SELECT window_start_time FROM WindowStart)
+            ORDER BY
+                period_end ASC,
+                variant_name ASC
+            FORMAT JSONEachRow
+            "
+        );
+
+        let max_periods_str = max_periods.to_string();
+        let params = HashMap::from([
+            ("function_name", function_name.as_str()),
+            ("metric_name", metric_name.as_str()),
+            ("max_periods", max_periods_str.as_str()),
+        ]);
+
+        let response = self.run_query_synchronous(query, &params).await?;
+
+        response
+            .response
+            .trim()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<BucketedFeedbackTimeSeriesPoint>, _>>()
+            .map_err(|e| {
+                Error::new(ErrorDetails::ClickHouseDeserialization {
+                    message: format!("Failed to deserialize BucketedFeedbackTimeSeriesPoint: {e}"),
+                })
+            })
+    }
+
     async fn get_cumulative_feedback_timeseries(
         &self,
         function_name: String,
@@ -1179,6 +1423,35 @@ impl FeedbackQueries for ClickHouseConnectionInfo {
         Ok(result)
     }
 
+    async fn get_feedback_by_variant_by_tag(
+        &self,
+        params: GetFeedbackByTagParams<'_>,
+    ) -> Result<Vec<FeedbackByVariantAndTag>, Error> {
+        let (query, params_owned) = build_feedback_by_tag_query(&params);
+
+        let query_params: HashMap<&str, &str> = params_owned
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let response = self.run_query_synchronous(query, &query_params).await?;
+
+        let result: Vec<FeedbackByVariantAndTag> = response
+            .response
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| {
+                    Error::new(ErrorDetails::ClickHouseDeserialization {
+                        message: format!("Failed to deserialize FeedbackByVariantAndTag: {e}"),
+                    })
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
     // ===== Write methods =====
 
     async fn insert_boolean_feedback(
@@ -2243,6 +2516,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         }
     }
@@ -2252,6 +2527,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Episode,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         }
     }
@@ -2261,6 +2538,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         }
     }
@@ -2569,6 +2848,92 @@ mod tests {
         assert!(result[0].ci_error.is_none());
     }
 
+    // ===== Segment (feedback-by-tag) tests =====
+
+    #[tokio::test]
+    async fn test_get_feedback_by_variant_by_tag_executes() {
+        let mut mock_clickhouse_client = MockClickHouseClient::new();
+
+        mock_clickhouse_client
+            .expect_run_query_synchronous()
+            .withf(|query, params| {
+                assert_query_contains(query, "FROM ChatInference i");
+                assert_query_contains(query, "FROM FloatMetricFeedback");
+                assert_query_contains(query, "i.tags[{tag_key:String}] AS tag_value");
+                assert_eq!(params.get("function_name"), Some(&"test_function"));
+                assert_eq!(params.get("metric_name"), Some(&"accuracy"));
+                assert_eq!(params.get("tag_key"), Some(&"customer_tier"));
+                true
+            })
+            .returning(|_, _| {
+                Ok(ClickHouseResponse {
+                    response: r#"{"variant_name":"variant_a","tag_value":"gold","count":10,"avg_metric":0.85,"stdev":0.05}
+{"variant_name":"variant_a","tag_value":"silver","count":20,"avg_metric":0.60,"stdev":0.10}"#.to_string(),
+                    metadata: ClickHouseResponseMetadata {
+                        read_rows: 2,
+                        written_rows: 0,
+                    },
+                })
+            });
+
+        let conn = ClickHouseConnectionInfo::new_mock(Arc::new(mock_clickhouse_client));
+        let metric_config = make_inference_level_float_metric();
+        let params = GetFeedbackByTagParams {
+            function_name: "test_function",
+            function_type: FunctionConfigType::Chat,
+            metric_name: "accuracy",
+            metric_config: &metric_config,
+            tag_key: "customer_tier",
+            variant_name: None,
+        };
+
+        let result = conn.get_feedback_by_variant_by_tag(params).await.unwrap();
+
+        assert_eq!(
+            result.len(),
+            2,
+            "expected one row per (variant, tag_value) pair"
+        );
+        assert_eq!(result[0].tag_value, "gold");
+        assert!((result[0].avg_metric - 0.85).abs() < 0.001);
+        assert_eq!(result[1].tag_value, "silver");
+        assert!((result[1].avg_metric - 0.60).abs() < 0.001);
+    }
+
+    #[tokio::test]
+    async fn test_get_feedback_by_variant_by_tag_empty_result() {
+        let mut mock_clickhouse_client = MockClickHouseClient::new();
+
+        mock_clickhouse_client
+            .expect_run_query_synchronous()
+            .returning(|_, _| {
+                Ok(ClickHouseResponse {
+                    response: String::new(),
+                    metadata: ClickHouseResponseMetadata {
+                        read_rows: 0,
+                        written_rows: 0,
+                    },
+                })
+            });
+
+        let conn = ClickHouseConnectionInfo::new_mock(Arc::new(mock_clickhouse_client));
+        let metric_config = make_inference_level_float_metric();
+        let params = GetFeedbackByTagParams {
+            function_name: "nonexistent_function",
+            function_type: FunctionConfigType::Chat,
+            metric_name: "accuracy",
+            metric_config: &metric_config,
+            tag_key: "customer_tier",
+            variant_name: None,
+        };
+
+        let result = conn.get_feedback_by_variant_by_tag(params).await.unwrap();
+        assert!(
+            result.is_empty(),
+            "expected no rows when the function has no matching inferences"
+        );
+    }
+
     // ===== Write method tests =====
 
     #[tokio::test]
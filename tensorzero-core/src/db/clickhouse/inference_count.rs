@@ -694,6 +694,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -741,6 +743,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Min,
             level: MetricConfigLevel::Episode,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -823,6 +827,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -865,6 +871,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             optimize: MetricConfigOptimize::Min,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -908,6 +916,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -951,6 +961,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Min,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let params = CountInferencesWithFeedbackParams {
@@ -0,0 +1,58 @@
+use super::check_column_exists;
+use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::db::clickhouse::migration_manager::migration_trait::Migration;
+use crate::error::Error;
+use async_trait::async_trait;
+
+const MIGRATION_ID: &str = "0047";
+
+/// This migration adds a `model_name` column to the `ModelInferenceCache` table, so that
+/// cache entries can be invalidated (and their hit rate reported) per model without having
+/// to re-derive it from the opaque `long_cache_key` hash.
+pub struct Migration0047<'a> {
+    pub clickhouse: &'a ClickHouseConnectionInfo,
+}
+
+#[async_trait]
+impl Migration for Migration0047<'_> {
+    async fn can_apply(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn should_apply(&self) -> Result<bool, Error> {
+        Ok(!check_column_exists(
+            self.clickhouse,
+            "ModelInferenceCache",
+            "model_name",
+            MIGRATION_ID,
+        )
+        .await?)
+    }
+
+    async fn apply(&self, _clean_start: bool) -> Result<(), Error> {
+        let on_cluster_name = self.clickhouse.get_on_cluster_name();
+
+        self.clickhouse
+            .run_query_synchronous_no_params(format!(
+                "ALTER TABLE ModelInferenceCache{on_cluster_name} ADD COLUMN IF NOT EXISTS model_name Nullable(String)"
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    fn rollback_instructions(&self) -> String {
+        let on_cluster_name = self.clickhouse.get_on_cluster_name();
+        format!("ALTER TABLE ModelInferenceCache{on_cluster_name} DROP COLUMN model_name;")
+    }
+
+    async fn has_succeeded(&self) -> Result<bool, Error> {
+        Ok(check_column_exists(
+            self.clickhouse,
+            "ModelInferenceCache",
+            "model_name",
+            MIGRATION_ID,
+        )
+        .await?)
+    }
+}
@@ -0,0 +1,119 @@
+use super::check_column_exists;
+use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::db::clickhouse::migration_manager::migration_trait::Migration;
+use crate::error::Error;
+use async_trait::async_trait;
+
+const MIGRATION_ID: &str = "0048";
+
+/// This migration adds a `tokens_per_second_quantiles` column to `ModelProviderStatistics`,
+/// tracking streaming decode throughput (`output_tokens / (response_time_ms - ttft_ms)`)
+/// alongside the existing `response_time_ms_quantiles`/`ttft_ms_quantiles` columns, so that
+/// variants and providers can be compared on streaming throughput, not just latency.
+///
+/// Rows where `ttft_ms` is `NULL` (non-streaming inferences) or where `response_time_ms` doesn't
+/// exceed `ttft_ms` are excluded, since tokens/second is only meaningful for the decode phase of
+/// a streaming response.
+///
+/// Note: this only affects data ingested by `ModelProviderStatisticsView` after this migration
+/// runs. Unlike migration 0037, this migration does not backfill `tokens_per_second_quantiles`
+/// for pre-existing minutes in `ModelProviderStatistics`, since doing so would require merging
+/// partial aggregate states into existing rows of an `AggregatingMergeTree` table, which isn't
+/// safe to do with an `INSERT` (it would create a second row for each `(model_name,
+/// model_provider_name, minute)` with a real `tokens_per_second_quantiles` state and empty
+/// states for the other columns, silently double-counting `count`/`total_output_tokens` once the
+/// parts are merged). Existing rows keep an empty `tokens_per_second_quantiles` state, which
+/// merges as if no samples were observed.
+pub struct Migration0048<'a> {
+    pub clickhouse: &'a ClickHouseConnectionInfo,
+}
+
+#[async_trait]
+impl Migration for Migration0048<'_> {
+    async fn can_apply(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn should_apply(&self) -> Result<bool, Error> {
+        Ok(!check_column_exists(
+            self.clickhouse,
+            "ModelProviderStatistics",
+            "tokens_per_second_quantiles",
+            MIGRATION_ID,
+        )
+        .await?)
+    }
+
+    async fn apply(&self, _clean_start: bool) -> Result<(), Error> {
+        let on_cluster_name = self.clickhouse.get_on_cluster_name();
+        let qs = super::migration_0037::quantiles_sql_args();
+
+        self.clickhouse
+            .run_query_synchronous_no_params(format!(
+                r"ALTER TABLE ModelProviderStatistics{on_cluster_name} ADD COLUMN IF NOT EXISTS tokens_per_second_quantiles AggregateFunction(quantilesTDigest({qs}), Nullable(Float64))"
+            ))
+            .await?;
+
+        let query = format!(
+            r"
+            ALTER TABLE ModelProviderStatisticsView{on_cluster_name} MODIFY QUERY
+            SELECT
+                model_name,
+                model_provider_name,
+                toStartOfMinute(timestamp) as minute,
+
+                quantilesTDigestState({qs})(response_time_ms) as response_time_ms_quantiles,
+                quantilesTDigestState({qs})(ttft_ms) as ttft_ms_quantiles,
+                quantilesTDigestState({qs})(
+                    if(ttft_ms IS NOT NULL AND response_time_ms > ttft_ms,
+                       output_tokens / ((response_time_ms - ttft_ms) / 1000.0),
+                       NULL)
+                ) as tokens_per_second_quantiles,
+                sumState(input_tokens) as total_input_tokens,
+                sumState(output_tokens) as total_output_tokens,
+                countState() as count
+            FROM ModelInference
+            GROUP BY model_name, model_provider_name, minute
+            "
+        );
+        self.clickhouse
+            .run_query_synchronous_no_params(query)
+            .await?;
+
+        Ok(())
+    }
+
+    fn rollback_instructions(&self) -> String {
+        let on_cluster_name = self.clickhouse.get_on_cluster_name();
+        let qs = super::migration_0037::quantiles_sql_args();
+        format!(
+            r"ALTER TABLE ModelProviderStatisticsView{on_cluster_name} MODIFY QUERY SELECT model_name, model_provider_name, toStartOfMinute(timestamp) as minute, quantilesTDigestState({qs})(response_time_ms) as response_time_ms_quantiles, quantilesTDigestState({qs})(ttft_ms) as ttft_ms_quantiles, sumState(input_tokens) as total_input_tokens, sumState(output_tokens) as total_output_tokens, countState() as count FROM ModelInference GROUP BY model_name, model_provider_name, minute;
+ALTER TABLE ModelProviderStatistics{on_cluster_name} DROP COLUMN tokens_per_second_quantiles;"
+        )
+    }
+
+    async fn has_succeeded(&self) -> Result<bool, Error> {
+        Ok(check_column_exists(
+            self.clickhouse,
+            "ModelProviderStatistics",
+            "tokens_per_second_quantiles",
+            MIGRATION_ID,
+        )
+        .await?)
+    }
+}
+
+/*
+Example query (using the same quantile list):
+SELECT
+    model_name,
+    model_provider_name,
+    minute,
+    quantilesTDigestMerge({qs})(tokens_per_second_quantiles) AS tokens_per_second_quantiles,
+    quantilesTDigestMerge({qs})(response_time_ms_quantiles) AS response_time_quantiles,
+    sumMerge(total_output_tokens) AS total_output_tokens,
+    countMerge(count) AS count
+FROM ModelProviderStatistics
+GROUP BY model_name, model_provider_name, minute
+LIMIT 1;
+*/
@@ -6,7 +6,7 @@ use crate::config::Config;
 use crate::config::MetricConfigLevel;
 use crate::config::snapshot::{ConfigSnapshot, SnapshotHash};
 use crate::db::datasets::{
-    DatasetMetadata, DatasetQueries, GetDatapointParams, GetDatapointsParams,
+    DatapointLineage, DatasetMetadata, DatasetQueries, GetDatapointParams, GetDatapointsParams,
     GetDatasetMetadataParams, MockDatasetQueries,
 };
 use crate::db::inference_count::{
@@ -21,7 +21,9 @@ use crate::db::inferences::{
 };
 use crate::db::model_inferences::{MockModelInferenceQueries, ModelInferenceQueries};
 use crate::db::stored_datapoint::StoredDatapoint;
-use crate::db::{ConfigQueries, MockConfigQueries};
+use crate::db::{
+    ConfigQueries, ConfigSnapshotSummary, ListConfigSnapshotsParams, MockConfigQueries,
+};
 use crate::error::Error;
 use crate::inference::types::StoredModelInference;
 use crate::stored_inference::StoredInferenceDatabase;
@@ -179,6 +181,16 @@ impl DatasetQueries for MockClickHouseConnectionInfo {
             .clone_datapoints(target_dataset_name, source_datapoint_ids)
             .await
     }
+
+    async fn get_datapoint_lineage(
+        &self,
+        dataset_name: &str,
+        datapoint_id: Uuid,
+    ) -> Result<DatapointLineage, Error> {
+        self.dataset_queries
+            .get_datapoint_lineage(dataset_name, datapoint_id)
+            .await
+    }
 }
 
 impl ConfigQueries for MockClickHouseConnectionInfo {
@@ -188,6 +200,23 @@ impl ConfigQueries for MockClickHouseConnectionInfo {
     ) -> Result<ConfigSnapshot, Error> {
         self.config_queries.get_config_snapshot(snapshot_hash).await
     }
+
+    async fn list_config_snapshots(
+        &self,
+        params: ListConfigSnapshotsParams,
+    ) -> Result<Vec<ConfigSnapshotSummary>, Error> {
+        self.config_queries.list_config_snapshots(params).await
+    }
+
+    async fn update_snapshot_tags(
+        &self,
+        snapshot_hash: SnapshotHash,
+        tags: std::collections::HashMap<String, String>,
+    ) -> Result<(), Error> {
+        self.config_queries
+            .update_snapshot_tags(snapshot_hash, tags)
+            .await
+    }
 }
 
 #[async_trait]
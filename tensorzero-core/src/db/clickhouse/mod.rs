@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use secrecy::SecretString;
 use serde::Deserialize;
 use serde::Serialize;
@@ -14,7 +15,11 @@ use crate::config::snapshot::{ConfigSnapshot, SnapshotHash};
 use crate::db::clickhouse::clickhouse_client::ClickHouseClientType;
 use crate::db::clickhouse::clickhouse_client::DisabledClickHouseClient;
 use crate::db::clickhouse::clickhouse_client::ProductionClickHouseClient;
-use crate::db::{ConfigQueries, HealthCheckable};
+use crate::db::clickhouse::select_queries::parse_json_rows;
+use crate::db::{
+    ConfigQueries, ConfigSnapshotSummary, ConfigSnapshotTagFilter, HealthCheckable,
+    ListConfigSnapshotsParams,
+};
 use crate::error::DelayedError;
 use crate::error::{Error, ErrorDetails};
 
@@ -29,7 +34,9 @@ use crate::db::clickhouse::clickhouse_client::FakeClickHouseClient;
 
 mod batch_inference;
 mod batching;
+pub mod cache_queries;
 pub mod clickhouse_client; // Public because tests will use clickhouse_client::FakeClickHouseClient and clickhouse_client::MockClickHouseClient
+pub mod cost;
 pub mod dataset_queries;
 pub mod evaluation_queries;
 pub mod feedback;
@@ -372,6 +379,107 @@ impl ConfigQueries for ClickHouseConnectionInfo {
 
         ConfigSnapshot::from_stored(&row.config, row.extra_templates, row.tags, &snapshot_hash)
     }
+
+    async fn list_config_snapshots(
+        &self,
+        params: ListConfigSnapshotsParams,
+    ) -> Result<Vec<ConfigSnapshotSummary>, Error> {
+        let mut query_params: HashMap<&str, &str> = HashMap::new();
+        let limit_str = params.limit.to_string();
+        let offset_str = params.offset.to_string();
+        query_params.insert("limit", &limit_str);
+        query_params.insert("offset", &offset_str);
+
+        let tag_where_clause = if let Some(tag_filter) = &params.tag_filter {
+            query_params.insert("tag_key", &tag_filter.key);
+            query_params.insert("tag_value", &tag_filter.value);
+            "WHERE tags[{tag_key:String}] = {tag_value:String}"
+        } else {
+            ""
+        };
+
+        let query = format!(
+            "SELECT hash, tags, \
+             formatDateTime(created_at, '%Y-%m-%dT%H:%i:%SZ') AS created_at \
+             FROM ConfigSnapshot FINAL \
+             {tag_where_clause} \
+             ORDER BY created_at DESC \
+             LIMIT {{limit:UInt32}} \
+             OFFSET {{offset:UInt32}} \
+             FORMAT JSONEachRow"
+        );
+
+        let response = self.run_query_synchronous(query, &query_params).await?;
+
+        #[derive(Deserialize)]
+        struct ConfigSnapshotSummaryRow {
+            hash: String,
+            tags: HashMap<String, String>,
+            created_at: DateTime<Utc>,
+        }
+
+        let rows: Vec<ConfigSnapshotSummaryRow> = parse_json_rows(&response.response)?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(ConfigSnapshotSummary {
+                    hash: row.hash.parse().map_err(|_: std::convert::Infallible| {
+                        Error::new(ErrorDetails::ClickHouseDeserialization {
+                            message: format!("Invalid config snapshot hash: {}", row.hash),
+                        })
+                    })?,
+                    tags: row.tags,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn update_snapshot_tags(
+        &self,
+        snapshot_hash: SnapshotHash,
+        tags: HashMap<String, String>,
+    ) -> Result<(), Error> {
+        // Confirm the snapshot exists so callers get a clear `ConfigSnapshotNotFound` instead of
+        // the merge query below silently inserting nothing.
+        self.get_config_snapshot(snapshot_hash.clone()).await?;
+
+        let hash_str = snapshot_hash.to_string();
+        let tags_json = serde_json::to_string(&tags).map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!("Failed to serialize tags: {e}"),
+            })
+        })?;
+
+        let external_data = ExternalDataInfo {
+            external_data_name: "new_tags".to_string(),
+            structure: "tags Map(String, String)".to_string(),
+            format: "JSONEachRow".to_string(),
+            data: format!(r#"{{"tags": {tags_json}}}"#),
+        };
+
+        // Merge `tags` into the existing row's tags (mapUpdate: new keys override existing ones)
+        // and insert a new version of the row, the same way `write_config_snapshot` merges tags
+        // on write. Everything else (config, extra_templates, created_at) is carried over as-is.
+        let query = format!(
+            r"INSERT INTO ConfigSnapshot
+(config, extra_templates, hash, tensorzero_version, tags, created_at, last_used)
+SELECT
+    existing.config,
+    existing.extra_templates,
+    existing.hash,
+    existing.tensorzero_version,
+    mapUpdate(existing.tags, new_tags.tags) as tags,
+    existing.created_at,
+    now64() as last_used
+FROM (SELECT * FROM ConfigSnapshot FINAL WHERE hash = toUInt256('{hash_str}')) AS existing
+CROSS JOIN new_tags"
+        );
+
+        self.run_query_with_external_data(external_data, query)
+            .await?;
+
+        Ok(())
+    }
 }
 
 pub struct GetMaybeReplicatedTableEngineNameArgs<'a> {
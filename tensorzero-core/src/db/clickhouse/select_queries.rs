@@ -126,6 +126,7 @@ impl SelectQueries for ClickHouseConnectionInfo {
                 model_name,
                 quantilesTDigestMerge({qs})(response_time_ms_quantiles) AS response_time_ms_quantiles,
                 quantilesTDigestMerge({qs})(ttft_ms_quantiles) AS ttft_ms_quantiles,
+                quantilesTDigestMerge({qs})(tokens_per_second_quantiles) AS tokens_per_second_quantiles,
                 countMerge(count) as count
             FROM ModelProviderStatistics
             WHERE {time_filter}
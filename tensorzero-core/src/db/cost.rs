@@ -0,0 +1,39 @@
+//! Cost query types and trait definitions.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// Internally computed cost for a single model on a single day, used to reconcile against
+/// provider-reported spend (see [`crate::spend_reconciliation`]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DailyModelCost {
+    /// The UTC calendar day this cost was accrued on, formatted as `YYYY-MM-DD`.
+    pub date: String,
+    pub model_name: String,
+    pub cost_usd: f64,
+}
+
+/// Trait for cost queries
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait CostQueries {
+    /// Sums the cost (in USD) of every model inference made within an episode.
+    /// Model inferences whose provider has no `pricing` configured don't contribute,
+    /// so this can be an undercount if some providers in the episode lack pricing.
+    async fn get_episode_cost_usd(&self, episode_id: Uuid) -> Result<f64, Error>;
+
+    /// Sums the internally computed cost (in USD) of every model inference in
+    /// `[start_date, end_date]` (inclusive, UTC calendar days), bucketed by day and model name.
+    /// Model inferences whose provider has no `pricing` configured don't contribute, so this
+    /// can be an undercount if some providers lack pricing.
+    async fn get_daily_cost_by_model(
+        &self,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<DailyModelCost>, Error>;
+}
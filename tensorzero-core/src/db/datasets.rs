@@ -8,6 +8,7 @@ use mockall::automock;
 use crate::config::{MetricConfigLevel, MetricConfigType};
 use crate::db::clickhouse::query_builder::{DatapointFilter, FloatComparisonOperator};
 use crate::db::stored_datapoint::StoredDatapoint;
+use crate::endpoints::datasets::DatapointProvenance;
 use crate::endpoints::datasets::v1::types::DatapointOrderBy;
 use crate::error::Error;
 
@@ -55,6 +56,18 @@ pub struct DatasetMetadata {
     pub last_updated: String,
 }
 
+/// Provenance information for a single datapoint, along with any sibling
+/// datapoints derived from the same source inference (if any).
+#[derive(Debug, Serialize, PartialEq)]
+pub struct DatapointLineage {
+    pub datapoint_id: Uuid,
+    pub provenance: DatapointProvenance,
+    /// Other datapoints (across all datasets) derived from the same source
+    /// inference as this one, excluding `datapoint_id` itself. Always empty
+    /// unless `provenance` is `DatapointProvenance::Inference`.
+    pub sibling_datapoint_ids: Vec<Uuid>,
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Deserialize)]
 #[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
@@ -163,4 +176,12 @@ pub trait DatasetQueries {
         target_dataset_name: &str,
         source_datapoint_ids: &[Uuid],
     ) -> Result<Vec<Option<Uuid>>, Error>;
+
+    /// Returns provenance information for a datapoint, along with any sibling
+    /// datapoints derived from the same source inference.
+    async fn get_datapoint_lineage(
+        &self,
+        dataset_name: &str,
+        datapoint_id: Uuid,
+    ) -> Result<DatapointLineage, Error>;
 }
@@ -0,0 +1,43 @@
+//! Episode budget usage types and trait definitions.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// Cumulative usage recorded for a single episode, checked against the
+/// limits in `gateway.episode_budgets`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EpisodeBudgetUsage {
+    pub tokens_used: u64,
+    pub cost_used_usd: f64,
+    pub inference_count: u64,
+}
+
+/// Additional usage to record for an episode after an inference completes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EpisodeBudgetUsageDelta {
+    pub tokens: u64,
+    pub cost_usd: f64,
+}
+
+/// Trait for tracking cumulative per-episode usage against configured budgets.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EpisodeBudgetQueries {
+    /// Returns the usage recorded so far for `episode_id`, or the zero value
+    /// if the episode has no recorded usage yet.
+    async fn get_episode_budget_usage(&self, episode_id: Uuid)
+    -> Result<EpisodeBudgetUsage, Error>;
+
+    /// Atomically adds `delta` to the episode's recorded usage and increments
+    /// its inference count by one, creating the row if it doesn't exist yet.
+    async fn record_episode_budget_usage(
+        &self,
+        episode_id: Uuid,
+        delta: EpisodeBudgetUsageDelta,
+    ) -> Result<(), Error>;
+}
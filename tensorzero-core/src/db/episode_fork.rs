@@ -0,0 +1,31 @@
+//! Episode fork lineage types and trait definitions.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// A single parent -> child link created by `fork_episode`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EpisodeFork {
+    pub episode_id: Uuid,
+    pub parent_episode_id: Uuid,
+    /// The last inference in the parent episode that this fork inherits history up to,
+    /// if the caller specified one. `None` means no particular cutoff was requested.
+    pub fork_point_inference_id: Option<Uuid>,
+}
+
+/// Trait for recording and looking up episode fork lineage.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EpisodeForkQueries {
+    /// Records `fork`, linking a newly created episode back to its parent.
+    async fn create_episode_fork(&self, fork: EpisodeFork) -> Result<(), Error>;
+
+    /// Returns the fork lineage for `episode_id`, or `None` if it wasn't created via
+    /// `fork_episode`.
+    async fn get_episode_fork(&self, episode_id: Uuid) -> Result<Option<EpisodeFork>, Error>;
+}
@@ -53,6 +53,37 @@ pub struct EvaluationStatisticsRow {
     pub ci_upper: Option<f64>,
 }
 
+/// Database struct for deserializing, per evaluation run and metric, the distinct set of
+/// LLM-judge config snapshot hashes that produced the feedback for that run.
+///
+/// More than one hash for the same `(evaluation_run_id, metric_name)` means the judge's
+/// prompt/template changed mid-run; a caller comparing `mean_metric` across runs should treat
+/// runs whose hash sets don't overlap as measuring (at least partly) a different judge, not just
+/// a different variant.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EvaluationJudgeSnapshotRow {
+    pub evaluation_run_id: Uuid,
+    pub metric_name: String,
+    pub snapshot_hashes: Vec<String>,
+}
+
+/// Database struct for deserializing paired per-metric comparison statistics between two
+/// evaluation runs from ClickHouse.
+///
+/// Unlike [`EvaluationStatisticsRow`] (which summarizes one run in isolation), this pairs up
+/// feedback for the *same datapoint* across both runs and summarizes the differences, so the
+/// comparison isn't skewed by datapoints only one of the two runs happened to cover.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EvaluationComparisonRow {
+    pub metric_name: String,
+    /// Number of datapoints for which both runs produced feedback for this metric.
+    pub paired_datapoint_count: u32,
+    /// Mean of `run_b`'s value minus `run_a`'s value, across paired datapoints.
+    pub mean_diff: f64,
+    pub ci_lower: Option<f64>,
+    pub ci_upper: Option<f64>,
+}
+
 /// Result of checking for existing human feedback for an inference evaluation.
 /// This is used to determine if a human has already provided feedback for a
 /// (metric_name, datapoint_id, output) combination, allowing the evaluation
@@ -77,6 +108,7 @@ pub(crate) struct RawEvaluationResultRow {
     pub datapoint_id: Uuid,
     pub evaluation_run_id: Uuid,
     pub evaluator_inference_id: Option<Uuid>,
+    pub evaluator_snapshot_hash: Option<String>,
     #[serde(deserialize_with = "deserialize_json_string")]
     pub input: StoredInput,
     pub generated_output: String,
@@ -103,6 +135,11 @@ pub struct ChatEvaluationResultRow {
     /// The evaluator inference ID, if the feedback was generated by an LLM judge
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluator_inference_id: Option<Uuid>,
+    /// The config snapshot hash of the judge's own inference, if the feedback was generated by
+    /// an LLM judge. Lets a longitudinal comparison distinguish a score shift caused by a
+    /// judge prompt/template change from one caused by an actual variant change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluator_snapshot_hash: Option<String>,
     /// The input to the function
     pub input: Input,
     /// The generated output from the model
@@ -144,6 +181,11 @@ pub struct JsonEvaluationResultRow {
     /// The evaluator inference ID, if the feedback was generated by an LLM judge
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluator_inference_id: Option<Uuid>,
+    /// The config snapshot hash of the judge's own inference, if the feedback was generated by
+    /// an LLM judge. Lets a longitudinal comparison distinguish a score shift caused by a
+    /// judge prompt/template change from one caused by an actual variant change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluator_snapshot_hash: Option<String>,
     /// The input to the function
     pub input: Input,
     /// The generated output from the model
@@ -224,6 +266,7 @@ impl RawEvaluationResultRow {
             datapoint_id: self.datapoint_id,
             evaluation_run_id: self.evaluation_run_id,
             evaluator_inference_id: self.evaluator_inference_id,
+            evaluator_snapshot_hash: self.evaluator_snapshot_hash,
             input: self.input.into_input(),
             generated_output,
             reference_output,
@@ -261,6 +304,7 @@ impl RawEvaluationResultRow {
             datapoint_id: self.datapoint_id,
             evaluation_run_id: self.evaluation_run_id,
             evaluator_inference_id: self.evaluator_inference_id,
+            evaluator_snapshot_hash: self.evaluator_snapshot_hash,
             input: self.input.into_input(),
             generated_output,
             reference_output,
@@ -359,6 +403,35 @@ pub trait EvaluationQueries {
         offset: u32,
     ) -> Result<Vec<EvaluationResultRow>, Error>;
 
+    /// Gets paired per-metric comparison statistics between two evaluation runs.
+    ///
+    /// For each metric, pairs up feedback from `run_a` and `run_b` on shared datapoint IDs and
+    /// returns the mean difference (`run_b` minus `run_a`) with a Wald confidence interval, along
+    /// with the number of datapoints both runs covered. A metric with no shared datapoints is
+    /// omitted from the result.
+    async fn get_evaluation_run_comparison(
+        &self,
+        function_name: &str,
+        metric_names: &[String],
+        run_a: Uuid,
+        run_b: Uuid,
+    ) -> Result<Vec<EvaluationComparisonRow>, Error>;
+
+    /// Gets, per evaluation run and metric, the distinct LLM-judge config snapshot hashes that
+    /// produced the metric's feedback.
+    ///
+    /// A metric with more than one hash in a single run means the judge's prompt/template
+    /// changed mid-run. Comparing the hash sets across runs flags apparent score shifts that
+    /// coincide with a judge change rather than a variant change, so longitudinal comparisons
+    /// don't draw a false conclusion from a moved target.
+    async fn get_evaluation_judge_snapshot_hashes(
+        &self,
+        function_name: &str,
+        function_type: FunctionConfigType,
+        evaluation_run_ids: &[Uuid],
+        metric_names: &[String],
+    ) -> Result<Vec<EvaluationJudgeSnapshotRow>, Error>;
+
     /// Gets existing human feedback for a given inference evaluation if it exists.
     ///
     /// This function queries the StaticEvaluationHumanFeedback table to find existing
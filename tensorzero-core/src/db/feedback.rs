@@ -123,6 +123,35 @@ pub trait FeedbackQueries {
         max_periods: u32,
     ) -> Result<Vec<CumulativeFeedbackTimeSeriesPoint>, Error>;
 
+    /// Retrieves a bucketed (non-cumulative) time series of feedback statistics for a given
+    /// metric and function, optionally filtered by variant names. Unlike
+    /// `get_cumulative_feedback_timeseries`, each point covers only the feedback received
+    /// during its own bucket, so a regression in a single bucket shows up as a spike rather
+    /// than being smoothed into a running average - useful for drift detection rather than
+    /// lifetime aggregates like `get_feedback_by_variant`.
+    ///
+    /// Does not include percentiles: the backing `FeedbackByVariantStatistics` materialized
+    /// view only stores mergeable mean/variance aggregate states (`avgState`/
+    /// `varSampStableState`), not quantile sketches, so percentiles cannot be computed from it
+    /// without a schema migration adding quantile aggregate columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `function_name`: The name of the function to query
+    /// - `metric_name`: The name of the metric to query
+    /// - `variant_names`: Optional filter for specific variants. If `None`, all variants are included.
+    ///   If `Some(vec![])`, returns empty results.
+    /// - `time_window`: The bucket granularity (Minute, Hour, Day, Week, or Month)
+    /// - `max_periods`: Maximum number of complete buckets to return
+    async fn get_feedback_timeseries(
+        &self,
+        function_name: String,
+        metric_name: String,
+        variant_names: Option<Vec<String>>,
+        time_window: super::TimeWindow,
+        max_periods: u32,
+    ) -> Result<Vec<BucketedFeedbackTimeSeriesPoint>, Error>;
+
     /// Queries all feedback (boolean metrics, float metrics, comments, demonstrations) for a given target ID
     async fn query_feedback_by_target_id(
         &self,
@@ -165,8 +194,14 @@ pub trait FeedbackQueries {
 
     /// Get variant performance statistics for a given function and metric.
     ///
-    /// Returns performance statistics (average, stdev, count, confidence interval) for each
-    /// variant, optionally grouped by time period.
+    /// Returns performance statistics (average, stdev, count, confidence interval, median,
+    /// p5/p95, min/max) for each variant, optionally grouped by time period. Unlike
+    /// `get_feedback_by_variant`, which aggregates from the `FeedbackByVariantStatistics`
+    /// materialized view and so is limited to whatever aggregate function states that view
+    /// stores (mean/variance - no percentiles), this joins against the per-inference metric
+    /// values directly, so quantiles and min/max are computable here. It also already
+    /// restricts to the latest feedback per inference/episode (via the `rn = 1` join) and
+    /// supports arbitrary time windows, since that's what this query was built for.
     ///
     /// # Parameters
     ///
@@ -180,6 +215,29 @@ pub trait FeedbackQueries {
         params: GetVariantPerformanceParams<'_>,
     ) -> Result<Vec<VariantPerformanceRow>, Error>;
 
+    /// Get cumulative feedback statistics for a given function and metric, broken down by both
+    /// variant and a chosen inference tag (e.g. customer tier, locale, channel), so that a
+    /// segment which is doing worse than the overall population for a variant can be detected
+    /// even when the overall average looks fine.
+    ///
+    /// Joins against the per-inference metric values directly (like `get_variant_performances`),
+    /// grouping by `(variant_name, tags[tag_key])` instead of `(variant_name, time_period)`.
+    /// Only cumulative statistics are supported - segment analysis is meant to answer "is this
+    /// segment currently underserved", not to be broken down over time as well.
+    ///
+    /// # Parameters
+    ///
+    /// - `params`: Parameters specifying the function, metric configuration, tag key, and optional variant filter
+    ///
+    /// # Returns
+    ///
+    /// A vector of `FeedbackByVariantAndTag` containing statistics for each (variant, tag_value) combination.
+    /// Inferences missing the requested tag are excluded.
+    async fn get_feedback_by_variant_by_tag(
+        &self,
+        params: GetFeedbackByTagParams<'_>,
+    ) -> Result<Vec<FeedbackByVariantAndTag>, Error>;
+
     // ===== Write methods =====
 
     /// Insert a boolean metric feedback row
@@ -219,6 +277,23 @@ pub struct FeedbackByVariant {
     pub count: u64,
 }
 
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct BucketedFeedbackTimeSeriesPoint {
+    // The end of the bucket these statistics were computed over
+    pub period_end: DateTime<Utc>,
+    pub variant_name: String,
+    // Mean of feedback values received during this bucket
+    pub mean: f32,
+    // Variance of feedback values received during this bucket
+    // Equal to None for sample size 1 because ClickHouse uses sample variance with (n - 1) in the denominator
+    pub variance: Option<f32>,
+    #[serde(deserialize_with = "deserialize_u64")]
+    // Number of feedback values received during this bucket
+    pub count: u64,
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct InternalCumulativeFeedbackTimeSeriesPoint {
@@ -416,6 +491,70 @@ pub struct VariantPerformanceRow {
     #[cfg_attr(feature = "ts-bindings", ts(optional))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ci_error: Option<f64>,
+    /// Median (50th percentile) metric value
+    pub median: f64,
+    /// 5th percentile metric value
+    pub p5: f64,
+    /// 95th percentile metric value
+    pub p95: f64,
+    /// Minimum metric value
+    pub min: f64,
+    /// Maximum metric value
+    pub max: f64,
+}
+
+/// Parameters for getting per-segment feedback statistics.
+#[derive(Debug)]
+pub struct GetFeedbackByTagParams<'a> {
+    /// The name of the function to query
+    pub function_name: &'a str,
+    /// The type of the function (Chat or Json) - determines inference table
+    pub function_type: FunctionConfigType,
+    /// The name of the metric to query
+    pub metric_name: &'a str,
+    /// Configuration for the metric - determines metric table and level
+    pub metric_config: &'a MetricConfig,
+    /// The inference tag key to segment by (e.g. "customer_tier")
+    pub tag_key: &'a str,
+    /// Optional variant name filter. If provided, only returns data for this variant.
+    pub variant_name: Option<&'a str>,
+}
+
+impl GetFeedbackByTagParams<'_> {
+    /// Returns the ClickHouse table name for the inference table based on function type.
+    pub fn inference_table_name(&self) -> &'static str {
+        self.function_type.table_name()
+    }
+
+    /// Returns the ClickHouse table name for the metric feedback table based on metric type.
+    pub fn metric_table_name(&self) -> &'static str {
+        self.metric_config.r#type.to_clickhouse_table_name()
+    }
+
+    /// Returns the level of the metric (inference or episode).
+    pub fn metric_level(&self) -> MetricConfigLevel {
+        self.metric_config.level.clone()
+    }
+}
+
+/// Row returned from the per-segment feedback query.
+/// Contains statistics for each (variant, tag_value) combination for a given metric.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct FeedbackByVariantAndTag {
+    /// The variant name
+    pub variant_name: String,
+    /// The value of the segmenting tag for this row (e.g. "gold", "us-west", "mobile")
+    pub tag_value: String,
+    /// Number of data points in this (variant, tag_value) combination
+    pub count: u32,
+    /// Average metric value for this segment
+    pub avg_metric: f64,
+    /// Sample standard deviation for this segment (null if count < 2)
+    #[cfg_attr(feature = "ts-bindings", ts(optional))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdev: Option<f64>,
 }
 
 impl GetVariantPerformanceParams<'_> {
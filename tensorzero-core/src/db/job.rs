@@ -0,0 +1,168 @@
+//! A unified `Job` entity for tracking long-running background work: optimization runs,
+//! evaluation top-k passes, bulk inference, and backfills.
+//!
+//! This is deliberately a thin tracking record, not a queue - each subsystem still owns its
+//! own execution (e.g. optimization providers are polled via `OptimizationJobHandle`,
+//! evaluations top-k runs on the `evaluations_topk` durable queue). A `Job` row exists so
+//! operators have one place (`list_jobs`/`get_job`) to see everything running, regardless of
+//! which subsystem started it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// The subsystem that created a `Job`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    Optimization,
+    Evaluation,
+    BulkInference,
+    Backfill,
+}
+
+impl JobKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobKind::Optimization => "optimization",
+            JobKind::Evaluation => "evaluation",
+            JobKind::BulkInference => "bulk_inference",
+            JobKind::Backfill => "backfill",
+        }
+    }
+}
+
+impl std::str::FromStr for JobKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "optimization" => Ok(JobKind::Optimization),
+            "evaluation" => Ok(JobKind::Evaluation),
+            "bulk_inference" => Ok(JobKind::BulkInference),
+            "backfill" => Ok(JobKind::Backfill),
+            _ => Err(Error::new(crate::error::ErrorDetails::Serialization {
+                message: format!("Invalid job kind: {s}"),
+            })),
+        }
+    }
+}
+
+/// Status of a `Job`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    #[default]
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobState::Pending => "pending",
+            JobState::Running => "running",
+            JobState::Completed => "completed",
+            JobState::Failed => "failed",
+            JobState::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether a job in this state can still transition (i.e. hasn't reached a terminal state).
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            JobState::Completed | JobState::Failed | JobState::Cancelled
+        )
+    }
+}
+
+impl std::str::FromStr for JobState {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobState::Pending),
+            "running" => Ok(JobState::Running),
+            "completed" => Ok(JobState::Completed),
+            "failed" => Ok(JobState::Failed),
+            "cancelled" => Ok(JobState::Cancelled),
+            _ => Err(Error::new(crate::error::ErrorDetails::Serialization {
+                message: format!("Invalid job state: {s}"),
+            })),
+        }
+    }
+}
+
+/// A unit of background work tracked across subsystems. `params_hash` lets operators recognize
+/// duplicate/retried work without exposing the (potentially large) original parameters.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: JobKind,
+    pub params_hash: String,
+    pub state: JobState,
+    /// Progress in `[0.0, 1.0]`, if the subsystem reports one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// Opaque pointer to where the finished result lives (e.g. a model provider id, a dataset
+    /// id) - interpreted by the subsystem that created the job, not by this module.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_ref: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Trait for creating, progressing, and querying `Job` rows.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait JobQueries {
+    /// Creates a new job in the `Pending` state.
+    async fn create_job(&self, kind: JobKind, params_hash: &str) -> Result<Job, Error>;
+
+    /// Updates a job's state and/or progress. Pass `None` to leave a field unchanged.
+    /// Transitioning into a terminal state stamps `completed_at`.
+    async fn update_job_progress(
+        &self,
+        job_id: Uuid,
+        state: Option<JobState>,
+        progress: Option<f64>,
+        result_ref: Option<Value>,
+        error_message: Option<String>,
+    ) -> Result<Job, Error>;
+
+    /// Returns the job with the given id, or `None` if it doesn't exist.
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>, Error>;
+
+    /// Lists jobs, optionally filtered by kind and/or state, newest first.
+    async fn list_jobs(
+        &self,
+        kind: Option<JobKind>,
+        state: Option<JobState>,
+        limit: u64,
+    ) -> Result<Vec<Job>, Error>;
+
+    /// Marks a non-terminal job as `Cancelled`. This only updates the tracking row - it's the
+    /// caller's responsibility to also stop the underlying work (e.g. drop the polling loop).
+    async fn cancel_job(&self, job_id: Uuid) -> Result<Job, Error>;
+}
@@ -14,15 +14,21 @@ use crate::error::Error;
 use crate::serde_util::{deserialize_option_u64, deserialize_u64};
 
 pub mod batch_inference;
+pub mod cache_queries;
 pub mod clickhouse;
+pub mod cost;
 pub mod datasets;
+pub mod episode_budget;
+pub mod episode_fork;
 pub mod evaluation_queries;
 pub mod feedback;
 pub mod inference_count;
 pub mod inferences;
+pub mod job;
 pub mod model_inferences;
 pub mod postgres;
 pub mod rate_limiting;
+pub mod review_queue;
 pub mod stored_datapoint;
 pub mod valkey;
 pub mod workflow_evaluation_queries;
@@ -133,6 +139,9 @@ pub struct ModelLatencyDatapoint {
     // should be an array of quantiles_len u64
     pub response_time_ms_quantiles: Vec<Option<f32>>,
     pub ttft_ms_quantiles: Vec<Option<f32>>,
+    /// Streaming decode throughput, in output tokens per second (`NULL` for a quantile computed
+    /// over zero streaming samples in the window).
+    pub tokens_per_second_quantiles: Vec<Option<f32>>,
     #[serde(deserialize_with = "deserialize_u64")]
     pub count: u64,
 }
@@ -188,10 +197,48 @@ pub trait ExperimentationQueries {
     ) -> Result<String, Error>;
 }
 
+/// Filters `ConfigQueries::list_config_snapshots` down to snapshots with a matching tag.
+#[derive(Debug, Clone)]
+pub struct ConfigSnapshotTagFilter {
+    pub key: String,
+    pub value: String,
+}
+
+/// Parameters for `ConfigQueries::list_config_snapshots`.
+#[derive(Debug, Clone, Default)]
+pub struct ListConfigSnapshotsParams {
+    pub limit: u32,
+    pub offset: u32,
+    pub tag_filter: Option<ConfigSnapshotTagFilter>,
+}
+
+/// A config snapshot's identity and metadata, without its config or templates, for listings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigSnapshotSummary {
+    pub hash: SnapshotHash,
+    pub tags: std::collections::HashMap<String, String>,
+    pub created_at: DateTime<Utc>,
+}
+
 #[cfg_attr(test, automock)]
 pub trait ConfigQueries {
     fn get_config_snapshot(
         &self,
         snapshot_hash: SnapshotHash,
     ) -> impl Future<Output = Result<ConfigSnapshot, Error>> + Send;
+
+    /// Lists config snapshots ordered by creation time (most recent first), with pagination and
+    /// optional tag filtering.
+    fn list_config_snapshots(
+        &self,
+        params: ListConfigSnapshotsParams,
+    ) -> impl Future<Output = Result<Vec<ConfigSnapshotSummary>, Error>> + Send;
+
+    /// Merges `tags` into a config snapshot's existing tags (new tags override existing keys),
+    /// leaving its config and templates untouched. Fails if the snapshot doesn't exist.
+    fn update_snapshot_tags(
+        &self,
+        snapshot_hash: SnapshotHash,
+        tags: std::collections::HashMap<String, String>,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 }
@@ -0,0 +1,71 @@
+//! Postgres queries for episode budget usage.
+
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::PostgresConnectionInfo;
+use crate::db::episode_budget::{
+    EpisodeBudgetQueries, EpisodeBudgetUsage, EpisodeBudgetUsageDelta,
+};
+use crate::error::Error;
+
+#[async_trait]
+impl EpisodeBudgetQueries for PostgresConnectionInfo {
+    async fn get_episode_budget_usage(
+        &self,
+        episode_id: Uuid,
+    ) -> Result<EpisodeBudgetUsage, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "SELECT tokens_used, cost_used_usd, inference_count
+             FROM episode_budget_usage
+             WHERE episode_id = $1",
+        )
+        .bind(episode_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?;
+
+        let Some(row) = row else {
+            return Ok(EpisodeBudgetUsage::default());
+        };
+
+        let tokens_used: i64 = row.get("tokens_used");
+        let cost_used_usd: f64 = row.get("cost_used_usd");
+        let inference_count: i64 = row.get("inference_count");
+
+        Ok(EpisodeBudgetUsage {
+            tokens_used: tokens_used as u64,
+            cost_used_usd,
+            inference_count: inference_count as u64,
+        })
+    }
+
+    async fn record_episode_budget_usage(
+        &self,
+        episode_id: Uuid,
+        delta: EpisodeBudgetUsageDelta,
+    ) -> Result<(), Error> {
+        let pool = self.get_pool_result()?;
+
+        sqlx::query(
+            "INSERT INTO episode_budget_usage (episode_id, tokens_used, cost_used_usd, inference_count, updated_at)
+             VALUES ($1, $2, $3, 1, NOW())
+             ON CONFLICT (episode_id) DO UPDATE SET
+                 tokens_used = episode_budget_usage.tokens_used + EXCLUDED.tokens_used,
+                 cost_used_usd = episode_budget_usage.cost_used_usd + EXCLUDED.cost_used_usd,
+                 inference_count = episode_budget_usage.inference_count + 1,
+                 updated_at = NOW()",
+        )
+        .bind(episode_id)
+        .bind(delta.tokens as i64)
+        .bind(delta.cost_usd)
+        .execute(pool)
+        .await
+        .map_err(Error::from)?;
+
+        Ok(())
+    }
+}
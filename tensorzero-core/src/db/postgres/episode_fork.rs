@@ -0,0 +1,56 @@
+//! Postgres queries for episode fork lineage.
+
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::PostgresConnectionInfo;
+use crate::db::episode_fork::{EpisodeFork, EpisodeForkQueries};
+use crate::error::Error;
+
+#[async_trait]
+impl EpisodeForkQueries for PostgresConnectionInfo {
+    async fn create_episode_fork(&self, fork: EpisodeFork) -> Result<(), Error> {
+        let pool = self.get_pool_result()?;
+
+        sqlx::query(
+            "INSERT INTO episode_forks (episode_id, parent_episode_id, fork_point_inference_id)
+             VALUES ($1, $2, $3)",
+        )
+        .bind(fork.episode_id)
+        .bind(fork.parent_episode_id)
+        .bind(fork.fork_point_inference_id)
+        .execute(pool)
+        .await
+        .map_err(Error::from)?;
+
+        Ok(())
+    }
+
+    async fn get_episode_fork(&self, episode_id: Uuid) -> Result<Option<EpisodeFork>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "SELECT parent_episode_id, fork_point_inference_id
+             FROM episode_forks
+             WHERE episode_id = $1",
+        )
+        .bind(episode_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let parent_episode_id: Uuid = row.get("parent_episode_id");
+        let fork_point_inference_id: Option<Uuid> = row.get("fork_point_inference_id");
+
+        Ok(Some(EpisodeFork {
+            episode_id,
+            parent_episode_id,
+            fork_point_inference_id,
+        }))
+    }
+}
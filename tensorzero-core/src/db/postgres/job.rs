@@ -0,0 +1,177 @@
+//! Postgres queries for the unified `Job` tracking table.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::{QueryBuilder, Row};
+use uuid::Uuid;
+
+use super::PostgresConnectionInfo;
+use crate::db::job::{Job, JobKind, JobQueries, JobState};
+use crate::error::{Error, ErrorDetails};
+
+fn row_to_job(row: sqlx::postgres::PgRow) -> Result<Job, Error> {
+    let kind: String = row.get("kind");
+    let state: String = row.get("state");
+    let result_ref: Option<String> = row.get("result_ref");
+    let result_ref = result_ref
+        .map(|s| {
+            serde_json::from_str(&s).map_err(|e| {
+                Error::new(ErrorDetails::Serialization {
+                    message: format!("Failed to deserialize job result_ref: {e}"),
+                })
+            })
+        })
+        .transpose()?;
+    Ok(Job {
+        id: row.get("id"),
+        kind: kind.parse()?,
+        params_hash: row.get("params_hash"),
+        state: state.parse()?,
+        progress: row.get("progress"),
+        result_ref,
+        error_message: row.get("error_message"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        completed_at: row.get("completed_at"),
+    })
+}
+
+#[async_trait]
+impl JobQueries for PostgresConnectionInfo {
+    async fn create_job(&self, kind: JobKind, params_hash: &str) -> Result<Job, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "INSERT INTO job (id, kind, params_hash, state)
+             VALUES ($1, $2, $3, 'pending')
+             RETURNING id, kind, params_hash, state, progress, result_ref, error_message,
+                       created_at, updated_at, completed_at",
+        )
+        .bind(Uuid::now_v7())
+        .bind(kind.as_str())
+        .bind(params_hash)
+        .fetch_one(pool)
+        .await
+        .map_err(Error::from)?;
+
+        row_to_job(row)
+    }
+
+    async fn update_job_progress(
+        &self,
+        job_id: Uuid,
+        state: Option<JobState>,
+        progress: Option<f64>,
+        result_ref: Option<Value>,
+        error_message: Option<String>,
+    ) -> Result<Job, Error> {
+        let pool = self.get_pool_result()?;
+
+        let completed_at = state.filter(JobState::is_terminal).map(|_| ());
+        let result_ref = result_ref
+            .map(|v| {
+                serde_json::to_string(&v).map_err(|e| {
+                    Error::new(ErrorDetails::Serialization {
+                        message: format!("Failed to serialize job result_ref: {e}"),
+                    })
+                })
+            })
+            .transpose()?;
+        let row = sqlx::query(
+            "UPDATE job
+             SET state = COALESCE($2, state),
+                 progress = COALESCE($3, progress),
+                 result_ref = COALESCE($4, result_ref),
+                 error_message = COALESCE($5, error_message),
+                 updated_at = NOW(),
+                 completed_at = CASE WHEN $6 THEN NOW() ELSE completed_at END
+             WHERE id = $1
+             RETURNING id, kind, params_hash, state, progress, result_ref, error_message,
+                       created_at, updated_at, completed_at",
+        )
+        .bind(job_id)
+        .bind(state.map(|s| s.as_str()))
+        .bind(progress)
+        .bind(result_ref)
+        .bind(error_message)
+        .bind(completed_at.is_some())
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Job {job_id} does not exist"),
+            })
+        })?;
+
+        row_to_job(row)
+    }
+
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "SELECT id, kind, params_hash, state, progress, result_ref, error_message,
+                    created_at, updated_at, completed_at
+             FROM job
+             WHERE id = $1",
+        )
+        .bind(job_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?;
+
+        row.map(row_to_job).transpose()
+    }
+
+    async fn list_jobs(
+        &self,
+        kind: Option<JobKind>,
+        state: Option<JobState>,
+        limit: u64,
+    ) -> Result<Vec<Job>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let mut query = QueryBuilder::new(
+            "SELECT id, kind, params_hash, state, progress, result_ref, error_message,
+                    created_at, updated_at, completed_at
+             FROM job WHERE 1 = 1",
+        );
+        if let Some(kind) = kind {
+            query.push(" AND kind = ").push_bind(kind.as_str());
+        }
+        if let Some(state) = state {
+            query.push(" AND state = ").push_bind(state.as_str());
+        }
+        query
+            .push(" ORDER BY created_at DESC LIMIT ")
+            .push_bind(limit as i64);
+
+        let rows = query.build().fetch_all(pool).await.map_err(Error::from)?;
+
+        rows.into_iter().map(row_to_job).collect()
+    }
+
+    async fn cancel_job(&self, job_id: Uuid) -> Result<Job, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "UPDATE job
+             SET state = 'cancelled', updated_at = NOW(), completed_at = NOW()
+             WHERE id = $1 AND state NOT IN ('completed', 'failed', 'cancelled')
+             RETURNING id, kind, params_hash, state, progress, result_ref, error_message,
+                       created_at, updated_at, completed_at",
+        )
+        .bind(job_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Job {job_id} does not exist or is already in a terminal state"),
+            })
+        })?;
+
+        row_to_job(row)
+    }
+}
@@ -10,9 +10,13 @@ use crate::error::{Error, ErrorDetails};
 
 use super::HealthCheckable;
 
+pub mod episode_budget;
+pub mod episode_fork;
 pub mod experimentation;
 pub mod inference_count;
+pub mod job;
 pub mod rate_limiting;
+pub mod review_queue;
 
 #[cfg(any(test, feature = "e2e_tests"))]
 pub mod test_helpers;
@@ -0,0 +1,185 @@
+//! Postgres queries for the review queue / labeling workflow.
+
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::PostgresConnectionInfo;
+use crate::db::review_queue::{
+    ReviewQueueQueries, ReviewQueueSummary, ReviewTask, ReviewTaskStatus,
+};
+use crate::error::Error;
+
+fn row_to_review_task(row: sqlx::postgres::PgRow) -> Result<ReviewTask, Error> {
+    let status: String = row.get("status");
+    Ok(ReviewTask {
+        id: row.get("id"),
+        inference_id: row.get("inference_id"),
+        metric_name: row.get("metric_name"),
+        status: status.parse()?,
+        assignee: row.get("assignee"),
+        feedback_id: row.get("feedback_id"),
+    })
+}
+
+#[async_trait]
+impl ReviewQueueQueries for PostgresConnectionInfo {
+    async fn create_review_tasks(
+        &self,
+        inference_ids: &[Uuid],
+        metric_name: &str,
+    ) -> Result<Vec<ReviewTask>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let mut tasks = Vec::with_capacity(inference_ids.len());
+        for inference_id in inference_ids {
+            let id = Uuid::now_v7();
+            let row = sqlx::query(
+                "INSERT INTO review_task (id, inference_id, metric_name, status)
+                 VALUES ($1, $2, $3, 'pending')
+                 RETURNING id, inference_id, metric_name, status, assignee, feedback_id",
+            )
+            .bind(id)
+            .bind(inference_id)
+            .bind(metric_name)
+            .fetch_one(pool)
+            .await
+            .map_err(Error::from)?;
+            tasks.push(row_to_review_task(row)?);
+        }
+        Ok(tasks)
+    }
+
+    async fn get_review_task(&self, task_id: Uuid) -> Result<Option<ReviewTask>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "SELECT id, inference_id, metric_name, status, assignee, feedback_id
+             FROM review_task
+             WHERE id = $1",
+        )
+        .bind(task_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?;
+
+        row.map(row_to_review_task).transpose()
+    }
+
+    async fn assign_review_task(&self, task_id: Uuid, assignee: &str) -> Result<ReviewTask, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "UPDATE review_task
+             SET assignee = $2, status = 'assigned', assigned_at = NOW()
+             WHERE id = $1
+             RETURNING id, inference_id, metric_name, status, assignee, feedback_id",
+        )
+        .bind(task_id)
+        .bind(assignee)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?
+        .ok_or_else(|| {
+            Error::new(crate::error::ErrorDetails::InvalidRequest {
+                message: format!("Review task {task_id} does not exist"),
+            })
+        })?;
+
+        row_to_review_task(row)
+    }
+
+    async fn complete_review_task(
+        &self,
+        task_id: Uuid,
+        feedback_id: Uuid,
+    ) -> Result<ReviewTask, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "UPDATE review_task
+             SET status = 'completed', feedback_id = $2, completed_at = NOW()
+             WHERE id = $1
+             RETURNING id, inference_id, metric_name, status, assignee, feedback_id",
+        )
+        .bind(task_id)
+        .bind(feedback_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::from)?
+        .ok_or_else(|| {
+            Error::new(crate::error::ErrorDetails::InvalidRequest {
+                message: format!("Review task {task_id} does not exist"),
+            })
+        })?;
+
+        row_to_review_task(row)
+    }
+
+    async fn get_review_queue_summary(
+        &self,
+        metric_name: &str,
+    ) -> Result<ReviewQueueSummary, Error> {
+        let pool = self.get_pool_result()?;
+
+        let row = sqlx::query(
+            "SELECT
+                 COUNT(*) FILTER (WHERE status = 'pending') AS pending,
+                 COUNT(*) FILTER (WHERE status = 'assigned') AS assigned,
+                 COUNT(*) FILTER (WHERE status = 'completed') AS completed
+             FROM review_task
+             WHERE metric_name = $1",
+        )
+        .bind(metric_name)
+        .fetch_one(pool)
+        .await
+        .map_err(Error::from)?;
+
+        let pending: i64 = row.get("pending");
+        let assigned: i64 = row.get("assigned");
+        let completed: i64 = row.get("completed");
+
+        Ok(ReviewQueueSummary {
+            pending: pending as u64,
+            assigned: assigned as u64,
+            completed: completed as u64,
+        })
+    }
+
+    async fn list_review_tasks(
+        &self,
+        metric_name: &str,
+        status: Option<ReviewTaskStatus>,
+    ) -> Result<Vec<ReviewTask>, Error> {
+        let pool = self.get_pool_result()?;
+
+        let rows = match status {
+            Some(status) => {
+                sqlx::query(
+                    "SELECT id, inference_id, metric_name, status, assignee, feedback_id
+                     FROM review_task
+                     WHERE metric_name = $1 AND status = $2
+                     ORDER BY created_at",
+                )
+                .bind(metric_name)
+                .bind(status.as_str())
+                .fetch_all(pool)
+                .await
+            }
+            None => {
+                sqlx::query(
+                    "SELECT id, inference_id, metric_name, status, assignee, feedback_id
+                     FROM review_task
+                     WHERE metric_name = $1
+                     ORDER BY created_at",
+                )
+                .bind(metric_name)
+                .fetch_all(pool)
+                .await
+            }
+        }
+        .map_err(Error::from)?;
+
+        rows.into_iter().map(row_to_review_task).collect()
+    }
+}
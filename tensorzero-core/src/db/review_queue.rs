@@ -0,0 +1,119 @@
+//! Review queue types and trait definitions.
+//!
+//! A review task pairs a sampled inference with a metric name and tracks it through a
+//! reviewer labeling workflow. Submitting a label for a task (see
+//! `tensorzero_core::endpoints::review_queue`) writes an ordinary feedback record for the
+//! task's `inference_id`/`metric_name` and links the resulting `feedback_id` back onto the
+//! task, so the review queue never duplicates feedback storage or validation.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::Error;
+
+/// Status of a single review task in the labeling queue.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewTaskStatus {
+    #[default]
+    Pending,
+    Assigned,
+    Completed,
+}
+
+impl ReviewTaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewTaskStatus::Pending => "pending",
+            ReviewTaskStatus::Assigned => "assigned",
+            ReviewTaskStatus::Completed => "completed",
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewTaskStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(ReviewTaskStatus::Pending),
+            "assigned" => Ok(ReviewTaskStatus::Assigned),
+            "completed" => Ok(ReviewTaskStatus::Completed),
+            _ => Err(Error::new(crate::error::ErrorDetails::Serialization {
+                message: format!("Invalid review task status: {s}"),
+            })),
+        }
+    }
+}
+
+/// A single unit of work in the review queue: one sampled inference awaiting a human
+/// label for `metric_name`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct ReviewTask {
+    pub id: Uuid,
+    pub inference_id: Uuid,
+    pub metric_name: String,
+    pub status: ReviewTaskStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback_id: Option<Uuid>,
+}
+
+/// Counts of review tasks for a metric, grouped by status.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ReviewQueueSummary {
+    pub pending: u64,
+    pub assigned: u64,
+    pub completed: u64,
+}
+
+/// Trait for creating and progressing review tasks in the labeling queue.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ReviewQueueQueries {
+    /// Creates one pending review task per entry in `inference_ids`, all for the same
+    /// `metric_name`. Sampling which inferences to review is the caller's
+    /// responsibility; this only records the resulting task set.
+    async fn create_review_tasks(
+        &self,
+        inference_ids: &[Uuid],
+        metric_name: &str,
+    ) -> Result<Vec<ReviewTask>, Error>;
+
+    /// Returns the review task with the given id, or `None` if it doesn't exist.
+    async fn get_review_task(&self, task_id: Uuid) -> Result<Option<ReviewTask>, Error>;
+
+    /// Assigns `assignee` to a pending or already-assigned task.
+    async fn assign_review_task(&self, task_id: Uuid, assignee: &str) -> Result<ReviewTask, Error>;
+
+    /// Marks a task completed and links it to the feedback record created from its label.
+    async fn complete_review_task(
+        &self,
+        task_id: Uuid,
+        feedback_id: Uuid,
+    ) -> Result<ReviewTask, Error>;
+
+    /// Returns task counts by status for `metric_name`.
+    async fn get_review_queue_summary(
+        &self,
+        metric_name: &str,
+    ) -> Result<ReviewQueueSummary, Error>;
+
+    /// Lists review tasks for `metric_name`, optionally filtered to a single status.
+    async fn list_review_tasks(
+        &self,
+        metric_name: &str,
+        status: Option<ReviewTaskStatus>,
+    ) -> Result<Vec<ReviewTask>, Error>;
+}
@@ -5,7 +5,7 @@ use tensorzero_derive::TensorZeroDeserialize;
 use uuid::Uuid;
 
 use crate::config::snapshot::SnapshotHash;
-use crate::endpoints::datasets::Datapoint;
+use crate::endpoints::datasets::{Datapoint, DatapointProvenance};
 use crate::error::Error;
 use crate::inference::types::stored_input::StoredInput;
 use crate::inference::types::{ContentBlockChatOutput, JsonInferenceOutput, Text};
@@ -202,6 +202,15 @@ impl StoredDatapoint {
         }
     }
 
+    /// Inserts `(key, value)` into this datapoint's `tags` map, creating the map if absent.
+    pub fn set_tag(&mut self, key: String, value: String) {
+        let tags = match self {
+            StoredDatapoint::Chat(datapoint) => &mut datapoint.tags,
+            StoredDatapoint::Json(datapoint) => &mut datapoint.tags,
+        };
+        tags.get_or_insert_with(HashMap::new).insert(key, value);
+    }
+
     pub fn input(&self) -> &StoredInput {
         match self {
             StoredDatapoint::Chat(datapoint) => &datapoint.input,
@@ -230,6 +239,22 @@ impl StoredDatapoint {
         }
     }
 
+    pub fn source_inference_id(&self) -> Option<Uuid> {
+        match self {
+            StoredDatapoint::Chat(datapoint) => datapoint.source_inference_id,
+            StoredDatapoint::Json(datapoint) => datapoint.source_inference_id,
+        }
+    }
+
+    /// Returns how this datapoint was created. See [`DatapointProvenance`].
+    pub fn provenance(&self) -> DatapointProvenance {
+        let tags = match self {
+            StoredDatapoint::Chat(datapoint) => datapoint.tags.as_ref(),
+            StoredDatapoint::Json(datapoint) => datapoint.tags.as_ref(),
+        };
+        DatapointProvenance::from_tags(tags, self.source_inference_id())
+    }
+
     /// Convert to wire type, properly handling tool params by subtracting static tools
     /// TODO(shuyangli): Add parameter to optionally fetch files from object storage
     pub fn into_datapoint(self) -> Result<Datapoint, Error> {
@@ -186,6 +186,7 @@ impl EmbeddingModelConfig {
                 if clients.cache_options.enabled.read() {
                     let cache_lookup = embedding_cache_lookup(
                         &clients.clickhouse_connection_info,
+                        &*clients.hot_cache,
                         &provider_request,
                         clients.cache_options.max_age_s,
                     )
@@ -211,6 +212,7 @@ impl EmbeddingModelConfig {
                             };
                             let _ = start_cache_write(
                                 &clients.clickhouse_connection_info,
+                                clients.hot_cache.clone(),
                                 provider_request.get_cache_key()?,
                                 CacheData {
                                     output: EmbeddingCacheData {
@@ -856,6 +858,11 @@ mod tests {
                         max_age_s: None,
                         enabled: CacheEnabledMode::Off,
                     },
+                    hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                        crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                        crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                        crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+                    )),
                     tags: Arc::new(Default::default()),
                     rate_limiting_manager: Arc::new(RateLimitingManager::new_dummy()),
                     otlp_config: Default::default(),
@@ -863,6 +870,7 @@ mod tests {
                     scope_info: ScopeInfo {
                         tags: Arc::new(HashMap::new()),
                         api_key_public_id: None,
+                        model_name: None,
                     },
                     relay: None,
                     include_raw_usage: false,
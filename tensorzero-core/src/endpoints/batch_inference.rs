@@ -128,6 +128,7 @@ pub async fn start_batch_inference(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
         ..
     }: AppStateData,
     params: StartBatchInferenceParams,
@@ -229,6 +230,7 @@ pub async fn start_batch_inference(
         postgres_connection_info: postgres_connection_info.clone(),
         credentials: Arc::new(params.credentials.clone()),
         cache_options: cache_options.clone(),
+        hot_cache,
         rate_limiting_manager,
         tags: tags.clone(),
         otlp_config: config.gateway.export.otlp.clone(),
@@ -1026,6 +1028,7 @@ pub async fn write_completed_batch_inference<'a>(
             false, // batch inference does not support include_raw_usage (#5452)
             false, // batch inference does not support include_original_response
             false, // batch inference does not support include_raw_response
+            None,  // batch inference does not support include_snapshot_hash
         );
         inferences.push(inference_response);
         let metadata = InferenceDatabaseInsertMetadata {
@@ -1043,7 +1046,7 @@ pub async fn write_completed_batch_inference<'a>(
         };
         model_inference_rows_to_write.extend(
             inference_result
-                .get_serialized_model_inferences(config.hash.clone())
+                .get_serialized_model_inferences(config.hash.clone(), config)
                 .await,
         );
         match inference_result {
@@ -1168,6 +1171,7 @@ fn convert_row_to_inference_response(
                 original_response: None,
                 raw_response: None,
                 finish_reason: row.finish_reason,
+                snapshot_hash: None, // batch inference does not support include_snapshot_hash
             }))
         }
         FunctionConfig::Json(_) => {
@@ -1186,6 +1190,7 @@ fn convert_row_to_inference_response(
                 original_response: None,
                 raw_response: None,
                 finish_reason: row.finish_reason,
+                snapshot_hash: None, // batch inference does not support include_snapshot_hash
             }))
         }
     }
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::inference::types::stored_input::StoredInput;
+
+/// Reserved tag key used to record that a datapoint was collapsed as a duplicate of another
+/// datapoint. Stored inline in the datapoint's `tags` map for the same reason as
+/// [`crate::endpoints::datasets::SPLIT_TAG_KEY`]: `tags` already exists on every datapoint,
+/// so this can be introduced without a schema migration.
+pub const DUPLICATE_TAG_KEY: &str = "tensorzero::duplicate_of";
+
+/// Groups `inputs` (datapoint id -> canonicalized input) by exact-match SHA-256 hash.
+///
+/// Returns one group per distinct hash, each containing every datapoint id that shares it.
+/// Groups of size 1 (no duplicates) are omitted.
+pub fn group_by_exact_hash(inputs: &[(Uuid, &StoredInput)]) -> Vec<Vec<Uuid>> {
+    let mut groups: HashMap<[u8; 32], Vec<Uuid>> = HashMap::new();
+    for (id, input) in inputs {
+        groups.entry(hash_input(input)).or_default().push(*id);
+    }
+    groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect()
+}
+
+/// Groups `embeddings` (datapoint id -> embedding vector) using single-linkage clustering:
+/// two datapoints land in the same group if their cosine similarity is at least `threshold`,
+/// transitively. This is intentionally simple (not the tightest possible clustering) so that
+/// results don't depend on the order datapoints are processed in.
+///
+/// Returns one group per cluster with more than one member; datapoints with no near-duplicate
+/// are omitted.
+pub fn group_by_embedding_similarity(
+    embeddings: &[(Uuid, &[f32])],
+    threshold: f32,
+) -> Vec<Vec<Uuid>> {
+    let mut parent: HashMap<Uuid, Uuid> = embeddings.iter().map(|(id, _)| (*id, *id)).collect();
+
+    fn find(parent: &mut HashMap<Uuid, Uuid>, id: Uuid) -> Uuid {
+        let root = parent[&id];
+        if root == id {
+            return id;
+        }
+        let root = find(parent, root);
+        parent.insert(id, root);
+        root
+    }
+
+    for i in 0..embeddings.len() {
+        for j in (i + 1)..embeddings.len() {
+            let (id_a, vec_a) = embeddings[i];
+            let (id_b, vec_b) = embeddings[j];
+            if cosine_similarity(vec_a, vec_b) >= threshold {
+                let root_a = find(&mut parent, id_a);
+                let root_b = find(&mut parent, id_b);
+                if root_a != root_b {
+                    parent.insert(root_a, root_b);
+                }
+            }
+        }
+    }
+
+    let mut clusters: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (id, _) in embeddings {
+        let root = find(&mut parent, *id);
+        clusters.entry(root).or_default().push(*id);
+    }
+    clusters
+        .into_values()
+        .filter(|cluster| cluster.len() > 1)
+        .collect()
+}
+
+fn hash_input(input: &StoredInput) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    // `StoredInput` doesn't implement `Hash`, but every field is `Serialize`, and JSON
+    // serialization is deterministic for our purposes (map keys come from fixed struct
+    // fields, not a `HashMap`), so hashing the serialized bytes gives a stable content hash.
+    let bytes = serde_json::to_vec(input).unwrap_or_default();
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_exact_hash_groups_identical_inputs() {
+        let a = StoredInput::default();
+        let mut b = StoredInput::default();
+        b.messages.clear();
+        let id_a = Uuid::now_v7();
+        let id_b = Uuid::now_v7();
+        let id_c = Uuid::now_v7();
+        let c = StoredInput::default();
+        let groups = group_by_exact_hash(&[(id_a, &a), (id_b, &b), (id_c, &c)]);
+        assert_eq!(
+            groups.len(),
+            1,
+            "identical inputs should collapse into a single group"
+        );
+        let mut group = groups.into_iter().next().unwrap();
+        group.sort();
+        let mut expected = vec![id_a, id_b, id_c];
+        expected.sort();
+        assert_eq!(group, expected, "the group should contain all three ids");
+    }
+
+    #[test]
+    fn cosine_similarity_of_identical_vectors_is_one() {
+        let similarity = cosine_similarity(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]);
+        assert!(
+            (similarity - 1.0).abs() < 1e-6,
+            "identical vectors must have cosine similarity 1.0, got {similarity}"
+        );
+    }
+
+    #[test]
+    fn group_by_embedding_similarity_transitively_clusters() {
+        let id_a = Uuid::now_v7();
+        let id_b = Uuid::now_v7();
+        let id_c = Uuid::now_v7();
+        let vec_a: Vec<f32> = vec![1.0, 0.0];
+        let vec_b: Vec<f32> = vec![0.99, 0.01];
+        let vec_c: Vec<f32> = vec![0.0, 1.0];
+        let groups =
+            group_by_embedding_similarity(&[(id_a, &vec_a), (id_b, &vec_b), (id_c, &vec_c)], 0.9);
+        assert_eq!(
+            groups.len(),
+            1,
+            "only the near-identical pair should form a group"
+        );
+        let mut group = groups.into_iter().next().unwrap();
+        group.sort();
+        let mut expected = vec![id_a, id_b];
+        expected.sort();
+        assert_eq!(group, expected);
+    }
+}
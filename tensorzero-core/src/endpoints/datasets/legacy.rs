@@ -1245,6 +1245,20 @@ impl Datapoint {
             Datapoint::Json(datapoint) => Some(&datapoint.output_schema),
         }
     }
+
+    pub fn tags(&self) -> Option<&HashMap<String, String>> {
+        match self {
+            Datapoint::Chat(datapoint) => datapoint.tags.as_ref(),
+            Datapoint::Json(datapoint) => datapoint.tags.as_ref(),
+        }
+    }
+
+    pub fn source_inference_id(&self) -> Option<Uuid> {
+        match self {
+            Datapoint::Chat(datapoint) => datapoint.source_inference_id,
+            Datapoint::Json(datapoint) => datapoint.source_inference_id,
+        }
+    }
 }
 
 impl ChatInferenceDatapoint {
@@ -1,6 +1,12 @@
 pub mod internal;
 pub mod v1;
 
+pub mod dedup;
 mod legacy;
+mod provenance;
+pub mod split;
 
+pub use dedup::{DUPLICATE_TAG_KEY, group_by_embedding_similarity, group_by_exact_hash};
 pub use legacy::*;
+pub use provenance::{DatapointProvenance, PROVENANCE_TAG};
+pub use split::{SPLIT_TAG_KEY, assign_split};
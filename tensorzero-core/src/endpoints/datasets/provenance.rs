@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tensorzero_derive::TensorZeroDeserialize;
+use uuid::Uuid;
+
+use crate::endpoints::datasets::Datapoint;
+
+/// Reserved tag key used to record how a datapoint was created. Stored inline
+/// in the datapoint's `tags` map rather than as a dedicated column, since
+/// `tags` is already present (and optional) on every existing datapoint, so
+/// this can be introduced without a schema migration or touching the many
+/// call sites that construct `StoredChatInferenceDatapoint` /
+/// `StoredJsonInferenceDatapoint` literals.
+pub const PROVENANCE_TAG: &str = "tensorzero::provenance";
+
+/// How a datapoint came to exist.
+///
+/// This is derived from a datapoint's tags (see [`PROVENANCE_TAG`]) rather
+/// than stored as its own column. Datapoints written before this feature
+/// existed have no such tag, so [`Datapoint::provenance`] falls back to
+/// `Inference` (when `source_inference_id` is set) or `Manual` (otherwise).
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, JsonSchema, PartialEq, Serialize, TensorZeroDeserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum DatapointProvenance {
+    /// Created or edited directly by a user, with no automated source.
+    Manual,
+    /// Derived from a historical inference (e.g. by taking its output as a
+    /// demonstration).
+    Inference { inference_id: Uuid },
+    /// Produced by a synthetic data generation process.
+    Synthetic { generator: String },
+    /// Brought in from an external file or dataset.
+    Import { file: String },
+    /// Brought in by a [`crate::dataset_sync`] connector pulling from an external source
+    /// (e.g. an object storage prefix of JSONL files, a spreadsheet, a customer database).
+    /// `external_id` identifies this record within `source` (e.g. an object storage key),
+    /// and `content_hash` is the hash of the record's contents at sync time - later syncs
+    /// compare against this to detect whether the source has changed since.
+    ExternalSync {
+        source: String,
+        external_id: String,
+        content_hash: String,
+    },
+}
+
+impl DatapointProvenance {
+    /// Serializes this provenance as the `(key, value)` tag pair that should
+    /// be inserted into a datapoint's `tags` map.
+    pub fn to_tag(&self) -> Result<(String, String), serde_json::Error> {
+        Ok((PROVENANCE_TAG.to_string(), serde_json::to_string(self)?))
+    }
+
+    /// Reconstructs a `DatapointProvenance` from a datapoint's tags, falling
+    /// back to `source_inference_id` for datapoints written before this
+    /// feature existed, and finally to `Manual`.
+    pub fn from_tags(
+        tags: Option<&HashMap<String, String>>,
+        source_inference_id: Option<Uuid>,
+    ) -> Self {
+        if let Some(raw) = tags.and_then(|tags| tags.get(PROVENANCE_TAG)) {
+            if let Ok(provenance) = serde_json::from_str(raw) {
+                return provenance;
+            }
+        }
+        match source_inference_id {
+            Some(inference_id) => DatapointProvenance::Inference { inference_id },
+            None => DatapointProvenance::Manual,
+        }
+    }
+}
+
+impl Datapoint {
+    /// Returns how this datapoint was created. See [`DatapointProvenance`].
+    pub fn provenance(&self) -> DatapointProvenance {
+        DatapointProvenance::from_tags(self.tags(), self.source_inference_id())
+    }
+}
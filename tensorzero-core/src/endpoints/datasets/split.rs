@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::error::{Error, ErrorDetails};
+
+/// Reserved tag key used to record which named split (e.g. `train`, `test`) a datapoint
+/// was assigned to. Stored inline in the datapoint's `tags` map for the same reason as
+/// [`crate::endpoints::datasets::PROVENANCE_TAG`]: `tags` already exists on every
+/// datapoint, so this can be introduced without a schema migration.
+pub const SPLIT_TAG_KEY: &str = "tensorzero::split";
+
+/// Deterministically assigns `datapoint_id` to one of `splits` (name -> relative weight).
+///
+/// The assignment only depends on `seed`, `splits`, and `datapoint_id` - not on the order
+/// datapoints are processed in or which other datapoints exist - so re-running a split with
+/// the same arguments always reproduces the same assignment, and adding new datapoints to
+/// the dataset doesn't reshuffle existing ones.
+///
+/// Weights need not sum to 1; they are normalized. Returns an error if `splits` is empty or
+/// any weight is not a positive, finite number.
+pub fn assign_split<'a>(
+    splits: &'a BTreeMap<String, f64>,
+    seed: u64,
+    datapoint_id: Uuid,
+) -> Result<&'a str, Error> {
+    if splits.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "`splits` must not be empty".to_string(),
+        }));
+    }
+    if splits
+        .values()
+        .any(|weight| !weight.is_finite() || *weight <= 0.0)
+    {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "Every split weight must be a positive, finite number".to_string(),
+        }));
+    }
+
+    let total_weight: f64 = splits.values().sum();
+    let fraction = hash_to_unit_interval(seed, datapoint_id);
+
+    let mut cumulative = 0.0;
+    let mut last_name = splits.keys().next_back().map(String::as_str);
+    for (name, weight) in splits {
+        cumulative += weight / total_weight;
+        if fraction < cumulative {
+            return Ok(name);
+        }
+        last_name = Some(name);
+    }
+    // Floating-point rounding can leave `fraction` fractionally past the last boundary;
+    // fall back to the last split (in `splits`' key order) rather than erroring.
+    Ok(last_name.expect("splits is non-empty, checked above"))
+}
+
+/// Hashes `(seed, datapoint_id)` with SHA-256 and maps the result into `[0, 1)`. Using a
+/// cryptographic hash (rather than `DefaultHasher`, whose output isn't guaranteed stable
+/// across Rust versions) keeps split assignments reproducible indefinitely.
+fn hash_to_unit_interval(seed: u64, datapoint_id: Uuid) -> f64 {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.to_le_bytes());
+    hasher.update(datapoint_id.as_bytes());
+    let digest = hasher.finalize();
+    let bytes: [u8; 8] = digest[..8].try_into().expect("digest is at least 8 bytes");
+    (u64::from_le_bytes(bytes) as f64) / (u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assign_split_is_deterministic() {
+        let splits = BTreeMap::from([("train".to_string(), 0.8), ("test".to_string(), 0.2)]);
+        let id = Uuid::now_v7();
+        let first = assign_split(&splits, 42, id).unwrap();
+        let second = assign_split(&splits, 42, id).unwrap();
+        assert_eq!(
+            first, second,
+            "the same seed, splits, and datapoint id must always produce the same assignment"
+        );
+    }
+
+    #[test]
+    fn assign_split_uses_only_configured_names() {
+        let splits = BTreeMap::from([("train".to_string(), 0.8), ("test".to_string(), 0.2)]);
+        for _ in 0..100 {
+            let split = assign_split(&splits, 7, Uuid::now_v7()).unwrap();
+            assert!(
+                split == "train" || split == "test",
+                "assigned split `{split}` must be one of the configured split names"
+            );
+        }
+    }
+
+    #[test]
+    fn assign_split_rejects_empty_splits() {
+        let splits = BTreeMap::new();
+        assert!(assign_split(&splits, 0, Uuid::now_v7()).is_err());
+    }
+
+    #[test]
+    fn assign_split_rejects_non_positive_weight() {
+        let splits = BTreeMap::from([("train".to_string(), 0.0), ("test".to_string(), 1.0)]);
+        assert!(assign_split(&splits, 0, Uuid::now_v7()).is_err());
+    }
+}
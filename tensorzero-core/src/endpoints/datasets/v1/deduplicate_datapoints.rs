@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use axum::Json;
+use axum::extract::{Path, State};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::db::datasets::{DatasetQueries, GetDatapointsParams};
+use crate::endpoints::datasets::{
+    DUPLICATE_TAG_KEY, group_by_embedding_similarity, group_by_exact_hash, validate_dataset_name,
+};
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::types::{
+    DeduplicateDatapointsRequest, DeduplicateDatapointsResponse, DeduplicationAction,
+    DeduplicationStrategy, DuplicateGroup,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct DeduplicateDatapointsPathParams {
+    pub dataset_name: String,
+}
+
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "datasets.v1.deduplicate_datapoints", skip(app_state, request))]
+pub async fn deduplicate_datapoints_handler(
+    State(app_state): AppState,
+    Path(path_params): Path<DeduplicateDatapointsPathParams>,
+    StructuredJson(request): StructuredJson<DeduplicateDatapointsRequest>,
+) -> Result<Json<DeduplicateDatapointsResponse>, Error> {
+    let response = deduplicate_datapoints(
+        &app_state.clickhouse_connection_info,
+        &path_params.dataset_name,
+        request,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// Business logic for detecting and collapsing duplicate datapoints in a dataset.
+///
+/// Within each duplicate group, the first datapoint returned by `get_datapoints` is kept and
+/// every other datapoint is either tagged with [`DUPLICATE_TAG_KEY`] or staled, depending on
+/// `request.action`.
+///
+/// Returns a report of the duplicate groups that were found, or an error if the dataset name
+/// is invalid or (for `EmbeddingSimilarity`) an embedding is missing for one of the
+/// dataset's datapoints.
+pub async fn deduplicate_datapoints(
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    request: DeduplicateDatapointsRequest,
+) -> Result<DeduplicateDatapointsResponse, Error> {
+    validate_dataset_name(dataset_name)?;
+
+    let mut datapoints = clickhouse
+        .get_datapoints(&GetDatapointsParams {
+            dataset_name: Some(dataset_name.to_string()),
+            function_name: request.function_name,
+            ids: None,
+            limit: u32::MAX,
+            offset: 0,
+            allow_stale: false,
+            filter: None,
+            order_by: None,
+            search_query_experimental: None,
+        })
+        .await?;
+
+    if datapoints.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!("Dataset `{dataset_name}` has no datapoints to deduplicate"),
+        }));
+    }
+
+    let groups = match &request.strategy {
+        DeduplicationStrategy::ExactHash => {
+            let inputs: Vec<_> = datapoints
+                .iter()
+                .map(|datapoint| (datapoint.id(), datapoint.input()))
+                .collect();
+            group_by_exact_hash(&inputs)
+        }
+        DeduplicationStrategy::EmbeddingSimilarity {
+            embeddings,
+            threshold,
+        } => {
+            let dataset_ids: HashSet<_> = datapoints.iter().map(|d| d.id()).collect();
+            let embeddings: Vec<_> = embeddings
+                .iter()
+                .filter(|e| dataset_ids.contains(&e.datapoint_id))
+                .map(|e| (e.datapoint_id, e.embedding.as_slice()))
+                .collect();
+            group_by_embedding_similarity(&embeddings, *threshold)
+        }
+    };
+
+    let mut duplicate_of = std::collections::HashMap::new();
+    let mut response_groups = Vec::with_capacity(groups.len());
+    for group in groups {
+        let Some((kept_id, duplicate_ids)) = group.split_first() else {
+            continue;
+        };
+        for duplicate_id in duplicate_ids {
+            duplicate_of.insert(*duplicate_id, *kept_id);
+        }
+        response_groups.push(DuplicateGroup {
+            kept_id: *kept_id,
+            duplicate_ids: duplicate_ids.to_vec(),
+        });
+    }
+
+    let num_duplicates = duplicate_of.len() as u32;
+
+    match request.action {
+        DeduplicationAction::Tag => {
+            for datapoint in &mut datapoints {
+                if let Some(kept_id) = duplicate_of.get(&datapoint.id()) {
+                    datapoint.set_tag(DUPLICATE_TAG_KEY.to_string(), kept_id.to_string());
+                }
+            }
+            clickhouse.insert_datapoints(&datapoints).await?;
+        }
+        DeduplicationAction::Delete => {
+            if !duplicate_of.is_empty() {
+                let ids: Vec<_> = duplicate_of.keys().copied().collect();
+                clickhouse
+                    .delete_datapoints(dataset_name, Some(ids.as_slice()))
+                    .await?;
+            }
+        }
+    }
+
+    Ok(DeduplicateDatapointsResponse {
+        groups: response_groups,
+        num_duplicates,
+    })
+}
@@ -0,0 +1,104 @@
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::db::datasets::{DatasetQueries, GetDatapointsParams};
+use crate::endpoints::datasets::validate_dataset_name;
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::types::{DatasetFileFormat, ExportDatasetRequest};
+
+#[derive(Debug, Deserialize)]
+pub struct ExportDatasetPathParams {
+    pub dataset_name: String,
+}
+
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "datasets.v1.export_dataset", skip(app_state, request))]
+pub async fn export_dataset_handler(
+    State(app_state): AppState,
+    Path(path_params): Path<ExportDatasetPathParams>,
+    StructuredJson(request): StructuredJson<ExportDatasetRequest>,
+) -> Result<Response, Error> {
+    let format = request.format;
+    let body = export_dataset(
+        &app_state.clickhouse_connection_info,
+        &path_params.dataset_name,
+        request,
+    )
+    .await?;
+
+    let (content_type, extension) = match format {
+        DatasetFileFormat::Jsonl => ("application/x-ndjson", "jsonl"),
+        DatasetFileFormat::Parquet => ("application/vnd.apache.parquet", "parquet"),
+    };
+    Ok((
+        [
+            (CONTENT_TYPE, content_type.to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}.{extension}\"",
+                    path_params.dataset_name
+                ),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Business logic for exporting a dataset's datapoints as a downloadable file.
+///
+/// Datapoints are exported in the same wire representation used by
+/// `GET /v1/datasets/{dataset_name}/get_datapoints`, one per line for `jsonl`.
+///
+/// Returns an error if the dataset name is invalid, or if `Parquet` is requested (not yet
+/// implemented; see [`DatasetFileFormat::Parquet`]).
+pub async fn export_dataset(
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    request: ExportDatasetRequest,
+) -> Result<Vec<u8>, Error> {
+    validate_dataset_name(dataset_name)?;
+
+    match request.format {
+        DatasetFileFormat::Jsonl => {}
+        DatasetFileFormat::Parquet => {
+            return Err(ErrorDetails::NotImplemented {
+                message: "Parquet dataset export is not yet implemented; use `jsonl`".to_string(),
+            }
+            .into());
+        }
+    }
+
+    let datapoints = clickhouse
+        .get_datapoints(&GetDatapointsParams {
+            dataset_name: Some(dataset_name.to_string()),
+            function_name: request.function_name,
+            ids: None,
+            limit: u32::MAX,
+            offset: 0,
+            allow_stale: false,
+            filter: None,
+            order_by: None,
+            search_query_experimental: None,
+        })
+        .await?;
+
+    let mut body = Vec::new();
+    for datapoint in datapoints {
+        let datapoint = datapoint.into_datapoint()?;
+        serde_json::to_writer(&mut body, &datapoint).map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!("Failed to serialize datapoint for export: {e}"),
+            })
+        })?;
+        body.push(b'\n');
+    }
+
+    Ok(body)
+}
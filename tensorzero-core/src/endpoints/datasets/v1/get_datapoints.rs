@@ -3,12 +3,15 @@ use axum::extract::{Path, State};
 use tracing::instrument;
 
 use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::db::clickhouse::query_builder::TagComparisonOperator;
 use crate::db::datasets::{DatasetQueries, GetDatapointsParams};
-use crate::endpoints::datasets::validate_dataset_name;
+use crate::endpoints::datasets::{SPLIT_TAG_KEY, validate_dataset_name};
 use crate::error::Error;
 use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
 
-use super::types::{GetDatapointsRequest, GetDatapointsResponse, ListDatapointsRequest};
+use super::types::{
+    DatapointFilter, GetDatapointsRequest, GetDatapointsResponse, ListDatapointsRequest, TagFilter,
+};
 
 const DEFAULT_LIMIT: u32 = 20;
 const DEFAULT_OFFSET: u32 = 0;
@@ -90,6 +93,23 @@ pub async fn list_datapoints(
         DEFAULT_LIMIT
     };
 
+    let filter = match (request.filter, request.split) {
+        (filter, None) => filter,
+        (filter, Some(split)) => {
+            let split_filter = DatapointFilter::Tag(TagFilter {
+                key: SPLIT_TAG_KEY.to_string(),
+                value: split,
+                comparison_operator: TagComparisonOperator::Equal,
+            });
+            Some(match filter {
+                Some(filter) => DatapointFilter::And {
+                    children: vec![filter, split_filter],
+                },
+                None => split_filter,
+            })
+        }
+    };
+
     let params = GetDatapointsParams {
         dataset_name: Some(dataset_name),
         function_name: request.function_name,
@@ -97,7 +117,7 @@ pub async fn list_datapoints(
         limit,
         offset: request.offset.unwrap_or(DEFAULT_OFFSET),
         allow_stale: DEFAULT_ALLOW_STALE,
-        filter: request.filter,
+        filter,
         order_by: request.order_by,
         search_query_experimental: request.search_query_experimental,
     };
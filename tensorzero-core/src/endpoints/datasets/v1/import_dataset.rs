@@ -0,0 +1,146 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use futures::future::try_join_all;
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::config::Config;
+use crate::db::datasets::DatasetQueries;
+use crate::db::stored_datapoint::StoredDatapoint;
+use crate::endpoints::datasets::{Datapoint, validate_dataset_name};
+use crate::error::{Error, ErrorDetails};
+use crate::http::TensorzeroHttpClient;
+use crate::inference::types::FetchContext;
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::types::{
+    CreateChatDatapointRequest, CreateJsonDatapointRequest, DatasetFileFormat,
+    ImportDatasetRequest, ImportDatasetResponse, JsonDatapointOutputUpdate,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ImportDatasetPathParams {
+    pub dataset_name: String,
+}
+
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "datasets.v1.import_dataset", skip(app_state, request))]
+pub async fn import_dataset_handler(
+    State(app_state): AppState,
+    Path(path_params): Path<ImportDatasetPathParams>,
+    StructuredJson(request): StructuredJson<ImportDatasetRequest>,
+) -> Result<Json<ImportDatasetResponse>, Error> {
+    let response = import_dataset(
+        &app_state.config,
+        &app_state.http_client,
+        &app_state.clickhouse_connection_info,
+        &path_params.dataset_name,
+        request,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// Business logic for bulk-importing datapoints into a dataset from a previously exported
+/// file. Reuses the same per-datapoint validation as `create_datapoints` (schema validation
+/// against the function's current config), so an import can never insert a datapoint that
+/// wouldn't be accepted individually.
+///
+/// Returns an error if the dataset name is invalid, any line fails to parse or validate, or
+/// if `Parquet` is requested (not yet implemented; see [`DatasetFileFormat::Parquet`]).
+pub async fn import_dataset(
+    config: &Config,
+    http_client: &TensorzeroHttpClient,
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    request: ImportDatasetRequest,
+) -> Result<ImportDatasetResponse, Error> {
+    validate_dataset_name(dataset_name)?;
+
+    let lines = match request.format {
+        DatasetFileFormat::Jsonl => request.data,
+        DatasetFileFormat::Parquet => {
+            return Err(ErrorDetails::NotImplemented {
+                message: "Parquet dataset import is not yet implemented; use `jsonl`".to_string(),
+            }
+            .into());
+        }
+    };
+
+    let datapoints: Vec<Datapoint> = lines
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Failed to parse datapoint from import file: {e}"),
+                })
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    if datapoints.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "Import file contains no datapoints".to_string(),
+        }));
+    }
+
+    let fetch_context = FetchContext {
+        client: http_client,
+        object_store_info: &config.object_store_info,
+    };
+    let insert_futures = datapoints.into_iter().map(|datapoint| async {
+        let result: Result<StoredDatapoint, Error> = match datapoint {
+            Datapoint::Chat(chat) => {
+                let insert = CreateChatDatapointRequest {
+                    function_name: chat.function_name,
+                    episode_id: chat.episode_id,
+                    input: chat.input,
+                    output: chat.output,
+                    dynamic_tool_params: chat.tool_params,
+                    tags: chat.tags,
+                    name: chat.name,
+                }
+                .into_database_insert(config, &fetch_context, dataset_name)
+                .await
+                .map_err(|e| {
+                    Error::new(ErrorDetails::InvalidRequest {
+                        message: format!("Failed to import chat datapoint: {e}"),
+                    })
+                })?;
+                Ok(StoredDatapoint::Chat(insert))
+            }
+            Datapoint::Json(json) => {
+                let insert = CreateJsonDatapointRequest {
+                    function_name: json.function_name,
+                    episode_id: json.episode_id,
+                    input: json.input,
+                    output: json
+                        .output
+                        .map(|output| JsonDatapointOutputUpdate { raw: output.raw }),
+                    output_schema: Some(json.output_schema),
+                    tags: json.tags,
+                    name: json.name,
+                }
+                .into_database_insert(config, &fetch_context, dataset_name)
+                .await
+                .map_err(|e| {
+                    Error::new(ErrorDetails::InvalidRequest {
+                        message: format!("Failed to import json datapoint: {e}"),
+                    })
+                })?;
+                Ok(StoredDatapoint::Json(insert))
+            }
+        };
+        result
+    });
+    let datapoints_to_insert: Vec<StoredDatapoint> = try_join_all(insert_futures).await?;
+    let ids = datapoints_to_insert
+        .iter()
+        .map(StoredDatapoint::id)
+        .collect::<Vec<_>>();
+
+    clickhouse.insert_datapoints(&datapoints_to_insert).await?;
+
+    Ok(ImportDatasetResponse { ids })
+}
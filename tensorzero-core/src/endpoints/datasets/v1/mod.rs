@@ -1,23 +1,33 @@
 mod conversion_utils;
 mod create_datapoints;
 mod create_from_inferences;
+mod deduplicate_datapoints;
 mod delete_datapoints;
+mod export_dataset;
 mod get_datapoints;
+mod import_dataset;
 mod list_datasets;
+mod split_dataset;
+mod sync_dataset;
 mod update_datapoints;
 
 pub mod types;
 
 pub use create_datapoints::{create_datapoints, create_datapoints_handler};
 pub use create_from_inferences::{create_from_inferences, create_from_inferences_handler};
+pub use deduplicate_datapoints::{deduplicate_datapoints, deduplicate_datapoints_handler};
 pub use delete_datapoints::{
     delete_datapoints, delete_datapoints_handler, delete_dataset, delete_dataset_handler,
 };
+pub use export_dataset::{export_dataset, export_dataset_handler};
 pub use get_datapoints::{
     get_datapoints, get_datapoints_by_dataset_handler, get_datapoints_handler, list_datapoints,
     list_datapoints_handler,
 };
+pub use import_dataset::{import_dataset, import_dataset_handler};
 pub use list_datasets::{list_datasets, list_datasets_handler};
+pub use split_dataset::{split_dataset, split_dataset_handler};
+pub use sync_dataset::{sync_dataset, sync_dataset_handler};
 pub use update_datapoints::{
     update_datapoints, update_datapoints_handler, update_datapoints_metadata,
     update_datapoints_metadata_handler,
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use axum::Json;
+use axum::extract::{Path, State};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::db::datasets::{DatasetQueries, GetDatapointsParams};
+use crate::endpoints::datasets::{SPLIT_TAG_KEY, assign_split, validate_dataset_name};
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::types::{SplitDatasetRequest, SplitDatasetResponse};
+
+#[derive(Debug, Deserialize)]
+pub struct SplitDatasetPathParams {
+    pub dataset_name: String,
+}
+
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "datasets.v1.split_dataset", skip(app_state, request))]
+pub async fn split_dataset_handler(
+    State(app_state): AppState,
+    Path(path_params): Path<SplitDatasetPathParams>,
+    StructuredJson(request): StructuredJson<SplitDatasetRequest>,
+) -> Result<Json<SplitDatasetResponse>, Error> {
+    let response = split_dataset(
+        &app_state.clickhouse_connection_info,
+        &path_params.dataset_name,
+        request,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// Business logic for deterministically partitioning a dataset's datapoints into named
+/// splits. Each datapoint's split is recorded as a `tensorzero::split` tag
+/// (see [`crate::endpoints::datasets::split`]) rather than by creating a new datapoint
+/// version, since split assignment is bookkeeping metadata rather than a semantic change
+/// to the datapoint's content.
+///
+/// Returns the number of datapoints assigned to each split, or an error if the dataset
+/// name or `splits` weights are invalid.
+pub async fn split_dataset(
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    request: SplitDatasetRequest,
+) -> Result<SplitDatasetResponse, Error> {
+    validate_dataset_name(dataset_name)?;
+
+    let datapoints = clickhouse
+        .get_datapoints(&GetDatapointsParams {
+            dataset_name: Some(dataset_name.to_string()),
+            function_name: request.function_name,
+            ids: None,
+            limit: u32::MAX,
+            offset: 0,
+            allow_stale: false,
+            filter: None,
+            order_by: None,
+            search_query_experimental: None,
+        })
+        .await?;
+
+    if datapoints.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!("Dataset `{dataset_name}` has no datapoints to split"),
+        }));
+    }
+
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    let mut updated_datapoints = Vec::with_capacity(datapoints.len());
+    for mut datapoint in datapoints {
+        let split = assign_split(&request.splits, request.seed, datapoint.id())?;
+        *counts.entry(split.to_string()).or_insert(0) += 1;
+        datapoint.set_tag(SPLIT_TAG_KEY.to_string(), split.to_string());
+        updated_datapoints.push(datapoint);
+    }
+
+    clickhouse.insert_datapoints(&updated_datapoints).await?;
+
+    Ok(SplitDatasetResponse { counts })
+}
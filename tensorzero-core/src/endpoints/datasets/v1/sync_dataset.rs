@@ -0,0 +1,114 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::config::{Config, ObjectStoreInfo};
+use crate::dataset_sync::jsonl_prefix::JsonlPrefixSource;
+use crate::dataset_sync::{
+    DatasetSyncReport, DatasetSyncSource, sync_dataset as sync_dataset_impl,
+};
+use crate::db::datasets::{DatasetQueries, GetDatapointsParams};
+use crate::endpoints::datasets::DatapointProvenance;
+use crate::error::{Error, ErrorDetails};
+use crate::http::TensorzeroHttpClient;
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::types::{DatasetSyncSourceConfig, SyncDatasetRequest};
+
+#[derive(Debug, Deserialize)]
+pub struct SyncDatasetPathParams {
+    pub dataset_name: String,
+}
+
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "datasets.v1.sync_dataset", skip(app_state, request))]
+pub async fn sync_dataset_handler(
+    State(app_state): AppState,
+    Path(path_params): Path<SyncDatasetPathParams>,
+    StructuredJson(request): StructuredJson<SyncDatasetRequest>,
+) -> Result<Json<DatasetSyncReport>, Error> {
+    let report = sync_dataset(
+        &app_state.config,
+        &app_state.http_client,
+        &app_state.clickhouse_connection_info,
+        &path_params.dataset_name,
+        request,
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+/// Business logic for `POST /v1/datasets/{dataset_name}/sync`. Builds the requested
+/// [`crate::dataset_sync::DatasetSyncSource`], looks up the content hash this dataset last saw
+/// for each `external_id` so the sync can skip unchanged records, and runs
+/// [`crate::dataset_sync::sync_dataset`].
+///
+/// Only sources with the same `dataset_name` are considered when building the "previously
+/// synced" hash map - a datapoint synced into a different dataset with the same `external_id`
+/// is a different record as far as this dataset is concerned.
+pub async fn sync_dataset(
+    config: &Config,
+    http_client: &TensorzeroHttpClient,
+    clickhouse: &impl DatasetQueries,
+    dataset_name: &str,
+    request: SyncDatasetRequest,
+) -> Result<DatasetSyncReport, Error> {
+    let source = build_source(request.source)?;
+
+    let existing_datapoints = clickhouse
+        .get_datapoints(&GetDatapointsParams {
+            dataset_name: Some(dataset_name.to_string()),
+            function_name: None,
+            ids: None,
+            limit: u32::MAX,
+            offset: 0,
+            allow_stale: false,
+            filter: None,
+            order_by: None,
+            search_query_experimental: None,
+        })
+        .await?;
+    let previously_synced_hashes = existing_datapoints
+        .into_iter()
+        .filter_map(|datapoint| match datapoint.provenance() {
+            DatapointProvenance::ExternalSync {
+                source: existing_source,
+                external_id,
+                content_hash,
+            } if existing_source == source.source_name() => Some((external_id, content_hash)),
+            _ => None,
+        })
+        .collect();
+
+    sync_dataset_impl(
+        config,
+        http_client,
+        clickhouse,
+        dataset_name,
+        source.as_ref(),
+        &previously_synced_hashes,
+    )
+    .await
+}
+
+fn build_source(config: DatasetSyncSourceConfig) -> Result<Box<dyn DatasetSyncSource>, Error> {
+    match config {
+        DatasetSyncSourceConfig::JsonlPrefix {
+            storage,
+            prefix,
+            source_name,
+        } => {
+            let object_store = ObjectStoreInfo::new(Some(storage))?.ok_or_else(|| {
+                Error::new(ErrorDetails::Config {
+                    message: "Sync source storage config resolved to no object store".to_string(),
+                })
+            })?;
+            Ok(Box::new(JsonlPrefixSource::new(
+                object_store,
+                &prefix,
+                source_name,
+            )?))
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub use crate::db::clickhouse::query_builder::{
 use crate::db::inferences::InferenceOutputSource;
 use crate::endpoints::datasets::Datapoint;
 use crate::endpoints::stored_inferences::v1::types::ListInferencesRequest;
+use crate::inference::types::storage::StorageKind;
 use crate::inference::types::{ContentBlockChatOutput, Input};
 use crate::serde_util::deserialize_double_option;
 use crate::tool::{DynamicToolParams, ProviderTool, Tool, ToolChoice};
@@ -505,6 +506,12 @@ pub struct ListDatapointsRequest {
     ///   filters, it will perform a full table scan, which may be extremely slow depending
     ///   on the data volume.
     pub search_query_experimental: Option<String>,
+
+    /// Optional split name to filter datapoints by (e.g. `train`, `test`).
+    /// Matches datapoints previously assigned to this split by the
+    /// `POST /v1/datasets/{dataset_name}/split` endpoint. Combined with `filter` (if present)
+    /// using AND.
+    pub split: Option<String>,
 }
 
 /// Request to get specific datapoints by their IDs.
@@ -591,7 +598,7 @@ pub struct CreateDatapointsRequest {
 
 /// A tagged request to create a single datapoint.
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
-#[derive(Debug, JsonSchema, Serialize, TensorZeroDeserialize)]
+#[derive(Clone, Debug, JsonSchema, Serialize, TensorZeroDeserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 #[export_schema]
@@ -610,7 +617,7 @@ pub enum CreateDatapointRequest {
 
 /// A request to create a chat datapoint.
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[export_schema]
 #[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
 pub struct CreateChatDatapointRequest {
@@ -644,7 +651,7 @@ pub struct CreateChatDatapointRequest {
 
 /// A request to create a JSON datapoint.
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[export_schema]
 #[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
 pub struct CreateJsonDatapointRequest {
@@ -737,6 +744,226 @@ pub struct ListDatasetsResponse {
     pub datasets: Vec<DatasetMetadata>,
 }
 
+/// Request to deterministically partition a dataset's datapoints into named splits
+/// (e.g. `train` / `test`). Used by the `POST /v1/datasets/{dataset_name}/split` endpoint.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct SplitDatasetRequest {
+    /// Split names and their relative weights (e.g. `{"train": 0.8, "test": 0.2}`).
+    /// Weights need not sum to 1; they are normalized. Must be non-empty.
+    pub splits: std::collections::BTreeMap<String, f64>,
+
+    /// Seed for the deterministic hash used to assign datapoints to splits.
+    /// Re-running with the same `seed` and `splits` reproduces the same assignment.
+    pub seed: u64,
+
+    /// Optional function name to restrict the split to.
+    /// If provided, only datapoints from this function are assigned a split.
+    pub function_name: Option<String>,
+}
+
+/// Response containing the number of datapoints assigned to each split.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct SplitDatasetResponse {
+    /// The number of datapoints assigned to each split, keyed by split name.
+    pub counts: HashMap<String, u32>,
+}
+
+/// Strategy for identifying duplicate datapoints within a dataset.
+/// Used by [`DeduplicateDatapointsRequest`].
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, JsonSchema, Serialize, TensorZeroDeserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(
+    feature = "ts-bindings",
+    ts(export, tag = "type", rename_all = "snake_case")
+)]
+#[export_schema]
+pub enum DeduplicationStrategy {
+    /// Exact-duplicate detection: two datapoints are duplicates if their input is
+    /// byte-for-byte identical once canonicalized.
+    ExactHash,
+    /// Near-duplicate detection using caller-supplied embeddings of each datapoint's input
+    /// (e.g. computed via `POST /embeddings`). Two datapoints are duplicates if the cosine
+    /// similarity of their embeddings is at least `threshold`. Datapoints with no embedding
+    /// provided are left untouched.
+    EmbeddingSimilarity {
+        embeddings: Vec<DatapointEmbedding>,
+        threshold: f32,
+    },
+}
+
+/// A single datapoint's embedding, used by [`DeduplicationStrategy::EmbeddingSimilarity`].
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct DatapointEmbedding {
+    pub datapoint_id: Uuid,
+    pub embedding: Vec<f32>,
+}
+
+/// What to do with datapoints identified as duplicates.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub enum DeduplicationAction {
+    /// Tag duplicate datapoints with a `tensorzero::duplicate_of` tag rather than removing
+    /// them, so callers can filter them out without losing the data.
+    #[default]
+    Tag,
+    /// Delete (stale) duplicate datapoints, keeping the first-seen datapoint in each group.
+    Delete,
+}
+
+/// Request to detect and collapse duplicate or near-duplicate datapoints within a dataset.
+/// Used by the `POST /v1/datasets/{dataset_name}/deduplicate` endpoint.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct DeduplicateDatapointsRequest {
+    /// How to identify duplicates.
+    pub strategy: DeduplicationStrategy,
+
+    /// What to do with the datapoints identified as duplicates. Defaults to `tag`.
+    #[serde(default)]
+    pub action: DeduplicationAction,
+
+    /// Optional function name to restrict deduplication to.
+    /// If provided, only datapoints from this function are considered.
+    pub function_name: Option<String>,
+}
+
+/// A group of datapoints found to be duplicates of one another.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct DuplicateGroup {
+    /// The datapoint that was kept.
+    pub kept_id: Uuid,
+    /// The datapoints found to be duplicates of `kept_id`.
+    pub duplicate_ids: Vec<Uuid>,
+}
+
+/// Response containing a report of the duplicate datapoints that were found and collapsed.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct DeduplicateDatapointsResponse {
+    /// The duplicate groups that were found, keyed implicitly by the kept datapoint.
+    pub groups: Vec<DuplicateGroup>,
+    /// The total number of duplicate datapoints collapsed across all groups.
+    pub num_duplicates: u32,
+}
+
+/// File format for bulk dataset export/import.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub enum DatasetFileFormat {
+    /// One JSON-serialized datapoint per line. Supported for both export and import.
+    #[default]
+    Jsonl,
+    /// Columnar Parquet format. Not yet implemented for either export or import.
+    Parquet,
+}
+
+/// Request to export a dataset's datapoints as a downloadable file.
+/// Used by the `POST /v1/datasets/{dataset_name}/export` endpoint.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct ExportDatasetRequest {
+    /// The file format to export as. Defaults to `jsonl`.
+    #[serde(default)]
+    pub format: DatasetFileFormat,
+
+    /// Optional function name to restrict the export to.
+    /// If provided, only datapoints from this function are exported.
+    #[serde(default)]
+    pub function_name: Option<String>,
+}
+
+/// Request to bulk-import datapoints from a previously exported file.
+/// Used by the `POST /v1/datasets/{dataset_name}/import` endpoint.
+///
+/// Each imported datapoint is validated against its function's current config (input and
+/// output schemas) exactly as if it had been created individually via
+/// `POST /v1/datasets/{dataset_name}/datapoints`; imported datapoints that fail validation
+/// cause the whole import to fail rather than being silently dropped, so that a partially
+/// invalid file doesn't leave the dataset in an inconsistent state.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct ImportDatasetRequest {
+    /// The file format `data` is encoded in.
+    pub format: DatasetFileFormat,
+
+    /// The file contents to import, exactly as produced by the export endpoint.
+    pub data: String,
+}
+
+/// Response returned after successfully importing datapoints into a dataset.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct ImportDatasetResponse {
+    /// IDs of the datapoints that were created.
+    pub ids: Vec<Uuid>,
+}
+
+/// Where a `POST /v1/datasets/{dataset_name}/sync` request should pull records from.
+///
+/// A dedicated type rather than reusing `StorageKind` directly, since a sync source also needs
+/// a prefix to scan within the store and a stable name to record on the datapoints it produces
+/// (see `DatapointProvenance::ExternalSync`).
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub enum DatasetSyncSourceConfig {
+    /// Every `.jsonl` object under `prefix` in `storage`, one datapoint per line.
+    JsonlPrefix {
+        storage: StorageKind,
+        prefix: String,
+        /// A stable name for this source, recorded on every datapoint it produces. Two syncs
+        /// intended to update the same records must use the same `source_name`, or the later
+        /// sync won't recognize the earlier one's datapoints and will insert duplicates.
+        source_name: String,
+    },
+}
+
+/// Request to sync a dataset's datapoints from an external source.
+/// Used by the `POST /v1/datasets/{dataset_name}/sync` endpoint.
+///
+/// A sync is incremental: records whose content hasn't changed since the last sync (as
+/// determined by `DatapointProvenance::ExternalSync`) are skipped, so repeated syncs against an
+/// unchanged source are a no-op.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct SyncDatasetRequest {
+    pub source: DatasetSyncSourceConfig,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,7 +6,7 @@ use tokio_util::task::TaskTracker;
 use tracing::instrument;
 
 use crate::{
-    cache::CacheParamsOptions,
+    cache::{CacheBackend, CacheParamsOptions},
     config::Config,
     db::{clickhouse::ClickHouseConnectionInfo, postgres::PostgresConnectionInfo},
     embeddings::{Embedding, EmbeddingEncodingFormat, EmbeddingInput, EmbeddingRequest},
@@ -54,6 +54,7 @@ pub async fn embeddings(
     postgres_connection_info: PostgresConnectionInfo,
     deferred_tasks: TaskTracker,
     rate_limiting_manager: std::sync::Arc<RateLimitingManager>,
+    hot_cache: Arc<dyn CacheBackend>,
     params: EmbeddingsParams,
     api_key_ext: Option<Extension<RequestApiKeyExtension>>,
 ) -> Result<EmbeddingResponse, Error> {
@@ -96,6 +97,7 @@ pub async fn embeddings(
         cache_options: (params.cache_options, dryrun).into(),
         clickhouse_connection_info: clickhouse_connection_info.clone(),
         postgres_connection_info: postgres_connection_info.clone(),
+        hot_cache,
         tags: tags.clone(),
         rate_limiting_manager,
         otlp_config: config.gateway.export.otlp.clone(),
@@ -210,6 +212,11 @@ mod tests {
             PostgresConnectionInfo::Disabled,
             tokio_util::task::TaskTracker::new(),
             Arc::new(RateLimitingManager::new_dummy()),
+            Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             params,
             None,
         )
@@ -248,6 +255,11 @@ mod tests {
             PostgresConnectionInfo::Disabled,
             tokio_util::task::TaskTracker::new(),
             Arc::new(RateLimitingManager::new_dummy()),
+            Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             params,
             None,
         )
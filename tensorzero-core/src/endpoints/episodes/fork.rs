@@ -0,0 +1,133 @@
+//! Episode forking.
+//!
+//! Forking creates a new episode linked back to a parent episode, so that an agent can
+//! explore alternative branches from the same point in a conversation and compare them
+//! later (e.g. via [`crate::db::episode_fork::EpisodeForkQueries::get_episode_fork`]).
+//!
+//! TensorZero doesn't store conversation history server-side - callers already send the
+//! full `input` on every inference request. Forking therefore doesn't replay or copy any
+//! history into the new episode; it only records the parent/child link (and, optionally,
+//! the inference the fork was taken after) so that branches created from a common episode
+//! can be discovered. Callers still need to send the desired history themselves on
+//! subsequent inference requests made with the new `episode_id`.
+
+use axum::extract::{Path, State};
+use axum::{Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::db::episode_fork::{EpisodeFork, EpisodeForkQueries};
+use crate::error::Error;
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct ForkEpisodeParams {
+    /// The last inference in the parent episode that this fork should be recorded as
+    /// inheriting history up to. Purely informational lineage metadata - TensorZero
+    /// doesn't validate that this inference belongs to the parent episode, and doesn't
+    /// use it to filter or replay any history.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fork_point_inference_id: Option<Uuid>,
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ForkEpisodeResponse {
+    pub episode_id: Uuid,
+    pub parent_episode_id: Uuid,
+}
+
+/// HTTP handler for forking an episode.
+#[debug_handler(state = AppStateData)]
+#[instrument(
+    name = "fork_episode_handler",
+    skip_all,
+    fields(
+        parent_episode_id = %parent_episode_id,
+    )
+)]
+pub async fn fork_episode_handler(
+    State(app_state): AppState,
+    Path(parent_episode_id): Path<Uuid>,
+    StructuredJson(params): StructuredJson<ForkEpisodeParams>,
+) -> Result<Json<ForkEpisodeResponse>, Error> {
+    let response = fork_episode(
+        &app_state.postgres_connection_info,
+        parent_episode_id,
+        params,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// Core business logic for forking an episode.
+pub async fn fork_episode(
+    postgres: &impl EpisodeForkQueries,
+    parent_episode_id: Uuid,
+    params: ForkEpisodeParams,
+) -> Result<ForkEpisodeResponse, Error> {
+    let episode_id = Uuid::now_v7();
+    postgres
+        .create_episode_fork(EpisodeFork {
+            episode_id,
+            parent_episode_id,
+            fork_point_inference_id: params.fork_point_inference_id,
+        })
+        .await?;
+    Ok(ForkEpisodeResponse {
+        episode_id,
+        parent_episode_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::episode_fork::MockEpisodeForkQueries;
+
+    #[tokio::test]
+    async fn test_fork_episode_records_lineage() {
+        let mut mock_postgres = MockEpisodeForkQueries::new();
+
+        let parent_episode_id = Uuid::now_v7();
+        let fork_point_inference_id = Uuid::now_v7();
+
+        mock_postgres
+            .expect_create_episode_fork()
+            .withf(move |fork| {
+                assert_eq!(
+                    fork.parent_episode_id, parent_episode_id,
+                    "fork should be recorded against the requested parent episode"
+                );
+                assert_eq!(
+                    fork.fork_point_inference_id,
+                    Some(fork_point_inference_id),
+                    "fork point should be passed through unchanged"
+                );
+                true
+            })
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let params = ForkEpisodeParams {
+            fork_point_inference_id: Some(fork_point_inference_id),
+        };
+
+        let result = fork_episode(&mock_postgres, parent_episode_id, params)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.parent_episode_id, parent_episode_id,
+            "response should echo back the parent episode id"
+        );
+        assert_ne!(
+            result.episode_id, parent_episode_id,
+            "forked episode should get a freshly generated id"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    db::cost::CostQueries,
+    error::Error,
+    utils::gateway::{AppState, AppStateData},
+};
+use axum::{
+    Json, debug_handler,
+    extract::{Path, State},
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct GetEpisodeCostResponse {
+    pub cost_usd: f64,
+}
+
+/// HTTP handler for getting the total cost of an episode.
+///
+/// Cost is only tracked in ClickHouse (it's derived from `ModelInference.cost_usd`,
+/// which requires the served provider to have `pricing` configured), so unlike the
+/// sibling inference-count endpoint this doesn't have a Postgres-backed path.
+#[debug_handler(state = AppStateData)]
+#[instrument(
+    name = "get_episode_cost_handler",
+    skip_all,
+    fields(
+        episode_id = %episode_id,
+    )
+)]
+pub async fn get_episode_cost_handler(
+    State(app_state): AppState,
+    Path(episode_id): Path<Uuid>,
+) -> Result<Json<GetEpisodeCostResponse>, Error> {
+    let stats = get_episode_cost(&app_state.clickhouse_connection_info, episode_id).await?;
+    Ok(Json(stats))
+}
+
+/// Core business logic for getting the total cost of an episode.
+pub async fn get_episode_cost(
+    clickhouse: &impl CostQueries,
+    episode_id: Uuid,
+) -> Result<GetEpisodeCostResponse, Error> {
+    let cost_usd = clickhouse.get_episode_cost_usd(episode_id).await?;
+
+    Ok(GetEpisodeCostResponse { cost_usd })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::cost::MockCostQueries;
+
+    #[tokio::test]
+    async fn test_get_episode_cost_calls_clickhouse() {
+        let mut mock_clickhouse = MockCostQueries::new();
+
+        let episode_id = Uuid::now_v7();
+        let expected_cost_usd = 1.25;
+
+        mock_clickhouse
+            .expect_get_episode_cost_usd()
+            .withf(move |id| *id == episode_id)
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(expected_cost_usd) }));
+
+        let result = get_episode_cost(&mock_clickhouse, episode_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.cost_usd, expected_cost_usd);
+    }
+}
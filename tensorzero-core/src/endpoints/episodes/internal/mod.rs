@@ -1,6 +1,8 @@
+mod get_episode_cost;
 mod get_episode_inference_count;
 mod list_episodes;
 
+pub use get_episode_cost::*;
 pub use get_episode_inference_count::*;
 pub use list_episodes::{
     ListEpisodesResponse, list_episodes_handler, query_episode_table_bounds_handler,
@@ -1 +1,2 @@
+pub mod fork;
 pub mod internal;
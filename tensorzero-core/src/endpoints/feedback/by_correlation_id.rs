@@ -0,0 +1,141 @@
+//! Feedback keyed by a caller-provided correlation id (e.g. an order id) instead of an
+//! inference or episode id, exposed at `POST /feedback/by_correlation_id`.
+//!
+//! Some outcomes (a purchase, a support ticket resolution) are only known to a system that
+//! never saw the inference id and may report them long after the inference happened. As long
+//! as the correlation id was attached to the inference as a tag at inference time, this
+//! endpoint joins the feedback to that inference by looking the tag up in ClickHouse, then
+//! delegates to the regular [`feedback`] flow.
+
+use std::collections::HashMap;
+
+use axum::extract::State;
+use axum::{Extension, Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tensorzero_auth::middleware::RequestApiKeyExtension;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::db::clickhouse::query_builder::{
+    InferenceFilter, OrderBy, OrderByTerm, OrderDirection, TagComparisonOperator, TagFilter,
+};
+use crate::db::inferences::{InferenceOutputSource, InferenceQueries, ListInferencesParams};
+use crate::error::{Error, ErrorDetails};
+use crate::stored_inference::StoredInferenceDatabase;
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+use super::{FeedbackResponse, Params, feedback};
+
+/// Tag key under which a caller-provided correlation id is expected to have been stored on
+/// the target inference, unless `correlation_id_key` overrides it.
+pub const DEFAULT_CORRELATION_ID_TAG_KEY: &str = "correlation_id";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CorrelationIdParams {
+    /// The caller-provided correlation id (e.g. an order id) that was attached to the target
+    /// inference as a tag at inference time.
+    pub correlation_id: String,
+    /// The tag key that `correlation_id` was stored under at inference time.
+    /// Defaults to `DEFAULT_CORRELATION_ID_TAG_KEY` if not provided.
+    pub correlation_id_key: Option<String>,
+    // the name of the Metric to provide feedback for (this can always also be "comment" or "demonstration")
+    pub metric_name: String,
+    // the value of the feedback being provided
+    pub value: Value,
+    // if true, the feedback will be internal and validation of tags will be skipped
+    #[serde(default)]
+    pub internal: bool,
+    // the tags to add to the feedback
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    // if true, the feedback will not be stored
+    pub dryrun: Option<bool>,
+}
+
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "feedback_by_correlation_id", skip_all, fields(metric_name = %params.metric_name))]
+pub async fn feedback_by_correlation_id_handler(
+    State(app_state): AppState,
+    api_key_ext: Option<Extension<RequestApiKeyExtension>>,
+    StructuredJson(params): StructuredJson<CorrelationIdParams>,
+) -> Result<Json<FeedbackResponse>, Error> {
+    Ok(Json(
+        feedback_by_correlation_id(app_state, params, api_key_ext).await?,
+    ))
+}
+
+// Helper function to avoid requiring axum types in the client
+pub async fn feedback_by_correlation_id(
+    app_state: AppStateData,
+    params: CorrelationIdParams,
+    api_key_ext: Option<Extension<RequestApiKeyExtension>>,
+) -> Result<FeedbackResponse, Error> {
+    let tag_key = params
+        .correlation_id_key
+        .as_deref()
+        .unwrap_or(DEFAULT_CORRELATION_ID_TAG_KEY);
+    let inference_id = resolve_correlation_id(
+        &app_state.clickhouse_connection_info,
+        &app_state.config,
+        &params.correlation_id,
+        tag_key,
+    )
+    .await?;
+
+    feedback(
+        app_state,
+        Params {
+            episode_id: None,
+            inference_id: Some(inference_id),
+            metric_name: params.metric_name,
+            value: params.value,
+            internal: params.internal,
+            tags: params.tags,
+            dryrun: params.dryrun,
+        },
+        api_key_ext,
+    )
+    .await
+}
+
+/// Resolves a caller-provided correlation id to the inference it was attached to as a tag.
+///
+/// If multiple inferences carry the same tag (e.g. the caller retried inference with the same
+/// correlation id), the most recently created one is used.
+async fn resolve_correlation_id(
+    clickhouse: &ClickHouseConnectionInfo,
+    config: &Config,
+    correlation_id: &str,
+    tag_key: &str,
+) -> Result<Uuid, Error> {
+    let filter = InferenceFilter::Tag(TagFilter {
+        key: tag_key.to_string(),
+        value: correlation_id.to_string(),
+        comparison_operator: TagComparisonOperator::Equal,
+    });
+    let order_by = [OrderBy {
+        term: OrderByTerm::Timestamp,
+        direction: OrderDirection::Desc,
+    }];
+    let params = ListInferencesParams {
+        filters: Some(&filter),
+        output_source: InferenceOutputSource::None,
+        limit: 1,
+        order_by: Some(&order_by),
+        ..Default::default()
+    };
+    let inferences = clickhouse.list_inferences(config, &params).await?;
+    inferences
+        .first()
+        .map(StoredInferenceDatabase::id)
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::CorrelationIdNotFound {
+                correlation_id: correlation_id.to_string(),
+                tag_key: tag_key.to_string(),
+            })
+        })
+}
@@ -7,7 +7,8 @@ use tracing::instrument;
 
 use crate::db::TimeWindow;
 use crate::db::feedback::{CumulativeFeedbackTimeSeriesPoint, FeedbackQueries};
-use crate::error::Error;
+use crate::error::{Error, ErrorDetails};
+use crate::statistics_util::add_laplace_noise;
 use crate::utils::gateway::{AppState, AppStateData};
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +19,13 @@ pub struct GetCumulativeFeedbackTimeseriesParams {
     pub variant_names: Option<String>,
     pub time_window: TimeWindow,
     pub max_periods: u32,
+    /// If set, applies the Laplace mechanism with this privacy budget to each
+    /// point's `mean` before it is returned, so the response can be shared
+    /// externally without exposing individual feedback values. Opt-in: omit
+    /// this field to get exact (non-private) aggregates. Smaller values give
+    /// stronger privacy at the cost of noisier means.
+    #[serde(default)]
+    pub dp_epsilon: Option<f64>,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -57,6 +65,7 @@ pub async fn get_cumulative_feedback_timeseries_handler(
         variant_names,
         params.time_window,
         params.max_periods,
+        params.dp_epsilon,
     )
     .await?;
     Ok(Json(response))
@@ -70,8 +79,17 @@ pub async fn get_cumulative_feedback_timeseries(
     variant_names: Option<Vec<String>>,
     time_window: TimeWindow,
     max_periods: u32,
+    dp_epsilon: Option<f64>,
 ) -> Result<GetCumulativeFeedbackTimeseriesResponse, Error> {
-    let timeseries = clickhouse
+    if let Some(epsilon) = dp_epsilon
+        && epsilon <= 0.0
+    {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!("`dp_epsilon` must be positive, got {epsilon}"),
+        }));
+    }
+
+    let mut timeseries = clickhouse
         .get_cumulative_feedback_timeseries(
             function_name,
             metric_name,
@@ -81,6 +99,19 @@ pub async fn get_cumulative_feedback_timeseries(
         )
         .await?;
 
+    if let Some(epsilon) = dp_epsilon {
+        for point in &mut timeseries {
+            // A single feedback value can move the mean by at most `1 / count`,
+            // so that's the sensitivity of this query.
+            let sensitivity = if point.count > 0 {
+                1.0 / point.count as f64
+            } else {
+                1.0
+            };
+            point.mean = add_laplace_noise(f64::from(point.mean), sensitivity, epsilon) as f32;
+        }
+    }
+
     Ok(GetCumulativeFeedbackTimeseriesResponse { timeseries })
 }
 
@@ -137,6 +168,7 @@ mod tests {
             Some(vec!["variant_a".to_string()]),
             TimeWindow::Hour,
             24,
+            None,
         )
         .await
         .unwrap();
@@ -182,6 +214,7 @@ mod tests {
             None,
             TimeWindow::Day,
             7,
+            None,
         )
         .await
         .unwrap();
@@ -207,6 +240,7 @@ mod tests {
             None,
             TimeWindow::Week,
             4,
+            None,
         )
         .await
         .unwrap();
@@ -241,6 +275,7 @@ mod tests {
             None,
             TimeWindow::Cumulative,
             1,
+            None,
         )
         .await;
 
@@ -249,4 +284,80 @@ mod tests {
             "Cumulative time window should return an error"
         );
     }
+
+    #[tokio::test]
+    async fn test_get_cumulative_feedback_timeseries_applies_dp_noise() {
+        let mut mock_clickhouse = MockFeedbackQueries::new();
+
+        mock_clickhouse
+            .expect_get_cumulative_feedback_timeseries()
+            .times(1)
+            .returning(|_, _, _, _, _| {
+                let rows = vec![CumulativeFeedbackTimeSeriesPoint {
+                    period_end: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                    variant_name: "default".to_string(),
+                    mean: 0.90,
+                    variance: Some(0.005),
+                    count: 200,
+                    alpha: 0.05,
+                    cs_lower: Some(0.87),
+                    cs_upper: Some(0.93),
+                }];
+                Box::pin(async move { Ok(rows) })
+            });
+
+        let result = get_cumulative_feedback_timeseries(
+            &mock_clickhouse,
+            "test_function".to_string(),
+            "task_success".to_string(),
+            None,
+            TimeWindow::Day,
+            7,
+            Some(0.5),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.timeseries.len(),
+            1,
+            "DP noise should not change the number of points"
+        );
+        // The Laplace mechanism is randomized, but with epsilon = 0.5 and a
+        // sensitivity of 1/200, the noise should almost never push the mean
+        // outside of this generous window.
+        assert!(
+            (result.timeseries[0].mean - 0.90).abs() < 0.5,
+            "noisy mean {} should stay close to the true mean 0.90",
+            result.timeseries[0].mean
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_cumulative_feedback_timeseries_rejects_nonpositive_epsilon() {
+        // The mock should never be called: invalid `dp_epsilon` must be rejected
+        // before we ever reach ClickHouse or `add_laplace_noise`.
+        let mut mock_clickhouse = MockFeedbackQueries::new();
+        mock_clickhouse
+            .expect_get_cumulative_feedback_timeseries()
+            .times(0);
+
+        for epsilon in [0.0, -1.0] {
+            let result = get_cumulative_feedback_timeseries(
+                &mock_clickhouse,
+                "test_function".to_string(),
+                "task_success".to_string(),
+                None,
+                TimeWindow::Day,
+                7,
+                Some(epsilon),
+            )
+            .await;
+
+            assert!(
+                result.is_err(),
+                "dp_epsilon = {epsilon} should be rejected instead of panicking in `add_laplace_noise`"
+            );
+        }
+    }
 }
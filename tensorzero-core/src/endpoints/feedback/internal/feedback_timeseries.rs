@@ -0,0 +1,64 @@
+//! Feedback endpoint for querying bucketed (non-cumulative) feedback time series
+
+use axum::extract::{Query, State};
+use axum::{Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::db::TimeWindow;
+use crate::db::feedback::{BucketedFeedbackTimeSeriesPoint, FeedbackQueries};
+use crate::error::Error;
+use crate::utils::gateway::{AppState, AppStateData};
+
+#[derive(Debug, Deserialize)]
+pub struct GetFeedbackTimeseriesParams {
+    pub function_name: String,
+    pub metric_name: String,
+    /// Comma-separated list of variant names to filter by. If not provided, all variants are included.
+    pub variant_names: Option<String>,
+    pub time_window: TimeWindow,
+    pub max_periods: u32,
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct GetFeedbackTimeseriesResponse {
+    pub timeseries: Vec<BucketedFeedbackTimeSeriesPoint>,
+}
+
+/// HTTP handler for getting bucketed feedback time series
+#[debug_handler(state = AppStateData)]
+#[instrument(
+    name = "get_feedback_timeseries_handler",
+    skip_all,
+    fields(
+        function_name = %params.function_name,
+        metric_name = %params.metric_name,
+        time_window = ?params.time_window,
+        max_periods = %params.max_periods,
+    )
+)]
+pub async fn get_feedback_timeseries_handler(
+    State(app_state): AppState,
+    Query(params): Query<GetFeedbackTimeseriesParams>,
+) -> Result<Json<GetFeedbackTimeseriesResponse>, Error> {
+    let variant_names = params.variant_names.map(|s| {
+        s.split(',')
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>()
+    });
+
+    let timeseries = app_state
+        .clickhouse_connection_info
+        .get_feedback_timeseries(
+            params.function_name,
+            params.metric_name,
+            variant_names,
+            params.time_window,
+            params.max_periods,
+        )
+        .await?;
+    Ok(Json(GetFeedbackTimeseriesResponse { timeseries }))
+}
@@ -1,5 +1,6 @@
 mod count_feedback;
 mod cumulative_feedback_timeseries;
+mod feedback_timeseries;
 mod get_demonstration_feedback;
 mod get_feedback_bounds;
 mod get_feedback_by_target_id;
@@ -7,6 +8,7 @@ mod latest_feedback_by_metric;
 
 pub use count_feedback::*;
 pub use cumulative_feedback_timeseries::*;
+pub use feedback_timeseries::*;
 pub use get_demonstration_feedback::*;
 pub use get_feedback_bounds::*;
 pub use get_feedback_by_target_id::*;
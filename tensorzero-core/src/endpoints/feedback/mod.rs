@@ -24,6 +24,7 @@ use crate::db::feedback::{
 };
 use crate::db::inferences::{FunctionInfo, InferenceQueries};
 use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEvent;
 use crate::function::FunctionConfig;
 use crate::inference::types::{
     ContentBlockChatOutput, ContentBlockOutput, Text, parse_chat_output,
@@ -37,6 +38,7 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 use super::validate_tags;
 
+pub mod by_correlation_id;
 pub mod human_feedback;
 pub mod internal;
 
@@ -125,6 +127,7 @@ pub async fn feedback(
         config,
         clickhouse_connection_info,
         deferred_tasks,
+        event_bus,
         ..
     }: AppStateData,
     mut params: Params,
@@ -165,6 +168,7 @@ pub async fn feedback(
     )?;
 
     let feedback_id = Uuid::now_v7();
+    let event_metric_name = params.metric_name.clone();
 
     let dryrun = params.dryrun.unwrap_or(false);
 
@@ -233,6 +237,12 @@ pub async fn feedback(
         }
     }
 
+    event_bus.publish(GatewayEvent::FeedbackReceived {
+        feedback_id,
+        target_id: feedback_metadata.target_id,
+        metric_name: event_metric_name,
+    });
+
     Ok(FeedbackResponse { feedback_id })
 }
 
@@ -414,6 +424,19 @@ async fn write_float(
             message: format!("Feedback value for metric `{metric_name}` must be a number"),
         })
     })?;
+    if let Some(bounds) = &metric_config.bounds {
+        if bounds.min.is_some_and(|min| float_value < min)
+            || bounds.max.is_some_and(|max| float_value > max)
+        {
+            return Err(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "Feedback value {float_value} for metric `{metric_name}` is outside the configured bounds ({:?}..={:?})",
+                    bounds.min, bounds.max
+                ),
+            }
+            .into());
+        }
+    }
     let insert = FloatMetricFeedbackInsert {
         id: feedback_id,
         target_id,
@@ -880,6 +903,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 level: MetricConfigLevel::Inference,
                 optimize: MetricConfigOptimize::Max,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -997,6 +1022,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             level: MetricConfigLevel::Episode,
             optimize: MetricConfigOptimize::Max,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
         let mut metrics = HashMap::new();
@@ -1150,6 +1177,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 level: MetricConfigLevel::Episode,
                 optimize: MetricConfigOptimize::Max,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -1223,6 +1252,8 @@ mod tests {
                 r#type: MetricConfigType::Boolean,
                 level: MetricConfigLevel::Inference,
                 optimize: MetricConfigOptimize::Max,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
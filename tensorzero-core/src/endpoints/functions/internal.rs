@@ -1,5 +1,7 @@
 //! Functions endpoint for querying function-level information
 
+use std::collections::HashMap;
+
 use axum::extract::{Path, Query, State};
 use axum::{Json, debug_handler};
 use serde::{Deserialize, Serialize};
@@ -8,13 +10,18 @@ use tracing::instrument;
 use crate::config::{Config, MetricConfigType};
 use crate::db::TimeWindow;
 use crate::db::feedback::{
-    FeedbackQueries, GetVariantPerformanceParams, MetricType, MetricWithFeedback,
-    VariantPerformanceRow,
+    FeedbackByVariant, FeedbackByVariantAndTag, FeedbackQueries, GetFeedbackByTagParams,
+    GetVariantPerformanceParams, MetricType, MetricWithFeedback, VariantPerformanceRow,
 };
 use crate::error::{Error, ErrorDetails};
 use crate::function::get_function;
+use crate::statistics_util::{bonferroni_z, wald_confint_with_z};
 use crate::utils::gateway::{AppState, AppStateData};
 
+/// Overall family-wise significance level for segment-deviation flags, before the
+/// Bonferroni correction is applied across the number of segments returned.
+const SEGMENT_ANALYSIS_ALPHA: f64 = 0.05;
+
 /// Query parameters for the metrics endpoint
 #[derive(Debug, Deserialize)]
 pub struct MetricsQueryParams {
@@ -185,6 +192,172 @@ pub async fn get_variant_performances(
     Ok(VariantPerformancesResponse { performances })
 }
 
+/// Query parameters for the segment analysis endpoint
+#[derive(Debug, Deserialize)]
+pub struct SegmentAnalysisQueryParams {
+    /// The metric name to segment
+    pub metric_name: String,
+    /// The inference tag key to segment by (e.g. "customer_tier", "locale", "channel")
+    pub tag_key: String,
+    /// Optional variant name to filter by
+    pub variant_name: Option<String>,
+}
+
+/// Per-(variant, tag_value) row, with the variant's overall metric average included for
+/// comparison and a flag for whether the segment deviates from it significantly.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct SegmentRow {
+    pub variant_name: String,
+    pub tag_value: String,
+    pub count: u32,
+    pub avg_metric: f64,
+    #[cfg_attr(feature = "ts-bindings", ts(optional))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdev: Option<f64>,
+    /// The variant's overall (all-segments) average metric value, for comparison
+    pub overall_avg_metric: f64,
+    /// True if this segment's average deviates from the variant's overall average by more
+    /// than a Bonferroni-corrected Wald confidence interval (family-wise alpha 0.05, corrected
+    /// for the number of segments returned) would allow by chance
+    pub significant_deviation: bool,
+}
+
+/// Response containing per-segment metric breakdowns
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct SegmentAnalysisResponse {
+    /// Statistics for each (variant, tag_value) combination
+    pub segments: Vec<SegmentRow>,
+}
+
+/// HTTP handler for getting per-segment metric breakdowns for a function and metric
+#[debug_handler(state = AppStateData)]
+#[instrument(
+    name = "get_segment_analysis_handler",
+    skip_all,
+    fields(
+        function_name = %function_name,
+        metric_name = %params.metric_name,
+        tag_key = %params.tag_key,
+    )
+)]
+pub async fn get_segment_analysis_handler(
+    State(app_state): AppState,
+    Path(function_name): Path<String>,
+    Query(params): Query<SegmentAnalysisQueryParams>,
+) -> Result<Json<SegmentAnalysisResponse>, Error> {
+    let response = get_segment_analysis(
+        &app_state.config,
+        &app_state.clickhouse_connection_info,
+        &function_name,
+        &params.metric_name,
+        &params.tag_key,
+        params.variant_name.as_deref(),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// Core business logic for computing per-segment metric breakdowns.
+///
+/// Validates function and metric exist in config, queries ClickHouse for both the overall
+/// (per-variant) statistics and the per-(variant, tag_value) statistics, then flags segments
+/// whose average deviates significantly from their variant's overall average - so that a
+/// variant which improves the average while harming one segment can be caught even though
+/// the aggregate metric alone would look like an improvement.
+///
+/// Significance is judged with a one-sample Wald confidence interval around each segment's
+/// mean, using a Bonferroni-corrected z-score for the number of segments returned, checking
+/// whether the variant's overall average falls outside that interval. This is deliberately a
+/// simpler test than a full two-sample comparison (which would need the overall statistic's
+/// own variance too, information `get_feedback_by_variant` does not expose the raw values
+/// for) - it is a reasonable proxy for "is this segment behaving differently" without
+/// requiring a new aggregate-statistics query.
+pub async fn get_segment_analysis(
+    config: &Config,
+    clickhouse: &impl FeedbackQueries,
+    function_name: &str,
+    metric_name: &str,
+    tag_key: &str,
+    variant_name: Option<&str>,
+) -> Result<SegmentAnalysisResponse, Error> {
+    // Get function config to determine the function type
+    let function_config = get_function(&config.functions, function_name)?;
+    let function_type = function_config.config_type();
+
+    // Get metric config to determine the metric type and level
+    let metric_config = config.metrics.get(metric_name).ok_or_else(|| {
+        Error::new(ErrorDetails::UnknownMetric {
+            name: metric_name.to_string(),
+        })
+    })?;
+
+    // If variant_name is provided, validate that it exists
+    if let Some(variant) = variant_name
+        && !function_config.variants().contains_key(variant)
+    {
+        return Err(ErrorDetails::UnknownVariant {
+            name: variant.to_string(),
+        }
+        .into());
+    }
+
+    let overall_variant_names = variant_name.map(|v| vec![v.to_string()]);
+    let overall = clickhouse
+        .get_feedback_by_variant(metric_name, function_name, overall_variant_names.as_ref())
+        .await?;
+    let overall_by_variant: HashMap<&str, &FeedbackByVariant> = overall
+        .iter()
+        .map(|row| (row.variant_name.as_str(), row))
+        .collect();
+
+    let params = GetFeedbackByTagParams {
+        function_name,
+        function_type,
+        metric_name,
+        metric_config,
+        tag_key,
+        variant_name,
+    };
+    let segments = clickhouse.get_feedback_by_variant_by_tag(params).await?;
+
+    let z = bonferroni_z(SEGMENT_ANALYSIS_ALPHA, segments.len());
+    let segments = segments
+        .into_iter()
+        .map(|segment| build_segment_row(segment, &overall_by_variant, z))
+        .collect();
+
+    Ok(SegmentAnalysisResponse { segments })
+}
+
+fn build_segment_row(
+    segment: FeedbackByVariantAndTag,
+    overall_by_variant: &HashMap<&str, &FeedbackByVariant>,
+    z: f64,
+) -> SegmentRow {
+    let overall_avg_metric = overall_by_variant
+        .get(segment.variant_name.as_str())
+        .map_or(segment.avg_metric, |row| f64::from(row.mean));
+
+    let significant_deviation = segment
+        .stdev
+        .and_then(|stdev| wald_confint_with_z(segment.avg_metric, stdev, segment.count, z))
+        .is_some_and(|(lower, upper)| overall_avg_metric < lower || overall_avg_metric > upper);
+
+    SegmentRow {
+        variant_name: segment.variant_name,
+        tag_value: segment.tag_value,
+        count: segment.count,
+        avg_metric: segment.avg_metric,
+        stdev: segment.stdev,
+        overall_avg_metric,
+        significant_deviation,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -422,6 +595,11 @@ mod tests {
                             avg_metric: 0.85,
                             stdev: Some(0.05),
                             ci_error: Some(0.03),
+                            median: 0.85,
+                            p5: 0.75,
+                            p95: 0.95,
+                            min: 0.70,
+                            max: 0.99,
                         },
                         VariantPerformanceRow {
                             period_start: Utc::now(),
@@ -430,6 +608,11 @@ mod tests {
                             avg_metric: 0.90,
                             stdev: Some(0.03),
                             ci_error: Some(0.02),
+                            median: 0.90,
+                            p5: 0.82,
+                            p95: 0.97,
+                            min: 0.80,
+                            max: 0.99,
                         },
                     ])
                 })
@@ -484,6 +667,11 @@ mod tests {
                         avg_metric: 0.85,
                         stdev: Some(0.05),
                         ci_error: Some(0.03),
+                        median: 0.85,
+                        p5: 0.75,
+                        p95: 0.95,
+                        min: 0.70,
+                        max: 0.99,
                     }])
                 })
             });
@@ -502,4 +690,148 @@ mod tests {
         assert_eq!(result.performances.len(), 1);
         assert_eq!(result.performances[0].variant_name, "variant_a");
     }
+
+    // =================================================================
+    // Tests for get_segment_analysis
+    // =================================================================
+
+    #[tokio::test]
+    async fn test_get_segment_analysis_function_not_found() {
+        let config = Config::default();
+        let mut mock_clickhouse = MockFeedbackQueries::new();
+
+        mock_clickhouse.expect_get_feedback_by_variant().never();
+        mock_clickhouse
+            .expect_get_feedback_by_variant_by_tag()
+            .never();
+
+        let result = get_segment_analysis(
+            &config,
+            &mock_clickhouse,
+            "nonexistent_function",
+            "accuracy",
+            "customer_tier",
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("nonexistent_function"));
+    }
+
+    #[tokio::test]
+    async fn test_get_segment_analysis_metric_not_found() {
+        let config_str = create_config_with_function_and_metric();
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_str.as_bytes()).unwrap();
+
+        let config = Config::load_from_path_optional_verify_credentials(
+            &ConfigFileGlob::new_from_path(temp_file.path()).unwrap(),
+            false,
+        )
+        .await
+        .unwrap()
+        .into_config_without_writing_for_tests();
+
+        let mut mock_clickhouse = MockFeedbackQueries::new();
+        mock_clickhouse
+            .expect_get_feedback_by_variant_by_tag()
+            .never();
+
+        let result = get_segment_analysis(
+            &config,
+            &mock_clickhouse,
+            "test_function",
+            "nonexistent_metric",
+            "customer_tier",
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.to_string().contains("nonexistent_metric"),
+            "Error should contain metric name: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_segment_analysis_flags_significant_deviation() {
+        let config_str = create_config_with_function_and_metric();
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_str.as_bytes()).unwrap();
+
+        let config = Config::load_from_path_optional_verify_credentials(
+            &ConfigFileGlob::new_from_path(temp_file.path()).unwrap(),
+            false,
+        )
+        .await
+        .unwrap()
+        .into_config_without_writing_for_tests();
+
+        let mut mock_clickhouse = MockFeedbackQueries::new();
+        mock_clickhouse
+            .expect_get_feedback_by_variant()
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Ok(vec![FeedbackByVariant {
+                        variant_name: "variant_a".to_string(),
+                        mean: 0.85,
+                        variance: Some(0.01),
+                        count: 1000,
+                    }])
+                })
+            });
+        mock_clickhouse
+            .expect_get_feedback_by_variant_by_tag()
+            .withf(|params| {
+                assert_eq!(params.tag_key, "customer_tier");
+                true
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(async move {
+                    Ok(vec![
+                        FeedbackByVariantAndTag {
+                            variant_name: "variant_a".to_string(),
+                            tag_value: "gold".to_string(),
+                            count: 500,
+                            avg_metric: 0.90,
+                            stdev: Some(0.05),
+                        },
+                        FeedbackByVariantAndTag {
+                            variant_name: "variant_a".to_string(),
+                            tag_value: "bronze".to_string(),
+                            count: 500,
+                            avg_metric: 0.40,
+                            stdev: Some(0.05),
+                        },
+                    ])
+                })
+            });
+
+        let result = get_segment_analysis(
+            &config,
+            &mock_clickhouse,
+            "test_function",
+            "accuracy",
+            "customer_tier",
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.segments.len(), 2);
+        let bronze = result
+            .segments
+            .iter()
+            .find(|s| s.tag_value == "bronze")
+            .unwrap();
+        assert!(
+            bronze.significant_deviation,
+            "a segment averaging 0.40 with tight stdev should be flagged as deviating from the overall average of 0.85"
+        );
+    }
 }
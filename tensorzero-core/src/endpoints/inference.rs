@@ -25,13 +25,15 @@ use tracing_futures::Instrument;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 use uuid::Uuid;
 
-use crate::cache::{CacheOptions, CacheParamsOptions};
+use crate::cache::{CacheBackend, CacheOptions, CacheParamsOptions};
 use crate::config::snapshot::SnapshotHash;
 use crate::config::{Config, ErrorContext, OtlpConfig, SchemaData, UninitializedVariantInfo};
 use crate::db::clickhouse::{ClickHouseConnectionInfo, TableName};
+use crate::db::episode_budget::{EpisodeBudgetQueries, EpisodeBudgetUsageDelta};
 use crate::db::postgres::PostgresConnectionInfo;
 use crate::embeddings::EmbeddingModelTable;
 use crate::error::{Error, ErrorDetails, IMPOSSIBLE_ERROR_MESSAGE};
+use crate::events::GatewayEvent;
 use crate::experimentation::ExperimentationConfig;
 use crate::function::{DEFAULT_FUNCTION_NAME, FunctionConfig, FunctionConfigChat};
 use crate::http::TensorzeroHttpClient;
@@ -41,7 +43,7 @@ use crate::inference::types::chat_completion_inference_params::{
 use crate::inference::types::extra_body::UnfilteredInferenceExtraBody;
 use crate::inference::types::extra_headers::UnfilteredInferenceExtraHeaders;
 use crate::inference::types::extra_stuff::validate_inference_filters;
-use crate::inference::types::resolved_input::LazyResolvedInput;
+use crate::inference::types::resolved_input::{LazyResolvedInput, ResolvedInputMessageContent};
 use crate::inference::types::usage::{
     aggregate_usage_across_model_inferences, aggregate_usage_from_single_streaming_model_inference,
 };
@@ -51,9 +53,10 @@ use crate::inference::types::{
     InferenceResultChunk, InferenceResultStream, Input, InputExt, InternalJsonInferenceOutput,
     JsonInferenceDatabaseInsert, JsonInferenceOutput, JsonInferenceResultChunk,
     ModelInferenceResponseWithMetadata, RawResponseEntry, RawUsageEntry, RequestMessage,
-    ResolvedInput, TextChunk, Usage, collect_chunks,
+    ResolvedInput, System, TextChunk, Usage, collect_chunks,
 };
 use crate::jsonschema_util::JSONSchema;
+use crate::language_detection::{INPUT_LANGUAGE_TAG, OUTPUT_LANGUAGE_TAG, detect_language};
 use crate::minijinja_util::TemplateConfig;
 use crate::model::ModelTable;
 use crate::observability::request_logging::HttpMetricData;
@@ -135,12 +138,25 @@ pub struct Params {
     /// If `true`, include `raw_usage` in the response's `usage` field, containing the raw usage data from each model inference.
     #[serde(default)]
     pub include_raw_usage: bool,
+    /// If `true`, add a `snapshot_hash` field to the response, identifying the exact config
+    /// snapshot that produced it (the same hash reported by the `/status` endpoint's
+    /// `config_hash` field). Combined with `inference_id` and `variant_name` (already top-level
+    /// on every response), this lets a downstream system that reports outcomes later reference
+    /// the exact inference and config version without any extra plumbing.
+    #[serde(default)]
+    pub include_snapshot_hash: bool,
     #[serde(default)]
     pub extra_body: UnfilteredInferenceExtraBody,
     #[serde(default)]
     pub extra_headers: UnfilteredInferenceExtraHeaders,
     #[serde(default)]
     pub internal_dynamic_variant_config: Option<UninitializedVariantInfo>,
+    /// An overall deadline for this request, covering variant selection, sampling retries, and
+    /// each variant's own model/provider fallback chain. If exceeded, the request fails with a
+    /// structured `InferenceTimeout` error instead of continuing to retry other variants.
+    /// This is independent of (and applied in addition to) any `timeouts` configured on the
+    /// function's variants, models, or model providers.
+    pub timeout_ms: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -171,6 +187,8 @@ struct InferenceMetadata {
     pub include_original_response: bool,
     pub include_raw_response: bool,
     pub include_raw_usage: bool,
+    pub include_snapshot_hash: bool,
+    pub snapshot_hash: SnapshotHash,
     pub model_inference_id: Uuid,
 }
 
@@ -186,6 +204,8 @@ pub async fn inference_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
+        event_bus,
         ..
     }): AppState,
     api_key_ext: Option<Extension<RequestApiKeyExtension>>,
@@ -205,6 +225,10 @@ pub async fn inference_handler(
             .extra_overhead_labels
             .push(Label::new("function_name", "tensorzero::default"));
     }
+    let event_function_name = params
+        .function_name
+        .clone()
+        .unwrap_or_else(|| "tensorzero::default".to_string());
     let inference_output = Box::pin(inference(
         config,
         &http_client,
@@ -212,6 +236,7 @@ pub async fn inference_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
         params,
         api_key_ext,
     ))
@@ -224,7 +249,14 @@ pub async fn inference_handler(
                     .push(Label::new("variant_name", variant_name));
             }
             match data.output {
-                InferenceOutput::NonStreaming(response) => Json(response).into_response(),
+                InferenceOutput::NonStreaming(response) => {
+                    event_bus.publish(GatewayEvent::InferenceCompleted {
+                        inference_id: response.inference_id(),
+                        function_name: event_function_name,
+                        variant_name: response.variant_name().to_string(),
+                    });
+                    Json(response).into_response()
+                }
                 InferenceOutput::Streaming(stream) => {
                     let event_stream = prepare_serialized_events(stream);
 
@@ -294,6 +326,7 @@ pub async fn inference(
     postgres_connection_info: PostgresConnectionInfo,
     deferred_tasks: TaskTracker,
     rate_limiting_manager: Arc<RateLimitingManager>,
+    hot_cache: Arc<dyn CacheBackend>,
     mut params: Params,
     api_key_ext: Option<Extension<RequestApiKeyExtension>>,
 ) -> Result<InferenceOutputData, Error> {
@@ -343,6 +376,8 @@ pub async fn inference(
     // Retrieve or generate the episode ID
     let episode_id = params.episode_id.unwrap_or_else(Uuid::now_v7);
 
+    check_episode_budget(&config, &postgres_connection_info, episode_id).await?;
+
     validate_inference_episode_id_and_apply_workflow_evaluation_run(
         episode_id,
         params.function_name.as_ref(),
@@ -442,6 +477,7 @@ pub async fn inference(
         postgres_connection_info: postgres_connection_info.clone(),
         credentials: Arc::new(params.credentials.clone()),
         cache_options: (params.cache_options, dryrun).into(),
+        hot_cache,
         tags: tags.clone(),
         rate_limiting_manager,
         otlp_config: config.gateway.export.otlp.clone(),
@@ -462,133 +498,169 @@ pub async fn inference(
     };
     let resolved_input = Arc::new(params.input.into_lazy_resolved_input(&fetch_context)?);
 
-    // If we don't need sampling (pinned or dynamic variant), directly infer with the single variant
-    if !needs_sampling {
-        // Extract the single variant (should be exactly one)
-        let (variant_name, variant) = candidate_variants
-            .into_iter()
-            .next()
-            .ok_or_else(|| {
-                Error::new(ErrorDetails::Inference {
-                    message: format!("No candidate variants available for direct inference. {IMPOSSIBLE_ERROR_MESSAGE}"),
-                })
-            })?;
-
-        let output = infer_variant(InferVariantArgs {
-            variant_name: variant_name.clone(),
-            variant,
-            function: &function,
-            function_name: &function_name,
-            inference_id,
-            episode_id,
-            dryrun,
-            start_time,
-            stream,
-            resolved_input,
-            inference_models,
-            inference_clients,
-            inference_params: params.params.clone(),
-            templates,
-            tool_config: &tool_config,
-            output_schema: &output_schema,
-            config: &config,
-            clickhouse_connection_info: &clickhouse_connection_info,
-            tags: &params.tags,
-            extra_body: &params.extra_body,
-            extra_headers: &params.extra_headers,
-            include_original_response: params.include_original_response,
-            include_raw_response: params.include_raw_response,
-            include_raw_usage: params.include_raw_usage,
-        })
-        .await?;
-        return Ok(InferenceOutputData {
-            output,
-            exactly_one_variant: Some(variant_name),
-        });
-    }
+    let request_timeout = params.timeout_ms.map(Duration::from_millis);
+    // Tracks whichever variant is currently being attempted, so that if the request-level
+    // timeout below fires, the resulting `InferenceTimeout` error can name it.
+    let current_variant_name: Arc<std::sync::Mutex<Option<String>>> =
+        Arc::new(std::sync::Mutex::new(None));
+    let timeout_variant_name = current_variant_name.clone();
+
+    // Everything from here down (variant selection, sampling retries, and the model/provider
+    // fallback chains each variant runs internally) is bounded by the request's `timeout_ms`, if
+    // provided. Dropping this future on timeout cancels whatever variant/model call is currently
+    // in flight. For streaming responses, this only bounds the time to obtain the stream (which
+    // includes time-to-first-chunk, matching `timeouts.streaming.ttft_ms`'s semantics at the
+    // model level) - chunks already being streamed to the client are not subject to this
+    // deadline, since bounding an in-progress SSE stream would require buffering and replaying
+    // partial output, which is out of scope here.
+    let run_variants = async {
+        // If we don't need sampling (pinned or dynamic variant), directly infer with the single variant
+        if !needs_sampling {
+            // Extract the single variant (should be exactly one)
+            let (variant_name, variant) = candidate_variants
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    Error::new(ErrorDetails::Inference {
+                        message: format!("No candidate variants available for direct inference. {IMPOSSIBLE_ERROR_MESSAGE}"),
+                    })
+                })?;
+
+            *timeout_variant_name.lock().unwrap() = Some(variant_name.clone());
+            let output = infer_variant(InferVariantArgs {
+                variant_name: variant_name.clone(),
+                variant,
+                function: &function,
+                function_name: &function_name,
+                inference_id,
+                episode_id,
+                dryrun,
+                start_time,
+                stream,
+                resolved_input,
+                inference_models,
+                inference_clients,
+                inference_params: params.params.clone(),
+                templates,
+                tool_config: &tool_config,
+                output_schema: &output_schema,
+                config: &config,
+                clickhouse_connection_info: &clickhouse_connection_info,
+                tags: &params.tags,
+                extra_body: &params.extra_body,
+                extra_headers: &params.extra_headers,
+                include_original_response: params.include_original_response,
+                include_raw_response: params.include_raw_response,
+                include_raw_usage: params.include_raw_usage,
+                include_snapshot_hash: params.include_snapshot_hash,
+            })
+            .await?;
+            return Ok(InferenceOutputData {
+                output,
+                exactly_one_variant: Some(variant_name),
+            });
+        }
+
+        // Keep sampling variants until one succeeds
+        let mut already_sampled = false;
+        while !candidate_variants.is_empty() {
+            let result = function
+                .experimentation()
+                .sample(
+                    &function_name,
+                    episode_id,
+                    &mut candidate_variants,
+                    &postgres_connection_info,
+                )
+                .await;
+            let (variant_name, variant) = match result {
+                Ok((variant_name, variant)) => (variant_name, variant),
+                Err(e) => {
+                    if variant_errors.is_empty() {
+                        return Err(e);
+                    }
+                    // If the sampling fails we break out of the loop and return the AllVariantsExhausted error
+                    // It is more informative to the caller that variants have failed than that there's some internal error with the sampling strategy.
+                    // As we continue work on experimentation we will make sure that the sampler only errors if there is no way to provide a valid variant.
+                    break;
+                }
+            };
 
-    // Keep sampling variants until one succeeds
-    let mut already_sampled = false;
-    while !candidate_variants.is_empty() {
-        let result = function
-            .experimentation()
-            .sample(
-                &function_name,
+            *timeout_variant_name.lock().unwrap() = Some(variant_name.clone());
+            let result = infer_variant(InferVariantArgs {
+                variant_name: variant_name.clone(),
+                variant,
+                function: &function,
+                function_name: &function_name,
+                inference_id,
                 episode_id,
-                &mut candidate_variants,
-                &postgres_connection_info,
-            )
+                dryrun,
+                start_time,
+                stream,
+                resolved_input: resolved_input.clone(),
+                inference_models: inference_models.clone(),
+                inference_clients: inference_clients.clone(),
+                inference_params: params.params.clone(),
+                templates,
+                tool_config: &tool_config,
+                output_schema: &output_schema,
+                config: &config,
+                clickhouse_connection_info: &clickhouse_connection_info,
+                tags: &params.tags,
+                extra_body: &params.extra_body,
+                extra_headers: &params.extra_headers,
+                include_original_response: params.include_original_response,
+                include_raw_response: params.include_raw_response,
+                include_raw_usage: params.include_raw_usage,
+                include_snapshot_hash: params.include_snapshot_hash,
+            })
             .await;
-        let (variant_name, variant) = match result {
-            Ok((variant_name, variant)) => (variant_name, variant),
-            Err(e) => {
-                if variant_errors.is_empty() {
-                    return Err(e);
+
+            match result {
+                Ok(output) => {
+                    return Ok(InferenceOutputData {
+                        output,
+                        exactly_one_variant: if already_sampled {
+                            None
+                        } else {
+                            Some(variant_name)
+                        },
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "functions.{function_name}.variants.{variant_name} failed during inference: {e}",
+                        function_name = function_name,
+                        variant_name = variant_name,
+                    );
+                    variant_errors.insert(variant_name, e);
+                    already_sampled = true;
+                    continue;
                 }
-                // If the sampling fails we break out of the loop and return the AllVariantsExhausted error
-                // It is more informative to the caller that variants have failed than that there's some internal error with the sampling strategy.
-                // As we continue work on experimentation we will make sure that the sampler only errors if there is no way to provide a valid variant.
-                break;
             }
-        };
-
-        let result = infer_variant(InferVariantArgs {
-            variant_name: variant_name.clone(),
-            variant,
-            function: &function,
-            function_name: &function_name,
-            inference_id,
-            episode_id,
-            dryrun,
-            start_time,
-            stream,
-            resolved_input: resolved_input.clone(),
-            inference_models: inference_models.clone(),
-            inference_clients: inference_clients.clone(),
-            inference_params: params.params.clone(),
-            templates,
-            tool_config: &tool_config,
-            output_schema: &output_schema,
-            config: &config,
-            clickhouse_connection_info: &clickhouse_connection_info,
-            tags: &params.tags,
-            extra_body: &params.extra_body,
-            extra_headers: &params.extra_headers,
-            include_original_response: params.include_original_response,
-            include_raw_response: params.include_raw_response,
-            include_raw_usage: params.include_raw_usage,
-        })
-        .await;
+        }
 
-        match result {
-            Ok(output) => {
-                return Ok(InferenceOutputData {
-                    output,
-                    exactly_one_variant: if already_sampled {
-                        None
-                    } else {
-                        Some(variant_name)
-                    },
-                });
-            }
-            Err(e) => {
-                tracing::warn!(
-                    "functions.{function_name}.variants.{variant_name} failed during inference: {e}",
-                    function_name = function_name,
-                    variant_name = variant_name,
-                );
-                variant_errors.insert(variant_name, e);
-                already_sampled = true;
-                continue;
-            }
+        // Eventually, if we get here, it means we tried every variant and none of them worked
+        Err(ErrorDetails::AllVariantsFailed {
+            errors: variant_errors,
         }
-    }
+        .into())
+    };
 
-    // Eventually, if we get here, it means we tried every variant and none of them worked
-    Err(ErrorDetails::AllVariantsFailed {
-        errors: variant_errors,
+    if let Some(timeout) = request_timeout {
+        tokio::time::timeout(timeout, run_variants)
+            .await
+            .unwrap_or_else(|_: tokio::time::error::Elapsed| {
+                let variant_name = current_variant_name
+                    .lock()
+                    .unwrap()
+                    .clone()
+                    .unwrap_or_else(|| "<none attempted>".to_string());
+                Err(Error::new(ErrorDetails::InferenceTimeout { variant_name }))
+            })
+    } else {
+        run_variants.await
     }
-    .into())
 }
 
 struct InferVariantArgs<'a> {
@@ -616,6 +688,7 @@ struct InferVariantArgs<'a> {
     include_original_response: bool,
     include_raw_response: bool,
     include_raw_usage: bool,
+    include_snapshot_hash: bool,
 }
 
 async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Error> {
@@ -644,6 +717,7 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
         include_original_response,
         include_raw_response,
         include_raw_usage,
+        include_snapshot_hash,
     } = args;
 
     // Will be edited by the variant as part of making the request so we must clone here
@@ -670,6 +744,7 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
 
     if stream {
         let deferred_tasks = inference_clients.deferred_tasks.clone();
+        let postgres_connection_info = inference_clients.postgres_connection_info.clone();
         let result = variant
             .infer_stream(
                 resolved_input.clone(),
@@ -714,6 +789,8 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
             include_original_response,
             include_raw_response,
             include_raw_usage,
+            include_snapshot_hash,
+            snapshot_hash: config.hash.clone(),
             fetch_and_encode_input_files_before_inference: config
                 .gateway
                 .fetch_and_encode_input_files_before_inference,
@@ -726,12 +803,14 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
             inference_metadata,
             stream,
             clickhouse_connection_info.clone(),
+            postgres_connection_info,
             deferred_tasks.clone(),
         );
 
         Ok(InferenceOutput::Streaming(Box::pin(stream)))
     } else {
         let deferred_tasks = inference_clients.deferred_tasks.clone();
+        let postgres_connection_info = inference_clients.postgres_connection_info.clone();
         let result = variant
             .infer(
                 Arc::clone(&resolved_input),
@@ -777,6 +856,7 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
             let write_future = deferred_tasks.spawn(async move {
                 let _: () = write_inference(
                     &clickhouse_connection_info,
+                    &postgres_connection_info,
                     &config,
                     Arc::unwrap_or_clone(resolved_input).resolve().await?,
                     result_to_write,
@@ -801,12 +881,107 @@ async fn infer_variant(args: InferVariantArgs<'_>) -> Result<InferenceOutput, Er
             include_raw_usage,
             include_original_response,
             include_raw_response,
+            include_snapshot_hash.then_some(&config.hash),
         );
 
         Ok(InferenceOutput::NonStreaming(response))
     }
 }
 
+/// Checks the episode's previously recorded cumulative usage against the
+/// limits in `gateway.episode_budgets`, if configured, returning
+/// `EpisodeBudgetExceeded` if any limit has already been reached.
+///
+/// NOTE: this only accounts for usage recorded by prior inferences in the
+/// episode - it can't know the cost of the request currently being made, so
+/// an episode can still exceed its budget by (at most) one inference's worth
+/// of usage. Enforcement is skipped entirely if Postgres is disabled.
+async fn check_episode_budget(
+    config: &Config,
+    postgres_connection_info: &PostgresConnectionInfo,
+    episode_id: Uuid,
+) -> Result<(), Error> {
+    let Some(episode_budgets) = &config.gateway.episode_budgets else {
+        return Ok(());
+    };
+    if !episode_budgets.enabled {
+        return Ok(());
+    }
+    if matches!(postgres_connection_info, PostgresConnectionInfo::Disabled) {
+        return Ok(());
+    }
+
+    let usage = postgres_connection_info
+        .get_episode_budget_usage(episode_id)
+        .await?;
+
+    let exceeded = |budget_kind: &str, limit: f64, used: f64| {
+        Err(ErrorDetails::EpisodeBudgetExceeded {
+            episode_id,
+            budget_kind: budget_kind.to_string(),
+            limit,
+            used,
+        }
+        .into())
+    };
+
+    if let Some(max_tokens) = episode_budgets.max_tokens {
+        if usage.tokens_used >= max_tokens {
+            return exceeded("max_tokens", max_tokens as f64, usage.tokens_used as f64);
+        }
+    }
+    if let Some(max_cost_usd) = episode_budgets.max_cost_usd {
+        if usage.cost_used_usd >= max_cost_usd {
+            return exceeded("max_cost_usd", max_cost_usd, usage.cost_used_usd);
+        }
+    }
+    if let Some(max_inference_count) = episode_budgets.max_inference_count {
+        if usage.inference_count >= u64::from(max_inference_count) {
+            return exceeded(
+                "max_inference_count",
+                f64::from(max_inference_count),
+                usage.inference_count as f64,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Records this inference's token usage and cost against the episode's cumulative
+/// budget usage, if `gateway.episode_budgets` is configured and Postgres is
+/// enabled. Best-effort: failures are logged and otherwise ignored, since
+/// this runs from the same fire-and-forget write path as the ClickHouse
+/// inference write.
+async fn record_episode_budget_usage(
+    config: &Config,
+    postgres_connection_info: &PostgresConnectionInfo,
+    episode_id: Uuid,
+    result: &InferenceResult,
+) {
+    let Some(episode_budgets) = &config.gateway.episode_budgets else {
+        return;
+    };
+    if !episode_budgets.enabled {
+        return;
+    }
+    if matches!(postgres_connection_info, PostgresConnectionInfo::Disabled) {
+        return;
+    }
+
+    let usage = result.usage_considering_cached();
+    let tokens =
+        u64::from(usage.input_tokens.unwrap_or(0)) + u64::from(usage.output_tokens.unwrap_or(0));
+    let cost_usd = result.total_cost_usd(config).await;
+
+    if let Err(e) = postgres_connection_info
+        .record_episode_budget_usage(episode_id, EpisodeBudgetUsageDelta { tokens, cost_usd })
+        .await
+    {
+        tracing::warn!("Failed to record episode budget usage for episode {episode_id}: {e:?}");
+    }
+}
+
 /// Finds a function by `function_name` or `model_name`, erroring if an
 /// invalid combination of parameters is provided.
 /// If `model_name` is specified, then we use the special 'default' function
@@ -1002,6 +1177,7 @@ fn create_stream(
     metadata: InferenceMetadata,
     mut stream: InferenceResultStream,
     clickhouse_connection_info: ClickHouseConnectionInfo,
+    postgres_connection_info: PostgresConnectionInfo,
     deferred_tasks: TaskTracker,
 ) -> impl FusedStream<Item = Result<InferenceResponseChunk, Error>> + Send {
     // Capture the parent span (function_inference) so we can use it as the parent
@@ -1128,6 +1304,8 @@ fn create_stream(
                 include_original_response: _,
                 include_raw_response: _,
                 include_raw_usage: _,
+                include_snapshot_hash: _,
+                snapshot_hash: _,
                 model_inference_id,
             } = metadata;
 
@@ -1186,8 +1364,10 @@ fn create_stream(
                         match Arc::unwrap_or_clone(input).resolve().await {
                             Ok(input) => {
                                 let clickhouse_connection_info = clickhouse_connection_info.clone();
+                                let postgres_connection_info = postgres_connection_info.clone();
                                 write_inference(
                                     &clickhouse_connection_info,
+                                    &postgres_connection_info,
                                     &config,
                                     input,
                                     inference_response,
@@ -1308,6 +1488,8 @@ fn prepare_response_chunk(
         metadata.include_raw_response,
         metadata.json_mode,
         metadata.include_raw_usage,
+        metadata.include_snapshot_hash,
+        &metadata.snapshot_hash,
     )
 }
 
@@ -1357,13 +1539,35 @@ pub struct InferenceDatabaseInsertMetadata {
 
 async fn write_inference(
     clickhouse_connection_info: &ClickHouseConnectionInfo,
+    postgres_connection_info: &PostgresConnectionInfo,
     config: &Config,
     input: ResolvedInput,
     result: InferenceResult,
-    metadata: InferenceDatabaseInsertMetadata,
+    mut metadata: InferenceDatabaseInsertMetadata,
 ) {
+    record_episode_budget_usage(
+        config,
+        postgres_connection_info,
+        metadata.episode_id,
+        &result,
+    )
+    .await;
+
+    if let Some(language) = detect_language(&resolved_input_text(&input)) {
+        metadata
+            .tags
+            .entry(INPUT_LANGUAGE_TAG.to_string())
+            .or_insert(language);
+    }
+    if let Some(language) = detect_language(&inference_result_text(&result)) {
+        metadata
+            .tags
+            .entry(OUTPUT_LANGUAGE_TAG.to_string())
+            .or_insert(language);
+    }
+
     let model_responses: Vec<serde_json::Value> = result
-        .get_serialized_model_inferences(metadata.snapshot_hash.clone())
+        .get_serialized_model_inferences(metadata.snapshot_hash.clone(), config)
         .await;
     let mut futures: Vec<Pin<Box<dyn Future<Output = ()> + Send>>> =
         input.clone().write_all_files(config);
@@ -1400,6 +1604,41 @@ async fn write_inference(
     futures::future::join_all(futures).await;
 }
 
+/// Concatenates the plain-text content (`Text`/`RawText` blocks; templates and files are
+/// skipped) of a resolved input's messages and system prompt, for automatic language detection.
+fn resolved_input_text(input: &ResolvedInput) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    if let Some(System::Text(text)) = &input.system {
+        parts.push(text.as_str());
+    }
+    for message in &input.messages {
+        for content in &message.content {
+            match content {
+                ResolvedInputMessageContent::Text(text) => parts.push(&text.text),
+                ResolvedInputMessageContent::RawText(raw_text) => parts.push(&raw_text.value),
+                _ => {}
+            }
+        }
+    }
+    parts.join(" ")
+}
+
+/// Extracts the plain-text content of an inference result, for automatic language detection.
+fn inference_result_text(result: &InferenceResult) -> String {
+    match result {
+        InferenceResult::Chat(chat_result) => chat_result
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlockChatOutput::Text(text) => Some(text.text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        InferenceResult::Json(json_result) => json_result.output.raw.clone().unwrap_or_default(),
+    }
+}
+
 /// InferenceResponse and InferenceResultChunk determine what gets serialized and sent to the client
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1431,6 +1670,11 @@ pub struct ChatInferenceResponse {
     pub raw_response: Option<Vec<RawResponseEntry>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub finish_reason: Option<FinishReason>,
+    /// The hash of the config snapshot active for this inference, identifying the exact config
+    /// version that produced it. Only present if `include_snapshot_hash` was set on the request.
+    #[cfg_attr(feature = "ts-bindings", ts(optional))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_hash: Option<String>,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -1454,9 +1698,15 @@ pub struct JsonInferenceResponse {
     pub raw_response: Option<Vec<RawResponseEntry>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub finish_reason: Option<FinishReason>,
+    /// The hash of the config snapshot active for this inference, identifying the exact config
+    /// version that produced it. Only present if `include_snapshot_hash` was set on the request.
+    #[cfg_attr(feature = "ts-bindings", ts(optional))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_hash: Option<String>,
 }
 
 impl InferenceResponse {
+    #[expect(clippy::too_many_arguments)]
     pub fn new(
         inference_result: InferenceResult,
         episode_id: Uuid,
@@ -1464,7 +1714,9 @@ impl InferenceResponse {
         include_raw_usage: bool,
         include_original_response: bool,
         include_raw_response: bool,
+        snapshot_hash: Option<&SnapshotHash>,
     ) -> Self {
+        let snapshot_hash = snapshot_hash.map(SnapshotHash::to_string);
         let usage = inference_result.usage_considering_cached();
 
         // Build raw_usage if requested
@@ -1532,6 +1784,7 @@ impl InferenceResponse {
                     original_response,
                     raw_response: raw_response.clone(),
                     finish_reason: result.finish_reason,
+                    snapshot_hash: snapshot_hash.clone(),
                 })
             }
             InferenceResult::Json(result) => {
@@ -1553,6 +1806,7 @@ impl InferenceResponse {
                     original_response,
                     raw_response,
                     finish_reason: result.finish_reason,
+                    snapshot_hash,
                 })
             }
         }
@@ -1663,6 +1917,10 @@ pub struct ChatInferenceResponseChunk {
     pub original_chunk: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub raw_chunk: Option<String>,
+    /// The hash of the config snapshot active for this inference. Only present if
+    /// `include_snapshot_hash` was set on the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_hash: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1686,6 +1944,10 @@ pub struct JsonInferenceResponseChunk {
     pub original_chunk: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub raw_chunk: Option<String>,
+    /// The hash of the config snapshot active for this inference. Only present if
+    /// `include_snapshot_hash` was set on the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_hash: Option<String>,
 }
 
 impl InferenceResponseChunk {
@@ -1700,7 +1962,14 @@ impl InferenceResponseChunk {
         include_raw_response: bool,
         json_mode: Option<JsonMode>,
         include_raw_usage: bool,
+        include_snapshot_hash: bool,
+        snapshot_hash: &SnapshotHash,
     ) -> Self {
+        let snapshot_hash = if include_snapshot_hash {
+            Some(snapshot_hash.to_string())
+        } else {
+            None
+        };
         // Compute the usage
         let usage = if cached {
             // `usage` represents billed tokens. We set values to 0 if TensorZero cached the inference.
@@ -1770,6 +2039,7 @@ impl InferenceResponseChunk {
                     finish_reason: result.finish_reason,
                     original_chunk,
                     raw_chunk,
+                    snapshot_hash,
                 })
             }
             InferenceResultChunk::Json(result) => {
@@ -1793,6 +2063,7 @@ impl InferenceResponseChunk {
                     finish_reason: result.finish_reason,
                     original_chunk,
                     raw_chunk,
+                    snapshot_hash,
                 })
             }
         }
@@ -1850,6 +2121,10 @@ pub struct InferenceClients {
     pub postgres_connection_info: PostgresConnectionInfo,
     pub credentials: Arc<InferenceCredentials>,
     pub cache_options: CacheOptions,
+    /// Hot cache tier checked before (and backfilled after) each ClickHouse cache lookup. See
+    /// `CacheBackend` for why every caller can share the same handle whether or not a hot tier
+    /// is actually configured.
+    pub hot_cache: Arc<dyn CacheBackend>,
     pub tags: Arc<HashMap<String, String>>,
     pub rate_limiting_manager: Arc<RateLimitingManager>,
     pub otlp_config: OtlpConfig,
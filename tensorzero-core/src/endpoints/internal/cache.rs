@@ -0,0 +1,78 @@
+//! Cache inspection and invalidation endpoints: expose the inference cache's hit rate (by model
+//! and by function) and let operators purge stale cached outputs after a provider-side model
+//! update, without waiting for the ClickHouse-backed cache entries to age out naturally.
+
+use axum::extract::State;
+use axum::{Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::db::cache_queries::{CacheQueries, CacheStats};
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+/// Returns the cache hit rate broken down by model and by function.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "get_cache_stats", skip_all)]
+pub async fn get_cache_stats_handler(
+    State(app_state): AppState,
+) -> Result<Json<CacheStats>, Error> {
+    let stats = app_state
+        .clickhouse_connection_info
+        .get_cache_stats()
+        .await?;
+    Ok(Json(stats))
+}
+
+/// Request body for invalidating cache entries.
+///
+/// Only `model_name` is currently supported: the `ModelInferenceCache` table's key is an opaque
+/// hash of the model provider request, and neither `function_name` nor a config-snapshot hash is
+/// available at the point where that hash is computed (see `Model::infer` in
+/// `tensorzero_core::model`). Supporting those would mean threading additional identifiers
+/// through the cache-key computation and every write path, which is a larger, separate change -
+/// so requests naming either are rejected with an explicit error rather than silently ignored.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InvalidateCacheParams {
+    pub model_name: Option<String>,
+    pub function_name: Option<String>,
+    pub snapshot_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InvalidateCacheResponse {
+    pub invalidated_count: u64,
+}
+
+/// Invalidates (soft-deletes) cache entries for the given model.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "invalidate_cache", skip_all)]
+pub async fn invalidate_cache_handler(
+    State(app_state): AppState,
+    StructuredJson(params): StructuredJson<InvalidateCacheParams>,
+) -> Result<Json<InvalidateCacheResponse>, Error> {
+    let invalidated_count = invalidate_cache(&app_state.clickhouse_connection_info, params).await?;
+    Ok(Json(InvalidateCacheResponse { invalidated_count }))
+}
+
+/// Core invalidation logic, shared between the HTTP handler and the embedded gateway client.
+pub async fn invalidate_cache(
+    clickhouse: &impl CacheQueries,
+    params: InvalidateCacheParams,
+) -> Result<u64, Error> {
+    if params.function_name.is_some() || params.snapshot_hash.is_some() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "Cache invalidation by function_name or snapshot_hash is not yet supported; \
+                only model_name is currently supported"
+                .to_string(),
+        }));
+    }
+    let Some(model_name) = params.model_name else {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "model_name must be provided".to_string(),
+        }));
+    };
+
+    clickhouse.invalidate_cache_by_model(&model_name).await
+}
@@ -6,15 +6,24 @@
 use std::collections::HashMap;
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
+use crate::config::DuplicateModelFinding;
 use crate::config::UninitializedConfig;
+use crate::config::UninitializedFunctionConfig;
+use crate::config::policy::PolicyMode;
+use crate::config::secrets_scan::scan_for_secrets;
 use crate::config::snapshot::{ConfigSnapshot, SnapshotHash};
-use crate::config::write_config_snapshot;
+use crate::config::{Config, RuntimeOverlay, write_config_snapshot};
 use crate::db::ConfigQueries;
+use crate::db::clickhouse::ClickHouseConnectionInfo;
+use crate::db::{ConfigSnapshotTagFilter, ListConfigSnapshotsParams};
 use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEvent;
+use crate::experimentation::UninitializedExperimentationConfig;
 use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
 
 /// Response containing a config snapshot.
@@ -100,6 +109,12 @@ pub struct WriteConfigRequest {
 pub struct WriteConfigResponse {
     /// The hash identifying this config version.
     pub hash: String,
+    /// Organization policy violations found in this snapshot (see `gateway.policy` in the live
+    /// config). Always empty unless the policy is enabled and set to `warn` mode - in `enforce`
+    /// mode (the default when the policy is enabled), a violating snapshot is rejected instead
+    /// of being written with violations attached.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policy_violations: Vec<String>,
 }
 
 /// Handler for `POST /internal/config`
@@ -107,18 +122,954 @@ pub struct WriteConfigResponse {
 /// Writes a config snapshot to the database and returns its hash.
 /// If a config with the same hash already exists, tags are merged
 /// (new tags override existing keys) and created_at is preserved.
+///
+/// The snapshot is always scanned for embedded credentials first; if any are found, the write
+/// is rejected with `ConfigSecretDetected` regardless of the organization policy's mode.
+///
+/// If the live gateway config has `gateway.policy` enabled, the snapshot is evaluated against
+/// it first: in `enforce` mode (the default) a violating snapshot is rejected with
+/// `ConfigPolicyViolation`, while in `warn` mode it's still written but the violations are
+/// returned in the response.
 #[axum::debug_handler(state = AppStateData)]
 #[instrument(name = "config.write", skip_all)]
 pub async fn write_config_handler(
     State(app_state): AppState,
     StructuredJson(request): StructuredJson<WriteConfigRequest>,
 ) -> Result<Json<WriteConfigResponse>, Error> {
+    let response = write_config(
+        &app_state.clickhouse_connection_info,
+        &app_state.config,
+        request,
+    )
+    .await?;
+    app_state.event_bus.publish(GatewayEvent::ConfigChanged {
+        config_snapshot_hash: response.hash.clone(),
+    });
+    Ok(Json(response))
+}
+
+/// Core business logic for writing a config snapshot. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler. `live_config` supplies the
+/// policy (and, like `validate_config`, the runtime overlay) that the write is checked against.
+///
+/// Before anything else, the snapshot is scanned for embedded credentials (API keys, tokens,
+/// private keys) that may have been pasted into a template or another free-text field. Unlike
+/// the organization policy, this check has no `warn` mode and always rejects - snapshots are
+/// immutable and kept forever, so there's no later chance to redact a leaked secret.
+pub async fn write_config(
+    clickhouse: &ClickHouseConnectionInfo,
+    live_config: &Config,
+    request: WriteConfigRequest,
+) -> Result<WriteConfigResponse, Error> {
+    let detected_secrets = scan_for_secrets(&request.config, &request.extra_templates);
+    if !detected_secrets.is_empty() {
+        return Err(Error::new(ErrorDetails::ConfigSecretDetected {
+            secrets: detected_secrets
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }));
+    }
+
+    let policy = &live_config.gateway.policy;
+    let policy_violations = if policy.enabled {
+        let policy_snapshot =
+            ConfigSnapshot::new(request.config.clone(), request.extra_templates.clone())?;
+        let runtime_overlay = RuntimeOverlay::from_config(live_config);
+        let loaded_config =
+            Config::load_from_snapshot(policy_snapshot, runtime_overlay, false).await?;
+        let violations = policy.evaluate(&loaded_config);
+        if !violations.is_empty() && policy.mode == PolicyMode::Enforce {
+            return Err(Error::new(ErrorDetails::ConfigPolicyViolation {
+                violations,
+            }));
+        }
+        violations
+    } else {
+        vec![]
+    };
+
     let mut snapshot = ConfigSnapshot::new(request.config, request.extra_templates)?;
     snapshot.tags = request.tags;
 
     let hash = snapshot.hash.to_string();
 
-    write_config_snapshot(&app_state.clickhouse_connection_info, snapshot).await?;
+    write_config_snapshot(clickhouse, snapshot).await?;
+
+    Ok(WriteConfigResponse {
+        hash,
+        policy_violations,
+    })
+}
+
+/// Request body for validating a config without persisting it.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValidateConfigRequest {
+    /// The config to validate.
+    pub config: UninitializedConfig,
+    /// Templates that would be stored with the config, if written.
+    #[serde(default)]
+    pub extra_templates: HashMap<String, String>,
+    /// If true, also verify model provider credentials and object storage connectivity.
+    /// This makes outbound requests, so it's opt-in and defaults to `false`.
+    #[serde(default)]
+    pub verify_credentials: bool,
+}
+
+/// Response from validating a config.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValidateConfigResponse {
+    /// The hash the config would have if written, even if validation failed - lets callers
+    /// correlate a validation attempt with a subsequent `write_config` call.
+    pub hash: String,
+    /// Whether the config passed validation.
+    pub valid: bool,
+    /// A human-readable description of the first validation failure, if any.
+    /// Parsing/schema/template/model validation fails fast on the first error rather than
+    /// collecting every issue in the config.
+    pub error: Option<String>,
+    /// The structured form of `error` (the same shape used for `error_json` in gateway error
+    /// responses), for programmatic handling. There are no per-file/line spans available here,
+    /// since validation runs on the already-merged, in-memory config rather than the original
+    /// TOML text - this is as granular as the section/field name embedded in the error itself.
+    pub error_details: Option<serde_json::Value>,
+    /// Providers across different `[models.*]` entries that appear to be redundant or
+    /// conflicting definitions of the same underlying model (see
+    /// `UninitializedConfig::find_duplicate_models`). This is populated regardless of `valid`,
+    /// since it's an advisory finding rather than a validation failure.
+    pub duplicate_models: Vec<DuplicateModelFinding>,
+}
+
+/// Handler for `POST /internal/config/validate`
+///
+/// Runs the same parsing, schema, template, and model validation as `write_config` (optionally
+/// including model provider credential and object storage checks), without persisting a
+/// snapshot.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.validate", skip_all)]
+pub async fn validate_config_handler(
+    State(app_state): AppState,
+    StructuredJson(request): StructuredJson<ValidateConfigRequest>,
+) -> Result<Json<ValidateConfigResponse>, Error> {
+    Ok(Json(validate_config(&app_state.config, request).await))
+}
+
+/// Core validation logic, shared between the HTTP handler and the embedded gateway client.
+/// `live_config` supplies the runtime overlay (gateway/postgres/rate-limiting/object-storage
+/// settings) that a written config would run under - only `request.config` is validated.
+pub async fn validate_config(
+    live_config: &Config,
+    request: ValidateConfigRequest,
+) -> ValidateConfigResponse {
+    let duplicate_models = request.config.find_duplicate_models();
+
+    let snapshot = match ConfigSnapshot::new(request.config, request.extra_templates) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            return ValidateConfigResponse {
+                hash: String::new(),
+                valid: false,
+                error: Some(e.to_string()),
+                error_details: serde_json::to_value(e.get_details()).ok(),
+                duplicate_models,
+            };
+        }
+    };
+    let hash = snapshot.hash.to_string();
+    let runtime_overlay = RuntimeOverlay::from_config(live_config);
+
+    match Config::load_from_snapshot(snapshot, runtime_overlay, request.verify_credentials).await {
+        Ok(_) => ValidateConfigResponse {
+            hash,
+            valid: true,
+            error: None,
+            error_details: None,
+            duplicate_models,
+        },
+        Err(e) => ValidateConfigResponse {
+            hash,
+            valid: false,
+            error: Some(e.to_string()),
+            error_details: serde_json::to_value(e.get_details()).ok(),
+            duplicate_models,
+        },
+    }
+}
+
+/// Query parameters for `GET /internal/config/snapshots`.
+#[derive(Debug, Deserialize)]
+pub struct ListConfigSnapshotsRequest {
+    #[serde(default = "default_list_snapshots_limit")]
+    pub limit: u32,
+    #[serde(default)]
+    pub offset: u32,
+    /// If set (together with `tag_value`), only include snapshots with a matching tag.
+    #[serde(default)]
+    pub tag_key: Option<String>,
+    #[serde(default)]
+    pub tag_value: Option<String>,
+}
+
+fn default_list_snapshots_limit() -> u32 {
+    100
+}
+
+/// A single entry in `ListConfigSnapshotsResponse`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigSnapshotSummaryResponse {
+    pub hash: String,
+    pub tags: HashMap<String, String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Response for `GET /internal/config/snapshots`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListConfigSnapshotsResponse {
+    pub snapshots: Vec<ConfigSnapshotSummaryResponse>,
+}
+
+/// Handler for `GET /internal/config/snapshots`
+///
+/// Returns config snapshots ordered by creation time (most recent first), with pagination and
+/// optional tag filtering.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.list_snapshots", skip_all)]
+pub async fn list_config_snapshots_handler(
+    State(app_state): AppState,
+    Query(request): Query<ListConfigSnapshotsRequest>,
+) -> Result<Json<ListConfigSnapshotsResponse>, Error> {
+    Ok(Json(
+        list_config_snapshots(&app_state.clickhouse_connection_info, request).await?,
+    ))
+}
+
+/// Core business logic for listing config snapshots. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler.
+pub async fn list_config_snapshots(
+    clickhouse: &ClickHouseConnectionInfo,
+    request: ListConfigSnapshotsRequest,
+) -> Result<ListConfigSnapshotsResponse, Error> {
+    let tag_filter = match (request.tag_key, request.tag_value) {
+        (Some(key), Some(value)) => Some(ConfigSnapshotTagFilter { key, value }),
+        _ => None,
+    };
+
+    let summaries = clickhouse
+        .list_config_snapshots(ListConfigSnapshotsParams {
+            limit: request.limit,
+            offset: request.offset,
+            tag_filter,
+        })
+        .await?;
+
+    Ok(ListConfigSnapshotsResponse {
+        snapshots: summaries
+            .into_iter()
+            .map(|summary| ConfigSnapshotSummaryResponse {
+                hash: summary.hash.to_string(),
+                tags: summary.tags,
+                created_at: summary.created_at,
+            })
+            .collect(),
+    })
+}
+
+/// Request body for `POST /internal/config/{hash}/tags`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateSnapshotTagsRequest {
+    /// Tags to merge into the snapshot's existing tags (new tags override existing keys).
+    pub tags: HashMap<String, String>,
+}
+
+/// Response from updating a config snapshot's tags.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateSnapshotTagsResponse {
+    pub hash: String,
+    pub tags: HashMap<String, String>,
+}
+
+/// Handler for `POST /internal/config/{hash}/tags`
+///
+/// Merges the request's tags into the config snapshot's existing tags, leaving its config and
+/// templates untouched.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.update_snapshot_tags", skip_all, fields(hash = %hash))]
+pub async fn update_snapshot_tags_handler(
+    State(app_state): AppState,
+    Path(hash): Path<String>,
+    StructuredJson(request): StructuredJson<UpdateSnapshotTagsRequest>,
+) -> Result<Json<UpdateSnapshotTagsResponse>, Error> {
+    Ok(Json(
+        update_snapshot_tags(&app_state.clickhouse_connection_info, &hash, request.tags).await?,
+    ))
+}
+
+/// Core business logic for merging tags into a config snapshot. Also used directly by the
+/// embedded gateway client, so it's reusable outside of the axum handler.
+pub async fn update_snapshot_tags(
+    clickhouse: &ClickHouseConnectionInfo,
+    config_snapshot_hash: &str,
+    tags: HashMap<String, String>,
+) -> Result<UpdateSnapshotTagsResponse, Error> {
+    let snapshot_hash: SnapshotHash = config_snapshot_hash.parse().map_err(|_| {
+        Error::new(ErrorDetails::ConfigSnapshotNotFound {
+            snapshot_hash: config_snapshot_hash.to_string(),
+        })
+    })?;
+
+    clickhouse
+        .update_snapshot_tags(snapshot_hash.clone(), tags)
+        .await?;
+
+    let snapshot = clickhouse.get_config_snapshot(snapshot_hash).await?;
+    Ok(UpdateSnapshotTagsResponse {
+        hash: snapshot.hash.to_string(),
+        tags: snapshot.tags,
+    })
+}
+
+// ================================================================
+// Canary rollouts
+//
+// A canary rollout stages a config snapshot for a percentage of traffic, keyed by episode ID
+// or by a tag, and tracks a guardrail metric so the rollout can be aborted if it regresses.
+//
+// The rollout's state is stored as tags on the config snapshot it targets (tags are already
+// mutable, merge-on-write metadata for a snapshot - see `write_config_handler` above), rather
+// than in a new table. This keeps a canary rollout's lifecycle attached to the snapshot it
+// describes, and reuses machinery that's already relied on for the same purpose (see
+// `AutopilotSideInfo::to_tags` for another example of tags used for auditability).
+//
+// NOTE: this only tracks a rollout's state (percentage, guardrail metric, status) - it does not
+// wire percentage-based routing into inference variant selection, and it does not automatically
+// poll the guardrail metric and call `abort`. Actually shifting live traffic and evaluating the
+// guardrail are left to a caller (e.g. Autopilot, which already has tools for querying feedback
+// and comparing evaluation runs) that starts the rollout, polls `get_canary_status` alongside its
+// own guardrail evaluation, and calls `abort_canary` if the guardrail regresses.
+// ================================================================
+
+const CANARY_TAG_PERCENTAGE: &str = "tensorzero::canary::percentage";
+const CANARY_TAG_GUARDRAIL_METRIC_NAME: &str = "tensorzero::canary::guardrail_metric_name";
+const CANARY_TAG_KEY: &str = "tensorzero::canary::key";
+const CANARY_TAG_STATUS: &str = "tensorzero::canary::status";
+const CANARY_TAG_STARTED_AT: &str = "tensorzero::canary::started_at";
+const CANARY_TAG_ABORTED_REASON: &str = "tensorzero::canary::aborted_reason";
+
+/// What a canary rollout's traffic percentage is keyed by.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum CanaryRolloutKey {
+    /// Bucket by episode ID, so all inferences in an episode land on the same side of the split.
+    Episode,
+    /// Bucket by the value of a specific tag.
+    Tag { name: String },
+}
+
+impl std::fmt::Display for CanaryRolloutKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanaryRolloutKey::Episode => write!(f, "episode"),
+            CanaryRolloutKey::Tag { name } => write!(f, "tag:{name}"),
+        }
+    }
+}
+
+impl std::str::FromStr for CanaryRolloutKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s == "episode" {
+            Ok(CanaryRolloutKey::Episode)
+        } else if let Some(name) = s.strip_prefix("tag:") {
+            Ok(CanaryRolloutKey::Tag {
+                name: name.to_string(),
+            })
+        } else {
+            Err(Error::new(ErrorDetails::Config {
+                message: format!("Invalid canary rollout key: {s}"),
+            }))
+        }
+    }
+}
+
+/// The status of a canary rollout.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum CanaryStatus {
+    Active,
+    Aborted,
+}
+
+/// The state of a canary rollout for a config snapshot.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct CanaryRollout {
+    /// The hash of the config snapshot being staged.
+    pub config_snapshot_hash: String,
+    /// The percentage (0-100) of traffic routed to `config_snapshot_hash`.
+    pub percentage: f64,
+    /// The metric name that guards this rollout; a caller should abort the rollout if it
+    /// regresses beyond an acceptable margin.
+    pub guardrail_metric_name: String,
+    /// What the traffic split is keyed by.
+    pub key: CanaryRolloutKey,
+    pub status: CanaryStatus,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aborted_reason: Option<String>,
+}
+
+impl CanaryRollout {
+    /// Serializes this rollout's state as tags to be merged into the config snapshot's tags.
+    fn to_tags(&self) -> HashMap<String, String> {
+        let mut tags = HashMap::new();
+        tags.insert(
+            CANARY_TAG_PERCENTAGE.to_string(),
+            self.percentage.to_string(),
+        );
+        tags.insert(
+            CANARY_TAG_GUARDRAIL_METRIC_NAME.to_string(),
+            self.guardrail_metric_name.clone(),
+        );
+        tags.insert(CANARY_TAG_KEY.to_string(), self.key.to_string());
+        let status = match self.status {
+            CanaryStatus::Active => "active",
+            CanaryStatus::Aborted => "aborted",
+        };
+        tags.insert(CANARY_TAG_STATUS.to_string(), status.to_string());
+        tags.insert(
+            CANARY_TAG_STARTED_AT.to_string(),
+            self.started_at.to_rfc3339(),
+        );
+        if let Some(reason) = &self.aborted_reason {
+            tags.insert(CANARY_TAG_ABORTED_REASON.to_string(), reason.clone());
+        }
+        tags
+    }
+
+    /// Reconstructs a rollout's state from a config snapshot's tags. Returns `None` if the
+    /// snapshot has no canary rollout tags at all.
+    fn from_tags(config_snapshot_hash: &str, tags: &HashMap<String, String>) -> Option<Self> {
+        let percentage = tags.get(CANARY_TAG_PERCENTAGE)?.parse().ok()?;
+        let guardrail_metric_name = tags.get(CANARY_TAG_GUARDRAIL_METRIC_NAME)?.clone();
+        let key: CanaryRolloutKey = tags.get(CANARY_TAG_KEY)?.parse().ok()?;
+        let status = match tags.get(CANARY_TAG_STATUS)?.as_str() {
+            "active" => CanaryStatus::Active,
+            "aborted" => CanaryStatus::Aborted,
+            _ => return None,
+        };
+        let started_at = DateTime::parse_from_rfc3339(tags.get(CANARY_TAG_STARTED_AT)?)
+            .ok()?
+            .with_timezone(&Utc);
+        let aborted_reason = tags.get(CANARY_TAG_ABORTED_REASON).cloned();
+        Some(Self {
+            config_snapshot_hash: config_snapshot_hash.to_string(),
+            percentage,
+            guardrail_metric_name,
+            key,
+            status,
+            started_at,
+            aborted_reason,
+        })
+    }
+}
+
+/// Request body for starting a canary rollout.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StartCanaryRequest {
+    /// The percentage (0-100) of traffic to route to this config snapshot.
+    pub percentage: f64,
+    /// The metric name that guards this rollout.
+    pub guardrail_metric_name: String,
+    /// What the traffic split should be keyed by.
+    pub key: CanaryRolloutKey,
+}
+
+async fn load_snapshot_for_canary(
+    clickhouse: &ClickHouseConnectionInfo,
+    hash: &str,
+) -> Result<ConfigSnapshot, Error> {
+    let snapshot_hash: SnapshotHash = hash.parse().map_err(|_| {
+        Error::new(ErrorDetails::ConfigSnapshotNotFound {
+            snapshot_hash: hash.to_string(),
+        })
+    })?;
+    clickhouse.get_config_snapshot(snapshot_hash).await
+}
+
+/// Core business logic for starting a canary rollout. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler.
+pub async fn start_canary(
+    clickhouse: &ClickHouseConnectionInfo,
+    config_snapshot_hash: &str,
+    request: StartCanaryRequest,
+) -> Result<CanaryRollout, Error> {
+    if !(0.0..=100.0).contains(&request.percentage) {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "`percentage` must be between 0 and 100".to_string(),
+        }));
+    }
+
+    let mut snapshot = load_snapshot_for_canary(clickhouse, config_snapshot_hash).await?;
+
+    if !snapshot
+        .config
+        .metrics
+        .contains_key(&request.guardrail_metric_name)
+    {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!(
+                "Unknown guardrail metric: {}",
+                request.guardrail_metric_name
+            ),
+        }));
+    }
+
+    if let Some(existing) = CanaryRollout::from_tags(config_snapshot_hash, &snapshot.tags) {
+        if existing.status == CanaryStatus::Active {
+            return Err(Error::new(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "A canary rollout is already active for config snapshot `{config_snapshot_hash}`; abort it first"
+                ),
+            }));
+        }
+    }
+
+    let rollout = CanaryRollout {
+        config_snapshot_hash: config_snapshot_hash.to_string(),
+        percentage: request.percentage,
+        guardrail_metric_name: request.guardrail_metric_name,
+        key: request.key,
+        status: CanaryStatus::Active,
+        started_at: Utc::now(),
+        aborted_reason: None,
+    };
+    // Clear any stale reason left over from a previous, aborted rollout of this snapshot.
+    snapshot.tags.remove(CANARY_TAG_ABORTED_REASON);
+    snapshot.tags.extend(rollout.to_tags());
+
+    write_config_snapshot(clickhouse, snapshot).await?;
+
+    Ok(rollout)
+}
+
+/// Core business logic for reading a canary rollout's state. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler.
+pub async fn get_canary_status(
+    clickhouse: &ClickHouseConnectionInfo,
+    config_snapshot_hash: &str,
+) -> Result<Option<CanaryRollout>, Error> {
+    let snapshot = load_snapshot_for_canary(clickhouse, config_snapshot_hash).await?;
+    Ok(CanaryRollout::from_tags(
+        config_snapshot_hash,
+        &snapshot.tags,
+    ))
+}
+
+/// Request body for aborting a canary rollout.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AbortCanaryRequest {
+    /// Why the rollout is being aborted (e.g. which guardrail metric regressed).
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Core business logic for aborting a canary rollout. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler.
+pub async fn abort_canary(
+    clickhouse: &ClickHouseConnectionInfo,
+    config_snapshot_hash: &str,
+    request: AbortCanaryRequest,
+) -> Result<CanaryRollout, Error> {
+    let mut snapshot = load_snapshot_for_canary(clickhouse, config_snapshot_hash).await?;
+
+    let mut rollout =
+        CanaryRollout::from_tags(config_snapshot_hash, &snapshot.tags).ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "No canary rollout found for config snapshot `{config_snapshot_hash}`"
+                ),
+            })
+        })?;
+    if rollout.status == CanaryStatus::Aborted {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!(
+                "Canary rollout for config snapshot `{config_snapshot_hash}` is already aborted"
+            ),
+        }));
+    }
+
+    rollout.status = CanaryStatus::Aborted;
+    rollout.aborted_reason = request.reason;
+    snapshot.tags.extend(rollout.to_tags());
+
+    write_config_snapshot(clickhouse, snapshot).await?;
+
+    Ok(rollout)
+}
+
+/// Handler for `POST /internal/config/{hash}/canary`
+///
+/// Stages `hash` as a canary rollout: records the rollout's percentage, guardrail metric, and
+/// key as tags on the snapshot. Fails if a canary rollout is already active for this snapshot.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.start_canary", skip_all, fields(hash = %hash))]
+pub async fn start_canary_handler(
+    State(app_state): AppState,
+    Path(hash): Path<String>,
+    StructuredJson(request): StructuredJson<StartCanaryRequest>,
+) -> Result<Json<CanaryRollout>, Error> {
+    Ok(Json(
+        start_canary(&app_state.clickhouse_connection_info, &hash, request).await?,
+    ))
+}
+
+/// Handler for `GET /internal/config/{hash}/canary`
+///
+/// Returns the canary rollout state for `hash`, or `null` if it has none.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.get_canary_status", skip_all, fields(hash = %hash))]
+pub async fn get_canary_status_handler(
+    State(app_state): AppState,
+    Path(hash): Path<String>,
+) -> Result<Json<Option<CanaryRollout>>, Error> {
+    Ok(Json(
+        get_canary_status(&app_state.clickhouse_connection_info, &hash).await?,
+    ))
+}
+
+/// Handler for `POST /internal/config/{hash}/canary/abort`
+///
+/// Marks the canary rollout for `hash` as aborted. Fails if there is no active rollout.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.abort_canary", skip_all, fields(hash = %hash))]
+pub async fn abort_canary_handler(
+    State(app_state): AppState,
+    Path(hash): Path<String>,
+    StructuredJson(request): StructuredJson<AbortCanaryRequest>,
+) -> Result<Json<CanaryRollout>, Error> {
+    Ok(Json(
+        abort_canary(&app_state.clickhouse_connection_info, &hash, request).await?,
+    ))
+}
+
+// ================================================================
+// Diffing a staged snapshot against another snapshot (typically the live config)
+//
+// This is deliberately a read-only, structural comparison of the two snapshots' configs - it
+// does not attempt to model "promotion" (atomically swapping which snapshot is live). The
+// gateway loads its config once at startup and has no mechanism for swapping `AppStateData.config`
+// out from under in-flight requests, so an atomic promote operation has nowhere to plug in today;
+// building one is a much larger project (safe hot-reload of models/variants/templates while
+// requests are in flight) than this diff view. What a caller *can* already do with a staged
+// snapshot, via the endpoints above, is validate it (`validate_config_handler`), write it
+// (`write_config_handler`), and stage it for a percentage of traffic (`start_canary_handler`) -
+// this adds the missing "what would actually change" step in between.
+// ================================================================
+
+/// A single dotted-path config value that changed between two snapshots.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ChangedConfigField {
+    /// The dotted path to the changed value (e.g. `models.gpt-4o.providers.openai.model_name`).
+    pub path: String,
+    pub base: serde_json::Value,
+    pub target: serde_json::Value,
+}
+
+/// Response from diffing two config snapshots.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct DiffConfigResponse {
+    /// The hash of the snapshot being compared against (defaults to the live config's hash).
+    pub base_hash: String,
+    /// The hash of the snapshot being diffed (the `{hash}` path segment).
+    pub target_hash: String,
+    /// Dotted paths present in `target_hash` but not in `base_hash`.
+    pub added: Vec<String>,
+    /// Dotted paths present in `base_hash` but not in `target_hash`.
+    pub removed: Vec<String>,
+    /// Dotted paths present in both snapshots with different values.
+    pub changed: Vec<ChangedConfigField>,
+}
+
+/// Query parameters for `GET /internal/config/{hash}/diff`.
+#[derive(Debug, Deserialize)]
+pub struct DiffConfigQuery {
+    /// The snapshot hash to diff against. Defaults to the live config's hash.
+    #[serde(default)]
+    pub against: Option<String>,
+}
+
+/// Handler for `GET /internal/config/{hash}/diff`
+///
+/// Structurally diffs the config snapshot `hash` against another snapshot (`against`, defaulting
+/// to the live config), field by field.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "config.diff", skip_all, fields(hash = %hash))]
+pub async fn diff_config_handler(
+    State(app_state): AppState,
+    Path(hash): Path<String>,
+    Query(query): Query<DiffConfigQuery>,
+) -> Result<Json<DiffConfigResponse>, Error> {
+    let base_hash = query
+        .against
+        .unwrap_or_else(|| app_state.config.hash.to_string());
+    Ok(Json(
+        diff_config(&app_state.clickhouse_connection_info, &base_hash, &hash).await?,
+    ))
+}
+
+/// Core business logic for diffing two config snapshots. Also used directly by the embedded
+/// gateway client, so it's reusable outside of the axum handler.
+pub async fn diff_config(
+    clickhouse: &ClickHouseConnectionInfo,
+    base_hash: &str,
+    target_hash: &str,
+) -> Result<DiffConfigResponse, Error> {
+    let base = load_snapshot_for_canary(clickhouse, base_hash).await?;
+    let target = load_snapshot_for_canary(clickhouse, target_hash).await?;
+
+    let base_value = serde_json::to_value(&base.config).map_err(|e| {
+        Error::new(ErrorDetails::Serialization {
+            message: format!("Failed to serialize base config for diffing: {e}"),
+        })
+    })?;
+    let target_value = serde_json::to_value(&target.config).map_err(|e| {
+        Error::new(ErrorDetails::Serialization {
+            message: format!("Failed to serialize target config for diffing: {e}"),
+        })
+    })?;
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    diff_json_values(
+        "",
+        &base_value,
+        &target_value,
+        &mut added,
+        &mut removed,
+        &mut changed,
+    );
+    added.sort();
+    removed.sort();
+    changed.sort_by(|a: &ChangedConfigField, b: &ChangedConfigField| a.path.cmp(&b.path));
+
+    Ok(DiffConfigResponse {
+        base_hash: base_hash.to_string(),
+        target_hash: target_hash.to_string(),
+        added,
+        removed,
+        changed,
+    })
+}
+
+/// Recursively walks two JSON values in lockstep, recording every dotted path that was added,
+/// removed, or changed between them. Objects are recursed into; any other value (including
+/// arrays, which we don't attempt to diff element-by-element) is compared for equality as a
+/// whole leaf value.
+fn diff_json_values(
+    prefix: &str,
+    base: &serde_json::Value,
+    target: &serde_json::Value,
+    added: &mut Vec<String>,
+    removed: &mut Vec<String>,
+    changed: &mut Vec<ChangedConfigField>,
+) {
+    match (base, target) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(target_map)) => {
+            let mut keys: Vec<&String> = base_map.keys().chain(target_map.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                match (base_map.get(key), target_map.get(key)) {
+                    (Some(b), Some(t)) => {
+                        diff_json_values(&path, b, t, added, removed, changed);
+                    }
+                    (Some(_), None) => removed.push(path),
+                    (None, Some(_)) => added.push(path),
+                    (None, None) => unreachable!(
+                        "path came from the union of both maps' keys, so at least one side has it"
+                    ),
+                }
+            }
+        }
+        _ if base != target => changed.push(ChangedConfigField {
+            path: prefix.to_string(),
+            base: base.clone(),
+            target: target.clone(),
+        }),
+        _ => {}
+    }
+}
+
+// ================================================================
+// Retiring a variant
+//
+// Like `diff_config` above, this doesn't mutate a snapshot in place - there's no mechanism for
+// swapping `AppStateData.config` out from under in-flight requests, so "removing a variant"
+// means "stage a new config snapshot with the variant removed", which a caller can then
+// validate/write/canary through the existing endpoints.
+// ================================================================
+
+/// Request body for
+/// `POST /internal/config/{hash}/functions/{function_name}/variants/{variant_name}/retire`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RetireVariantRequest {
+    /// Skip the traffic-weight safety check. This is the only way to retire a variant of a
+    /// function using `track_and_stop` or `thompson_sampling` experimentation, since traffic
+    /// weight for those isn't something we can determine from the config alone.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Response from retiring a variant.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RetireVariantResponse {
+    /// The hash of the newly staged snapshot with the variant removed. The snapshot named by
+    /// `hash` in the request path is left untouched.
+    pub new_config_snapshot_hash: String,
+}
+
+/// Handler for
+/// `POST /internal/config/{hash}/functions/{function_name}/variants/{variant_name}/retire`
+///
+/// Removes `variant_name` from `function_name` after verifying it's safe to do so (see
+/// `retire_variant`), and stages the result as a new config snapshot.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(
+    name = "config.retire_variant",
+    skip_all,
+    fields(hash = %hash, function_name = %function_name, variant_name = %variant_name)
+)]
+pub async fn retire_variant_handler(
+    State(app_state): AppState,
+    Path((hash, function_name, variant_name)): Path<(String, String, String)>,
+    StructuredJson(request): StructuredJson<RetireVariantRequest>,
+) -> Result<Json<RetireVariantResponse>, Error> {
+    Ok(Json(
+        retire_variant(
+            &app_state.clickhouse_connection_info,
+            &hash,
+            &function_name,
+            &variant_name,
+            request,
+        )
+        .await?,
+    ))
+}
+
+/// Core business logic for retiring a variant. Also used directly by the embedded gateway
+/// client, so it's reusable outside of the axum handler.
+///
+/// Before removing `variant_name` from `function_name`, verifies:
+/// - the config snapshot has no active canary rollout (a canary can reference any variant of the
+///   snapshot it targets, so we conservatively block on any active rollout rather than trying to
+///   prove the retired variant specifically isn't involved)
+/// - the variant currently has zero traffic weight
+///
+/// Traffic weight can only be checked for functions using `static_weights` experimentation, or no
+/// `experimentation` config at all (which falls back to the legacy per-variant `weight` field).
+/// For `track_and_stop` and `thompson_sampling`, sampling probability is adaptive and isn't
+/// recoverable from the config alone, so that check is rejected outright unless `request.force`
+/// is set - we don't want to guess at whether a variant still has traffic.
+///
+/// "Archiving stats" is realized as a tag recording when and from where the variant was retired,
+/// following the same tags-on-an-immutable-snapshot pattern the canary rollouts above already use
+/// for auditability, rather than introducing a new stats table.
+pub async fn retire_variant(
+    clickhouse: &ClickHouseConnectionInfo,
+    config_snapshot_hash: &str,
+    function_name: &str,
+    variant_name: &str,
+    request: RetireVariantRequest,
+) -> Result<RetireVariantResponse, Error> {
+    let snapshot = load_snapshot_for_canary(clickhouse, config_snapshot_hash).await?;
+
+    if let Some(rollout) = CanaryRollout::from_tags(config_snapshot_hash, &snapshot.tags) {
+        if rollout.status == CanaryStatus::Active {
+            return Err(Error::new(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "Config snapshot `{config_snapshot_hash}` has an active canary rollout; abort it before retiring a variant from it"
+                ),
+            }));
+        }
+    }
+
+    let mut config: UninitializedConfig = snapshot.config.into();
+    let function = config.functions.get_mut(function_name).ok_or_else(|| {
+        Error::new(ErrorDetails::InvalidRequest {
+            message: format!("Unknown function: {function_name}"),
+        })
+    })?;
+    let (variants, experimentation) = match function {
+        UninitializedFunctionConfig::Chat(chat) => (&mut chat.variants, &mut chat.experimentation),
+        UninitializedFunctionConfig::Json(json) => (&mut json.variants, &mut json.experimentation),
+    };
+
+    let variant_info = variants.get(variant_name).ok_or_else(|| {
+        Error::new(ErrorDetails::InvalidRequest {
+            message: format!("Function `{function_name}` has no variant `{variant_name}`"),
+        })
+    })?;
+
+    if !request.force {
+        let has_traffic = match experimentation.as_ref() {
+            Some(UninitializedExperimentationConfig::StaticWeights(weights)) => {
+                weights.has_traffic(variant_name)
+            }
+            // Uniform sampling ignores the legacy `weight` field entirely and gives every
+            // configured variant equal traffic, so any variant that's still present has traffic.
+            Some(UninitializedExperimentationConfig::Uniform(_)) => true,
+            Some(UninitializedExperimentationConfig::TrackAndStop(_))
+            | Some(UninitializedExperimentationConfig::ThompsonSampling(_)) => {
+                return Err(Error::new(ErrorDetails::InvalidRequest {
+                    message: format!(
+                        "Function `{function_name}` uses adaptive experimentation; traffic weight for `{variant_name}` can't be verified from the config alone. Pass `force: true` to retire it anyway."
+                    ),
+                }));
+            }
+            None => variant_info.inner.weight() != Some(0.0),
+        };
+
+        if has_traffic {
+            return Err(Error::new(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "Variant `{variant_name}` of function `{function_name}` still has nonzero traffic weight; set its weight to 0 (or remove it from `static_weights`) before retiring it"
+                ),
+            }));
+        }
+    }
+
+    variants.remove(variant_name);
+    if let Some(UninitializedExperimentationConfig::StaticWeights(weights)) = experimentation {
+        weights.remove_variant(variant_name);
+    }
+
+    let mut new_snapshot = ConfigSnapshot::new(config, snapshot.extra_templates)?;
+    new_snapshot.tags = snapshot.tags;
+    new_snapshot.tags.insert(
+        format!("tensorzero::retired_variant::{function_name}::{variant_name}"),
+        Utc::now().to_rfc3339(),
+    );
+
+    let new_config_snapshot_hash = new_snapshot.hash.to_string();
+    write_config_snapshot(clickhouse, new_snapshot).await?;
 
-    Ok(Json(WriteConfigResponse { hash }))
+    Ok(RetireVariantResponse {
+        new_config_snapshot_hash,
+    })
 }
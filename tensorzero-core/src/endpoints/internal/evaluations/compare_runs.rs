@@ -0,0 +1,304 @@
+//! Handler for comparing two evaluation runs: per-metric deltas with paired statistical tests,
+//! flagging significant regressions.
+
+use std::collections::HashMap;
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::config::MetricConfigOptimize;
+use crate::db::evaluation_queries::EvaluationQueries;
+use crate::error::{Error, ErrorDetails};
+use crate::evaluations::{EvaluationConfig, get_evaluator_metric_name};
+use crate::function::{FunctionConfigType, get_function};
+use crate::utils::gateway::{AppState, AppStateData};
+
+/// Query parameters for comparing two evaluation runs.
+#[derive(Debug, Deserialize)]
+pub struct CompareEvaluationRunsParams {
+    pub other_evaluation_run_id: Uuid,
+    pub evaluation_name: String,
+    pub function_name: String,
+}
+
+/// Per-metric comparison between two evaluation runs.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct EvaluatorDelta {
+    pub metric_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_a_mean: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_b_mean: Option<f64>,
+    /// Number of datapoints for which both runs produced feedback for this metric.
+    pub paired_datapoint_count: u32,
+    /// Mean of `run_b`'s value minus `run_a`'s value, across paired datapoints.
+    pub mean_diff: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_lower: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_upper: Option<f64>,
+    /// Whether `mean_diff`'s confidence interval excludes zero in the direction that's
+    /// unfavorable for this metric's configured optimization direction.
+    pub is_regression: bool,
+}
+
+/// Response comparing two evaluation runs.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct EvaluationRunComparison {
+    pub run_a: Uuid,
+    pub run_b: Uuid,
+    pub deltas: Vec<EvaluatorDelta>,
+}
+
+/// Handler for `GET /internal/evaluations/runs/{evaluation_run_id}/compare`
+///
+/// Compares `evaluation_run_id` (`run_a`) against `other_evaluation_run_id` (`run_b`), pairing
+/// per-datapoint feedback on shared datapoint IDs so the comparison isn't skewed by datapoints
+/// only one of the two runs happened to cover.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "evaluations.compare_runs", skip_all, fields(run_a = %run_a))]
+pub async fn compare_evaluation_runs_handler(
+    State(app_state): AppState,
+    Path(run_a): Path<Uuid>,
+    Query(params): Query<CompareEvaluationRunsParams>,
+) -> Result<Json<EvaluationRunComparison>, Error> {
+    let evaluation_config = app_state
+        .config
+        .evaluations
+        .get(&params.evaluation_name)
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Unknown evaluation: {}", params.evaluation_name),
+            })
+        })?;
+    let function_config = get_function(&app_state.config.functions, &params.function_name)?;
+    let function_type = function_config.config_type();
+
+    let response = compare_evaluation_runs(
+        &app_state.clickhouse_connection_info,
+        run_a,
+        params.other_evaluation_run_id,
+        &params.evaluation_name,
+        &params.function_name,
+        function_type,
+        evaluation_config,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// Core business logic for comparing two evaluation runs. Also used directly by the embedded
+/// gateway client, so it's testable with mock ClickHouse and reusable outside of the axum handler.
+#[expect(clippy::too_many_arguments)]
+pub async fn compare_evaluation_runs(
+    clickhouse: &impl EvaluationQueries,
+    run_a: Uuid,
+    run_b: Uuid,
+    evaluation_name: &str,
+    function_name: &str,
+    function_type: FunctionConfigType,
+    evaluation_config: &EvaluationConfig,
+) -> Result<EvaluationRunComparison, Error> {
+    let EvaluationConfig::Inference(inference_evaluation_config) = evaluation_config;
+
+    // Map each metric name to the optimization direction of the evaluator that produces it, so a
+    // paired difference can be judged "significant and unfavorable" (i.e. a regression) rather
+    // than just "significant".
+    let optimize_by_metric_name: HashMap<String, MetricConfigOptimize> =
+        inference_evaluation_config
+            .evaluators
+            .iter()
+            .map(|(evaluator_name, evaluator_config)| {
+                let metric_name = get_evaluator_metric_name(evaluation_name, evaluator_name);
+                (metric_name, evaluator_config.optimize())
+            })
+            .collect();
+    let metric_names: Vec<String> = optimize_by_metric_name.keys().cloned().collect();
+
+    if metric_names.is_empty() {
+        return Ok(EvaluationRunComparison {
+            run_a,
+            run_b,
+            deltas: Vec::new(),
+        });
+    }
+
+    let run_ids = [run_a, run_b];
+    let statistics_rows = clickhouse
+        .get_evaluation_statistics(function_name, function_type, &metric_names, &run_ids)
+        .await?;
+    let mut mean_by_run_and_metric: HashMap<(Uuid, String), f64> = HashMap::new();
+    for row in statistics_rows {
+        mean_by_run_and_metric.insert((row.evaluation_run_id, row.metric_name), row.mean_metric);
+    }
+
+    let comparison_rows = clickhouse
+        .get_evaluation_run_comparison(function_name, &metric_names, run_a, run_b)
+        .await?;
+
+    let deltas = comparison_rows
+        .into_iter()
+        .map(|row| {
+            let optimize = optimize_by_metric_name
+                .get(&row.metric_name)
+                .copied()
+                .unwrap_or(MetricConfigOptimize::Max);
+            let is_regression = match optimize {
+                MetricConfigOptimize::Max => row.ci_upper.is_some_and(|upper| upper < 0.0),
+                MetricConfigOptimize::Min => row.ci_lower.is_some_and(|lower| lower > 0.0),
+            };
+            EvaluatorDelta {
+                run_a_mean: mean_by_run_and_metric
+                    .get(&(run_a, row.metric_name.clone()))
+                    .copied(),
+                run_b_mean: mean_by_run_and_metric
+                    .get(&(run_b, row.metric_name.clone()))
+                    .copied(),
+                metric_name: row.metric_name,
+                paired_datapoint_count: row.paired_datapoint_count,
+                mean_diff: row.mean_diff,
+                ci_lower: row.ci_lower,
+                ci_upper: row.ci_upper,
+                is_regression,
+            }
+        })
+        .collect();
+
+    Ok(EvaluationRunComparison {
+        run_a,
+        run_b,
+        deltas,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::db::evaluation_queries::{
+        EvaluationComparisonRow, EvaluationStatisticsRow, MockEvaluationQueries,
+    };
+    use crate::evaluations::{EvaluatorConfig, InferenceEvaluationConfig};
+
+    fn test_evaluation_config() -> EvaluationConfig {
+        let mut evaluators = HashMap::new();
+        evaluators.insert(
+            "exact_match".to_string(),
+            EvaluatorConfig::ExactMatch(Default::default()),
+        );
+        EvaluationConfig::Inference(InferenceEvaluationConfig {
+            evaluators,
+            function_name: "test_function".to_string(),
+            description: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_compare_evaluation_runs_no_evaluators_returns_empty() {
+        let evaluation_config = EvaluationConfig::Inference(InferenceEvaluationConfig {
+            evaluators: HashMap::new(),
+            function_name: "test_function".to_string(),
+            description: None,
+        });
+        let mock_clickhouse = MockEvaluationQueries::new();
+
+        let result = compare_evaluation_runs(
+            &mock_clickhouse,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test_evaluation",
+            "test_function",
+            FunctionConfigType::Chat,
+            &evaluation_config,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            result.deltas.is_empty(),
+            "Expected no deltas when the evaluation has no evaluators"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compare_evaluation_runs_flags_regression() {
+        let run_a = Uuid::now_v7();
+        let run_b = Uuid::now_v7();
+        let metric_name = get_evaluator_metric_name("test_evaluation", "exact_match");
+
+        let mut mock_clickhouse = MockEvaluationQueries::new();
+        mock_clickhouse
+            .expect_get_evaluation_statistics()
+            .times(1)
+            .returning(move |_, _, _, _| {
+                let metric_name = metric_name.clone();
+                Box::pin(async move {
+                    Ok(vec![
+                        EvaluationStatisticsRow {
+                            evaluation_run_id: run_a,
+                            metric_name: metric_name.clone(),
+                            datapoint_count: 10,
+                            mean_metric: 0.9,
+                            ci_lower: None,
+                            ci_upper: None,
+                        },
+                        EvaluationStatisticsRow {
+                            evaluation_run_id: run_b,
+                            metric_name,
+                            datapoint_count: 10,
+                            mean_metric: 0.5,
+                            ci_lower: None,
+                            ci_upper: None,
+                        },
+                    ])
+                })
+            });
+        let comparison_metric_name = get_evaluator_metric_name("test_evaluation", "exact_match");
+        mock_clickhouse
+            .expect_get_evaluation_run_comparison()
+            .times(1)
+            .returning(move |_, _, _, _| {
+                Box::pin({
+                    let metric_name = comparison_metric_name.clone();
+                    async move {
+                        Ok(vec![EvaluationComparisonRow {
+                            metric_name,
+                            paired_datapoint_count: 10,
+                            mean_diff: -0.4,
+                            ci_lower: Some(-0.6),
+                            ci_upper: Some(-0.2),
+                        }])
+                    }
+                })
+            });
+
+        let result = compare_evaluation_runs(
+            &mock_clickhouse,
+            run_a,
+            run_b,
+            "test_evaluation",
+            "test_function",
+            FunctionConfigType::Chat,
+            &test_evaluation_config(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.deltas.len(), 1, "Expected one delta");
+        assert!(
+            result.deltas[0].is_regression,
+            "Expected a significant negative diff on a Max-optimized metric to be flagged"
+        );
+        assert_eq!(result.deltas[0].run_a_mean, Some(0.9));
+        assert_eq!(result.deltas[0].run_b_mean, Some(0.5));
+    }
+}
@@ -244,6 +244,7 @@ mod tests {
                         datapoint_id,
                         evaluation_run_id,
                         evaluator_inference_id: None,
+                        evaluator_snapshot_hash: None,
                         input: Input::default(),
                         generated_output: vec![],
                         reference_output: Some(vec![]),
@@ -493,6 +494,7 @@ mod tests {
                         datapoint_id,
                         evaluation_run_id,
                         evaluator_inference_id: None,
+                        evaluator_snapshot_hash: None,
                         input: Input::default(),
                         generated_output: vec![],
                         reference_output: Some(vec![]),
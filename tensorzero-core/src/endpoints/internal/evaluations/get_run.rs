@@ -0,0 +1,278 @@
+//! Handler for getting a single evaluation run, combining its identifying info with its
+//! aggregate per-metric statistics.
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::types::EvaluationStatistics;
+use crate::db::evaluation_queries::EvaluationQueries;
+use crate::error::{Error, ErrorDetails};
+use crate::evaluations::{EvaluationConfig, get_evaluator_metric_name};
+use crate::function::{FunctionConfigType, get_function};
+use crate::utils::gateway::{AppState, AppStateData};
+
+/// Query parameters for getting a single evaluation run.
+#[derive(Debug, Deserialize)]
+pub struct GetEvaluationRunParams {
+    pub evaluation_name: String,
+    pub function_name: String,
+}
+
+/// A single evaluation run, with its aggregate statistics.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct EvaluationRunDetails {
+    pub evaluation_run_id: Uuid,
+    pub evaluation_name: String,
+    pub function_name: String,
+    pub variant_name: String,
+    pub most_recent_inference_date: String,
+    pub statistics: Vec<EvaluationStatistics>,
+}
+
+/// Handler for `GET /internal/evaluations/runs/{evaluation_run_id}`
+///
+/// Returns identifying info and aggregate per-metric statistics for a single evaluation run, so
+/// past runs can be compared without a caller having to separately fetch run info and
+/// statistics and join them itself.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "evaluations.get_run", skip_all, fields(evaluation_run_id = %evaluation_run_id))]
+pub async fn get_evaluation_run_handler(
+    State(app_state): AppState,
+    Path(evaluation_run_id): Path<Uuid>,
+    Query(params): Query<GetEvaluationRunParams>,
+) -> Result<Json<EvaluationRunDetails>, Error> {
+    let evaluation_config = app_state
+        .config
+        .evaluations
+        .get(&params.evaluation_name)
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Unknown evaluation: {}", params.evaluation_name),
+            })
+        })?;
+    let function_config = get_function(&app_state.config.functions, &params.function_name)?;
+    let function_type = function_config.config_type();
+
+    let response = get_evaluation_run(
+        &app_state.clickhouse_connection_info,
+        evaluation_run_id,
+        &params.evaluation_name,
+        &params.function_name,
+        function_type,
+        evaluation_config,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// Core business logic for getting a single evaluation run. Also used directly by the embedded
+/// gateway client, so it's testable with mock ClickHouse and reusable outside of the axum handler.
+pub async fn get_evaluation_run(
+    clickhouse: &impl EvaluationQueries,
+    evaluation_run_id: Uuid,
+    evaluation_name: &str,
+    function_name: &str,
+    function_type: FunctionConfigType,
+    evaluation_config: &EvaluationConfig,
+) -> Result<EvaluationRunDetails, Error> {
+    let evaluation_run_ids = [evaluation_run_id];
+    let run_infos = clickhouse
+        .get_evaluation_run_infos(&evaluation_run_ids, function_name)
+        .await?;
+    let run_info = run_infos.into_iter().next().ok_or_else(|| {
+        Error::new(ErrorDetails::InvalidRequest {
+            message: format!(
+                "No evaluation run found with id {evaluation_run_id} for function `{function_name}`"
+            ),
+        })
+    })?;
+
+    let EvaluationConfig::Inference(inference_evaluation_config) = evaluation_config;
+    let metric_names: Vec<String> = inference_evaluation_config
+        .evaluators
+        .keys()
+        .map(|evaluator_name| get_evaluator_metric_name(evaluation_name, evaluator_name))
+        .collect();
+
+    let statistics_rows = if metric_names.is_empty() {
+        Vec::new()
+    } else {
+        clickhouse
+            .get_evaluation_statistics(
+                function_name,
+                function_type,
+                &metric_names,
+                &evaluation_run_ids,
+            )
+            .await?
+    };
+    let statistics = statistics_rows
+        .into_iter()
+        .map(|row| EvaluationStatistics {
+            evaluation_run_id: row.evaluation_run_id,
+            metric_name: row.metric_name,
+            datapoint_count: row.datapoint_count,
+            mean_metric: row.mean_metric,
+            ci_lower: row.ci_lower,
+            ci_upper: row.ci_upper,
+        })
+        .collect();
+
+    Ok(EvaluationRunDetails {
+        evaluation_run_id: run_info.evaluation_run_id,
+        evaluation_name: evaluation_name.to_string(),
+        function_name: function_name.to_string(),
+        variant_name: run_info.variant_name,
+        most_recent_inference_date: run_info.most_recent_inference_date.to_rfc3339(),
+        statistics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::db::evaluation_queries::{
+        EvaluationRunInfoByIdRow, EvaluationStatisticsRow, MockEvaluationQueries,
+    };
+    use crate::evaluations::InferenceEvaluationConfig;
+
+    fn test_evaluation_config() -> EvaluationConfig {
+        EvaluationConfig::Inference(InferenceEvaluationConfig {
+            evaluators: std::collections::HashMap::new(),
+            function_name: "test_function".to_string(),
+            description: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_evaluation_run_not_found() {
+        let mut mock_clickhouse = MockEvaluationQueries::new();
+        mock_clickhouse
+            .expect_get_evaluation_run_infos()
+            .times(1)
+            .returning(|_, _| Box::pin(async move { Ok(vec![]) }));
+
+        let result = get_evaluation_run(
+            &mock_clickhouse,
+            Uuid::now_v7(),
+            "test_evaluation",
+            "test_function",
+            FunctionConfigType::Chat,
+            &test_evaluation_config(),
+        )
+        .await;
+
+        assert!(
+            result.is_err(),
+            "Expected an error when no run info is found"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_evaluation_run_with_no_evaluators_skips_statistics() {
+        let run_id = Uuid::now_v7();
+        let timestamp = Utc::now();
+
+        let mut mock_clickhouse = MockEvaluationQueries::new();
+        mock_clickhouse
+            .expect_get_evaluation_run_infos()
+            .times(1)
+            .returning(move |_, _| {
+                Box::pin(async move {
+                    Ok(vec![EvaluationRunInfoByIdRow {
+                        evaluation_run_id: run_id,
+                        variant_name: "my_variant".to_string(),
+                        most_recent_inference_date: timestamp,
+                    }])
+                })
+            });
+        mock_clickhouse.expect_get_evaluation_statistics().times(0);
+
+        let result = get_evaluation_run(
+            &mock_clickhouse,
+            run_id,
+            "test_evaluation",
+            "test_function",
+            FunctionConfigType::Chat,
+            &test_evaluation_config(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.evaluation_run_id, run_id);
+        assert_eq!(result.variant_name, "my_variant");
+        assert!(
+            result.statistics.is_empty(),
+            "Expected no statistics when the evaluation has no evaluators"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_evaluation_run_includes_statistics() {
+        let run_id = Uuid::now_v7();
+        let timestamp = Utc::now();
+
+        let mut evaluators = std::collections::HashMap::new();
+        evaluators.insert(
+            "exact_match".to_string(),
+            crate::evaluations::EvaluatorConfig::ExactMatch(Default::default()),
+        );
+        let evaluation_config = EvaluationConfig::Inference(InferenceEvaluationConfig {
+            evaluators,
+            function_name: "test_function".to_string(),
+            description: None,
+        });
+
+        let mut mock_clickhouse = MockEvaluationQueries::new();
+        mock_clickhouse
+            .expect_get_evaluation_run_infos()
+            .times(1)
+            .returning(move |_, _| {
+                Box::pin(async move {
+                    Ok(vec![EvaluationRunInfoByIdRow {
+                        evaluation_run_id: run_id,
+                        variant_name: "my_variant".to_string(),
+                        most_recent_inference_date: timestamp,
+                    }])
+                })
+            });
+        mock_clickhouse
+            .expect_get_evaluation_statistics()
+            .times(1)
+            .returning(move |_, _, metric_names, _| {
+                let metric_name = metric_names[0].clone();
+                Box::pin(async move {
+                    Ok(vec![EvaluationStatisticsRow {
+                        evaluation_run_id: run_id,
+                        metric_name,
+                        datapoint_count: 10,
+                        mean_metric: 0.5,
+                        ci_lower: None,
+                        ci_upper: None,
+                    }])
+                })
+            });
+
+        let result = get_evaluation_run(
+            &mock_clickhouse,
+            run_id,
+            "test_evaluation",
+            "test_function",
+            FunctionConfigType::Chat,
+            &evaluation_config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.statistics.len(), 1, "Expected one statistics row");
+        assert_eq!(result.statistics[0].datapoint_count, 10);
+    }
+}
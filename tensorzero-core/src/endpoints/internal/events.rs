@@ -0,0 +1,85 @@
+//! Gateway activity event stream: exposes the in-process [`crate::events::GatewayEventBus`] to
+//! HTTP clients over SSE, so dashboards can show live activity (inferences, feedback, job
+//! progress, config changes) without polling the various list endpoints.
+//!
+//! See `crate::events` for which gateway code paths actually publish to the bus - not every
+//! mutation is wired up yet, so this is a best-effort activity feed, not an exhaustive audit log.
+
+use std::time::Duration;
+
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio_stream::StreamExt;
+use tracing::instrument;
+
+use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEvent;
+use crate::utils::gateway::{AppState, AppStateData};
+
+/// Minimum gap enforced between consecutive events sent to a single SSE connection. A slow
+/// dashboard consumer falls behind and misses events past the bus's buffer (see
+/// `GatewayEventBus`) rather than causing us to buffer unboundedly here.
+const MIN_EVENT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Query parameters for the event stream endpoint.
+#[derive(Debug, Deserialize)]
+pub struct StreamEventsQueryParams {
+    /// Only stream events of this kind (see [`GatewayEvent::kind`]), e.g. `inference` or
+    /// `feedback`. Streams every kind if omitted.
+    pub kind: Option<String>,
+}
+
+/// Handler for `GET /internal/events/stream`
+///
+/// Streams gateway activity events via SSE. Note: the `#[instrument]` macro is not used here
+/// due to lifetime issues with the SSE stream (see `autopilot::stream_events_handler`).
+#[axum::debug_handler(state = AppStateData)]
+pub async fn stream_events_handler(
+    State(app_state): AppState,
+    Query(query): Query<StreamEventsQueryParams>,
+) -> Result<impl IntoResponse, Error> {
+    let mut receiver = app_state.event_bus.subscribe();
+    let kind_filter = query.kind;
+
+    let event_stream = async_stream::stream! {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if let Some(kind_filter) = &kind_filter
+                        && event.kind() != kind_filter
+                    {
+                        continue;
+                    }
+                    yield serialize_event(&event);
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "Gateway event stream subscriber lagged, skipped {} events",
+                        skipped
+                    );
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    };
+
+    Ok(Sse::new(
+        event_stream
+            .throttle(MIN_EVENT_INTERVAL)
+            .take_until(app_state.shutdown_token.clone().cancelled_owned()),
+    )
+    .keep_alive(KeepAlive::new()))
+}
+
+fn serialize_event(event: &GatewayEvent) -> Result<SseEvent, Error> {
+    let data = serde_json::to_string(event).map_err(|e| {
+        Error::new(ErrorDetails::Serialization {
+            message: e.to_string(),
+        })
+    })?;
+    Ok(SseEvent::default().event(event.kind()).data(data))
+}
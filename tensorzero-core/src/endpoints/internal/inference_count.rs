@@ -1,5 +1,7 @@
 //! Inference count endpoint for getting inference counts and feedback counts.
 
+use std::collections::HashSet;
+
 use axum::extract::{Path, Query, State};
 use axum::{Json, debug_handler};
 use futures::future::try_join;
@@ -335,11 +337,25 @@ pub async fn get_function_throughput_by_variant(
     Ok(GetFunctionThroughputByVariantResponse { throughput })
 }
 
+/// Query parameters for the list-functions-with-inference-count endpoint
+#[derive(Debug, Deserialize)]
+pub struct ListFunctionsWithInferenceCountQueryParams {
+    /// Optional namespace filter, e.g. `billing/*` to only include functions whose name
+    /// starts with `billing/`, or `billing/classify_ticket` for an exact match. If omitted,
+    /// all functions are returned.
+    pub namespace: Option<String>,
+}
+
 /// HTTP handler for listing all functions with their inference counts
 #[debug_handler(state = AppStateData)]
-#[instrument(name = "list_functions_with_inference_count_handler", skip_all)]
+#[instrument(
+    name = "list_functions_with_inference_count_handler",
+    skip_all,
+    fields(namespace = ?params.namespace)
+)]
 pub async fn list_functions_with_inference_count_handler(
     State(state): State<AppStateData>,
+    Query(params): Query<ListFunctionsWithInferenceCountQueryParams>,
 ) -> Result<Json<ListFunctionsWithInferenceCountResponse>, Error> {
     let database: &(dyn InferenceCountQueries + Sync) = if ENABLE_POSTGRES_READ.get() {
         &state.postgres_connection_info
@@ -347,15 +363,27 @@ pub async fn list_functions_with_inference_count_handler(
         &state.clickhouse_connection_info
     };
 
-    let response = list_functions_with_inference_count(database).await?;
+    let response =
+        list_functions_with_inference_count(&state.config, database, params.namespace).await?;
     Ok(Json(response))
 }
 
 /// Core business logic for listing all functions with their inference counts
 async fn list_functions_with_inference_count(
+    config: &Config,
     database: &(dyn InferenceCountQueries + Sync),
+    namespace: Option<String>,
 ) -> Result<ListFunctionsWithInferenceCountResponse, Error> {
-    let functions = database.list_functions_with_inference_count().await?;
+    let mut functions = database.list_functions_with_inference_count().await?;
+
+    if let Some(namespace) = namespace {
+        let namespace_functions = config
+            .get_functions_in_namespace(&namespace)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<HashSet<_>>();
+        functions.retain(|function| namespace_functions.contains(function.function_name.as_str()));
+    }
 
     Ok(ListFunctionsWithInferenceCountResponse { functions })
 }
@@ -578,7 +606,8 @@ mod tests {
                 })
             });
 
-        let result = list_functions_with_inference_count(&mock_clickhouse)
+        let config = Config::default();
+        let result = list_functions_with_inference_count(&config, &mock_clickhouse, None)
             .await
             .unwrap();
 
@@ -598,13 +627,101 @@ mod tests {
             .times(1)
             .returning(|| Box::pin(async move { Ok(vec![]) }));
 
-        let result = list_functions_with_inference_count(&mock_clickhouse)
+        let config = Config::default();
+        let result = list_functions_with_inference_count(&config, &mock_clickhouse, None)
             .await
             .unwrap();
 
         assert!(result.functions.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_list_functions_with_inference_count_namespace_filter() {
+        use chrono::Utc;
+
+        let config_str = r#"
+            [functions."billing/classify_ticket"]
+            type = "chat"
+
+            [functions."billing/classify_ticket".variants.test_variant]
+            type = "chat_completion"
+            model = "openai::gpt-4"
+
+            [functions."billing/summarize_ticket"]
+            type = "chat"
+
+            [functions."billing/summarize_ticket".variants.test_variant]
+            type = "chat_completion"
+            model = "openai::gpt-4"
+
+            [functions.write_haiku]
+            type = "chat"
+
+            [functions.write_haiku.variants.test_variant]
+            type = "chat_completion"
+            model = "openai::gpt-4"
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_str.as_bytes()).unwrap();
+
+        let config = Config::load_from_path_optional_verify_credentials(
+            &ConfigFileGlob::new_from_path(temp_file.path()).unwrap(),
+            false,
+        )
+        .await
+        .unwrap()
+        .into_config_without_writing_for_tests();
+
+        let mut mock_clickhouse = MockClickHouseConnectionInfo::new();
+        mock_clickhouse
+            .inference_count_queries
+            .expect_list_functions_with_inference_count()
+            .times(1)
+            .returning(|| {
+                Box::pin(async move {
+                    Ok(vec![
+                        FunctionInferenceCount {
+                            function_name: "billing/classify_ticket".to_string(),
+                            last_inference_timestamp: Utc::now(),
+                            inference_count: 10,
+                        },
+                        FunctionInferenceCount {
+                            function_name: "billing/summarize_ticket".to_string(),
+                            last_inference_timestamp: Utc::now(),
+                            inference_count: 5,
+                        },
+                        FunctionInferenceCount {
+                            function_name: "write_haiku".to_string(),
+                            last_inference_timestamp: Utc::now(),
+                            inference_count: 150,
+                        },
+                    ])
+                })
+            });
+
+        let result = list_functions_with_inference_count(
+            &config,
+            &mock_clickhouse,
+            Some("billing/*".to_string()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.functions.len(),
+            2,
+            "namespace filter should only include functions under `billing/`"
+        );
+        assert!(
+            result
+                .functions
+                .iter()
+                .all(|f| f.function_name.starts_with("billing/")),
+            "all returned functions should be in the `billing` namespace"
+        );
+    }
+
     #[tokio::test]
     async fn test_get_inference_count_default_function_skips_variant_validation() {
         // Default config includes tensorzero::default which has no variants in config
@@ -687,8 +804,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_functions_with_postgres_disabled_returns_error() {
+        let config = Config::default();
         let postgres = PostgresConnectionInfo::new_disabled();
-        let result = list_functions_with_inference_count(&postgres).await;
+        let result = list_functions_with_inference_count(&config, &postgres, None).await;
 
         assert!(
             result.is_err(),
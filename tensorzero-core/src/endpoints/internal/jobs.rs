@@ -0,0 +1,86 @@
+//! Job endpoints: a read/cancel surface over the unified `Job` tracking table so operators have
+//! one place to see all background work (optimizations, evaluations, bulk inference, backfills),
+//! regardless of which subsystem started it. See [`crate::db::job`] for the tracking model;
+//! subsystems create and progress `Job` rows themselves as they run.
+
+use axum::extract::{Path, Query, State};
+use axum::{Json, debug_handler};
+use serde::Deserialize;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::db::job::{Job, JobKind, JobQueries, JobState};
+use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEvent;
+use crate::utils::gateway::{AppState, AppStateData};
+
+/// Query parameters for the list-jobs endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ListJobsQueryParams {
+    pub kind: Option<JobKind>,
+    pub state: Option<JobState>,
+    #[serde(default = "default_list_jobs_limit")]
+    pub limit: u64,
+}
+
+fn default_list_jobs_limit() -> u64 {
+    100
+}
+
+/// Lists jobs, optionally filtered by kind and/or state, newest first.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "list_jobs", skip_all)]
+pub async fn list_jobs_handler(
+    State(app_state): AppState,
+    Query(query): Query<ListJobsQueryParams>,
+) -> Result<Json<Vec<Job>>, Error> {
+    let jobs = app_state
+        .postgres_connection_info
+        .list_jobs(query.kind, query.state, query.limit)
+        .await?;
+    Ok(Json(jobs))
+}
+
+/// Returns the job with the given id.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "get_job", skip_all, fields(job_id = %job_id))]
+pub async fn get_job_handler(
+    State(app_state): AppState,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Job>, Error> {
+    let job = app_state
+        .postgres_connection_info
+        .get_job(job_id)
+        .await?
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Job {job_id} does not exist"),
+            })
+        })?;
+    Ok(Json(job))
+}
+
+/// Marks a non-terminal job as cancelled. This only updates the tracking row - it's the
+/// subsystem's responsibility to also stop the underlying work.
+///
+/// Publishes a `JobProgressed` event on the gateway event bus. This is the only place a `Job`'s
+/// state change is published: other transitions (e.g. a subsystem marking a job `running` or
+/// `completed`) happen deep inside optimization/evaluation/backfill code that only has a
+/// `JobQueries` handle, not the full `AppStateData` the event bus lives on.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "cancel_job", skip_all, fields(job_id = %job_id))]
+pub async fn cancel_job_handler(
+    State(app_state): AppState,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Job>, Error> {
+    let job = app_state
+        .postgres_connection_info
+        .cancel_job(job_id)
+        .await?;
+    app_state.event_bus.publish(GatewayEvent::JobProgressed {
+        job_id: job.id,
+        kind: job.kind.as_str().to_string(),
+        state: job.state.as_str().to_string(),
+    });
+    Ok(Json(job))
+}
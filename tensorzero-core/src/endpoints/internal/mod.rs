@@ -1,8 +1,13 @@
 pub mod autopilot;
+pub mod cache;
 pub mod config;
 pub mod count_inferences;
 pub mod evaluations;
+pub mod events;
 pub mod inference_count;
 pub mod inference_metadata;
+pub mod jobs;
 pub mod model_inferences;
 pub mod models;
+pub mod ollama;
+pub mod review_queue;
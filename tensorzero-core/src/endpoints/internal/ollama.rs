@@ -0,0 +1,149 @@
+//! Admin API for listing and pulling models on a local Ollama server.
+//!
+//! This proxies to Ollama's own native `/api/tags` and `/api/pull` endpoints (not the
+//! OpenAI-compatible API that `providers::ollama` uses for inference - see that module's
+//! doc comment for why the two are separate). The caller supplies `api_base` explicitly
+//! rather than us trying to derive it from a configured `ollama` model provider, since a
+//! deployment may want to manage models on a server that isn't (yet) wired up as a model
+//! provider at all.
+//!
+//! Scope note: this only covers listing and pulling models, which is what's needed to
+//! support offline/local development workflows. Deleting models, showing per-model
+//! metadata, and the `llama.cpp` server mentioned alongside Ollama in the original request
+//! are out of scope here - `llama.cpp` servers are typically run behind an OpenAI-compatible
+//! HTTP API, which is already covered by the `openai_compatible` provider, so no dedicated
+//! provider is added for it.
+
+use axum::Json;
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use url::Url;
+
+use crate::error::{DisplayOrDebugGateway, Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+
+const PROVIDER_TYPE: &str = "ollama";
+
+fn join_api_path(api_base: &Url, path: &str) -> Result<Url, Error> {
+    api_base.join(path).map_err(|e| {
+        Error::new(ErrorDetails::InvalidBaseUrl {
+            message: format!(
+                "Invalid Ollama `api_base`: {}",
+                DisplayOrDebugGateway::new(e)
+            ),
+        })
+    })
+}
+
+/// Query parameters for the list-local-models endpoint.
+#[derive(Debug, Deserialize)]
+pub struct OllamaApiBaseQueryParams {
+    /// Base URL of the Ollama server, e.g. `http://localhost:11434`.
+    pub api_base: Url,
+}
+
+/// A single entry from Ollama's `GET /api/tags` response.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct OllamaLocalModel {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTagsResponse {
+    #[serde(default)]
+    models: Vec<OllamaLocalModel>,
+}
+
+/// Response for `GET /internal/ollama/models`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ListOllamaModelsResponse {
+    pub models: Vec<OllamaLocalModel>,
+}
+
+/// Handler for `GET /internal/ollama/models`.
+///
+/// Lists the models currently pulled on the Ollama server at `api_base`.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "ollama.list_models", skip_all)]
+pub async fn list_local_models_handler(
+    State(app_state): AppState,
+    Query(params): Query<OllamaApiBaseQueryParams>,
+) -> Result<Json<ListOllamaModelsResponse>, Error> {
+    let url = join_api_path(&params.api_base, "api/tags")?;
+    let response: OllamaTagsResponse = app_state
+        .http_client
+        .get(url)
+        .send_and_parse_json(PROVIDER_TYPE)
+        .await?;
+    Ok(Json(ListOllamaModelsResponse {
+        models: response.models,
+    }))
+}
+
+/// Request body for `POST /internal/ollama/models/pull`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PullOllamaModelParams {
+    /// Base URL of the Ollama server, e.g. `http://localhost:11434`.
+    pub api_base: Url,
+    /// Name of the model to pull, e.g. `llama3.1` or `llama3.1:8b`.
+    pub model: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaPullRequest<'a> {
+    name: &'a str,
+    // We wait for the pull to complete rather than relaying Ollama's streamed progress
+    // events, since this admin API is a synchronous request/response call.
+    stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaPullResponseBody {
+    #[serde(default)]
+    status: String,
+}
+
+/// Response for `POST /internal/ollama/models/pull`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct PullOllamaModelResponse {
+    /// Ollama's final status string for the pull, e.g. `"success"`.
+    pub status: String,
+}
+
+/// Handler for `POST /internal/ollama/models/pull`.
+///
+/// Pulls (downloads) a model onto the Ollama server at `api_base`. This blocks until the
+/// pull completes, which can take a long time for large models.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "ollama.pull_model", skip_all, fields(model = %params.model))]
+pub async fn pull_model_handler(
+    State(app_state): AppState,
+    StructuredJson(params): StructuredJson<PullOllamaModelParams>,
+) -> Result<Json<PullOllamaModelResponse>, Error> {
+    let url = join_api_path(&params.api_base, "api/pull")?;
+    let request_body = OllamaPullRequest {
+        name: &params.model,
+        stream: false,
+    };
+    let response: OllamaPullResponseBody = app_state
+        .http_client
+        .post(url)
+        .json(&request_body)
+        .send_and_parse_json(PROVIDER_TYPE)
+        .await?;
+    Ok(Json(PullOllamaModelResponse {
+        status: response.status,
+    }))
+}
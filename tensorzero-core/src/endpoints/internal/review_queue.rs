@@ -0,0 +1,160 @@
+//! Review queue endpoints: a lightweight labeling workflow for human review of already-sampled
+//! production inferences. A review task pairs an inference with a metric name; submitting a
+//! label for a task calls into [`feedback`] so the label is written as an ordinary feedback
+//! record, then marks the task completed with the resulting `feedback_id`. This closes the loop
+//! from production traffic to human-labeled metrics without the review queue having its own
+//! copy of feedback validation or storage.
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query, State};
+use axum::{Extension, Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::db::review_queue::{
+    ReviewQueueQueries, ReviewQueueSummary, ReviewTask, ReviewTaskStatus,
+};
+use crate::endpoints::feedback::{Params as FeedbackParams, feedback};
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+use tensorzero_auth::middleware::RequestApiKeyExtension;
+
+/// Request body for creating a set of review tasks from already-sampled inferences.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateReviewTasksParams {
+    pub inference_ids: Vec<Uuid>,
+    pub metric_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateReviewTasksResponse {
+    pub tasks: Vec<ReviewTask>,
+}
+
+/// Creates one pending review task per inference id supplied by the caller.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "create_review_tasks", skip_all, fields(metric_name = %params.metric_name))]
+pub async fn create_review_tasks_handler(
+    State(app_state): AppState,
+    StructuredJson(params): StructuredJson<CreateReviewTasksParams>,
+) -> Result<Json<CreateReviewTasksResponse>, Error> {
+    if params.inference_ids.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "inference_ids must not be empty".to_string(),
+        }));
+    }
+    let tasks = app_state
+        .postgres_connection_info
+        .create_review_tasks(&params.inference_ids, &params.metric_name)
+        .await?;
+    Ok(Json(CreateReviewTasksResponse { tasks }))
+}
+
+/// Request body for assigning a reviewer to a review task.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssignReviewTaskParams {
+    pub assignee: String,
+}
+
+/// Assigns a reviewer to a pending review task.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "assign_review_task", skip_all, fields(task_id = %task_id))]
+pub async fn assign_review_task_handler(
+    State(app_state): AppState,
+    Path(task_id): Path<Uuid>,
+    StructuredJson(params): StructuredJson<AssignReviewTaskParams>,
+) -> Result<Json<ReviewTask>, Error> {
+    let task = app_state
+        .postgres_connection_info
+        .assign_review_task(task_id, &params.assignee)
+        .await?;
+    Ok(Json(task))
+}
+
+/// Request body for submitting a label for a review task.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitReviewLabelParams {
+    pub value: Value,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+/// Submits a label for a review task. The label is written as a feedback record for the
+/// task's inference and metric, and the task is marked completed with the resulting
+/// `feedback_id`.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "submit_review_label", skip_all, fields(task_id = %task_id))]
+pub async fn submit_review_label_handler(
+    State(app_state): AppState,
+    Path(task_id): Path<Uuid>,
+    api_key_ext: Option<Extension<RequestApiKeyExtension>>,
+    StructuredJson(params): StructuredJson<SubmitReviewLabelParams>,
+) -> Result<Json<ReviewTask>, Error> {
+    let task = app_state
+        .postgres_connection_info
+        .get_review_task(task_id)
+        .await?
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Review task {task_id} does not exist"),
+            })
+        })?;
+
+    let feedback_params = FeedbackParams {
+        episode_id: None,
+        inference_id: Some(task.inference_id),
+        metric_name: task.metric_name,
+        value: params.value,
+        internal: false,
+        tags: params.tags,
+        dryrun: None,
+    };
+    let feedback_response = feedback(app_state.clone(), feedback_params, api_key_ext).await?;
+
+    let task = app_state
+        .postgres_connection_info
+        .complete_review_task(task_id, feedback_response.feedback_id)
+        .await?;
+    Ok(Json(task))
+}
+
+/// Returns task counts by status for `metric_name`.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "get_review_queue_summary", skip_all, fields(metric_name = %metric_name))]
+pub async fn get_review_queue_summary_handler(
+    State(app_state): AppState,
+    Path(metric_name): Path<String>,
+) -> Result<Json<ReviewQueueSummary>, Error> {
+    let summary = app_state
+        .postgres_connection_info
+        .get_review_queue_summary(&metric_name)
+        .await?;
+    Ok(Json(summary))
+}
+
+/// Query parameters for the list-review-tasks endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ListReviewTasksQueryParams {
+    pub status: Option<ReviewTaskStatus>,
+}
+
+/// Lists review tasks for `metric_name`, optionally filtered to a single status.
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "list_review_tasks", skip_all, fields(metric_name = %metric_name))]
+pub async fn list_review_tasks_handler(
+    State(app_state): AppState,
+    Path(metric_name): Path<String>,
+    Query(query): Query<ListReviewTasksQueryParams>,
+) -> Result<Json<Vec<ReviewTask>>, Error> {
+    let tasks = app_state
+        .postgres_connection_info
+        .list_review_tasks(&metric_name, query.status)
+        .await?;
+    Ok(Json(tasks))
+}
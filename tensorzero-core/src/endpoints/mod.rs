@@ -21,6 +21,8 @@ pub mod status;
 pub mod stored_inferences;
 pub mod ui;
 pub mod variant_probabilities;
+pub mod variant_prompt_overhead;
+pub mod webhooks;
 pub mod workflow_evaluation_run;
 pub mod workflow_evaluations;
 
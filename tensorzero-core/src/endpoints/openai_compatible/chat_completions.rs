@@ -14,6 +14,7 @@ use axum::{Extension, debug_handler};
 
 use crate::endpoints::inference::{InferenceOutput, Params, inference};
 use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEvent;
 use crate::utils::gateway::{AppState, AppStateData};
 use tensorzero_auth::middleware::RequestApiKeyExtension;
 
@@ -31,6 +32,8 @@ pub async fn chat_completions_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
+        event_bus,
         ..
     }): AppState,
     api_key_ext: Option<Extension<RequestApiKeyExtension>>,
@@ -101,6 +104,10 @@ pub async fn chat_completions_handler(
     let include_usage = explicit_include_usage.unwrap_or(false) || include_raw_usage;
 
     let params = Params::try_from_openai(openai_compatible_params)?;
+    let event_function_name = params
+        .function_name
+        .clone()
+        .unwrap_or_else(|| "tensorzero::default".to_string());
 
     // The prefix for the response's `model` field depends on the inference target
     // (We run this disambiguation deep in the `inference` call below but we don't get the decision out, so we duplicate it here)
@@ -126,6 +133,7 @@ pub async fn chat_completions_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
         params,
         api_key_ext,
     ))
@@ -134,6 +142,11 @@ pub async fn chat_completions_handler(
 
     match response {
         InferenceOutput::NonStreaming(response) => {
+            event_bus.publish(GatewayEvent::InferenceCompleted {
+                inference_id: response.inference_id(),
+                function_name: event_function_name,
+                variant_name: response.variant_name().to_string(),
+            });
             let openai_compatible_response = OpenAICompatibleResponse::from((
                 response,
                 response_model_prefix,
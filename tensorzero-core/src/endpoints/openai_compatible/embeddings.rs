@@ -21,6 +21,7 @@ pub async fn embeddings_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
         ..
     }): AppState,
     api_key_ext: Option<Extension<RequestApiKeyExtension>>,
@@ -36,6 +37,7 @@ pub async fn embeddings_handler(
         postgres_connection_info,
         deferred_tasks,
         rate_limiting_manager,
+        hot_cache,
         embedding_params,
         api_key_ext,
     )
@@ -159,6 +159,10 @@ pub struct OpenAICompatibleParams {
     pub tensorzero_include_original_response: bool,
     #[serde(default, rename = "tensorzero::include_raw_response")]
     pub tensorzero_include_raw_response: bool,
+    #[serde(default, rename = "tensorzero::include_snapshot_hash")]
+    pub tensorzero_include_snapshot_hash: bool,
+    #[serde(rename = "tensorzero::timeout_ms")]
+    pub tensorzero_timeout_ms: Option<u64>,
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, Value>,
 }
@@ -222,6 +226,8 @@ pub struct OpenAICompatibleResponse {
     pub tensorzero_original_response: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tensorzero_raw_response: Option<Vec<RawResponseEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tensorzero_snapshot_hash: Option<String>,
 }
 
 // ============================================================================
@@ -455,10 +461,12 @@ impl Params {
                 .tensorzero_include_original_response,
             include_raw_response: openai_compatible_params.tensorzero_include_raw_response,
             include_raw_usage: openai_compatible_params.tensorzero_include_raw_usage,
+            include_snapshot_hash: openai_compatible_params.tensorzero_include_snapshot_hash,
             extra_body: openai_compatible_params.tensorzero_extra_body,
             extra_headers: openai_compatible_params.tensorzero_extra_headers,
             internal_dynamic_variant_config: openai_compatible_params
                 .tensorzero_internal_dynamic_variant_config,
+            timeout_ms: openai_compatible_params.tensorzero_timeout_ms,
         })
     }
 }
@@ -721,6 +729,7 @@ impl From<(InferenceResponse, String, bool, bool)> for OpenAICompatibleResponse
                     tensorzero_raw_usage: response.raw_usage,
                     tensorzero_original_response,
                     tensorzero_raw_response,
+                    tensorzero_snapshot_hash: response.snapshot_hash,
                     episode_id: response.episode_id.to_string(),
                 }
             }
@@ -756,6 +765,7 @@ impl From<(InferenceResponse, String, bool, bool)> for OpenAICompatibleResponse
                     tensorzero_raw_usage: response.raw_usage,
                     tensorzero_original_response,
                     tensorzero_raw_response,
+                    tensorzero_snapshot_hash: response.snapshot_hash,
                     episode_id: response.episode_id.to_string(),
                 }
             }
@@ -15,9 +15,11 @@ use crate::embeddings::{Embedding, EmbeddingEncodingFormat, EmbeddingInput};
 use crate::endpoints::embeddings::{EmbeddingResponse, EmbeddingsParams as EmbeddingParams};
 use crate::endpoints::inference::InferenceCredentials;
 use crate::error::Error;
+use crate::inference::types::Usage;
 use crate::inference::types::usage::RawResponseEntry;
 
-const TENSORZERO_EMBEDDING_MODEL_NAME_PREFIX: &str = "tensorzero::embedding_model_name::";
+pub(crate) const TENSORZERO_EMBEDDING_MODEL_NAME_PREFIX: &str =
+    "tensorzero::embedding_model_name::";
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OpenAICompatibleEmbeddingParams {
@@ -133,6 +135,37 @@ impl From<EmbeddingResponse> for OpenAIEmbeddingResponse {
     }
 }
 
+impl From<OpenAIEmbeddingResponse> for EmbeddingResponse {
+    fn from(response: OpenAIEmbeddingResponse) -> Self {
+        let OpenAIEmbeddingResponse::List {
+            mut data,
+            model,
+            usage,
+            tensorzero_raw_response,
+        } = response;
+        data.sort_by_key(|embedding| match embedding {
+            OpenAIEmbedding::Embedding { index, .. } => *index,
+        });
+        let model = model
+            .strip_prefix(TENSORZERO_EMBEDDING_MODEL_NAME_PREFIX)
+            .map_or(model.clone(), str::to_string);
+        EmbeddingResponse {
+            embeddings: data
+                .into_iter()
+                .map(|embedding| match embedding {
+                    OpenAIEmbedding::Embedding { embedding, .. } => embedding,
+                })
+                .collect(),
+            usage: Usage {
+                input_tokens: usage.as_ref().and_then(|u| u.prompt_tokens),
+                output_tokens: None,
+            },
+            model,
+            tensorzero_raw_response,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +43,8 @@ pub struct OpenAICompatibleResponseChunk {
     pub tensorzero_original_chunk: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tensorzero_raw_chunk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tensorzero_snapshot_hash: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -136,6 +138,7 @@ pub fn convert_inference_response_chunk_to_openai_compatible(
                 tensorzero_raw_response: tensorzero_raw_response.clone(),
                 tensorzero_original_chunk,
                 tensorzero_raw_chunk,
+                tensorzero_snapshot_hash: c.snapshot_hash,
             }
         }
         InferenceResponseChunk::Json(c) => {
@@ -184,6 +187,7 @@ pub fn convert_inference_response_chunk_to_openai_compatible(
                 tensorzero_raw_response,
                 tensorzero_original_chunk,
                 tensorzero_raw_chunk,
+                tensorzero_snapshot_hash: c.snapshot_hash,
             }
         }
     };
@@ -310,6 +314,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -366,6 +371,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -413,6 +419,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -470,6 +477,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -510,6 +518,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -561,6 +570,7 @@ mod tests {
             original_chunk: None,
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -603,6 +613,7 @@ mod tests {
             original_chunk: Some(raw_response.clone()),
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -650,6 +661,7 @@ mod tests {
             original_chunk: Some(raw_response),
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -689,6 +701,7 @@ mod tests {
             original_chunk: Some(raw_response.clone()),
             raw_chunk: None,
             raw_response: None,
+            snapshot_hash: None,
         });
 
         let mut tool_id_to_index = HashMap::new();
@@ -1,7 +1,9 @@
 mod get_inferences;
+mod search_inferences;
 
 pub mod types;
 
 pub use get_inferences::{
     get_inferences, get_inferences_handler, list_inferences, list_inferences_handler,
 };
+pub use search_inferences::{search_inferences, search_inferences_handler};
@@ -0,0 +1,135 @@
+use axum::Extension;
+use axum::Json;
+use axum::extract::State;
+use tracing::instrument;
+
+use crate::db::inferences::{InferenceOutputSource, InferenceQueries, ListInferencesParams};
+use crate::embeddings::{Embedding, EmbeddingInput};
+use crate::endpoints::datasets::dedup::cosine_similarity;
+use crate::endpoints::embeddings::{EmbeddingsParams, embeddings};
+use crate::error::{Error, ErrorDetails};
+use crate::stored_inference::StoredInferenceDatabase;
+use crate::utils::gateway::{AppState, AppStateData, StructuredJson};
+use tensorzero_auth::middleware::RequestApiKeyExtension;
+
+use super::types::{ScoredStoredInference, SearchInferencesRequest, SearchInferencesResponse};
+
+/// Upper bound on the number of candidate inferences that are embedded and ranked per search,
+/// since there is no vector index backing this search: every candidate is embedded on the fly.
+const SEARCH_INFERENCES_CANDIDATE_LIMIT: u32 = 500;
+
+/// Default number of results returned when `SearchInferencesRequest::limit` is unset.
+const DEFAULT_SEARCH_INFERENCES_LIMIT: u32 = 10;
+
+/// Handler for the POST `/v1/inferences/search_inferences` endpoint.
+/// Semantically searches stored inferences by embedding similarity to a query.
+#[axum::debug_handler(state = AppStateData)]
+#[instrument(name = "inferences.v1.search_inferences", skip(app_state, request))]
+pub async fn search_inferences_handler(
+    State(app_state): AppState,
+    api_key_ext: Option<Extension<RequestApiKeyExtension>>,
+    StructuredJson(request): StructuredJson<SearchInferencesRequest>,
+) -> Result<Json<SearchInferencesResponse>, Error> {
+    let response = search_inferences(&app_state, api_key_ext, request).await?;
+    Ok(Json(response))
+}
+
+/// Business logic for semantically searching stored inferences.
+///
+/// Selects up to `SEARCH_INFERENCES_CANDIDATE_LIMIT` candidate inferences matching
+/// `request.function_name`/`request.filters`, embeds the query and every candidate (via their
+/// full serialized representation) with `request.embedding_model_name`, and returns the
+/// `request.limit` candidates with the highest cosine similarity to the query.
+///
+/// This brute-forces similarity over the candidate window rather than querying a vector index,
+/// since none exists for stored inferences today - see `SearchInferencesRequest`'s doc comment.
+pub async fn search_inferences(
+    app_state: &AppStateData,
+    api_key_ext: Option<Extension<RequestApiKeyExtension>>,
+    request: SearchInferencesRequest,
+) -> Result<SearchInferencesResponse, Error> {
+    if request.query.trim().is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "`query` must not be empty".to_string(),
+        }));
+    }
+
+    let candidates_storage = app_state
+        .clickhouse_connection_info
+        .list_inferences(
+            &app_state.config,
+            &ListInferencesParams {
+                function_name: request.function_name.as_deref(),
+                filters: request.filters.as_ref(),
+                output_source: InferenceOutputSource::Inference,
+                limit: SEARCH_INFERENCES_CANDIDATE_LIMIT,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let candidates = candidates_storage
+        .into_iter()
+        .map(StoredInferenceDatabase::into_stored_inference)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if candidates.is_empty() {
+        return Ok(SearchInferencesResponse { inferences: vec![] });
+    }
+
+    // Every candidate's full serialized representation (input, output, tags, etc., via
+    // `StoredInference`'s `Display` impl) is embedded alongside the query, in one batched
+    // request, so a single embedding model call covers the whole candidate window.
+    let mut texts: Vec<String> = candidates.iter().map(ToString::to_string).collect();
+    texts.push(request.query.clone());
+
+    let response = embeddings(
+        app_state.config.clone(),
+        &app_state.http_client,
+        app_state.clickhouse_connection_info.clone(),
+        app_state.postgres_connection_info.clone(),
+        app_state.deferred_tasks.clone(),
+        app_state.rate_limiting_manager.clone(),
+        EmbeddingsParams {
+            input: EmbeddingInput::Batch(texts),
+            model_name: request.embedding_model_name,
+            dimensions: None,
+            encoding_format: Default::default(),
+            dryrun: Some(true),
+            credentials: Default::default(),
+            cache_options: Default::default(),
+            include_raw_response: false,
+        },
+        api_key_ext,
+    )
+    .await?;
+
+    let Some((query_embedding, candidate_embeddings)) = response.embeddings.split_last() else {
+        return Err(Error::new(ErrorDetails::InternalError {
+            message: "Embedding model returned no embeddings for the search query".to_string(),
+        }));
+    };
+    let query_vector = float_vector(query_embedding)?;
+
+    let mut scored: Vec<ScoredStoredInference> = candidates
+        .into_iter()
+        .zip(candidate_embeddings)
+        .map(|(inference, embedding)| {
+            let score = cosine_similarity(query_vector, float_vector(embedding)?);
+            Ok(ScoredStoredInference { inference, score })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scored.truncate(request.limit.unwrap_or(DEFAULT_SEARCH_INFERENCES_LIMIT) as usize);
+
+    Ok(SearchInferencesResponse { inferences: scored })
+}
+
+fn float_vector(embedding: &Embedding) -> Result<&[f32], Error> {
+    embedding.as_float().map(Vec::as_slice).ok_or_else(|| {
+        Error::new(ErrorDetails::InternalError {
+            message: "Search requires an embedding model that returns float embeddings, not base64"
+                .to_string(),
+        })
+    })
+}
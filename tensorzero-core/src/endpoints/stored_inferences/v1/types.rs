@@ -236,7 +236,10 @@ pub struct ListInferencesRequest {
     pub after: Option<Uuid>,
 
     /// Optional filter to apply when querying inferences.
-    /// Supports filtering by metrics, tags, time, and logical combinations (AND/OR/NOT).
+    /// Supports filtering by metrics, tags, time, and logical combinations (AND/OR/NOT) - e.g.
+    /// `FloatMetric { metric_name: "accuracy", value: 0.5, comparison_operator: "<" }` restricts
+    /// results to inferences with a feedback value below the threshold, joining against the
+    /// appropriate metric feedback table in ClickHouse.
     pub filters: Option<InferenceFilter>,
 
     /// **Deprecated:** Use `filters` instead. This field will be removed in a future release.
@@ -353,3 +356,61 @@ pub struct GetInferencesResponse {
     /// The retrieved inferences.
     pub inferences: Vec<StoredInference>,
 }
+
+/// Request to semantically search stored inferences.
+/// Used by the `POST /v1/inferences/search_inferences` endpoint.
+///
+/// THIS FEATURE IS EXPERIMENTAL, and we may change or remove it at any time.
+///
+/// Unlike `search_query_experimental` on `ListInferencesRequest` (a substring match), this
+/// ranks inferences by embedding cosine similarity to `query`. There is no vector index
+/// backing this today: candidates are selected with `filters`/`function_name` (capped at
+/// `SEARCH_INFERENCES_CANDIDATE_LIMIT`), then embedded and ranked on the fly, so this is
+/// best-effort and not suited to searching an entire large inference store.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[export_schema]
+pub struct SearchInferencesRequest {
+    /// The natural-language query to search for.
+    pub query: String,
+
+    /// The embedding model to use for both the query and the candidate inferences.
+    pub embedding_model_name: String,
+
+    /// Optional function name to restrict the search to.
+    /// Including this improves query performance since `function_name` is the first column
+    /// in the ClickHouse primary key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_name: Option<String>,
+
+    /// Optional filter to apply when selecting candidate inferences to rank.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filters: Option<InferenceFilter>,
+
+    /// The maximum number of results to return, ranked by similarity (descending).
+    /// Defaults to 10.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A stored inference along with its similarity score against the search query.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ScoredStoredInference {
+    pub inference: StoredInference,
+    /// Cosine similarity between the query embedding and this inference's embedding.
+    /// Ranges from -1.0 to 1.0; higher is more similar.
+    pub score: f32,
+}
+
+/// Response containing inferences ranked by similarity to the search query.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[export_schema]
+pub struct SearchInferencesResponse {
+    /// The matching inferences, ordered from most to least similar.
+    pub inferences: Vec<ScoredStoredInference>,
+}
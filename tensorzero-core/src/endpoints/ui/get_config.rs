@@ -9,9 +9,10 @@ use axum::{Json, extract::State};
 use serde::Serialize;
 
 use crate::{
-    config::{Config, MetricConfig},
+    config::{CompositeObjectiveConfig, Config, MetricConfig},
     evaluations::EvaluationConfig,
     function::FunctionConfig,
+    model_capabilities::ProviderCapabilities,
     tool::StaticToolConfig,
     utils::gateway::AppState,
 };
@@ -26,9 +27,15 @@ use crate::{
 pub struct UiConfig {
     pub functions: HashMap<String, Arc<FunctionConfig>>,
     pub metrics: HashMap<String, MetricConfig>,
+    pub composite_objectives: HashMap<String, CompositeObjectiveConfig>,
     pub tools: HashMap<String, Arc<StaticToolConfig>>,
     pub evaluations: HashMap<String, Arc<EvaluationConfig>>,
     pub model_names: Vec<String>,
+    /// Per-provider inference-time feature support (tools, streaming, JSON mode, vision, batch,
+    /// reasoning) for each statically-configured model, keyed by model name and then provider
+    /// name. Shorthand models (e.g. `openai::gpt-4o`) aren't included, since their provider
+    /// isn't resolved until request time.
+    pub model_capabilities: HashMap<String, HashMap<String, ProviderCapabilities>>,
     pub config_hash: String,
 }
 
@@ -41,6 +48,7 @@ impl UiConfig {
                 .map(|(k, v)| (k.clone(), Arc::clone(v)))
                 .collect(),
             metrics: config.metrics.clone(),
+            composite_objectives: config.composite_objectives.clone(),
             tools: config
                 .tools
                 .iter()
@@ -52,6 +60,20 @@ impl UiConfig {
                 .map(|(k, v)| (k.clone(), Arc::clone(v)))
                 .collect(),
             model_names: config.models.table.keys().map(|s| s.to_string()).collect(),
+            model_capabilities: config
+                .models
+                .iter_static_models()
+                .map(|(model_name, model_config)| {
+                    let providers = model_config
+                        .providers
+                        .iter()
+                        .map(|(provider_name, provider)| {
+                            (provider_name.to_string(), provider.config.capabilities())
+                        })
+                        .collect();
+                    (model_name.to_string(), providers)
+                })
+                .collect(),
             config_hash: config.hash.to_string(),
         }
     }
@@ -99,6 +121,8 @@ mod tests {
             r#type: MetricConfigType::Boolean,
             optimize: MetricConfigOptimize::Max,
             level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
 
@@ -138,6 +162,7 @@ mod tests {
 
         // Verify model_names is empty (default config has no models)
         assert!(ui_config.model_names.is_empty());
+        assert!(ui_config.model_capabilities.is_empty());
 
         // Verify tools and evaluations are empty
         assert!(ui_config.tools.is_empty());
@@ -166,6 +191,8 @@ mod tests {
             r#type: MetricConfigType::Float,
             optimize: MetricConfigOptimize::Min,
             level: MetricConfigLevel::Episode,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
             description: None,
         };
 
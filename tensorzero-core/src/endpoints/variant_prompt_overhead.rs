@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query, State};
+use axum::{Json, debug_handler};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::error::{Error, ErrorDetails};
+use crate::utils::gateway::{AppState, AppStateData};
+use crate::utils::token_estimate::estimate_tokens_for_chars;
+use crate::variant::VariantConfig;
+
+/// Query parameters for the per-variant prompt token overhead endpoint
+#[derive(Debug, Deserialize)]
+pub struct GetVariantPromptTokenOverheadParams {
+    /// The name of the function to analyze
+    pub function_name: String,
+}
+
+/// Estimated prompt token overhead for a single variant, independent of any
+/// particular input. This only accounts for the static portions of a
+/// variant's templates (system/user/assistant templates and their fixed
+/// text) - it does not render templates against real inputs.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct VariantPromptTokenOverhead {
+    /// A rough estimate of the number of prompt tokens contributed by this
+    /// variant's static template text, using a chars-per-token heuristic.
+    ///
+    /// NOTE: this is an approximation and is not computed with the actual
+    /// tokenizer for the variant's model. It is intended to let users
+    /// compare the relative verbosity of variants on the same function, not
+    /// to predict exact provider-billed token counts.
+    pub estimated_prompt_tokens: u64,
+}
+
+/// Response containing per-variant prompt token overhead estimates
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct GetVariantPromptTokenOverheadResponse {
+    /// Map of variant names to their estimated prompt token overhead
+    pub variants: HashMap<String, VariantPromptTokenOverhead>,
+}
+
+/// HTTP handler for the per-variant prompt token overhead endpoint (query-based)
+#[debug_handler(state = AppStateData)]
+pub async fn get_variant_prompt_token_overhead_handler(
+    State(app_state): AppState,
+    Query(params): Query<GetVariantPromptTokenOverheadParams>,
+) -> Result<Json<GetVariantPromptTokenOverheadResponse>, Error> {
+    Ok(Json(
+        get_variant_prompt_token_overhead(app_state, params).await?,
+    ))
+}
+
+/// HTTP handler for the per-variant prompt token overhead endpoint (path-based)
+#[debug_handler(state = AppStateData)]
+pub async fn get_variant_prompt_token_overhead_by_function_handler(
+    State(app_state): AppState,
+    Path(function_name): Path<String>,
+) -> Result<Json<GetVariantPromptTokenOverheadResponse>, Error> {
+    let params = GetVariantPromptTokenOverheadParams { function_name };
+    Ok(Json(
+        get_variant_prompt_token_overhead(app_state, params).await?,
+    ))
+}
+
+/// Core business logic for estimating per-variant prompt token overhead.
+///
+/// For each of the function's variants, sums the static text of every
+/// template it declares (system/user/assistant templates, including any
+/// `input_wrappers`) and converts that to an estimated token count. This
+/// lets users compare how much of their prompt budget is spent on
+/// boilerplate that is constant across inputs, independent of per-request
+/// content.
+#[instrument(
+    name = "get_variant_prompt_token_overhead",
+    skip_all,
+    fields(
+        function_name = %params.function_name,
+    )
+)]
+pub async fn get_variant_prompt_token_overhead(
+    AppStateData { config, .. }: AppStateData,
+    params: GetVariantPromptTokenOverheadParams,
+) -> Result<GetVariantPromptTokenOverheadResponse, Error> {
+    let function_name = &params.function_name;
+    let function = config.get_function(function_name)?;
+
+    if function.variants().is_empty() {
+        return Err(ErrorDetails::InvalidFunctionVariants {
+            message: format!("Function `{function_name}` has no variants"),
+        }
+        .into());
+    }
+
+    let mut variants = HashMap::new();
+    for (variant_name, variant_config) in function.variants() {
+        // Non chat-completion variants (best-of-n, mixture-of-n, etc.) don't
+        // own their own templates directly, so we skip them for now.
+        let VariantConfig::ChatCompletion(chat_completion_config) = &variant_config.inner else {
+            continue;
+        };
+        let total_chars: usize = chat_completion_config
+            .templates()
+            .get_all_template_paths()
+            .iter()
+            .map(|path| path.contents.len())
+            .sum();
+        variants.insert(
+            variant_name.to_string(),
+            VariantPromptTokenOverhead {
+                estimated_prompt_tokens: estimate_tokens_for_chars(total_chars),
+            },
+        );
+    }
+
+    Ok(GetVariantPromptTokenOverheadResponse { variants })
+}
@@ -0,0 +1,189 @@
+//! Inbound webhook endpoint: translates feedback events from external systems (a support
+//! tool, a CRM) into TensorZero feedback. Each webhook is registered under
+//! `gateway.webhooks.<name>` (see [`crate::config::gateway::WebhookConfig`]) and exposed at
+//! `POST /webhooks/{webhook_name}`. Requests are authenticated with an HMAC-SHA256 signature
+//! of the raw body rather than an API key, since the caller is a third-party system that
+//! cannot be issued a TensorZero credential.
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::{Json, debug_handler};
+use hmac::{Hmac, Mac};
+use secrecy::ExposeSecret;
+use serde_json::Value;
+use sha2::Sha256;
+use tracing::instrument;
+
+use crate::config::gateway::WebhookConfig;
+use crate::endpoints::feedback::{FeedbackResponse, Params as FeedbackParams, feedback};
+use crate::error::{Error, ErrorDetails};
+use crate::model::Credential;
+use crate::model_table::load_webhook_credential;
+use crate::utils::gateway::{AppState, AppStateData};
+
+const SIGNATURE_HEADER: &str = "x-tensorzero-webhook-signature";
+
+/// Verifies and ingests a single webhook event, translating it into a call to
+/// [`feedback`] via the webhook's configured [`WebhookFieldMapping`](crate::config::gateway::WebhookFieldMapping).
+#[debug_handler(state = AppStateData)]
+#[instrument(name = "webhook", skip_all, fields(webhook_name = %webhook_name))]
+pub async fn webhook_handler(
+    State(app_state): AppState,
+    Path(webhook_name): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<FeedbackResponse>, Error> {
+    let webhook_config = app_state
+        .config
+        .gateway
+        .webhooks
+        .get(&webhook_name)
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::WebhookNotFound {
+                name: webhook_name.clone(),
+            })
+        })?;
+
+    verify_signature(&webhook_name, webhook_config, &headers, &body)?;
+
+    let payload: Value = serde_json::from_slice(&body).map_err(|e| {
+        Error::new(ErrorDetails::InvalidRequest {
+            message: format!("Webhook `{webhook_name}` payload is not valid JSON: {e}"),
+        })
+    })?;
+
+    let feedback_params = map_payload_to_feedback_params(&webhook_name, webhook_config, &payload)?;
+    let feedback_response = feedback(app_state.clone(), feedback_params, None).await?;
+    Ok(Json(feedback_response))
+}
+
+/// Verifies the `X-TensorZero-Webhook-Signature` header against an HMAC-SHA256 of the raw
+/// request body, using constant-time comparison.
+fn verify_signature(
+    webhook_name: &str,
+    webhook_config: &WebhookConfig,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), Error> {
+    let signature_header = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::WebhookSignatureInvalid {
+                name: webhook_name.to_string(),
+            })
+        })?;
+    let signature = hex::decode(signature_header).map_err(|_| {
+        Error::new(ErrorDetails::WebhookSignatureInvalid {
+            name: webhook_name.to_string(),
+        })
+    })?;
+
+    let credential = load_webhook_credential(&webhook_config.secret_location)?;
+    let secret = match &credential {
+        Credential::Static(secret) => secret,
+        _ => {
+            return Err(Error::new(ErrorDetails::Config {
+                message: format!(
+                    "webhook `{webhook_name}` `secret_location` must resolve to a static credential"
+                ),
+            }));
+        }
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.expose_secret().as_bytes()).map_err(|e| {
+            Error::new(ErrorDetails::Config {
+                message: format!("Failed to initialize HMAC for webhook `{webhook_name}`: {e}"),
+            })
+        })?;
+    mac.update(body);
+    mac.verify_slice(&signature).map_err(|_| {
+        Error::new(ErrorDetails::WebhookSignatureInvalid {
+            name: webhook_name.to_string(),
+        })
+    })
+}
+
+/// Applies the webhook's `WebhookFieldMapping` to the parsed payload to construct
+/// `FeedbackParams`.
+fn map_payload_to_feedback_params(
+    webhook_name: &str,
+    webhook_config: &WebhookConfig,
+    payload: &Value,
+) -> Result<FeedbackParams, Error> {
+    let mapping = &webhook_config.field_mapping;
+
+    let pointer_str = |pointer: &str| -> Result<String, Error> {
+        payload
+            .pointer(pointer)
+            .and_then(|v| {
+                v.as_str()
+                    .map(str::to_string)
+                    .or_else(|| Some(v.to_string()))
+            })
+            .ok_or_else(|| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!(
+                        "Webhook `{webhook_name}` payload is missing a value at `{pointer}`"
+                    ),
+                })
+            })
+    };
+
+    let (inference_id, episode_id) = match (
+        &mapping.inference_id_pointer,
+        &mapping.episode_id_pointer,
+    ) {
+        (Some(pointer), None) => (
+            Some(pointer_str(pointer)?.parse().map_err(|_| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!(
+                        "Webhook `{webhook_name}` `inference_id_pointer` did not resolve to a UUID"
+                    ),
+                })
+            })?),
+            None,
+        ),
+        (None, Some(pointer)) => (
+            None,
+            Some(pointer_str(pointer)?.parse().map_err(|_| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!(
+                        "Webhook `{webhook_name}` `episode_id_pointer` did not resolve to a UUID"
+                    ),
+                })
+            })?),
+        ),
+        _ => {
+            return Err(Error::new(ErrorDetails::Config {
+                message: format!(
+                    "webhook `{webhook_name}` must set exactly one of `inference_id_pointer` or `episode_id_pointer`"
+                ),
+            }));
+        }
+    };
+
+    let value = payload
+        .pointer(&mapping.value_pointer)
+        .cloned()
+        .ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!(
+                    "Webhook `{webhook_name}` payload is missing a value at `{}`",
+                    mapping.value_pointer
+                ),
+            })
+        })?;
+
+    Ok(FeedbackParams {
+        episode_id,
+        inference_id,
+        metric_name: mapping.metric_name.clone(),
+        value,
+        internal: false,
+        tags: Default::default(),
+        dryrun: None,
+    })
+}
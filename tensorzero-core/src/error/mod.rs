@@ -52,6 +52,14 @@ pub fn set_debug(debug: bool) -> Result<(), Error> {
     })
 }
 
+/// Whether raw request/response payloads may be logged or included in error
+/// output. Providers should gate any logging of full payloads (e.g. raw
+/// provider responses) behind this, rather than logging unconditionally at
+/// `info` level, since payloads can contain sensitive user content.
+pub fn debug_enabled() -> bool {
+    *DEBUG.get().unwrap_or(&false)
+}
+
 static UNSTABLE_ERROR_JSON: OnceCell<bool> = OnceCell::const_new();
 
 pub fn set_unstable_error_json(unstable_error_json: bool) -> Result<(), Error> {
@@ -232,6 +240,10 @@ pub enum ErrorDetails {
         // We use an `IndexMap` to preserve the insertion order for `underlying_status_code`
         errors: IndexMap<String, Error>,
     },
+    FallbackChainExhausted {
+        // We use an `IndexMap` to preserve the insertion order for `underlying_status_code`
+        step_errors: IndexMap<String, Error>,
+    },
     TensorZeroAuth {
         message: String,
     },
@@ -299,6 +311,16 @@ pub enum ErrorDetails {
         expected: SnapshotHash,
         actual: SnapshotHash,
     },
+    ConfigPolicyViolation {
+        violations: Vec<String>,
+    },
+    ConfigSecretDetected {
+        secrets: Vec<String>,
+    },
+    CorrelationIdNotFound {
+        correlation_id: String,
+        tag_key: String,
+    },
     ObjectStoreUnconfigured {
         block_type: String,
     },
@@ -306,6 +328,12 @@ pub enum ErrorDetails {
         dataset_name: String,
         datapoint_id: Uuid,
     },
+    /// No provider in a model's routing list has a `region` matching the calling function's
+    /// `data_residency` policy.
+    DataResidencyViolation {
+        model_name: String,
+        allowed_regions: Vec<String>,
+    },
     DiclMissingOutput,
     DuplicateTool {
         name: String,
@@ -322,6 +350,12 @@ pub enum ErrorDetails {
     DynamicJsonSchema {
         message: String,
     },
+    EpisodeBudgetExceeded {
+        episode_id: Uuid,
+        budget_kind: String,
+        limit: f64,
+        used: f64,
+    },
     EvaluationRun {
         message: String,
     },
@@ -335,6 +369,9 @@ pub enum ErrorDetails {
     GCPCredentials {
         message: String,
     },
+    AzureCredentials {
+        message: String,
+    },
     Inference {
         message: String,
     },
@@ -367,6 +404,16 @@ pub enum ErrorDetails {
         #[serde(serialize_with = "serialize_if_debug")]
         raw_response: Option<String>,
     },
+    /// A provider-specific error condition that doesn't fit the generic
+    /// client/server split, e.g. quota exhaustion or a region restriction.
+    /// Carries a retryability hint and any quota metadata the provider
+    /// reported, so callers can decide whether to fall back or back off.
+    ProviderQuotaExceeded {
+        message: String,
+        provider_type: String,
+        retryable: bool,
+        remaining_quota: Option<i64>,
+    },
     InvalidClientMode {
         mode: String,
         message: String,
@@ -560,6 +607,12 @@ pub enum ErrorDetails {
     ValkeyQuery {
         message: String,
     },
+    WebhookNotFound {
+        name: String,
+    },
+    WebhookSignatureInvalid {
+        name: String,
+    },
     ProviderNotFound {
         provider_name: String,
     },
@@ -601,6 +654,11 @@ pub enum ErrorDetails {
     UnknownFunction {
         name: String,
     },
+    FunctionAliasSunset {
+        alias: String,
+        target: String,
+        sunset_date: chrono::DateTime<chrono::Utc>,
+    },
     UnknownModel {
         name: String,
     },
@@ -657,6 +715,7 @@ impl ErrorDetails {
     fn level(&self) -> tracing::Level {
         match self {
             ErrorDetails::AllVariantsFailed { .. } => tracing::Level::ERROR,
+            ErrorDetails::FallbackChainExhausted { .. } => tracing::Level::ERROR,
             ErrorDetails::TensorZeroAuth { .. } => tracing::Level::WARN,
             ErrorDetails::ApiKeyMissing { .. } => tracing::Level::ERROR,
             ErrorDetails::AppState { .. } => tracing::Level::ERROR,
@@ -681,21 +740,28 @@ impl ErrorDetails {
             ErrorDetails::Config { .. } => tracing::Level::ERROR,
             ErrorDetails::ConfigSnapshotNotFound { .. } => tracing::Level::ERROR,
             ErrorDetails::ConfigSnapshotHashMismatch { .. } => tracing::Level::ERROR,
+            ErrorDetails::ConfigPolicyViolation { .. } => tracing::Level::WARN,
+            ErrorDetails::ConfigSecretDetected { .. } => tracing::Level::WARN,
+            ErrorDetails::CorrelationIdNotFound { .. } => tracing::Level::WARN,
             ErrorDetails::DatapointNotFound { .. } => tracing::Level::WARN,
+            ErrorDetails::DataResidencyViolation { .. } => tracing::Level::WARN,
             ErrorDetails::DiclMissingOutput => tracing::Level::ERROR,
             ErrorDetails::DuplicateTool { .. } => tracing::Level::WARN,
             ErrorDetails::DuplicateRateLimitingConfigScope { .. } => tracing::Level::WARN,
             ErrorDetails::DynamicJsonSchema { .. } => tracing::Level::WARN,
             ErrorDetails::DynamicEndpointNotFound { .. } => tracing::Level::WARN,
             ErrorDetails::DynamicRegionNotFound { .. } => tracing::Level::WARN,
+            ErrorDetails::EpisodeBudgetExceeded { .. } => tracing::Level::WARN,
             ErrorDetails::EvaluationRun { .. } => tracing::Level::ERROR,
             ErrorDetails::DynamicTemplateLoad { .. } => tracing::Level::ERROR,
             ErrorDetails::FileRead { .. } => tracing::Level::ERROR,
             ErrorDetails::GCPCredentials { .. } => tracing::Level::ERROR,
+            ErrorDetails::AzureCredentials { .. } => tracing::Level::ERROR,
             ErrorDetails::Inference { .. } => tracing::Level::ERROR,
             ErrorDetails::InferenceClient { .. } => tracing::Level::ERROR,
             ErrorDetails::InferenceNotFound { .. } => tracing::Level::WARN,
             ErrorDetails::InferenceServer { .. } => tracing::Level::ERROR,
+            ErrorDetails::ProviderQuotaExceeded { .. } => tracing::Level::WARN,
             ErrorDetails::Relay { .. } => tracing::Level::ERROR,
             ErrorDetails::FatalStreamError { .. } => tracing::Level::ERROR,
             ErrorDetails::InferenceTimeout { .. } => tracing::Level::WARN,
@@ -758,6 +824,8 @@ impl ErrorDetails {
             ErrorDetails::PostgresQuery { .. } => tracing::Level::ERROR,
             ErrorDetails::ValkeyConnection { .. } => tracing::Level::ERROR,
             ErrorDetails::ValkeyQuery { .. } => tracing::Level::ERROR,
+            ErrorDetails::WebhookNotFound { .. } => tracing::Level::WARN,
+            ErrorDetails::WebhookSignatureInvalid { .. } => tracing::Level::WARN,
             ErrorDetails::RateLimitExceeded { .. } => tracing::Level::WARN,
             ErrorDetails::RateLimitMissingMaxTokens => tracing::Level::WARN,
             ErrorDetails::Serialization { .. } => tracing::Level::ERROR,
@@ -768,6 +836,7 @@ impl ErrorDetails {
             ErrorDetails::TypeConversion { .. } => tracing::Level::ERROR,
             ErrorDetails::UnknownCandidate { .. } => tracing::Level::ERROR,
             ErrorDetails::UnknownFunction { .. } => tracing::Level::WARN,
+            ErrorDetails::FunctionAliasSunset { .. } => tracing::Level::WARN,
             ErrorDetails::UnknownEvaluation { .. } => tracing::Level::WARN,
             ErrorDetails::UnknownModel { .. } => tracing::Level::ERROR,
             ErrorDetails::UnknownTool { .. } => tracing::Level::ERROR,
@@ -799,6 +868,10 @@ impl ErrorDetails {
                 .values()
                 .last()
                 .and_then(|error| error.underlying_status_code()),
+            ErrorDetails::FallbackChainExhausted { step_errors } => step_errors
+                .values()
+                .last()
+                .and_then(|error| error.underlying_status_code()),
             ErrorDetails::InferenceClient { status_code, .. } => *status_code,
             ErrorDetails::ModelProvidersExhausted { provider_errors } => provider_errors
                 .values()
@@ -812,6 +885,7 @@ impl ErrorDetails {
     fn status_code(&self) -> StatusCode {
         match self {
             ErrorDetails::AllVariantsFailed { .. } => StatusCode::BAD_GATEWAY,
+            ErrorDetails::FallbackChainExhausted { .. } => StatusCode::BAD_GATEWAY,
             ErrorDetails::TensorZeroAuth { .. } => StatusCode::UNAUTHORIZED,
             ErrorDetails::ApiKeyMissing { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::Glob { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -830,19 +904,25 @@ impl ErrorDetails {
             ErrorDetails::ClickHouseQuery { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::ObjectStoreUnconfigured { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::DatapointNotFound { .. } => StatusCode::NOT_FOUND,
+            ErrorDetails::DataResidencyViolation { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::Config { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::ConfigSnapshotNotFound { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::ConfigSnapshotHashMismatch { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorDetails::ConfigPolicyViolation { .. } => StatusCode::BAD_REQUEST,
+            ErrorDetails::ConfigSecretDetected { .. } => StatusCode::BAD_REQUEST,
+            ErrorDetails::CorrelationIdNotFound { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::DiclMissingOutput => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::DuplicateTool { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::DuplicateRateLimitingConfigScope { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::DynamicJsonSchema { .. } => StatusCode::BAD_REQUEST,
+            ErrorDetails::EpisodeBudgetExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
             ErrorDetails::EvaluationRun { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::DynamicTemplateLoad { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::DynamicEndpointNotFound { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::DynamicRegionNotFound { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::FileRead { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::GCPCredentials { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorDetails::AzureCredentials { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::InvalidInferenceTarget { .. } => StatusCode::BAD_REQUEST,
             ErrorDetails::Inference { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::ObjectStoreWrite { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -853,6 +933,7 @@ impl ErrorDetails {
             ErrorDetails::BadFileFetch { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::InferenceNotFound { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::InferenceServer { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorDetails::ProviderQuotaExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
             ErrorDetails::FatalStreamError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::InferenceTimeout { .. } => StatusCode::REQUEST_TIMEOUT,
             ErrorDetails::Relay { .. } => StatusCode::BAD_GATEWAY,
@@ -917,6 +998,8 @@ impl ErrorDetails {
             ErrorDetails::PostgresMigration { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::ValkeyConnection { .. } => StatusCode::SERVICE_UNAVAILABLE,
             ErrorDetails::ValkeyQuery { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorDetails::WebhookNotFound { .. } => StatusCode::NOT_FOUND,
+            ErrorDetails::WebhookSignatureInvalid { .. } => StatusCode::UNAUTHORIZED,
             ErrorDetails::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
             ErrorDetails::RateLimitMissingMaxTokens => StatusCode::BAD_REQUEST,
             ErrorDetails::Serialization { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -927,6 +1010,7 @@ impl ErrorDetails {
             ErrorDetails::TypeConversion { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::UnknownCandidate { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::UnknownFunction { .. } => StatusCode::NOT_FOUND,
+            ErrorDetails::FunctionAliasSunset { .. } => StatusCode::GONE,
             ErrorDetails::UnknownEvaluation { .. } => StatusCode::NOT_FOUND,
             ErrorDetails::UnknownModel { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ErrorDetails::UnknownTool { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -973,10 +1057,16 @@ impl ErrorDetails {
     pub fn is_retryable(&self) -> bool {
         match &self {
             ErrorDetails::RateLimitExceeded { .. } => false,
+            ErrorDetails::EpisodeBudgetExceeded { .. } => false,
             // For ModelProvidersExhausted we will retry if any provider error is retryable
             ErrorDetails::ModelProvidersExhausted { provider_errors } => provider_errors
                 .iter()
                 .any(|(_, error)| error.is_retryable()),
+            // For FallbackChainExhausted we will retry if any step error is retryable
+            ErrorDetails::FallbackChainExhausted { step_errors } => {
+                step_errors.iter().any(|(_, error)| error.is_retryable())
+            }
+            ErrorDetails::ProviderQuotaExceeded { retryable, .. } => *retryable,
             _ => true,
         }
     }
@@ -996,6 +1086,17 @@ impl std::fmt::Display for ErrorDetails {
                         .join("\n")
                 )
             }
+            ErrorDetails::FallbackChainExhausted { step_errors } => {
+                write!(
+                    f,
+                    "All steps in the fallback chain failed with errors: {}",
+                    step_errors
+                        .iter()
+                        .map(|(step_name, error)| format!("{step_name}: {error}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            }
             ErrorDetails::TensorZeroAuth { message } => {
                 write!(f, "TensorZero authentication error: {message}")
             }
@@ -1153,6 +1254,31 @@ impl std::fmt::Display for ErrorDetails {
                     "Config snapshot hash does not match expected hash. Expected {expected} but got {actual}. {IMPOSSIBLE_ERROR_MESSAGE}"
                 )
             }
+            ErrorDetails::ConfigPolicyViolation { violations } => {
+                write!(
+                    f,
+                    "Config snapshot rejected by organization policy: {}",
+                    violations.join("; ")
+                )
+            }
+            ErrorDetails::ConfigSecretDetected { secrets } => {
+                write!(
+                    f,
+                    "Config snapshot rejected because it appears to contain embedded credentials: {}. \
+                     Reference credentials via `CredentialLocation` (an environment variable, file path, \
+                     or SDK default) instead of embedding them directly.",
+                    secrets.join("; ")
+                )
+            }
+            ErrorDetails::CorrelationIdNotFound {
+                correlation_id,
+                tag_key,
+            } => {
+                write!(
+                    f,
+                    "No inference found with tag `{tag_key}` = `{correlation_id}`"
+                )
+            }
             ErrorDetails::DatapointNotFound {
                 dataset_name,
                 datapoint_id,
@@ -1162,6 +1288,16 @@ impl std::fmt::Display for ErrorDetails {
                     "Datapoint not found for dataset: {dataset_name} and id: {datapoint_id}"
                 )
             }
+            ErrorDetails::DataResidencyViolation {
+                model_name,
+                allowed_regions,
+            } => {
+                write!(
+                    f,
+                    "No provider for model `{model_name}` serves from an allowed region ({})",
+                    allowed_regions.join(", ")
+                )
+            }
             ErrorDetails::DiclMissingOutput => {
                 write!(
                     f,
@@ -1183,6 +1319,17 @@ impl std::fmt::Display for ErrorDetails {
                     "Error in compiling client-provided JSON schema: {message}"
                 )
             }
+            ErrorDetails::EpisodeBudgetExceeded {
+                episode_id,
+                budget_kind,
+                limit,
+                used,
+            } => {
+                write!(
+                    f,
+                    "Episode `{episode_id}` has exceeded its `{budget_kind}` budget: used {used}, limit {limit}"
+                )
+            }
             ErrorDetails::EvaluationRun { message } => {
                 write!(f, "Evaluation run error: {message}")
             }
@@ -1234,6 +1381,9 @@ impl std::fmt::Display for ErrorDetails {
             ErrorDetails::GCPCredentials { message } => {
                 write!(f, "Error in acquiring GCP credentials: {message}")
             }
+            ErrorDetails::AzureCredentials { message } => {
+                write!(f, "Error in acquiring Azure credentials: {message}")
+            }
             ErrorDetails::Inference { message } => write!(f, "{message}"),
             ErrorDetails::InferenceClient {
                 message,
@@ -1293,6 +1443,18 @@ impl std::fmt::Display for ErrorDetails {
                     write!(f, "Error from {provider_type} server: {message}")
                 }
             }
+            ErrorDetails::ProviderQuotaExceeded {
+                message,
+                provider_type,
+                retryable,
+                remaining_quota,
+            } => {
+                write!(
+                    f,
+                    "Quota error from {provider_type}: {message} (retryable: {retryable}{})",
+                    remaining_quota.map_or(String::new(), |q| format!(", remaining_quota: {q}"))
+                )
+            }
             ErrorDetails::FatalStreamError {
                 message,
                 provider_type,
@@ -1565,6 +1727,12 @@ impl std::fmt::Display for ErrorDetails {
             ErrorDetails::ValkeyQuery { message } => {
                 write!(f, "Valkey query failed: {message}")
             }
+            ErrorDetails::WebhookNotFound { name } => {
+                write!(f, "Webhook `{name}` is not configured")
+            }
+            ErrorDetails::WebhookSignatureInvalid { name } => {
+                write!(f, "Signature verification failed for webhook `{name}`")
+            }
             ErrorDetails::ProviderNotFound { provider_name } => {
                 write!(f, "Provider not found: {provider_name}")
             }
@@ -1634,6 +1802,14 @@ impl std::fmt::Display for ErrorDetails {
             }
             ErrorDetails::UnknownEvaluation { name } => write!(f, "Unknown evaluation: {name}"),
             ErrorDetails::UnknownFunction { name } => write!(f, "Unknown function: {name}"),
+            ErrorDetails::FunctionAliasSunset {
+                alias,
+                target,
+                sunset_date,
+            } => write!(
+                f,
+                "Function alias `{alias}` (routing to `{target}`) was sunset on {sunset_date} and can no longer be used"
+            ),
             ErrorDetails::UnknownModel { name } => write!(f, "Unknown model: {name}"),
             ErrorDetails::UnknownTool { name } => write!(f, "Unknown tool: {name}"),
             ErrorDetails::UnknownVariant { name } => write!(f, "Unknown variant: {name}"),
@@ -4,6 +4,7 @@ use std::{collections::HashMap, sync::Arc};
 use schemars::JsonSchema;
 use serde::de::{self, Deserializer, MapAccess, Visitor};
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 use tensorzero_derive::TensorZeroDeserialize;
 
 use crate::variant::chain_of_thought::ChainOfThoughtConfig;
@@ -18,7 +19,7 @@ use crate::{
         MetricConfig, MetricConfigLevel, MetricConfigOptimize, MetricConfigType, PathWithContents,
         SchemaData, TimeoutsConfig, path::ResolvedTomlPathData,
     },
-    error::{Error, ErrorDetails},
+    error::{Error, ErrorDetails, IMPOSSIBLE_ERROR_MESSAGE},
     function::{FunctionConfig, FunctionConfigJson},
     inference::types::{
         chat_completion_inference_params::ServiceTier, extra_body::ExtraBodyConfig,
@@ -43,6 +44,32 @@ pub const LLM_JUDGE_FLOAT_OUTPUT_SCHEMA_TEXT: &str =
 pub const LLM_JUDGE_BOOLEAN_OUTPUT_SCHEMA_TEXT: &str =
     include_str!("llm_judge_boolean_output_schema.json");
 
+/// Configuration for a multi-turn simulation: a "user simulator" function converses
+/// with a target function for up to `max_turns` turns per scenario datapoint, with
+/// `evaluators` scoring the target's response on each turn.
+///
+/// Unlike [`EvaluationConfig`], this is not loaded from a `[evaluations.*]` config
+/// section — it's a standalone config struct that callers (e.g. the `evaluations`
+/// crate's `run_simulation`) construct directly, since wiring simulations into the
+/// TOML config loader, snapshotting, and every existing `EvaluationConfig::Inference`
+/// call site across the workspace is a larger, separate change.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct SimulationConfig {
+    /// Evaluators run against the target function's response on every turn.
+    pub evaluators: HashMap<String, EvaluatorConfig>,
+    /// The function under test.
+    pub target_function_name: String,
+    /// The function whose variant plays the role of the user, replying to the
+    /// target function's output each turn.
+    pub user_simulator_function_name: String,
+    /// Maximum number of target-function turns to run per scenario datapoint.
+    pub max_turns: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
@@ -56,6 +83,25 @@ pub struct InferenceEvaluationConfig {
 /// Deprecated: Use `InferenceEvaluationConfig` instead
 pub type StaticEvaluationConfig = InferenceEvaluationConfig;
 
+/// Configuration for a trajectory-level evaluation: `evaluators` score an entire episode
+/// (its full ordered list of inferences) with a single result, rather than scoring one
+/// inference at a time like [`InferenceEvaluationConfig`] does.
+///
+/// Like [`SimulationConfig`], this is not loaded from a `[evaluations.*]` config section -
+/// it's a standalone config struct that callers (e.g. the `evaluations` crate's
+/// `run_trajectory_evaluation`) construct directly, for the same reason: wiring this into
+/// the TOML config loader and every existing `EvaluationConfig::Inference` call site
+/// across the workspace is a larger, separate change.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct TrajectoryEvaluationConfig {
+    /// Evaluators run once per episode, against the full ordered trajectory.
+    pub evaluators: HashMap<String, EvaluatorConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Clone, Debug, Serialize, TensorZeroDeserialize)]
 #[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
@@ -75,6 +121,11 @@ pub enum EvaluatorConfig {
     ExactMatch(ExactMatchConfig),
     #[serde(rename = "llm_judge")]
     LLMJudge(LLMJudgeConfig),
+    #[serde(rename = "judge_panel")]
+    JudgePanel(JudgePanelConfig),
+    Human(HumanEvaluatorConfig),
+    ToolCallCorrectness(ToolCallCorrectnessConfig),
+    LexicalDiversity(LexicalDiversityConfig),
 }
 
 /// Minimal function configuration for evaluation purposes.
@@ -110,6 +161,10 @@ impl EvaluatorConfig {
         match self {
             EvaluatorConfig::ExactMatch(config) => config.cutoff,
             EvaluatorConfig::LLMJudge(config) => config.cutoff,
+            EvaluatorConfig::JudgePanel(config) => config.cutoff,
+            EvaluatorConfig::Human(config) => config.cutoff,
+            EvaluatorConfig::ToolCallCorrectness(config) => config.cutoff,
+            EvaluatorConfig::LexicalDiversity(config) => config.cutoff,
         }
     }
 
@@ -117,6 +172,12 @@ impl EvaluatorConfig {
         match self {
             EvaluatorConfig::ExactMatch(_) => MetricConfigOptimize::Max,
             EvaluatorConfig::LLMJudge(config) => config.optimize.into(),
+            EvaluatorConfig::JudgePanel(config) => config.optimize.into(),
+            EvaluatorConfig::Human(config) => config.optimize.into(),
+            // Higher F-scores are always better.
+            EvaluatorConfig::ToolCallCorrectness(_) => MetricConfigOptimize::Max,
+            // Higher lexical diversity (fewer repeated n-grams) is always better.
+            EvaluatorConfig::LexicalDiversity(_) => MetricConfigOptimize::Max,
         }
     }
 
@@ -127,6 +188,70 @@ impl EvaluatorConfig {
             EvaluatorConfig::LLMJudge(config) => {
                 matches!(config.output_type, LLMJudgeOutputType::Boolean)
             }
+            EvaluatorConfig::JudgePanel(config) => {
+                matches!(config.output_type, LLMJudgeOutputType::Boolean)
+            }
+            EvaluatorConfig::Human(config) => {
+                matches!(config.output_type, LLMJudgeOutputType::Boolean)
+            }
+            // Produces a continuous F-score, not a boolean.
+            EvaluatorConfig::ToolCallCorrectness(_) => false,
+            // Produces a continuous distinct-n ratio, not a boolean.
+            EvaluatorConfig::LexicalDiversity(_) => false,
+        }
+    }
+}
+
+/// An evaluator for agentic (tool-calling) functions: compares the tool calls in the model's
+/// response against the tool calls declared as the datapoint's expected output (reusing the
+/// same `ContentBlockChatOutput::ToolCall` blocks that a chat datapoint's `output` field already
+/// supports for [`EvaluatorConfig::ExactMatch`]), matching on tool name and an argument subset,
+/// and produces an F1 score combining precision and recall over the matched calls.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[serde(deny_unknown_fields)]
+pub struct ToolCallCorrectnessConfig {
+    /// Relative tolerance applied when comparing numeric argument values - e.g. `0.01` accepts a
+    /// generated argument that is within 1% of the reference value. Non-numeric argument values
+    /// must match exactly regardless of this setting. Defaults to `0.0` (exact match).
+    #[serde(default)]
+    pub argument_tolerance: f32,
+    /// When `true`, matches must follow the reference order: each reference call can only match
+    /// a generated call that comes after the generated call matched by the previous reference
+    /// call (positions need not be identical). When `false` (the default), calls are matched by
+    /// name and arguments alone, regardless of order.
+    #[serde(default)]
+    pub require_order: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cutoff: Option<f32>,
+}
+
+/// A judge-free evaluator that scores lexical diversity via "distinct-n": the fraction of
+/// `ngram_size`-grams in the response text that are unique. Cheap decoding-pathology signal -
+/// a response that degenerates into a repeated phrase or loop scores close to `0.0`, while a
+/// lexically varied response scores close to `1.0`. Requires no reference output.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[serde(deny_unknown_fields)]
+pub struct LexicalDiversityConfig {
+    /// The size of the n-grams used to measure diversity/repetition. Defaults to `3`.
+    #[serde(default = "default_ngram_size")]
+    pub ngram_size: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cutoff: Option<f32>,
+}
+
+fn default_ngram_size() -> usize {
+    3
+}
+
+impl Default for LexicalDiversityConfig {
+    fn default() -> Self {
+        Self {
+            ngram_size: default_ngram_size(),
+            cutoff: None,
         }
     }
 }
@@ -153,6 +278,57 @@ pub struct LLMJudgeConfig {
     pub cutoff: Option<f32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// When set, the judge scores each named criterion independently instead of
+    /// producing a single `score` field - see `RubricCriterionConfig`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<HashMap<String, RubricCriterionConfig>>,
+}
+
+/// A single named criterion in a rubric-based LLM judge. The judge's output schema
+/// gains one required numeric field per criterion (named after the map key this is
+/// stored under in `LLMJudgeConfig::criteria`), so `description` plays the same role
+/// here as the hardcoded "The score assigned as a number" description does for the
+/// plain `score` field in `llm_judge_float_output_schema.json`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct RubricCriterionConfig {
+    pub description: String,
+}
+
+/// A panel of independently-run LLM judges whose scores are aggregated into
+/// a single result, reducing single-judge bias. All judges in a panel must
+/// agree on `output_type` and `optimize`, since their scores are combined.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[serde(deny_unknown_fields)]
+pub struct JudgePanelConfig {
+    /// The panel's member judges, keyed by judge name. Each judge's
+    /// individual score is retained alongside the aggregate.
+    pub judges: HashMap<String, LLMJudgeConfig>,
+    pub aggregation: JudgePanelAggregation,
+    pub output_type: LLMJudgeOutputType,
+    pub optimize: LLMJudgeOptimize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cutoff: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum JudgePanelAggregation {
+    /// The mean of all judges' scores.
+    Mean,
+    /// The median of all judges' scores.
+    Median,
+    /// The most common score among judges (boolean judges only). Ties are
+    /// broken in favor of `false`.
+    Majority,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -210,6 +386,37 @@ impl From<LLMJudgeOptimize> for MetricConfigOptimize {
     }
 }
 
+/// An evaluator that defers scoring to a human reviewer instead of calling a
+/// model. Running this evaluator enqueues a pending row in the review queue
+/// (see `db::review_queue`) rather than producing a value immediately; the
+/// value is only known once a reviewer submits it through the review queue's
+/// gateway endpoints, at which point it is cached in `StaticEvaluationHumanFeedback`
+/// and keyed by this evaluator's metric name (see `get_evaluator_metric_name`).
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[serde(deny_unknown_fields)]
+pub struct HumanEvaluatorConfig {
+    pub output_type: LLMJudgeOutputType,
+    pub optimize: LLMJudgeOptimize,
+    /// How long `run_evaluation` should wait for a human label to arrive
+    /// before giving up on a given datapoint. There is no durable task queue
+    /// backing evaluations (see `durable-tools::evaluation_jobs`), so this is
+    /// a plain bounded poll rather than a durable suspend/resume - a worker
+    /// restart while waiting on a human label loses the wait, not the queued
+    /// review task itself.
+    #[serde(default = "default_human_timeout_s")]
+    pub timeout_s: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cutoff: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+fn default_human_timeout_s() -> u64 {
+    3600
+}
+
 pub fn get_llm_judge_function_name(evaluation_name: &str, evaluator_name: &str) -> String {
     format!("tensorzero::llm_judge::{evaluation_name}::{evaluator_name}")
 }
@@ -363,15 +570,18 @@ impl UninitializedInferenceEvaluationConfig {
         let mut evaluators = HashMap::new();
         let mut function_configs = HashMap::new();
         let mut metric_configs = HashMap::new();
-        for (evaluator_name, evaluator_config, function_config, metric_config) in evaluator_results
+        for (evaluator_name, evaluator_config, evaluator_function_configs, metric_config) in
+            evaluator_results
         {
             // Add to evaluators map
             evaluators.insert(evaluator_name.clone(), evaluator_config);
 
-            // Add to function_configs map if Some
-            if let Some(config) = function_config {
+            // Add each function this evaluator needs (a plain `llm_judge` evaluator needs
+            // exactly one, keyed by its own name; a `judge_panel` needs one per judge,
+            // keyed by `{evaluator_name}::{judge_name}`)
+            for (function_name_suffix, config) in evaluator_function_configs {
                 function_configs.insert(
-                    get_llm_judge_function_name(evaluation_name, &evaluator_name),
+                    get_llm_judge_function_name(evaluation_name, &function_name_suffix),
                     Arc::new(config),
                 );
             }
@@ -401,6 +611,22 @@ pub enum UninitializedEvaluatorConfig {
     ExactMatch(ExactMatchConfig),
     #[serde(rename = "llm_judge")]
     LLMJudge(UninitializedLLMJudgeConfig),
+    #[serde(rename = "judge_panel")]
+    JudgePanel(UninitializedJudgePanelConfig),
+    Human(HumanEvaluatorConfig),
+    ToolCallCorrectness(ToolCallCorrectnessConfig),
+    LexicalDiversity(LexicalDiversityConfig),
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UninitializedJudgePanelConfig {
+    pub judges: HashMap<String, UninitializedLLMJudgeConfig>,
+    pub aggregation: JudgePanelAggregation,
+    #[serde(default)]
+    pub cutoff: Option<f32>,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
@@ -417,6 +643,8 @@ pub struct UninitializedLLMJudgeConfig {
     pub cutoff: Option<f32>,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
+    pub criteria: Option<HashMap<String, RubricCriterionConfig>>,
 }
 
 impl UninitializedEvaluatorConfig {
@@ -424,7 +652,7 @@ impl UninitializedEvaluatorConfig {
         self,
         evaluation_name: &str,
         evaluator_name: &str,
-    ) -> Result<(EvaluatorConfig, Option<FunctionConfig>, MetricConfig), Error> {
+    ) -> Result<(EvaluatorConfig, Vec<(String, FunctionConfig)>, MetricConfig), Error> {
         // Evaluator names cannot have "::" in them since we use it as a delimiter in our function names later on
         if evaluator_name.contains("::") {
             return Err(ErrorDetails::Config {
@@ -437,16 +665,210 @@ impl UninitializedEvaluatorConfig {
         match self {
             UninitializedEvaluatorConfig::ExactMatch(params) => Ok((
                 EvaluatorConfig::ExactMatch(params),
-                None,
+                vec![],
                 MetricConfig {
                     r#type: MetricConfigType::Boolean,
                     optimize: MetricConfigOptimize::Max,
                     level: MetricConfigLevel::Inference,
+                    aggregation: MetricConfigAggregation::default(),
+                    bounds: None,
                     description: None,
                 },
             )),
             UninitializedEvaluatorConfig::LLMJudge(params) => {
-                let user_schema_value: Option<serde_json::Value> = match params.input_format {
+                let (llm_judge_config, function_config, metric_config) =
+                    load_llm_judge_evaluator(evaluation_name, evaluator_name, params)?;
+                Ok((
+                    EvaluatorConfig::LLMJudge(llm_judge_config),
+                    vec![(evaluator_name.to_string(), function_config)],
+                    metric_config,
+                ))
+            }
+            UninitializedEvaluatorConfig::JudgePanel(params) => {
+                if params.judges.is_empty() {
+                    return Err(ErrorDetails::Config {
+                        message: format!(
+                            "Judge panel `{evaluator_name}` in `[evaluations.{evaluation_name}]` must have at least one judge"
+                        ),
+                    }
+                    .into());
+                }
+                let mut judges = HashMap::new();
+                let mut function_configs = Vec::new();
+                let mut output_type: Option<LLMJudgeOutputType> = None;
+                let mut optimize: Option<LLMJudgeOptimize> = None;
+                for (judge_name, judge_params) in params.judges {
+                    if judge_name.contains("::") {
+                        return Err(ErrorDetails::Config {
+                            message: format!(
+                                "Judge names cannot contain \"::\" (referenced in `[evaluations.{evaluation_name}.{evaluator_name}.{judge_name}]`)"
+                            ),
+                        }
+                        .into());
+                    }
+                    let this_output_type = judge_params.output_type;
+                    let this_optimize = judge_params.optimize;
+                    match output_type {
+                        None => output_type = Some(this_output_type),
+                        Some(existing)
+                            if MetricConfigType::from(existing)
+                                != MetricConfigType::from(this_output_type) =>
+                        {
+                            return Err(ErrorDetails::Config {
+                                message: format!(
+                                    "All judges in judge panel `{evaluator_name}` in `[evaluations.{evaluation_name}]` must share the same `output_type`"
+                                ),
+                            }
+                            .into());
+                        }
+                        _ => {}
+                    }
+                    match optimize {
+                        None => optimize = Some(this_optimize),
+                        Some(existing)
+                            if MetricConfigOptimize::from(existing)
+                                != MetricConfigOptimize::from(this_optimize) =>
+                        {
+                            return Err(ErrorDetails::Config {
+                                message: format!(
+                                    "All judges in judge panel `{evaluator_name}` in `[evaluations.{evaluation_name}]` must share the same `optimize`"
+                                ),
+                            }
+                            .into());
+                        }
+                        _ => {}
+                    }
+                    let function_name_suffix = format!("{evaluator_name}::{judge_name}");
+                    let (llm_judge_config, function_config, _metric_config) =
+                        load_llm_judge_evaluator(
+                            evaluation_name,
+                            &function_name_suffix,
+                            judge_params,
+                        )?;
+                    judges.insert(judge_name, llm_judge_config);
+                    function_configs.push((function_name_suffix, function_config));
+                }
+                // Unwrap safe: we returned early above if `params.judges` was empty.
+                let output_type = output_type.ok_or_else(|| {
+                    Error::new(ErrorDetails::Config {
+                        message: format!("Judge panel `{evaluator_name}` has no judges. {IMPOSSIBLE_ERROR_MESSAGE}"),
+                    })
+                })?;
+                let optimize = optimize.ok_or_else(|| {
+                    Error::new(ErrorDetails::Config {
+                        message: format!("Judge panel `{evaluator_name}` has no judges. {IMPOSSIBLE_ERROR_MESSAGE}"),
+                    })
+                })?;
+                Ok((
+                    EvaluatorConfig::JudgePanel(JudgePanelConfig {
+                        judges,
+                        aggregation: params.aggregation,
+                        output_type,
+                        optimize,
+                        cutoff: params.cutoff,
+                        description: params.description,
+                    }),
+                    function_configs,
+                    MetricConfig {
+                        r#type: output_type.into(),
+                        optimize: optimize.into(),
+                        level: MetricConfigLevel::Inference,
+                        aggregation: MetricConfigAggregation::default(),
+                        bounds: None,
+                        description: None,
+                    },
+                ))
+            }
+            UninitializedEvaluatorConfig::Human(params) => Ok((
+                EvaluatorConfig::Human(HumanEvaluatorConfig {
+                    output_type: params.output_type,
+                    optimize: params.optimize,
+                    timeout_s: params.timeout_s,
+                    cutoff: params.cutoff,
+                    description: params.description,
+                }),
+                vec![],
+                MetricConfig {
+                    r#type: params.output_type.into(),
+                    optimize: params.optimize.into(),
+                    level: MetricConfigLevel::Inference,
+                    aggregation: MetricConfigAggregation::default(),
+                    bounds: None,
+                    description: None,
+                },
+            )),
+            UninitializedEvaluatorConfig::ToolCallCorrectness(params) => Ok((
+                EvaluatorConfig::ToolCallCorrectness(params),
+                vec![],
+                MetricConfig {
+                    r#type: MetricConfigType::Float,
+                    optimize: MetricConfigOptimize::Max,
+                    level: MetricConfigLevel::Inference,
+                    aggregation: MetricConfigAggregation::default(),
+                    bounds: None,
+                    description: None,
+                },
+            )),
+            UninitializedEvaluatorConfig::LexicalDiversity(params) => Ok((
+                EvaluatorConfig::LexicalDiversity(params),
+                vec![],
+                MetricConfig {
+                    r#type: MetricConfigType::Float,
+                    optimize: MetricConfigOptimize::Max,
+                    level: MetricConfigLevel::Inference,
+                    aggregation: MetricConfigAggregation::default(),
+                    bounds: None,
+                    description: None,
+                },
+            )),
+        }
+    }
+}
+
+/// Builds the JSON output schema for a rubric-based LLM judge: an object with one
+/// required numeric field per criterion, named after the criterion's key in
+/// `LLMJudgeConfig::criteria`. This plays the same role as
+/// `llm_judge_float_output_schema.json` does for the non-rubric case.
+fn build_rubric_output_schema(criteria: &HashMap<String, RubricCriterionConfig>) -> Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    // Sort so that the generated schema (and thus the judge's prompt) is deterministic
+    // regardless of the HashMap's iteration order.
+    let mut criterion_names: Vec<&String> = criteria.keys().collect();
+    criterion_names.sort();
+    for name in criterion_names {
+        #[expect(clippy::expect_used)]
+        let criterion = criteria.get(name).expect("key came from criteria.keys()");
+        properties.insert(
+            name.clone(),
+            json!({
+                "type": "number",
+                "description": criterion.description,
+            }),
+        );
+        required.push(name.clone());
+    }
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "required": required,
+        "additionalProperties": false,
+        "properties": properties,
+    })
+}
+
+/// Loads a single LLM judge evaluator: builds its evaluation function (with
+/// the judge's variants) and its metric config. `function_name_suffix` is
+/// used to namespace the judge's schemas/templates - for a standalone
+/// `llm_judge` evaluator this is just the evaluator name, but for a judge
+/// within a `judge_panel` it's `{evaluator_name}::{judge_name}`.
+fn load_llm_judge_evaluator(
+    evaluation_name: &str,
+    function_name_suffix: &str,
+    params: UninitializedLLMJudgeConfig,
+) -> Result<(LLMJudgeConfig, FunctionConfig, MetricConfig), Error> {
+    let evaluator_name = function_name_suffix;
+    let user_schema_value: Option<serde_json::Value> = match params.input_format {
                     LLMJudgeInputFormat::Serialized => Some(serde_json::from_str(LLM_JUDGE_USER_SCHEMA_TEXT)
                         .map_err(|e| {
                             Error::new(ErrorDetails::JsonSchema {
@@ -455,125 +877,150 @@ impl UninitializedEvaluatorConfig {
                         })?),
                     LLMJudgeInputFormat::Messages => None,
                 };
-                let user_schema = user_schema_value.map(JSONSchema::from_value).transpose()?;
-                let output_schema_str = match params.output_type {
-                    LLMJudgeOutputType::Float => LLM_JUDGE_FLOAT_OUTPUT_SCHEMA_TEXT,
-                    LLMJudgeOutputType::Boolean => LLM_JUDGE_BOOLEAN_OUTPUT_SCHEMA_TEXT,
-                };
-                let output_schema_value = serde_json::from_str(output_schema_str)
+    let user_schema = user_schema_value.map(JSONSchema::from_value).transpose()?;
+    let output_schema_value = match &params.criteria {
+        Some(criteria) => {
+            if params.output_type != LLMJudgeOutputType::Float {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Evaluator `{evaluator_name}` in `[evaluations.{evaluation_name}]` sets `criteria`, which is only supported with `output_type = \"float\"`"
+                    ),
+                }
+                .into());
+            }
+            if criteria.is_empty() {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Evaluator `{evaluator_name}` in `[evaluations.{evaluation_name}]` sets `criteria` but it is empty"
+                    ),
+                }
+                .into());
+            }
+            build_rubric_output_schema(criteria)
+        }
+        None => {
+            let output_schema_str = match params.output_type {
+                LLMJudgeOutputType::Float => LLM_JUDGE_FLOAT_OUTPUT_SCHEMA_TEXT,
+                LLMJudgeOutputType::Boolean => LLM_JUDGE_BOOLEAN_OUTPUT_SCHEMA_TEXT,
+            };
+            serde_json::from_str(output_schema_str)
                     .map_err(|e| {
                         Error::new(ErrorDetails::JsonSchema {
                             message: format!("Failed to parse LLM judge output schema: {e}. This should never happen, please file a bug report at https://github.com/tensorzero/tensorzero/discussions/new?category=bug-reports."),
                         })
-                    })?;
-                let output_schema = JSONSchema::from_value(output_schema_value)?;
-                let json_mode_tool_call_config =
-                    create_json_mode_tool_call_config(output_schema.clone());
-
-                let mut variants = params
-                    .variants
-                    .into_iter()
-                    .map(|(name, variant)| {
-                        variant
-                            .load(
-                                evaluation_name,
-                                evaluator_name,
-                                &params.input_format,
-                                &name,
-                                user_schema.clone(),
-                            )
-                            .map(|v| (name, v))
-                    })
-                    .collect::<Result<HashMap<_, _>, Error>>()?;
-                let nonzero_weights = variants
-                    .iter()
-                    // Treat a None weight as 0.0 for this check - we only care if we have multiple variants with an explicit positive weight
-                    .filter(|(_, variant)| variant.inner.weight().unwrap_or(0.0) > 0.0)
-                    .count();
-                if nonzero_weights != 1 && variants.len() > 1 {
-                    return Err(ErrorDetails::Config {
+                    })?
+        }
+    };
+    let output_schema = JSONSchema::from_value(output_schema_value)?;
+    let json_mode_tool_call_config = create_json_mode_tool_call_config(output_schema.clone());
+
+    let mut variants = params
+        .variants
+        .into_iter()
+        .map(|(name, variant)| {
+            variant
+                .load(
+                    evaluation_name,
+                    evaluator_name,
+                    &params.input_format,
+                    &name,
+                    user_schema.clone(),
+                )
+                .map(|v| (name, v))
+        })
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+    let nonzero_weights = variants
+        .iter()
+        // Treat a None weight as 0.0 for this check - we only care if we have multiple variants with an explicit positive weight
+        .filter(|(_, variant)| variant.inner.weight().unwrap_or(0.0) > 0.0)
+        .count();
+    if nonzero_weights != 1 && variants.len() > 1 {
+        return Err(ErrorDetails::Config {
                         message: format!(
                             "Evaluator `{evaluator_name}` in `[evaluations.{evaluation_name}]` must have exactly 1 variant that is active. Found {nonzero_weights} variants with nonzero weights."
                         ),
                     }
                     .into());
-                } else if variants.len() == 1 {
-                    // If there is only one variant, it should have weight 1.0
-                    let Some((_, variant)) = variants.iter_mut().next() else {
-                        return Err(ErrorDetails::Config {
+    } else if variants.len() == 1 {
+        // If there is only one variant, it should have weight 1.0
+        let Some((_, variant)) = variants.iter_mut().next() else {
+            return Err(ErrorDetails::Config {
                             message: "Failed to grab first variant from variants map. This should never happen, please file a bug report at https://github.com/tensorzero/tensorzero/discussions/new?category=bug-reports.".to_string(),
                         }.into());
-                    };
-                    if let Some(weight) = variant.inner.weight()
-                        && weight == 0.0
-                    {
-                        return Err(ErrorDetails::Config {
+        };
+        if let Some(weight) = variant.inner.weight()
+            && weight == 0.0
+        {
+            return Err(ErrorDetails::Config {
                                 message: format!("Evaluator `{evaluator_name}` in `[evaluations.{evaluation_name}]` must have exactly 1 variant that is active. You have specified a single inactive variant."),
                             }
                             .into());
-                    }
-                    match &mut variant.inner {
-                        VariantConfig::ChatCompletion(variant) => {
-                            variant.set_weight(Some(1.0));
-                        }
-                        VariantConfig::BestOfNSampling(variant) => {
-                            variant.set_weight(Some(1.0));
-                        }
-                        VariantConfig::MixtureOfN(variant) => {
-                            variant.set_weight(Some(1.0));
-                        }
-                        VariantConfig::Dicl(variant) => {
-                            variant.set_weight(Some(1.0));
-                        }
-                        VariantConfig::ChainOfThought(variant) => {
-                            variant.inner.set_weight(Some(1.0));
-                        }
-                    };
-                }
-                let variants: HashMap<_, _> = variants
-                    .into_iter()
-                    .map(|(name, variant)| (name, Arc::new(variant)))
-                    .collect();
-                let all_template_names: HashSet<String> = variants
-                    .values()
-                    .flat_map(|v| v.get_all_explicit_template_names())
-                    .collect();
-                let experimentation = ExperimentationConfig::legacy_from_variants_map(&variants);
-                let function_config = FunctionConfig::Json(FunctionConfigJson {
-                    variants,
-                    schemas: SchemaData::load(
-                        user_schema,
-                        None,
-                        None,
-                        UninitializedSchemas::default(),
-                        &format!("tensorzero::evaluator::{evaluator_name}"),
-                    )?,
-                    output_schema,
-                    json_mode_tool_call_config,
-                    description: None,
-                    all_explicit_template_names: all_template_names,
-                    experimentation,
-                });
-                Ok((
-                    EvaluatorConfig::LLMJudge(LLMJudgeConfig {
-                        input_format: params.input_format,
-                        output_type: params.output_type,
-                        include: params.include,
-                        optimize: params.optimize,
-                        cutoff: params.cutoff,
-                        description: params.description,
-                    }),
-                    Some(function_config),
-                    MetricConfig {
-                        r#type: params.output_type.into(),
-                        optimize: params.optimize.into(),
-                        level: MetricConfigLevel::Inference,
-                        description: None,
-                    },
-                ))
-            }
         }
+        match &mut variant.inner {
+            VariantConfig::ChatCompletion(variant) => {
+                variant.set_weight(Some(1.0));
+            }
+            VariantConfig::BestOfNSampling(variant) => {
+                variant.set_weight(Some(1.0));
+            }
+            VariantConfig::MixtureOfN(variant) => {
+                variant.set_weight(Some(1.0));
+            }
+            VariantConfig::Dicl(variant) => {
+                variant.set_weight(Some(1.0));
+            }
+            VariantConfig::ChainOfThought(variant) => {
+                variant.inner.set_weight(Some(1.0));
+            }
+            VariantConfig::FallbackChain(variant) => {
+                variant.set_weight(Some(1.0));
+            }
+        };
     }
+    let variants: HashMap<_, _> = variants
+        .into_iter()
+        .map(|(name, variant)| (name, Arc::new(variant)))
+        .collect();
+    let all_template_names: HashSet<String> = variants
+        .values()
+        .flat_map(|v| v.get_all_explicit_template_names())
+        .collect();
+    let experimentation = ExperimentationConfig::legacy_from_variants_map(&variants);
+    let function_config = FunctionConfig::Json(FunctionConfigJson {
+        variants,
+        schemas: SchemaData::load(
+            user_schema,
+            None,
+            None,
+            UninitializedSchemas::default(),
+            &format!("tensorzero::evaluator::{evaluator_name}"),
+        )?,
+        output_schema,
+        json_mode_tool_call_config,
+        description: None,
+        all_explicit_template_names: all_template_names,
+        experimentation,
+    });
+    Ok((
+        EvaluatorConfig::LLMJudge(LLMJudgeConfig {
+            input_format: params.input_format,
+            output_type: params.output_type,
+            include: params.include,
+            optimize: params.optimize,
+            cutoff: params.cutoff,
+            description: params.description,
+            criteria: params.criteria,
+        }),
+        function_config,
+        MetricConfig {
+            r#type: params.output_type.into(),
+            optimize: params.optimize.into(),
+            level: MetricConfigLevel::Inference,
+            aggregation: MetricConfigAggregation::default(),
+            bounds: None,
+            description: None,
+        },
+    ))
 }
 
 #[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
@@ -670,6 +1117,8 @@ fn convert_chat_completion_judge_to_variant(
         frequency_penalty: params.frequency_penalty,
         input_wrappers: None,
         json_mode: Some(params.json_mode),
+        json_repair: None,
+        prompt_compression: None,
         max_tokens: params.max_tokens,
         model: params.model,
         presence_penalty: params.presence_penalty,
@@ -847,6 +1296,8 @@ impl UninitializedLLMJudgeVariantInfo {
                                 frequency_penalty: params.evaluator.frequency_penalty,
                                 input_wrappers: None,
                                 json_mode: Some(params.evaluator.json_mode),
+                                json_repair: None,
+                                prompt_compression: None,
                                 max_tokens: params.evaluator.max_tokens,
                                 model: params.evaluator.model,
                                 presence_penalty: params.evaluator.presence_penalty,
@@ -920,6 +1371,8 @@ impl UninitializedLLMJudgeVariantInfo {
                                 frequency_penalty: params.fuser.frequency_penalty,
                                 input_wrappers: None,
                                 json_mode: Some(params.fuser.json_mode),
+                                json_repair: None,
+                                prompt_compression: None,
                                 max_tokens: params.fuser.max_tokens,
                                 model: params.fuser.model,
                                 presence_penalty: params.fuser.presence_penalty,
@@ -1307,6 +1760,7 @@ mod tests {
                 },
                 cutoff: None,
                 description: Some("llm judge description".to_string()),
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -1445,6 +1899,7 @@ mod tests {
                 },
                 cutoff: None,
                 description: Some("llm judge description float".to_string()),
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -1653,6 +2108,7 @@ mod tests {
                 },
                 cutoff: Some(0.3),
                 description: None,
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -1768,6 +2224,7 @@ mod tests {
                 },
                 cutoff: None,
                 description: None,
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -1843,6 +2300,7 @@ mod tests {
                 include: LLMJudgeIncludeConfig::default(),
                 cutoff: None,
                 description: None,
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -1922,6 +2380,7 @@ mod tests {
                 include: LLMJudgeIncludeConfig::default(),
                 cutoff: None,
                 description: None,
+                criteria: None,
             };
 
             let mut evaluators = HashMap::new();
@@ -0,0 +1,95 @@
+//! In-process event bus broadcasting gateway activity (inferences, feedback, job progress,
+//! config changes) to any number of subscribers, so dashboards can show live activity without
+//! polling list endpoints. See `endpoints::internal::events` for the SSE endpoint that exposes
+//! this to HTTP clients.
+//!
+//! Publishing is deliberately fire-and-forget: a `GatewayEvent` is dropped if there are no
+//! subscribers, and a slow subscriber that falls behind the channel's capacity misses old events
+//! rather than blocking the publisher (see `tokio::sync::broadcast`). Gateway activity is never
+//! reconstructed from this stream - it's a live activity feed, not a source of truth.
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum GatewayEvent {
+    InferenceCompleted {
+        inference_id: Uuid,
+        function_name: String,
+        variant_name: String,
+    },
+    FeedbackReceived {
+        feedback_id: Uuid,
+        target_id: Uuid,
+        metric_name: String,
+    },
+    JobProgressed {
+        job_id: Uuid,
+        kind: String,
+        state: String,
+    },
+    ConfigChanged {
+        config_snapshot_hash: String,
+    },
+}
+
+impl GatewayEvent {
+    /// The `kind` query parameter value that selects this event in the SSE endpoint's filter.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GatewayEvent::InferenceCompleted { .. } => "inference",
+            GatewayEvent::FeedbackReceived { .. } => "feedback",
+            GatewayEvent::JobProgressed { .. } => "job",
+            GatewayEvent::ConfigChanged { .. } => "config",
+        }
+    }
+}
+
+/// Number of recent events a lagging subscriber can fall behind before it starts missing events.
+/// Chosen to comfortably absorb a short burst without holding much memory - this is a live feed,
+/// not a durable log.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Broadcasts [`GatewayEvent`]s to any number of subscribers (e.g. SSE connections).
+///
+/// Cheap to clone: it's a thin wrapper around a `tokio::sync::broadcast::Sender`, which is
+/// itself reference-counted.
+#[derive(Clone)]
+pub struct GatewayEventBus {
+    sender: broadcast::Sender<GatewayEvent>,
+}
+
+impl std::fmt::Debug for GatewayEventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GatewayEventBus")
+            .field("subscriber_count", &self.sender.receiver_count())
+            .finish()
+    }
+}
+
+impl Default for GatewayEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GatewayEventBus {
+    pub fn new() -> Self {
+        let (sender, _receiver) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Publishes an event to all current subscribers. A no-op if there are none.
+    pub fn publish(&self, event: GatewayEvent) {
+        // An error here just means there are no subscribers right now - not worth logging.
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<GatewayEvent> {
+        self.sender.subscribe()
+    }
+}
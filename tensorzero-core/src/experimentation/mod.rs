@@ -17,6 +17,7 @@ use crate::variant::VariantInfo;
 
 pub mod asymptotic_confidence_sequences;
 mod static_weights;
+pub mod thompson_sampling;
 pub mod track_and_stop;
 mod uniform;
 
@@ -104,6 +105,7 @@ pub enum ExperimentationConfig {
     // (serde enums cannot be #[serde(flatten)])
     // we can write a custom deserializer for this if we want
     TrackAndStop(track_and_stop::TrackAndStopConfig),
+    ThompsonSampling(thompson_sampling::ThompsonSamplingConfig),
     #[cfg_attr(feature = "ts-bindings", ts(skip))]
     #[cfg(test)]
     AlwaysFails(AlwaysFailsConfig),
@@ -122,6 +124,7 @@ pub enum UninitializedExperimentationConfig {
     StaticWeights(static_weights::StaticWeightsConfig),
     Uniform(uniform::UniformConfig),
     TrackAndStop(track_and_stop::UninitializedTrackAndStopConfig),
+    ThompsonSampling(thompson_sampling::UninitializedThompsonSamplingConfig),
 }
 
 impl UninitializedExperimentationConfig {
@@ -158,6 +161,9 @@ impl UninitializedExperimentationConfig {
             UninitializedExperimentationConfig::TrackAndStop(config) => Ok(
                 ExperimentationConfig::TrackAndStop(config.load(variants, metrics)?),
             ),
+            UninitializedExperimentationConfig::ThompsonSampling(config) => Ok(
+                ExperimentationConfig::ThompsonSampling(config.load(variants, metrics)?),
+            ),
         }
     }
 }
@@ -231,6 +237,11 @@ impl ExperimentationConfig {
                     .setup(db, function_name, postgres, cancel_token)
                     .await
             }
+            Self::ThompsonSampling(config) => {
+                config
+                    .setup(db, function_name, postgres, cancel_token)
+                    .await
+            }
             #[cfg(test)]
             Self::AlwaysFails(config) => {
                 config
@@ -270,6 +281,11 @@ impl ExperimentationConfig {
                     .sample(function_name, episode_id, active_variants, postgres)
                     .await
             }
+            Self::ThompsonSampling(config) => {
+                config
+                    .sample(function_name, episode_id, active_variants, postgres)
+                    .await
+            }
         };
 
         // If the sampler fails but there are active variants, fall back to uniform sampling
@@ -284,6 +300,7 @@ impl ExperimentationConfig {
                     #[cfg(test)]
                     Self::AlwaysFails(config) => config.allowed_variants().collect(),
                     Self::TrackAndStop(config) => config.allowed_variants().collect(),
+                    Self::ThompsonSampling(config) => config.allowed_variants().collect(),
                 };
                 // If allowed is empty (UniformConfig with None, None), fall back to all variants
                 if allowed.is_empty() {
@@ -315,6 +332,9 @@ impl ExperimentationConfig {
             Self::TrackAndStop(config) => {
                 config.get_current_display_probabilities(function_name, active_variants, postgres)
             }
+            Self::ThompsonSampling(config) => {
+                config.get_current_display_probabilities(function_name, active_variants, postgres)
+            }
         }
     }
 }
@@ -109,6 +109,27 @@ impl StaticWeightsConfig {
             fallback_variants,
         }
     }
+
+    /// Returns true if `variant_name` can ever be sampled by this config: either it has a
+    /// positive candidate weight, or it's a fallback variant (fallbacks are only skipped when
+    /// every candidate has positive weight, which isn't something we can verify from this config
+    /// alone, so we conservatively treat fallback membership as "has traffic").
+    pub fn has_traffic(&self, variant_name: &str) -> bool {
+        self.candidate_variants
+            .get(variant_name)
+            .is_some_and(|&weight| weight > 0.0)
+            || self
+                .fallback_variants
+                .iter()
+                .any(|name| name == variant_name)
+    }
+
+    /// Removes a variant from both the candidate and fallback lists, e.g. once it's been
+    /// retired from the function entirely (see `retire_variant`).
+    pub fn remove_variant(&mut self, variant_name: &str) {
+        self.candidate_variants.remove(variant_name);
+        self.fallback_variants.retain(|name| name != variant_name);
+    }
 }
 
 impl VariantSampler for StaticWeightsConfig {
@@ -0,0 +1,503 @@
+//! Thompson-sampling bandit experimentation strategy.
+//!
+//! This strategy maintains a Beta-Bernoulli posterior over each candidate variant's success
+//! rate for a boolean metric, and samples a variant by drawing from each posterior and picking
+//! the largest draw. Untried variants start from a flat `Beta(1, 1)` prior, so they naturally
+//! get explored before the posteriors concentrate around the observed success rates.
+//!
+//! Like `TrackAndStop`, a background task periodically refreshes the posteriors from feedback
+//! (`VariantSampler::sample` has no database handle of its own), and Postgres is used only for
+//! episode-to-variant stickiness via `check_and_set_variant_by_episode` - the posteriors
+//! themselves are recomputed from ClickHouse feedback on every `update_period` tick rather than
+//! persisted, mirroring `TrackAndStop`'s own in-memory `ArcSwap` state.
+//!
+//! Freezing exploration (via `frozen`) and inspecting the current posteriors (via
+//! `get_current_display_probabilities`) are both exposed at the config level so that a future
+//! admin endpoint can drive them; wiring an HTTP route for runtime freeze/unfreeze is left for
+//! a follow-up, since it also needs a way to look up a live `ThompsonSamplingConfig` for a given
+//! function from the running `Config`, which doesn't exist yet.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use arc_swap::ArcSwap;
+use rand::Rng;
+use rand_distr::{Beta, Distribution};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::{
+    config::{MetricConfig, MetricConfigType},
+    db::{
+        ExperimentationQueries, HealthCheckable, feedback::FeedbackQueries,
+        postgres::PostgresConnectionInfo,
+    },
+    error::{Error, ErrorDetails, IMPOSSIBLE_ERROR_MESSAGE},
+    utils::spawn_ignoring_shutdown,
+    variant::VariantInfo,
+};
+
+use super::{VariantSampler, check_duplicates_across, check_duplicates_within};
+
+/// Number of Monte Carlo draws used to estimate "probability of being optimal" for
+/// `get_current_display_probabilities`. Exact for two arms, an approximation beyond that -
+/// good enough for display purposes without pulling in a numerical integration dependency.
+const OPTIMALITY_DRAWS: usize = 2_000;
+
+#[derive(Clone, Copy, Debug)]
+struct BetaPosterior {
+    alpha: f64,
+    beta: f64,
+}
+
+impl BetaPosterior {
+    const PRIOR: Self = Self {
+        alpha: 1.0,
+        beta: 1.0,
+    };
+
+    fn mean(&self) -> f64 {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> f64 {
+        // `Beta::new` only fails for non-positive parameters, which can't happen here since
+        // `alpha`/`beta` start at 1.0 and only ever increase.
+        Beta::new(self.alpha, self.beta)
+            .unwrap_or_else(|_| Beta::new(1.0, 1.0).expect(IMPOSSIBLE_ERROR_MESSAGE))
+            .sample(rng)
+    }
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ThompsonSamplingConfig {
+    metric: String,
+    candidate_variants: Vec<String>,
+    fallback_variants: Vec<String>,
+    #[cfg_attr(feature = "ts-bindings", ts(skip))]
+    update_period: Duration,
+    /// Current Beta posteriors, keyed by candidate variant name. Missing entries are treated
+    /// as the flat `Beta(1, 1)` prior.
+    #[cfg_attr(feature = "ts-bindings", ts(skip))]
+    #[serde(skip)]
+    posteriors: Arc<ArcSwap<HashMap<String, BetaPosterior>>>,
+    /// When frozen, `sample` always exploits the variant with the highest posterior mean
+    /// instead of drawing from the posteriors, which stops further exploration.
+    #[cfg_attr(feature = "ts-bindings", ts(skip))]
+    #[serde(skip)]
+    frozen: Arc<AtomicBool>,
+    #[cfg_attr(feature = "ts-bindings", ts(skip))]
+    #[serde(skip)]
+    task_spawned: AtomicBool,
+}
+
+impl ThompsonSamplingConfig {
+    /// Stops further exploration: `sample` will always exploit the variant with the highest
+    /// posterior mean success rate among the active candidates.
+    pub fn freeze(&self) {
+        self.frozen.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes drawing from the posteriors on every `sample` call.
+    pub fn unfreeze(&self) {
+        self.frozen.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::SeqCst)
+    }
+
+    /// Snapshot of the current posterior mean success rate for each candidate variant, for
+    /// inspection by callers such as an admin UI. Variants without feedback yet report the
+    /// prior mean of `0.5`.
+    pub fn posterior_means(&self) -> HashMap<String, f64> {
+        let posteriors = self.posteriors.load();
+        self.candidate_variants
+            .iter()
+            .map(|name| {
+                let mean = posteriors
+                    .get(name)
+                    .copied()
+                    .unwrap_or(BetaPosterior::PRIOR)
+                    .mean();
+                (name.clone(), mean)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UninitializedThompsonSamplingConfig {
+    metric: String,
+    candidate_variants: Vec<String>,
+    #[serde(default)]
+    fallback_variants: Vec<String>,
+    #[serde(default = "default_update_period_s")]
+    update_period_s: u64,
+    #[serde(default)]
+    frozen: bool,
+}
+
+fn default_update_period_s() -> u64 {
+    30
+}
+
+impl UninitializedThompsonSamplingConfig {
+    pub fn load(
+        self,
+        variants: &HashMap<String, Arc<VariantInfo>>,
+        metrics: &HashMap<String, MetricConfig>,
+    ) -> Result<ThompsonSamplingConfig, Error> {
+        let metric_config = metrics.get(&self.metric).ok_or_else(|| {
+            Error::new(ErrorDetails::Config {
+                message: format!(
+                    "Thompson-sampling experiment references unknown metric '{}'. Available metrics: {:?}",
+                    self.metric,
+                    metrics.keys().collect::<Vec<_>>()
+                ),
+            })
+        })?;
+
+        // Thompson sampling here uses the Beta-Bernoulli conjugate prior, which requires the
+        // metric's feedback values to be 0/1. We only support boolean metrics rather than
+        // approximating counts from a float mean, to keep the posterior update exact.
+        if metric_config.r#type != MetricConfigType::Boolean {
+            return Err(Error::new(ErrorDetails::Config {
+                message: format!(
+                    "Thompson-sampling experiment references metric '{}', which is not a boolean metric. \
+                    Thompson sampling requires a boolean metric so that its Beta-Bernoulli posterior update is exact.",
+                    self.metric
+                ),
+            }));
+        }
+
+        if self.candidate_variants.is_empty() {
+            return Err(Error::new(ErrorDetails::Config {
+                message: "Thompson-sampling candidate_variants cannot be empty".to_string(),
+            }));
+        }
+
+        check_duplicates_within(&self.candidate_variants, "candidate_variants")?;
+        for variant in &self.candidate_variants {
+            if !variants.contains_key(variant) {
+                return Err(Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Thompson-sampling candidate_variants includes unknown variant '{}'. Available variants: {:?}",
+                        variant,
+                        variants.keys().collect::<Vec<_>>()
+                    ),
+                }));
+            }
+        }
+
+        check_duplicates_within(&self.fallback_variants, "fallback_variants")?;
+        for variant in &self.fallback_variants {
+            if !variants.contains_key(variant) {
+                return Err(Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Thompson-sampling fallback_variants includes unknown variant '{}'. Available variants: {:?}",
+                        variant,
+                        variants.keys().collect::<Vec<_>>()
+                    ),
+                }));
+            }
+        }
+
+        check_duplicates_across(&self.candidate_variants, &self.fallback_variants)?;
+
+        Ok(ThompsonSamplingConfig {
+            metric: self.metric,
+            candidate_variants: self.candidate_variants,
+            fallback_variants: self.fallback_variants,
+            update_period: Duration::from_secs(self.update_period_s),
+            posteriors: Arc::new(ArcSwap::new(Arc::new(HashMap::new()))),
+            frozen: Arc::new(AtomicBool::new(self.frozen)),
+            task_spawned: AtomicBool::new(false),
+        })
+    }
+}
+
+impl VariantSampler for ThompsonSamplingConfig {
+    async fn setup(
+        &self,
+        db: Arc<dyn FeedbackQueries + Send + Sync>,
+        function_name: &str,
+        postgres: &PostgresConnectionInfo,
+        cancel_token: CancellationToken,
+    ) -> Result<(), Error> {
+        // Like Track-and-Stop, we need Postgres for episode-to-variant consistency.
+        match postgres {
+            PostgresConnectionInfo::Disabled => {
+                return Err(Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "Thompson-sampling experimentation is configured for function '{function_name}' but PostgreSQL is not available. \
+                        Thompson sampling requires PostgreSQL for episode-to-variant consistency. \
+                        Please set the `TENSORZERO_POSTGRES_URL` environment variable.",
+                    ),
+                }));
+            }
+            PostgresConnectionInfo::Enabled { .. } => {}
+            #[cfg(test)]
+            PostgresConnectionInfo::Mock { .. } => {}
+        }
+
+        postgres.health().await.map_err(|e| {
+            Error::new(ErrorDetails::Config {
+                message: format!(
+                    "Thompson-sampling experimentation is configured for function '{function_name}' but PostgreSQL is unhealthy: {e}. \
+                    Thompson sampling requires a healthy PostgreSQL connection for episode-to-variant consistency.",
+                ),
+            })
+        })?;
+
+        if self
+            .task_spawned
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return Err(Error::new(ErrorDetails::Config {
+                message: format!(
+                    "Thompson-sampling posterior update task has already been spawned for function '{function_name}'"
+                ),
+            }));
+        }
+
+        spawn_ignoring_shutdown(posterior_update_task(PosteriorUpdateTaskArgs {
+            db,
+            candidate_variants: self.candidate_variants.clone(),
+            metric_name: self.metric.clone(),
+            function_name: function_name.to_string(),
+            posteriors: self.posteriors.clone(),
+            update_period: self.update_period,
+            cancel_token,
+        }));
+        Ok(())
+    }
+
+    fn allowed_variants(&self) -> impl Iterator<Item = &str> + '_ {
+        self.candidate_variants
+            .iter()
+            .map(String::as_str)
+            .chain(self.fallback_variants.iter().map(String::as_str))
+    }
+
+    async fn sample(
+        &self,
+        function_name: &str,
+        episode_id: Uuid,
+        active_variants: &mut BTreeMap<String, Arc<VariantInfo>>,
+        postgres: &PostgresConnectionInfo,
+    ) -> Result<(String, Arc<VariantInfo>), Error> {
+        let posteriors = self.posteriors.load();
+        let frozen = self.is_frozen();
+
+        let candidate_name = {
+            // Drop the RNG before any await points.
+            let mut rng = rand::rng();
+            select_candidate(
+                &self.candidate_variants,
+                active_variants,
+                &posteriors,
+                frozen,
+                &mut rng,
+            )
+        };
+
+        let variant_name = if let Some(candidate_name) = candidate_name {
+            let set_variant = postgres
+                .check_and_set_variant_by_episode(episode_id, function_name, candidate_name)
+                .await?;
+
+            if active_variants.contains_key(&set_variant) {
+                set_variant
+            } else {
+                fallback_sample(active_variants, &self.fallback_variants)?
+            }
+        } else {
+            fallback_sample(active_variants, &self.fallback_variants)?
+        };
+
+        active_variants.remove_entry(&variant_name).ok_or_else(|| {
+            Error::new(ErrorDetails::InternalError {
+                message: format!(
+                    "Sampled variant {variant_name} not found in active_variants. {IMPOSSIBLE_ERROR_MESSAGE}."
+                ),
+            })
+        })
+    }
+
+    fn get_current_display_probabilities<'a>(
+        &self,
+        _function_name: &str,
+        active_variants: &'a HashMap<String, Arc<VariantInfo>>,
+        _postgres: &PostgresConnectionInfo,
+    ) -> Result<HashMap<&'a str, f64>, Error> {
+        let posteriors = self.posteriors.load();
+        let active_candidates: Vec<&'a str> = active_variants
+            .keys()
+            .filter(|k| self.candidate_variants.contains(k))
+            .map(String::as_str)
+            .collect();
+
+        if active_candidates.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        if self.is_frozen() {
+            let best = active_candidates
+                .iter()
+                .copied()
+                .max_by(|a, b| {
+                    let mean_a = posteriors.get(*a).copied().unwrap_or(BetaPosterior::PRIOR).mean();
+                    let mean_b = posteriors.get(*b).copied().unwrap_or(BetaPosterior::PRIOR).mean();
+                    mean_a.total_cmp(&mean_b)
+                })
+                .ok_or_else(|| {
+                    Error::new(ErrorDetails::InternalError {
+                        message: format!(
+                            "Non-empty active_candidates produced no best variant. {IMPOSSIBLE_ERROR_MESSAGE}"
+                        ),
+                    })
+                })?;
+            return Ok(active_candidates
+                .into_iter()
+                .map(|name| (name, if name == best { 1.0 } else { 0.0 }))
+                .collect());
+        }
+
+        // Estimate "probability of being optimal" for each active candidate via Monte Carlo:
+        // draw once from every candidate's posterior and tally which one wins, repeated.
+        let mut wins: HashMap<&'a str, u32> = active_candidates.iter().map(|&k| (k, 0)).collect();
+        let mut rng = rand::rng();
+        for _ in 0..OPTIMALITY_DRAWS {
+            let winner = active_candidates.iter().copied().max_by(|a, b| {
+                let draw_a = posteriors
+                    .get(*a)
+                    .copied()
+                    .unwrap_or(BetaPosterior::PRIOR)
+                    .sample(&mut rng);
+                let draw_b = posteriors
+                    .get(*b)
+                    .copied()
+                    .unwrap_or(BetaPosterior::PRIOR)
+                    .sample(&mut rng);
+                draw_a.total_cmp(&draw_b)
+            });
+            if let Some(winner) = winner {
+                *wins.entry(winner).or_insert(0) += 1;
+            }
+        }
+
+        Ok(wins
+            .into_iter()
+            .map(|(name, count)| (name, f64::from(count) / OPTIMALITY_DRAWS as f64))
+            .collect())
+    }
+}
+
+/// Picks the candidate variant to sample: the highest posterior draw (or posterior mean, when
+/// `frozen`) among the active candidates. Returns `None` if no candidate is active.
+fn select_candidate<'a>(
+    candidate_variants: &'a [String],
+    active_variants: &BTreeMap<String, Arc<VariantInfo>>,
+    posteriors: &HashMap<String, BetaPosterior>,
+    frozen: bool,
+    rng: &mut impl Rng,
+) -> Option<&'a str> {
+    candidate_variants
+        .iter()
+        .filter(|name| active_variants.contains_key(name.as_str()))
+        .map(|name| {
+            let posterior = posteriors
+                .get(name)
+                .copied()
+                .unwrap_or(BetaPosterior::PRIOR);
+            let score = if frozen {
+                posterior.mean()
+            } else {
+                posterior.sample(rng)
+            };
+            (name.as_str(), score)
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(name, _)| name)
+}
+
+/// Select the first active variant from the ranked `fallback_variants` list.
+fn fallback_sample(
+    active_variants: &BTreeMap<String, Arc<VariantInfo>>,
+    fallback_variants: &[String],
+) -> Result<String, Error> {
+    for variant_name in fallback_variants {
+        if active_variants.contains_key(variant_name) {
+            return Ok(variant_name.clone());
+        }
+    }
+    Err(ErrorDetails::NoFallbackVariantsRemaining.into())
+}
+
+struct PosteriorUpdateTaskArgs {
+    db: Arc<dyn FeedbackQueries + Send + Sync>,
+    candidate_variants: Vec<String>,
+    metric_name: String,
+    function_name: String,
+    posteriors: Arc<ArcSwap<HashMap<String, BetaPosterior>>>,
+    update_period: Duration,
+    cancel_token: CancellationToken,
+}
+
+async fn posterior_update_task(args: PosteriorUpdateTaskArgs) {
+    let PosteriorUpdateTaskArgs {
+        db,
+        candidate_variants,
+        metric_name,
+        function_name,
+        posteriors,
+        update_period,
+        cancel_token,
+    } = args;
+
+    let mut interval = tokio::time::interval(update_period);
+    loop {
+        tokio::select! {
+            () = cancel_token.cancelled() => {
+                break;
+            }
+            _ = interval.tick() => {}
+        }
+
+        match db
+            .get_feedback_by_variant(&metric_name, &function_name, Some(&candidate_variants))
+            .await
+        {
+            Ok(variant_performances) => {
+                let mut new_posteriors = HashMap::new();
+                for performance in variant_performances {
+                    // The metric is boolean, so `mean` is the observed success rate; recover
+                    // approximate success/failure counts from it to update the conjugate prior.
+                    let successes =
+                        (f64::from(performance.mean) * performance.count as f64).round();
+                    let failures = performance.count as f64 - successes;
+                    new_posteriors.insert(
+                        performance.variant_name,
+                        BetaPosterior {
+                            alpha: BetaPosterior::PRIOR.alpha + successes,
+                            beta: BetaPosterior::PRIOR.beta + failures,
+                        },
+                    );
+                }
+                posteriors.store(Arc::new(new_posteriors));
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to update Thompson-sampling posteriors for {function_name}: {e}"
+                );
+            }
+        }
+    }
+}
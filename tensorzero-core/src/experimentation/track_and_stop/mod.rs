@@ -2794,6 +2794,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 optimize: MetricConfigOptimize::Max,
                 level: MetricConfigLevel::Inference,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -2829,6 +2831,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 optimize: MetricConfigOptimize::Max,
                 level: MetricConfigLevel::Inference,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -2863,6 +2867,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 optimize: MetricConfigOptimize::Max,
                 level: MetricConfigLevel::Inference,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -2897,6 +2903,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 optimize: MetricConfigOptimize::Max,
                 level: MetricConfigLevel::Inference,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
@@ -2934,6 +2942,8 @@ mod tests {
                 r#type: MetricConfigType::Float,
                 optimize: MetricConfigOptimize::Max,
                 level: MetricConfigLevel::Inference,
+                aggregation: MetricConfigAggregation::default(),
+                bounds: None,
                 description: None,
             },
         );
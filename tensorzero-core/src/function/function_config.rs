@@ -1,4 +1,5 @@
 use crate::config::SchemaData;
+use crate::config::data_residency::DataResidencyPolicy;
 use crate::config::gateway::GatewayConfig;
 #[cfg(feature = "pyo3")]
 use crate::error::IMPOSSIBLE_ERROR_MESSAGE;
@@ -8,7 +9,7 @@ use crate::inference::types::pyo3_helpers::serialize_to_dict;
 #[cfg(feature = "pyo3")]
 use crate::variant::{
     BestOfNSamplingConfigPyClass, ChainOfThoughtConfigPyClass, ChatCompletionConfigPyClass,
-    DiclConfigPyClass, MixtureOfNConfigPyClass, VariantConfig,
+    DiclConfigPyClass, FallbackChainConfigPyClass, MixtureOfNConfigPyClass, VariantConfig,
 };
 #[cfg(feature = "pyo3")]
 use pyo3::IntoPyObjectExt;
@@ -267,6 +268,9 @@ impl VariantsConfigPyClass {
             VariantConfig::ChainOfThought(_) => {
                 ChainOfThoughtConfigPyClass { inner: v }.into_bound_py_any(py)
             }
+            VariantConfig::FallbackChain(_) => {
+                FallbackChainConfigPyClass { inner: v }.into_bound_py_any(py)
+            }
         }
     }
 }
@@ -297,6 +301,9 @@ pub struct FunctionConfigChat {
     // the same template error for every variant.
     #[serde(skip)]
     pub all_explicit_templates_names: HashSet<String>,
+    /// Restricts which model provider regions inference for this function may be routed to.
+    /// See [`DataResidencyPolicy`].
+    pub data_residency: Option<DataResidencyPolicy>,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -312,6 +319,8 @@ pub struct FunctionConfigJson {
     // See `FunctionConfigChat.all_explicit_template_names`.
     #[serde(skip)]
     pub all_explicit_template_names: HashSet<String>,
+    /// See `FunctionConfigChat.data_residency`.
+    pub data_residency: Option<DataResidencyPolicy>,
 }
 
 impl FunctionConfig {
@@ -322,6 +331,13 @@ impl FunctionConfig {
         }
     }
 
+    pub fn data_residency(&self) -> Option<&DataResidencyPolicy> {
+        match self {
+            FunctionConfig::Chat(params) => params.data_residency.as_ref(),
+            FunctionConfig::Json(params) => params.data_residency.as_ref(),
+        }
+    }
+
     pub async fn validate_inference_params(
         &self,
         params: &crate::endpoints::inference::Params,
@@ -0,0 +1,123 @@
+//! Content-addressable storage for large, frequently-repeated text blobs
+//! (e.g. system prompts and context blocks shared across many inferences).
+//!
+//! This reuses the same object store already configured for file storage
+//! (see `storage::StorageKindExt::file_path`), but stores plain text blobs
+//! under a separate `observability/blobs/` prefix, keyed by the `blake3` hash
+//! of their contents. Writing the same blob twice is a no-op after the first
+//! write, so identical system prompts/context blocks are stored exactly once
+//! regardless of how many inferences reference them.
+//!
+//! NOTE: this is a standalone building block. It is not yet wired into the
+//! ClickHouse inference insert path - doing so would require teaching
+//! `ResolvedInput`/`StoredInput` to store a `ContentHash` instead of inline
+//! text and to rehydrate it on read, which is a larger, separate change.
+
+use object_store::{PutMode, PutOptions, path::Path};
+use std::fmt;
+
+use crate::config::ObjectStoreInfo;
+use crate::error::{Error, ErrorDetails};
+
+/// The `blake3` hash of a stored blob's contents, used as its storage key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ContentHash(blake3::Hash);
+
+impl ContentHash {
+    pub fn of(data: &[u8]) -> Self {
+        Self(blake3::hash(data))
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn blob_path(hash: ContentHash) -> Result<Path, Error> {
+    Path::parse(format!("observability/blobs/{hash}")).map_err(|e| {
+        Error::new(ErrorDetails::InternalError {
+            message: format!("Failed to construct object_store path for blob {hash}: {e}"),
+        })
+    })
+}
+
+/// Stores `data` in the content-addressable blob store, if it isn't already
+/// present, and returns its `ContentHash`.
+///
+/// Returns an error if no object store is configured, or if the write fails
+/// for a reason other than the blob already existing.
+pub async fn store_blob(object_store: &ObjectStoreInfo, data: &[u8]) -> Result<ContentHash, Error> {
+    let hash = ContentHash::of(data);
+    let Some(store) = object_store.object_store.as_ref() else {
+        return Err(ErrorDetails::InternalError {
+            message: "Called `store_blob` with no object store configured".to_string(),
+        }
+        .into());
+    };
+    let path = blob_path(hash)?;
+    let res = store
+        .put_opts(
+            &path,
+            data.to_vec().into(),
+            PutOptions {
+                mode: PutMode::Create,
+                ..Default::default()
+            },
+        )
+        .await;
+    match res {
+        Ok(_) | Err(object_store::Error::AlreadyExists { .. }) => Ok(hash),
+        Err(e) => Err(ErrorDetails::InternalError {
+            message: format!("Failed to write blob {hash} to object store: {e:?}"),
+        }
+        .into()),
+    }
+}
+
+/// Fetches the blob previously stored under `hash` via `store_blob`.
+pub async fn fetch_blob(
+    object_store: &ObjectStoreInfo,
+    hash: ContentHash,
+) -> Result<Vec<u8>, Error> {
+    let Some(store) = object_store.object_store.as_ref() else {
+        return Err(ErrorDetails::InternalError {
+            message: "Called `fetch_blob` with no object store configured".to_string(),
+        }
+        .into());
+    };
+    let path = blob_path(hash)?;
+    let result = store.get(&path).await.map_err(|e| {
+        Error::new(ErrorDetails::InternalError {
+            message: format!("Failed to read blob {hash} from object store: {e:?}"),
+        })
+    })?;
+    let bytes = result.bytes().await.map_err(|e| {
+        Error::new(ErrorDetails::InternalError {
+            message: format!("Failed to read blob {hash} contents from object store: {e:?}"),
+        })
+    })?;
+    Ok(bytes.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hash_is_deterministic_and_content_dependent() {
+        let hash_a = ContentHash::of(b"the quick brown fox");
+        let hash_b = ContentHash::of(b"the quick brown fox");
+        let hash_c = ContentHash::of(b"a different string");
+
+        assert_eq!(
+            hash_a, hash_b,
+            "hashing the same data should be deterministic"
+        );
+        assert_ne!(
+            hash_a, hash_c,
+            "hashing different data should produce different hashes"
+        );
+    }
+}
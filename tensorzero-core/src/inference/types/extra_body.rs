@@ -37,6 +37,13 @@ pub enum ExtraBodyReplacementKind {
         deserialize_with = "deserialize_delete"
     )]
     Delete,
+    /// Moves the value at `move_from` to `pointer`, removing it from `move_from`.
+    /// This lets a config author rename a field in a provider's request body
+    /// (e.g. to work around a vendor's non-standard field naming) without
+    /// needing to know the value ahead of time, unlike `Value`.
+    #[schemars(title = "ExtraBodyReplacementKindMove")]
+    #[serde(rename = "move_from")]
+    Move(String),
 }
 
 /// In relay mode, we perform special handling of extra_body options:
@@ -69,6 +76,10 @@ pub fn prepare_relay_extra_body(extra_body: &FullExtraBodyConfig) -> UnfilteredI
                         pointer: replacement.pointer.clone(),
                         delete: (),
                     },
+                    ExtraBodyReplacementKind::Move(move_from) => ExtraBody::AlwaysMove {
+                        pointer: replacement.pointer.clone(),
+                        move_from: move_from.clone(),
+                    },
                 })
                 .collect::<Vec<_>>()
         })
@@ -109,7 +120,8 @@ pub fn prepare_relay_extra_body(extra_body: &FullExtraBodyConfig) -> UnfilteredI
                 | ExtraBody::Provider { .. }
                 | ExtraBody::ProviderDelete { .. }
                 | ExtraBody::Always { .. }
-                | ExtraBody::AlwaysDelete { .. } => replacement.clone(),
+                | ExtraBody::AlwaysDelete { .. }
+                | ExtraBody::AlwaysMove { .. } => replacement.clone(),
             }),
     );
     UnfilteredInferenceExtraBody {
@@ -340,6 +352,13 @@ pub mod dynamic {
             /// Set to true to remove the field from the model provider request's body
             delete: (),
         },
+        #[schemars(title = "AlwaysExtraBodyMove")]
+        AlwaysMove {
+            /// A JSON Pointer to the field to move the value to (e.g. `/enable_agi`)
+            pointer: String,
+            /// A JSON Pointer to the field to move the value from, removing it from that location
+            move_from: String,
+        },
     }
 
     impl ExtraBody {
@@ -354,7 +373,9 @@ pub mod dynamic {
                     variant_name: v, ..
                 } => v == variant_name,
                 ExtraBody::ModelProvider { .. } | ExtraBody::ModelProviderDelete { .. } => true,
-                ExtraBody::Always { .. } | ExtraBody::AlwaysDelete { .. } => true,
+                ExtraBody::Always { .. }
+                | ExtraBody::AlwaysDelete { .. }
+                | ExtraBody::AlwaysMove { .. } => true,
             }
         }
     }
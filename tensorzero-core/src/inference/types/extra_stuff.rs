@@ -62,7 +62,9 @@ pub async fn validate_inference_filters(
                     .await?;
                 }
             }
-            DynamicExtraBody::Always { .. } | DynamicExtraBody::AlwaysDelete { .. } => {
+            DynamicExtraBody::Always { .. }
+            | DynamicExtraBody::AlwaysDelete { .. }
+            | DynamicExtraBody::AlwaysMove { .. } => {
                 // Always variant has no filter to validate
             }
         }
@@ -270,10 +272,12 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
                     },
                 )]),
                 timeouts: Default::default(),
                 skip_relay: false,
+                hedge: None,
             },
         )]);
 
@@ -84,6 +84,7 @@ pub use tensorzero_types::{Input, InputMessage, InputMessageContent, TextKind, T
 use uuid::Uuid;
 
 use crate::cache::{CacheData, NonStreamingCacheData};
+use crate::config::Config;
 use crate::config::ObjectStoreInfo;
 use crate::config::snapshot::SnapshotHash;
 use crate::endpoints::inference::{InferenceDatabaseInsertMetadata, InferenceParams};
@@ -114,6 +115,7 @@ use crate::variant::{InferenceConfig, JsonMode};
 
 pub mod batch;
 pub mod chat_completion_inference_params;
+pub mod content_store;
 pub mod extra_body;
 pub mod extra_headers;
 pub mod extra_stuff;
@@ -1574,6 +1576,11 @@ pub struct StoredModelInference {
     pub cached: bool,
     pub finish_reason: Option<FinishReason>,
     pub snapshot_hash: Option<SnapshotHash>,
+    /// The cost of this model inference in USD, computed from the model provider's
+    /// `pricing` config (see [`crate::model::ModelPricing`]) and this inference's usage.
+    /// `None` if the provider has no `pricing` configured.
+    #[serde(default)]
+    pub cost_usd: Option<f64>,
     /// Materialized column in ClickHouse - only present when reading from the database.
     /// Ignored during insert (computed from `UUIDv7ToDateTime(id)`).
     #[serde(default, skip_serializing)]
@@ -1697,11 +1704,18 @@ impl ModelInferenceResponseWithMetadata {
 impl StoredModelInference {
     /// Create a new StoredModelInference from a runtime ModelInferenceResponseWithMetadata.
     /// Used when inserting into ClickHouse.
+    ///
+    /// `config` is used to look up the model provider's `pricing` (if any) to compute
+    /// `cost_usd`. Lookup failures (e.g. a shorthand model that no longer resolves) are
+    /// logged and treated as "no pricing configured", since a missing cost shouldn't block
+    /// writing the rest of the inference record.
     pub async fn new(
         result: ModelInferenceResponseWithMetadata,
         inference_id: Uuid,
         snapshot_hash: SnapshotHash,
+        config: &Config,
     ) -> Result<Self, Error> {
+        let cost_usd = Self::compute_cost_usd(config, &result).await;
         let (latency_ms, ttft_ms) = match result.latency {
             Latency::Streaming {
                 ttft,
@@ -1760,10 +1774,38 @@ impl StoredModelInference {
             finish_reason: result.finish_reason,
             input_messages: stored_input_messages,
             snapshot_hash: Some(snapshot_hash),
+            cost_usd,
             // timestamp is a materialized column, not set during insert
             timestamp: None,
         })
     }
+
+    /// Looks up the model provider that served `result` and, if it has `pricing` configured,
+    /// computes the cost of `result.usage` in USD.
+    async fn compute_cost_usd(
+        config: &Config,
+        result: &ModelInferenceResponseWithMetadata,
+    ) -> Option<f64> {
+        let model_config = match config.models.get(&result.model_name, None).await {
+            Ok(Some(model_config)) => model_config,
+            Ok(None) => return None,
+            Err(e) => {
+                ErrorDetails::Serialization {
+                    message: format!(
+                        "Failed to look up model '{}' for cost computation: {e:?}",
+                        result.model_name
+                    ),
+                }
+                .log();
+                return None;
+            }
+        };
+        let pricing = model_config
+            .providers
+            .get(&result.model_provider_name)?
+            .pricing?;
+        Some(pricing.cost_usd(&result.usage))
+    }
 }
 
 pub struct ProviderInferenceResponseArgs {
@@ -1813,6 +1855,7 @@ impl InferenceResult {
     pub async fn get_serialized_model_inferences(
         &self,
         snapshot_hash: SnapshotHash,
+        config: &Config,
     ) -> Vec<serde_json::Value> {
         let model_inference_responses = self.model_inference_results();
         let inference_id = match self {
@@ -1823,7 +1866,7 @@ impl InferenceResult {
             let snapshot_hash = snapshot_hash.clone();
             async move {
                 let model_inference =
-                    StoredModelInference::new(r.clone(), inference_id, snapshot_hash).await;
+                    StoredModelInference::new(r.clone(), inference_id, snapshot_hash, config).await;
                 let model_inference = match model_inference {
                     Ok(model_inference) => model_inference,
                     Err(e) => {
@@ -1849,6 +1892,21 @@ impl InferenceResult {
         .await
     }
 
+    /// Sums the cost (in USD) of every model inference result, using each model provider's
+    /// `pricing` config. Model inference results whose provider has no `pricing` configured
+    /// don't contribute to the total.
+    pub async fn total_cost_usd(&self, config: &Config) -> f64 {
+        join_all(
+            self.model_inference_results()
+                .iter()
+                .map(|r| StoredModelInference::compute_cost_usd(config, r)),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .sum()
+    }
+
     /// Aggregates the usage of all model inference results, considering cached results.
     /// If any of the values are None, the total usage is considered as None (via `sum_usage_strict`).
     pub fn usage_considering_cached(&self) -> Usage {
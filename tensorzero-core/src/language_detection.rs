@@ -0,0 +1,68 @@
+//! Lightweight automatic language detection for inference inputs/outputs.
+//!
+//! Detected languages are attached to inferences as tags (see
+//! [`INPUT_LANGUAGE_TAG`]/[`OUTPUT_LANGUAGE_TAG`]) rather than as dedicated ClickHouse columns,
+//! so they can be filtered and segmented using the tag-based filtering that inference listing,
+//! evaluations, and analytics already support - multilingual deployments can see which
+//! languages a variant underperforms on by filtering/grouping on these tags without any new
+//! stats infrastructure.
+
+use whatlang::detect;
+
+/// Tag key holding the detected language (ISO 639-3 code, e.g. `"eng"`, `"jpn"`) of the text
+/// content of an inference's input.
+pub const INPUT_LANGUAGE_TAG: &str = "tensorzero::input_language";
+/// Tag key holding the detected language of an inference's output.
+pub const OUTPUT_LANGUAGE_TAG: &str = "tensorzero::output_language";
+
+/// Minimum text length (in bytes) below which detection is skipped, since `whatlang` is
+/// unreliable on very short strings.
+const MIN_DETECTABLE_LEN: usize = 10;
+
+/// Detects the dominant language of `text` and returns its ISO 639-3 code (e.g. `"eng"`,
+/// `"jpn"`), or `None` if the text is too short or no language could be reliably detected.
+pub fn detect_language(text: &str) -> Option<String> {
+    if text.trim().len() < MIN_DETECTABLE_LEN {
+        return None;
+    }
+    let info = detect(text)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    Some(info.lang().code().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language_english() {
+        let result = detect_language(
+            "The quick brown fox jumps over the lazy dog near the riverbank at dawn.",
+        );
+        assert_eq!(
+            result.as_deref(),
+            Some("eng"),
+            "a clear, long English sentence should be reliably detected as English"
+        );
+    }
+
+    #[test]
+    fn test_detect_language_too_short() {
+        assert_eq!(
+            detect_language("hi"),
+            None,
+            "text shorter than the minimum detectable length should not be detected"
+        );
+    }
+
+    #[test]
+    fn test_detect_language_empty() {
+        assert_eq!(
+            detect_language(""),
+            None,
+            "empty text should not be detected"
+        );
+    }
+}
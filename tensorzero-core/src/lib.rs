@@ -5,11 +5,13 @@
 pub mod cache;
 pub mod client; // Rust client for TensorZero
 pub mod config; // TensorZero config file
+pub mod dataset_sync; // connectors for syncing datapoints from external sources
 pub mod db;
 pub mod embeddings; // embedding inference
 pub mod endpoints; // API endpoints
 pub mod error; // error handling
 pub mod evaluations; // evaluation
+pub mod events; // in-process gateway activity event bus, consumed by the SSE events endpoint
 pub mod experimentation;
 pub mod feature_flags;
 pub mod function; // types and methods for working with TensorZero functions
@@ -17,15 +19,19 @@ pub mod howdy;
 pub mod http;
 pub mod inference; // model inference
 pub mod jsonschema_util; // utilities for working with JSON schemas
+pub mod language_detection; // automatic language detection for inference inputs/outputs
 mod minijinja_util; // utilities for working with MiniJinja templates
 pub mod model; // types and methods for working with TensorZero-supported models
+pub mod model_capabilities; // per-provider inference-time feature support, checked at config-load time
 pub mod model_table;
 pub mod observability; // utilities for observability (logs, metrics, etc.)
 pub mod optimization;
 pub mod providers; // providers for the inference and / or optimization services TensorZero integrates
 pub mod rate_limiting; // utilities for rate limiting
 pub mod relay;
+pub mod schema_registry; // versioned registry for function input/output JSON Schemas
 pub mod serde_util; // utilities for working with serde
+pub mod spend_reconciliation; // reconciling provider-reported spend against internally computed cost
 pub mod statistics_util; // statistical utilities (confidence intervals, etc.)
 pub mod stored_inference; // types and methods for working with stored inferences
 #[cfg(any(test, feature = "e2e_tests"))]
@@ -22,9 +22,10 @@ use crate::cache::{
     StreamingCacheData, cache_lookup, cache_lookup_streaming, start_cache_write,
     start_cache_write_streaming,
 };
+use crate::config::data_residency::DataResidencyPolicy;
 use crate::config::with_skip_credential_validation;
 use crate::config::{
-    OtlpConfig, OtlpTracesFormat, TimeoutsConfig, provider_types::ProviderTypesConfig,
+    HedgeConfig, OtlpConfig, OtlpTracesFormat, TimeoutsConfig, provider_types::ProviderTypesConfig,
 };
 use crate::endpoints::inference::InferenceClients;
 use crate::http::TensorzeroHttpClient;
@@ -50,9 +51,9 @@ use crate::inference::types::{
 };
 use crate::model_table::{
     AnthropicKind, AzureKind, BaseModelTable, DeepSeekKind, FireworksKind,
-    GoogleAIStudioGeminiKind, GroqKind, HyperbolicKind, KIEKind, MistralKind, OpenAIKind, OpenRouterKind,
-    ProviderTypeDefaultCredentials, SGLangKind, ShorthandModelConfig, TGIKind, TogetherKind,
-    VLLMKind, XAIKind,
+    GoogleAIStudioGeminiKind, GroqKind, HyperbolicKind, KIEKind, MistralKind, OllamaKind,
+    OpenAICompatibleKind, OpenAIKind, OpenRouterKind, ProviderTypeDefaultCredentials, SGLangKind,
+    ShorthandModelConfig, TGIKind, TogetherKind, VLLMKind, XAIKind,
 };
 use crate::providers::helpers::peek_first_chunk;
 use crate::providers::hyperbolic::HyperbolicProvider;
@@ -75,7 +76,8 @@ use crate::providers::{
     anthropic::AnthropicProvider, aws_bedrock::AWSBedrockProvider, azure::AzureProvider,
     deepseek::DeepSeekProvider, fireworks::FireworksProvider,
     gcp_vertex_anthropic::GCPVertexAnthropicProvider, gcp_vertex_gemini::GCPVertexGeminiProvider,
-    groq::GroqProvider, kie::KIEProvider, mistral::MistralProvider, openai::OpenAIProvider,
+    groq::GroqProvider, kie::KIEProvider, mistral::MistralProvider, ollama::OllamaProvider,
+    openai::OpenAIProvider, openai_compatible::OpenAICompatibleProvider,
     openrouter::OpenRouterProvider, together::TogetherProvider, vllm::VLLMProvider,
     xai::XAIProvider,
 };
@@ -88,6 +90,7 @@ pub struct ModelConfig {
     pub providers: HashMap<Arc<str>, ModelProvider>, // provider name => provider config
     pub timeouts: TimeoutsConfig,
     pub skip_relay: bool,
+    pub hedge: Option<HedgeConfig>,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -101,6 +104,8 @@ pub struct UninitializedModelConfig {
     pub timeouts: TimeoutsConfig,
     #[serde(default)]
     pub skip_relay: Option<bool>,
+    #[serde(default)]
+    pub hedge: Option<HedgeConfig>,
 }
 
 impl UninitializedModelConfig {
@@ -143,6 +148,8 @@ impl UninitializedModelConfig {
                         extra_headers: provider.extra_headers,
                         timeouts: provider.timeouts,
                         discard_unknown_chunks: provider.discard_unknown_chunks,
+                        pricing: provider.pricing,
+                        region: provider.region,
                     },
                 ))
             }
@@ -155,6 +162,7 @@ impl UninitializedModelConfig {
             providers,
             timeouts: self.timeouts,
             skip_relay,
+            hedge: self.hedge,
         })
     }
 }
@@ -246,6 +254,37 @@ impl ModelConfig {
         false
     }
 
+    /// The subset of `self.routing` compliant with `data_residency` (if any), in routing order.
+    ///
+    /// Returns an error if `data_residency` is set and no provider in `self.routing` has a
+    /// `region` allowed by the policy, rather than silently falling through to a non-compliant
+    /// provider.
+    fn compliant_routing(
+        &self,
+        model_name: &str,
+        data_residency: Option<&DataResidencyPolicy>,
+    ) -> Result<Vec<&Arc<str>>, Error> {
+        let Some(policy) = data_residency else {
+            return Ok(self.routing.iter().collect());
+        };
+        let compliant: Vec<&Arc<str>> = self
+            .routing
+            .iter()
+            .filter(|provider_name| {
+                self.providers
+                    .get(*provider_name)
+                    .is_some_and(|provider| policy.allows(provider.region.as_deref()))
+            })
+            .collect();
+        if compliant.is_empty() {
+            return Err(Error::new(ErrorDetails::DataResidencyViolation {
+                model_name: model_name.to_string(),
+                allowed_regions: policy.allowed_regions.clone(),
+            }));
+        }
+        Ok(compliant)
+    }
+
     fn filter_content_blocks<'a>(
         request: &'a ModelInferenceRequest<'a>,
         model_name: &str,
@@ -347,6 +386,7 @@ impl ModelConfig {
         if clients.cache_options.enabled.read() {
             let cache_lookup = cache_lookup(
                 &clients.clickhouse_connection_info,
+                &*clients.hot_cache,
                 model_provider_request,
                 clients.cache_options.max_age_s,
             )
@@ -390,6 +430,7 @@ impl ModelConfig {
         if clients.cache_options.enabled.read() {
             let cache_lookup = cache_lookup_streaming(
                 &clients.clickhouse_connection_info,
+                &*clients.hot_cache,
                 model_provider_request,
                 clients.cache_options.max_age_s,
             )
@@ -446,12 +487,156 @@ impl ModelConfig {
         })
     }
 
+    /// Attempts a single non-streaming request against `provider_name`, including the
+    /// provider-level timeout and the resulting cache write. Shared between the normal
+    /// sequential fallback loop and [`Self::race_providers`]' hedged request.
+    async fn try_provider_once<'request>(
+        &self,
+        provider_name: &'request Arc<str>,
+        request: &'request ModelInferenceRequest<'request>,
+        clients: &InferenceClients,
+        model_name: &'request str,
+    ) -> Result<ModelInferenceResponse, Error> {
+        let provider = self.providers.get(provider_name).ok_or_else(|| {
+            Error::new(ErrorDetails::ProviderNotFound {
+                provider_name: provider_name.to_string(),
+            })
+        })?;
+        let request = Self::filter_content_blocks(request, model_name, provider);
+        let model_provider_request = ModelProviderRequest {
+            request: &request,
+            model_name,
+            provider_name,
+            otlp_config: &clients.otlp_config,
+            model_inference_id: Uuid::now_v7(),
+        };
+        let cache_key = model_provider_request.get_cache_key()?;
+
+        let response_fut =
+            self.non_streaming_provider_request(model_provider_request, provider, clients);
+        let response = if let Some(timeout) = provider.non_streaming_total_timeout() {
+            tokio::time::timeout(timeout, response_fut)
+                .await
+                // Convert the outer `Elapsed` error into a TensorZero error,
+                // so that it can be handled by the `match response` block below
+                .unwrap_or_else(|_: Elapsed| {
+                    Err(Error::new(ErrorDetails::ModelProviderTimeout {
+                        provider_name: provider_name.to_string(),
+                        timeout,
+                        streaming: false,
+                    }))
+                })
+        } else {
+            response_fut.await
+        }?;
+
+        // Perform the cache write outside of the `non_streaming_total_timeout` timeout future,
+        // (in case we ever add a blocking cache write option)
+        if !response.cached && clients.cache_options.enabled.write() {
+            let _ = start_cache_write(
+                &clients.clickhouse_connection_info,
+                clients.hot_cache.clone(),
+                cache_key,
+                CacheData {
+                    output: NonStreamingCacheData {
+                        blocks: response.output.clone(),
+                    },
+                    raw_request: response.raw_request.clone(),
+                    raw_response: response.raw_response.clone(),
+                    input_tokens: response.usage.input_tokens,
+                    output_tokens: response.usage.output_tokens,
+                    finish_reason: response.finish_reason,
+                },
+                CacheValidationInfo {
+                    tool_config: request
+                        .tool_config
+                        .clone()
+                        .map(std::borrow::Cow::into_owned),
+                },
+            );
+        }
+
+        Ok(response)
+    }
+
+    /// Races `primary_name` against `secondary_name`: `secondary_name` is only fired if
+    /// `primary_name` hasn't responded within `delay_ms`. Whichever responds first is returned;
+    /// the other request is dropped (a best-effort cancellation - e.g. the underlying HTTP
+    /// connection is closed, but a provider that already started generating isn't told to stop).
+    ///
+    /// Returns the errors from both providers if both fail.
+    async fn race_providers<'request>(
+        &self,
+        primary_name: &'request Arc<str>,
+        secondary_name: &'request Arc<str>,
+        delay_ms: u64,
+        request: &'request ModelInferenceRequest<'request>,
+        clients: &InferenceClients,
+        model_name: &'request str,
+    ) -> Result<ModelInferenceResponse, Vec<(String, Error)>> {
+        let primary_fut = self.try_provider_once(primary_name, request, clients, model_name);
+        let secondary_fut = async {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            self.try_provider_once(secondary_name, request, clients, model_name)
+                .await
+        };
+        tokio::pin!(primary_fut);
+        tokio::pin!(secondary_fut);
+
+        let mut primary_error = None;
+        let mut secondary_error = None;
+        loop {
+            tokio::select! {
+                biased;
+                result = &mut primary_fut, if primary_error.is_none() => {
+                    match result {
+                        Ok(response) => {
+                            tracing::info!(
+                                hedge.winner = %primary_name,
+                                hedge.fired_secondary = secondary_error.is_some(),
+                                "hedge race won by primary provider"
+                            );
+                            return Ok(response);
+                        }
+                        Err(error) => primary_error = Some(error),
+                    }
+                }
+                result = &mut secondary_fut, if secondary_error.is_none() => {
+                    match result {
+                        Ok(response) => {
+                            tracing::info!(
+                                hedge.winner = %secondary_name,
+                                hedge.fired_secondary = true,
+                                "hedge race won by secondary provider"
+                            );
+                            return Ok(response);
+                        }
+                        Err(error) => secondary_error = Some(error),
+                    }
+                }
+            }
+            if primary_error.is_some() && secondary_error.is_some() {
+                return Err(vec![
+                    (
+                        primary_name.to_string(),
+                        primary_error.expect("checked above"),
+                    ),
+                    (
+                        secondary_name.to_string(),
+                        secondary_error.expect("checked above"),
+                    ),
+                ]);
+            }
+        }
+    }
+
     #[tracing::instrument(skip_all, fields(model_name = model_name, otel.name = "model_inference", stream = false))]
     pub async fn infer<'request>(
         &self,
         request: &'request ModelInferenceRequest<'request>,
         clients: &InferenceClients,
         model_name: &'request str,
+        data_residency: Option<&DataResidencyPolicy>,
     ) -> Result<ModelInferenceResponse, Error> {
         let span = tracing::Span::current();
         clients.otlp_config.mark_openinference_chain_span(&span);
@@ -475,67 +660,44 @@ impl ModelConfig {
                     false,
                 ));
             }
-            for provider_name in &self.routing {
-                let provider = self.providers.get(provider_name).ok_or_else(|| {
-                    Error::new(ErrorDetails::ProviderNotFound {
-                        provider_name: provider_name.to_string(),
-                    })
-                })?;
-                let request = Self::filter_content_blocks(request, model_name, provider);
-                let model_provider_request = ModelProviderRequest {
-                    request: &request,
-                    model_name,
-                    provider_name,
-                    otlp_config: &clients.otlp_config,
-                    model_inference_id: Uuid::now_v7(),
-                };
-                let cache_key = model_provider_request.get_cache_key()?;
+            let routing = self.compliant_routing(model_name, data_residency)?;
+            let mut routing: std::collections::VecDeque<&Arc<str>> = routing.into();
 
-                let response_fut =
-                    self.non_streaming_provider_request(model_provider_request, provider, clients);
-                let response = if let Some(timeout) = provider.non_streaming_total_timeout() {
-                    tokio::time::timeout(timeout, response_fut)
-                        .await
-                        // Convert the outer `Elapsed` error into a TensorZero error,
-                        // so that it can be handled by the `match response` block below
-                        .unwrap_or_else(|_: Elapsed| {
-                            Err(Error::new(ErrorDetails::ModelProviderTimeout {
-                                provider_name: provider_name.to_string(),
-                                timeout,
-                                streaming: false,
-                            }))
-                        })
-                } else {
-                    response_fut.await
-                };
+            // If hedging is configured and there are at least two providers to race, run the
+            // first two providers in `routing` concurrently instead of sequentially, then fall
+            // back to the normal sequential loop for any remaining providers.
+            if let Some(hedge) = &self.hedge
+                && routing.len() >= 2
+            {
+                let primary_name = routing.pop_front().expect("routing.len() >= 2");
+                let secondary_name = routing.pop_front().expect("routing.len() >= 2");
+                match self
+                    .race_providers(
+                        primary_name,
+                        secondary_name,
+                        hedge.delay_ms,
+                        request,
+                        clients,
+                        model_name,
+                    )
+                    .await
+                {
+                    Ok(response) => return Ok(response),
+                    Err(errors) => {
+                        for (provider_name, error) in errors {
+                            provider_errors.insert(provider_name, error);
+                        }
+                    }
+                }
+            }
+
+            for provider_name in routing {
+                let response = self
+                    .try_provider_once(provider_name, request, clients, model_name)
+                    .await;
 
                 match response {
                     Ok(response) => {
-                        // Perform the cache write outside of the `non_streaming_total_timeout` timeout future,
-                        // (in case we ever add a blocking cache write option)
-                        if !response.cached && clients.cache_options.enabled.write() {
-                            let _ = start_cache_write(
-                                &clients.clickhouse_connection_info,
-                                cache_key,
-                                CacheData {
-                                    output: NonStreamingCacheData {
-                                        blocks: response.output.clone(),
-                                    },
-                                    raw_request: response.raw_request.clone(),
-                                    raw_response: response.raw_response.clone(),
-                                    input_tokens: response.usage.input_tokens,
-                                    output_tokens: response.usage.output_tokens,
-                                    finish_reason: response.finish_reason,
-                                },
-                                CacheValidationInfo {
-                                    tool_config: request
-                                        .tool_config
-                                        .clone()
-                                        .map(std::borrow::Cow::into_owned),
-                                },
-                            );
-                        }
-
                         return Ok(response);
                     }
                     Err(error) => {
@@ -575,6 +737,7 @@ impl ModelConfig {
         request: &'request ModelInferenceRequest<'request>,
         clients: &InferenceClients,
         model_name: &'request str,
+        data_residency: Option<&DataResidencyPolicy>,
     ) -> Result<StreamResponseAndMessages, Error> {
         clients
             .otlp_config
@@ -605,7 +768,8 @@ impl ModelConfig {
                     messages: request.messages.clone(),
                 });
             }
-            for provider_name in &self.routing {
+            let routing = self.compliant_routing(model_name, data_residency)?;
+            for provider_name in routing {
                 let provider = self.providers.get(provider_name).ok_or_else(|| {
                     Error::new(ErrorDetails::ProviderNotFound {
                         provider_name: provider_name.to_string(),
@@ -730,6 +894,7 @@ fn wrap_provider_stream(
     let mut stream = stream.into_inner();
     let cache_key = model_request.get_cache_key()?;
     let clickhouse_info = clients.clickhouse_connection_info.clone();
+    let hot_cache = clients.hot_cache.clone();
     let tool_config = model_request
         .request
         .tool_config
@@ -802,6 +967,7 @@ fn wrap_provider_stream(
         if write_to_cache && !errored {
             let _ = start_cache_write_streaming(
                 &clickhouse_info,
+                hot_cache,
                 cache_key,
                 buffer,
                 &raw_request,
@@ -830,6 +996,33 @@ fn wrap_provider_stream(
     )
 }
 
+/// Per-token pricing for a single model provider, used to compute the cost of an inference
+/// from its [`Usage`]. Configured under `models.<name>.providers.<name>.pricing`; a provider
+/// with no `pricing` configured simply has an unknown (untracked) cost.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ModelPricing {
+    /// Cost, in USD, per one million input (prompt) tokens.
+    pub input_price_per_million: f64,
+    /// Cost, in USD, per one million output (completion) tokens.
+    pub output_price_per_million: f64,
+}
+
+impl ModelPricing {
+    /// Computes the cost, in USD, of an inference with the given `usage`.
+    /// Tokens with unknown counts (`None`) don't contribute to the total.
+    pub fn cost_usd(&self, usage: &Usage) -> f64 {
+        let input_cost =
+            f64::from(usage.input_tokens.unwrap_or(0)) * self.input_price_per_million / 1_000_000.0;
+        let output_cost = f64::from(usage.output_tokens.unwrap_or(0))
+            * self.output_price_per_million
+            / 1_000_000.0;
+        input_cost + output_cost
+    }
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "ts-bindings", ts(export))]
@@ -849,6 +1042,17 @@ pub struct UninitializedModelProvider {
     /// know how to correctly merge them.
     #[serde(default)]
     pub discard_unknown_chunks: bool,
+    /// Optional per-token pricing, used to compute and persist the cost of inferences made
+    /// through this provider. See [`ModelPricing`].
+    #[serde(default)]
+    pub pricing: Option<ModelPricing>,
+    /// The geographic region this provider endpoint serves inference from, e.g. `"eu-west-1"`.
+    /// Checked against a function's [`crate::config::data_residency::DataResidencyPolicy`] (if
+    /// any) before routing to this provider, and recorded with each inference for audit. This is
+    /// a free-form, operator-supplied label - unlike `AWSBedrockProvider`'s `region`, it's not
+    /// interpreted by any SDK.
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -864,6 +1068,10 @@ pub struct ModelProvider {
     pub timeouts: TimeoutsConfig,
     /// See `UninitializedModelProvider.discard_unknown_chunks`.
     pub discard_unknown_chunks: bool,
+    /// See `UninitializedModelProvider.pricing`.
+    pub pricing: Option<ModelPricing>,
+    /// See `UninitializedModelProvider.region`.
+    pub region: Option<String>,
 }
 
 impl ModelProvider {
@@ -899,7 +1107,9 @@ impl ModelProvider {
             ProviderConfig::Hyperbolic(_) => "hyperbolic",
             ProviderConfig::KIE(_) => "kie",
             ProviderConfig::Mistral(_) => "mistral",
+            ProviderConfig::Ollama(_) => "ollama",
             ProviderConfig::OpenAI(_) => "openai",
+            ProviderConfig::OpenAICompatible(_) => "openai_compatible",
             ProviderConfig::OpenRouter(_) => "openrouter",
             ProviderConfig::Together(_) => "together",
             ProviderConfig::VLLM(_) => "vllm",
@@ -940,7 +1150,9 @@ impl ModelProvider {
             ProviderConfig::Hyperbolic(provider) => Some(provider.model_name()),
             ProviderConfig::KIE(provider) => Some(provider.model_name()),
             ProviderConfig::Mistral(provider) => Some(provider.model_name()),
+            ProviderConfig::Ollama(provider) => Some(provider.model_name()),
             ProviderConfig::OpenAI(provider) => Some(provider.model_name()),
+            ProviderConfig::OpenAICompatible(provider) => Some(provider.model_name()),
             ProviderConfig::OpenRouter(provider) => Some(provider.model_name()),
             ProviderConfig::Together(provider) => Some(provider.model_name()),
             ProviderConfig::VLLM(provider) => Some(provider.model_name()),
@@ -996,7 +1208,10 @@ pub enum ProviderConfig {
     Hyperbolic(HyperbolicProvider),
     KIE(KIEProvider),
     Mistral(MistralProvider),
+    Ollama(OllamaProvider),
     OpenAI(OpenAIProvider),
+    #[serde(rename = "openai_compatible")]
+    OpenAICompatible(OpenAICompatibleProvider),
     OpenRouter(OpenRouterProvider),
     #[serde(rename = "sglang")]
     SGLang(SGLangProvider),
@@ -1049,7 +1264,11 @@ impl ProviderConfig {
             }
             ProviderConfig::KIE(_) => Cow::Borrowed(crate::providers::kie::PROVIDER_TYPE),
             ProviderConfig::Mistral(_) => Cow::Borrowed(crate::providers::mistral::PROVIDER_TYPE),
+            ProviderConfig::Ollama(_) => Cow::Borrowed(crate::providers::ollama::PROVIDER_TYPE),
             ProviderConfig::OpenAI(_) => Cow::Borrowed(crate::providers::openai::PROVIDER_TYPE),
+            ProviderConfig::OpenAICompatible(_) => {
+                Cow::Borrowed(crate::providers::openai_compatible::PROVIDER_TYPE)
+            }
             ProviderConfig::OpenRouter(_) => {
                 Cow::Borrowed(crate::providers::openrouter::PROVIDER_TYPE)
             }
@@ -1144,6 +1363,10 @@ fn build_aws_provider_config(
     })
 }
 
+fn default_true() -> bool {
+    true
+}
+
 /// Contains all providers which implement `SelfHostedProvider` - these providers
 /// can be used as the target provider hosted by AWS Sagemaker
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
@@ -1172,6 +1395,11 @@ pub enum UninitializedProviderConfig {
         api_key_location: Option<CredentialLocationWithFallback>,
         #[serde(default)]
         beta_structured_outputs: bool,
+        /// If true, mark the system prompt and tool definitions as Anthropic prompt-cache
+        /// breakpoints, so repeated requests that share a long system prompt and/or tool schema
+        /// only pay full input-token price on cache misses.
+        #[serde(default)]
+        prompt_caching: bool,
     },
     #[strum(serialize = "aws_bedrock")]
     #[serde(rename = "aws_bedrock")]
@@ -1274,6 +1502,12 @@ pub enum UninitializedProviderConfig {
         #[cfg_attr(feature = "ts-bindings", ts(type = "string | null"))]
         api_key_location: Option<CredentialLocationWithFallback>,
     },
+    Ollama {
+        model_name: String,
+        api_base: Url,
+        #[cfg_attr(feature = "ts-bindings", ts(type = "string | null"))]
+        api_key_location: Option<CredentialLocationWithFallback>,
+    },
     OpenAI {
         model_name: String,
         api_base: Option<Url>,
@@ -1286,6 +1520,24 @@ pub enum UninitializedProviderConfig {
         #[serde(default)]
         provider_tools: Vec<Value>,
     },
+    #[strum(serialize = "openai_compatible")]
+    #[serde(rename = "openai_compatible")]
+    OpenAICompatible {
+        model_name: String,
+        api_base: Url,
+        #[cfg_attr(feature = "ts-bindings", ts(type = "string | null"))]
+        api_key_location: Option<CredentialLocationWithFallback>,
+        #[serde(default = "default_true")]
+        supports_tools: bool,
+        #[serde(default = "default_true")]
+        supports_json_mode: bool,
+        #[serde(default)]
+        supports_logprobs: bool,
+        #[serde(default)]
+        reasoning_content_field: Option<String>,
+        #[serde(default)]
+        request_signing: Option<crate::providers::request_signing::HmacRequestSigningConfig>,
+    },
     OpenRouter {
         model_name: String,
         #[cfg_attr(feature = "ts-bindings", ts(type = "string | null"))]
@@ -1345,6 +1597,7 @@ impl UninitializedProviderConfig {
                 api_base,
                 api_key_location,
                 beta_structured_outputs,
+                prompt_caching,
             } => ProviderConfig::Anthropic(AnthropicProvider::new(
                 model_name,
                 api_base,
@@ -1355,6 +1608,7 @@ impl UninitializedProviderConfig {
                     )
                     .await?,
                 beta_structured_outputs,
+                prompt_caching,
             )),
             UninitializedProviderConfig::AWSBedrock {
                 model_id,
@@ -1572,6 +1826,20 @@ impl UninitializedProviderConfig {
                     )
                     .await?,
             )),
+            UninitializedProviderConfig::Ollama {
+                model_name,
+                api_base,
+                api_key_location,
+            } => ProviderConfig::Ollama(OllamaProvider::new(
+                model_name,
+                api_base,
+                OllamaKind
+                    .get_defaulted_credential(
+                        api_key_location.as_ref(),
+                        provider_type_default_credentials,
+                    )
+                    .await?,
+            )),
             UninitializedProviderConfig::OpenAI {
                 model_name,
                 api_base,
@@ -1597,6 +1865,33 @@ impl UninitializedProviderConfig {
                     provider_tools,
                 )?)
             }
+            UninitializedProviderConfig::OpenAICompatible {
+                model_name,
+                api_base,
+                api_key_location,
+                supports_tools,
+                supports_json_mode,
+                supports_logprobs,
+                reasoning_content_field,
+                request_signing,
+            } => ProviderConfig::OpenAICompatible(OpenAICompatibleProvider::new(
+                model_name,
+                api_base,
+                OpenAICompatibleKind
+                    .get_defaulted_credential(
+                        api_key_location.as_ref(),
+                        provider_type_default_credentials,
+                    )
+                    .await?,
+                supports_tools,
+                supports_json_mode,
+                supports_logprobs,
+                reasoning_content_field,
+                request_signing
+                    .as_ref()
+                    .map(crate::providers::request_signing::ResolvedRequestSigning::new)
+                    .transpose()?,
+            )),
             UninitializedProviderConfig::OpenRouter {
                 model_name,
                 api_key_location,
@@ -1794,9 +2089,10 @@ impl ModelProvider {
     ) -> Result<ProviderInferenceResponse, Error> {
         let span = Span::current();
         self.apply_otlp_span_fields_input(request.otlp_config, &span);
+        let scope_info = clients.scope_info.with_model_name(request.model_name);
         let ticket_borrow = clients
             .rate_limiting_manager
-            .consume_tickets(&clients.scope_info, request.request)
+            .consume_tickets(&scope_info, request.request)
             .await?;
         let res = match &self.config {
             ProviderConfig::Anthropic(provider) => {
@@ -1859,11 +2155,21 @@ impl ModelProvider {
                     .infer(request, &clients.http_client, &clients.credentials, self)
                     .await
             }
+            ProviderConfig::Ollama(provider) => {
+                provider
+                    .infer(request, &clients.http_client, &clients.credentials, self)
+                    .await
+            }
             ProviderConfig::OpenAI(provider) => {
                 provider
                     .infer(request, &clients.http_client, &clients.credentials, self)
                     .await
             }
+            ProviderConfig::OpenAICompatible(provider) => {
+                provider
+                    .infer(request, &clients.http_client, &clients.credentials, self)
+                    .await
+            }
             ProviderConfig::OpenRouter(provider) => {
                 provider
                     .infer(request, &clients.http_client, &clients.credentials, self)
@@ -1929,9 +2235,10 @@ impl ModelProvider {
         clients: &InferenceClients,
     ) -> Result<StreamAndRawRequest, Error> {
         self.apply_otlp_span_fields_input(request.otlp_config, &Span::current());
+        let scope_info = clients.scope_info.with_model_name(request.model_name);
         let ticket_borrow = clients
             .rate_limiting_manager
-            .consume_tickets(&clients.scope_info, request.request)
+            .consume_tickets(&scope_info, request.request)
             .await?;
         let (stream, raw_request) = match &self.config {
             ProviderConfig::Anthropic(provider) => {
@@ -1994,11 +2301,21 @@ impl ModelProvider {
                     .infer_stream(request, &clients.http_client, &clients.credentials, self)
                     .await
             }
+            ProviderConfig::Ollama(provider) => {
+                provider
+                    .infer_stream(request, &clients.http_client, &clients.credentials, self)
+                    .await
+            }
             ProviderConfig::OpenAI(provider) => {
                 provider
                     .infer_stream(request, &clients.http_client, &clients.credentials, self)
                     .await
             }
+            ProviderConfig::OpenAICompatible(provider) => {
+                provider
+                    .infer_stream(request, &clients.http_client, &clients.credentials, self)
+                    .await
+            }
             ProviderConfig::OpenRouter(provider) => {
                 provider
                     .infer_stream(request, &clients.http_client, &clients.credentials, self)
@@ -2120,11 +2437,21 @@ impl ModelProvider {
                     .start_batch_inference(requests, client, api_keys)
                     .await
             }
+            ProviderConfig::Ollama(provider) => {
+                provider
+                    .start_batch_inference(requests, client, api_keys)
+                    .await
+            }
             ProviderConfig::OpenAI(provider) => {
                 provider
                     .start_batch_inference(requests, client, api_keys)
                     .await
             }
+            ProviderConfig::OpenAICompatible(provider) => {
+                provider
+                    .start_batch_inference(requests, client, api_keys)
+                    .await
+            }
             ProviderConfig::OpenRouter(provider) => {
                 provider
                     .start_batch_inference(requests, client, api_keys)
@@ -2236,11 +2563,21 @@ impl ModelProvider {
                     .poll_batch_inference(batch_request, http_client, dynamic_api_keys)
                     .await
             }
+            ProviderConfig::Ollama(provider) => {
+                provider
+                    .poll_batch_inference(batch_request, http_client, dynamic_api_keys)
+                    .await
+            }
             ProviderConfig::OpenAI(provider) => {
                 provider
                     .poll_batch_inference(batch_request, http_client, dynamic_api_keys)
                     .await
             }
+            ProviderConfig::OpenAICompatible(provider) => {
+                provider
+                    .poll_batch_inference(batch_request, http_client, dynamic_api_keys)
+                    .await
+            }
             ProviderConfig::OpenRouter(provider) => {
                 provider
                     .poll_batch_inference(batch_request, http_client, dynamic_api_keys)
@@ -2300,6 +2637,11 @@ pub enum CredentialLocation {
     Path(String),
     /// Use a provider-specific SDK to determine credentials
     Sdk,
+    /// A secret stored in an external secret manager (AWS Secrets Manager, GCP Secret
+    /// Manager, HashiCorp Vault) - see `crate::config::secret_manager`. Only resolvable
+    /// from a provider's `api_key_location`, not from `load_webhook_credential` or
+    /// `load_tensorzero_relay_credential` (see that module's doc comment).
+    SecretManager(crate::config::secret_manager::SecretManagerLocation),
     None,
 }
 
@@ -2457,6 +2799,17 @@ impl<'de> Deserialize<'de> for CredentialLocation {
             Ok(CredentialLocation::Dynamic(inner.to_string()))
         } else if let Some(inner) = s.strip_prefix("path::") {
             Ok(CredentialLocation::Path(inner.to_string()))
+        } else if let Some(inner) = s.strip_prefix("secret_manager::") {
+            crate::config::secret_manager::SecretManagerLocation::parse_location_str(inner)
+                .map(CredentialLocation::SecretManager)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "Invalid `secret_manager::` credential location: `{s}`. \
+                         Use `secret_manager::vault::PATH#KEY`, \
+                         `secret_manager::aws_secrets_manager::SECRET_ID`, or \
+                         `secret_manager::gcp_secret_manager::SECRET_NAME`."
+                    ))
+                })
         } else if s == "sdk" {
             Ok(CredentialLocation::Sdk)
         } else if s == "none" {
@@ -2464,7 +2817,8 @@ impl<'de> Deserialize<'de> for CredentialLocation {
         } else {
             Err(serde::de::Error::custom(format!(
                 "Invalid credential location format: `{s}`. \
-                 Use `env::VAR_NAME`, `path::FILE_PATH`, `dynamic::KEY_NAME`, or `sdk`."
+                 Use `env::VAR_NAME`, `path::FILE_PATH`, `dynamic::KEY_NAME`, `sdk`, or \
+                 `secret_manager::...`."
             )))
         }
     }
@@ -2481,6 +2835,9 @@ impl Serialize for CredentialLocation {
             CredentialLocation::Dynamic(inner) => format!("dynamic::{inner}"),
             CredentialLocation::Path(inner) => format!("path::{inner}"),
             CredentialLocation::Sdk => "sdk".to_string(),
+            CredentialLocation::SecretManager(location) => {
+                format!("secret_manager::{}", location.to_location_string())
+            }
             CredentialLocation::None => "none".to_string(),
         };
         serializer.serialize_str(&s)
@@ -2564,6 +2921,11 @@ pub enum Credential {
     FileContents(SecretString),
     Dynamic(String),
     Sdk,
+    /// An unresolved secret-manager location - see `CredentialLocation::SecretManager`.
+    /// Resolved into `Credential::Static` by
+    /// `crate::config::secret_manager::resolve_secret_manager_credential` before it
+    /// reaches a provider's `TryFrom<Credential>`.
+    SecretManager(crate::config::secret_manager::SecretManagerLocation),
     None,
     Missing,
     WithFallback {
@@ -2592,6 +2954,37 @@ pub const SHORTHAND_MODEL_PREFIXES: &[&str] = &[
 
 pub type ModelTable = BaseModelTable<ModelConfig>;
 
+impl ModelTable {
+    /// Checks that every provider routed to by the statically-configured model `key` supports
+    /// tool calling, so a variant with a function that has tools configured fails config
+    /// loading instead of silently dropping (or erroring on) tools at inference time.
+    ///
+    /// Shorthand models (e.g. `openai::gpt-4o`) are skipped: their provider isn't resolved
+    /// until request time, so there's nothing to check yet at config load.
+    pub fn validate_tool_support(&self, key: &str) -> Result<(), Error> {
+        let Some(model_config) = self
+            .iter_static_models()
+            .find_map(|(name, config)| (name.as_ref() == key).then_some(config))
+        else {
+            return Ok(());
+        };
+        for provider_name in &model_config.routing {
+            let Some(provider) = model_config.providers.get(provider_name) else {
+                continue;
+            };
+            if !provider.config.capabilities().tools {
+                return Err(ErrorDetails::Config {
+                    message: format!(
+                        "Model `{key}`'s provider `{provider_name}` does not support tool calling, but a function using this model has tools configured"
+                    ),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
 impl ShorthandModelConfig for ModelConfig {
     const SHORTHAND_MODEL_PREFIXES: &[&str] = SHORTHAND_MODEL_PREFIXES;
     const MODEL_TYPE: &str = "Model";
@@ -2608,7 +3001,9 @@ impl ShorthandModelConfig for ModelConfig {
                 AnthropicKind
                     .get_defaulted_credential(None, default_credentials)
                     .await?,
-                // We don't support beta structured output for shorthand models
+                // We don't support beta structured output or prompt caching for shorthand
+                // models - both need explicit provider config, which shorthand models don't have.
+                false,
                 false,
             )),
             "deepseek" => ProviderConfig::DeepSeek(DeepSeekProvider::new(
@@ -2726,10 +3121,13 @@ impl ShorthandModelConfig for ModelConfig {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         })
     }
 
@@ -2839,10 +3237,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let tool_config = ToolCallConfig::with_tools_available(vec![], vec![]);
         let api_keys = InferenceCredentials::default();
@@ -2853,6 +3254,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -2864,6 +3270,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -2891,7 +3298,7 @@ mod tests {
         };
         let model_name = "test model";
         let response = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap();
         let content = response.output;
@@ -2923,13 +3330,16 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let response = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap_err();
         assert_eq!(
@@ -2964,6 +3374,8 @@ mod tests {
             extra_headers: Default::default(),
             timeouts: Default::default(),
             discard_unknown_chunks: false,
+            pricing: None,
+            region: None,
         };
 
         let http_client = TensorzeroHttpClient::new_testing().unwrap();
@@ -2987,6 +3399,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: postgres_mock.clone(),
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3081,6 +3498,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3092,6 +3514,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -3132,6 +3555,8 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
                 (
@@ -3143,16 +3568,19 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
             ]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
 
         let model_name = "test model";
         let response = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap();
         // Ensure that the error for the bad provider was logged, but the request worked nonetheless
@@ -3219,16 +3647,24 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let clients = InferenceClients {
             http_client: TensorzeroHttpClient::new_testing().unwrap(),
             clickhouse_connection_info: ClickHouseConnectionInfo::new_disabled(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::Off,
@@ -3240,6 +3676,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -3256,7 +3693,7 @@ mod tests {
                 },
             messages: _input,
         } = model_config
-            .infer_stream(&request, &clients, "my_model")
+            .infer_stream(&request, &clients, "my_model", None)
             .await
             .unwrap();
         let initial_chunk = stream.next().await.unwrap().unwrap();
@@ -3303,13 +3740,16 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let response = model_config
-            .infer_stream(&request, &clients, "my_model")
+            .infer_stream(&request, &clients, "my_model", None)
             .await;
         assert!(response.is_err());
         let error = match response {
@@ -3382,6 +3822,8 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
                 (
@@ -3393,17 +3835,25 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
             ]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let clients = InferenceClients {
             http_client: TensorzeroHttpClient::new_testing().unwrap(),
             clickhouse_connection_info: ClickHouseConnectionInfo::new_disabled(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::Off,
@@ -3415,6 +3865,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -3431,7 +3882,7 @@ mod tests {
                 },
             messages: _,
         } = model_config
-            .infer_stream(&request, &clients, "my_model")
+            .infer_stream(&request, &clients, "my_model", None)
             .await
             .unwrap();
         let initial_chunk = stream.next().await.unwrap().unwrap();
@@ -3486,10 +3937,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let tool_config = ToolCallConfig::with_tools_available(vec![], vec![]);
         let api_keys = InferenceCredentials::default();
@@ -3500,6 +3954,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3511,6 +3970,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -3537,7 +3997,7 @@ mod tests {
         };
         let model_name = "test model";
         let error = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap_err();
         assert_eq!(
@@ -3564,6 +4024,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3575,13 +4040,14 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
             include_raw_response: false,
         };
         let response = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap_err();
         assert_eq!(
@@ -3617,10 +4083,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let tool_config = ToolCallConfig::with_tools_available(vec![], vec![]);
         let api_keys = InferenceCredentials::default();
@@ -3631,6 +4100,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3642,6 +4116,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -3667,7 +4142,7 @@ mod tests {
             ..Default::default()
         };
         let error = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap_err();
         assert_eq!(
@@ -3694,6 +4169,11 @@ mod tests {
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
             credentials: Arc::new(api_keys.clone()),
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             cache_options: CacheOptions {
                 max_age_s: None,
                 enabled: CacheEnabledMode::WriteOnly,
@@ -3705,13 +4185,14 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
             include_raw_response: false,
         };
         let response = model_config
-            .infer(&request, &clients, model_name)
+            .infer(&request, &clients, model_name, None)
             .await
             .unwrap();
         assert_eq!(
@@ -3756,6 +4237,7 @@ mod tests {
                 None,
                 AnthropicCredentials::None,
                 false,
+                false,
             ))
         })
         .await;
@@ -3770,10 +4252,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let provider_types = ProviderTypesConfig::default();
         let model_table: ModelTable = ModelTable::new(
@@ -0,0 +1,62 @@
+//! A registry of which inference-time features each model provider can serve (tool calling,
+//! streaming, JSON mode, vision, batch inference, reasoning), consulted at config-load time so a
+//! variant that requires a feature its model's provider can't serve fails fast with a clear
+//! error, instead of failing (or silently degrading) at inference time.
+
+use serde::Serialize;
+
+use crate::model::ProviderConfig;
+
+/// The set of inference-time features a model provider can serve.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ProviderCapabilities {
+    pub tools: bool,
+    pub streaming: bool,
+    pub json_mode: bool,
+    pub vision: bool,
+    pub batch: bool,
+    pub reasoning: bool,
+}
+
+impl Default for ProviderCapabilities {
+    /// Every provider module in this codebase implements the same chat-completion-shaped
+    /// `InferenceProvider` trait, so we default to supporting the common inference-time
+    /// features, with two exceptions:
+    /// - `batch` defaults to `false`, since batch inference isn't implemented anywhere in the
+    ///   provider layer yet - every `InferenceProvider::start_batch_inference` impl returns
+    ///   `UnsupportedModelProviderForBatchInference`.
+    /// - `ProviderConfig::capabilities` below overrides individual flags for providers that are
+    ///   actually known to lack a feature.
+    fn default() -> Self {
+        Self {
+            tools: true,
+            streaming: true,
+            json_mode: true,
+            vision: true,
+            batch: false,
+            reasoning: true,
+        }
+    }
+}
+
+impl ProviderConfig {
+    /// Returns the set of inference-time features this provider can serve.
+    ///
+    /// `OpenAICompatible` is the only provider with per-instance capability flags: it fronts an
+    /// arbitrary self-hosted endpoint, so the operator declares what it supports
+    /// (`supports_tools`/`supports_json_mode`) rather than us guessing. Every other provider
+    /// here talks to a specific, known API, so its capabilities are a fixed property of the
+    /// provider type.
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        match self {
+            ProviderConfig::OpenAICompatible(provider) => ProviderCapabilities {
+                tools: provider.supports_tools(),
+                json_mode: provider.supports_json_mode(),
+                ..ProviderCapabilities::default()
+            },
+            _ => ProviderCapabilities::default(),
+        }
+    }
+}
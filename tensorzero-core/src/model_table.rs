@@ -28,7 +28,9 @@ use crate::{
         hyperbolic::HyperbolicCredentials,
         kie::KIECredentials,
         mistral::MistralCredentials,
+        ollama::OllamaCredentials,
         openai::OpenAICredentials,
+        openai_compatible::OpenAICompatibleCredentials,
         openrouter::OpenRouterCredentials,
         sglang::SGLangCredentials,
         tgi::TGICredentials,
@@ -74,7 +76,11 @@ pub trait ProviderKind {
     {
         let provider_type = self.get_provider_type();
         if let Some(api_key_location) = api_key_location {
-            return load_credential_with_fallback(api_key_location, provider_type)?.try_into();
+            let credential = load_credential_with_fallback(api_key_location, provider_type)?;
+            let credential =
+                crate::config::secret_manager::resolve_secret_manager_credential(credential)
+                    .await?;
+            return credential.try_into();
         }
 
         Ok(self
@@ -98,7 +104,9 @@ pub enum ProviderType {
     Hyperbolic,
     KIE,
     Mistral,
+    Ollama,
     OpenAI,
+    OpenAICompatible,
     OpenRouter,
     SGLang,
     TGI,
@@ -122,7 +130,9 @@ impl Display for ProviderType {
             ProviderType::Hyperbolic => write!(f, "Hyperbolic"),
             ProviderType::KIE => write!(f, "KIE"),
             ProviderType::Mistral => write!(f, "Mistral"),
+            ProviderType::Ollama => write!(f, "Ollama"),
             ProviderType::OpenAI => write!(f, "OpenAI"),
+            ProviderType::OpenAICompatible => write!(f, "OpenAICompatible"),
             ProviderType::OpenRouter => write!(f, "OpenRouter"),
             ProviderType::SGLang => write!(f, "SGLang"),
             ProviderType::TGI => write!(f, "TGI"),
@@ -380,7 +390,9 @@ pub struct ProviderTypeDefaultCredentials {
     hyperbolic: LazyCredential<HyperbolicCredentials>,
     kie: LazyCredential<KIECredentials>,
     mistral: LazyCredential<MistralCredentials>,
+    ollama: LazyCredential<OllamaCredentials>,
     openai: LazyCredential<OpenAICredentials>,
+    openai_compatible: LazyCredential<OpenAICompatibleCredentials>,
     openrouter: LazyCredential<OpenRouterCredentials>,
     sglang: LazyCredential<SGLangCredentials>,
     tgi: LazyCredential<TGICredentials>,
@@ -443,6 +455,11 @@ impl ProviderTypeDefaultCredentials {
             .defaults
             .api_key_location
             .clone();
+        let openai_compatible_location = provider_types_config
+            .openai_compatible
+            .defaults
+            .api_key_location
+            .clone();
         let openrouter_location = provider_types_config
             .openrouter
             .defaults
@@ -453,6 +470,11 @@ impl ProviderTypeDefaultCredentials {
             .defaults
             .api_key_location
             .clone();
+        let ollama_location = provider_types_config
+            .ollama
+            .defaults
+            .api_key_location
+            .clone();
         let tgi_location = provider_types_config.tgi.defaults.api_key_location.clone();
         let together_location = provider_types_config
             .together
@@ -513,9 +535,19 @@ impl ProviderTypeDefaultCredentials {
             mistral: LazyCredential::new(move || {
                 load_credential_with_fallback(&mistral_location, ProviderType::Mistral)?.try_into()
             }),
+            ollama: LazyCredential::new(move || {
+                load_credential_with_fallback(&ollama_location, ProviderType::Ollama)?.try_into()
+            }),
             openai: LazyCredential::new(move || {
                 load_credential_with_fallback(&openai_location, ProviderType::OpenAI)?.try_into()
             }),
+            openai_compatible: LazyCredential::new(move || {
+                load_credential_with_fallback(
+                    &openai_compatible_location,
+                    ProviderType::OpenAICompatible,
+                )?
+                .try_into()
+            }),
             openrouter: LazyCredential::new(move || {
                 load_credential_with_fallback(&openrouter_location, ProviderType::OpenRouter)?
                     .try_into()
@@ -547,17 +579,26 @@ async fn make_gcp_credentials_with_fallback(
     // Build default credential
     let default_cred = match location.default_location() {
         CredentialLocation::Sdk => make_gcp_sdk_credentials(provider_type).await?,
-        loc => build_gcp_non_sdk_credentials(load_credential(loc, provider_type)?, &provider_type)?,
+        loc => {
+            let credential = load_credential(loc, provider_type)?;
+            let credential =
+                crate::config::secret_manager::resolve_secret_manager_credential(credential)
+                    .await?;
+            build_gcp_non_sdk_credentials(credential, &provider_type)?
+        }
     };
 
     // If fallback location is specified, construct a WithFallback credential
     if let Some(fallback_location) = location.fallback_location() {
         let fallback_cred = match fallback_location {
             CredentialLocation::Sdk => make_gcp_sdk_credentials(provider_type).await?,
-            fallback_loc => build_gcp_non_sdk_credentials(
-                load_credential(fallback_loc, provider_type)?,
-                &provider_type,
-            )?,
+            fallback_loc => {
+                let credential = load_credential(fallback_loc, provider_type)?;
+                let credential =
+                    crate::config::secret_manager::resolve_secret_manager_credential(credential)
+                        .await?;
+                build_gcp_non_sdk_credentials(credential, &provider_type)?
+            }
         };
         Ok(GCPVertexCredentials::WithFallback {
             default: Box::new(default_cred),
@@ -672,6 +713,9 @@ fn load_credential(
         },
         CredentialLocation::Dynamic(key_name) => Ok(Credential::Dynamic(key_name.clone())),
         CredentialLocation::Sdk => Ok(Credential::Sdk),
+        CredentialLocation::SecretManager(location) => {
+            Ok(Credential::SecretManager(location.clone()))
+        }
         CredentialLocation::None => Ok(Credential::None),
     }
 }
@@ -682,6 +726,17 @@ pub fn load_tensorzero_relay_credential(
     load_credential_with_fallback(location_with_fallback, "tensorzero::relay")
 }
 
+pub fn load_webhook_credential(location: &CredentialLocation) -> Result<Credential, Error> {
+    load_credential(location, "tensorzero::webhook")
+}
+
+/// Loads the shared secret used by `providers::request_signing` to sign outbound
+/// requests. Synchronous, like `load_webhook_credential` above: `CredentialLocation::SecretManager`
+/// isn't resolved here yet (see `config::secret_manager`'s module doc).
+pub fn load_request_signing_credential(location: &CredentialLocation) -> Result<Credential, Error> {
+    load_credential(location, "tensorzero::request_signing")
+}
+
 /// Load credential with fallback support
 /// Constructs a WithFallback credential that will be resolved at inference time
 fn load_credential_with_fallback(
@@ -761,6 +816,22 @@ impl ProviderKind for OpenAIKind {
     }
 }
 
+pub struct OpenAICompatibleKind;
+
+impl ProviderKind for OpenAICompatibleKind {
+    type Credential = OpenAICompatibleCredentials;
+    fn get_provider_type(&self) -> ProviderType {
+        ProviderType::OpenAICompatible
+    }
+
+    async fn get_credential_field(
+        &self,
+        default_credentials: &ProviderTypeDefaultCredentials,
+    ) -> Result<Self::Credential, Error> {
+        default_credentials.openai_compatible.get_cloned()
+    }
+}
+
 pub struct AzureKind;
 
 impl ProviderKind for AzureKind {
@@ -1027,6 +1098,22 @@ impl ProviderKind for TogetherKind {
     }
 }
 
+pub struct OllamaKind;
+
+impl ProviderKind for OllamaKind {
+    type Credential = OllamaCredentials;
+    fn get_provider_type(&self) -> ProviderType {
+        ProviderType::Ollama
+    }
+
+    async fn get_credential_field(
+        &self,
+        default_credentials: &ProviderTypeDefaultCredentials,
+    ) -> Result<Self::Credential, Error> {
+        default_credentials.ollama.get_cloned()
+    }
+}
+
 pub struct VLLMKind;
 
 impl ProviderKind for VLLMKind {
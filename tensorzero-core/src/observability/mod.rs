@@ -60,7 +60,7 @@ use axum::response::{IntoResponse, Response};
 use axum::{Router, middleware};
 use clap::ValueEnum;
 use http::HeaderMap;
-use metrics::{Unit, describe_counter, describe_histogram};
+use metrics::{Unit, describe_counter, describe_gauge, describe_histogram};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
 use moka::sync::Cache;
 use opentelemetry::trace::Status;
@@ -1262,6 +1262,30 @@ pub fn setup_metrics(metrics_config: Option<&MetricsConfig>) -> Result<Prometheu
         "Inferences performed by TensorZero",
     );
 
+    describe_counter!(
+        "tensorzero_function_alias_deprecated_total",
+        Unit::Count,
+        "Calls made through a deprecated function alias (see `function_aliases` in the config)",
+    );
+
+    describe_counter!(
+        "tensorzero_rate_limit_tickets_consumed_total",
+        Unit::Count,
+        "Rate limit tickets consumed, labeled by resource",
+    );
+
+    describe_gauge!(
+        "tensorzero_rate_limit_tickets_remaining",
+        Unit::Count,
+        "Tickets remaining in the most recently checked rate limit bucket, labeled by resource",
+    );
+
+    describe_counter!(
+        "tensorzero_rate_limit_exceeded_total",
+        Unit::Count,
+        "Requests rejected because a rate limit was exceeded, labeled by resource",
+    );
+
     if !buckets.is_empty() {
         describe_histogram!(
             "tensorzero_inference_latency_overhead_seconds",
@@ -1276,5 +1300,11 @@ pub fn setup_metrics(metrics_config: Option<&MetricsConfig>) -> Result<Prometheu
         );
     }
 
+    describe_histogram!(
+        "tensorzero_prompt_compression_ratio",
+        Unit::Count,
+        "Ratio of compressed to original estimated prompt tokens, recorded when a variant's `prompt_compression` option compresses a request. A value of 1.0 means no reduction was needed."
+    );
+
     Ok(metrics_handle)
 }
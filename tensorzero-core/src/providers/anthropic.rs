@@ -131,6 +131,7 @@ pub struct AnthropicProvider {
     #[serde(skip)]
     credentials: AnthropicCredentials,
     beta_structured_outputs: bool,
+    prompt_caching: bool,
 }
 
 impl AnthropicProvider {
@@ -139,6 +140,7 @@ impl AnthropicProvider {
         api_base: Option<Url>,
         credentials: AnthropicCredentials,
         beta_structured_outputs: bool,
+        prompt_caching: bool,
     ) -> Self {
         // Check and normalize api_base if provided
         let normalized_api_base = api_base.map(|url| {
@@ -151,6 +153,7 @@ impl AnthropicProvider {
             api_base: normalized_api_base,
             credentials,
             beta_structured_outputs,
+            prompt_caching,
         }
     }
 
@@ -253,8 +256,13 @@ impl InferenceProvider for AnthropicProvider {
         model_provider: &'a ModelProvider,
     ) -> Result<ProviderInferenceResponse, Error> {
         let request_body = serde_json::to_value(
-            AnthropicRequestBody::new(&self.model_name, request, self.beta_structured_outputs)
-                .await?,
+            AnthropicRequestBody::new(
+                &self.model_name,
+                request,
+                self.beta_structured_outputs,
+                self.prompt_caching,
+            )
+            .await?,
         )
         .map_err(|e| {
             Error::new(ErrorDetails::Serialization {
@@ -361,8 +369,13 @@ impl InferenceProvider for AnthropicProvider {
         model_provider: &'a ModelProvider,
     ) -> Result<(PeekableProviderInferenceResponseStream, String), Error> {
         let request_body = serde_json::to_value(
-            AnthropicRequestBody::new(&self.model_name, request, self.beta_structured_outputs)
-                .await?,
+            AnthropicRequestBody::new(
+                &self.model_name,
+                request,
+                self.beta_structured_outputs,
+                self.prompt_caching,
+            )
+            .await?,
         )
         .map_err(|e| {
             Error::new(ErrorDetails::Serialization {
@@ -579,6 +592,8 @@ pub(super) struct AnthropicTool<'a> {
     pub(super) input_schema: &'a Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) cache_control: Option<AnthropicCacheControl>,
 }
 
 impl<'a> AnthropicTool<'a> {
@@ -589,6 +604,25 @@ impl<'a> AnthropicTool<'a> {
             description: Some(tool.description()),
             input_schema: tool.parameters(),
             strict: beta_structured_outputs.then_some(tool.strict()),
+            // Set by the caller (see `AnthropicRequestBody::new`) on the last tool only, once all
+            // tools are known, since a cache breakpoint caches everything up to and including it.
+            cache_control: None,
+        }
+    }
+}
+
+/// A cache breakpoint marker for Anthropic's prompt caching. We only ever use the `ephemeral`
+/// cache type (Anthropic's default, currently a 5-minute TTL); there's no config knob for the
+/// other cache types since we don't yet have a use case that needs them.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(super) struct AnthropicCacheControl {
+    r#type: &'static str,
+}
+
+impl AnthropicCacheControl {
+    fn ephemeral() -> Self {
+        Self {
+            r#type: "ephemeral",
         }
     }
 }
@@ -806,7 +840,9 @@ impl<'a> AnthropicMessage<'a> {
 pub(super) enum AnthropicSystemBlock<'a> {
     Text {
         text: &'a str,
-        // This also contains cache control and citations but we will ignore these for now.
+        // This also contains citations, which we still ignore for now.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<AnthropicCacheControl>,
     },
 }
 
@@ -869,6 +905,7 @@ impl<'a> AnthropicRequestBody<'a> {
         model_name: &'a str,
         request: &'a ModelInferenceRequest<'_>,
         beta_structured_outputs: bool,
+        prompt_caching: bool,
     ) -> Result<AnthropicRequestBody<'a>, Error> {
         if request.messages.is_empty() {
             return Err(ErrorDetails::InvalidRequest {
@@ -880,10 +917,15 @@ impl<'a> AnthropicRequestBody<'a> {
             fetch_and_encode_input_files_before_inference: request
                 .fetch_and_encode_input_files_before_inference,
         };
-        // We use the content block form rather than string so people can use
-        // extra_body for cache control.
+        // We use the content block form rather than string so people can use extra_body for
+        // finer-grained cache control (e.g. a different cache type, or caching a multi-block
+        // system prompt at a specific point). When `prompt_caching` is set, we mark the whole
+        // system prompt as a cache breakpoint here, which covers the common case.
         let system = match request.system.as_deref() {
-            Some(text) => Some(vec![AnthropicSystemBlock::Text { text }]),
+            Some(text) => Some(vec![AnthropicSystemBlock::Text {
+                text,
+                cache_control: prompt_caching.then(AnthropicCacheControl::ephemeral),
+            }]),
             None => None,
         };
         let messages: Vec<AnthropicMessage> =
@@ -900,7 +942,7 @@ impl<'a> AnthropicRequestBody<'a> {
         // Workaround for Anthropic API limitation: they don't support explicitly specifying "none"
         // for tool choice. When ToolChoice::None is specified, we don't send any tools in the
         // request payload to achieve the same effect.
-        let tools = match &request.tool_config {
+        let mut tools = match &request.tool_config {
             Some(c) if !matches!(c.tool_choice, ToolChoice::None) => Some(
                 c.strict_tools_available()?
                     .map(|tool| AnthropicTool::new(tool, beta_structured_outputs))
@@ -908,6 +950,13 @@ impl<'a> AnthropicRequestBody<'a> {
             ),
             _ => None,
         };
+        // A cache breakpoint caches everything up to and including it, so marking the last tool
+        // is enough to cache the whole (usually static, and often large) tool schema list.
+        if prompt_caching {
+            if let Some(last_tool) = tools.as_mut().and_then(|tools| tools.last_mut()) {
+                last_tool.cache_control = Some(AnthropicCacheControl::ephemeral());
+            }
+        }
 
         // `tool_choice` should only be set if tools are set and non-empty
         let tool_choice: Option<AnthropicToolChoice> = tools
@@ -1792,6 +1841,7 @@ mod tests {
                 description: Some("test"),
                 input_schema: &parameters,
                 strict: None,
+                cache_control: None,
             }
         );
     }
@@ -1946,7 +1996,7 @@ mod tests {
             ..Default::default()
         };
         let anthropic_request_body =
-            AnthropicRequestBody::new(&model, &inference_request, false).await;
+            AnthropicRequestBody::new(&model, &inference_request, false, false).await;
         let error = anthropic_request_body.unwrap_err();
         let details = error.get_details();
         assert_eq!(
@@ -1980,7 +2030,7 @@ mod tests {
             ..Default::default()
         };
         let anthropic_request_body =
-            AnthropicRequestBody::new(&model, &inference_request, false).await;
+            AnthropicRequestBody::new(&model, &inference_request, false, false).await;
         assert!(anthropic_request_body.is_ok());
         assert_eq!(
             anthropic_request_body.unwrap(),
@@ -2000,7 +2050,8 @@ mod tests {
                 max_tokens: 64_000,
                 stream: Some(false),
                 system: Some(vec![AnthropicSystemBlock::Text {
-                    text: "test_system"
+                    text: "test_system",
+                    cache_control: None,
                 }]),
                 ..Default::default()
             }
@@ -2036,7 +2087,7 @@ mod tests {
             ..Default::default()
         };
         let anthropic_request_body =
-            AnthropicRequestBody::new(&model, &inference_request, false).await;
+            AnthropicRequestBody::new(&model, &inference_request, false, false).await;
         assert!(anthropic_request_body.is_ok());
         assert_eq!(
             anthropic_request_body.unwrap(),
@@ -2065,7 +2116,8 @@ mod tests {
                 max_tokens: 100,
                 stream: Some(true),
                 system: Some(vec![AnthropicSystemBlock::Text {
-                    text: "test_system"
+                    text: "test_system",
+                    cache_control: None,
                 }]),
                 temperature: Some(0.5),
                 ..Default::default()
@@ -2106,7 +2158,7 @@ mod tests {
             ..Default::default()
         };
         let anthropic_request_body =
-            AnthropicRequestBody::new(&model, &inference_request, false).await;
+            AnthropicRequestBody::new(&model, &inference_request, false, false).await;
         assert!(anthropic_request_body.is_ok());
         // Convert messages asynchronously
         let expected_messages = try_join_all(inference_request.messages.iter().map(|m| {
@@ -2166,7 +2218,7 @@ mod tests {
             ..Default::default()
         };
         let anthropic_request_body =
-            AnthropicRequestBody::new(&model, &inference_request, false).await;
+            AnthropicRequestBody::new(&model, &inference_request, false, false).await;
         assert!(anthropic_request_body.is_ok());
         let result = anthropic_request_body.unwrap();
         assert_eq!(result.messages.len(), 3); // Original 2 messages + JSON prefill
@@ -2224,105 +2276,105 @@ mod tests {
         };
 
         let model = "claude-opus-4-1-20250805".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 32_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-opus-4-20250514".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 32_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-sonnet-4-20250514".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-7-sonnet-20250219".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-5-sonnet-20241022".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 8_192);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-5-haiku-20241022".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 8_192);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-opus-4-1".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 32_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-opus-4-0".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 32_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-sonnet-4-0".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-7-sonnet-latest".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-5-sonnet-latest".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 8_192);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-5-haiku-latest".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 8_192);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-haiku-20240307".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 4_096);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-haiku-4-5-20251001".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-sonnet-4-5-20250929".to_string();
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 64_000);
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-3-5-ballad-latest".to_string(); // fake model
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert!(body.is_err());
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
 
         let model = "claude-4-5-haiku-20260101".to_string(); // fake model
-        let body = AnthropicRequestBody::new(&model, &request, false).await;
+        let body = AnthropicRequestBody::new(&model, &request, false, false).await;
         assert!(body.is_err());
-        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false).await;
+        let body = AnthropicRequestBody::new(&model, &request_with_max_tokens, false, false).await;
         assert_eq!(body.unwrap().max_tokens, 100);
     }
 
@@ -3097,6 +3149,7 @@ mod tests {
             Some(custom_url.clone()),
             AnthropicCredentials::None,
             false,
+            false,
         );
 
         assert_eq!(
@@ -3118,6 +3171,7 @@ mod tests {
             None,
             AnthropicCredentials::None,
             false,
+            false,
         );
 
         assert_eq!(
@@ -3241,6 +3295,7 @@ mod tests {
             Some(url_with_messages),
             AnthropicCredentials::None,
             false,
+            false,
         );
 
         // Verify the stored api_base is normalized
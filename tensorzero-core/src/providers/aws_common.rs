@@ -99,6 +99,12 @@ impl AWSEndpointUrl {
                         ),
                     }))
                 }
+                CredentialLocation::SecretManager(_) => Err(Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "`endpoint_url = \"secret_manager::...\"` is not supported for `{provider_type}`. \
+                         Use a static URL, `env::`, `path::`, or `dynamic::` instead."
+                    ),
+                })),
                 CredentialLocation::None => Ok(None),
             },
         }
@@ -192,6 +198,12 @@ impl AWSRegion {
                     ))))
                 }
                 CredentialLocation::Sdk => Ok(Some(AWSRegion::Sdk)),
+                CredentialLocation::SecretManager(_) => Err(Error::new(ErrorDetails::Config {
+                    message: format!(
+                        "`region = \"secret_manager::...\"` is not supported for `{provider_type}`. \
+                         Use a static region, `env::`, `path::`, `dynamic::`, or `sdk` instead."
+                    ),
+                })),
                 CredentialLocation::None => Ok(None),
             },
         }
@@ -398,6 +410,7 @@ fn validate_aws_credential_location(
         }
         CredentialLocation::Path(_)
         | CredentialLocation::PathFromEnv(_)
+        | CredentialLocation::SecretManager(_)
         | CredentialLocation::None => Err(Error::new(ErrorDetails::Config {
             message: format!(
                 "Invalid `{field_name}` for `{provider_type}` provider: \
@@ -1,9 +1,13 @@
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
 
 use futures::{StreamExt, TryStreamExt};
+use http::{HeaderMap, HeaderValue};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use tokio::sync::RwLock;
 use tokio::time::Instant;
 use url::Url;
 
@@ -141,6 +145,10 @@ impl AzureProvider {
 pub enum AzureCredentials {
     Static(SecretString),
     Dynamic(String),
+    /// Azure AD (Entra ID) client credentials. Requests are authenticated with a
+    /// `Authorization: Bearer` token obtained from Azure AD instead of the `api-key` header,
+    /// since Azure OpenAI accepts either. The token is cached and transparently refreshed.
+    Aad(AzureAadCredentials),
     None,
     WithFallback {
         default: Box<AzureCredentials>,
@@ -155,6 +163,9 @@ impl TryFrom<Credential> for AzureCredentials {
         match credentials {
             Credential::Static(key) => Ok(AzureCredentials::Static(key)),
             Credential::Dynamic(key_name) => Ok(AzureCredentials::Dynamic(key_name)),
+            Credential::FileContents(file_content) => Ok(AzureCredentials::Aad(
+                AzureAadCredentials::from_json_str(file_content.expose_secret())?,
+            )),
             Credential::Missing => Ok(AzureCredentials::None),
             Credential::WithFallback { default, fallback } => Ok(AzureCredentials::WithFallback {
                 default: Box::new((*default).try_into()?),
@@ -182,6 +193,9 @@ impl AzureCredentials {
                     })
                 })
             }
+            AzureCredentials::Aad(_) => Err(DelayedError::new(ErrorDetails::AzureCredentials {
+                message: "Azure AD credentials do not use an `api-key` header; call `get_auth_headers` instead".to_string(),
+            })),
             AzureCredentials::None => Err(DelayedError::new(ErrorDetails::ApiKeyMissing {
                 provider_name: PROVIDER_NAME.to_string(),
                 message: "No credentials are set".to_string(),
@@ -201,6 +215,179 @@ impl AzureCredentials {
             }
         }
     }
+
+    /// Builds the auth headers for a request against this credential: `api-key` for
+    /// static/dynamic API keys, or `Authorization: Bearer <token>` for Azure AD.
+    async fn get_auth_headers<'a>(
+        &'a self,
+        http_client: &'a TensorzeroHttpClient,
+        dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<HeaderMap, DelayedError> {
+        match self {
+            AzureCredentials::Static(_) | AzureCredentials::Dynamic(_) => {
+                let api_key = self.get_api_key(dynamic_api_keys)?;
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    "api-key",
+                    HeaderValue::from_str(api_key.expose_secret()).map_err(|e| {
+                        DelayedError::new(ErrorDetails::AzureCredentials {
+                            message: format!("Failed to create Azure `api-key` header: {e}"),
+                        })
+                    })?,
+                );
+                Ok(headers)
+            }
+            AzureCredentials::Aad(creds) => {
+                let token = creds.get_bearer_token(http_client).await?;
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    "Authorization",
+                    HeaderValue::from_str(&format!("Bearer {}", token.expose_secret())).map_err(
+                        |e| {
+                            DelayedError::new(ErrorDetails::AzureCredentials {
+                                message: format!(
+                                    "Failed to create Azure `Authorization` header: {e}"
+                                ),
+                            })
+                        },
+                    )?,
+                );
+                Ok(headers)
+            }
+            AzureCredentials::None => Err(DelayedError::new(ErrorDetails::ApiKeyMissing {
+                provider_name: PROVIDER_NAME.to_string(),
+                message: "No credentials are set".to_string(),
+            })),
+            AzureCredentials::WithFallback { default, fallback } => {
+                // Try default first, fall back to fallback if it fails
+                match Box::pin(default.get_auth_headers(http_client, dynamic_api_keys)).await {
+                    Ok(headers) => Ok(headers),
+                    Err(e) => {
+                        e.log_at_level(
+                            "Using fallback credential, as default credential is unavailable: ",
+                            tracing::Level::WARN,
+                        );
+                        Box::pin(fallback.get_auth_headers(http_client, dynamic_api_keys)).await
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A cached Azure AD access token, along with when it should be refreshed.
+#[derive(Clone)]
+struct CachedAadToken {
+    access_token: SecretString,
+    expires_at: Instant,
+}
+
+/// Refresh the token this long before it actually expires, to avoid using a token that
+/// expires mid-flight.
+const AZURE_AAD_TOKEN_REFRESH_BUFFER: Duration = Duration::from_secs(60);
+
+fn default_azure_aad_scope() -> String {
+    "https://cognitiveservices.azure.com/.default".to_string()
+}
+
+/// Azure AD (Entra ID) client credentials, used to obtain a short-lived access token via the
+/// OAuth 2.0 client credentials flow. See the
+/// [Azure AD docs](https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-client-creds-grant-flow)
+/// for more details.
+#[derive(Clone, Deserialize)]
+pub struct AzureAadCredentials {
+    tenant_id: String,
+    client_id: String,
+    client_secret: SecretString,
+    #[serde(default = "default_azure_aad_scope")]
+    scope: String,
+    #[serde(skip, default = "new_aad_token_cache")]
+    token_cache: Arc<RwLock<Option<CachedAadToken>>>,
+}
+
+fn new_aad_token_cache() -> Arc<RwLock<Option<CachedAadToken>>> {
+    Arc::new(RwLock::new(None))
+}
+
+impl std::fmt::Debug for AzureAadCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AzureAadCredentials")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[redacted]")
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+struct AzureAadTokenResponse {
+    access_token: SecretString,
+    expires_in: u64,
+}
+
+impl AzureAadCredentials {
+    fn from_json_str(json_str: &str) -> Result<Self, Error> {
+        serde_json::from_str(json_str).map_err(|e| {
+            Error::new(ErrorDetails::AzureCredentials {
+                message: format!("Failed to load Azure AD credentials: {e}"),
+            })
+        })
+    }
+
+    /// Returns a cached access token if it's not close to expiring, otherwise fetches
+    /// (and caches) a fresh one from Azure AD.
+    async fn get_bearer_token(
+        &self,
+        http_client: &TensorzeroHttpClient,
+    ) -> Result<SecretString, DelayedError> {
+        if let Some(token) = self.cached_token_if_fresh().await {
+            return Ok(token);
+        }
+        let mut cache = self.token_cache.write().await;
+        // Another task may have refreshed the token while we were waiting for the write lock.
+        if let Some(cached) = &*cache {
+            if cached.expires_at > Instant::now() + AZURE_AAD_TOKEN_REFRESH_BUFFER {
+                return Ok(cached.access_token.clone());
+            }
+        }
+        let token_url = format!(
+            "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
+            self.tenant_id
+        );
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "client_credentials")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("client_secret", self.client_secret.expose_secret())
+            .append_pair("scope", &self.scope)
+            .finish();
+        let response: AzureAadTokenResponse = http_client
+            .post(token_url)
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send_and_parse_json(PROVIDER_TYPE)
+            .await
+            .map_err(|e| {
+                DelayedError::new(ErrorDetails::AzureCredentials {
+                    message: format!("Failed to acquire Azure AD token: {e}"),
+                })
+            })?;
+        *cache = Some(CachedAadToken {
+            access_token: response.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+        });
+        Ok(response.access_token)
+    }
+
+    async fn cached_token_if_fresh(&self) -> Option<SecretString> {
+        let cache = self.token_cache.read().await;
+        let cached = cache.as_ref()?;
+        if cached.expires_at > Instant::now() + AZURE_AAD_TOKEN_REFRESH_BUFFER {
+            Some(cached.access_token.clone())
+        } else {
+            None
+        }
+    }
 }
 
 impl InferenceProvider for AzureProvider {
@@ -229,10 +416,12 @@ impl InferenceProvider for AzureProvider {
         let endpoint = self.endpoint.get_endpoint(api_key)?;
         let request_url = get_azure_chat_url(&endpoint, &self.deployment_id)?;
         let start_time = Instant::now();
-        let api_key = self.credentials.get_api_key(api_key).map_err(|e| e.log())?;
-        let builder = http_client
-            .post(request_url)
-            .header("api-key", api_key.expose_secret());
+        let auth_headers = self
+            .credentials
+            .get_auth_headers(http_client, api_key)
+            .await
+            .map_err(|e| e.log())?;
+        let builder = http_client.post(request_url).headers(auth_headers);
 
         let (res, raw_request) = inject_extra_request_data_and_send(
             PROVIDER_TYPE,
@@ -326,14 +515,13 @@ impl InferenceProvider for AzureProvider {
             })?;
         let endpoint = self.endpoint.get_endpoint(dynamic_api_keys)?;
         let request_url = get_azure_chat_url(&endpoint, &self.deployment_id)?;
-        let api_key = self
+        let auth_headers = self
             .credentials
-            .get_api_key(dynamic_api_keys)
+            .get_auth_headers(http_client, dynamic_api_keys)
+            .await
             .map_err(|e| e.log())?;
         let start_time = Instant::now();
-        let builder = http_client
-            .post(request_url)
-            .header("api-key", api_key.expose_secret());
+        let builder = http_client.post(request_url).headers(auth_headers);
         let (event_source, raw_request) = inject_extra_request_data_and_send_eventsource(
             PROVIDER_TYPE,
             &request.extra_body,
@@ -389,17 +577,16 @@ impl EmbeddingProvider for AzureProvider {
         dynamic_api_keys: &InferenceCredentials,
         model_provider_data: &EmbeddingProviderRequestInfo,
     ) -> Result<EmbeddingProviderResponse, Error> {
-        let api_key = self
+        let auth_headers = self
             .credentials
-            .get_api_key(dynamic_api_keys)
+            .get_auth_headers(client, dynamic_api_keys)
+            .await
             .map_err(|e| e.log())?;
         let endpoint = self.endpoint.get_endpoint(dynamic_api_keys)?;
         let request_url = get_azure_embedding_url(&endpoint, &self.deployment_id)?;
         let request_body = AzureEmbeddingRequest::new(request);
 
-        let request_builder = client
-            .post(request_url)
-            .header("api-key", api_key.expose_secret());
+        let request_builder = client.post(request_url).headers(auth_headers);
         let start_time = Instant::now();
 
         let request_body_value = serde_json::to_value(&request_body).map_err(|e| {
@@ -881,10 +1068,45 @@ impl<'a> TryFrom<AzureResponseWithMetadata<'a>> for ProviderInferenceResponse {
     }
 }
 
+/// Extracts `usage` from the raw response, along with Azure's content-filter annotations
+/// (`prompt_filter_results` and each choice's `content_filter_results`). Those annotations
+/// aren't part of the generic OpenAI-compatible response schema we deserialize into, so they'd
+/// otherwise be silently dropped; we surface them here so they're still available in the stored
+/// raw usage entry.
 fn azure_usage_from_raw_response(raw_response: &str) -> Option<Value> {
-    serde_json::from_str::<Value>(raw_response)
-        .ok()
-        .and_then(|value| value.get("usage").filter(|v| !v.is_null()).cloned())
+    let value = serde_json::from_str::<Value>(raw_response).ok()?;
+    let mut usage = value
+        .get("usage")
+        .filter(|v| !v.is_null())
+        .cloned()
+        .unwrap_or_else(|| json!({}));
+    let usage_object = usage.as_object_mut()?;
+
+    if let Some(prompt_filter_results) = value.get("prompt_filter_results") {
+        usage_object.insert(
+            "prompt_filter_results".to_string(),
+            prompt_filter_results.clone(),
+        );
+    }
+    let content_filter_results: Vec<Value> = value
+        .get("choices")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|choice| choice.get("content_filter_results").cloned())
+        .collect();
+    if !content_filter_results.is_empty() {
+        usage_object.insert(
+            "content_filter_results".to_string(),
+            Value::Array(content_filter_results),
+        );
+    }
+
+    if usage_object.is_empty() {
+        None
+    } else {
+        Some(usage)
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -1099,8 +1321,23 @@ mod tests {
         let creds = AzureCredentials::try_from(generic).unwrap();
         assert!(matches!(creds, AzureCredentials::None));
 
+        // Test FileContents credential (Azure AD client credentials JSON)
+        let aad_json = r#"{"tenant_id": "t", "client_id": "c", "client_secret": "s"}"#;
+        let generic = Credential::FileContents(SecretString::from(aad_json));
+        let creds = AzureCredentials::try_from(generic).unwrap();
+        assert!(matches!(creds, AzureCredentials::Aad(_)));
+
+        // Test invalid FileContents
+        let generic = Credential::FileContents(SecretString::from("not json"));
+        let result = AzureCredentials::try_from(generic);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err().get_details(),
+            ErrorDetails::AzureCredentials { message } if message.contains("Failed to load Azure AD credentials")
+        ));
+
         // Test invalid type
-        let generic = Credential::FileContents(SecretString::from("test"));
+        let generic = Credential::Sdk;
         let result = AzureCredentials::try_from(generic);
         assert!(result.is_err());
         assert!(matches!(
@@ -1109,6 +1346,67 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_azure_aad_credentials_default_scope() {
+        let aad_json = r#"{"tenant_id": "t", "client_id": "c", "client_secret": "s"}"#;
+        let creds = AzureAadCredentials::from_json_str(aad_json).unwrap();
+        assert_eq!(
+            creds.scope, "https://cognitiveservices.azure.com/.default",
+            "expected the default Azure AD scope to be used when none is provided"
+        );
+        assert!(
+            creds.cached_token_if_fresh().await.is_none(),
+            "expected a freshly-parsed credential to have no cached token"
+        );
+    }
+
+    #[test]
+    fn test_azure_usage_from_raw_response_includes_content_filter_results() {
+        let raw_response = json!({
+            "choices": [{
+                "index": 0,
+                "message": {"content": "hi"},
+                "finish_reason": "stop",
+                "content_filter_results": {"hate": {"filtered": false, "severity": "safe"}},
+            }],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1},
+            "prompt_filter_results": [{"prompt_index": 0, "content_filter_results": {}}],
+        })
+        .to_string();
+
+        let usage = azure_usage_from_raw_response(&raw_response)
+            .expect("expected usage to be present when the raw response has a usage field");
+        assert_eq!(
+            usage["prompt_tokens"], 1,
+            "expected the original usage fields to be preserved"
+        );
+        assert!(
+            usage.get("prompt_filter_results").is_some(),
+            "expected prompt_filter_results to be merged into the usage value"
+        );
+        assert_eq!(
+            usage["content_filter_results"].as_array().unwrap().len(),
+            1,
+            "expected one entry in content_filter_results, one per choice"
+        );
+    }
+
+    #[test]
+    fn test_azure_usage_from_raw_response_no_content_filter_results() {
+        let raw_response = json!({
+            "choices": [{"index": 0, "message": {"content": "hi"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1},
+        })
+        .to_string();
+
+        let usage = azure_usage_from_raw_response(&raw_response)
+            .expect("expected usage to be present when the raw response has a usage field");
+        assert!(
+            usage.get("content_filter_results").is_none(),
+            "expected no content_filter_results key when Azure didn't return any"
+        );
+    }
+
     #[tokio::test]
     async fn test_azure_response_with_metadata_try_into() {
         let valid_response = OpenAIResponse {
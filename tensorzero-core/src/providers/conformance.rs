@@ -0,0 +1,139 @@
+//! Reusable property checks for provider implementations.
+//!
+//! Each provider module hand-rolls its own request/response types, but they're all expected to
+//! uphold the same handful of invariants (usage accounting is populated, streaming chunks don't
+//! contradict the non-streaming response, HTTP error statuses map to the right `ErrorDetails`
+//! bucket, and request types round-trip through JSON). Rather than re-deriving those checks in
+//! every provider's `#[cfg(test)] mod tests`, call the helpers below against a recorded or live
+//! response.
+//!
+//! This module doesn't make any network calls itself - it only asserts properties of values a
+//! provider's test module has already produced (e.g. by deserializing a recorded fixture and
+//! running it through the provider's own `TryFrom`/parsing functions).
+
+use std::fmt::Debug;
+
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::{Error, ErrorDetails};
+use crate::inference::types::Usage;
+use crate::inference::types::streams::ProviderInferenceResponseChunk;
+
+/// Asserts that a successful response's [`Usage`] reports at least one token count.
+///
+/// A provider that silently drops usage information (e.g. because it forgot to map a
+/// provider-specific field) breaks cost tracking and rate limiting without ever failing an
+/// inference request, so this needs to be checked explicitly rather than relying on it showing up
+/// as a visible bug.
+pub fn assert_usage_accounting(usage: &Usage) {
+    assert!(
+        usage.input_tokens.is_some() || usage.output_tokens.is_some(),
+        "usage accounting should report at least one of input_tokens/output_tokens for a \
+         successful response, got {usage:?}"
+    );
+}
+
+/// Asserts that a stream of chunks is non-empty and that at most one chunk carries a
+/// `finish_reason` other than the last one seen.
+///
+/// Providers stream `finish_reason` as part of whichever chunk happens to carry it (which isn't
+/// always the last chunk on the wire, since some providers send trailing usage-only chunks), but
+/// it should never appear more than once with conflicting values.
+pub fn assert_streaming_chunk_invariants(chunks: &[ProviderInferenceResponseChunk]) {
+    assert!(
+        !chunks.is_empty(),
+        "a streaming response should produce at least one chunk"
+    );
+    let mut seen_finish_reason = None;
+    for chunk in chunks {
+        if let Some(finish_reason) = chunk.finish_reason {
+            if let Some(seen) = seen_finish_reason {
+                assert_eq!(
+                    seen, finish_reason,
+                    "a streaming response should not report conflicting finish reasons across chunks"
+                );
+            }
+            seen_finish_reason = Some(finish_reason);
+        }
+    }
+}
+
+/// Asserts that a value round-trips through JSON serialization unchanged.
+///
+/// This is meant for provider request types (e.g. `OpenAIRequest`): if a field is silently
+/// dropped or mangled going out to serde_json and back, that's a sign it was set up incorrectly
+/// (e.g. missing a `#[serde(rename)]` or accidentally skipped when empty).
+pub fn assert_json_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_value(value)
+        .unwrap_or_else(|e| panic!("failed to serialize {value:?}: {e}"));
+    let round_tripped: T = serde_json::from_value(json.clone()).unwrap_or_else(|e| {
+        panic!("failed to deserialize {json} back into the original type: {e}")
+    });
+    assert_eq!(
+        value, &round_tripped,
+        "value changed after a JSON round-trip (before: {value:?}, after: {round_tripped:?})"
+    );
+}
+
+/// Which bucket of [`ErrorDetails`] an HTTP status code is expected to map to.
+///
+/// This mirrors the `handle_*_error` convention used by most providers (e.g.
+/// `openai::handle_openai_error`): 4xx errors caused by the request itself (bad auth, bad
+/// arguments, rate limits) become [`ErrorDetails::InferenceClient`], and anything else - most
+/// often a 5xx from the provider - becomes [`ErrorDetails::InferenceServer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpectedErrorBucket {
+    Client,
+    Server,
+}
+
+/// Asserts that `handle_error(status_code, ...)` maps `status_code` to the expected
+/// [`ErrorDetails`] bucket and correctly threads through `provider_type`.
+///
+/// `handle_error` should have the same signature as the `handle_*_error` function most providers
+/// define, e.g. `openai::handle_openai_error`.
+pub fn assert_error_mapping<F>(
+    handle_error: F,
+    status_code: StatusCode,
+    provider_type: &str,
+    expected_bucket: ExpectedErrorBucket,
+) where
+    F: FnOnce(&str, StatusCode, &str, &str, Option<&str>) -> Error,
+{
+    let error = handle_error(
+        "raw request",
+        status_code,
+        "raw response",
+        provider_type,
+        None,
+    );
+    match (error.get_details(), expected_bucket) {
+        (
+            ErrorDetails::InferenceClient {
+                provider_type: actual,
+                ..
+            },
+            ExpectedErrorBucket::Client,
+        )
+        | (
+            ErrorDetails::InferenceServer {
+                provider_type: actual,
+                ..
+            },
+            ExpectedErrorBucket::Server,
+        ) => {
+            assert_eq!(
+                actual, provider_type,
+                "error mapped from status {status_code} should carry provider_type {provider_type:?}"
+            );
+        }
+        (other, expected) => panic!(
+            "status {status_code} should map to the {expected:?} error bucket, got {other:?}"
+        ),
+    }
+}
@@ -219,6 +219,8 @@ pub fn convert_to_optimizer_status(
                 extra_body: None,
                 timeouts: TimeoutsConfig::default(),
                 discard_unknown_chunks: false,
+                pricing: None,
+                region: None,
             };
             OptimizationJobInfo::Completed {
                 output: OptimizerOutput::Model(UninitializedModelConfig {
@@ -226,6 +228,7 @@ pub fn convert_to_optimizer_status(
                     providers: HashMap::from([(model_name.clone().into(), model_provider)]),
                     timeouts: TimeoutsConfig::default(),
                     skip_relay: None,
+                    hedge: None,
                 }),
             }
         }
@@ -7,6 +7,7 @@ use std::{collections::HashMap, pin::Pin};
 use uuid::Uuid;
 
 use crate::{
+    endpoints::inference::InferenceCredentials,
     error::{DisplayOrDebugGateway, Error, ErrorDetails, IMPOSSIBLE_ERROR_MESSAGE},
     http::{TensorZeroEventSource, TensorzeroRequestBuilder, TensorzeroResponseWrapper},
     inference::types::{
@@ -17,6 +18,7 @@ use crate::{
         resolved_input::{FileUrl, LazyFile},
     },
     model::{ModelProviderRequestInfo, fully_qualified_name},
+    providers::request_signing::ResolvedRequestSigning,
 };
 
 pub struct JsonlBatchFileInfo {
@@ -404,6 +406,176 @@ pub async fn inject_extra_request_data_and_send_eventsource_with_headers(
     })
 }
 
+/// Like `inject_extra_request_data_and_send`, but additionally signs the final request
+/// body (after `extra_body` has been applied) and attaches the result as a header when
+/// `request_signing` is configured. See `providers::request_signing`.
+pub async fn inject_extra_request_data_and_send_signed(
+    provider_type: &str,
+    config: &FullExtraBodyConfig,
+    extra_headers_config: &FullExtraHeadersConfig,
+    model_provider_data: impl Into<ModelProviderRequestInfo>,
+    model_name: &str,
+    mut body: serde_json::Value,
+    builder: TensorzeroRequestBuilder<'_>,
+    request_signing: Option<&ResolvedRequestSigning>,
+    dynamic_credentials: &InferenceCredentials,
+) -> Result<(TensorzeroResponseWrapper, String), Error> {
+    let mut headers = inject_extra_request_data(
+        config,
+        extra_headers_config,
+        model_provider_data,
+        model_name,
+        &mut body,
+    )?;
+    let raw_request = body.to_string();
+    if let Some(signing) = request_signing {
+        insert_signature_header(
+            &mut headers,
+            signing,
+            dynamic_credentials,
+            raw_request.as_bytes(),
+        )?;
+    }
+    let response = builder
+        .body(raw_request.clone())
+        .header("content-type", "application/json")
+        .headers(headers)
+        .send()
+        .await
+        .map_err(|e| {
+            let status_code = e.status();
+            let message = if e.is_timeout() {
+                format!(
+                    "Request timed out due to `gateway.global_outbound_http_timeout_ms`. Consider increasing this value in your configuration if you expect inferences to take longer to complete. ({})",
+                    DisplayOrDebugGateway::new(&e)
+                )
+            } else {
+                format!("Error sending request: {}", DisplayOrDebugGateway::new(&e))
+            };
+            Error::new(ErrorDetails::InferenceClient {
+                status_code,
+                message,
+                provider_type: provider_type.to_string(),
+                raw_request: Some(raw_request.clone()),
+                raw_response: None,
+            })
+        })?;
+    Ok((response, raw_request))
+}
+
+/// Like `inject_extra_request_data_and_send_eventsource`, but additionally signs the
+/// final request body, the same way `inject_extra_request_data_and_send_signed` does.
+pub async fn inject_extra_request_data_and_send_eventsource_signed(
+    provider_type: &str,
+    config: &FullExtraBodyConfig,
+    extra_headers_config: &FullExtraHeadersConfig,
+    model_provider_data: impl Into<ModelProviderRequestInfo>,
+    model_name: &str,
+    mut body: serde_json::Value,
+    builder: TensorzeroRequestBuilder<'_>,
+    request_signing: Option<&ResolvedRequestSigning>,
+    dynamic_credentials: &InferenceCredentials,
+) -> Result<(TensorZeroEventSource, String), Error> {
+    let mut headers = inject_extra_request_data(
+        config,
+        extra_headers_config,
+        model_provider_data,
+        model_name,
+        &mut body,
+    )?;
+    let raw_request = body.to_string();
+    if let Some(signing) = request_signing {
+        insert_signature_header(
+            &mut headers,
+            signing,
+            dynamic_credentials,
+            raw_request.as_bytes(),
+        )?;
+    }
+    let event_source = match builder
+        .body(raw_request.clone())
+        .header("content-type", "application/json")
+        .headers(headers)
+        .eventsource_with_headers()
+        .await
+    {
+        Ok((event_source, _headers)) => event_source,
+        Err((e, headers)) => {
+            // Extract status code first (by borrowing), then consume Response to read body
+            let (message, raw_response) = match e {
+                reqwest_eventsource::Error::InvalidStatusCode(status, resp) => {
+                    let body = resp.text().await.ok();
+                    let message = match &body {
+                        Some(b) => {
+                            format!("Error sending request: InvalidStatusCode({status}): {b}")
+                        }
+                        None => format!("Error sending request: InvalidStatusCode({status})"),
+                    };
+                    (message, body)
+                }
+                reqwest_eventsource::Error::InvalidContentType(content_type, resp) => {
+                    let body = resp.text().await.ok();
+                    let message = match &body {
+                        Some(b) => format!(
+                            "Error sending request: InvalidContentType({}): {b}",
+                            content_type.to_str().unwrap_or("<invalid>")
+                        ),
+                        None => format!(
+                            "Error sending request: InvalidContentType({})",
+                            content_type.to_str().unwrap_or("<invalid>")
+                        ),
+                    };
+                    (message, body)
+                }
+                other => {
+                    let is_timeout = matches!(&other, reqwest_eventsource::Error::Transport(e) if e.is_timeout());
+                    let message = if is_timeout {
+                        format!(
+                            "Request timed out due to `gateway.global_outbound_http_timeout_ms`. Consider increasing this value in your configuration if you expect inferences to take longer to complete. ({})",
+                            DisplayOrDebugGateway::new(&other)
+                        )
+                    } else {
+                        format!(
+                            "Error sending request: {}",
+                            DisplayOrDebugGateway::new(other)
+                        )
+                    };
+                    (message, None)
+                }
+            };
+            let _ = headers;
+            return Err(Error::new(ErrorDetails::FatalStreamError {
+                message,
+                provider_type: provider_type.to_string(),
+                raw_request: Some(raw_request),
+                raw_response,
+            }));
+        }
+    };
+    Ok((event_source, raw_request))
+}
+
+fn insert_signature_header(
+    headers: &mut http::HeaderMap,
+    signing: &ResolvedRequestSigning,
+    dynamic_credentials: &InferenceCredentials,
+    body: &[u8],
+) -> Result<(), Error> {
+    let (header_name, signature) = signing.sign(dynamic_credentials, body)?;
+    let header_name = http::HeaderName::from_bytes(header_name.as_bytes()).map_err(|e| {
+        Error::new(ErrorDetails::Config {
+            message: format!("Invalid request signing header name `{header_name}`: {e}"),
+        })
+    })?;
+    let header_value = http::HeaderValue::from_str(&signature).map_err(|e| {
+        Error::new(ErrorDetails::Config {
+            message: format!("Invalid request signing signature value: {e}"),
+        })
+    })?;
+    headers.insert(header_name, header_value);
+    Ok(())
+}
+
 /// A helper method to inject extra_body fields into a request, and
 /// construct the `HeaderMap` for the applicable extra_headers.
 ///
@@ -441,6 +613,9 @@ pub fn inject_extra_request_data(
             ExtraBodyReplacementKind::Delete => {
                 delete_json_pointer(body, &replacement.pointer)?;
             }
+            ExtraBodyReplacementKind::Move(move_from) => {
+                move_json_pointer(body, move_from, &replacement.pointer)?;
+            }
         }
     }
 
@@ -518,6 +693,9 @@ pub fn inject_extra_request_data(
             DynamicExtraBody::AlwaysDelete { pointer, .. } => {
                 delete_json_pointer(body, pointer)?;
             }
+            DynamicExtraBody::AlwaysMove { pointer, move_from } => {
+                move_json_pointer(body, move_from, pointer)?;
+            }
         }
     }
 
@@ -739,6 +917,25 @@ pub fn inject_extra_request_data(
     Ok(headers)
 }
 
+/// Moves the value at `move_from` to `pointer`, removing it from `move_from`.
+/// If `move_from` doesn't point to an existing value, this is a no-op (matching
+/// the "skip and warn" behavior of `delete_json_pointer`), so that a rename
+/// declared for a field a vendor sometimes omits doesn't fail the request.
+fn move_json_pointer(
+    body: &mut serde_json::Value,
+    move_from: &str,
+    pointer: &str,
+) -> Result<(), Error> {
+    let Some(value) = body.pointer(move_from).cloned() else {
+        tracing::warn!(
+            "Skipping move of extra_body pointer `{move_from}` to `{pointer}` - source pointer doesn't exist"
+        );
+        return Ok(());
+    };
+    delete_json_pointer(body, move_from)?;
+    write_json_pointer_with_parent_creation(body, pointer, value)
+}
+
 // Copied from serde_json (MIT-licensed): https://github.com/serde-rs/json/blob/400eaa977f1f0a1c9ad5e35d634ed2226bf1218c/src/value/mod.rs#L259
 // This accepts positive integers, rejecting integers with a leading plus or extra leading zero.
 // We use this to parse integers according to the JSON pointer spec
@@ -1464,6 +1661,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extra_body_move() {
+        let mut body = serde_json::json!({
+            "modelName": "gpt-5",
+            "otherKey": "otherValue",
+        });
+        inject_extra_request_data(
+            &FullExtraBodyConfig {
+                extra_body: Some(ExtraBodyConfig {
+                    data: vec![ExtraBodyReplacement {
+                        pointer: "/model".to_string(),
+                        kind: ExtraBodyReplacementKind::Move("/modelName".to_string()),
+                    }],
+                }),
+                inference_extra_body: FilteredInferenceExtraBody::default(),
+            },
+            &Default::default(),
+            ModelProviderRequestInfo {
+                provider_name: "dummy_provider".into(),
+                extra_body: None,
+                extra_headers: None,
+            },
+            "dummy_model",
+            &mut body,
+        )
+        .unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "otherKey": "otherValue",
+                "model": "gpt-5",
+            }),
+            "expected `modelName` to be renamed to `model`"
+        );
+    }
+
+    #[test]
+    fn test_extra_body_move_missing_source_is_noop() {
+        let mut body = serde_json::json!({
+            "otherKey": "otherValue",
+        });
+        inject_extra_request_data(
+            &FullExtraBodyConfig {
+                extra_body: Some(ExtraBodyConfig {
+                    data: vec![ExtraBodyReplacement {
+                        pointer: "/model".to_string(),
+                        kind: ExtraBodyReplacementKind::Move("/modelName".to_string()),
+                    }],
+                }),
+                inference_extra_body: FilteredInferenceExtraBody::default(),
+            },
+            &Default::default(),
+            ModelProviderRequestInfo {
+                provider_name: "dummy_provider".into(),
+                extra_body: None,
+                extra_headers: None,
+            },
+            "dummy_model",
+            &mut body,
+        )
+        .unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "otherKey": "otherValue",
+            }),
+            "a missing move source should leave the body untouched"
+        );
+    }
+
     #[test]
     fn test_json_pointer_write_simple() {
         let mut obj1 = serde_json::json!({
@@ -8,25 +8,35 @@ use std::time::Duration;
 use tokio::time::Instant;
 use url::Url;
 
-use super::helpers::{convert_stream_error, inject_extra_request_data_and_send, inject_extra_request_data_and_send_eventsource};
+use super::helpers::{
+    convert_stream_error, inject_extra_request_data_and_send,
+    inject_extra_request_data_and_send_eventsource,
+};
 use crate::cache::ModelProviderRequest;
 use crate::endpoints::inference::InferenceCredentials;
 use crate::error::{DelayedError, DisplayOrDebugGateway, Error, ErrorDetails};
 use crate::http::{TensorZeroEventSource, TensorzeroHttpClient};
 use crate::inference::InferenceProvider;
-use crate::inference::types::batch::{BatchRequestRow, PollBatchInferenceResponse, StartBatchProviderInferenceResponse};
-use crate::inference::types::chat_completion_inference_params::{ChatCompletionInferenceParamsV2, warn_inference_parameter_not_supported};
+use crate::inference::types::batch::{
+    BatchRequestRow, PollBatchInferenceResponse, StartBatchProviderInferenceResponse,
+};
+use crate::inference::types::chat_completion_inference_params::{
+    ChatCompletionInferenceParamsV2, warn_inference_parameter_not_supported,
+};
 use crate::inference::types::usage::raw_usage_entries_from_value;
 use crate::inference::types::{
-    ApiType, ContentBlockChunk, ContentBlockOutput, Latency, ModelInferenceRequest, ModelInferenceRequestJsonMode, PeekableProviderInferenceResponseStream,
-    ProviderInferenceResponse, ProviderInferenceResponseArgs, ProviderInferenceResponseChunk, ProviderInferenceResponseStreamInner, TextChunk, ThoughtChunk, Thought,
+    ApiType, ContentBlockChunk, ContentBlockOutput, Latency, ModelInferenceRequest,
+    ModelInferenceRequestJsonMode, PeekableProviderInferenceResponseStream,
+    ProviderInferenceResponse, ProviderInferenceResponseArgs, ProviderInferenceResponseChunk,
+    ProviderInferenceResponseStreamInner, TextChunk, Thought, ThoughtChunk,
 };
 use crate::model::{Credential, ModelProvider};
 use crate::providers::chat_completions::prepare_chat_completion_tools;
 use crate::providers::chat_completions::{ChatCompletionTool, ChatCompletionToolChoice};
 use crate::providers::openai::{
-    OpenAIFinishReason, OpenAIRequestMessage, OpenAIResponseToolCall, OpenAIUsage,
-    StreamOptions, SystemOrDeveloper, handle_openai_error, prepare_system_or_developer_message, tensorzero_to_openai_messages,
+    OpenAIFinishReason, OpenAIRequestMessage, OpenAIResponseToolCall, OpenAIUsage, StreamOptions,
+    SystemOrDeveloper, handle_openai_error, prepare_system_or_developer_message,
+    tensorzero_to_openai_messages,
 };
 use uuid::Uuid;
 
@@ -37,7 +47,6 @@ lazy_static! {
 const PROVIDER_NAME: &str = "KIE";
 pub const PROVIDER_TYPE: &str = "kie";
 
-
 #[derive(Clone, Debug)]
 pub enum KIECredentials {
     Static(SecretString),
@@ -57,12 +66,10 @@ impl TryFrom<Credential> for KIECredentials {
             Credential::Static(key) => Ok(KIECredentials::Static(key)),
             Credential::Dynamic(key_name) => Ok(KIECredentials::Dynamic(key_name)),
             Credential::Missing => Ok(KIECredentials::None),
-            Credential::WithFallback { default, fallback } => {
-                Ok(KIECredentials::WithFallback {
-                    default: Box::new((*default).try_into()?),
-                    fallback: Box::new((*fallback).try_into()?),
-                })
-            }
+            Credential::WithFallback { default, fallback } => Ok(KIECredentials::WithFallback {
+                default: Box::new((*default).try_into()?),
+                fallback: Box::new((*fallback).try_into()?),
+            }),
             _ => Err(Error::new(ErrorDetails::Config {
                 message: "Invalid api_key_location for KIE provider".to_string(),
             })),
@@ -77,14 +84,12 @@ impl KIECredentials {
     ) -> Result<&'a SecretString, DelayedError> {
         match self {
             KIECredentials::Static(api_key) => Ok(api_key),
-            KIECredentials::Dynamic(key_name) => {
-                dynamic_api_keys.get(key_name).ok_or_else(|| {
-                    DelayedError::new(ErrorDetails::ApiKeyMissing {
-                        provider_name: PROVIDER_NAME.to_string(),
-                        message: format!("Dynamic api key `{key_name}` is missing"),
-                    })
+            KIECredentials::Dynamic(key_name) => dynamic_api_keys.get(key_name).ok_or_else(|| {
+                DelayedError::new(ErrorDetails::ApiKeyMissing {
+                    provider_name: PROVIDER_NAME.to_string(),
+                    message: format!("Dynamic api key `{key_name}` is missing"),
                 })
-            }
+            }),
             KIECredentials::WithFallback { default, fallback } => {
                 match default.get_api_key(dynamic_api_keys) {
                     Ok(key) => Ok(key),
@@ -141,15 +146,16 @@ impl InferenceProvider for KIEProvider {
         dynamic_api_keys: &'a InferenceCredentials,
         model_provider: &'a ModelProvider,
     ) -> Result<ProviderInferenceResponse, Error> {
-        let request_body = serde_json::to_value(KIERequest::new(self.model_name.as_str(), request).await?)
-            .map_err(|e| {
-                Error::new(ErrorDetails::Serialization {
-                    message: format!(
-                        "Error serializing KIE request: {}",
-                        DisplayOrDebugGateway::new(e)
-                    ),
-                })
-            })?;
+        let request_body =
+            serde_json::to_value(KIERequest::new(self.model_name.as_str(), request).await?)
+                .map_err(|e| {
+                    Error::new(ErrorDetails::Serialization {
+                        message: format!(
+                            "Error serializing KIE request: {}",
+                            DisplayOrDebugGateway::new(e)
+                        ),
+                    })
+                })?;
 
         let request_url = format!("{}/{}/v1/chat/completions", *KIE_API_BASE, self.model_name)
             .parse::<Url>()
@@ -193,7 +199,12 @@ impl InferenceProvider for KIEProvider {
                 })
             })?;
 
-            tracing::info!("raw_response: {}", raw_response);
+            // Only log the full raw response when debug mode is enabled -
+            // it can contain sensitive user content and shouldn't be logged
+            // unconditionally at `info` level.
+            if crate::error::debug_enabled() {
+                tracing::debug!("raw_response: {}", raw_response);
+            }
             let response: KIEResponse = serde_json::from_str(&raw_response).map_err(|e| {
                 Error::new(ErrorDetails::InferenceServer {
                     message: format!(
@@ -233,13 +244,7 @@ impl InferenceProvider for KIEProvider {
                     provider_type: PROVIDER_TYPE.to_string(),
                 })
             })?;
-            Err(handle_openai_error(
-                &raw_request,
-                status,
-                &response,
-                PROVIDER_TYPE,
-                None,
-            ))
+            Err(handle_kie_error(&raw_request, status, &response))
         }
     }
 
@@ -256,15 +261,16 @@ impl InferenceProvider for KIEProvider {
         dynamic_api_keys: &'a InferenceCredentials,
         model_provider: &'a ModelProvider,
     ) -> Result<(PeekableProviderInferenceResponseStream, String), Error> {
-        let mut request_body = serde_json::to_value(KIERequest::new(self.model_name.as_str(), request).await?)
-            .map_err(|e| {
-                Error::new(ErrorDetails::Serialization {
-                    message: format!(
-                        "Error serializing KIE request: {}",
-                        DisplayOrDebugGateway::new(e)
-                    ),
-                })
-            })?;
+        let mut request_body =
+            serde_json::to_value(KIERequest::new(self.model_name.as_str(), request).await?)
+                .map_err(|e| {
+                    Error::new(ErrorDetails::Serialization {
+                        message: format!(
+                            "Error serializing KIE request: {}",
+                            DisplayOrDebugGateway::new(e)
+                        ),
+                    })
+                })?;
 
         request_body["stream"] = serde_json::json!(true);
 
@@ -297,7 +303,8 @@ impl InferenceProvider for KIEProvider {
         )
         .await?;
 
-        let stream = stream_kie(event_source, start_time, &raw_request, model_inference_id).peekable();
+        let stream =
+            stream_kie(event_source, start_time, &raw_request, model_inference_id).peekable();
 
         Ok((stream, raw_request))
     }
@@ -330,6 +337,10 @@ impl InferenceProvider for KIEProvider {
 struct KIERequest<'a> {
     #[serde(skip_serializing)]
     model: &'a str,
+    // Reuses the same `OpenAIRequestMessage` type (and `tensorzero_to_openai_messages` /
+    // `prepare_file_message` conversion) that the OpenAI provider does, so image and file
+    // content blocks are forwarded to KIE the same way they are to any other OpenAI-compatible
+    // chat completions endpoint - no KIE-specific handling is needed.
     messages: Vec<OpenAIRequestMessage<'a>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -448,7 +459,8 @@ impl<'a> KIERequest<'a> {
 
         if request.json_mode == ModelInferenceRequestJsonMode::Strict {
             tracing::warn!(
-                "KIE provider does not support strict JSON mode. Downgrading to normal JSON mode."
+                "KIE provider does not support strict JSON mode. Downgrading to normal JSON mode. \
+                Consider configuring `json_repair` on the variant to re-prompt on invalid output."
             );
         }
 
@@ -753,6 +765,70 @@ struct KIEResponse {
     usage: OpenAIUsage,
 }
 
+/// KIE error bodies use their own `code`/`message` shape rather than
+/// OpenAI's `error.type`/`error.code`, and sometimes report quota
+/// information alongside quota-exhaustion errors.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct KIEErrorBody {
+    code: Option<String>,
+    message: Option<String>,
+    #[serde(default)]
+    remaining_quota: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct KIEErrorResponse {
+    error: Option<KIEErrorBody>,
+}
+
+/// KIE error codes that indicate the account has exhausted its quota or
+/// isn't provisioned for the requested model/region. These are surfaced as
+/// `ErrorDetails::ProviderQuotaExceeded` instead of the generic
+/// `InferenceClient`/`InferenceServer` split, so callers can inspect
+/// `remaining_quota` and decide whether to retry or fall back.
+const KIE_QUOTA_ERROR_CODES: &[&str] = &[
+    "quota_exceeded",
+    "insufficient_quota",
+    "model_not_available",
+    "region_restricted",
+];
+
+fn handle_kie_error(
+    raw_request: &str,
+    response_code: reqwest::StatusCode,
+    response_body: &str,
+) -> Error {
+    if let Ok(KIEErrorResponse {
+        error: Some(error_body),
+    }) = serde_json::from_str::<KIEErrorResponse>(response_body)
+    {
+        let is_quota_error = error_body
+            .code
+            .as_deref()
+            .is_some_and(|code| KIE_QUOTA_ERROR_CODES.contains(&code));
+        if is_quota_error {
+            return ErrorDetails::ProviderQuotaExceeded {
+                message: error_body
+                    .message
+                    .unwrap_or_else(|| response_body.to_string()),
+                provider_type: PROVIDER_TYPE.to_string(),
+                // Quota/provisioning errors won't resolve themselves on an
+                // immediate retry against the same provider.
+                retryable: false,
+                remaining_quota: error_body.remaining_quota,
+            }
+            .into();
+        }
+    }
+    handle_openai_error(
+        raw_request,
+        response_code,
+        response_body,
+        PROVIDER_TYPE,
+        None,
+    )
+}
+
 #[expect(dead_code)]
 fn extract_content_blocks_from_kie_response(response: &KIEResponse) -> Vec<ContentBlockOutput> {
     let mut content_blocks = Vec::new();
@@ -825,8 +901,76 @@ fn kie_response_to_raw_usage_from_chunk(
 
 #[cfg(test)]
 mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_STANDARD;
+
+    use crate::inference::types::resolved_input::LazyFile;
+    use crate::inference::types::storage::{StorageKind, StoragePath};
+    use crate::inference::types::{
+        ContentBlock, ObjectStorageFile, ObjectStoragePointer, PendingObjectStoreFile,
+        RequestMessage, Role,
+    };
+
     use super::*;
 
+    #[tokio::test]
+    async fn test_kie_request_forwards_image_content() {
+        let dummy_storage_path = StoragePath {
+            kind: StorageKind::Disabled,
+            path: object_store::path::Path::parse("dummy-path").unwrap(),
+        };
+        let file = LazyFile::Base64(PendingObjectStoreFile(ObjectStorageFile {
+            file: ObjectStoragePointer {
+                source_url: None,
+                mime_type: mime::IMAGE_PNG,
+                storage_path: dummy_storage_path,
+                detail: None,
+                filename: None,
+            },
+            data: BASE64_STANDARD.encode(b"fake-png-bytes"),
+        }));
+        let request = ModelInferenceRequest {
+            inference_id: Uuid::now_v7(),
+            messages: vec![RequestMessage {
+                role: Role::User,
+                content: vec![
+                    "What's in this image?".to_string().into(),
+                    ContentBlock::File(Box::new(file)),
+                ],
+            }],
+            system: None,
+            temperature: None,
+            top_p: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            max_tokens: None,
+            stream: false,
+            seed: None,
+            json_mode: ModelInferenceRequestJsonMode::Off,
+            tool_config: None,
+            function_type: crate::inference::types::FunctionType::Chat,
+            output_schema: None,
+            extra_body: Default::default(),
+            ..Default::default()
+        };
+
+        let kie_request = KIERequest::new("gemini-3-pro", &request)
+            .await
+            .expect("failed to create KIE Request during test");
+
+        let OpenAIRequestMessage::User(user_message) = &kie_request.messages[0] else {
+            panic!("expected a user message");
+        };
+        assert!(
+            user_message
+                .content
+                .iter()
+                .any(|block| matches!(block, OpenAIContentBlock::ImageUrl { .. })),
+            "expected the image content block to be forwarded as an `image_url` block, the same \
+            way it is for other OpenAI-compatible providers"
+        );
+    }
+
     #[tokio::test]
     async fn test_kie_request_new() {
         let request_with_tools = ModelInferenceRequest {
@@ -852,8 +996,16 @@ mod tests {
             .await
             .expect("failed to create KIE Request during test");
 
-        assert_eq!(kie_request.temperature, Some(0.5), "Expected temperature to be 0.5");
-        assert_eq!(kie_request.max_tokens, Some(100), "Expected max_tokens to be 100");
+        assert_eq!(
+            kie_request.temperature,
+            Some(0.5),
+            "Expected temperature to be 0.5"
+        );
+        assert_eq!(
+            kie_request.max_tokens,
+            Some(100),
+            "Expected max_tokens to be 100"
+        );
         assert!(!kie_request.stream, "Expected stream to be false");
         assert_eq!(kie_request.seed, Some(69), "Expected seed to be 69");
     }
@@ -863,10 +1015,9 @@ mod tests {
         // Test that URLs are constructed correctly with model name
         let model_name = "gemini-3-pro";
         let expected_url = format!("{}/{}/v1/chat/completions", *KIE_API_BASE, model_name);
-        
+
         assert_eq!(
-            expected_url,
-            "https://api.kie.ai/gemini-3-pro/v1/chat/completions",
+            expected_url, "https://api.kie.ai/gemini-3-pro/v1/chat/completions",
             "Expected URL to include model name in correct format"
         );
     }
@@ -894,7 +1045,7 @@ mod tests {
 
         // Test with "medium" reasoning_effort (should be mapped to "high")
         request.inference_params_v2.reasoning_effort = Some("medium".to_string());
-        
+
         let mut kie_request = KIERequest {
             model: "kie-chat",
             messages: vec![],
@@ -915,7 +1066,7 @@ mod tests {
         };
 
         apply_inference_params(&mut kie_request, &request.inference_params_v2);
-        
+
         assert_eq!(
             kie_request.reasoning_effort,
             Some("high".to_string()),
@@ -924,7 +1075,7 @@ mod tests {
 
         // Test with "low" reasoning_effort (should remain "low")
         request.inference_params_v2.reasoning_effort = Some("low".to_string());
-        
+
         let mut kie_request_low = KIERequest {
             model: "kie-chat",
             messages: vec![],
@@ -945,7 +1096,7 @@ mod tests {
         };
 
         apply_inference_params(&mut kie_request_low, &request.inference_params_v2);
-        
+
         assert_eq!(
             kie_request_low.reasoning_effort,
             Some("low".to_string()),
@@ -954,7 +1105,7 @@ mod tests {
 
         // Test with "high" reasoning_effort (should remain "high")
         request.inference_params_v2.reasoning_effort = Some("high".to_string());
-        
+
         let mut kie_request_high = KIERequest {
             model: "kie-chat",
             messages: vec![],
@@ -975,7 +1126,7 @@ mod tests {
         };
 
         apply_inference_params(&mut kie_request_high, &request.inference_params_v2);
-        
+
         assert_eq!(
             kie_request_high.reasoning_effort,
             Some("high".to_string()),
@@ -983,4 +1134,3 @@ mod tests {
         );
     }
 }
-
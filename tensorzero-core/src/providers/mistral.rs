@@ -10,7 +10,7 @@ use reqwest::StatusCode;
 use reqwest_eventsource::Event;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 use tokio::time::Instant;
 use url::Url;
 
@@ -437,10 +437,35 @@ fn tensorzero_to_mistral_system_message(system: Option<&str>) -> Option<OpenAIRe
 #[serde(tag = "type")]
 enum MistralResponseFormat {
     JsonObject,
+    /// Mistral's native structured-output mode: the model is constrained to the given JSON
+    /// Schema, rather than just "some valid JSON object".
+    JsonSchema {
+        json_schema: Value,
+    },
     #[default]
     Text,
 }
 
+impl MistralResponseFormat {
+    fn new(
+        json_mode: ModelInferenceRequestJsonMode,
+        output_schema: Option<&Value>,
+    ) -> Option<Self> {
+        match json_mode {
+            ModelInferenceRequestJsonMode::Off => None,
+            // Only use native json_schema mode when we actually have a schema to constrain to -
+            // dynamic JSON mode without a schema falls back to the looser `json_object` mode.
+            ModelInferenceRequestJsonMode::Strict => match output_schema {
+                Some(schema) => Some(MistralResponseFormat::JsonSchema {
+                    json_schema: json!({"name": "response", "strict": true, "schema": schema}),
+                }),
+                None => Some(MistralResponseFormat::JsonObject),
+            },
+            ModelInferenceRequestJsonMode::On => Some(MistralResponseFormat::JsonObject),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(untagged)]
 pub(super) enum MistralToolChoice<'a> {
@@ -595,12 +620,7 @@ impl<'a> MistralRequest<'a> {
         model: &'a str,
         request: &'a ModelInferenceRequest<'_>,
     ) -> Result<MistralRequest<'a>, Error> {
-        let response_format = match request.json_mode {
-            ModelInferenceRequestJsonMode::On | ModelInferenceRequestJsonMode::Strict => {
-                Some(MistralResponseFormat::JsonObject)
-            }
-            ModelInferenceRequestJsonMode::Off => None,
-        };
+        let response_format = MistralResponseFormat::new(request.json_mode, request.output_schema);
         let messages = prepare_mistral_messages(
             request,
             OpenAIMessagesConfig {
@@ -4,6 +4,8 @@ pub mod aws_common;
 pub mod aws_sagemaker;
 pub mod azure;
 pub mod chat_completions;
+#[cfg(test)]
+pub mod conformance;
 pub mod deepseek;
 #[cfg(any(test, feature = "e2e_tests"))]
 pub mod dummy;
@@ -17,8 +19,11 @@ pub mod helpers_thinking_block;
 pub mod hyperbolic;
 pub mod kie;
 pub mod mistral;
+pub mod ollama;
 pub mod openai;
+pub mod openai_compatible;
 pub mod openrouter;
+pub mod request_signing;
 pub mod sglang;
 #[cfg(test)]
 pub mod test_helpers;
@@ -0,0 +1,804 @@
+use std::borrow::Cow;
+
+use futures::future::try_join_all;
+use futures::{StreamExt, TryStreamExt};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::time::Instant;
+use url::Url;
+
+use super::openai::{
+    OpenAIRequestMessage, OpenAIResponse, OpenAIResponseChoice, OpenAISystemRequestMessage,
+    OpenAITool, OpenAIToolChoice, StreamOptions, get_chat_url, handle_openai_error, stream_openai,
+    tensorzero_to_openai_messages,
+};
+use crate::cache::ModelProviderRequest;
+use crate::endpoints::inference::InferenceCredentials;
+use crate::error::{DelayedError, DisplayOrDebugGateway, Error, ErrorDetails};
+use crate::http::TensorzeroHttpClient;
+use crate::inference::types::Thought;
+use crate::inference::types::batch::{BatchRequestRow, PollBatchInferenceResponse};
+use crate::inference::types::chat_completion_inference_params::{
+    ChatCompletionInferenceParamsV2, warn_inference_parameter_not_supported,
+};
+use crate::inference::types::usage::raw_usage_entries_from_value;
+use crate::inference::types::{
+    ApiType, ContentBlockOutput, Latency, ModelInferenceRequest, ModelInferenceRequestJsonMode,
+    PeekableProviderInferenceResponseStream, ProviderInferenceResponse,
+    ProviderInferenceResponseArgs, batch::StartBatchProviderInferenceResponse,
+};
+use crate::inference::{InferenceProvider, TensorZeroEventError};
+use crate::model::{Credential, ModelProvider};
+use crate::providers::helpers::{
+    inject_extra_request_data_and_send, inject_extra_request_data_and_send_eventsource,
+};
+use crate::providers::openai::{OpenAIMessagesConfig, check_api_base_suffix};
+use uuid::Uuid;
+
+const PROVIDER_NAME: &str = "Ollama";
+pub const PROVIDER_TYPE: &str = "ollama";
+
+/// Ollama exposes an OpenAI-compatible `/v1/chat/completions` endpoint (in addition to
+/// its own native `/api/*` routes used for model management - see
+/// `endpoints::internal::ollama` for the admin API that lists/pulls local models), so
+/// this provider is implemented the same way as our other local-server providers
+/// (`vllm`, `sglang`, `tgi`): a thin wrapper around the shared OpenAI request/response
+/// types. Ollama servers are typically unauthenticated, so credentials default to `None`.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct OllamaProvider {
+    model_name: String,
+    api_base: Url,
+    #[serde(skip)]
+    credentials: OllamaCredentials,
+}
+
+impl OllamaProvider {
+    pub fn new(model_name: String, api_base: Url, credentials: OllamaCredentials) -> Self {
+        // Check if the api_base has the `/chat/completions` suffix and warn if it does
+        check_api_base_suffix(&api_base);
+
+        OllamaProvider {
+            model_name,
+            api_base,
+            credentials,
+        }
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum OllamaCredentials {
+    Static(SecretString),
+    Dynamic(String),
+    WithFallback {
+        default: Box<OllamaCredentials>,
+        fallback: Box<OllamaCredentials>,
+    },
+    None,
+}
+
+impl TryFrom<Credential> for OllamaCredentials {
+    type Error = Error;
+
+    fn try_from(credentials: Credential) -> Result<Self, Error> {
+        match credentials {
+            Credential::Static(key) => Ok(OllamaCredentials::Static(key)),
+            Credential::Dynamic(key_name) => Ok(OllamaCredentials::Dynamic(key_name)),
+            Credential::None => Ok(OllamaCredentials::None),
+            Credential::WithFallback { default, fallback } => Ok(OllamaCredentials::WithFallback {
+                default: Box::new((*default).try_into()?),
+                fallback: Box::new((*fallback).try_into()?),
+            }),
+            #[cfg(any(test, feature = "e2e_tests"))]
+            Credential::Missing => Ok(OllamaCredentials::None),
+            _ => Err(Error::new(ErrorDetails::Config {
+                message: "Invalid api_key_location for Ollama provider".to_string(),
+            })),
+        }
+    }
+}
+impl OllamaCredentials {
+    fn get_api_key<'a>(
+        &'a self,
+        dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<Option<&'a SecretString>, DelayedError> {
+        match self {
+            OllamaCredentials::Static(api_key) => Ok(Some(api_key)),
+            OllamaCredentials::Dynamic(key_name) => {
+                Ok(Some(dynamic_api_keys.get(key_name).ok_or_else(|| {
+                    DelayedError::new(ErrorDetails::ApiKeyMissing {
+                        provider_name: PROVIDER_NAME.to_string(),
+                        message: format!("Dynamic api key `{key_name}` is missing"),
+                    })
+                })?))
+            }
+            OllamaCredentials::WithFallback { default, fallback } => {
+                // Try default first, fall back to fallback if it fails
+                match default.get_api_key(dynamic_api_keys) {
+                    Ok(key) => Ok(key),
+                    Err(e) => {
+                        e.log_at_level(
+                            "Using fallback credential, as default credential is unavailable: ",
+                            tracing::Level::WARN,
+                        );
+                        fallback.get_api_key(dynamic_api_keys)
+                    }
+                }
+            }
+            OllamaCredentials::None => Ok(None),
+        }
+    }
+}
+
+/// Ollama does not support batch inference and does not support guided decoding via
+/// `guided_json` the way vLLM does - JSON mode is instead handled by the model itself
+/// based on the prompt, so we omit that field entirely.
+impl InferenceProvider for OllamaProvider {
+    async fn infer<'a>(
+        &'a self,
+        ModelProviderRequest {
+            request,
+            provider_name: _,
+            model_name,
+            otlp_config: _,
+            model_inference_id,
+        }: ModelProviderRequest<'a>,
+        http_client: &'a TensorzeroHttpClient,
+        dynamic_api_keys: &'a InferenceCredentials,
+        model_provider: &'a ModelProvider,
+    ) -> Result<ProviderInferenceResponse, Error> {
+        let request_body = serde_json::to_value(
+            OllamaRequest::new(&self.model_name, request).await?,
+        )
+        .map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!(
+                    "Error serializing Ollama request: {}",
+                    DisplayOrDebugGateway::new(e)
+                ),
+            })
+        })?;
+        let request_url = get_chat_url(&self.api_base)?;
+        let start_time = Instant::now();
+        let api_key = self
+            .credentials
+            .get_api_key(dynamic_api_keys)
+            .map_err(|e| e.log())?;
+        let mut request_builder = http_client.post(request_url);
+        if let Some(key) = api_key {
+            request_builder = request_builder.bearer_auth(key.expose_secret());
+        }
+        let (res, raw_request) = inject_extra_request_data_and_send(
+            PROVIDER_TYPE,
+            &request.extra_body,
+            &request.extra_headers,
+            model_provider,
+            model_name,
+            request_body,
+            request_builder,
+        )
+        .await?;
+
+        let latency = Latency::NonStreaming {
+            response_time: start_time.elapsed(),
+        };
+        if res.status().is_success() {
+            let raw_response = res.text().await.map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!("Error parsing response: {}", DisplayOrDebugGateway::new(e)),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: None,
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            let response_body = serde_json::from_str(&raw_response).map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!("Error parsing response: {}", DisplayOrDebugGateway::new(e)),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: Some(raw_response.clone()),
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            Ok(OllamaResponseWithMetadata {
+                response: response_body,
+                latency,
+                raw_response,
+                raw_request,
+                generic_request: request,
+                model_inference_id,
+            }
+            .try_into()?)
+        } else {
+            let status = res.status();
+            let raw_response = res.text().await.map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!(
+                        "Error parsing error response: {}",
+                        DisplayOrDebugGateway::new(e)
+                    ),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: None,
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            Err(handle_openai_error(
+                &raw_request,
+                status,
+                &raw_response,
+                PROVIDER_TYPE,
+                None,
+            ))
+        }
+    }
+
+    async fn infer_stream<'a>(
+        &'a self,
+        ModelProviderRequest {
+            request,
+            provider_name: _,
+            model_name,
+            otlp_config: _,
+            model_inference_id,
+        }: ModelProviderRequest<'a>,
+        http_client: &'a TensorzeroHttpClient,
+        dynamic_api_keys: &'a InferenceCredentials,
+        model_provider: &'a ModelProvider,
+    ) -> Result<(PeekableProviderInferenceResponseStream, String), Error> {
+        let request_body = serde_json::to_value(
+            OllamaRequest::new(&self.model_name, request).await?,
+        )
+        .map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!(
+                    "Error serializing Ollama request: {}",
+                    DisplayOrDebugGateway::new(e)
+                ),
+            })
+        })?;
+
+        let api_key = self
+            .credentials
+            .get_api_key(dynamic_api_keys)
+            .map_err(|e| e.log())?;
+        let request_url = get_chat_url(&self.api_base)?;
+        let start_time = Instant::now();
+        let mut request_builder = http_client.post(request_url);
+        if let Some(key) = api_key {
+            request_builder = request_builder.bearer_auth(key.expose_secret());
+        }
+        let (event_source, raw_request) = inject_extra_request_data_and_send_eventsource(
+            PROVIDER_TYPE,
+            &request.extra_body,
+            &request.extra_headers,
+            model_provider,
+            model_name,
+            request_body,
+            request_builder,
+        )
+        .await?;
+        let stream = stream_openai(
+            PROVIDER_TYPE.to_string(),
+            model_inference_id,
+            event_source.map_err(TensorZeroEventError::EventSource),
+            start_time,
+            None,
+            &raw_request,
+        )
+        .peekable();
+        Ok((stream, raw_request))
+    }
+
+    async fn start_batch_inference<'a>(
+        &'a self,
+        _requests: &'a [ModelInferenceRequest<'_>],
+        _client: &'a TensorzeroHttpClient,
+        _dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<StartBatchProviderInferenceResponse, Error> {
+        Err(ErrorDetails::UnsupportedModelProviderForBatchInference {
+            provider_type: PROVIDER_TYPE.to_string(),
+        }
+        .into())
+    }
+
+    async fn poll_batch_inference<'a>(
+        &'a self,
+        _batch_request: &'a BatchRequestRow<'a>,
+        _http_client: &'a TensorzeroHttpClient,
+        _dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<PollBatchInferenceResponse, Error> {
+        Err(ErrorDetails::UnsupportedModelProviderForBatchInference {
+            provider_type: PROVIDER_TYPE.to_string(),
+        }
+        .into())
+    }
+}
+
+/// This struct defines the supported parameters for Ollama's OpenAI-compatible
+/// chat completions API. See the [Ollama API documentation](https://github.com/ollama/ollama/blob/main/docs/openai.md)
+/// for more details. We are not handling many features of the API here.
+#[derive(Debug, Serialize)]
+#[cfg_attr(test, derive(Default))]
+struct OllamaRequest<'a> {
+    messages: Vec<OpenAIRequestMessage<'a>>,
+    model: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'a Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Cow<'a, [String]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<OpenAITool<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<OpenAIToolChoice<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+}
+
+type PreparedOllamaToolsResult<'a> = (
+    Option<Vec<OpenAITool<'a>>>,
+    Option<OpenAIToolChoice<'a>>,
+    Option<bool>,
+);
+
+/// If there are no tools passed or the tools are empty, return None for both tools and tool_choice
+/// Otherwise convert the tool choice and tools to Ollama format
+pub(super) fn prepare_ollama_tools<'a>(
+    request: &'a ModelInferenceRequest,
+) -> Result<PreparedOllamaToolsResult<'a>, Error> {
+    match &request.tool_config {
+        None => Ok((None, None, None)),
+        Some(tool_config) => {
+            if !tool_config.any_tools_available() {
+                return Ok((None, None, None));
+            }
+            let tools = Some(
+                tool_config
+                    .strict_tools_available()?
+                    .map(Into::into)
+                    .collect(),
+            );
+            let parallel_tool_calls = tool_config.parallel_tool_calls;
+
+            let tool_choice = Some((&tool_config.tool_choice).into());
+            Ok((tools, tool_choice, parallel_tool_calls))
+        }
+    }
+}
+
+fn apply_inference_params(
+    _request: &mut OllamaRequest,
+    inference_params: &ChatCompletionInferenceParamsV2,
+) {
+    let ChatCompletionInferenceParamsV2 {
+        reasoning_effort,
+        service_tier,
+        thinking_budget_tokens,
+        verbosity,
+    } = inference_params;
+
+    if reasoning_effort.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "reasoning_effort", None);
+    }
+
+    if service_tier.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "service_tier", None);
+    }
+
+    if thinking_budget_tokens.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "thinking_budget_tokens", None);
+    }
+
+    if verbosity.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "verbosity", None);
+    }
+}
+
+impl<'a> OllamaRequest<'a> {
+    pub async fn new(
+        model: &'a str,
+        request: &'a ModelInferenceRequest<'_>,
+    ) -> Result<OllamaRequest<'a>, Error> {
+        let format = match (&request.json_mode, request.output_schema) {
+            (
+                ModelInferenceRequestJsonMode::On | ModelInferenceRequestJsonMode::Strict,
+                Some(schema),
+            ) => Some(schema),
+            _ => None,
+        };
+        let stream_options = if request.stream {
+            Some(StreamOptions {
+                include_usage: true,
+            })
+        } else {
+            None
+        };
+        let messages = prepare_ollama_messages(
+            request,
+            OpenAIMessagesConfig {
+                json_mode: Some(&request.json_mode),
+                provider_type: PROVIDER_TYPE,
+                fetch_and_encode_input_files_before_inference: request
+                    .fetch_and_encode_input_files_before_inference,
+            },
+        )
+        .await?;
+
+        let (tools, tool_choice, parallel_tool_calls) = prepare_ollama_tools(request)?;
+
+        let mut ollama_request = OllamaRequest {
+            messages,
+            model,
+            temperature: request.temperature,
+            top_p: request.top_p,
+            presence_penalty: request.presence_penalty,
+            frequency_penalty: request.frequency_penalty,
+            max_tokens: request.max_tokens,
+            stream: request.stream,
+            stream_options,
+            format,
+            seed: request.seed,
+            stop: request.borrow_stop_sequences(),
+            tools,
+            tool_choice,
+            parallel_tool_calls,
+        };
+
+        apply_inference_params(&mut ollama_request, &request.inference_params_v2);
+
+        Ok(ollama_request)
+    }
+}
+
+struct OllamaResponseWithMetadata<'a> {
+    response: OpenAIResponse,
+    latency: Latency,
+    raw_response: String,
+    raw_request: String,
+    generic_request: &'a ModelInferenceRequest<'a>,
+    model_inference_id: Uuid,
+}
+
+impl<'a> TryFrom<OllamaResponseWithMetadata<'a>> for ProviderInferenceResponse {
+    type Error = Error;
+    fn try_from(value: OllamaResponseWithMetadata<'a>) -> Result<Self, Self::Error> {
+        let OllamaResponseWithMetadata {
+            mut response,
+            latency,
+            raw_response,
+            raw_request,
+            generic_request,
+            model_inference_id,
+        } = value;
+
+        if response.choices.len() != 1 {
+            return Err(ErrorDetails::InferenceServer {
+                message: format!(
+                    "Response has invalid number of choices: {}. Expected 1.",
+                    response.choices.len()
+                ),
+                raw_request: Some(raw_request.clone()),
+                raw_response: Some(raw_response.clone()),
+                provider_type: PROVIDER_TYPE.to_string(),
+            }
+            .into());
+        }
+        let OpenAIResponseChoice {
+            message,
+            finish_reason,
+            ..
+        } = response
+            .choices
+            .pop()
+            .ok_or_else(|| Error::new(ErrorDetails::InferenceServer {
+                message: "Response has no choices (this should never happen). Please file a bug report: https://github.com/tensorzero/tensorzero/issues/new".to_string(),
+                provider_type: PROVIDER_TYPE.to_string(),
+                raw_request: Some(raw_request.clone()),
+                raw_response: Some(raw_response.clone()),
+            }))?;
+        let mut content: Vec<ContentBlockOutput> = Vec::new();
+        if let Some(reasoning) = message.reasoning_content {
+            content.push(ContentBlockOutput::Thought(Thought {
+                text: Some(reasoning),
+                signature: None,
+                summary: None,
+                provider_type: Some(PROVIDER_TYPE.to_string()),
+                extra_data: None,
+            }));
+        }
+        if let Some(text) = message.content {
+            content.push(text.into());
+        }
+        if let Some(tool_calls) = message.tool_calls {
+            for tool_call in tool_calls {
+                content.push(ContentBlockOutput::ToolCall(tool_call.into()));
+            }
+        }
+        let raw_usage = ollama_usage_from_raw_response(&raw_response).map(|usage| {
+            raw_usage_entries_from_value(
+                model_inference_id,
+                PROVIDER_TYPE,
+                ApiType::ChatCompletions,
+                usage,
+            )
+        });
+        let usage = response.usage.into();
+        let system = generic_request.system.clone();
+        let input_messages = generic_request.messages.clone();
+        Ok(ProviderInferenceResponse::new(
+            ProviderInferenceResponseArgs {
+                output: content,
+                system,
+                input_messages,
+                raw_request,
+                raw_response: raw_response.clone(),
+                usage,
+                raw_usage,
+                relay_raw_response: None,
+                provider_latency: latency,
+                finish_reason: Some(finish_reason.into()),
+                id: model_inference_id,
+            },
+        ))
+    }
+}
+
+fn ollama_usage_from_raw_response(raw_response: &str) -> Option<Value> {
+    serde_json::from_str::<Value>(raw_response)
+        .ok()
+        .and_then(|value| value.get("usage").filter(|v| !v.is_null()).cloned())
+}
+
+pub(super) async fn prepare_ollama_messages<'a>(
+    request: &'a ModelInferenceRequest<'_>,
+    config: OpenAIMessagesConfig<'a>,
+) -> Result<Vec<OpenAIRequestMessage<'a>>, Error> {
+    let mut messages: Vec<_> = try_join_all(
+        request
+            .messages
+            .iter()
+            .map(|msg| tensorzero_to_openai_messages(msg, config)),
+    )
+    .await?
+    .into_iter()
+    .flatten()
+    .collect();
+    if let Some(system_msg) = tensorzero_to_ollama_system_message(request.system.as_deref()) {
+        messages.insert(0, system_msg);
+    }
+    Ok(messages)
+}
+
+fn tensorzero_to_ollama_system_message(system: Option<&str>) -> Option<OpenAIRequestMessage<'_>> {
+    system.map(|instructions| {
+        OpenAIRequestMessage::System(OpenAISystemRequestMessage {
+            content: Cow::Borrowed(instructions),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, time::Duration};
+
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+
+    use crate::{
+        inference::types::{FunctionType, ModelInferenceRequestJsonMode, RequestMessage, Role},
+        providers::{
+            openai::{
+                OpenAIFinishReason, OpenAIResponseChoice, OpenAIResponseMessage, OpenAIUsage,
+            },
+            test_helpers::{WEATHER_TOOL, WEATHER_TOOL_CONFIG},
+        },
+    };
+
+    #[tokio::test]
+    async fn test_ollama_request_new() {
+        let model_name = "llama3.1";
+        let output_schema = json!({
+            "type": "object",
+            "properties": {
+                "temperature": {"type": "number"},
+                "location": {"type": "string"}
+            }
+        });
+
+        let request_with_tools = ModelInferenceRequest {
+            inference_id: Uuid::now_v7(),
+            messages: vec![RequestMessage {
+                role: Role::User,
+                content: vec!["What's the weather?".to_string().into()],
+            }],
+            system: None,
+            temperature: Some(0.5),
+            top_p: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            max_tokens: Some(100),
+            seed: Some(69),
+            stream: false,
+            json_mode: ModelInferenceRequestJsonMode::On,
+            tool_config: Some(Cow::Borrowed(&WEATHER_TOOL_CONFIG)),
+            function_type: FunctionType::Chat,
+            output_schema: Some(&output_schema),
+            extra_body: Default::default(),
+            ..Default::default()
+        };
+
+        let ollama_request = OllamaRequest::new(model_name, &request_with_tools)
+            .await
+            .unwrap();
+
+        assert_eq!(ollama_request.model, model_name);
+        assert_eq!(ollama_request.messages.len(), 1);
+        assert_eq!(ollama_request.temperature, Some(0.5));
+        assert_eq!(ollama_request.max_tokens, Some(100));
+        assert!(!ollama_request.stream);
+        assert_eq!(ollama_request.format, Some(&output_schema));
+        let tools = ollama_request.tools.unwrap();
+        assert_eq!(tools.len(), 1);
+        match &tools[0] {
+            crate::providers::openai::OpenAITool::Function { function, .. } => {
+                assert_eq!(function.name, WEATHER_TOOL.name());
+            }
+            crate::providers::openai::OpenAITool::Custom { .. } => panic!("Expected Function tool"),
+        }
+    }
+
+    #[test]
+    fn test_credential_to_ollama_credentials() {
+        // Test Static credential
+        let generic = Credential::Static(SecretString::from("test_key"));
+        let creds: OllamaCredentials = OllamaCredentials::try_from(generic).unwrap();
+        assert!(matches!(creds, OllamaCredentials::Static(_)));
+
+        // Test Dynamic credential
+        let generic = Credential::Dynamic("key_name".to_string());
+        let creds = OllamaCredentials::try_from(generic).unwrap();
+        assert!(matches!(creds, OllamaCredentials::Dynamic(_)));
+
+        // Test Missing credential
+        let generic = Credential::Missing;
+        let creds = OllamaCredentials::try_from(generic).unwrap();
+        assert!(matches!(creds, OllamaCredentials::None));
+
+        // Test invalid type
+        let generic = Credential::FileContents(SecretString::from("test"));
+        let result = OllamaCredentials::try_from(generic);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err().get_details(),
+            ErrorDetails::Config { message } if message.contains("Invalid api_key_location")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ollama_response_with_metadata_try_into() {
+        let valid_response = OpenAIResponse {
+            choices: vec![OpenAIResponseChoice {
+                index: 0,
+                message: OpenAIResponseMessage {
+                    content: Some("Hello, world!".to_string()),
+                    reasoning_content: None,
+                    tool_calls: None,
+                },
+                finish_reason: OpenAIFinishReason::Stop,
+            }],
+            usage: OpenAIUsage {
+                prompt_tokens: Some(10),
+                completion_tokens: Some(20),
+            },
+        };
+        let generic_request = ModelInferenceRequest {
+            inference_id: Uuid::now_v7(),
+            messages: vec![RequestMessage {
+                role: Role::User,
+                content: vec!["test_user".to_string().into()],
+            }],
+            system: None,
+            temperature: Some(0.5),
+            top_p: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            max_tokens: Some(100),
+            stream: false,
+            seed: Some(69),
+            json_mode: ModelInferenceRequestJsonMode::Off,
+            tool_config: None,
+            function_type: FunctionType::Chat,
+            output_schema: None,
+            extra_body: Default::default(),
+            ..Default::default()
+        };
+        let ollama_response_with_metadata = OllamaResponseWithMetadata {
+            response: valid_response,
+            raw_response: "test_response".to_string(),
+            latency: Latency::NonStreaming {
+                response_time: Duration::from_secs(0),
+            },
+            raw_request: serde_json::to_string(
+                &OllamaRequest::new("test-model", &generic_request)
+                    .await
+                    .unwrap(),
+            )
+            .unwrap(),
+            generic_request: &generic_request,
+            model_inference_id: Uuid::now_v7(),
+        };
+        let inference_response: ProviderInferenceResponse =
+            ollama_response_with_metadata.try_into().unwrap();
+
+        assert_eq!(inference_response.output.len(), 1);
+        assert_eq!(
+            inference_response.output[0],
+            "Hello, world!".to_string().into()
+        );
+        assert_eq!(inference_response.raw_response, "test_response");
+        assert_eq!(inference_response.usage.input_tokens, Some(10));
+        assert_eq!(inference_response.usage.output_tokens, Some(20));
+    }
+
+    #[test]
+    fn test_ollama_provider_new_api_base_check() {
+        let logs_contain = crate::utils::testing::capture_logs();
+        let model_name = "test-model".to_string();
+
+        let _ = OllamaProvider::new(
+            model_name.clone(),
+            Url::parse("http://localhost:11434/v1/").unwrap(),
+            OllamaCredentials::None,
+        );
+
+        let invalid_url = Url::parse("http://localhost:11434/chat/completions").unwrap();
+        let _ = OllamaProvider::new(model_name, invalid_url.clone(), OllamaCredentials::None);
+        assert!(logs_contain("automatically appends `/chat/completions`"));
+        assert!(logs_contain(invalid_url.as_ref()));
+    }
+
+    #[test]
+    fn test_ollama_apply_inference_params_called() {
+        let logs_contain = crate::utils::testing::capture_logs();
+        let inference_params = ChatCompletionInferenceParamsV2 {
+            reasoning_effort: Some("high".to_string()),
+            service_tier: None,
+            thinking_budget_tokens: Some(1024),
+            verbosity: Some("low".to_string()),
+        };
+        let mut request = OllamaRequest::default();
+
+        apply_inference_params(&mut request, &inference_params);
+
+        assert!(logs_contain(
+            "Ollama does not support the inference parameter `reasoning_effort`"
+        ));
+        assert!(logs_contain(
+            "Ollama does not support the inference parameter `thinking_budget_tokens`"
+        ));
+        assert!(logs_contain(
+            "Ollama does not support the inference parameter `verbosity`"
+        ));
+    }
+}
@@ -182,6 +182,38 @@ pub fn check_api_base_suffix(api_base: &Url) {
     }
 }
 
+/// Top-level fields in a standard OpenAI-compatible chat completion response. Any other
+/// top-level field is treated as a server-specific extension (for example, some vLLM/TGI
+/// deployments attach engine metrics like queue time or KV cache usage directly to the
+/// response body) and gets merged into the returned `usage` object so it isn't silently
+/// dropped when relayed as raw usage.
+const STANDARD_CHAT_COMPLETION_RESPONSE_FIELDS: &[&str] =
+    &["id", "object", "created", "model", "choices", "usage"];
+
+/// Parses a raw chat completion response and returns its `usage` object, merged with any
+/// non-standard top-level fields the server included (see
+/// [`STANDARD_CHAT_COMPLETION_RESPONSE_FIELDS`]). Returns `None` if there is nothing to report.
+pub fn usage_with_engine_metrics_from_raw_response(raw_response: &str) -> Option<Value> {
+    let response = serde_json::from_str::<Value>(raw_response).ok()?;
+    let response_object = response.as_object()?;
+    let mut usage = response_object
+        .get("usage")
+        .filter(|v| !v.is_null())
+        .cloned()
+        .unwrap_or_else(|| json!({}));
+    let usage_object = usage.as_object_mut()?;
+    for (key, value) in response_object {
+        if !STANDARD_CHAT_COMPLETION_RESPONSE_FIELDS.contains(&key.as_str()) {
+            usage_object.insert(key.clone(), value.clone());
+        }
+    }
+    if usage_object.is_empty() {
+        None
+    } else {
+        Some(usage)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum OpenAICredentials {
     Static(SecretString),
@@ -3185,6 +3217,8 @@ mod tests {
         FunctionType, ObjectStorageFile, ObjectStoragePointer, PendingObjectStoreFile,
         RequestMessage,
     };
+    use crate::providers::conformance;
+    use crate::providers::conformance::ExpectedErrorBucket;
     use crate::providers::test_helpers::{
         MULTI_TOOL_CONFIG, QUERY_TOOL, WEATHER_TOOL, WEATHER_TOOL_CONFIG,
     };
@@ -3337,6 +3371,49 @@ mod tests {
         }
     }
 
+    /// Runs `handle_openai_error` through the shared provider conformance harness, so this
+    /// provider is checked against the same client-vs-server error bucketing as every other
+    /// `handle_*_error` function.
+    #[test]
+    fn test_handle_openai_error_conformance() {
+        use reqwest::StatusCode;
+
+        for status_code in [
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN,
+            StatusCode::TOO_MANY_REQUESTS,
+        ] {
+            conformance::assert_error_mapping(
+                handle_openai_error,
+                status_code,
+                PROVIDER_TYPE,
+                ExpectedErrorBucket::Client,
+            );
+        }
+        for status_code in [StatusCode::INTERNAL_SERVER_ERROR, StatusCode::BAD_GATEWAY] {
+            conformance::assert_error_mapping(
+                handle_openai_error,
+                status_code,
+                PROVIDER_TYPE,
+                ExpectedErrorBucket::Server,
+            );
+        }
+    }
+
+    #[test]
+    fn test_openai_request_tool_call_round_trip() {
+        let tool_call = OpenAIRequestToolCall {
+            id: Cow::Borrowed("call1"),
+            r#type: OpenAIToolType::Function,
+            function: OpenAIRequestFunctionCall {
+                name: Cow::Borrowed("get_temperature"),
+                arguments: Cow::Borrowed("{\"location\":\"tokyo\"}"),
+            },
+        };
+        conformance::assert_json_round_trip(&tool_call);
+    }
+
     #[tokio::test]
     async fn test_openai_request_new() {
         // Test basic request
@@ -3693,6 +3770,7 @@ mod tests {
         );
         assert_eq!(inference_response.usage.input_tokens, Some(10));
         assert_eq!(inference_response.usage.output_tokens, Some(20));
+        conformance::assert_usage_accounting(&inference_response.usage);
         assert_eq!(inference_response.finish_reason, Some(FinishReason::Stop));
         assert_eq!(
             inference_response.provider_latency,
@@ -4289,6 +4367,56 @@ mod tests {
         );
     }
 
+    /// Runs a small simulated stream through the shared provider conformance harness, to check
+    /// that this provider's chunks don't report conflicting finish reasons.
+    #[test]
+    fn test_openai_to_tensorzero_chunk_conformance() {
+        let mut tool_call_ids = vec![];
+        let text_chunk = OpenAIChatChunk {
+            choices: vec![OpenAIChatChunkChoice {
+                delta: OpenAIDelta {
+                    content: Some("Hello".to_string()),
+                    reasoning_content: None,
+                    tool_calls: None,
+                },
+                finish_reason: None,
+            }],
+            usage: None,
+        };
+        let final_chunk = OpenAIChatChunk {
+            choices: vec![OpenAIChatChunkChoice {
+                delta: OpenAIDelta {
+                    content: None,
+                    reasoning_content: None,
+                    tool_calls: None,
+                },
+                finish_reason: Some(OpenAIFinishReason::Stop),
+            }],
+            usage: None,
+        };
+        let chunks = vec![
+            openai_to_tensorzero_chunk(
+                "chunk1".to_string(),
+                text_chunk,
+                Duration::from_millis(10),
+                &mut tool_call_ids,
+                Uuid::now_v7(),
+                PROVIDER_TYPE,
+            )
+            .unwrap(),
+            openai_to_tensorzero_chunk(
+                "chunk2".to_string(),
+                final_chunk,
+                Duration::from_millis(20),
+                &mut tool_call_ids,
+                Uuid::now_v7(),
+                PROVIDER_TYPE,
+            )
+            .unwrap(),
+        ];
+        conformance::assert_streaming_chunk_invariants(&chunks);
+    }
+
     #[test]
     fn test_new_openai_response_format() {
         // Test JSON mode On
@@ -5052,6 +5180,45 @@ mod tests {
         assert!(logs_contain(url4.as_ref()));
     }
 
+    #[test]
+    fn test_usage_with_engine_metrics_from_raw_response() {
+        // Malformed JSON: nothing to report.
+        assert_eq!(
+            usage_with_engine_metrics_from_raw_response("not json"),
+            None
+        );
+
+        // No usage and no extensions: nothing to report.
+        assert_eq!(
+            usage_with_engine_metrics_from_raw_response(
+                &json!({"id": "cmpl-1", "choices": []}).to_string()
+            ),
+            None
+        );
+
+        // Extension fields with no usage object still get reported.
+        assert_eq!(
+            usage_with_engine_metrics_from_raw_response(
+                &json!({"id": "cmpl-2", "choices": [], "queue_time": 0.02}).to_string()
+            ),
+            Some(json!({"queue_time": 0.02}))
+        );
+
+        // Usage plus extension fields are merged together.
+        assert_eq!(
+            usage_with_engine_metrics_from_raw_response(
+                &json!({
+                    "id": "cmpl-3",
+                    "choices": [],
+                    "usage": {"prompt_tokens": 1},
+                    "kv_cache_usage": 0.5,
+                })
+                .to_string()
+            ),
+            Some(json!({"prompt_tokens": 1, "kv_cache_usage": 0.5}))
+        );
+    }
+
     #[test]
     fn test_openai_provider_new_api_base_check() {
         let logs_contain = capture_logs();
@@ -0,0 +1,832 @@
+use std::borrow::Cow;
+
+use futures::future::try_join_all;
+use futures::{StreamExt, TryStreamExt};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::time::Instant;
+use url::Url;
+
+use super::openai::{
+    OpenAIRequestMessage, OpenAIResponse, OpenAIResponseChoice, OpenAISystemRequestMessage,
+    OpenAITool, OpenAIToolChoice, StreamOptions, get_chat_url, handle_openai_error, stream_openai,
+    tensorzero_to_openai_messages,
+};
+use crate::cache::ModelProviderRequest;
+use crate::endpoints::inference::InferenceCredentials;
+use crate::error::{DelayedError, DisplayOrDebugGateway, Error, ErrorDetails};
+use crate::http::TensorzeroHttpClient;
+use crate::inference::types::Thought;
+use crate::inference::types::batch::{BatchRequestRow, PollBatchInferenceResponse};
+use crate::inference::types::chat_completion_inference_params::{
+    ChatCompletionInferenceParamsV2, warn_inference_parameter_not_supported,
+};
+use crate::inference::types::usage::raw_usage_entries_from_value;
+use crate::inference::types::{
+    ApiType, ContentBlockOutput, Latency, ModelInferenceRequest, ModelInferenceRequestJsonMode,
+    PeekableProviderInferenceResponseStream, ProviderInferenceResponse,
+    ProviderInferenceResponseArgs, batch::StartBatchProviderInferenceResponse,
+};
+use crate::inference::{InferenceProvider, TensorZeroEventError};
+use crate::model::{Credential, ModelProvider};
+use crate::providers::helpers::{
+    inject_extra_request_data_and_send_eventsource_signed,
+    inject_extra_request_data_and_send_signed,
+};
+use crate::providers::openai::{OpenAIMessagesConfig, check_api_base_suffix};
+use crate::providers::request_signing::ResolvedRequestSigning;
+use uuid::Uuid;
+
+const PROVIDER_NAME: &str = "OpenAI-compatible";
+pub const PROVIDER_TYPE: &str = "openai_compatible";
+
+/// A generic provider for OpenAI-compatible inference APIs.
+///
+/// This exists so that new OpenAI-compatible vendors can be onboarded via config alone,
+/// rather than requiring a bespoke provider module (like `kie.rs` or `vllm.rs`) for every
+/// new endpoint that just speaks the OpenAI chat completions wire format with minor
+/// differences in which features it actually supports.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct OpenAICompatibleProvider {
+    model_name: String,
+    api_base: Url,
+    #[serde(skip)]
+    credentials: OpenAICompatibleCredentials,
+    supports_tools: bool,
+    supports_json_mode: bool,
+    supports_logprobs: bool,
+    reasoning_content_field: Option<String>,
+    #[serde(skip)]
+    request_signing: Option<ResolvedRequestSigning>,
+}
+
+impl OpenAICompatibleProvider {
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        model_name: String,
+        api_base: Url,
+        credentials: OpenAICompatibleCredentials,
+        supports_tools: bool,
+        supports_json_mode: bool,
+        supports_logprobs: bool,
+        reasoning_content_field: Option<String>,
+        request_signing: Option<ResolvedRequestSigning>,
+    ) -> Self {
+        // Check if the api_base has the `/chat/completions` suffix and warn if it does
+        check_api_base_suffix(&api_base);
+
+        OpenAICompatibleProvider {
+            model_name,
+            api_base,
+            credentials,
+            supports_tools,
+            supports_json_mode,
+            supports_logprobs,
+            reasoning_content_field,
+            request_signing,
+        }
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    pub fn supports_tools(&self) -> bool {
+        self.supports_tools
+    }
+
+    pub fn supports_json_mode(&self) -> bool {
+        self.supports_json_mode
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum OpenAICompatibleCredentials {
+    Static(SecretString),
+    Dynamic(String),
+    WithFallback {
+        default: Box<OpenAICompatibleCredentials>,
+        fallback: Box<OpenAICompatibleCredentials>,
+    },
+    None,
+}
+
+impl TryFrom<Credential> for OpenAICompatibleCredentials {
+    type Error = Error;
+
+    fn try_from(credentials: Credential) -> Result<Self, Error> {
+        match credentials {
+            Credential::Static(key) => Ok(OpenAICompatibleCredentials::Static(key)),
+            Credential::Dynamic(key_name) => Ok(OpenAICompatibleCredentials::Dynamic(key_name)),
+            Credential::None => Ok(OpenAICompatibleCredentials::None),
+            Credential::WithFallback { default, fallback } => {
+                Ok(OpenAICompatibleCredentials::WithFallback {
+                    default: Box::new((*default).try_into()?),
+                    fallback: Box::new((*fallback).try_into()?),
+                })
+            }
+            #[cfg(any(test, feature = "e2e_tests"))]
+            Credential::Missing => Ok(OpenAICompatibleCredentials::None),
+            _ => Err(Error::new(ErrorDetails::Config {
+                message: "Invalid api_key_location for OpenAI-compatible provider".to_string(),
+            })),
+        }
+    }
+}
+
+impl OpenAICompatibleCredentials {
+    fn get_api_key<'a>(
+        &'a self,
+        dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<Option<&'a SecretString>, DelayedError> {
+        match self {
+            OpenAICompatibleCredentials::Static(api_key) => Ok(Some(api_key)),
+            OpenAICompatibleCredentials::Dynamic(key_name) => {
+                Ok(Some(dynamic_api_keys.get(key_name).ok_or_else(|| {
+                    DelayedError::new(ErrorDetails::ApiKeyMissing {
+                        provider_name: PROVIDER_NAME.to_string(),
+                        message: format!("Dynamic api key `{key_name}` is missing"),
+                    })
+                })?))
+            }
+            OpenAICompatibleCredentials::WithFallback { default, fallback } => {
+                // Try default first, fall back to fallback if it fails
+                match default.get_api_key(dynamic_api_keys) {
+                    Ok(key) => Ok(key),
+                    Err(e) => {
+                        e.log_at_level(
+                            "Using fallback credential, as default credential is unavailable: ",
+                            tracing::Level::WARN,
+                        );
+                        fallback.get_api_key(dynamic_api_keys)
+                    }
+                }
+            }
+            OpenAICompatibleCredentials::None => Ok(None),
+        }
+    }
+}
+
+/// Key differences between this provider and the `openai` provider:
+/// - The set of supported capabilities (tools, JSON mode, logprobs, reasoning content) is
+///   declared in config via boolean flags, instead of being hardcoded per-vendor.
+/// - Unsupported capabilities are simply omitted from the request, rather than the provider
+///   module needing to know in advance which vendor it's talking to.
+impl InferenceProvider for OpenAICompatibleProvider {
+    async fn infer<'a>(
+        &'a self,
+        ModelProviderRequest {
+            request,
+            provider_name: _,
+            model_name,
+            otlp_config: _,
+            model_inference_id,
+        }: ModelProviderRequest<'a>,
+        http_client: &'a TensorzeroHttpClient,
+        dynamic_api_keys: &'a InferenceCredentials,
+        model_provider: &'a ModelProvider,
+    ) -> Result<ProviderInferenceResponse, Error> {
+        let request_body = serde_json::to_value(
+            OpenAICompatibleRequest::new(&self.model_name, request, self).await?,
+        )
+        .map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!(
+                    "Error serializing OpenAI-compatible request: {}",
+                    DisplayOrDebugGateway::new(e)
+                ),
+            })
+        })?;
+        let request_url = get_chat_url(&self.api_base)?;
+        let start_time = Instant::now();
+        let api_key = self
+            .credentials
+            .get_api_key(dynamic_api_keys)
+            .map_err(|e| e.log())?;
+        let mut request_builder = http_client.post(request_url);
+        if let Some(key) = api_key {
+            request_builder = request_builder.bearer_auth(key.expose_secret());
+        }
+        let (res, raw_request) = inject_extra_request_data_and_send_signed(
+            PROVIDER_TYPE,
+            &request.extra_body,
+            &request.extra_headers,
+            model_provider,
+            model_name,
+            request_body,
+            request_builder,
+            self.request_signing.as_ref(),
+            dynamic_api_keys,
+        )
+        .await?;
+
+        let latency = Latency::NonStreaming {
+            response_time: start_time.elapsed(),
+        };
+        if res.status().is_success() {
+            let raw_response = res.text().await.map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!("Error parsing response: {}", DisplayOrDebugGateway::new(e)),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: None,
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            let response_body = serde_json::from_str(&raw_response).map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!("Error parsing response: {}", DisplayOrDebugGateway::new(e)),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: Some(raw_response.clone()),
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            Ok(OpenAICompatibleResponseWithMetadata {
+                response: response_body,
+                latency,
+                raw_response,
+                raw_request,
+                generic_request: request,
+                model_inference_id,
+                reasoning_content_field: self.reasoning_content_field.as_deref(),
+            }
+            .try_into()?)
+        } else {
+            let status = res.status();
+            let raw_response = res.text().await.map_err(|e| {
+                Error::new(ErrorDetails::InferenceServer {
+                    message: format!(
+                        "Error parsing error response: {}",
+                        DisplayOrDebugGateway::new(e)
+                    ),
+                    raw_request: Some(raw_request.clone()),
+                    raw_response: None,
+                    provider_type: PROVIDER_TYPE.to_string(),
+                })
+            })?;
+            Err(handle_openai_error(
+                &raw_request,
+                status,
+                &raw_response,
+                PROVIDER_TYPE,
+                None,
+            ))
+        }
+    }
+
+    async fn infer_stream<'a>(
+        &'a self,
+        ModelProviderRequest {
+            request,
+            provider_name: _,
+            model_name,
+            otlp_config: _,
+            model_inference_id,
+        }: ModelProviderRequest<'a>,
+        http_client: &'a TensorzeroHttpClient,
+        dynamic_api_keys: &'a InferenceCredentials,
+        model_provider: &'a ModelProvider,
+    ) -> Result<(PeekableProviderInferenceResponseStream, String), Error> {
+        let request_body = serde_json::to_value(
+            OpenAICompatibleRequest::new(&self.model_name, request, self).await?,
+        )
+        .map_err(|e| {
+            Error::new(ErrorDetails::Serialization {
+                message: format!(
+                    "Error serializing OpenAI-compatible request: {}",
+                    DisplayOrDebugGateway::new(e)
+                ),
+            })
+        })?;
+
+        let api_key = self
+            .credentials
+            .get_api_key(dynamic_api_keys)
+            .map_err(|e| e.log())?;
+        let request_url = get_chat_url(&self.api_base)?;
+        let start_time = Instant::now();
+        let mut request_builder = http_client.post(request_url);
+        if let Some(key) = api_key {
+            request_builder = request_builder.bearer_auth(key.expose_secret());
+        }
+        let (event_source, raw_request) = inject_extra_request_data_and_send_eventsource_signed(
+            PROVIDER_TYPE,
+            &request.extra_body,
+            &request.extra_headers,
+            model_provider,
+            model_name,
+            request_body,
+            request_builder,
+            self.request_signing.as_ref(),
+            dynamic_api_keys,
+        )
+        .await?;
+        let stream = stream_openai(
+            PROVIDER_TYPE.to_string(),
+            model_inference_id,
+            event_source.map_err(TensorZeroEventError::EventSource),
+            start_time,
+            None,
+            &raw_request,
+        )
+        .peekable();
+        Ok((stream, raw_request))
+    }
+
+    async fn start_batch_inference<'a>(
+        &'a self,
+        _requests: &'a [ModelInferenceRequest<'_>],
+        _client: &'a TensorzeroHttpClient,
+        _dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<StartBatchProviderInferenceResponse, Error> {
+        Err(ErrorDetails::UnsupportedModelProviderForBatchInference {
+            provider_type: PROVIDER_TYPE.to_string(),
+        }
+        .into())
+    }
+
+    async fn poll_batch_inference<'a>(
+        &'a self,
+        _batch_request: &'a BatchRequestRow<'a>,
+        _http_client: &'a TensorzeroHttpClient,
+        _dynamic_api_keys: &'a InferenceCredentials,
+    ) -> Result<PollBatchInferenceResponse, Error> {
+        Err(ErrorDetails::UnsupportedModelProviderForBatchInference {
+            provider_type: PROVIDER_TYPE.to_string(),
+        }
+        .into())
+    }
+}
+
+/// This struct defines the supported parameters for a generic OpenAI-compatible inference API.
+/// `tools`/`tool_choice`/`parallel_tool_calls` and `guided_json` (JSON mode) are only populated
+/// when the provider config enables the corresponding capability flag, and `logprobs` is only
+/// set when `supports_logprobs` is enabled.
+#[derive(Debug, Serialize)]
+#[cfg_attr(test, derive(Default))]
+struct OpenAICompatibleRequest<'a> {
+    messages: Vec<OpenAIRequestMessage<'a>>,
+    model: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guided_json: Option<&'a Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Cow<'a, [String]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<OpenAITool<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<OpenAIToolChoice<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logprobs: Option<bool>,
+}
+
+type PreparedOpenAICompatibleToolsResult<'a> = (
+    Option<Vec<OpenAITool<'a>>>,
+    Option<OpenAIToolChoice<'a>>,
+    Option<bool>,
+);
+
+/// If there are no tools passed or the tools are empty, return None for both tools and tool_choice
+/// Otherwise convert the tool choice and tools to OpenAI-compatible format
+fn prepare_openai_compatible_tools<'a>(
+    request: &'a ModelInferenceRequest,
+) -> Result<PreparedOpenAICompatibleToolsResult<'a>, Error> {
+    match &request.tool_config {
+        None => Ok((None, None, None)),
+        Some(tool_config) => {
+            if !tool_config.any_tools_available() {
+                return Ok((None, None, None));
+            }
+            let tools = Some(
+                tool_config
+                    .strict_tools_available()?
+                    .map(Into::into)
+                    .collect(),
+            );
+            let parallel_tool_calls = tool_config.parallel_tool_calls;
+            let tool_choice = Some((&tool_config.tool_choice).into());
+            Ok((tools, tool_choice, parallel_tool_calls))
+        }
+    }
+}
+
+fn apply_inference_params(
+    _request: &mut OpenAICompatibleRequest,
+    inference_params: &ChatCompletionInferenceParamsV2,
+) {
+    let ChatCompletionInferenceParamsV2 {
+        reasoning_effort,
+        service_tier,
+        thinking_budget_tokens,
+        verbosity,
+    } = inference_params;
+
+    if reasoning_effort.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "reasoning_effort", None);
+    }
+
+    if service_tier.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "service_tier", None);
+    }
+
+    if thinking_budget_tokens.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "thinking_budget_tokens", None);
+    }
+
+    if verbosity.is_some() {
+        warn_inference_parameter_not_supported(PROVIDER_NAME, "verbosity", None);
+    }
+}
+
+impl<'a> OpenAICompatibleRequest<'a> {
+    pub async fn new(
+        model: &'a str,
+        request: &'a ModelInferenceRequest<'_>,
+        provider: &'a OpenAICompatibleProvider,
+    ) -> Result<OpenAICompatibleRequest<'a>, Error> {
+        let guided_json = if provider.supports_json_mode {
+            match (&request.json_mode, request.output_schema) {
+                (
+                    ModelInferenceRequestJsonMode::On | ModelInferenceRequestJsonMode::Strict,
+                    Some(schema),
+                ) => Some(schema),
+                _ => None,
+            }
+        } else {
+            if !matches!(request.json_mode, ModelInferenceRequestJsonMode::Off) {
+                warn_inference_parameter_not_supported(PROVIDER_NAME, "json_mode", None);
+            }
+            None
+        };
+        let stream_options = if request.stream {
+            Some(StreamOptions {
+                include_usage: true,
+            })
+        } else {
+            None
+        };
+        let messages = prepare_openai_compatible_messages(
+            request,
+            OpenAIMessagesConfig {
+                json_mode: Some(&request.json_mode),
+                provider_type: PROVIDER_TYPE,
+                fetch_and_encode_input_files_before_inference: request
+                    .fetch_and_encode_input_files_before_inference,
+            },
+        )
+        .await?;
+
+        let (tools, tool_choice, parallel_tool_calls) = if provider.supports_tools {
+            prepare_openai_compatible_tools(request)?
+        } else {
+            if request
+                .tool_config
+                .as_ref()
+                .is_some_and(|c| c.any_tools_available())
+            {
+                warn_inference_parameter_not_supported(PROVIDER_NAME, "tools", None);
+            }
+            (None, None, None)
+        };
+
+        let mut openai_compatible_request = OpenAICompatibleRequest {
+            messages,
+            model,
+            temperature: request.temperature,
+            top_p: request.top_p,
+            presence_penalty: request.presence_penalty,
+            frequency_penalty: request.frequency_penalty,
+            max_tokens: request.max_tokens,
+            stream: request.stream,
+            stream_options,
+            guided_json,
+            seed: request.seed,
+            stop: request.borrow_stop_sequences(),
+            tools,
+            tool_choice,
+            parallel_tool_calls,
+            logprobs: provider.supports_logprobs.then_some(true),
+        };
+
+        apply_inference_params(&mut openai_compatible_request, &request.inference_params_v2);
+
+        Ok(openai_compatible_request)
+    }
+}
+
+struct OpenAICompatibleResponseWithMetadata<'a> {
+    response: OpenAIResponse,
+    latency: Latency,
+    raw_response: String,
+    raw_request: String,
+    generic_request: &'a ModelInferenceRequest<'a>,
+    model_inference_id: Uuid,
+    reasoning_content_field: Option<&'a str>,
+}
+
+impl<'a> TryFrom<OpenAICompatibleResponseWithMetadata<'a>> for ProviderInferenceResponse {
+    type Error = Error;
+    fn try_from(value: OpenAICompatibleResponseWithMetadata<'a>) -> Result<Self, Self::Error> {
+        let OpenAICompatibleResponseWithMetadata {
+            mut response,
+            latency,
+            raw_response,
+            raw_request,
+            generic_request,
+            model_inference_id,
+            reasoning_content_field,
+        } = value;
+
+        if response.choices.len() != 1 {
+            return Err(ErrorDetails::InferenceServer {
+                message: format!(
+                    "Response has invalid number of choices: {}. Expected 1.",
+                    response.choices.len()
+                ),
+                raw_request: Some(raw_request.clone()),
+                raw_response: Some(raw_response.clone()),
+                provider_type: PROVIDER_TYPE.to_string(),
+            }
+            .into());
+        }
+        let OpenAIResponseChoice {
+            message,
+            finish_reason,
+            ..
+        } = response
+            .choices
+            .pop()
+            .ok_or_else(|| Error::new(ErrorDetails::InferenceServer {
+                message: "Response has no choices (this should never happen). Please file a bug report: https://github.com/tensorzero/tensorzero/issues/new".to_string(),
+                provider_type: PROVIDER_TYPE.to_string(),
+                raw_request: Some(raw_request.clone()),
+                raw_response: Some(raw_response.clone()),
+            }))?;
+        let mut content: Vec<ContentBlockOutput> = Vec::new();
+        // Unlike `deepseek.rs`, the reasoning-content field name isn't hardcoded: we look it up
+        // by the configured `reasoning_content_field` directly in the raw response JSON, since
+        // `OpenAIResponseMessage` only knows about the field names OpenAI itself uses.
+        if let Some(field_name) = reasoning_content_field {
+            if let Some(reasoning) = extract_reasoning_content(&raw_response, field_name) {
+                content.push(ContentBlockOutput::Thought(Thought {
+                    text: Some(reasoning),
+                    signature: None,
+                    summary: None,
+                    provider_type: Some(PROVIDER_TYPE.to_string()),
+                    extra_data: None,
+                }));
+            }
+        }
+        if let Some(text) = message.content {
+            content.push(text.into());
+        }
+        if let Some(tool_calls) = message.tool_calls {
+            for tool_call in tool_calls {
+                content.push(ContentBlockOutput::ToolCall(tool_call.into()));
+            }
+        }
+        let raw_usage = openai_compatible_usage_from_raw_response(&raw_response).map(|usage| {
+            raw_usage_entries_from_value(
+                model_inference_id,
+                PROVIDER_TYPE,
+                ApiType::ChatCompletions,
+                usage,
+            )
+        });
+        let usage = response.usage.into();
+        let system = generic_request.system.clone();
+        let input_messages = generic_request.messages.clone();
+        Ok(ProviderInferenceResponse::new(
+            ProviderInferenceResponseArgs {
+                output: content,
+                system,
+                input_messages,
+                raw_request,
+                raw_response: raw_response.clone(),
+                usage,
+                raw_usage,
+                relay_raw_response: None,
+                provider_latency: latency,
+                finish_reason: Some(finish_reason.into()),
+                id: model_inference_id,
+            },
+        ))
+    }
+}
+
+/// Looks up `field_name` on the first message of the first choice in the raw response JSON.
+/// This only inspects the non-streaming response shape; streaming reasoning content is not
+/// currently extracted for this provider.
+fn extract_reasoning_content(raw_response: &str, field_name: &str) -> Option<String> {
+    let value = serde_json::from_str::<Value>(raw_response).ok()?;
+    value
+        .get("choices")?
+        .as_array()?
+        .first()?
+        .get("message")?
+        .get(field_name)?
+        .as_str()
+        .map(str::to_string)
+}
+
+fn openai_compatible_usage_from_raw_response(raw_response: &str) -> Option<Value> {
+    serde_json::from_str::<Value>(raw_response)
+        .ok()
+        .and_then(|value| value.get("usage").filter(|v| !v.is_null()).cloned())
+}
+
+async fn prepare_openai_compatible_messages<'a>(
+    request: &'a ModelInferenceRequest<'_>,
+    config: OpenAIMessagesConfig<'a>,
+) -> Result<Vec<OpenAIRequestMessage<'a>>, Error> {
+    let mut messages: Vec<_> = try_join_all(
+        request
+            .messages
+            .iter()
+            .map(|msg| tensorzero_to_openai_messages(msg, config)),
+    )
+    .await?
+    .into_iter()
+    .flatten()
+    .collect();
+    if let Some(system_msg) =
+        tensorzero_to_openai_compatible_system_message(request.system.as_deref())
+    {
+        messages.insert(0, system_msg);
+    }
+    Ok(messages)
+}
+
+fn tensorzero_to_openai_compatible_system_message(
+    system: Option<&str>,
+) -> Option<OpenAIRequestMessage<'_>> {
+    system.map(|instructions| {
+        OpenAIRequestMessage::System(OpenAISystemRequestMessage {
+            content: Cow::Borrowed(instructions),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        error::ErrorDetails,
+        inference::types::{FunctionType, ModelInferenceRequestJsonMode, RequestMessage, Role},
+        providers::test_helpers::WEATHER_TOOL_CONFIG,
+    };
+
+    fn test_provider(
+        supports_tools: bool,
+        supports_json_mode: bool,
+        supports_logprobs: bool,
+        reasoning_content_field: Option<String>,
+    ) -> OpenAICompatibleProvider {
+        OpenAICompatibleProvider::new(
+            "test-model".to_string(),
+            Url::parse("http://localhost:1234/v1/").unwrap(),
+            OpenAICompatibleCredentials::None,
+            supports_tools,
+            supports_json_mode,
+            supports_logprobs,
+            reasoning_content_field,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_openai_compatible_request_capability_flags() {
+        let output_schema = json!({
+            "type": "object",
+            "properties": {"location": {"type": "string"}},
+        });
+        let request = ModelInferenceRequest {
+            inference_id: Uuid::now_v7(),
+            messages: vec![RequestMessage {
+                role: Role::User,
+                content: vec!["What's the weather?".to_string().into()],
+            }],
+            system: None,
+            stream: false,
+            json_mode: ModelInferenceRequestJsonMode::On,
+            tool_config: Some(Cow::Borrowed(&WEATHER_TOOL_CONFIG)),
+            function_type: FunctionType::Chat,
+            output_schema: Some(&output_schema),
+            extra_body: Default::default(),
+            ..Default::default()
+        };
+
+        // All capabilities enabled: tools, JSON mode, and logprobs are all sent.
+        let enabled_provider = test_provider(true, true, true, None);
+        let enabled_request =
+            OpenAICompatibleRequest::new("test-model", &request, &enabled_provider)
+                .await
+                .unwrap();
+        assert!(
+            enabled_request.tools.is_some(),
+            "tools should be populated when supports_tools is true"
+        );
+        assert_eq!(
+            enabled_request.guided_json,
+            Some(&output_schema),
+            "guided_json should be populated when supports_json_mode is true"
+        );
+        assert_eq!(
+            enabled_request.logprobs,
+            Some(true),
+            "logprobs should be requested when supports_logprobs is true"
+        );
+
+        // All capabilities disabled: the corresponding fields are omitted entirely.
+        let disabled_provider = test_provider(false, false, false, None);
+        let disabled_request =
+            OpenAICompatibleRequest::new("test-model", &request, &disabled_provider)
+                .await
+                .unwrap();
+        assert!(
+            disabled_request.tools.is_none(),
+            "tools should be omitted when supports_tools is false"
+        );
+        assert_eq!(
+            disabled_request.guided_json, None,
+            "guided_json should be omitted when supports_json_mode is false"
+        );
+        assert_eq!(
+            disabled_request.logprobs, None,
+            "logprobs should be omitted when supports_logprobs is false"
+        );
+    }
+
+    #[test]
+    fn test_credential_to_openai_compatible_credentials() {
+        let generic = Credential::Static(SecretString::from("test_key"));
+        let creds = OpenAICompatibleCredentials::try_from(generic).unwrap();
+        assert!(
+            matches!(creds, OpenAICompatibleCredentials::Static(_)),
+            "a static credential should convert to OpenAICompatibleCredentials::Static"
+        );
+
+        let generic = Credential::Dynamic("key_name".to_string());
+        let creds = OpenAICompatibleCredentials::try_from(generic).unwrap();
+        assert!(
+            matches!(creds, OpenAICompatibleCredentials::Dynamic(_)),
+            "a dynamic credential should convert to OpenAICompatibleCredentials::Dynamic"
+        );
+
+        let generic = Credential::FileContents(SecretString::from("test"));
+        let result = OpenAICompatibleCredentials::try_from(generic);
+        assert!(
+            result.is_err(),
+            "an unsupported credential type should be rejected"
+        );
+        assert!(matches!(
+            result.unwrap_err().get_details(),
+            ErrorDetails::Config { message } if message.contains("Invalid api_key_location")
+        ));
+    }
+
+    #[test]
+    fn test_extract_reasoning_content() {
+        let raw_response = json!({
+            "choices": [{
+                "message": {
+                    "content": "the answer",
+                    "reasoning": "because I said so",
+                }
+            }]
+        })
+        .to_string();
+
+        assert_eq!(
+            extract_reasoning_content(&raw_response, "reasoning"),
+            Some("because I said so".to_string()),
+            "should extract the value at the configured field name"
+        );
+        assert_eq!(
+            extract_reasoning_content(&raw_response, "missing_field"),
+            None,
+            "should return None when the configured field is absent"
+        );
+    }
+}
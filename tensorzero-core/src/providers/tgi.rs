@@ -27,7 +27,7 @@ use url::Url;
 use super::helpers::convert_stream_error;
 use super::openai::{
     OpenAIRequestMessage, OpenAIToolType, StreamOptions, SystemOrDeveloper, get_chat_url,
-    prepare_openai_messages,
+    prepare_openai_messages, usage_with_engine_metrics_from_raw_response,
 };
 use crate::cache::ModelProviderRequest;
 use crate::endpoints::inference::InferenceCredentials;
@@ -858,9 +858,7 @@ fn tgi_to_tensorzero_chunk(
 }
 
 fn tgi_usage_from_raw_response(raw_response: &str) -> Option<Value> {
-    serde_json::from_str::<Value>(raw_response)
-        .ok()
-        .and_then(|value| value.get("usage").filter(|v| !v.is_null()).cloned())
+    usage_with_engine_metrics_from_raw_response(raw_response)
 }
 
 #[cfg(test)]
@@ -1212,4 +1210,24 @@ mod tests {
             "TGI does not support the inference parameter `verbosity`"
         ));
     }
+
+    #[test]
+    fn test_tgi_usage_from_raw_response_merges_engine_metrics() {
+        let raw_response = json!({
+            "id": "cmpl-1",
+            "choices": [],
+            "usage": {"prompt_tokens": 8, "completion_tokens": 3},
+            "queue_time": 0.007,
+        })
+        .to_string();
+        assert_eq!(
+            tgi_usage_from_raw_response(&raw_response),
+            Some(json!({
+                "prompt_tokens": 8,
+                "completion_tokens": 3,
+                "queue_time": 0.007,
+            })),
+            "engine metrics attached to the response should be merged into raw usage"
+        );
+    }
 }
@@ -11,7 +11,7 @@ use url::Url;
 use super::openai::{
     OpenAIRequestMessage, OpenAIResponse, OpenAIResponseChoice, OpenAISystemRequestMessage,
     OpenAITool, OpenAIToolChoice, StreamOptions, get_chat_url, handle_openai_error, stream_openai,
-    tensorzero_to_openai_messages,
+    tensorzero_to_openai_messages, usage_with_engine_metrics_from_raw_response,
 };
 use crate::cache::ModelProviderRequest;
 use crate::endpoints::inference::InferenceCredentials;
@@ -556,9 +556,7 @@ impl<'a> TryFrom<VLLMResponseWithMetadata<'a>> for ProviderInferenceResponse {
 }
 
 fn vllm_usage_from_raw_response(raw_response: &str) -> Option<Value> {
-    serde_json::from_str::<Value>(raw_response)
-        .ok()
-        .and_then(|value| value.get("usage").filter(|v| !v.is_null()).cloned())
+    usage_with_engine_metrics_from_raw_response(raw_response)
 }
 
 pub(super) async fn prepare_vllm_messages<'a>(
@@ -955,4 +953,49 @@ mod tests {
             "vLLM does not support the inference parameter `verbosity`"
         ));
     }
+
+    #[test]
+    fn test_vllm_usage_from_raw_response_merges_engine_metrics() {
+        // Standard usage, no engine metrics: pass through unchanged.
+        let raw_response = json!({
+            "id": "cmpl-1",
+            "object": "chat.completion",
+            "choices": [],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 5},
+        })
+        .to_string();
+        assert_eq!(
+            vllm_usage_from_raw_response(&raw_response),
+            Some(json!({"prompt_tokens": 10, "completion_tokens": 5})),
+            "usage should be forwarded unchanged when there are no extra top-level fields"
+        );
+
+        // Engine metrics attached alongside the standard fields get merged into usage.
+        let raw_response = json!({
+            "id": "cmpl-2",
+            "choices": [],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 5},
+            "kv_cache_usage": 0.42,
+            "queue_time": 0.013,
+        })
+        .to_string();
+        assert_eq!(
+            vllm_usage_from_raw_response(&raw_response),
+            Some(json!({
+                "prompt_tokens": 10,
+                "completion_tokens": 5,
+                "kv_cache_usage": 0.42,
+                "queue_time": 0.013,
+            })),
+            "engine metrics attached to the response should be merged into raw usage"
+        );
+
+        // No usage and no extra fields: nothing to report.
+        let raw_response = json!({"id": "cmpl-3", "choices": []}).to_string();
+        assert_eq!(
+            vllm_usage_from_raw_response(&raw_response),
+            None,
+            "should return None when there is neither usage nor engine metrics"
+        );
+    }
 }
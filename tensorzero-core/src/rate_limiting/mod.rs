@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use axum::Extension;
+use metrics::{counter, gauge};
 use serde::{Deserialize, Serialize, Serializer};
 use tracing::Span;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
@@ -132,6 +133,10 @@ impl Default for RateLimitingConfig {
 pub struct ScopeInfo {
     pub tags: Arc<HashMap<String, String>>,
     pub api_key_public_id: Option<Arc<str>>,
+    /// The `tensorzero::model_name` of the model provider currently being called, if known.
+    /// This is `None` at the point `ScopeInfo` is first constructed (the endpoint hasn't picked
+    /// a model yet), and is filled in by `with_model_name` once model selection has happened.
+    pub model_name: Option<Arc<str>>,
 }
 
 impl ScopeInfo {
@@ -142,6 +147,17 @@ impl ScopeInfo {
         Self {
             tags,
             api_key_public_id: api_key.map(|ext| ext.0.api_key.get_public_id().into()),
+            model_name: None,
+        }
+    }
+
+    /// Returns a copy of this `ScopeInfo` scoped to a specific model, so that per-model
+    /// rate limits (`RateLimitingConfigScope::ModelName`) can be evaluated for this request.
+    pub(crate) fn with_model_name(&self, model_name: &str) -> Self {
+        Self {
+            tags: Arc::clone(&self.tags),
+            api_key_public_id: self.api_key_public_id.clone(),
+            model_name: Some(Arc::from(model_name)),
         }
     }
 
@@ -150,6 +166,7 @@ impl ScopeInfo {
         let ScopeInfo {
             tags,
             api_key_public_id,
+            model_name,
         } = self;
         for (key, value) in tags.iter() {
             span.set_attribute(format!("scope_info.tags.{key}"), value.clone());
@@ -157,6 +174,9 @@ impl ScopeInfo {
         if let Some(api_key_public_id) = api_key_public_id {
             span.set_attribute("scope_info.api_key_public_id", api_key_public_id.clone());
         }
+        if let Some(model_name) = model_name {
+            span.set_attribute("scope_info.model_name", model_name.clone());
+        }
     }
 }
 
@@ -291,6 +311,13 @@ fn get_failed_rate_limits_err(
             ),
         }.into();
     }
+    for failed in &failed_rate_limits {
+        counter!(
+            "tensorzero_rate_limit_exceeded_total",
+            "resource" => failed.resource.as_str(),
+        )
+        .increment(1);
+    }
     ErrorDetails::RateLimitExceeded { failed_rate_limits }.into()
 }
 
@@ -590,7 +617,7 @@ trait Scope {
 pub enum RateLimitingConfigScope {
     Tag(TagRateLimitingConfigScope),
     ApiKeyPublicId(ApiKeyPublicIdConfigScope),
-    // model_name = "my_model"
+    ModelName(ModelNameConfigScope),
     // function_name = "my_function"
 }
 
@@ -601,6 +628,7 @@ impl Scope for RateLimitingConfigScope {
             RateLimitingConfigScope::ApiKeyPublicId(api_key_public_id) => {
                 api_key_public_id.get_key_if_matches(info)
             }
+            RateLimitingConfigScope::ModelName(model_name) => model_name.get_key_if_matches(info),
         }
     }
 }
@@ -724,6 +752,53 @@ impl Serialize for ApiKeyPublicIdValueScope {
     }
 }
 
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ModelNameConfigScope {
+    model_name: ModelNameValueScope,
+}
+
+impl ModelNameConfigScope {
+    fn get_key_if_matches<'a>(&'a self, info: &'a ScopeInfo) -> Option<RateLimitingScopeKey> {
+        let model_name = info.model_name.as_deref()?;
+        match self.model_name {
+            ModelNameValueScope::Concrete(ref expected_value) => {
+                if model_name == expected_value {
+                    Some(RateLimitingScopeKey::ModelNameConcrete {
+                        model_name: model_name.to_string(),
+                    })
+                } else {
+                    None
+                }
+            }
+            ModelNameValueScope::Each => Some(RateLimitingScopeKey::ModelNameEach {
+                model_name: model_name.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum ModelNameValueScope {
+    Concrete(String),
+    Each,
+}
+
+impl Serialize for ModelNameValueScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ModelNameValueScope::Concrete(s) => serializer.serialize_str(s),
+            ModelNameValueScope::Each => serializer.serialize_str("tensorzero::each"),
+        }
+    }
+}
+
 /// Type that lists the different ways a Scope + matching ScopeInfo can be
 /// serialized into a key.
 /// We need this struct to have stable serialization behavior because we want rate limits to be stable
@@ -736,6 +811,8 @@ pub enum RateLimitingScopeKey {
     TagConcrete { key: String, value: String },
     ApiKeyPublicIdEach { api_key_public_id: Arc<str> },
     ApiKeyPublicIdConcrete { api_key_public_id: Arc<str> },
+    ModelNameEach { model_name: String },
+    ModelNameConcrete { model_name: String },
 }
 
 impl RateLimitingScopeKey {
@@ -757,6 +834,12 @@ impl RateLimitingScopeKey {
             RateLimitingScopeKey::ApiKeyPublicIdConcrete { api_key_public_id } => {
                 format!(r#"api_key_public_id="{api_key_public_id}""#)
             }
+            RateLimitingScopeKey::ModelNameEach { model_name } => {
+                format!(r#"model_name="tensorzero::each" (matched: "{model_name}")"#)
+            }
+            RateLimitingScopeKey::ModelNameConcrete { model_name } => {
+                format!(r#"model_name="{model_name}""#)
+            }
         }
     }
 }
@@ -808,7 +891,7 @@ impl TicketBorrows {
             }));
         }
         let receipts = align_and_check_limits(&active_limits, results, ticket_requests)?;
-        let borrows = receipts
+        let borrows: Vec<TicketBorrow> = receipts
             .into_iter()
             .zip(active_limits)
             .map(|(receipt, active_limit)| TicketBorrow {
@@ -817,6 +900,21 @@ impl TicketBorrows {
             })
             .collect();
 
+        // Surface the current bucket state on the gateway's Prometheus `/metrics` endpoint.
+        for borrow in &borrows {
+            let resource = borrow.active_limit.limit.resource.as_str();
+            counter!(
+                "tensorzero_rate_limit_tickets_consumed_total",
+                "resource" => resource,
+            )
+            .increment(borrow.receipt.tickets_consumed);
+            gauge!(
+                "tensorzero_rate_limit_tickets_remaining",
+                "resource" => resource,
+            )
+            .set(borrow.receipt.tickets_remaining as f64);
+        }
+
         Ok(Self {
             pool_manager,
             borrows,
@@ -886,6 +984,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let key = scope.get_key_if_matches(&info).unwrap();
@@ -911,6 +1010,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let key = scope.get_key_if_matches(&info);
@@ -930,6 +1030,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let key = scope.get_key_if_matches(&info).unwrap();
@@ -955,6 +1056,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let key = scope.get_key_if_matches(&info).unwrap();
@@ -978,6 +1080,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let key = scope.get_key_if_matches(&info);
@@ -992,6 +1095,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let keys = scopes.get_key_if_matches(&info).unwrap();
@@ -1012,6 +1116,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let keys = scopes.get_key_if_matches(&info).unwrap();
@@ -1040,6 +1145,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let keys = scopes.get_key_if_matches(&info);
@@ -1065,6 +1171,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let keys = scopes.get_key_if_matches(&info).unwrap();
@@ -1106,6 +1213,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         // Should return None because not all scopes match
@@ -1134,6 +1242,7 @@ mod tests {
         let info1 = ScopeInfo {
             tags: Arc::new(tags1),
             api_key_public_id: None,
+            model_name: None,
         };
 
         // Second ScopeInfo with different tag values but same structure
@@ -1144,6 +1253,7 @@ mod tests {
         let info2 = ScopeInfo {
             tags: Arc::new(tags2),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let keys1 = scopes.get_key_if_matches(&info1).unwrap();
@@ -1320,6 +1430,7 @@ mod tests {
         let info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         // Test each scope individually to verify different key types
@@ -1426,6 +1537,7 @@ mod tests {
         let scope_info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         // Disabled config should return empty limits
@@ -1497,6 +1609,7 @@ mod tests {
         let scope_info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         let token_limit = Arc::new(RateLimit {
@@ -1788,6 +1901,7 @@ mod tests {
         let scope_info = ScopeInfo {
             tags: Arc::new(tags),
             api_key_public_id: None,
+            model_name: None,
         };
 
         // Token rate limits active - should include Token resource
@@ -326,6 +326,7 @@ mod tests {
         ScopeInfo {
             tags: Arc::new(tags_map),
             api_key_public_id: None,
+            model_name: None,
         }
     }
 
@@ -525,6 +525,7 @@ impl TensorzeroRelay {
             internal_dynamic_variant_config: None,
             episode_id: None,
             dryrun: None,
+            timeout_ms: None,
             // Filter out internal tags (those starting with "tensorzero::") before forwarding
             // to the downstream gateway, as they will be rejected by tag validation
             tags: clients
@@ -539,6 +540,7 @@ impl TensorzeroRelay {
             include_original_response: false,
             include_raw_response: clients.include_raw_response,
             include_raw_usage: clients.include_raw_usage,
+            include_snapshot_hash: false,
             api_key,
         };
         Ok(res)
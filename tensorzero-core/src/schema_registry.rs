@@ -0,0 +1,269 @@
+//! A registry of function input/output JSON Schemas that are versioned
+//! independently of config snapshots.
+//!
+//! Function schemas normally live inline in `tensorzero.toml` and change
+//! whenever the config snapshot changes. This registry lets a schema be
+//! updated in place while recording every previous version, so that older
+//! clients (which may have been generated against an earlier schema) keep
+//! working, and so that the schema version used for a given inference can be
+//! recorded for later auditing.
+//!
+//! This is deliberately a standalone module - it isn't wired into
+//! `FunctionConfig`'s schema fields or the inference write path, since doing
+//! so would touch the config snapshot and ClickHouse insert schemas in ways
+//! that need their own dedicated design.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Error, ErrorDetails};
+
+pub type SchemaVersion = u32;
+
+/// The result of comparing two versions of a JSON Schema for a function's
+/// input or output.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum SchemaCompatibility {
+    /// Every instance that validated against the old schema still validates
+    /// against the new schema (existing clients can read new data).
+    Backward,
+    /// Every instance that validates against the new schema also validates
+    /// against the old schema (old clients can read new data).
+    Forward,
+    /// Both backward and forward compatible.
+    Full,
+    /// Neither: existing clients or existing data may break.
+    Breaking,
+}
+
+/// A single registered version of a function's input or output schema.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct RegisteredSchema {
+    pub version: SchemaVersion,
+    pub schema: Value,
+}
+
+/// Registers and versions JSON schemas for a single function's input or
+/// output, independently of the surrounding config snapshot.
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    // Keyed by (function_name, is_output) so that a function's input and
+    // output schemas are versioned independently of each other.
+    versions: RwLock<HashMap<(String, bool), Vec<RegisteredSchema>>>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `schema` as the next version for `function_name`, checking
+    /// its compatibility with the current latest version (if any). Returns
+    /// the new version number and the computed compatibility, without
+    /// rejecting breaking changes - callers decide whether a breaking change
+    /// is acceptable for their use case.
+    pub fn register(
+        &self,
+        function_name: &str,
+        is_output: bool,
+        schema: Value,
+    ) -> Result<(SchemaVersion, Option<SchemaCompatibility>), Error> {
+        let mut versions = self.versions.write().map_err(|_| {
+            Error::new(ErrorDetails::InternalError {
+                message: "Schema registry lock was poisoned".to_string(),
+            })
+        })?;
+        let entries = versions
+            .entry((function_name.to_string(), is_output))
+            .or_default();
+
+        let compatibility = entries
+            .last()
+            .map(|previous| check_compatibility(&previous.schema, &schema));
+
+        let version = entries.last().map_or(1, |previous| previous.version + 1);
+        entries.push(RegisteredSchema { version, schema });
+        Ok((version, compatibility))
+    }
+
+    /// Looks up a specific version of a function's schema, or the latest
+    /// registered version if `version` is `None`.
+    pub fn get(
+        &self,
+        function_name: &str,
+        is_output: bool,
+        version: Option<SchemaVersion>,
+    ) -> Result<Option<RegisteredSchema>, Error> {
+        let versions = self.versions.read().map_err(|_| {
+            Error::new(ErrorDetails::InternalError {
+                message: "Schema registry lock was poisoned".to_string(),
+            })
+        })?;
+        let Some(entries) = versions.get(&(function_name.to_string(), is_output)) else {
+            return Ok(None);
+        };
+        Ok(match version {
+            Some(version) => entries.iter().find(|e| e.version == version).cloned(),
+            None => entries.last().cloned(),
+        })
+    }
+}
+
+/// A heuristic compatibility check between two versions of a JSON Schema,
+/// based on `required` fields and declared property types. This isn't a
+/// full JSON Schema diff - it's meant to catch the common breaking changes
+/// (removing/retyping a property, adding a new required property).
+fn check_compatibility(old_schema: &Value, new_schema: &Value) -> SchemaCompatibility {
+    let old_required = required_fields(old_schema);
+    let new_required = required_fields(new_schema);
+    let old_properties = property_types(old_schema);
+    let new_properties = property_types(new_schema);
+
+    // Backward compatible (old data still validates under the new schema):
+    // the new schema can't require anything the old schema didn't, and any
+    // property present in both must keep the same type.
+    let backward = new_required.is_subset(&old_required)
+        && old_properties
+            .iter()
+            .all(|(name, ty)| new_properties.get(name).is_none_or(|new_ty| new_ty == ty));
+
+    // Forward compatible (new data still validates under the old schema):
+    // the old schema can't require anything the new schema doesn't, and any
+    // shared property must keep the same type.
+    let forward = old_required.is_subset(&new_required)
+        && new_properties
+            .iter()
+            .all(|(name, ty)| old_properties.get(name).is_none_or(|old_ty| old_ty == ty));
+
+    match (backward, forward) {
+        (true, true) => SchemaCompatibility::Full,
+        (true, false) => SchemaCompatibility::Backward,
+        (false, true) => SchemaCompatibility::Forward,
+        (false, false) => SchemaCompatibility::Breaking,
+    }
+}
+
+fn required_fields(schema: &Value) -> std::collections::HashSet<String> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn property_types(schema: &Value) -> HashMap<String, String> {
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .filter_map(|(name, definition)| {
+                    let ty = definition.get("type")?.as_str()?.to_string();
+                    Some((name.clone(), ty))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_versions_schema() {
+        let registry = SchemaRegistry::new();
+        let (version, compatibility) = registry
+            .register(
+                "my_function",
+                false,
+                serde_json::json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+            )
+            .unwrap();
+        assert_eq!(
+            version, 1,
+            "the first registered schema should be version 1"
+        );
+        assert!(
+            compatibility.is_none(),
+            "there is no previous version to compare against"
+        );
+
+        let (version, compatibility) = registry
+            .register(
+                "my_function",
+                false,
+                serde_json::json!({"type": "object", "properties": {"name": {"type": "string"}, "age": {"type": "integer"}}}),
+            )
+            .unwrap();
+        assert_eq!(
+            version, 2,
+            "the second registered schema should be version 2"
+        );
+        assert_eq!(
+            compatibility,
+            Some(SchemaCompatibility::Full),
+            "adding an optional property is both backward and forward compatible"
+        );
+    }
+
+    #[test]
+    fn test_detects_breaking_change() {
+        let registry = SchemaRegistry::new();
+        registry
+            .register(
+                "my_function",
+                true,
+                serde_json::json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+            )
+            .unwrap();
+
+        let (_, compatibility) = registry
+            .register(
+                "my_function",
+                true,
+                serde_json::json!({"type": "object", "properties": {"name": {"type": "integer"}}, "required": ["name"]}),
+            )
+            .unwrap();
+
+        assert_eq!(
+            compatibility,
+            Some(SchemaCompatibility::Breaking),
+            "retyping an existing property and making it required should be flagged as breaking"
+        );
+    }
+
+    #[test]
+    fn test_get_specific_and_latest_version() {
+        let registry = SchemaRegistry::new();
+        registry
+            .register("my_function", false, serde_json::json!({"v": 1}))
+            .unwrap();
+        registry
+            .register("my_function", false, serde_json::json!({"v": 2}))
+            .unwrap();
+
+        let latest = registry.get("my_function", false, None).unwrap().unwrap();
+        assert_eq!(latest.version, 2, "`None` should return the latest version");
+
+        let first = registry
+            .get("my_function", false, Some(1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.schema, serde_json::json!({"v": 1}));
+    }
+}
@@ -0,0 +1,284 @@
+//! Reconciles TensorZero's internally computed cost against provider-reported spend.
+//!
+//! `ModelInference.cost_usd` is computed from token counts and the `pricing` configured for
+//! each model provider, so it can silently drift from what a provider actually bills (stale
+//! pricing, provider-side discounts, cached/batch pricing we don't model, etc.). This module
+//! ingests provider billing/usage exports into a common [`ProviderSpendRecord`] shape and
+//! diffs them against [`DailyModelCost`] rows from [`crate::db::cost::CostQueries`], so
+//! discrepancies can be surfaced per day/model instead of silently trusted.
+//!
+//! Only CSV exports are supported today, and only the columns needed to compute per-day,
+//! per-model spend are read; other columns in a provider's export are ignored.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::cost::DailyModelCost;
+use crate::error::{Error, ErrorDetails};
+
+/// One row of provider-reported spend, after parsing a billing/usage export.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProviderSpendRecord {
+    /// The UTC calendar day this spend was billed for, formatted as `YYYY-MM-DD`.
+    pub date: String,
+    /// The TensorZero model name this spend corresponds to (e.g. `openai::gpt-4o`).
+    pub model_name: String,
+    pub spend_usd: f64,
+}
+
+/// A day/model bucket where TensorZero's internally computed cost and the provider's reported
+/// spend disagree by more than the reconciliation's tolerance.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpendDiscrepancy {
+    pub date: String,
+    pub model_name: String,
+    pub internal_cost_usd: f64,
+    pub provider_spend_usd: f64,
+    /// `provider_spend_usd - internal_cost_usd`. Positive means TensorZero undercounted cost.
+    pub difference_usd: f64,
+}
+
+/// Compares internally computed cost against provider-reported spend, bucketed by day and
+/// model name, and returns every bucket whose absolute difference exceeds `tolerance_usd`.
+///
+/// A bucket present in only one of the two inputs (e.g. a model TensorZero never priced, or a
+/// day the provider hasn't billed for yet) is treated as having zero cost on the missing side,
+/// so it's reported like any other discrepancy rather than silently skipped.
+pub fn reconcile_provider_spend(
+    internal: &[DailyModelCost],
+    provider: &[ProviderSpendRecord],
+    tolerance_usd: f64,
+) -> Vec<SpendDiscrepancy> {
+    let mut buckets: HashMap<(String, String), (f64, f64)> = HashMap::new();
+
+    for cost in internal {
+        buckets
+            .entry((cost.date.clone(), cost.model_name.clone()))
+            .or_insert((0.0, 0.0))
+            .0 += cost.cost_usd;
+    }
+    for record in provider {
+        buckets
+            .entry((record.date.clone(), record.model_name.clone()))
+            .or_insert((0.0, 0.0))
+            .1 += record.spend_usd;
+    }
+
+    let mut discrepancies: Vec<SpendDiscrepancy> = buckets
+        .into_iter()
+        .filter_map(
+            |((date, model_name), (internal_cost_usd, provider_spend_usd))| {
+                let difference_usd = provider_spend_usd - internal_cost_usd;
+                (difference_usd.abs() > tolerance_usd).then_some(SpendDiscrepancy {
+                    date,
+                    model_name,
+                    internal_cost_usd,
+                    provider_spend_usd,
+                    difference_usd,
+                })
+            },
+        )
+        .collect();
+
+    discrepancies.sort_by(|a, b| (&a.date, &a.model_name).cmp(&(&b.date, &b.model_name)));
+    discrepancies
+}
+
+/// Parses an OpenAI usage export CSV (`Cost` report, as downloaded from the OpenAI usage
+/// dashboard), which is expected to have a header row including `date`, `line_item` (the
+/// provider's own model identifier, e.g. `gpt-4o`), and `cost` columns.
+///
+/// `model_name_map` resolves each provider model identifier to the TensorZero model name it
+/// should be reconciled against (e.g. `"gpt-4o" -> "openai::gpt-4o"`); an identifier with no
+/// entry is passed through unchanged.
+pub fn parse_openai_usage_csv(
+    csv: &str,
+    model_name_map: &HashMap<&str, &str>,
+) -> Result<Vec<ProviderSpendRecord>, Error> {
+    parse_usage_csv(csv, "date", "line_item", "cost", model_name_map)
+}
+
+/// Parses an Anthropic usage export CSV, which is expected to have a header row including
+/// `date`, `model` (the provider's own model identifier), and `cost_usd` columns.
+///
+/// See [`parse_openai_usage_csv`] for how `model_name_map` is applied.
+pub fn parse_anthropic_usage_csv(
+    csv: &str,
+    model_name_map: &HashMap<&str, &str>,
+) -> Result<Vec<ProviderSpendRecord>, Error> {
+    parse_usage_csv(csv, "date", "model", "cost_usd", model_name_map)
+}
+
+/// Parses a simple, unquoted, comma-separated usage export into [`ProviderSpendRecord`]s by
+/// name-matching the given columns in the header row. Rows are summed when a provider reports
+/// multiple line items for the same date/model (e.g. broken out by usage type).
+///
+/// This intentionally does not handle quoted fields or embedded commas: provider usage exports
+/// are plain numeric/identifier data, so a hand-rolled splitter avoids taking on a CSV parsing
+/// dependency for this narrow use case.
+fn parse_usage_csv(
+    csv: &str,
+    date_column: &str,
+    model_column: &str,
+    cost_column: &str,
+    model_name_map: &HashMap<&str, &str>,
+) -> Result<Vec<ProviderSpendRecord>, Error> {
+    let mut lines = csv.lines().filter(|line| !line.trim().is_empty());
+
+    let header = lines.next().ok_or_else(|| {
+        Error::new(ErrorDetails::InvalidRequest {
+            message: "Usage export is empty".to_string(),
+        })
+    })?;
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+    let column_index = |name: &str| -> Result<usize, Error> {
+        columns.iter().position(|c| *c == name).ok_or_else(|| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Usage export is missing required column `{name}`"),
+            })
+        })
+    };
+    let date_index = column_index(date_column)?;
+    let model_index = column_index(model_column)?;
+    let cost_index = column_index(cost_column)?;
+
+    let mut totals: HashMap<(String, String), f64> = HashMap::new();
+    for line in lines {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let get = |index: usize, name: &str| -> Result<&str, Error> {
+            fields.get(index).copied().ok_or_else(|| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!("Usage export row is missing column `{name}`: `{line}`"),
+                })
+            })
+        };
+        let date = get(date_index, date_column)?.to_string();
+        let provider_model_id = get(model_index, model_column)?;
+        let model_name = model_name_map
+            .get(provider_model_id)
+            .copied()
+            .unwrap_or(provider_model_id)
+            .to_string();
+        let cost: f64 = get(cost_index, cost_column)?.parse().map_err(|e| {
+            Error::new(ErrorDetails::InvalidRequest {
+                message: format!("Failed to parse cost in usage export row `{line}`: {e}"),
+            })
+        })?;
+        *totals.entry((date, model_name)).or_insert(0.0) += cost;
+    }
+
+    let mut records: Vec<ProviderSpendRecord> = totals
+        .into_iter()
+        .map(|((date, model_name), spend_usd)| ProviderSpendRecord {
+            date,
+            model_name,
+            spend_usd,
+        })
+        .collect();
+    records.sort_by(|a, b| (&a.date, &a.model_name).cmp(&(&b.date, &b.model_name)));
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_openai_usage_csv() {
+        let csv = "date,line_item,cost\n\
+                    2026-08-01,gpt-4o,1.50\n\
+                    2026-08-01,gpt-4o,0.50\n\
+                    2026-08-02,gpt-4o-mini,0.10\n";
+        let model_name_map = HashMap::from([("gpt-4o", "openai::gpt-4o")]);
+        let records = parse_openai_usage_csv(csv, &model_name_map).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                ProviderSpendRecord {
+                    date: "2026-08-01".to_string(),
+                    model_name: "openai::gpt-4o".to_string(),
+                    spend_usd: 2.0,
+                },
+                ProviderSpendRecord {
+                    date: "2026-08-02".to_string(),
+                    model_name: "gpt-4o-mini".to_string(),
+                    spend_usd: 0.10,
+                },
+            ],
+            "Multiple line items for the same date/model should be summed"
+        );
+    }
+
+    #[test]
+    fn test_parse_usage_csv_missing_column() {
+        let csv = "date,model\n2026-08-01,gpt-4o\n";
+        let err = parse_anthropic_usage_csv(csv, &HashMap::new()).unwrap_err();
+        assert!(
+            err.to_string().contains("cost_usd"),
+            "Error should name the missing column, got: {err}"
+        );
+    }
+
+    #[test]
+    fn test_reconcile_provider_spend_flags_over_tolerance() {
+        let internal = vec![
+            DailyModelCost {
+                date: "2026-08-01".to_string(),
+                model_name: "openai::gpt-4o".to_string(),
+                cost_usd: 1.0,
+            },
+            DailyModelCost {
+                date: "2026-08-02".to_string(),
+                model_name: "openai::gpt-4o".to_string(),
+                cost_usd: 1.0,
+            },
+        ];
+        let provider = vec![
+            ProviderSpendRecord {
+                date: "2026-08-01".to_string(),
+                model_name: "openai::gpt-4o".to_string(),
+                spend_usd: 1.0005,
+            },
+            ProviderSpendRecord {
+                date: "2026-08-02".to_string(),
+                model_name: "openai::gpt-4o".to_string(),
+                spend_usd: 1.50,
+            },
+        ];
+
+        let discrepancies = reconcile_provider_spend(&internal, &provider, 0.01);
+        assert_eq!(
+            discrepancies,
+            vec![SpendDiscrepancy {
+                date: "2026-08-02".to_string(),
+                model_name: "openai::gpt-4o".to_string(),
+                internal_cost_usd: 1.0,
+                provider_spend_usd: 1.50,
+                difference_usd: 0.50,
+            }],
+            "Only the bucket exceeding tolerance should be reported"
+        );
+    }
+
+    #[test]
+    fn test_reconcile_provider_spend_missing_bucket() {
+        let internal = vec![DailyModelCost {
+            date: "2026-08-01".to_string(),
+            model_name: "openai::gpt-4o".to_string(),
+            cost_usd: 5.0,
+        }];
+        let provider = vec![ProviderSpendRecord {
+            date: "2026-08-01".to_string(),
+            model_name: "anthropic::claude-3-5-haiku".to_string(),
+            spend_usd: 2.0,
+        }];
+
+        let discrepancies = reconcile_provider_spend(&internal, &provider, 0.0);
+        assert_eq!(
+            discrepancies.len(),
+            2,
+            "A model present in only one source should still be reported as a discrepancy against zero"
+        );
+    }
+}
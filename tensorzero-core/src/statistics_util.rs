@@ -105,6 +105,113 @@ pub fn wilson_confint_from_data(data: &[f32]) -> Option<(f64, f64)> {
     wilson_confint(mean, count as u32)
 }
 
+/// Draws a sample from a Laplace distribution centered at 0 with the given `scale`
+/// (i.e. `Laplace(0, scale)`), using inverse transform sampling.
+fn sample_laplace(scale: f64) -> f64 {
+    // `rand::random` samples uniformly from [0, 1). Shift into (-0.5, 0.5] so that
+    // `u == 0.0` doesn't send `ln(0.0)` to `-inf`.
+    let u: f64 = 0.5 - rand::random::<f64>();
+    -scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+}
+
+/// Standard normal quantile function (inverse CDF), via Acklam's rational approximation.
+/// Accurate to about 1.15e-9 relative error, more than sufficient for picking z-scores for
+/// confidence intervals and significance tests.
+///
+/// Returns `f64::NEG_INFINITY`/`f64::INFINITY` for `p <= 0.0`/`p >= 1.0` respectively.
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e+01,
+        2.209_460_984_245_205e+02,
+        -2.759_285_104_469_687e+02,
+        1.383_577_518_672_690e+02,
+        -3.066_479_806_614_716e+01,
+        2.506_628_277_459_239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e+01,
+        1.615_858_368_580_409e+02,
+        -1.556_989_798_598_866e+02,
+        6.680_131_188_771_972e+01,
+        -1.328_068_155_288_572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-03,
+        -3.223_964_580_411_365e-01,
+        -2.400_758_277_161_838e+00,
+        -2.549_732_539_343_734e+00,
+        4.374_664_141_464_968e+00,
+        2.938_163_982_698_783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-03,
+        3.224_671_290_700_398e-01,
+        2.445_134_137_142_996e+00,
+        3.754_408_661_907_416e+00,
+    ];
+    const P_LOW: f64 = 0.024_25;
+    const P_HIGH: f64 = 1.0 - P_LOW;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= P_HIGH {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+}
+
+/// Two-sided z-score for `num_comparisons` simultaneous tests at family-wise significance
+/// level `alpha`, using the Bonferroni correction (dividing `alpha` by the number of
+/// comparisons). Useful for flagging segments as significant only after accounting for the
+/// number of segments tested, so that testing more segments doesn't itself inflate the false
+/// positive rate.
+pub fn bonferroni_z(alpha: f64, num_comparisons: usize) -> f64 {
+    let corrected_alpha = alpha / (num_comparisons.max(1) as f64);
+    normal_quantile(1.0 - corrected_alpha / 2.0)
+}
+
+/// Computes the Wald confidence interval for continuous data using an arbitrary z-score,
+/// rather than the fixed 1.96 (95%) used by `wald_confint`. Useful when the per-comparison
+/// significance level has been adjusted for multiple comparisons, e.g. via `bonferroni_z`.
+pub fn wald_confint_with_z(mean: f64, stdev: f64, count: u32, z: f64) -> Option<(f64, f64)> {
+    if count == 0 {
+        return None;
+    }
+
+    let margin = z * (stdev / (count as f64).sqrt());
+    Some((mean - margin, mean + margin))
+}
+
+/// Applies the Laplace mechanism to `value`, providing `epsilon`-differential privacy
+/// for a query with the given `sensitivity` (the maximum amount `value` can change
+/// when a single individual's data is added or removed).
+///
+/// Smaller `epsilon` gives stronger privacy at the cost of noisier results. Callers
+/// are responsible for choosing a `sensitivity` appropriate to the aggregate being
+/// released (e.g. `1 / count` for a mean of 0/1 values).
+///
+/// # Panics
+/// Panics if `epsilon` is not positive.
+pub fn add_laplace_noise(value: f64, sensitivity: f64, epsilon: f64) -> f64 {
+    assert!(epsilon > 0.0, "epsilon must be positive, got {epsilon}");
+    value + sample_laplace(sensitivity / epsilon)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,4 +453,116 @@ mod tests {
             "Wilson lower bound should be >= 0, got {lower}",
         );
     }
+
+    // Tests for the Laplace mechanism
+
+    #[test]
+    #[should_panic(expected = "epsilon must be positive")]
+    fn test_add_laplace_noise_rejects_nonpositive_epsilon() {
+        add_laplace_noise(1.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_add_laplace_noise_smaller_epsilon_is_noisier_on_average() {
+        // Smaller epsilon (stronger privacy) should produce larger-magnitude noise
+        // on average, since the Laplace scale is `sensitivity / epsilon`.
+        let trials = 2000;
+        let mean_abs_noise = |epsilon: f64| -> f64 {
+            (0..trials)
+                .map(|_| (add_laplace_noise(0.0, 1.0, epsilon)).abs())
+                .sum::<f64>()
+                / trials as f64
+        };
+
+        let noisy_small_epsilon = mean_abs_noise(0.1);
+        let noisy_large_epsilon = mean_abs_noise(10.0);
+        assert!(
+            noisy_small_epsilon > noisy_large_epsilon,
+            "expected epsilon=0.1 to produce more average noise ({noisy_small_epsilon}) than \
+             epsilon=10.0 ({noisy_large_epsilon})"
+        );
+    }
+
+    // Tests for the normal quantile function
+
+    #[test]
+    fn test_normal_quantile_known_values() {
+        // Standard values from any normal quantile table
+        assert!(
+            (normal_quantile(0.5) - 0.0).abs() < 1e-9,
+            "median of the standard normal should be 0"
+        );
+        assert!(
+            (normal_quantile(0.975) - 1.959_963_985).abs() < 1e-6,
+            "97.5th percentile should be approximately 1.96"
+        );
+        assert!(
+            (normal_quantile(0.995) - 2.575_829_303).abs() < 1e-6,
+            "99.5th percentile should be approximately 2.576"
+        );
+    }
+
+    #[test]
+    fn test_normal_quantile_is_antisymmetric() {
+        let p = 0.9;
+        assert!(
+            (normal_quantile(p) + normal_quantile(1.0 - p)).abs() < 1e-9,
+            "the standard normal quantile function should satisfy q(p) = -q(1-p)"
+        );
+    }
+
+    #[test]
+    fn test_normal_quantile_extremes() {
+        assert_eq!(normal_quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(normal_quantile(1.0), f64::INFINITY);
+    }
+
+    // Tests for the Bonferroni-corrected z-score
+
+    #[test]
+    fn test_bonferroni_z_single_comparison_matches_uncorrected() {
+        // With a single comparison, the Bonferroni-corrected z-score should be the same
+        // one used by `wald_confint` (95% CI, z = 1.96)
+        let z = bonferroni_z(0.05, 1);
+        assert!(
+            (z - 1.959_963_985).abs() < 1e-6,
+            "a single comparison should use the uncorrected 95% z-score, got {z}"
+        );
+    }
+
+    #[test]
+    fn test_bonferroni_z_more_comparisons_widens_interval() {
+        // More simultaneous comparisons should require a larger z-score to maintain the
+        // same family-wise significance level
+        let z_one = bonferroni_z(0.05, 1);
+        let z_many = bonferroni_z(0.05, 20);
+        assert!(
+            z_many > z_one,
+            "correcting for more comparisons should require a larger z-score \
+             ({z_many} should exceed {z_one})"
+        );
+    }
+
+    // Tests for the generalized Wald confidence interval
+
+    #[test]
+    fn test_wald_confint_with_z_matches_fixed_z_variant() {
+        let z = bonferroni_z(0.05, 1);
+        let expected =
+            wald_confint(100.0, 10.0, 100).expect("should produce a valid confidence interval");
+        let actual = wald_confint_with_z(100.0, 10.0, 100, z)
+            .expect("should produce a valid confidence interval");
+        assert!(
+            (expected.0 - actual.0).abs() < 1e-6 && (expected.1 - actual.1).abs() < 1e-6,
+            "wald_confint_with_z at the uncorrected z-score should match wald_confint exactly"
+        );
+    }
+
+    #[test]
+    fn test_wald_confint_with_z_zero_count() {
+        assert!(
+            wald_confint_with_z(100.0, 10.0, 0, 1.96).is_none(),
+            "zero count should produce None as confidence interval"
+        );
+    }
 }
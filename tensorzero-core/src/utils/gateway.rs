@@ -16,6 +16,9 @@ use tokio_util::sync::CancellationToken;
 use tokio_util::task::TaskTracker;
 use tracing::instrument;
 
+use crate::cache::{
+    CacheBackend, DEFAULT_HOT_CACHE_MAX_VALUE_BYTES, DEFAULT_HOT_CACHE_TTL_S, ValkeyCacheBackend,
+};
 use crate::config::{Config, ConfigFileGlob, snapshot::SnapshotHash, unwritten::UnwrittenConfig};
 use crate::db::clickhouse::ClickHouseConnectionInfo;
 use crate::db::clickhouse::clickhouse_client::ClickHouseClientType;
@@ -26,6 +29,7 @@ use crate::db::valkey::ValkeyConnectionInfo;
 use crate::endpoints;
 use crate::endpoints::openai_compatible::RouterExt;
 use crate::error::{Error, ErrorDetails};
+use crate::events::GatewayEventBus;
 use crate::howdy::setup_howdy;
 use crate::http::TensorzeroHttpClient;
 use crate::rate_limiting::RateLimitingManager;
@@ -142,6 +146,9 @@ pub struct AppStateData {
     pub clickhouse_connection_info: ClickHouseConnectionInfo,
     pub postgres_connection_info: PostgresConnectionInfo,
     pub valkey_connection_info: ValkeyConnectionInfo,
+    /// Hot cache tier checked before (and backfilled after) each ClickHouse-backed cache
+    /// lookup. Backed by `valkey_connection_info` - see `setup_hot_cache`.
+    pub hot_cache: Arc<dyn CacheBackend>,
     /// Holds any background tasks that we want to wait on during shutdown
     /// We wait for these tasks to finish when `GatewayHandle` is dropped
     pub deferred_tasks: TaskTracker,
@@ -155,6 +162,9 @@ pub struct AppStateData {
     pub deployment_id: Option<String>,
     /// Token pool manager for rate limiting pre-borrowing
     pub rate_limiting_manager: Arc<RateLimitingManager>,
+    /// Broadcasts gateway activity (inferences, feedback, job progress, config changes) to the
+    /// `/internal/events/stream` SSE endpoint's subscribers.
+    pub event_bus: GatewayEventBus,
     pub shutdown_token: CancellationToken,
     // Prevent `AppStateData` from being directly constructed outside of this module
     // This ensures that `AppStateData` is only ever constructed via explicit `new` methods,
@@ -258,12 +268,14 @@ impl GatewayHandle {
                 clickhouse_connection_info,
                 postgres_connection_info,
                 valkey_connection_info: ValkeyConnectionInfo::Disabled,
+                hot_cache: setup_hot_cache(ValkeyConnectionInfo::Disabled),
                 deferred_tasks: TaskTracker::new(),
                 auth_cache,
                 config_snapshot_cache: None,
                 autopilot_client: None,
                 deployment_id: None,
                 rate_limiting_manager,
+                event_bus: GatewayEventBus::new(),
                 shutdown_token: cancel_token,
                 _private: (),
             },
@@ -328,6 +340,7 @@ impl GatewayHandle {
         )
         .await?;
 
+        let hot_cache = setup_hot_cache(valkey_connection_info.clone());
         Ok(Self {
             app_state: AppStateData {
                 config,
@@ -335,12 +348,14 @@ impl GatewayHandle {
                 clickhouse_connection_info,
                 postgres_connection_info,
                 valkey_connection_info,
+                hot_cache,
                 deferred_tasks: TaskTracker::new(),
                 auth_cache,
                 config_snapshot_cache,
                 autopilot_client,
                 deployment_id,
                 rate_limiting_manager,
+                event_bus: GatewayEventBus::new(),
                 shutdown_token: cancel_token,
                 _private: (),
             },
@@ -368,18 +383,21 @@ impl AppStateData {
             &valkey_connection_info,
             &postgres_connection_info,
         )?);
+        let hot_cache = setup_hot_cache(valkey_connection_info.clone());
         Ok(Self {
             config,
             http_client,
             clickhouse_connection_info,
             postgres_connection_info,
             valkey_connection_info,
+            hot_cache,
             deferred_tasks,
             auth_cache: None,
             config_snapshot_cache: None,
             autopilot_client: None,
             deployment_id: None,
             rate_limiting_manager,
+            event_bus: GatewayEventBus::new(),
             shutdown_token,
             _private: (),
         })
@@ -538,6 +556,31 @@ pub async fn setup_valkey(valkey_url: Option<&str>) -> Result<ValkeyConnectionIn
     }
 }
 
+/// Builds the hot cache tier from a (possibly disabled) Valkey connection. When
+/// `valkey_connection_info` is `Disabled`, the returned backend is still usable - every lookup
+/// is a no-op miss, so callers always fall through to ClickHouse.
+///
+/// Environment variables:
+/// - `TENSORZERO_CACHE_HOT_TTL_S`: Optional TTL override, in seconds (defaults to
+///   `DEFAULT_HOT_CACHE_TTL_S`)
+/// - `TENSORZERO_CACHE_HOT_MAX_VALUE_BYTES`: Optional max cached value size override, in bytes
+///   (defaults to `DEFAULT_HOT_CACHE_MAX_VALUE_BYTES`)
+fn setup_hot_cache(valkey_connection_info: ValkeyConnectionInfo) -> Arc<dyn CacheBackend> {
+    let ttl_s = std::env::var("TENSORZERO_CACHE_HOT_TTL_S")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_HOT_CACHE_TTL_S);
+    let max_value_bytes = std::env::var("TENSORZERO_CACHE_HOT_MAX_VALUE_BYTES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_HOT_CACHE_MAX_VALUE_BYTES);
+    Arc::new(ValkeyCacheBackend::new(
+        valkey_connection_info,
+        ttl_s,
+        max_value_bytes,
+    ))
+}
+
 /// Sets up the Autopilot API client from the environment.
 /// Returns `Ok(Some(client))` if TENSORZERO_AUTOPILOT_API_KEY is set,
 /// `Ok(None)` if not set, or an error if client construction fails.
@@ -787,6 +830,10 @@ mod tests {
             global_outbound_http_timeout: Default::default(),
             relay: None,
             metrics: Default::default(),
+            tls: Default::default(),
+            access_policy: Default::default(),
+            mirroring: Default::default(),
+            episode_budgets: Default::default(),
         };
 
         let config = Config {
@@ -859,6 +906,10 @@ mod tests {
             global_outbound_http_timeout: Default::default(),
             relay: None,
             metrics: Default::default(),
+            tls: Default::default(),
+            access_policy: Default::default(),
+            mirroring: Default::default(),
+            episode_budgets: Default::default(),
         };
 
         let config = Config {
@@ -896,6 +947,10 @@ mod tests {
             global_outbound_http_timeout: Default::default(),
             relay: None,
             metrics: Default::default(),
+            tls: Default::default(),
+            access_policy: Default::default(),
+            mirroring: Default::default(),
+            episode_budgets: Default::default(),
         };
         let config = Config {
             gateway: gateway_config,
@@ -932,6 +987,10 @@ mod tests {
             global_outbound_http_timeout: Default::default(),
             relay: None,
             metrics: Default::default(),
+            tls: Default::default(),
+            access_policy: Default::default(),
+            mirroring: Default::default(),
+            episode_budgets: Default::default(),
         };
         let config = Config {
             gateway: gateway_config,
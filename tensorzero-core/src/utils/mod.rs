@@ -12,6 +12,7 @@ pub mod mock;
 pub mod retries;
 #[cfg(any(test, feature = "e2e_tests"))]
 pub mod testing;
+pub mod token_estimate;
 pub mod uuid;
 
 /// A helper function that wraps a future that might have unbounded recursion.
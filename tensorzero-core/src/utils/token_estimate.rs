@@ -0,0 +1,13 @@
+//! A crude, tokenizer-free token count estimate shared by anything that needs a rough token
+//! budget without pulling in a provider-specific tokenizer (e.g. deciding whether a prompt is
+//! worth compressing, or estimating a variant's static template overhead).
+//!
+//! NOTE: this is an approximation, not computed with the actual tokenizer for a given model. It
+//! is intended for relative comparisons and threshold checks, not for predicting exact
+//! provider-billed token counts.
+const CHARS_PER_TOKEN_ESTIMATE: f64 = 4.0;
+
+/// Estimates the number of tokens `num_chars` characters of English-ish text would tokenize to.
+pub fn estimate_tokens_for_chars(num_chars: usize) -> u64 {
+    ((num_chars as f64) / CHARS_PER_TOKEN_ESTIMATE).ceil() as u64
+}
@@ -536,8 +536,10 @@ async fn inner_select_best_candidate<'a>(
         .inner
         .retries()
         .retry(|| async {
+            // The evaluator's own judge model isn't part of the function's regular variant
+            // routing, so it isn't gated by the function's `data_residency` policy.
             model_config
-                .infer(&inference_request, clients, evaluator.inner.model())
+                .infer(&inference_request, clients, evaluator.inner.model(), None)
                 .await
         })
         .await?;
@@ -1368,10 +1370,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             )]),
             ProviderTypeDefaultCredentials::new(&provider_types).into(),
@@ -1385,6 +1390,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1397,6 +1407,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1486,10 +1497,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             );
             let provider_types = ProviderTypesConfig::default();
@@ -1561,10 +1575,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             );
             let provider_types = ProviderTypesConfig::default();
@@ -1654,10 +1671,13 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 )]),
                 timeouts: Default::default(),
                 skip_relay: false,
+                hedge: None,
             },
         );
         let provider_types = ProviderTypesConfig::default();
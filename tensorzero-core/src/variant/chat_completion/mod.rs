@@ -34,7 +34,9 @@ use crate::minijinja_util::TemplateConfig;
 use crate::model::ModelTable;
 use crate::variant::JsonMode;
 
+mod prompt_compression;
 mod templates;
+pub use prompt_compression::PromptCompressionConfig;
 pub use templates::ChatTemplates;
 
 use super::{
@@ -61,6 +63,34 @@ pub struct TemplateWithSchema {
     pub legacy_definition: bool,
 }
 
+/// Configuration for a bounded retry loop that re-prompts the model when its JSON output fails
+/// to parse or to validate against the function's output schema, instead of the default
+/// behavior of silently returning `parsed: None` (see
+/// `FunctionConfig::prepare_response`). Only applies to JSON functions; ignored for chat
+/// functions and for `json_mode: "tool"`, since tool-call arguments already come back
+/// schema-constrained by the provider.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct JsonRepairConfig {
+    /// Maximum number of re-prompts to attempt after an invalid response, before giving up and
+    /// returning the last (still invalid) result as-is.
+    #[serde(default = "default_max_repairs")]
+    pub max_repairs: usize,
+}
+
+fn default_max_repairs() -> usize {
+    1
+}
+
+impl Default for JsonRepairConfig {
+    fn default() -> Self {
+        Self {
+            max_repairs: default_max_repairs(),
+        }
+    }
+}
+
 #[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
 #[derive(Debug, Default, Serialize)]
 #[cfg_attr(feature = "ts-bindings", ts(export))]
@@ -79,6 +109,8 @@ pub struct ChatCompletionConfig {
     pub(crate) inference_params_v2: ChatCompletionInferenceParamsV2,
     json_mode: Option<JsonMode>, // Only for JSON functions, not for chat functions
     retries: RetryConfig,
+    json_repair: Option<JsonRepairConfig>,
+    prompt_compression: Option<PromptCompressionConfig>,
     #[cfg_attr(feature = "ts-bindings", ts(skip))]
     extra_body: Option<ExtraBodyConfig>,
     #[cfg_attr(feature = "ts-bindings", ts(skip))]
@@ -200,6 +232,8 @@ impl ChatCompletionConfig {
             verbosity: self.inference_params_v2.verbosity.clone(),
             json_mode: self.json_mode,
             retries: self.retries,
+            json_repair: self.json_repair.clone(),
+            prompt_compression: self.prompt_compression.clone(),
             extra_body: self.extra_body.clone(),
             extra_headers: self.extra_headers.clone(),
         }
@@ -272,6 +306,10 @@ pub struct UninitializedChatCompletionConfig {
     #[serde(default)]
     pub retries: RetryConfig,
     #[serde(default)]
+    pub json_repair: Option<JsonRepairConfig>,
+    #[serde(default)]
+    pub prompt_compression: Option<PromptCompressionConfig>,
+    #[serde(default)]
     #[cfg_attr(feature = "ts-bindings", ts(skip))]
     pub extra_body: Option<ExtraBodyConfig>,
     #[serde(default)]
@@ -305,6 +343,8 @@ impl UninitializedChatCompletionConfig {
             },
             json_mode: self.json_mode,
             retries: self.retries,
+            json_repair: self.json_repair,
+            prompt_compression: self.prompt_compression,
             extra_body: self.extra_body,
             extra_headers: self.extra_headers,
             _private: (),
@@ -565,6 +605,22 @@ pub async fn prepare_request_message(
     })
 }
 
+/// Builds the follow-up user message sent to re-prompt the model after it produced JSON that
+/// failed to parse or to validate against the output schema (see [`JsonRepairConfig`]).
+fn json_repair_request_message(previous_raw_output: Option<&str>) -> RequestMessage {
+    let previous_raw_output = previous_raw_output.unwrap_or("(no output)");
+    RequestMessage {
+        role: Role::User,
+        content: vec![ContentBlock::Text(Text {
+            text: format!(
+                "Your previous response was not valid JSON matching the required schema:\n\n\
+                {previous_raw_output}\n\n\
+                Respond again with only valid JSON matching the schema, and nothing else."
+            ),
+        })],
+    }
+}
+
 impl Variant for ChatCompletionConfig {
     async fn infer(
         &self,
@@ -577,7 +633,7 @@ impl Variant for ChatCompletionConfig {
     ) -> Result<InferenceResult, Error> {
         let inference_config_clone = Arc::clone(&inference_config);
         let mut inference_params = inference_params;
-        let request = self
+        let mut request = self
             .prepare_request(
                 &input,
                 &function,
@@ -586,6 +642,9 @@ impl Variant for ChatCompletionConfig {
                 &mut inference_params,
             )
             .await?;
+        if let Some(prompt_compression) = &self.prompt_compression {
+            prompt_compression::compress_messages(&mut request.messages, prompt_compression);
+        }
         let model_config = models
             .models
             .get(&self.model, clients.relay.as_ref())
@@ -595,17 +654,45 @@ impl Variant for ChatCompletionConfig {
                     name: self.model.to_string(),
                 })
             })?;
-        let args = InferModelRequestArgs {
-            request,
-            model_name: self.model.clone(),
-            model_config: &model_config,
-            function: &function,
-            inference_config: inference_config_clone,
-            clients,
-            inference_params,
-            retry_config: &self.retries,
-        };
-        infer_model_request(args).await
+
+        let max_repairs = self.json_repair.as_ref().map_or(0, |c| c.max_repairs);
+        let mut attempt = 0;
+        let mut prior_model_inference_results = Vec::new();
+        loop {
+            let args = InferModelRequestArgs {
+                request: request.clone(),
+                model_name: self.model.clone(),
+                model_config: &model_config,
+                function: &function,
+                inference_config: Arc::clone(&inference_config_clone),
+                clients: clients.clone(),
+                inference_params: inference_params.clone(),
+                retry_config: &self.retries,
+            };
+            let mut result = infer_model_request(args).await?;
+            result
+                .mut_model_inference_results()
+                .splice(0..0, prior_model_inference_results.drain(..));
+
+            let invalid_raw_output = match &result {
+                InferenceResult::Json(json_result) if json_result.output.parsed.is_none() => {
+                    Some(json_result.output.raw.clone())
+                }
+                _ => None,
+            };
+            let Some(raw_output) = invalid_raw_output else {
+                return Ok(result);
+            };
+            if attempt >= max_repairs {
+                return Ok(result);
+            }
+
+            attempt += 1;
+            request
+                .messages
+                .push(json_repair_request_message(raw_output.as_deref()));
+            prior_model_inference_results = result.owned_model_inference_results();
+        }
     }
 
     async fn infer_stream(
@@ -618,7 +705,7 @@ impl Variant for ChatCompletionConfig {
         inference_params: InferenceParams,
     ) -> Result<(InferenceResultStream, ModelUsedInfo), Error> {
         let mut inference_params = inference_params;
-        let request = self
+        let mut request = self
             .prepare_request(
                 &input,
                 &function,
@@ -627,6 +714,9 @@ impl Variant for ChatCompletionConfig {
                 &mut inference_params,
             )
             .await?;
+        if let Some(prompt_compression) = &self.prompt_compression {
+            prompt_compression::compress_messages(&mut request.messages, prompt_compression);
+        }
         let model_config = models
             .models
             .get(&self.model, clients.relay.as_ref())
@@ -676,6 +766,16 @@ impl Variant for ChatCompletionConfig {
         }
         models.validate(&self.model)?;
 
+        // Fail fast at config-load time if this function has tools configured but the model's
+        // provider(s) can't serve tool calls, rather than failing later at inference time.
+        if function.tools().next().is_some() {
+            models.validate_tool_support(&self.model).map_err(|e| {
+                Error::new(ErrorDetails::Config {
+                    message: format!("`functions.{function_name}.variants.{variant_name}`: {e}"),
+                })
+            })?;
+        }
+
         // Validate the system template matches the system schema (best effort, we cannot check the variables comprehensively)
         validate_legacy_template_and_schema(
             TemplateKind::System,
@@ -1297,6 +1397,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1309,6 +1414,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1383,10 +1489,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let json_model_config = ModelConfig {
             routing: vec!["json_provider".into()],
@@ -1399,10 +1508,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let tool_provider_config = ProviderConfig::Dummy(DummyProvider {
             model_name: "tool".into(),
@@ -1419,10 +1531,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let error_model_config = ModelConfig {
             routing: vec!["error".into()],
@@ -1435,10 +1550,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         // Test case 1: invalid message (String passed when template required)
         let messages = vec![LazyResolvedInputMessage {
@@ -1677,10 +1795,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let provider_types = ProviderTypesConfig::default();
         let models = ModelTable::new(
@@ -1920,6 +2041,43 @@ mod tests {
             }
             InferenceResult::Chat(_) => panic!("Expected Json inference response"),
         }
+
+        // Test case 5b: same invalid JSON output, but with `json_repair` configured. The dummy
+        // "tool" model always returns the same (schema-invalid) text regardless of the extra
+        // repair prompt, so the result is still invalid after exhausting retries - but every
+        // attempt should be reflected in `model_inference_results`.
+        let repair_chat_completion_config = ChatCompletionConfig {
+            model: "tool".into(),
+            weight: Some(1.0),
+            json_repair: Some(JsonRepairConfig { max_repairs: 2 }),
+            ..Default::default()
+        };
+        let result = repair_chat_completion_config
+            .infer(
+                Arc::new(input.clone()),
+                inference_models.clone(),
+                Arc::clone(&json_function_config),
+                Arc::new(inference_config.clone()),
+                clients.clone(),
+                inference_params.clone(),
+            )
+            .await
+            .unwrap();
+        match result {
+            InferenceResult::Json(json_result) => {
+                assert!(
+                    json_result.output.parsed.is_none(),
+                    "output never matches the schema, so parsed should remain None after exhausting repairs"
+                );
+                assert_eq!(
+                    json_result.model_inference_results.len(),
+                    3,
+                    "should have made 1 initial attempt plus 2 repair attempts"
+                );
+            }
+            InferenceResult::Chat(_) => panic!("Expected Json inference response"),
+        }
+
         let messages = vec![LazyResolvedInputMessage {
             role: Role::User,
             content: vec![LazyResolvedInputMessageContent::Template(Template {
@@ -2313,6 +2471,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -2325,6 +2488,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -2371,10 +2535,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let error_model_config = ModelConfig {
             routing: vec!["error_provider".into()],
@@ -2387,10 +2554,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         // Test case 1: Model inference fails because of model issues
         let inference_params = InferenceParams::default();
@@ -0,0 +1,170 @@
+//! Heuristic prompt compression applied by [`super::ChatCompletionConfig`] before a request is
+//! sent to the model, when the request's estimated prompt tokens exceed a configured threshold.
+//!
+//! Only a chars-per-token heuristic trim is implemented here: text content blocks other than
+//! the most recent message are truncated in the middle down to a target fraction of their
+//! original length. A learned/model-based compressor (e.g. an LLMLingua-style small model) is
+//! out of scope - it would pull in a whole additional model dependency and its own inference
+//! path, which is a larger undertaking than this heuristic stage.
+
+use metrics::histogram;
+use serde::{Deserialize, Serialize};
+
+use crate::inference::types::{ContentBlock, RequestMessage};
+use crate::utils::token_estimate::estimate_tokens_for_chars;
+
+fn default_token_threshold() -> u64 {
+    8_000
+}
+
+fn default_target_ratio() -> f64 {
+    0.5
+}
+
+/// Configuration for [`compress_messages`], set via `prompt_compression` on a chat completion
+/// variant.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct PromptCompressionConfig {
+    /// Compression only runs once the request's estimated prompt tokens exceed this.
+    #[serde(default = "default_token_threshold")]
+    pub token_threshold: u64,
+    /// Target fraction (0.0-1.0) of their original length to truncate older messages' text
+    /// content down to.
+    #[serde(default = "default_target_ratio")]
+    pub target_ratio: f64,
+}
+
+impl Default for PromptCompressionConfig {
+    fn default() -> Self {
+        Self {
+            token_threshold: default_token_threshold(),
+            target_ratio: default_target_ratio(),
+        }
+    }
+}
+
+/// Compresses `messages` in place if their combined estimated prompt tokens exceed
+/// `config.token_threshold`, and records the resulting compression ratio to the
+/// `tensorzero_prompt_compression_ratio` metric so its quality impact can be tracked over time.
+///
+/// Every message except the last (usually the most recent, most relevant turn) has its text
+/// content blocks truncated in the middle down to `config.target_ratio` of their original
+/// length. Non-text content blocks (images, tool calls, etc.) are left untouched.
+pub fn compress_messages(messages: &mut [RequestMessage], config: &PromptCompressionConfig) {
+    let original_chars = total_text_chars(messages);
+    if estimate_tokens_for_chars(original_chars) <= config.token_threshold {
+        return;
+    }
+
+    let Some((_last, rest)) = messages.split_last_mut() else {
+        return;
+    };
+    for message in rest {
+        for block in &mut message.content {
+            if let ContentBlock::Text(text) = block {
+                text.text = truncate_middle(&text.text, config.target_ratio);
+            }
+        }
+    }
+
+    let compressed_chars = total_text_chars(messages);
+    let ratio = if original_chars == 0 {
+        1.0
+    } else {
+        compressed_chars as f64 / original_chars as f64
+    };
+    histogram!("tensorzero_prompt_compression_ratio").record(ratio);
+}
+
+fn total_text_chars(messages: &[RequestMessage]) -> usize {
+    messages
+        .iter()
+        .flat_map(|message| &message.content)
+        .filter_map(|block| match block {
+            ContentBlock::Text(text) => Some(text.text.len()),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Truncates `text` to roughly `target_ratio` of its original length, keeping a prefix and
+/// suffix and dropping the middle, since instructions and the final question tend to live at
+/// the edges of a long block of text.
+fn truncate_middle(text: &str, target_ratio: f64) -> String {
+    let target_ratio = target_ratio.clamp(0.0, 1.0);
+    let chars: Vec<char> = text.chars().collect();
+    let target_len = ((chars.len() as f64) * target_ratio).round() as usize;
+    if target_len >= chars.len() {
+        return text.to_string();
+    }
+
+    let prefix_len = target_len / 2;
+    let suffix_len = target_len - prefix_len;
+    let prefix: String = chars[..prefix_len].iter().collect();
+    let suffix: String = chars[chars.len() - suffix_len..].iter().collect();
+    format!("{prefix}\n[... truncated by prompt compression ...]\n{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::types::Role;
+
+    fn text_message(role: Role, text: &str) -> RequestMessage {
+        RequestMessage {
+            role,
+            content: vec![ContentBlock::Text(crate::inference::types::Text {
+                text: text.to_string(),
+            })],
+        }
+    }
+
+    #[test]
+    fn does_not_compress_under_threshold() {
+        let mut messages = vec![text_message(Role::User, "short prompt")];
+        let config = PromptCompressionConfig {
+            token_threshold: 1_000,
+            target_ratio: 0.5,
+        };
+        compress_messages(&mut messages, &config);
+        assert_eq!(
+            messages[0].content[0],
+            ContentBlock::Text(crate::inference::types::Text {
+                text: "short prompt".to_string()
+            }),
+            "messages under the token threshold should be left untouched"
+        );
+    }
+
+    #[test]
+    fn compresses_all_but_last_message_over_threshold() {
+        let long_text = "word ".repeat(1_000);
+        let mut messages = vec![
+            text_message(Role::User, &long_text),
+            text_message(Role::Assistant, &long_text),
+            text_message(Role::User, &long_text),
+        ];
+        let config = PromptCompressionConfig {
+            token_threshold: 10,
+            target_ratio: 0.5,
+        };
+        compress_messages(&mut messages, &config);
+
+        let ContentBlock::Text(first) = &messages[0].content[0] else {
+            panic!("expected a text content block");
+        };
+        assert!(
+            first.text.len() < long_text.len(),
+            "the first message should be truncated once the request is over the token threshold"
+        );
+        let ContentBlock::Text(last) = &messages[2].content[0] else {
+            panic!("expected a text content block");
+        };
+        assert_eq!(
+            last.text, long_text,
+            "the last message should be left untouched so the most recent turn stays intact"
+        );
+    }
+}
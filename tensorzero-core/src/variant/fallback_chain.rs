@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ErrorContext, PathWithContents, SchemaData};
+use crate::embeddings::EmbeddingModelTable;
+use crate::endpoints::inference::{InferenceClients, InferenceModels, InferenceParams};
+use crate::error::{Error, ErrorDetails};
+use crate::function::FunctionConfig;
+use crate::inference::types::resolved_input::LazyResolvedInput;
+use crate::inference::types::{
+    InferenceResult, InferenceResultStream, batch::StartBatchModelInferenceWithMetadata,
+};
+use crate::minijinja_util::TemplateConfig;
+use crate::model::ModelTable;
+use crate::relay::TensorzeroRelay;
+use crate::utils::unbounded_recursion_wrapper;
+
+use super::{InferenceConfig, ModelUsedInfo, Variant};
+
+/// A variant that tries a fixed, ordered list of sibling variants (each of which is typically
+/// backed by a different model), falling through to the next step whenever a step fails - for
+/// example due to a provider error, a timeout, or a content-policy rejection surfaced as an
+/// error by the model provider. The first step to succeed provides the response.
+///
+/// Each step is itself a full variant of the same function, so it gets its own prompt/model
+/// configuration and (via `VariantInfo::timeouts`) its own per-step timeout - we don't
+/// reintroduce a separate timeout mechanism here.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct FallbackChainConfig {
+    weight: Option<f64>,
+    steps: Vec<String>,
+}
+
+impl FallbackChainConfig {
+    pub fn weight(&self) -> Option<f64> {
+        self.weight
+    }
+
+    pub fn set_weight(&mut self, weight: Option<f64>) {
+        self.weight = weight;
+    }
+
+    pub fn steps(&self) -> &Vec<String> {
+        &self.steps
+    }
+}
+
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+#[serde(deny_unknown_fields)]
+pub struct UninitializedFallbackChainConfig {
+    #[serde(default)]
+    pub weight: Option<f64>,
+    pub steps: Vec<String>,
+}
+
+impl UninitializedFallbackChainConfig {
+    pub fn load(
+        self,
+        _schemas: &SchemaData,
+        error_context: &ErrorContext,
+    ) -> Result<FallbackChainConfig, Error> {
+        if self.steps.is_empty() {
+            return Err(ErrorDetails::Config {
+                message: format!(
+                    "`functions.{}.variants.{}`: `experimental_fallback_chain` variants must specify at least one step",
+                    error_context.function_name, error_context.variant_name
+                ),
+            }
+            .into());
+        }
+        Ok(FallbackChainConfig {
+            weight: self.weight,
+            steps: self.steps,
+        })
+    }
+}
+
+impl Variant for FallbackChainConfig {
+    #[expect(refining_impl_trait, clippy::manual_async_fn)]
+    fn infer(
+        &self,
+        input: Arc<LazyResolvedInput>,
+        models: InferenceModels,
+        function: Arc<FunctionConfig>,
+        inference_config: Arc<InferenceConfig>,
+        clients: InferenceClients,
+        inference_params: InferenceParams,
+    ) -> impl Future<Output = Result<InferenceResult, Error>> + Send {
+        async move {
+            let mut step_errors = IndexMap::new();
+            for step in &self.steps {
+                let variant = function.variants().get(step).ok_or_else(|| {
+                    Error::new(ErrorDetails::UnknownCandidate {
+                        name: step.to_string(),
+                    })
+                })?;
+                let step_config = Arc::new(InferenceConfig {
+                    variant_name: Arc::from(step.as_str()),
+                    ..inference_config.as_ref().clone()
+                });
+                let variant = Arc::clone(variant);
+                let models = models.clone();
+                let clients = clients.clone();
+                let function = Arc::clone(&function);
+                let input = Arc::clone(&input);
+                let inference_params = inference_params.clone();
+                let result = unbounded_recursion_wrapper(async move {
+                    variant
+                        .infer(
+                            input,
+                            models,
+                            function,
+                            step_config,
+                            clients,
+                            inference_params,
+                        )
+                        .await
+                })
+                .await;
+                match result {
+                    Ok(result) => return Ok(result),
+                    Err(error) => {
+                        step_errors.insert(step.to_string(), error);
+                    }
+                }
+            }
+            Err(Error::new(ErrorDetails::FallbackChainExhausted {
+                step_errors,
+            }))
+        }
+    }
+
+    async fn infer_stream(
+        &self,
+        input: Arc<LazyResolvedInput>,
+        models: InferenceModels,
+        function: Arc<FunctionConfig>,
+        inference_config: Arc<InferenceConfig>,
+        clients: InferenceClients,
+        inference_params: InferenceParams,
+    ) -> Result<(InferenceResultStream, ModelUsedInfo), Error> {
+        let mut step_errors = IndexMap::new();
+        for step in &self.steps {
+            let variant = function.variants().get(step).ok_or_else(|| {
+                Error::new(ErrorDetails::UnknownCandidate {
+                    name: step.to_string(),
+                })
+            })?;
+            let step_config = Arc::new(InferenceConfig {
+                variant_name: Arc::from(step.as_str()),
+                ..inference_config.as_ref().clone()
+            });
+            let result = variant
+                .infer_stream(
+                    Arc::clone(&input),
+                    models.clone(),
+                    Arc::clone(&function),
+                    step_config,
+                    clients.clone(),
+                    inference_params.clone(),
+                )
+                .await;
+            match result {
+                Ok(result) => return Ok(result),
+                Err(error) => {
+                    step_errors.insert(step.to_string(), error);
+                }
+            }
+        }
+        Err(Error::new(ErrorDetails::FallbackChainExhausted {
+            step_errors,
+        }))
+    }
+
+    async fn validate(
+        &self,
+        function: Arc<FunctionConfig>,
+        models: &ModelTable,
+        embedding_models: &EmbeddingModelTable,
+        templates: &TemplateConfig<'_>,
+        function_name: &str,
+        variant_name: &str,
+        global_outbound_http_timeout: &chrono::Duration,
+        relay: Option<&TensorzeroRelay>,
+    ) -> Result<(), Error> {
+        for step in &self.steps {
+            let variant = function.variants().get(step).ok_or_else(|| {
+                Error::new(ErrorDetails::UnknownCandidate {
+                    name: step.to_string(),
+                })
+            })?;
+            Box::pin(variant.validate(
+                Arc::clone(&function),
+                models,
+                embedding_models,
+                templates,
+                function_name,
+                step,
+                global_outbound_http_timeout,
+                relay,
+            ))
+            .await
+            .map_err(|e| {
+                Error::new(ErrorDetails::InvalidCandidate {
+                    variant_name: variant_name.to_string(),
+                    message: e.to_string(),
+                })
+            })?;
+        }
+        Ok(())
+    }
+
+    // Each step is required to be a variant of the same function, so it will already have its
+    // own templates returned when that variant is validated - we don't return them again here.
+    fn get_all_template_paths(&self) -> Vec<&PathWithContents> {
+        Vec::new()
+    }
+
+    fn get_all_explicit_template_names(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    async fn start_batch_inference<'a>(
+        &'a self,
+        _input: &[LazyResolvedInput],
+        _models: InferenceModels,
+        _function: &'a FunctionConfig,
+        _inference_configs: &'a [InferenceConfig],
+        _clients: InferenceClients,
+        _inference_params: Vec<InferenceParams>,
+    ) -> Result<StartBatchModelInferenceWithMetadata<'a>, Error> {
+        Err(ErrorDetails::UnsupportedVariantForBatchInference { variant_name: None }.into())
+    }
+}
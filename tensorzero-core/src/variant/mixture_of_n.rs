@@ -1459,10 +1459,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             )]),
             ProviderTypeDefaultCredentials::new(&provider_types).into(),
@@ -1476,6 +1479,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys.clone()),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1488,6 +1496,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1582,10 +1591,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             );
             let provider_types = ProviderTypesConfig::default();
@@ -1663,10 +1675,13 @@ mod tests {
                             extra_headers: Default::default(),
                             timeouts: Default::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: Default::default(),
                     skip_relay: false,
+                    hedge: None,
                 },
             );
             let provider_types = ProviderTypesConfig::default();
@@ -51,6 +51,7 @@ pub mod chain_of_thought;
 pub mod chat_completion;
 pub mod dicl;
 pub mod dynamic;
+pub mod fallback_chain;
 pub mod mixture_of_n;
 
 /// Holds a particular variant implementation, plus additional top-level configuration
@@ -81,6 +82,7 @@ pub enum VariantConfig {
     MixtureOfN(mixture_of_n::MixtureOfNConfig),
     /// DEPRECATED (#5298 / 2026.2+): Use `chat_completion` with reasoning instead.
     ChainOfThought(chain_of_thought::ChainOfThoughtConfig),
+    FallbackChain(fallback_chain::FallbackChainConfig),
 }
 
 #[cfg(feature = "pyo3")]
@@ -113,6 +115,12 @@ pub struct ChainOfThoughtConfigPyClass {
     pub inner: Arc<VariantInfo>,
 }
 
+#[cfg(feature = "pyo3")]
+#[pyclass(name = "FallbackChainConfig")]
+pub struct FallbackChainConfigPyClass {
+    pub inner: Arc<VariantInfo>,
+}
+
 /// This type is used to determine how to enforce JSON mode for a given variant.
 /// Variants represent JSON mode in a slightly more abstract sense than ModelInferenceRequests, as
 /// we support coercing tool calls into JSON mode.
@@ -264,6 +272,7 @@ impl VariantConfig {
             VariantConfig::Dicl(params) => params.weight(),
             VariantConfig::MixtureOfN(params) => params.weight(),
             VariantConfig::ChainOfThought(params) => params.inner.weight(),
+            VariantConfig::FallbackChain(params) => params.weight(),
         }
     }
 
@@ -274,6 +283,7 @@ impl VariantConfig {
             VariantConfig::Dicl(params) => params.set_weight(weight),
             VariantConfig::MixtureOfN(params) => params.set_weight(weight),
             VariantConfig::ChainOfThought(params) => params.inner.set_weight(weight),
+            VariantConfig::FallbackChain(params) => params.set_weight(weight),
         }
     }
 }
@@ -361,6 +371,18 @@ impl Variant for VariantInfo {
                         )
                         .await
                 }
+                VariantConfig::FallbackChain(params) => {
+                    params
+                        .infer(
+                            Arc::clone(&input),
+                            models,
+                            function,
+                            inference_config,
+                            clients,
+                            inference_params,
+                        )
+                        .await
+                }
             }
         };
         if let Some(timeout) = self.timeouts.non_streaming.total_ms {
@@ -460,6 +482,18 @@ impl Variant for VariantInfo {
                         )
                         .await
                 }
+                VariantConfig::FallbackChain(params) => {
+                    params
+                        .infer_stream(
+                            Arc::clone(&input),
+                            models,
+                            function,
+                            inference_config,
+                            clients,
+                            inference_params,
+                        )
+                        .await
+                }
             }
         };
 
@@ -594,6 +628,20 @@ impl Variant for VariantInfo {
                     )
                     .await
             }
+            VariantConfig::FallbackChain(params) => {
+                params
+                    .validate(
+                        function,
+                        models,
+                        embedding_models,
+                        templates,
+                        function_name,
+                        variant_name,
+                        global_outbound_http_timeout,
+                        relay,
+                    )
+                    .await
+            }
         }
     }
 
@@ -604,6 +652,7 @@ impl Variant for VariantInfo {
             VariantConfig::Dicl(params) => params.get_all_template_paths(),
             VariantConfig::MixtureOfN(params) => params.get_all_template_paths(),
             VariantConfig::ChainOfThought(params) => params.get_all_template_paths(),
+            VariantConfig::FallbackChain(params) => params.get_all_template_paths(),
         }
     }
 
@@ -614,6 +663,7 @@ impl Variant for VariantInfo {
             VariantConfig::Dicl(params) => params.get_all_explicit_template_names(),
             VariantConfig::MixtureOfN(params) => params.get_all_explicit_template_names(),
             VariantConfig::ChainOfThought(params) => params.get_all_explicit_template_names(),
+            VariantConfig::FallbackChain(params) => params.get_all_explicit_template_names(),
         }
     }
 }
@@ -771,7 +821,12 @@ async fn infer_model_request(
         .retry_config
         .retry(|| async {
             args.model_config
-                .infer(&args.request, &clients, &args.model_name)
+                .infer(
+                    &args.request,
+                    &clients,
+                    &args.model_name,
+                    args.function.data_residency(),
+                )
                 .await
         })
         .await?;
@@ -820,7 +875,7 @@ async fn infer_model_request_stream<'request>(
     } = retry_config
         .retry(|| async {
             model_config
-                .infer_stream(&request, &clients, &model_name)
+                .infer_stream(&request, &clients, &model_name, function.data_residency())
                 .await
         })
         .await?;
@@ -1184,6 +1239,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys.clone()),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1196,6 +1256,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1276,10 +1337,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let retry_config = Box::leak(Box::new(RetryConfig::default()));
 
@@ -1389,10 +1453,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
 
         // Create the arguments struct
@@ -1456,10 +1523,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
 
         // Create the arguments struct
@@ -1496,6 +1566,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys.clone()),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1508,6 +1583,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1594,6 +1670,8 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
                 (
@@ -1605,11 +1683,14 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
             ]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         };
         let retry_config = Box::leak(Box::new(RetryConfig::default()));
 
@@ -1670,6 +1751,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys.clone()),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1682,6 +1768,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1724,10 +1811,13 @@ mod tests {
                     extra_headers: Default::default(),
                     timeouts: Default::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 },
             )]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         }));
 
         // Prepare the model inference request
@@ -1832,6 +1922,11 @@ mod tests {
             http_client: client.clone(),
             clickhouse_connection_info: clickhouse_connection_info.clone(),
             postgres_connection_info: PostgresConnectionInfo::Disabled,
+            hot_cache: Arc::new(crate::cache::ValkeyCacheBackend::new(
+                crate::db::valkey::ValkeyConnectionInfo::new_disabled(),
+                crate::cache::DEFAULT_HOT_CACHE_TTL_S,
+                crate::cache::DEFAULT_HOT_CACHE_MAX_VALUE_BYTES,
+            )),
             credentials: Arc::new(api_keys.clone()),
             cache_options: CacheOptions {
                 max_age_s: None,
@@ -1844,6 +1939,7 @@ mod tests {
             scope_info: ScopeInfo {
                 tags: Arc::new(HashMap::new()),
                 api_key_public_id: None,
+                model_name: None,
             },
             relay: None,
             include_raw_usage: false,
@@ -1914,6 +2010,8 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
                 (
@@ -1925,11 +2023,14 @@ mod tests {
                         extra_headers: Default::default(),
                         timeouts: Default::default(),
                         discard_unknown_chunks: false,
+                        pricing: None,
+                        region: None,
                     },
                 ),
             ]),
             timeouts: Default::default(),
             skip_relay: false,
+            hedge: None,
         }));
         let retry_config = RetryConfig::default();
 
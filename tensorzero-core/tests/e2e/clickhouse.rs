@@ -810,6 +810,7 @@ async fn test_clickhouse_migration_manager() {
         cached: false,
         finish_reason: None,
         snapshot_hash: Some(SnapshotHash::new_test()),
+        cost_usd: None,
         timestamp: None,
     };
     clickhouse
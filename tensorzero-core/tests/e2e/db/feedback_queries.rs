@@ -491,6 +491,8 @@ async fn test_get_variant_performances_inference_level_cumulative() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -529,6 +531,8 @@ async fn test_get_variant_performances_inference_level_week() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -568,6 +572,8 @@ async fn test_get_variant_performances_episode_level_cumulative() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Episode,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -605,6 +611,8 @@ async fn test_get_variant_performances_with_variant_filter() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -660,6 +668,8 @@ async fn test_get_variant_performances_empty_for_nonexistent_function() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -688,6 +698,8 @@ async fn test_get_variant_performances_different_time_windows() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -732,6 +744,8 @@ async fn test_get_variant_performances_boolean_metric() {
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -766,6 +780,8 @@ async fn test_get_variant_performances_ask_question_solved_with_variant() {
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Episode,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -858,6 +874,8 @@ async fn test_get_variant_performances_ask_question_num_questions_with_variant()
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Min,
         level: MetricConfigLevel::Episode,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -919,6 +937,8 @@ async fn test_get_variant_performances_empty_for_nonexistent_metric() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
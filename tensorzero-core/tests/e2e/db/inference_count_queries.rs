@@ -302,6 +302,8 @@ async fn test_count_feedbacks_for_float_metric(conn: impl InferenceCountQueries)
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -328,6 +330,8 @@ async fn test_count_feedbacks_for_boolean_metric(conn: impl InferenceCountQuerie
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -354,6 +358,8 @@ async fn test_count_inferences_with_threshold_float_metric(conn: impl InferenceC
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -399,6 +405,8 @@ async fn test_count_inferences_with_threshold_boolean_metric_max(conn: impl Infe
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -460,6 +468,8 @@ async fn test_count_feedbacks_for_episode_level_boolean_metric(conn: impl Infere
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Episode,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
@@ -487,6 +497,8 @@ async fn test_count_feedbacks_for_episode_level_float_metric(conn: impl Inferenc
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Episode,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
 
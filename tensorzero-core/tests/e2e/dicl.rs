@@ -366,6 +366,7 @@ async fn embed_insert_example(
         scope_info: ScopeInfo {
             tags: Arc::new(HashMap::new()),
             api_key_public_id: None,
+            model_name: None,
         },
         relay: None,
         include_raw_usage: false,
@@ -1552,6 +1552,8 @@ async fn e2e_test_float_feedback_validation_disabled() {
         r#type: MetricConfigType::Float,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
     config
@@ -1894,6 +1896,8 @@ async fn e2e_test_boolean_feedback_validation_disabled() {
         r#type: MetricConfigType::Boolean,
         optimize: MetricConfigOptimize::Max,
         level: MetricConfigLevel::Inference,
+        aggregation: MetricConfigAggregation::default(),
+        bounds: None,
         description: None,
     };
     config
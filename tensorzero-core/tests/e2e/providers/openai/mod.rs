@@ -1298,6 +1298,7 @@ async fn test_embedding_request() {
         scope_info: ScopeInfo {
             tags: Arc::new(HashMap::new()),
             api_key_public_id: None,
+            model_name: None,
         },
         relay: None,
         include_raw_usage: false,
@@ -1449,6 +1450,7 @@ async fn test_embedding_sanity_check() {
         scope_info: ScopeInfo {
             tags: Arc::new(HashMap::new()),
             api_key_public_id: None,
+            model_name: None,
         },
         relay: None,
         include_raw_usage: false,
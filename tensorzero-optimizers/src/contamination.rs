@@ -0,0 +1,212 @@
+//! Train/test contamination checking for optimization workflows.
+//!
+//! Compares the rendered training examples for an optimization job against datapoints
+//! from one or more evaluation datasets, flagging exact and near-duplicate input matches.
+//! This is a lightweight, dependency-free check (no embeddings or external services): exact
+//! matches compare a normalized input representation directly, and near-duplicates use
+//! Jaccard similarity over word shingles.
+//!
+//! The report is not persisted alongside the optimization job: `OptimizationJobHandle` is a
+//! stateless handle for a job at an external provider (see `tensorzero-optimizers/src/lib.rs`),
+//! not a local database row, so there is nowhere durable to attach it. Instead, an
+//! over-threshold report is logged via `tracing::warn!` (and returned as the launch error when
+//! blocking), so it shows up wherever the gateway's structured logs are collected.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use tensorzero_core::db::clickhouse::ClickHouseConnectionInfo;
+use tensorzero_core::db::datasets::{DatasetQueries, GetDatapointsParams};
+use tensorzero_core::error::{Error, ErrorDetails};
+use tensorzero_core::inference::types::stored_input::StoredInput;
+use tensorzero_core::stored_inference::RenderedSample;
+
+/// Parameters controlling a train/test contamination check.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export, optional_fields))]
+pub struct ContaminationCheckConfig {
+    /// Names of the evaluation datasets to check the training set against.
+    pub evaluation_dataset_names: Vec<String>,
+    /// Jaccard similarity (over word shingles) at or above which two inputs are
+    /// considered near-duplicates. Defaults to 0.8.
+    #[serde(default = "default_near_duplicate_threshold")]
+    pub near_duplicate_threshold: f32,
+    /// Fraction of training examples that may overlap with the evaluation datasets
+    /// (exact or near-duplicate) before the launch is blocked. Defaults to 0.0
+    /// (any overlap blocks the launch).
+    #[serde(default)]
+    pub max_overlap_fraction: f32,
+    /// If true, overlap above `max_overlap_fraction` returns an error instead of
+    /// just being logged as a warning. Defaults to true.
+    #[serde(default = "default_block_on_exceed")]
+    pub block_on_exceed: bool,
+}
+
+fn default_near_duplicate_threshold() -> f32 {
+    0.8
+}
+
+fn default_block_on_exceed() -> bool {
+    true
+}
+
+/// A single training example that overlaps with an evaluation dataset.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ContaminationMatch {
+    pub training_example_index: usize,
+    pub dataset_name: String,
+    pub datapoint_id: uuid::Uuid,
+    pub exact: bool,
+    pub similarity: f32,
+}
+
+/// Report produced by [`check_contamination`].
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct ContaminationReport {
+    pub num_training_examples: usize,
+    pub num_contaminated: usize,
+    pub overlap_fraction: f32,
+    pub matches: Vec<ContaminationMatch>,
+    pub blocked: bool,
+}
+
+/// Normalizes a `StoredInput` to a string for exact comparison and shingling. This is
+/// intentionally just the canonical JSON serialization: it's stable across identical inputs
+/// and doesn't require reimplementing message rendering here.
+fn normalize_input(input: &StoredInput) -> String {
+    serde_json::to_string(input).unwrap_or_default()
+}
+
+/// Word shingles of size 3, used as a cheap stand-in for embedding-based similarity.
+fn shingles(text: &str) -> HashSet<String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.len() < 3 {
+        return HashSet::from([words.join(" ")]);
+    }
+    words.windows(3).map(|window| window.join(" ")).collect()
+}
+
+fn jaccard_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(b).count();
+    let union = a.union(b).count();
+    if union == 0 {
+        0.0
+    } else {
+        intersection as f32 / union as f32
+    }
+}
+
+/// Checks `train_examples` for exact and near-duplicate overlap against the datapoints in
+/// `config.evaluation_dataset_names`.
+///
+/// Returns an error if the overlap fraction exceeds `config.max_overlap_fraction` and
+/// `config.block_on_exceed` is set; otherwise returns the report so the caller can log or
+/// surface it as a warning.
+pub async fn check_contamination(
+    clickhouse_connection_info: &ClickHouseConnectionInfo,
+    train_examples: &[RenderedSample],
+    config: &ContaminationCheckConfig,
+) -> Result<ContaminationReport, Error> {
+    let mut eval_inputs = Vec::new();
+    for dataset_name in &config.evaluation_dataset_names {
+        let datapoints = clickhouse_connection_info
+            .get_datapoints(&GetDatapointsParams {
+                dataset_name: Some(dataset_name.clone()),
+                function_name: None,
+                ids: None,
+                limit: u32::MAX,
+                offset: 0,
+                allow_stale: false,
+                filter: None,
+                order_by: None,
+                search_query_experimental: None,
+            })
+            .await?;
+        for datapoint in datapoints {
+            eval_inputs.push((
+                dataset_name.clone(),
+                datapoint.id(),
+                normalize_input(datapoint.input()),
+            ));
+        }
+    }
+    let eval_shingles: Vec<(String, uuid::Uuid, String, HashSet<String>)> = eval_inputs
+        .into_iter()
+        .map(|(dataset_name, id, text)| {
+            let shingles = shingles(&text);
+            (dataset_name, id, text, shingles)
+        })
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut contaminated_indices = HashSet::new();
+    for (index, example) in train_examples.iter().enumerate() {
+        let train_text = normalize_input(&example.stored_input);
+        let train_shingles = shingles(&train_text);
+        for (dataset_name, datapoint_id, eval_text, eval_shingle_set) in &eval_shingles {
+            let exact = &train_text == eval_text;
+            let similarity = jaccard_similarity(&train_shingles, eval_shingle_set);
+            if exact || similarity >= config.near_duplicate_threshold {
+                contaminated_indices.insert(index);
+                matches.push(ContaminationMatch {
+                    training_example_index: index,
+                    dataset_name: dataset_name.clone(),
+                    datapoint_id: *datapoint_id,
+                    exact,
+                    similarity,
+                });
+            }
+        }
+    }
+
+    let num_training_examples = train_examples.len();
+    let overlap_fraction = if num_training_examples == 0 {
+        0.0
+    } else {
+        contaminated_indices.len() as f32 / num_training_examples as f32
+    };
+    let exceeds_threshold = overlap_fraction > config.max_overlap_fraction;
+    let blocked = exceeds_threshold && config.block_on_exceed;
+
+    let report = ContaminationReport {
+        num_training_examples,
+        num_contaminated: contaminated_indices.len(),
+        overlap_fraction,
+        matches,
+        blocked,
+    };
+
+    if exceeds_threshold {
+        tracing::warn!(
+            overlap_fraction = report.overlap_fraction,
+            num_contaminated = report.num_contaminated,
+            num_training_examples = report.num_training_examples,
+            blocked,
+            "Train/test contamination check found overlap between training data and evaluation datasets"
+        );
+    }
+
+    if blocked {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: format!(
+                "Train/test contamination check blocked optimization launch: {:.1}% of training examples ({}/{}) overlap with the evaluation dataset(s) {:?}, exceeding the allowed {:.1}%",
+                report.overlap_fraction * 100.0,
+                report.num_contaminated,
+                report.num_training_examples,
+                config.evaluation_dataset_names,
+                config.max_overlap_fraction * 100.0,
+            ),
+        }));
+    }
+
+    Ok(report)
+}
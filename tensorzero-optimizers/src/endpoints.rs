@@ -29,6 +29,7 @@ use tensorzero_core::{
     utils::gateway::{AppState, AppStateData, StructuredJson},
 };
 
+use crate::contamination::{ContaminationCheckConfig, check_contamination};
 use crate::{JobHandle, Optimizer};
 
 // TODO(shuyangli): revisit this and see if it should be u32::MAX.
@@ -48,6 +49,11 @@ pub struct LaunchOptimizationWorkflowParams {
     pub offset: Option<u32>,
     pub val_fraction: Option<f64>,
     pub optimizer_config: UninitializedOptimizerInfo,
+    /// If set, checks the training data for overlap with the given evaluation
+    /// dataset(s) before launching the optimization job. See
+    /// [`crate::contamination::ContaminationCheckConfig`].
+    #[serde(default)]
+    pub contamination_check: Option<ContaminationCheckConfig>,
 }
 
 pub async fn launch_optimization_workflow_handler(
@@ -88,6 +94,7 @@ pub async fn launch_optimization_workflow(
         offset,
         val_fraction,
         optimizer_config,
+        contamination_check,
     } = params;
     // Query the database for the stored inferences
     let stored_inferences = clickhouse_connection_info
@@ -122,6 +129,15 @@ pub async fn launch_optimization_workflow(
     // Split the inferences into train and val sets
     let (train_examples, val_examples) = split_examples(rendered_inferences, val_fraction)?;
 
+    if let Some(contamination_check) = &contamination_check {
+        check_contamination(
+            clickhouse_connection_info,
+            &train_examples,
+            contamination_check,
+        )
+        .await?;
+    }
+
     // Launch the optimization job
     optimizer_config
         .load()
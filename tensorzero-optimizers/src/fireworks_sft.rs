@@ -300,10 +300,13 @@ impl JobHandle for FireworksSFTJobHandle {
                             extra_body: None,
                             timeouts: TimeoutsConfig::default(),
                             discard_unknown_chunks: false,
+                            pricing: None,
+                            region: None,
                         },
                     )]),
                     timeouts: TimeoutsConfig::default(),
                     skip_relay: None,
+                    hedge: None,
                 }),
             };
             if !self.deploy_after_training {
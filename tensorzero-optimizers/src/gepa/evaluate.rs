@@ -8,6 +8,7 @@ use tensorzero_core::{
     client::Client,
     config::{Config, UninitializedVariantConfig, UninitializedVariantInfo},
     db::clickhouse::ClickHouseConnectionInfo,
+    db::postgres::PostgresConnectionInfo,
     endpoints::datasets::v1::{
         create_datapoints,
         types::{CreateDatapointRequest, CreateDatapointsRequest, CreateDatapointsResponse},
@@ -22,8 +23,8 @@ use tensorzero_core::{
 
 use evaluations::{
     ClientInferenceExecutor, EvaluationCoreArgs, EvaluationFunctionConfig,
-    EvaluationFunctionConfigTable, EvaluationStats, EvaluationVariant, EvaluatorStats,
-    OutputFormat, stats::EvaluationInfo,
+    EvaluationFunctionConfigTable, EvaluationRetryPolicy, EvaluationStats, EvaluationVariant,
+    EvaluatorStats, OutputFormat, stats::EvaluationInfo,
 };
 
 // Type aliases for score map signatures used for pareto filtering
@@ -178,6 +179,7 @@ pub async fn evaluate_variant(params: EvaluateVariantParams) -> Result<Evaluatio
     let core_args = EvaluationCoreArgs {
         inference_executor,
         clickhouse_client: params.clickhouse_connection_info.clone(),
+        postgres_connection_info: PostgresConnectionInfo::Disabled,
         evaluation_config: params.evaluation_config.clone(),
         function_configs,
         evaluation_name: params.evaluation_name,
@@ -188,7 +190,8 @@ pub async fn evaluate_variant(params: EvaluateVariantParams) -> Result<Evaluatio
         concurrency: params.concurrency,
         inference_cache: CacheEnabledMode::Off, // Disable caching for fair evaluation
         tags: HashMap::new(),                   // No external tags for optimizer evaluations
-                                                // We may want to tag inferences made as part of GEPA later as well.
+        // We may want to tag inferences made as part of GEPA later as well.
+        retry_policy: EvaluationRetryPolicy::default(),
     };
 
     // Call run_evaluation_core_streaming
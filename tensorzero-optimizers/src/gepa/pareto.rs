@@ -1067,6 +1067,7 @@ mod tests {
                             optimize: LLMJudgeOptimize::Min,
                             cutoff: None,
                             description: Some("test_llm_judge_evaluator".to_string()),
+                            criteria: None,
                         })
                     }
                     _ => panic!("Invalid optimize direction: {optimize}"),
@@ -1137,6 +1138,7 @@ mod tests {
                         mean: *mean,
                         stderr: *stderr,
                         count: *count,
+                        criteria: None,
                     },
                 )
             })
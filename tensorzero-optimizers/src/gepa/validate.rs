@@ -491,7 +491,8 @@ fn extract_chat_completion_from_variant_info(
         VariantConfig::BestOfNSampling(_)
         | VariantConfig::Dicl(_)
         | VariantConfig::MixtureOfN(_)
-        | VariantConfig::ChainOfThought(_) => {
+        | VariantConfig::ChainOfThought(_)
+        | VariantConfig::FallbackChain(_) => {
             tracing::warn!(
                 "Skipping non-ChatCompletion variant '{}' (GEPA only supports ChatCompletion variants)",
                 variant_name
@@ -0,0 +1,207 @@
+//! Hyperparameter sweep expansion for optimization jobs.
+//!
+//! An optimizer's config (e.g. `openai_sft`, `fireworks_sft`) exposes hyperparameters as plain
+//! fields with names that vary by optimizer type (`n_epochs` vs `epochs`, `learning_rate` vs
+//! `learning_rate_multiplier`, etc.), so this module doesn't try to model hyperparameters as a
+//! typed, optimizer-specific structure. Instead, [`expand_hyperparameter_sweep`] merges each
+//! sweep combination's named field overrides directly into a JSON-serialized copy of the base
+//! `optimizer_config`, so it works for any current or future optimizer without changes here.
+
+use std::collections::HashMap;
+
+use rand::seq::IndexedRandom;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use tensorzero_core::error::{Error, ErrorDetails};
+use tensorzero_core::optimization::UninitializedOptimizerInfo;
+
+/// How to turn a sweep's hyperparameter axes into concrete combinations.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub enum HyperparameterSweepSearch {
+    /// Every combination of the given axis values (the cartesian product).
+    Grid,
+    /// `num_samples` combinations, each drawing one value per axis independently at random.
+    Random { num_samples: usize },
+}
+
+/// A grid or random search over named hyperparameter axes, applied on top of a base
+/// `optimizer_config` to produce the per-job configs for a sweep.
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "ts-bindings", ts(export))]
+pub struct HyperparameterSweepSpec {
+    pub search: HyperparameterSweepSearch,
+    /// Named hyperparameter axes to sweep, e.g. `{"n_epochs": [1, 2, 3]}`. Each axis name must
+    /// match a field on the base optimizer's config; the exact field names vary by optimizer
+    /// type (see the optimizer's `Uninitialized*Config` type).
+    pub axes: HashMap<String, Vec<Value>>,
+}
+
+/// Expands `spec` into one concrete [`UninitializedOptimizerInfo`] per sweep combination, by
+/// merging each combination's axis values into a JSON-serialized copy of `base`.
+///
+/// Returns an error if `spec.axes` is empty, or if merging an axis's values back into `base`
+/// produces a config that doesn't deserialize (e.g. an axis name isn't a field on `base`'s
+/// optimizer type, or a value has the wrong type for that field).
+pub fn expand_hyperparameter_sweep(
+    base: &UninitializedOptimizerInfo,
+    spec: &HyperparameterSweepSpec,
+) -> Result<Vec<UninitializedOptimizerInfo>, Error> {
+    if spec.axes.is_empty() {
+        return Err(Error::new(ErrorDetails::InvalidRequest {
+            message: "Hyperparameter sweep must specify at least one axis".to_string(),
+        }));
+    }
+
+    let combinations = match &spec.search {
+        HyperparameterSweepSearch::Grid => grid_combinations(&spec.axes),
+        HyperparameterSweepSearch::Random { num_samples } => {
+            random_combinations(&spec.axes, *num_samples)
+        }
+    };
+
+    let base_value = serde_json::to_value(base).map_err(|e| {
+        Error::new(ErrorDetails::Serialization {
+            message: format!("Failed to serialize base optimizer_config for sweep: {e}"),
+        })
+    })?;
+
+    combinations
+        .into_iter()
+        .map(|overrides| {
+            let mut merged = base_value.clone();
+            let Value::Object(fields) = &mut merged else {
+                return Err(Error::new(ErrorDetails::InvalidRequest {
+                    message: "Base optimizer_config must serialize to a JSON object".to_string(),
+                }));
+            };
+            for (name, value) in overrides {
+                fields.insert(name, value);
+            }
+            serde_json::from_value(merged).map_err(|e| {
+                Error::new(ErrorDetails::InvalidRequest {
+                    message: format!(
+                        "Failed to apply hyperparameter sweep overrides to optimizer_config: {e}"
+                    ),
+                })
+            })
+        })
+        .collect()
+}
+
+/// The cartesian product of every axis's values, e.g. `{"a": [1, 2], "b": [3]}` produces
+/// `[{"a": 1, "b": 3}, {"a": 2, "b": 3}]`.
+fn grid_combinations(axes: &HashMap<String, Vec<Value>>) -> Vec<Vec<(String, Value)>> {
+    let mut combinations: Vec<Vec<(String, Value)>> = vec![Vec::new()];
+    for (name, values) in axes {
+        let mut next = Vec::with_capacity(combinations.len() * values.len());
+        for combination in &combinations {
+            for value in values {
+                let mut extended = combination.clone();
+                extended.push((name.clone(), value.clone()));
+                next.push(extended);
+            }
+        }
+        combinations = next;
+    }
+    combinations
+}
+
+/// `num_samples` combinations, each independently drawing one value per axis uniformly at
+/// random. Axes with no values are skipped, since there's nothing to sample.
+fn random_combinations(
+    axes: &HashMap<String, Vec<Value>>,
+    num_samples: usize,
+) -> Vec<Vec<(String, Value)>> {
+    let mut rng = rand::rng();
+    (0..num_samples)
+        .map(|_| {
+            axes.iter()
+                .filter_map(|(name, values)| {
+                    values
+                        .choose(&mut rng)
+                        .map(|value| (name.clone(), value.clone()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tensorzero_core::optimization::UninitializedOptimizerConfig;
+
+    fn base_openai_sft() -> UninitializedOptimizerInfo {
+        let config = serde_json::json!({
+            "type": "openai_sft",
+            "model": "gpt-4.1-2025-04-14",
+            "n_epochs": 1,
+        });
+        UninitializedOptimizerInfo {
+            inner: serde_json::from_value::<UninitializedOptimizerConfig>(config).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_grid_expands_cartesian_product() {
+        let spec = HyperparameterSweepSpec {
+            search: HyperparameterSweepSearch::Grid,
+            axes: HashMap::from([(
+                "n_epochs".to_string(),
+                vec![Value::from(1), Value::from(2), Value::from(3)],
+            )]),
+        };
+        let expanded = expand_hyperparameter_sweep(&base_openai_sft(), &spec).unwrap();
+        assert_eq!(
+            expanded.len(),
+            3,
+            "Grid search over a single 3-value axis should produce 3 configs"
+        );
+    }
+
+    #[test]
+    fn test_random_produces_requested_sample_count() {
+        let spec = HyperparameterSweepSpec {
+            search: HyperparameterSweepSearch::Random { num_samples: 5 },
+            axes: HashMap::from([("n_epochs".to_string(), vec![Value::from(1), Value::from(2)])]),
+        };
+        let expanded = expand_hyperparameter_sweep(&base_openai_sft(), &spec).unwrap();
+        assert_eq!(
+            expanded.len(),
+            5,
+            "Random search should produce exactly num_samples configs"
+        );
+    }
+
+    #[test]
+    fn test_empty_axes_is_rejected() {
+        let spec = HyperparameterSweepSpec {
+            search: HyperparameterSweepSearch::Grid,
+            axes: HashMap::new(),
+        };
+        let err = expand_hyperparameter_sweep(&base_openai_sft(), &spec).unwrap_err();
+        assert!(
+            err.to_string().contains("at least one axis"),
+            "Should reject a sweep with no axes, got: {err}"
+        );
+    }
+
+    #[test]
+    fn test_wrong_value_type_is_rejected() {
+        let spec = HyperparameterSweepSpec {
+            search: HyperparameterSweepSearch::Grid,
+            axes: HashMap::from([("n_epochs".to_string(), vec![Value::from("not-a-number")])]),
+        };
+        let err = expand_hyperparameter_sweep(&base_openai_sft(), &spec).unwrap_err();
+        assert!(
+            !err.to_string().is_empty(),
+            "Merging a wrong-typed override for a known field should surface a deserialization error"
+        );
+    }
+}
@@ -21,11 +21,13 @@ use tensorzero_core::{
     stored_inference::RenderedSample,
 };
 
+pub mod contamination;
 pub mod dicl;
 pub mod endpoints;
 pub mod fireworks_sft;
 pub mod gcp_vertex_gemini_sft;
 pub mod gepa;
+pub mod hyperparameter_sweep;
 pub mod openai;
 pub mod openai_rft;
 pub mod openai_sft;
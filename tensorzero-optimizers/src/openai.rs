@@ -369,6 +369,8 @@ pub fn convert_to_optimizer_status(job: OpenAIFineTuningJob) -> Result<Optimizat
                 extra_body: None,
                 timeouts: TimeoutsConfig::default(),
                 discard_unknown_chunks: false,
+                pricing: None,
+                region: None,
             };
             OptimizationJobInfo::Completed {
                 output: OptimizerOutput::Model(UninitializedModelConfig {
@@ -376,6 +378,7 @@ pub fn convert_to_optimizer_status(job: OpenAIFineTuningJob) -> Result<Optimizat
                     providers: HashMap::from([(model_name.clone().into(), model_provider)]),
                     timeouts: TimeoutsConfig::default(),
                     skip_relay: None,
+                    hedge: None,
                 }),
             }
         }
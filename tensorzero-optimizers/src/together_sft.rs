@@ -346,6 +346,8 @@ impl JobHandle for TogetherSFTJobHandle {
                     extra_body: None,
                     timeouts: TimeoutsConfig::default(),
                     discard_unknown_chunks: false,
+                    pricing: None,
+                    region: None,
                 };
                 Ok(OptimizationJobInfo::Completed {
                     output: OptimizerOutput::Model(UninitializedModelConfig {
@@ -353,6 +355,7 @@ impl JobHandle for TogetherSFTJobHandle {
                         providers: HashMap::from([(model_name.into(), model_provider)]),
                         timeouts: TimeoutsConfig::default(),
                         skip_relay: None,
+                        hedge: None,
                     }),
                 })
             }
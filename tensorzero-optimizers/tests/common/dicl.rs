@@ -658,6 +658,7 @@ fn create_inference_params(
         extra_body: Default::default(),
         extra_headers: Default::default(),
         internal_dynamic_variant_config: None,
+        timeout_ms: None,
         otlp_traces_extra_headers: Default::default(),
         otlp_traces_extra_attributes: Default::default(),
         otlp_traces_extra_resources: Default::default(),
@@ -1101,6 +1102,7 @@ pub async fn run_dicl_workflow_with_client(client: &tensorzero::Client) {
                 ..Default::default()
             }),
         },
+        contamination_check: None,
     };
 
     let job_handle = client
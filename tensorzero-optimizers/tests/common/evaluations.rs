@@ -28,6 +28,7 @@ fn test_evaluator_stats_clone_and_debug() {
         mean: 0.85,
         stderr: 0.05,
         count: 100,
+        criteria: None,
     };
 
     // Test Clone
@@ -201,6 +201,7 @@ pub async fn run_test_case(test_case: &impl OptimizationTestCase) {
                 scope_info: ScopeInfo {
                     tags: Arc::new(HashMap::new()),
                     api_key_public_id: None,
+                    model_name: None,
                 },
                 relay: None,
                 include_raw_usage: false,
@@ -211,7 +212,7 @@ pub async fn run_test_case(test_case: &impl OptimizationTestCase) {
                 return;
             }
             let response = model_config
-                .infer(&request, &clients, "test")
+                .infer(&request, &clients, "test", None)
                 .await
                 .unwrap();
             println!("Response: {response:?}");
@@ -243,6 +244,7 @@ pub async fn run_workflow_test_case_with_tensorzero_client(
         val_fraction: None,
         // Mock mode is configured via provider_types in the test config file
         optimizer_config: test_case.get_optimizer_info(),
+        contamination_check: None,
     };
     let job_handle = client
         .experimental_launch_optimization_workflow(params)
@@ -401,6 +401,7 @@ pub fn create_test_evaluation_config_with_evaluators() -> EvaluationConfig {
             optimize: LLMJudgeOptimize::Max,
             cutoff: Some(0.5),
             description: Some("fluency evaluation".to_string()),
+            criteria: None,
         }),
     );
 
@@ -889,6 +890,7 @@ async fn test_analyze_input_format_scenarios() {
             optimize: LLMJudgeOptimize::Max,
             cutoff: None,
             description: Some("numeric evaluator".to_string()),
+            criteria: None,
         }),
     );
     evaluators.insert(
@@ -902,6 +904,7 @@ async fn test_analyze_input_format_scenarios() {
             optimize: LLMJudgeOptimize::Max,
             cutoff: None,
             description: Some("bool evaluator".to_string()),
+            criteria: None,
         }),
     );
     evaluators.insert(
@@ -915,6 +918,7 @@ async fn test_analyze_input_format_scenarios() {
             optimize: LLMJudgeOptimize::Max,
             cutoff: None,
             description: Some("null evaluator".to_string()),
+            criteria: None,
         }),
     );
     let score_eval_config = EvaluationConfig::Inference(InferenceEvaluationConfig {